@@ -1,5 +1,38 @@
 //! Arena configuration parameters.
 
+use murk_core::Scalar;
+
+/// When sparse ranges freed by copy-on-write become reusable.
+///
+/// A sparse write retires the field's previous range into a pending list.
+/// Pending ranges are promoted to the reusable pool at the start of a
+/// tick, once the published descriptor no longer references them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SparseRetirementPolicy {
+    /// Promote pending ranges at the start of every tick.
+    #[default]
+    Eager,
+    /// Let pending ranges accumulate, promoting them all at the start of
+    /// the first tick at which at least `max_pending` are pending.
+    ///
+    /// A sparse allocation that would otherwise exceed capacity promotes
+    /// the ranges retired in earlier ticks first, whatever the count.
+    Deferred {
+        /// Pending range count that triggers a flush.
+        max_pending: usize,
+    },
+}
+
+impl SparseRetirementPolicy {
+    /// Whether `pending` ranges should be promoted now.
+    pub(crate) fn should_flush(self, pending: usize) -> bool {
+        match self {
+            Self::Eager => true,
+            Self::Deferred { max_pending } => pending >= max_pending,
+        }
+    }
+}
+
 /// Configuration for the arena allocator.
 ///
 /// Controls segment sizing, capacity limits, and generation retention.
@@ -7,9 +40,9 @@
 #[derive(Clone, Debug)]
 #[must_use]
 pub struct ArenaConfig {
-    /// Size of each arena segment in f32 elements.
+    /// Size of each arena segment in `Scalar` elements.
     ///
-    /// Default: 16_777_216 (64MB at 4 bytes per f32).
+    /// Default: 16_777_216 (64MB of `f32`, 128MB with `f64-fields`).
     /// Must be a power of two and at least 1024.
     pub segment_size: u32,
 
@@ -41,10 +74,15 @@ pub struct ArenaConfig {
     /// Used to compute per-field allocation sizes:
     /// `field_len = cell_count * field_type.components()`.
     pub cell_count: u32,
+
+    /// When copy-on-write sparse ranges are returned to the reusable pool.
+    ///
+    /// Default: [`SparseRetirementPolicy::Eager`].
+    pub sparse_retirement: SparseRetirementPolicy,
 }
 
 impl ArenaConfig {
-    /// Default segment size: 64MB / 4 bytes = 16M f32 elements.
+    /// Default segment size: 16M `Scalar` elements (64MB of `f32`).
     pub const DEFAULT_SEGMENT_SIZE: u32 = 16_777_216;
 
     /// Default maximum segment count.
@@ -62,12 +100,13 @@ impl ArenaConfig {
             max_segments: Self::DEFAULT_MAX_SEGMENTS,
             max_generation_age: Self::DEFAULT_MAX_GENERATION_AGE,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         }
     }
 
     /// Total capacity of a single segment in bytes.
     pub fn segment_bytes(&self) -> usize {
-        self.segment_size as usize * std::mem::size_of::<f32>()
+        self.segment_size as usize * std::mem::size_of::<Scalar>()
     }
 }
 
@@ -84,7 +123,10 @@ mod tests {
     #[test]
     fn default_segment_size_is_64mb() {
         let config = ArenaConfig::new(100);
-        assert_eq!(config.segment_bytes(), 64 * 1024 * 1024);
+        assert_eq!(
+            config.segment_bytes(),
+            16 * 1024 * 1024 * std::mem::size_of::<Scalar>()
+        );
     }
 
     #[test]
@@ -92,4 +134,12 @@ mod tests {
         let config = ArenaConfig::new(10_000);
         assert_eq!(config.cell_count, 10_000);
     }
+
+    #[test]
+    fn deferred_flushes_at_bound() {
+        let policy = SparseRetirementPolicy::Deferred { max_pending: 3 };
+        assert!(!policy.should_flush(2));
+        assert!(policy.should_flush(3));
+        assert!(SparseRetirementPolicy::Eager.should_flush(0));
+    }
 }
@@ -21,7 +21,7 @@ use crate::handle::{FieldHandle, FieldLocation};
 /// needs to look up metadata and then mutate segment data.
 #[derive(Clone, Debug)]
 pub struct FieldMeta {
-    /// Number of f32 components per cell for this field.
+    /// Number of components per cell for this field.
     pub components: u32,
     /// Allocation strategy.
     pub mutability: FieldMutability,
@@ -94,6 +94,13 @@ impl FieldDescriptor {
         Ok(Self { entries })
     }
 
+    /// Build a descriptor from ready-made entries, kept in the given order.
+    pub(crate) fn from_entries(entries: impl IntoIterator<Item = (FieldId, FieldEntry)>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+        }
+    }
+
     /// Look up a field's entry.
     pub fn get(&self, field: FieldId) -> Option<&FieldEntry> {
         self.entries.get(&field)
@@ -184,7 +191,7 @@ mod tests {
                     field_type: FieldType::Scalar,
                     mutability: FieldMutability::Sparse,
                     units: None,
-                    bounds: Some((0.0, 100.0)),
+                    bounds: Some((0.0, 100.0).into()),
                     boundary_behavior: BoundaryBehavior::Clamp,
                 },
             ),
@@ -9,7 +9,7 @@ use std::fmt;
 /// Physical location of a field allocation within the arena.
 ///
 /// Handles are internal to `murk-arena` and never cross the FFI boundary.
-/// They encode enough information to resolve a `&[f32]` slice in O(1).
+/// They encode enough information to resolve a `&[Scalar]` slice in O(1).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[must_use]
 pub struct FieldHandle {
@@ -17,7 +17,7 @@ pub struct FieldHandle {
     pub(crate) generation: u32,
     /// Byte offset within the target segment.
     pub(crate) offset: u32,
-    /// Length of the allocation in f32 elements.
+    /// Length of the allocation in `Scalar` elements.
     pub(crate) len: u32,
     /// Which pool and segment this handle points into.
     pub(crate) location: FieldLocation,
@@ -39,7 +39,7 @@ impl FieldHandle {
         self.generation
     }
 
-    /// Length of the allocation in f32 elements.
+    /// Length of the allocation in `Scalar` elements.
     pub fn len(&self) -> u32 {
         self.len
     }
@@ -85,7 +85,7 @@ pub enum FieldLocation {
     Static {
         /// Byte offset within the static arena's data vec.
         offset: u32,
-        /// Length in f32 elements.
+        /// Length in `Scalar` elements.
         len: u32,
     },
 }
@@ -11,7 +11,7 @@
 //! ```text
 //! PingPongArena (orchestrator)
 //! ├── ArenaBuffer × 2 (alternating published/staging)
-//! │   └── SegmentList → Segment[] (64MB bump-allocated Vec<f32>)
+//! │   └── SegmentList → Segment[] (64MB bump-allocated Vec<Scalar>)
 //! ├── SparseSlab + sparse SegmentList (dedicated, not ping-pong'd)
 //! ├── Arc<StaticArena> (gen-0 forever, shared across vectorized envs)
 //! ├── ScratchRegion (per-tick temp space)
@@ -26,9 +26,13 @@
 //!
 //! # Phase 1 safety
 //!
-//! All allocations are `Vec<f32>` with zero-init. No `MaybeUninit`, no
+//! All allocations are `Vec<Scalar>` with zero-init. No `MaybeUninit`, no
 //! `unsafe`. Phase 2 (after WP-4) will introduce bounded `unsafe` in
 //! `raw.rs` for `MaybeUninit` + `FullWriteGuard` optimisation.
+//!
+//! The optional `mmap` feature adds a small `unsafe` module wrapping
+//! `mmap`/`munmap` so `StaticArena::from_mmap` can back static fields
+//! with read-only file mappings (Unix only).
 
 #![deny(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -38,6 +42,8 @@ pub mod config;
 pub mod descriptor;
 pub mod error;
 pub mod handle;
+#[cfg(all(feature = "mmap", unix))]
+mod mmap;
 pub mod pingpong;
 mod raw;
 pub mod read;
@@ -48,9 +54,9 @@ pub mod static_arena;
 pub mod write;
 
 // Public re-exports for the primary API surface.
-pub use config::ArenaConfig;
+pub use config::{ArenaConfig, SparseRetirementPolicy};
 pub use error::ArenaError;
 pub use pingpong::{PingPongArena, TickGuard};
-pub use read::{OwnedSnapshot, Snapshot};
+pub use read::{FieldLayout, FieldStats, OwnedSnapshot, Snapshot};
 pub use scratch::ScratchRegion;
 pub use static_arena::{SharedStaticArena, StaticArena};
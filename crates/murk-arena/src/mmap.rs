@@ -0,0 +1,108 @@
+//! Read-only memory-mapped `Scalar` storage for static fields.
+//!
+//! Backs `StaticArena::from_mmap`. The mapping is private and read-only,
+//! so the file contents are shared by every world holding the same
+//! `SharedStaticArena` without being copied into the heap. Callers promise
+//! the file is not modified or truncated while it is mapped.
+
+#![allow(unsafe_code)]
+
+use std::fs::File;
+use std::io;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::ptr::NonNull;
+
+use murk_core::Scalar;
+
+/// A read-only mapping of `len` native-endian `Scalar` values from a file.
+pub(crate) struct MappedF32 {
+    ptr: NonNull<Scalar>,
+    len: usize,
+}
+
+// SAFETY: the mapping is read-only (`PROT_READ`) and private, so shared
+// references from any thread only ever observe immutable memory, and the
+// owner unmaps it exactly once in `Drop`.
+unsafe impl Send for MappedF32 {}
+// SAFETY: see `Send` above; no interior mutability is exposed.
+unsafe impl Sync for MappedF32 {}
+
+impl MappedF32 {
+    /// Map the first `len` `Scalar` values of the file at `path`.
+    ///
+    /// Fails if the file cannot be opened, is shorter than
+    /// `len * size_of::<Scalar>()` bytes, or cannot be mapped.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated until the returned
+    /// mapping is dropped; [`as_slice`](Self::as_slice) assumes its
+    /// contents are immutable and fully backed.
+    pub(crate) unsafe fn open(path: &Path, len: usize) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let bytes = len
+            .checked_mul(std::mem::size_of::<Scalar>())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "mapped length overflows")
+            })?;
+        let file_len = file.metadata()?.len();
+        if file_len < bytes as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("file holds {file_len} bytes, need {bytes} for {len} field values"),
+            ));
+        }
+        if bytes == 0 {
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len: 0,
+            });
+        }
+
+        // SAFETY: `fd` is a valid open descriptor for the duration of the
+        // call, `bytes` is non-zero and within the file, and we request a
+        // fresh read-only private mapping at a kernel-chosen address.
+        let addr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                bytes,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if addr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        // mmap returns page-aligned addresses, which satisfy `Scalar` alignment.
+        let ptr = NonNull::new(addr.cast::<Scalar>())
+            .ok_or_else(|| io::Error::other("mmap returned a null mapping"))?;
+        Ok(Self { ptr, len })
+    }
+
+    /// The mapped values.
+    pub(crate) fn as_slice(&self) -> &[Scalar] {
+        // SAFETY: `ptr` is either dangling with `len == 0` or points to a
+        // live, aligned, read-only mapping of exactly `len` `Scalar` values that
+        // outlives `&self`, and `open`'s caller keeps the file unmodified
+        // while it is mapped. Every bit pattern is a valid float.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Drop for MappedF32 {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            // SAFETY: `ptr`/`len` describe the mapping created in `open`,
+            // which has not been unmapped yet; no borrows outlive `self`.
+            unsafe {
+                libc::munmap(
+                    self.ptr.as_ptr().cast(),
+                    self.len * std::mem::size_of::<Scalar>(),
+                );
+            }
+        }
+    }
+}
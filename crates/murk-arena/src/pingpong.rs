@@ -15,7 +15,7 @@
 use std::sync::Arc;
 
 use murk_core::id::{FieldId, ParameterVersion, TickId, WorldGenerationId};
-use murk_core::{FieldDef, FieldMutability};
+use murk_core::{FieldDef, FieldMutability, ParameterStore, Scalar};
 
 use crate::config::ArenaConfig;
 use crate::descriptor::FieldDescriptor;
@@ -88,6 +88,8 @@ pub struct PingPongArena {
     last_tick_id: TickId,
     /// Last published parameter version.
     last_param_version: ParameterVersion,
+    /// Global parameters as of the last publish.
+    last_parameters: Arc<ParameterStore>,
     /// Field definitions (kept for reset).
     field_defs: Vec<(FieldId, FieldDef)>,
 }
@@ -211,6 +213,7 @@ impl PingPongArena {
             config,
             last_tick_id: TickId(0),
             last_param_version: ParameterVersion(0),
+            last_parameters: Arc::new(ParameterStore::new()),
             field_defs,
         })
     }
@@ -235,9 +238,18 @@ impl PingPongArena {
                 reason: "generation counter overflow (u32::MAX ticks reached)".into(),
             })?;
 
-        // Promote sparse ranges retired during the previous tick. After
-        // publish(), the published descriptor no longer references them.
-        self.sparse_slab.flush_retired();
+        // Promote sparse ranges retired during earlier ticks, as the
+        // retirement policy allows. After publish(), the published
+        // descriptor no longer references them.
+        if self
+            .config
+            .sparse_retirement
+            .should_flush(self.sparse_slab.pending_retired_count())
+        {
+            self.sparse_slab.flush_retired();
+        } else {
+            self.sparse_slab.defer_retired();
+        }
 
         // Reset the staging buffer (it was the published buffer last tick).
         if self.b_is_staging {
@@ -368,6 +380,22 @@ impl PingPongArena {
         Ok(())
     }
 
+    /// [`publish`](Self::publish), replacing the global parameters that
+    /// snapshots report from this generation on.
+    ///
+    /// Snapshots of later generations published with plain `publish()`
+    /// keep reporting `parameters`.
+    pub fn publish_with_parameters(
+        &mut self,
+        tick_id: TickId,
+        param_version: ParameterVersion,
+        parameters: Arc<ParameterStore>,
+    ) -> Result<(), ArenaError> {
+        self.publish(tick_id, param_version)?;
+        self.last_parameters = parameters;
+        Ok(())
+    }
+
     /// Get a read-only snapshot of the published generation.
     pub fn snapshot(&self) -> Snapshot<'_> {
         let published_segments = if self.b_is_staging {
@@ -385,6 +413,47 @@ impl PingPongArena {
             WorldGenerationId(self.generation as u64),
             self.last_param_version,
         )
+        .with_parameters(&self.last_parameters)
+    }
+
+    /// Staged and published contents of `field`, as `(staged, published)`.
+    ///
+    /// For diffing a tick against the previous generation after the
+    /// [`TickGuard`] is dropped and before [`publish`](Self::publish).
+    /// Returns `None` if no tick is in progress or the field is unknown.
+    /// A field whose storage the tick did not replace (static, or sparse
+    /// and unwritten) yields the same slice twice.
+    pub fn staged_and_published(&self, field: FieldId) -> Option<(&[Scalar], &[Scalar])> {
+        if !self.tick_in_progress {
+            return None;
+        }
+        let (staging_segments, published_segments) = if self.b_is_staging {
+            (&self.buffer_b, &self.buffer_a)
+        } else {
+            (&self.buffer_a, &self.buffer_b)
+        };
+        let generation = WorldGenerationId(self.generation as u64);
+        let staged = Snapshot::new(
+            staging_segments,
+            &self.sparse_segments,
+            &self.static_arena,
+            &self.staging_descriptor,
+            self.last_tick_id,
+            generation,
+            self.last_param_version,
+        )
+        .resolve_field(field)?;
+        let published = Snapshot::new(
+            published_segments,
+            &self.sparse_segments,
+            &self.static_arena,
+            &self.published_descriptor,
+            self.last_tick_id,
+            generation,
+            self.last_param_version,
+        )
+        .resolve_field(field)?;
+        Some((staged, published))
     }
 
     /// Get an owned, thread-safe snapshot of the published generation.
@@ -392,6 +461,9 @@ impl PingPongArena {
     /// Unlike [`PingPongArena::snapshot()`], the returned `OwnedSnapshot` owns
     /// clones of the segment data and can be sent across thread boundaries.
     /// Used by RealtimeAsync mode to populate the snapshot ring buffer.
+    ///
+    /// Only the allocated part of each segment is copied, in parallel on
+    /// the rayon pool with the `rayon` feature.
     pub fn owned_snapshot(&self) -> OwnedSnapshot {
         let published_segments = if self.b_is_staging {
             &self.buffer_a
@@ -399,14 +471,43 @@ impl PingPongArena {
             &self.buffer_b
         };
         OwnedSnapshot::new(
-            published_segments.clone(),
-            self.sparse_segments.clone(),
+            published_segments.clone_allocated(),
+            self.sparse_segments.clone_allocated(),
             Arc::clone(&self.static_arena),
             self.published_descriptor.clone(),
             self.last_tick_id,
             WorldGenerationId(self.generation as u64),
             self.last_param_version,
         )
+        .with_parameters(Arc::clone(&self.last_parameters))
+    }
+
+    /// Mutable access to a field of the generation-0 published buffer.
+    ///
+    /// Used to seed initial field state before the first tick. Returns
+    /// `None` once a tick has begun or been published, for `Static` fields
+    /// (initialise those through the [`StaticArena`](crate::StaticArena)
+    /// before sharing it), or for unknown fields.
+    pub fn write_initial(&mut self, field: FieldId) -> Option<&mut [Scalar]> {
+        if self.generation != 0 || self.tick_in_progress {
+            return None;
+        }
+        let handle = self.published_descriptor.get(field)?.handle;
+        match handle.location() {
+            FieldLocation::PerTick { segment_index } => {
+                let published = if self.b_is_staging {
+                    &mut self.buffer_a
+                } else {
+                    &mut self.buffer_b
+                };
+                published.slice_mut(segment_index, handle.offset, handle.len())
+            }
+            FieldLocation::Sparse { segment_index } => {
+                self.sparse_segments
+                    .slice_mut(segment_index, handle.offset, handle.len())
+            }
+            FieldLocation::Static { .. } => None,
+        }
     }
 
     /// Cancel the current tick without publishing.
@@ -496,6 +597,7 @@ impl PingPongArena {
         self.b_is_staging = false;
         self.last_tick_id = TickId(0);
         self.last_param_version = ParameterVersion(0);
+        self.last_parameters = Arc::new(ParameterStore::new());
         Ok(())
     }
 
@@ -552,6 +654,7 @@ impl PingPongArena {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::SparseRetirementPolicy;
     use crate::static_arena::StaticArena;
     use murk_core::traits::{FieldReader, FieldWriter, SnapshotAccess};
     use murk_core::{BoundaryBehavior, FieldType};
@@ -621,7 +724,7 @@ mod tests {
         // Fill terrain with recognisable data.
         if let Some(data) = static_arena.write_field(FieldId(2)) {
             for (i, v) in data.iter_mut().enumerate() {
-                *v = i as f32;
+                *v = i as Scalar;
             }
         }
         let shared_static = static_arena.into_shared();
@@ -692,6 +795,44 @@ mod tests {
         assert_eq!(terrain[99], 99.0);
     }
 
+    #[test]
+    fn tick_writer_refuses_static_field() {
+        let mut arena = make_arena();
+        {
+            let mut guard = arena.begin_tick().unwrap();
+            assert!(guard.writer.write(FieldId(2)).is_none());
+        }
+        arena.publish(TickId(1), ParameterVersion(0)).unwrap();
+
+        let terrain = arena.snapshot().read_field(FieldId(2)).unwrap().to_vec();
+        assert_eq!(terrain[50], 50.0);
+    }
+
+    #[test]
+    fn staged_and_published_pairs_adjacent_generations() {
+        let mut arena = make_arena();
+        {
+            let mut guard = arena.begin_tick().unwrap();
+            guard.writer.write(FieldId(0)).unwrap()[0] = 1.0;
+        }
+        arena.publish(TickId(1), ParameterVersion(0)).unwrap();
+        assert!(arena.staged_and_published(FieldId(0)).is_none());
+
+        {
+            let mut guard = arena.begin_tick().unwrap();
+            guard.writer.write(FieldId(0)).unwrap()[0] = 2.0;
+        }
+        let (staged, published) = arena.staged_and_published(FieldId(0)).unwrap();
+        assert_eq!((staged[0], published[0]), (2.0, 1.0));
+
+        // Unreplaced storage is shared between the two generations.
+        for field in [FieldId(2), FieldId(3)] {
+            let (staged, published) = arena.staged_and_published(field).unwrap();
+            assert!(std::ptr::eq(staged, published));
+        }
+        assert!(arena.staged_and_published(FieldId(9)).is_none());
+    }
+
     #[test]
     fn snapshot_metadata_matches_publish_args() {
         let mut arena = make_arena();
@@ -771,7 +912,7 @@ mod tests {
             {
                 let mut guard = arena.begin_tick().unwrap();
                 let data = guard.writer.write(FieldId(0)).unwrap();
-                data[0] = i as f32;
+                data[0] = i as Scalar;
             }
             arena.publish(TickId(i), ParameterVersion(0)).unwrap();
         }
@@ -781,6 +922,27 @@ mod tests {
         assert_eq!(arena.generation(), 0);
     }
 
+    #[test]
+    fn write_initial_seeds_generation_zero() {
+        let mut arena = make_arena();
+        arena.write_initial(FieldId(0)).unwrap()[3] = 7.0;
+        arena.write_initial(FieldId(3)).unwrap()[1] = 2.0;
+        assert!(arena.write_initial(FieldId(2)).is_none(), "static field");
+
+        let snap = arena.snapshot();
+        assert_eq!(snap.read(FieldId(0)).unwrap()[3], 7.0);
+        assert_eq!(snap.read(FieldId(3)).unwrap()[1], 2.0);
+
+        {
+            let _guard = arena.begin_tick().unwrap();
+        }
+        arena.publish(TickId(1), ParameterVersion(0)).unwrap();
+        assert!(arena.write_initial(FieldId(0)).is_none());
+
+        arena.reset().unwrap();
+        assert!(arena.write_initial(FieldId(0)).is_some());
+    }
+
     #[test]
     fn memory_bytes_is_positive() {
         let arena = make_arena();
@@ -795,12 +957,12 @@ mod tests {
             {
                 let mut guard = arena.begin_tick().unwrap();
                 let data = guard.writer.write(FieldId(0)).unwrap();
-                data[0] = tick as f32;
+                data[0] = tick as Scalar;
             }
             arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
 
             let snap = arena.snapshot();
-            assert_eq!(snap.read(FieldId(0)).unwrap()[0], tick as f32);
+            assert_eq!(snap.read(FieldId(0)).unwrap()[0], tick as Scalar);
             assert_eq!(snap.tick_id(), TickId(tick));
         }
     }
@@ -828,6 +990,47 @@ mod tests {
         assert_eq!(data[0], 77.0);
     }
 
+    #[test]
+    #[cfg(all(feature = "mmap", unix))]
+    #[allow(unsafe_code)]
+    fn worlds_share_one_mapped_static_field() {
+        let values: Vec<Scalar> = (0..100).map(|i| 100.0 - i as Scalar).collect();
+        let path = std::env::temp_dir().join(format!(
+            "murk-arena-{}-shared-terrain.bin",
+            std::process::id()
+        ));
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        std::fs::write(&path, bytes).unwrap();
+        // SAFETY: the file is private to this test and never written again.
+        let shared = unsafe { StaticArena::from_mmap(FieldId(2), &path, 100) }
+            .unwrap()
+            .into_shared();
+        std::fs::remove_file(&path).unwrap();
+
+        let mapped_ptr = shared.read_field(FieldId(2)).unwrap().as_ptr();
+        let mut worlds: Vec<PingPongArena> = (0..3)
+            .map(|_| {
+                PingPongArena::new(
+                    ArenaConfig::new(100),
+                    make_field_defs(),
+                    Arc::clone(&shared),
+                )
+                .unwrap()
+            })
+            .collect();
+        for arena in &mut worlds {
+            {
+                let _guard = arena.begin_tick().unwrap();
+            }
+            arena.publish(TickId(1), ParameterVersion(0)).unwrap();
+            let snap = arena.snapshot();
+            let terrain = snap.read_field(FieldId(2)).unwrap();
+            assert_eq!(terrain, &values[..]);
+            assert_eq!(terrain.as_ptr(), mapped_ptr, "static field was copied");
+        }
+        assert_eq!(Arc::strong_count(&shared), 4);
+    }
+
     #[test]
     fn new_fails_when_static_field_missing_from_static_arena() {
         let cell_count = 100u32;
@@ -861,6 +1064,7 @@ mod tests {
             max_segments: 16,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let field_defs = vec![(
             FieldId(0),
@@ -900,6 +1104,7 @@ mod tests {
                 max_segments: bad_max,
                 max_generation_age: 1,
                 cell_count,
+                sparse_retirement: SparseRetirementPolicy::Eager,
             };
             let result = PingPongArena::new(config, field_defs.clone(), static_arena.clone());
             assert!(
@@ -929,6 +1134,7 @@ mod tests {
             max_segments: 3,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         assert!(PingPongArena::new(config, field_defs, static_arena).is_ok());
     }
@@ -1000,6 +1206,7 @@ mod tests {
             max_segments: 6,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let field_defs = vec![(
             FieldId(0),
@@ -1019,7 +1226,7 @@ mod tests {
         // Each pool starts with 1 segment, so 3 segments total initially.
         // Maximum: per_tick_a(2) + per_tick_b(2) + sparse(2) = 6 = max_segments.
         let total_bytes = arena.memory_bytes();
-        let max_allowed = 6 * 1024 * std::mem::size_of::<f32>();
+        let max_allowed = 6 * 1024 * std::mem::size_of::<Scalar>();
         // memory_bytes includes static + scratch; just verify it's bounded.
         assert!(total_bytes <= max_allowed + arena.static_arena().memory_bytes() + 1024 * 4);
     }
@@ -1068,6 +1275,7 @@ mod tests {
             max_segments: 16,
             max_generation_age: 1,
             cell_count: 10,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let static_arena = StaticArena::new(&[]).into_shared();
         let result = PingPongArena::new(config, vec![], static_arena);
@@ -1084,6 +1292,7 @@ mod tests {
             max_segments: 16,
             max_generation_age: 1,
             cell_count: 10,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let static_arena = StaticArena::new(&[]).into_shared();
         let result = PingPongArena::new(config, vec![], static_arena);
@@ -1100,6 +1309,7 @@ mod tests {
             max_segments: 16,
             max_generation_age: 1,
             cell_count: 10,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let static_arena = StaticArena::new(&[]).into_shared();
         assert!(PingPongArena::new(config, vec![], static_arena).is_ok());
@@ -1139,6 +1349,7 @@ mod tests {
             max_segments: 6,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let field_defs = vec![(
             FieldId(0),
@@ -1160,13 +1371,13 @@ mod tests {
             {
                 let mut guard = arena.begin_tick().unwrap();
                 let data = guard.writer.write(FieldId(0)).unwrap();
-                data[0] = tick as f32;
+                data[0] = tick as Scalar;
             }
             arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
 
             // Published data must be correct.
             let snap = arena.snapshot();
-            assert_eq!(snap.read(FieldId(0)).unwrap()[0], tick as f32);
+            assert_eq!(snap.read(FieldId(0)).unwrap()[0], tick as Scalar);
         }
     }
 
@@ -1182,6 +1393,7 @@ mod tests {
             max_segments: 6,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let field_defs = vec![(
             FieldId(0),
@@ -1202,7 +1414,7 @@ mod tests {
             {
                 let mut guard = arena.begin_tick().unwrap();
                 let data = guard.writer.write(FieldId(0)).unwrap();
-                data[0] = tick as f32;
+                data[0] = tick as Scalar;
             }
             arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
         }
@@ -1217,12 +1429,73 @@ mod tests {
             {
                 let mut guard = arena.begin_tick().unwrap();
                 let data = guard.writer.write(FieldId(0)).unwrap();
-                data[0] = (tick + 1000) as f32;
+                data[0] = (tick + 1000) as Scalar;
             }
             arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
 
             let snap = arena.snapshot();
-            assert_eq!(snap.read(FieldId(0)).unwrap()[0], (tick + 1000) as f32);
+            assert_eq!(snap.read(FieldId(0)).unwrap()[0], (tick + 1000) as Scalar);
+        }
+    }
+
+    #[test]
+    fn owned_snapshot_matches_published_data() {
+        // Two PerTick fields of 100K cells in 128K-element segments: each
+        // copy covers only part of a segment, and spans two chunks with the
+        // `rayon` feature.
+        let cell_count = 100_000u32;
+        let make = || {
+            let config = ArenaConfig {
+                segment_size: 1 << 17,
+                max_segments: 9,
+                ..ArenaConfig::new(cell_count)
+            };
+            let field_defs = [
+                FieldMutability::PerTick,
+                FieldMutability::PerTick,
+                FieldMutability::Sparse,
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(i, mutability)| {
+                let def = FieldDef {
+                    name: format!("f{i}"),
+                    field_type: FieldType::Scalar,
+                    mutability,
+                    units: None,
+                    bounds: None,
+                    boundary_behavior: BoundaryBehavior::Clamp,
+                };
+                (FieldId(i as u32), def)
+            })
+            .collect();
+            let static_arena = StaticArena::new(&[]).into_shared();
+            PingPongArena::new(config, field_defs, static_arena).unwrap()
+        };
+        let mut arena = make();
+
+        for tick in 1u64..=3 {
+            {
+                let mut guard = arena.begin_tick().unwrap();
+                for f in 0..3u32 {
+                    let data = guard.writer.write(FieldId(f)).unwrap();
+                    for (i, v) in data.iter_mut().enumerate() {
+                        *v = (i as u64 * 7 + tick * 13 + f as u64) as Scalar;
+                    }
+                }
+            }
+            arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
+            let actual = arena.owned_snapshot();
+            let expected = arena.snapshot();
+            for f in 0..3u32 {
+                assert_eq!(
+                    actual.read_field(FieldId(f)),
+                    expected.read_field(FieldId(f)),
+                    "tick {tick}, field {f}"
+                );
+            }
+            let last = (99_999 * 7 + tick * 13 + 1) as Scalar;
+            assert_eq!(actual.read_field(FieldId(1)).unwrap()[99_999], last);
         }
     }
 
@@ -1238,6 +1511,7 @@ mod tests {
             max_segments: 6,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let field_defs = vec![(
             FieldId(0),
@@ -1320,6 +1594,7 @@ mod tests {
             max_segments: 6,
             max_generation_age: 1,
             cell_count,
+            sparse_retirement: SparseRetirementPolicy::Eager,
         };
         let field_defs = vec![(
             FieldId(0),
@@ -1368,4 +1643,86 @@ mod tests {
         assert_eq!(arena.sparse_retired_range_count(), 1);
         assert_eq!(arena.sparse_pending_retired_count(), 0);
     }
+
+    /// Pending retired count after each of `ticks` sparse CoW ticks.
+    fn pending_per_tick(policy: SparseRetirementPolicy, ticks: u64) -> Vec<usize> {
+        let config = ArenaConfig {
+            sparse_retirement: policy,
+            segment_size: 1024,
+            max_segments: 6,
+            ..ArenaConfig::new(100)
+        };
+        let field_defs = vec![(
+            FieldId(0),
+            FieldDef {
+                name: "resources".into(),
+                field_type: FieldType::Scalar,
+                mutability: FieldMutability::Sparse,
+                units: None,
+                bounds: None,
+                boundary_behavior: BoundaryBehavior::Clamp,
+            },
+        )];
+        let static_arena = StaticArena::new(&[]).into_shared();
+        let mut arena = PingPongArena::new(config, field_defs, static_arena).unwrap();
+        (1..=ticks)
+            .map(|t| {
+                {
+                    let mut guard = arena.begin_tick().unwrap();
+                    guard.writer.write(FieldId(0)).unwrap()[0] = t as Scalar;
+                }
+                arena.publish(TickId(t), ParameterVersion(0)).unwrap();
+                arena.sparse_pending_retired_count()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn eager_retirement_keeps_pending_at_one_tick() {
+        let pending = pending_per_tick(SparseRetirementPolicy::Eager, 8);
+        assert_eq!(pending, vec![1; 8]);
+    }
+
+    #[test]
+    fn deferred_retirement_flushes_at_bound() {
+        let policy = SparseRetirementPolicy::Deferred { max_pending: 4 };
+        let pending = pending_per_tick(policy, 10);
+        assert_eq!(pending, vec![1, 2, 3, 4, 1, 2, 3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn deferred_retirement_flushes_before_capacity_exceeded() {
+        // One 1024-element sparse segment fits ten 100-cell copies, so
+        // without an on-demand flush the eleventh CoW write would fail.
+        let config = ArenaConfig {
+            sparse_retirement: SparseRetirementPolicy::Deferred {
+                max_pending: usize::MAX,
+            },
+            segment_size: 1024,
+            max_segments: 3,
+            ..ArenaConfig::new(100)
+        };
+        let field_defs = vec![(
+            FieldId(0),
+            FieldDef {
+                name: "resources".into(),
+                field_type: FieldType::Scalar,
+                mutability: FieldMutability::Sparse,
+                units: None,
+                bounds: None,
+                boundary_behavior: BoundaryBehavior::Clamp,
+            },
+        )];
+        let static_arena = StaticArena::new(&[]).into_shared();
+        let mut arena = PingPongArena::new(config, field_defs, static_arena).unwrap();
+        for t in 1..=50u64 {
+            {
+                let mut guard = arena.begin_tick().unwrap();
+                guard.writer.write(FieldId(0)).unwrap()[0] = t as Scalar;
+            }
+            arena.publish(TickId(t), ParameterVersion(0)).unwrap();
+            assert_eq!(arena.snapshot().read(FieldId(0)).unwrap()[0], t as Scalar);
+        }
+        assert!(arena.sparse_reuse_hits() > 0);
+    }
 }
@@ -4,14 +4,53 @@
 //! implements both [`FieldReader`] and [`SnapshotAccess`]. It is the primary
 //! interface for observation extraction.
 
-use murk_core::id::{FieldId, ParameterVersion, TickId, WorldGenerationId};
+use std::sync::Arc;
+
+use murk_core::id::{FieldId, ParameterKey, ParameterVersion, TickId, WorldGenerationId};
 use murk_core::traits::{FieldReader, SnapshotAccess};
+use murk_core::{scalar_to_f64, FieldMutability, ParameterStore, Scalar};
 
-use crate::descriptor::FieldDescriptor;
-use crate::handle::FieldLocation;
+use crate::descriptor::{FieldDescriptor, FieldEntry, FieldMeta};
+use crate::error::ArenaError;
+use crate::handle::{FieldHandle, FieldLocation};
 use crate::segment::SegmentList;
 use crate::static_arena::{SharedStaticArena, StaticArena};
 
+/// Location of one field within a buffer produced by
+/// [`Snapshot::export_packed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldLayout {
+    /// The exported field.
+    pub field: FieldId,
+    /// Index of the field's first value in the packed buffer.
+    pub offset: usize,
+    /// Number of `Scalar` values (`cell_count * components`).
+    pub len: usize,
+    /// Values per cell (1 for scalars).
+    pub components: u32,
+}
+
+/// Summary statistics of one field, computed by [`Snapshot::field_stats`].
+///
+/// `NaN` values are skipped by every statistic and counted separately in
+/// `nan_count`. When every value is `NaN` (or the field is empty), `min`,
+/// `max`, and `mean` are `NaN` and `sum` is `0.0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FieldStats {
+    /// Smallest non-`NaN` value.
+    pub min: Scalar,
+    /// Largest non-`NaN` value.
+    pub max: Scalar,
+    /// Mean of the non-`NaN` values.
+    pub mean: Scalar,
+    /// Sum of the non-`NaN` values, accumulated in `f64`.
+    pub sum: f64,
+    /// Number of values that are neither `0.0` nor `NaN`.
+    pub nonzero_count: usize,
+    /// Number of `NaN` values.
+    pub nan_count: usize,
+}
+
 /// A read-only view of a published arena generation.
 ///
 /// Borrows from the published buffer segments, the sparse segments, and
@@ -41,6 +80,8 @@ pub struct Snapshot<'a> {
     world_generation_id: WorldGenerationId,
     /// Parameter version at the time of publication.
     parameter_version: ParameterVersion,
+    /// Global parameters frozen at publication, if attached.
+    parameters: Option<&'a ParameterStore>,
 }
 
 impl<'a> Snapshot<'a> {
@@ -62,11 +103,18 @@ impl<'a> Snapshot<'a> {
             tick_id,
             world_generation_id,
             parameter_version,
+            parameters: None,
         }
     }
 
+    /// Attach the global parameters frozen at publication.
+    pub(crate) fn with_parameters(mut self, parameters: &'a ParameterStore) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
     /// Resolve a field to its data slice by dispatching on the field's location.
-    fn resolve_field(&self, field: FieldId) -> Option<&'a [f32]> {
+    pub(crate) fn resolve_field(&self, field: FieldId) -> Option<&'a [Scalar]> {
         let entry = self.descriptor.get(field)?;
         let handle = &entry.handle;
 
@@ -82,16 +130,195 @@ impl<'a> Snapshot<'a> {
             FieldLocation::Static { .. } => self.static_arena.read_field(field),
         }
     }
+
+    /// Iterate over the non-zero entries of a field as `(index, value)`.
+    ///
+    /// `index` is the flat buffer index (the cell rank for scalar fields).
+    /// Returns `None` if the field is unknown.
+    ///
+    /// This is a linear scan of the field's dense buffer: arena storage,
+    /// including the [`SparseSlab`](crate::sparse::SparseSlab), holds whole
+    /// fields rather than individual cells, so there is no per-cell index
+    /// to walk. It saves consumers of mostly-empty fields (agent presence,
+    /// resources) their per-cell work on zero cells, not the scan itself.
+    pub fn iter_nonzero(
+        &self,
+        field: FieldId,
+    ) -> Option<impl Iterator<Item = (usize, Scalar)> + 'a> {
+        self.resolve_field(field).map(nonzero_entries)
+    }
+
+    /// Copy `fields` into one contiguous buffer, in request order.
+    ///
+    /// Each field's values keep their canonical cell order; the returned
+    /// [`FieldLayout`]s locate each field in the buffer, for example to
+    /// upload every field of a tick to the GPU in one transfer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaError::UnknownField`] for the first field that is
+    /// not registered in this snapshot.
+    pub fn export_packed(
+        &self,
+        fields: &[FieldId],
+    ) -> Result<(Vec<Scalar>, Vec<FieldLayout>), ArenaError> {
+        pack_fields(self.descriptor, fields, |f| self.resolve_field(f))
+    }
+
+    /// Whether `other` matches this snapshot to within `epsilon`.
+    ///
+    /// Tick, generation, and parameter version must be equal, and every
+    /// field registered in this snapshot must be present in `other` with the
+    /// same length and each element within `epsilon` (identical bit
+    /// patterns, such as matching NaNs, also compare equal). Unlike
+    /// `snapshot_hash`, which is exact, this tolerates rounding differences.
+    pub fn approx_eq(&self, other: &dyn SnapshotAccess, epsilon: Scalar) -> bool {
+        snapshots_approx_eq(self, self.descriptor, other, epsilon)
+    }
+
+    /// Min, max, mean, sum, and non-zero count of a field in one pass.
+    ///
+    /// Vector fields are summarised over all components. Returns `None`
+    /// if the field is unknown.
+    pub fn field_stats(&self, field: FieldId) -> Option<FieldStats> {
+        self.resolve_field(field).map(compute_field_stats)
+    }
+
+    /// Copy one component of a field out of its interleaved buffer.
+    ///
+    /// Returns one value per cell in canonical order, e.g. component 0 of
+    /// a 2-component velocity field is its x-velocity. Component 0 of a
+    /// scalar field is the field itself. Returns `None` if the field is
+    /// unknown or `component` is not below its component count.
+    pub fn read_field_component(&self, field: FieldId, component: usize) -> Option<Vec<Scalar>> {
+        let components = self.descriptor.get(field)?.meta.components as usize;
+        component_values(self.resolve_field(field)?, components, component)
+    }
+}
+
+/// `(index, value)` pairs of `data` whose value is not `0.0`.
+fn nonzero_entries(data: &[Scalar]) -> impl Iterator<Item = (usize, Scalar)> + '_ {
+    data.iter()
+        .enumerate()
+        .filter(|&(_, &v)| v != 0.0)
+        .map(|(i, &v)| (i, v))
+}
+
+/// Shared body of [`Snapshot::read_field_component`] and
+/// [`OwnedSnapshot::read_field_component`].
+fn component_values(data: &[Scalar], components: usize, component: usize) -> Option<Vec<Scalar>> {
+    if component >= components {
+        return None;
+    }
+    Some(
+        data.iter()
+            .skip(component)
+            .step_by(components)
+            .copied()
+            .collect(),
+    )
+}
+
+/// Shared body of [`Snapshot::field_stats`] and [`OwnedSnapshot::field_stats`].
+fn compute_field_stats(data: &[Scalar]) -> FieldStats {
+    let mut min = Scalar::INFINITY;
+    let mut max = Scalar::NEG_INFINITY;
+    let mut sum = 0.0f64;
+    let mut counted = 0usize;
+    let mut nonzero_count = 0usize;
+    let mut nan_count = 0usize;
+    for &v in data {
+        if v.is_nan() {
+            nan_count += 1;
+            continue;
+        }
+        min = min.min(v);
+        max = max.max(v);
+        sum += scalar_to_f64(v);
+        counted += 1;
+        if v != 0.0 {
+            nonzero_count += 1;
+        }
+    }
+    if counted == 0 {
+        min = Scalar::NAN;
+        max = Scalar::NAN;
+    }
+    FieldStats {
+        min,
+        max,
+        mean: (sum / counted as f64) as Scalar,
+        sum,
+        nonzero_count,
+        nan_count,
+    }
+}
+
+/// Shared body of [`Snapshot::approx_eq`] and [`OwnedSnapshot::approx_eq`].
+fn snapshots_approx_eq(
+    this: &dyn SnapshotAccess,
+    descriptor: &FieldDescriptor,
+    other: &dyn SnapshotAccess,
+    epsilon: Scalar,
+) -> bool {
+    if this.tick_id() != other.tick_id()
+        || this.world_generation_id() != other.world_generation_id()
+        || this.parameter_version() != other.parameter_version()
+    {
+        return false;
+    }
+    descriptor.iter().all(
+        |(&field, _)| match (this.read_field(field), other.read_field(field)) {
+            (Some(a), Some(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b)
+                        .all(|(&x, &y)| x.to_bits() == y.to_bits() || (x - y).abs() <= epsilon)
+            }
+            _ => false,
+        },
+    )
+}
+
+/// Concatenate the resolved data of `fields` and describe each one's span.
+fn pack_fields<'s>(
+    descriptor: &FieldDescriptor,
+    fields: &[FieldId],
+    resolve: impl Fn(FieldId) -> Option<&'s [Scalar]>,
+) -> Result<(Vec<Scalar>, Vec<FieldLayout>), ArenaError> {
+    let mut slices = Vec::with_capacity(fields.len());
+    for &field in fields {
+        let components = descriptor
+            .get(field)
+            .map(|entry| entry.meta.components)
+            .ok_or(ArenaError::UnknownField { field })?;
+        let data = resolve(field).ok_or(ArenaError::UnknownField { field })?;
+        slices.push((field, components, data));
+    }
+
+    let total = slices.iter().map(|(_, _, data)| data.len()).sum();
+    let mut packed = Vec::with_capacity(total);
+    let mut layouts = Vec::with_capacity(slices.len());
+    for (field, components, data) in slices {
+        layouts.push(FieldLayout {
+            field,
+            offset: packed.len(),
+            len: data.len(),
+            components,
+        });
+        packed.extend_from_slice(data);
+    }
+    Ok((packed, layouts))
 }
 
 impl FieldReader for Snapshot<'_> {
-    fn read(&self, field: FieldId) -> Option<&[f32]> {
+    fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         self.resolve_field(field)
     }
 }
 
 impl SnapshotAccess for Snapshot<'_> {
-    fn read_field(&self, field: FieldId) -> Option<&[f32]> {
+    fn read_field(&self, field: FieldId) -> Option<&[Scalar]> {
         self.resolve_field(field)
     }
 
@@ -106,6 +333,10 @@ impl SnapshotAccess for Snapshot<'_> {
     fn parameter_version(&self) -> ParameterVersion {
         self.parameter_version
     }
+
+    fn parameter(&self, key: ParameterKey) -> Option<f32> {
+        self.parameters?.get(key).map(|v| v as f32)
+    }
 }
 
 /// An owned, thread-safe snapshot of a published arena generation.
@@ -132,6 +363,8 @@ pub struct OwnedSnapshot {
     world_generation_id: WorldGenerationId,
     /// Parameter version at the time of publication.
     parameter_version: ParameterVersion,
+    /// Global parameters frozen at publication, if attached.
+    parameters: Option<Arc<ParameterStore>>,
 }
 
 // Compile-time assertion: OwnedSnapshot must be Send + Sync.
@@ -159,11 +392,87 @@ impl OwnedSnapshot {
             tick_id,
             world_generation_id,
             parameter_version,
+            parameters: None,
+        }
+    }
+
+    /// Attach the global parameters frozen at publication.
+    pub(crate) fn with_parameters(mut self, parameters: Arc<ParameterStore>) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+
+    /// Build a snapshot directly from raw field buffers, without a world.
+    ///
+    /// Intended for tests and for loading external initial states. Each
+    /// buffer is copied as-is and read back unchanged; fields are described
+    /// as single-component (`components == 1`) with one value per element.
+    /// The result is the same type the engine publishes, so observation
+    /// plans, hashing, and anything else taking a [`SnapshotAccess`] accept
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaError::InvalidConfig`] if a field appears twice or a
+    /// buffer holds more than `u32::MAX` values.
+    pub fn from_fields(
+        fields: impl IntoIterator<Item = (FieldId, Vec<Scalar>)>,
+        tick_id: TickId,
+        world_generation_id: WorldGenerationId,
+        parameter_version: ParameterVersion,
+    ) -> Result<Self, ArenaError> {
+        let mut fields: Vec<(FieldId, Vec<Scalar>)> = fields.into_iter().collect();
+        fields.sort_unstable_by_key(|&(id, _)| id);
+        if let Some(w) = fields.windows(2).find(|w| w[0].0 == w[1].0) {
+            return Err(ArenaError::InvalidConfig {
+                reason: format!("duplicate field {}", w[0].0),
+            });
+        }
+
+        let mut lens = Vec::with_capacity(fields.len());
+        for (id, data) in &fields {
+            let len = u32::try_from(data.len()).map_err(|_| ArenaError::InvalidConfig {
+                reason: format!("field {id} has {} values, more than u32::MAX", data.len()),
+            })?;
+            lens.push((*id, len));
+        }
+
+        let mut static_arena = StaticArena::new(&lens);
+        let mut entries = Vec::with_capacity(fields.len());
+        for (id, data) in &fields {
+            static_arena
+                .write_field(*id)
+                .expect("field was registered above")
+                .copy_from_slice(data);
+            let (offset, len) = static_arena
+                .field_location(*id)
+                .expect("field was registered above");
+            let entry = FieldEntry {
+                handle: FieldHandle::new(0, offset, len, FieldLocation::Static { offset, len }),
+                meta: FieldMeta {
+                    components: 1,
+                    mutability: FieldMutability::Static,
+                    total_len: len,
+                    name: Arc::from(format!("field_{}", id.0)),
+                },
+            };
+            entries.push((*id, entry));
         }
+        let descriptor = FieldDescriptor::from_entries(entries);
+
+        Ok(Self::new(
+            SegmentList::new(1, 1),
+            SegmentList::new(1, 1),
+            static_arena.into_shared(),
+            descriptor,
+            tick_id,
+            world_generation_id,
+            parameter_version,
+        ))
     }
 
     /// Resolve a field to its data slice by dispatching on the field's location.
-    fn resolve_field(&self, field: FieldId) -> Option<&[f32]> {
+    fn resolve_field(&self, field: FieldId) -> Option<&[Scalar]> {
         let entry = self.descriptor.get(field)?;
         let handle = &entry.handle;
 
@@ -179,16 +488,62 @@ impl OwnedSnapshot {
             FieldLocation::Static { .. } => self.static_arena.read_field(field),
         }
     }
+
+    /// Iterate over the non-zero entries of a field as `(index, value)`.
+    ///
+    /// See [`Snapshot::iter_nonzero`].
+    pub fn iter_nonzero(
+        &self,
+        field: FieldId,
+    ) -> Option<impl Iterator<Item = (usize, Scalar)> + '_> {
+        self.resolve_field(field).map(nonzero_entries)
+    }
+
+    /// Copy `fields` into one contiguous buffer, in request order.
+    ///
+    /// See [`Snapshot::export_packed`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArenaError::UnknownField`] for the first unknown field.
+    pub fn export_packed(
+        &self,
+        fields: &[FieldId],
+    ) -> Result<(Vec<Scalar>, Vec<FieldLayout>), ArenaError> {
+        pack_fields(&self.descriptor, fields, |f| self.resolve_field(f))
+    }
+
+    /// Whether `other` matches this snapshot to within `epsilon`.
+    ///
+    /// See [`Snapshot::approx_eq`].
+    pub fn approx_eq(&self, other: &dyn SnapshotAccess, epsilon: Scalar) -> bool {
+        snapshots_approx_eq(self, &self.descriptor, other, epsilon)
+    }
+
+    /// Min, max, mean, sum, and non-zero count of a field in one pass.
+    ///
+    /// See [`Snapshot::field_stats`].
+    pub fn field_stats(&self, field: FieldId) -> Option<FieldStats> {
+        self.resolve_field(field).map(compute_field_stats)
+    }
+
+    /// Copy one component of a field out of its interleaved buffer.
+    ///
+    /// See [`Snapshot::read_field_component`].
+    pub fn read_field_component(&self, field: FieldId, component: usize) -> Option<Vec<Scalar>> {
+        let components = self.descriptor.get(field)?.meta.components as usize;
+        component_values(self.resolve_field(field)?, components, component)
+    }
 }
 
 impl FieldReader for OwnedSnapshot {
-    fn read(&self, field: FieldId) -> Option<&[f32]> {
+    fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         self.resolve_field(field)
     }
 }
 
 impl SnapshotAccess for OwnedSnapshot {
-    fn read_field(&self, field: FieldId) -> Option<&[f32]> {
+    fn read_field(&self, field: FieldId) -> Option<&[Scalar]> {
         self.resolve_field(field)
     }
 
@@ -203,6 +558,10 @@ impl SnapshotAccess for OwnedSnapshot {
     fn parameter_version(&self) -> ParameterVersion {
         self.parameter_version
     }
+
+    fn parameter(&self, key: ParameterKey) -> Option<f32> {
+        self.parameters.as_deref()?.get(key).map(|v| v as f32)
+    }
 }
 
 #[cfg(test)]
@@ -341,6 +700,50 @@ mod tests {
         assert_eq!(snap.parameter_version(), ParameterVersion(3));
     }
 
+    #[test]
+    fn iter_nonzero_yields_only_populated_cells() {
+        let (mut per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        per_tick.slice_mut(0, 0, 10).unwrap()[4] = -2.5;
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+
+        let nz: Vec<_> = snap.iter_nonzero(FieldId(0)).unwrap().collect();
+        assert_eq!(nz, vec![(0, 1.0), (4, -2.5), (9, 10.0)]);
+        assert!(snap.iter_nonzero(FieldId(99)).is_none());
+    }
+
+    #[test]
+    fn iter_nonzero_dense_field_yields_every_cell() {
+        let (mut per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        let data = per_tick.slice_mut(0, 0, 10).unwrap();
+        for (i, v) in data.iter_mut().enumerate() {
+            *v = i as Scalar + 1.0;
+        }
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+
+        let indices: Vec<usize> = snap
+            .iter_nonzero(FieldId(0))
+            .unwrap()
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(indices, (0..10).collect::<Vec<_>>());
+    }
+
     #[test]
     fn unknown_field_returns_none() {
         let (per_tick, sparse, static_arena, desc) = make_test_snapshot();
@@ -357,6 +760,187 @@ mod tests {
         assert!(snap.read(FieldId(99)).is_none());
     }
 
+    #[test]
+    fn export_packed_concatenates_in_request_order() {
+        let (per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+
+        let (packed, layouts) = snap.export_packed(&[FieldId(1), FieldId(0)]).unwrap();
+        assert_eq!(packed.len(), 20);
+        assert_eq!(
+            layouts,
+            vec![
+                FieldLayout {
+                    field: FieldId(1),
+                    offset: 0,
+                    len: 10,
+                    components: 1,
+                },
+                FieldLayout {
+                    field: FieldId(0),
+                    offset: 10,
+                    len: 10,
+                    components: 1,
+                },
+            ]
+        );
+        for layout in &layouts {
+            let span = &packed[layout.offset..layout.offset + layout.len];
+            assert_eq!(span, snap.read(layout.field).unwrap());
+        }
+        assert_eq!(packed[0], 100.0);
+        assert_eq!(packed[10], 1.0);
+        assert_eq!(packed[19], 10.0);
+    }
+
+    #[test]
+    fn export_packed_unknown_field_errors() {
+        let (per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+        assert_eq!(
+            snap.export_packed(&[FieldId(0), FieldId(99)]),
+            Err(ArenaError::UnknownField { field: FieldId(99) })
+        );
+        assert_eq!(snap.export_packed(&[]).unwrap(), (vec![], vec![]));
+    }
+
+    #[test]
+    fn approx_eq_tolerates_epsilon_only() {
+        fn at<'a>(
+            pt: &'a SegmentList,
+            rest: &'a (SegmentList, StaticArena, FieldDescriptor),
+            tick: u64,
+        ) -> Snapshot<'a> {
+            Snapshot::new(
+                pt,
+                &rest.0,
+                &rest.1,
+                &rest.2,
+                TickId(tick),
+                WorldGenerationId(1),
+                ParameterVersion(0),
+            )
+        }
+        let (per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        let rest = (sparse, static_arena, desc);
+        let a = at(&per_tick, &rest, 1);
+
+        // Identical data.
+        let same = per_tick.clone();
+        assert!(a.approx_eq(&at(&same, &rest, 1), 0.0));
+
+        // Perturbation within epsilon.
+        let mut nudged = per_tick.clone();
+        nudged.slice_mut(0, 0, 10).unwrap()[9] += 1e-4;
+        assert!(a.approx_eq(&at(&nudged, &rest, 1), 1e-3));
+        assert!(!a.approx_eq(&at(&nudged, &rest, 1), 0.0));
+
+        // Difference larger than epsilon.
+        let mut moved = per_tick.clone();
+        moved.slice_mut(0, 0, 10).unwrap()[3] = 0.5;
+        assert!(!a.approx_eq(&at(&moved, &rest, 1), 1e-3));
+
+        // Metadata must match exactly.
+        assert!(!a.approx_eq(&at(&same, &rest, 2), 1.0));
+    }
+
+    /// A 3-cell snapshot holding a 2-component per-tick field 0 with
+    /// `(x, y) = (i, 10 * i)` at cell `i`.
+    fn vector_snapshot_parts() -> (SegmentList, SegmentList, StaticArena, FieldDescriptor) {
+        let defs = vec![(
+            FieldId(0),
+            FieldDef {
+                name: "velocity".into(),
+                field_type: FieldType::Vector { dims: 2 },
+                mutability: FieldMutability::PerTick,
+                units: None,
+                bounds: None,
+                boundary_behavior: BoundaryBehavior::Clamp,
+            },
+        )];
+        let mut desc = FieldDescriptor::from_field_defs(&defs, 3).unwrap();
+        let mut per_tick = SegmentList::new(4096, 4);
+        let (seg_idx, offset) = per_tick.alloc(6).unwrap();
+        per_tick
+            .slice_mut(seg_idx, offset, 6)
+            .unwrap()
+            .copy_from_slice(&[0.0, 0.0, 1.0, 10.0, 2.0, 20.0]);
+        desc.update_handle(
+            FieldId(0),
+            FieldHandle::new(
+                1,
+                offset,
+                6,
+                FieldLocation::PerTick {
+                    segment_index: seg_idx,
+                },
+            ),
+        );
+        (
+            per_tick,
+            SegmentList::new(4096, 4),
+            StaticArena::new(&[]),
+            desc,
+        )
+    }
+
+    #[test]
+    fn read_field_component_deinterleaves_vector_field() {
+        let (per_tick, sparse, static_arena, desc) = vector_snapshot_parts();
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+        assert_eq!(
+            snap.read_field_component(FieldId(0), 0),
+            Some(vec![0.0, 1.0, 2.0])
+        );
+        assert_eq!(
+            snap.read_field_component(FieldId(0), 1),
+            Some(vec![0.0, 10.0, 20.0])
+        );
+        assert_eq!(snap.read_field_component(FieldId(0), 2), None);
+        assert_eq!(snap.read_field_component(FieldId(9), 0), None);
+
+        // A scalar field has exactly one component: itself.
+        let (per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+        assert_eq!(
+            snap.read_field_component(FieldId(0), 0).as_deref(),
+            snap.read(FieldId(0))
+        );
+        assert_eq!(snap.read_field_component(FieldId(0), 1), None);
+    }
+
     // ── OwnedSnapshot tests ────────────────────────────────────
 
     use std::sync::Arc;
@@ -408,6 +992,56 @@ mod tests {
         assert_eq!(snap.parameter_version(), ParameterVersion(3));
     }
 
+    #[test]
+    fn test_owned_snapshot_approx_eq() {
+        let a = make_owned_snapshot();
+        assert!(a.approx_eq(&make_owned_snapshot(), 0.0));
+
+        let (mut per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        per_tick.slice_mut(0, 0, 10).unwrap()[0] += 0.25;
+        let b = OwnedSnapshot::new(
+            per_tick,
+            sparse,
+            Arc::new(static_arena),
+            desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+        assert!(a.approx_eq(&b, 0.5));
+        assert!(!a.approx_eq(&b, 0.1));
+        assert!(!b.approx_eq(&a, 0.1));
+    }
+
+    #[test]
+    fn test_owned_snapshot_iter_nonzero() {
+        let snap = make_owned_snapshot();
+        let nz: Vec<_> = snap.iter_nonzero(FieldId(0)).unwrap().collect();
+        assert_eq!(nz, vec![(0, 1.0), (9, 10.0)]);
+        let nz: Vec<_> = snap.iter_nonzero(FieldId(1)).unwrap().collect();
+        assert_eq!(nz, vec![(0, 100.0)]);
+    }
+
+    #[test]
+    fn test_owned_snapshot_export_packed_matches_borrowed() {
+        let owned = make_owned_snapshot();
+        let (per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+        let fields = [FieldId(0), FieldId(1)];
+        assert_eq!(
+            owned.export_packed(&fields).unwrap(),
+            snap.export_packed(&fields).unwrap()
+        );
+    }
+
     #[test]
     fn test_owned_snapshot_unknown_field_none() {
         let snap = make_owned_snapshot();
@@ -435,4 +1069,142 @@ mod tests {
         // OwnedSnapshot should be unaffected.
         assert_eq!(snap.read(FieldId(0)).unwrap()[0], 1.0);
     }
+
+    #[test]
+    fn from_fields_reads_back_buffers() {
+        let fields = [
+            (FieldId(0), vec![1.0, 2.0, 3.0]),
+            (FieldId(2), vec![-4.0; 6]),
+            (FieldId(1), vec![]),
+        ];
+        let snap = OwnedSnapshot::from_fields(
+            fields,
+            TickId(5),
+            WorldGenerationId(2),
+            ParameterVersion(1),
+        )
+        .unwrap();
+
+        assert_eq!(snap.read(FieldId(0)).unwrap(), &[1.0, 2.0, 3.0]);
+        assert_eq!(snap.read(FieldId(1)).unwrap(), &[] as &[Scalar]);
+        assert_eq!(snap.read(FieldId(2)).unwrap(), &[-4.0; 6]);
+        assert!(snap.read(FieldId(3)).is_none());
+        let (packed, layout) = snap.export_packed(&[FieldId(2), FieldId(0)]).unwrap();
+        assert_eq!(packed.len(), 9);
+        assert_eq!(layout[1].offset, 6);
+    }
+
+    #[test]
+    fn from_fields_satisfies_snapshot_access() {
+        let build = || {
+            OwnedSnapshot::from_fields(
+                [(FieldId(0), vec![7.0; 4])],
+                TickId(42),
+                WorldGenerationId(7),
+                ParameterVersion(3),
+            )
+            .unwrap()
+        };
+        let snap = build();
+        let access: &dyn SnapshotAccess = &snap;
+        assert_eq!(access.read_field(FieldId(0)).unwrap(), &[7.0; 4]);
+        assert_eq!(access.tick_id(), TickId(42));
+        assert_eq!(access.world_generation_id(), WorldGenerationId(7));
+        assert_eq!(access.parameter_version(), ParameterVersion(3));
+        assert!(snap.approx_eq(&build(), 0.0));
+    }
+
+    #[test]
+    fn from_fields_rejects_duplicate_field() {
+        let fields = [(FieldId(1), vec![1.0]), (FieldId(1), vec![2.0])];
+        let err = OwnedSnapshot::from_fields(
+            fields,
+            TickId(0),
+            WorldGenerationId(0),
+            ParameterVersion(0),
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(err, ArenaError::InvalidConfig { .. }), "{err:?}");
+    }
+
+    #[test]
+    fn test_owned_snapshot_read_field_component() {
+        let (per_tick, sparse, static_arena, desc) = vector_snapshot_parts();
+        let snap = OwnedSnapshot::new(
+            per_tick,
+            sparse,
+            Arc::new(static_arena),
+            desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+        assert_eq!(
+            snap.read_field_component(FieldId(0), 1),
+            Some(vec![0.0, 10.0, 20.0])
+        );
+        assert_eq!(snap.read_field_component(FieldId(0), 2), None);
+    }
+
+    #[test]
+    fn field_stats_summarises_known_field() {
+        let (mut per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        per_tick.slice_mut(0, 0, 10).unwrap()[4] = -3.0;
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+
+        // temp = [1, 0, 0, 0, -3, 0, 0, 0, 0, 10]
+        let stats = snap.field_stats(FieldId(0)).unwrap();
+        assert_eq!(stats.min, -3.0);
+        assert_eq!(stats.max, 10.0);
+        assert_eq!(stats.sum, 8.0);
+        assert!((stats.mean - 0.8).abs() < 1e-6);
+        assert_eq!(stats.nonzero_count, 3);
+        assert_eq!(stats.nan_count, 0);
+        assert!(snap.field_stats(FieldId(99)).is_none());
+    }
+
+    #[test]
+    fn field_stats_skips_nan_and_counts_zero_fields() {
+        let (mut per_tick, sparse, static_arena, desc) = make_test_snapshot();
+        {
+            let data = per_tick.slice_mut(0, 0, 10).unwrap();
+            data[2] = Scalar::NAN;
+            data[3] = Scalar::NAN;
+        }
+        let snap = Snapshot::new(
+            &per_tick,
+            &sparse,
+            &static_arena,
+            &desc,
+            TickId(1),
+            WorldGenerationId(1),
+            ParameterVersion(0),
+        );
+
+        let stats = snap.field_stats(FieldId(0)).unwrap();
+        assert_eq!(stats.nan_count, 2);
+        assert_eq!((stats.min, stats.max, stats.sum), (0.0, 10.0, 11.0));
+        assert!((stats.mean - 11.0 / 8.0).abs() < 1e-6);
+        assert_eq!(stats.nonzero_count, 2);
+
+        let zeros = compute_field_stats(&[0.0; 6]);
+        assert_eq!(zeros.nonzero_count, 0);
+        assert_eq!(
+            (zeros.min, zeros.max, zeros.mean, zeros.sum),
+            (0.0, 0.0, 0.0, 0.0)
+        );
+
+        let all_nan = compute_field_stats(&[Scalar::NAN; 3]);
+        assert!(all_nan.min.is_nan() && all_nan.max.is_nan() && all_nan.mean.is_nan());
+        assert_eq!((all_nan.sum, all_nan.nan_count), (0.0, 3));
+    }
 }
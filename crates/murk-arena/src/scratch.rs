@@ -1,13 +1,15 @@
 //! Per-tick scratch space for temporary propagator allocations.
 //!
-//! [`ScratchRegion`] is a bump allocator over a `Vec<f32>`. It is reset
+//! [`ScratchRegion`] is a bump allocator over a `Vec<Scalar>`. It is reset
 //! between propagator invocations by the tick engine, so each propagator
 //! sees a fresh, empty scratch region. The backing allocation is reused
 //! across ticks to avoid repeated heap allocation.
 
+use murk_core::Scalar;
+
 /// Bump-allocated scratch space for temporary per-propagator data.
 ///
-/// Propagators can request temporary f32 slices from scratch space for
+/// Propagators can request temporary `Scalar` slices from scratch space for
 /// intermediate calculations (e.g. neighbourhood sums, gradient buffers).
 /// The scratch region is reset between propagator executions within a
 /// single tick — allocations do not persist across propagators or ticks.
@@ -21,13 +23,13 @@
 /// ```
 pub struct ScratchRegion {
     /// Backing storage. Grows on demand, never shrinks during runtime.
-    data: Vec<f32>,
-    /// Current bump pointer (number of f32 elements allocated so far).
+    data: Vec<Scalar>,
+    /// Current bump pointer (number of `Scalar` elements allocated so far).
     cursor: usize,
 }
 
 impl ScratchRegion {
-    /// Create a new scratch region with the given initial capacity (in f32 elements).
+    /// Create a new scratch region with the given initial capacity (in `Scalar` elements).
     pub fn new(initial_capacity: usize) -> Self {
         Self {
             data: vec![0.0; initial_capacity],
@@ -35,13 +37,13 @@ impl ScratchRegion {
         }
     }
 
-    /// Allocate `len` f32 elements from scratch space.
+    /// Allocate `len` `Scalar` elements from scratch space.
     ///
     /// Returns a zero-initialised mutable slice. Returns `None` if the
     /// scratch region cannot grow to accommodate the request (this should
     /// not happen in practice since `Vec` grows on demand; `None` is
     /// returned only if the system is out of memory).
-    pub fn alloc(&mut self, len: usize) -> Option<&mut [f32]> {
+    pub fn alloc(&mut self, len: usize) -> Option<&mut [Scalar]> {
         let new_cursor = self.cursor.checked_add(len)?;
         if new_cursor > self.data.len() {
             // Grow to at least double or the required size, whichever is larger.
@@ -71,19 +73,19 @@ impl ScratchRegion {
         self.cursor = 0;
     }
 
-    /// Number of f32 elements currently allocated.
+    /// Number of `Scalar` elements currently allocated.
     pub fn used(&self) -> usize {
         self.cursor
     }
 
-    /// Total capacity of the backing storage in f32 elements.
+    /// Total capacity of the backing storage in `Scalar` elements.
     pub fn capacity(&self) -> usize {
         self.data.len()
     }
 
     /// Memory usage of the backing storage in bytes.
     pub fn memory_bytes(&self) -> usize {
-        self.data.len() * std::mem::size_of::<f32>()
+        self.data.len() * std::mem::size_of::<Scalar>()
     }
 }
 
@@ -150,7 +152,7 @@ mod tests {
     #[test]
     fn memory_bytes_tracks_capacity() {
         let scratch = ScratchRegion::new(1024);
-        assert_eq!(scratch.memory_bytes(), 1024 * 4);
+        assert_eq!(scratch.memory_bytes(), 1024 * std::mem::size_of::<Scalar>());
     }
 
     #[test]
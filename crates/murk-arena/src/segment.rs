@@ -1,26 +1,48 @@
 //! Contiguous memory segments and growable segment lists.
 //!
-//! A [`Segment`] is a 64MB (default) contiguous `Vec<f32>` with bump allocation.
+//! A [`Segment`] is a 64MB (default) contiguous `Vec<Scalar>` with bump allocation.
 //! A [`SegmentList`] is a growable collection of segments that overflow into
 //! new segments when the current one is full.
 
+use murk_core::Scalar;
+
 use crate::error::ArenaError;
 
+/// Minimum `Scalar` elements per rayon task in a segment copy.
+#[cfg(feature = "rayon")]
+const PARALLEL_COPY_MIN_CHUNK: usize = 65_536;
+
+/// Copy `src` into `dst`.
+///
+/// With the `rayon` feature, copies longer than one chunk are split into
+/// disjoint chunks on the rayon pool; the result is identical either way.
+fn copy_scalars(dst: &mut [Scalar], src: &[Scalar]) {
+    #[cfg(feature = "rayon")]
+    if src.len() > PARALLEL_COPY_MIN_CHUNK {
+        use rayon::prelude::*;
+        dst.par_chunks_mut(PARALLEL_COPY_MIN_CHUNK)
+            .zip(src.par_chunks(PARALLEL_COPY_MIN_CHUNK))
+            .for_each(|(d, s)| d.copy_from_slice(s));
+        return;
+    }
+    dst.copy_from_slice(src);
+}
+
 /// A single contiguous memory segment with bump allocation.
 ///
 /// Segments are the fundamental storage unit of the arena. Each segment is
-/// a pre-allocated `Vec<f32>` with a cursor that advances on each allocation.
+/// a pre-allocated `Vec<Scalar>` with a cursor that advances on each allocation.
 /// Segments are never freed during runtime — only reset or dropped at shutdown.
 #[derive(Clone)]
 pub struct Segment {
     /// Backing storage. Allocated to full capacity at creation.
-    data: Vec<f32>,
-    /// Bump pointer: next free position (in f32 elements).
+    data: Vec<Scalar>,
+    /// Bump pointer: next free position (in `Scalar` elements).
     cursor: usize,
 }
 
 impl Segment {
-    /// Create a new segment with the given capacity (in f32 elements).
+    /// Create a new segment with the given capacity (in `Scalar` elements).
     ///
     /// The segment is zero-initialised (Phase 1 safety guarantee).
     pub fn new(capacity: u32) -> Self {
@@ -30,12 +52,12 @@ impl Segment {
         }
     }
 
-    /// Bump-allocate `len` f32 elements from this segment.
+    /// Bump-allocate `len` `Scalar` elements from this segment.
     ///
-    /// Returns `Some((offset, &mut [f32]))` where `offset` is the starting
+    /// Returns `Some((offset, &mut [Scalar]))` where `offset` is the starting
     /// position within this segment, or `None` if there is insufficient
     /// remaining capacity.
-    pub fn alloc(&mut self, len: u32) -> Option<(u32, &mut [f32])> {
+    pub fn alloc(&mut self, len: u32) -> Option<(u32, &mut [Scalar])> {
         let len = len as usize;
         let new_cursor = self.cursor.checked_add(len)?;
         if new_cursor > self.data.len() {
@@ -52,7 +74,7 @@ impl Segment {
     /// Get a shared slice at the given offset and length.
     ///
     /// Returns `None` if `offset + len` exceeds the segment's allocated region.
-    pub fn slice(&self, offset: u32, len: u32) -> Option<&[f32]> {
+    pub fn slice(&self, offset: u32, len: u32) -> Option<&[Scalar]> {
         let start = offset as usize;
         let end = start.checked_add(len as usize)?;
         if end > self.cursor {
@@ -64,7 +86,7 @@ impl Segment {
     /// Get a mutable slice at the given offset and length.
     ///
     /// Returns `None` if `offset + len` exceeds the segment's allocated region.
-    pub fn slice_mut(&mut self, offset: u32, len: u32) -> Option<&mut [f32]> {
+    pub fn slice_mut(&mut self, offset: u32, len: u32) -> Option<&mut [Scalar]> {
         let start = offset as usize;
         let end = start.checked_add(len as usize)?;
         if end > self.cursor {
@@ -82,24 +104,37 @@ impl Segment {
         self.cursor = 0;
     }
 
-    /// Number of f32 elements currently allocated.
+    /// Number of `Scalar` elements currently allocated.
     pub fn used(&self) -> usize {
         self.cursor
     }
 
-    /// Total capacity in f32 elements.
+    /// Total capacity in `Scalar` elements.
     pub fn capacity(&self) -> usize {
         self.data.len()
     }
 
-    /// Remaining free capacity in f32 elements.
+    /// Remaining free capacity in `Scalar` elements.
     pub fn remaining(&self) -> usize {
         self.data.len() - self.cursor
     }
 
     /// Memory usage of the backing storage in bytes.
     pub fn memory_bytes(&self) -> usize {
-        self.data.len() * std::mem::size_of::<f32>()
+        self.data.len() * std::mem::size_of::<Scalar>()
+    }
+
+    /// Copy of this segment holding only its allocated region.
+    ///
+    /// Unlike [`clone`](Clone::clone), the unallocated tail is left zeroed
+    /// rather than copied; it is never read before `alloc()` zeroes it.
+    fn clone_allocated(&self) -> Self {
+        let mut data = vec![0.0; self.data.len()];
+        copy_scalars(&mut data[..self.cursor], &self.data[..self.cursor]);
+        Self {
+            data,
+            cursor: self.cursor,
+        }
     }
 }
 
@@ -129,7 +164,7 @@ impl SegmentList {
         }
     }
 
-    /// Bump-allocate `len` f32 elements, growing into a new segment if needed.
+    /// Bump-allocate `len` `Scalar` elements, growing into a new segment if needed.
     ///
     /// Returns `Ok((segment_index, offset))` on success, or
     /// `Err(ArenaError::CapacityExceeded)` if `max_segments` would be exceeded.
@@ -137,8 +172,8 @@ impl SegmentList {
         // Reject allocations that can never fit in a single segment.
         if len > self.segment_size {
             return Err(ArenaError::CapacityExceeded {
-                requested: len as usize * std::mem::size_of::<f32>(),
-                capacity: self.segment_size as usize * std::mem::size_of::<f32>(),
+                requested: len as usize * std::mem::size_of::<Scalar>(),
+                capacity: self.segment_size as usize * std::mem::size_of::<Scalar>(),
             });
         }
 
@@ -160,7 +195,7 @@ impl SegmentList {
         // Need a new segment.
         if self.segments.len() >= self.max_segments as usize {
             return Err(ArenaError::CapacityExceeded {
-                requested: len as usize * std::mem::size_of::<f32>(),
+                requested: len as usize * std::mem::size_of::<Scalar>(),
                 capacity: self.total_capacity_bytes(),
             });
         }
@@ -179,7 +214,7 @@ impl SegmentList {
     ///
     /// Returns `None` if `segment_index` is out of bounds or the slice
     /// exceeds the segment's allocated region.
-    pub fn slice(&self, segment_index: u16, offset: u32, len: u32) -> Option<&[f32]> {
+    pub fn slice(&self, segment_index: u16, offset: u32, len: u32) -> Option<&[Scalar]> {
         self.segments
             .get(segment_index as usize)?
             .slice(offset, len)
@@ -189,7 +224,12 @@ impl SegmentList {
     ///
     /// Returns `None` if `segment_index` is out of bounds or the slice
     /// exceeds the segment's allocated region.
-    pub fn slice_mut(&mut self, segment_index: u16, offset: u32, len: u32) -> Option<&mut [f32]> {
+    pub fn slice_mut(
+        &mut self,
+        segment_index: u16,
+        offset: u32,
+        len: u32,
+    ) -> Option<&mut [Scalar]> {
         self.segments
             .get_mut(segment_index as usize)?
             .slice_mut(offset, len)
@@ -215,13 +255,27 @@ impl SegmentList {
         self.segments.iter().map(|s| s.memory_bytes()).sum()
     }
 
-    /// Total used f32 elements across all segments.
+    /// Total used `Scalar` elements across all segments.
     pub fn total_used(&self) -> usize {
         self.segments.iter().map(|s| s.used()).sum()
     }
 
     fn total_capacity_bytes(&self) -> usize {
-        self.segments.len() * self.segment_size as usize * std::mem::size_of::<f32>()
+        self.segments.len() * self.segment_size as usize * std::mem::size_of::<Scalar>()
+    }
+
+    /// Deep copy of the list, like [`clone`](Clone::clone), that copies
+    /// only each segment's allocated data.
+    pub fn clone_allocated(&self) -> Self {
+        Self {
+            segments: self.segments[..=self.current]
+                .iter()
+                .map(Segment::clone_allocated)
+                .collect(),
+            segment_size: self.segment_size,
+            max_segments: self.max_segments,
+            current: self.current,
+        }
     }
 }
 
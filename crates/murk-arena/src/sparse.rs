@@ -32,9 +32,9 @@ pub struct SparseSlot {
     pub generation_created: u32,
     /// Segment index within the sparse segment list.
     pub segment_index: u16,
-    /// Offset within the segment (in f32 elements).
+    /// Offset within the segment (in `Scalar` elements).
     pub offset: u32,
-    /// Length in f32 elements.
+    /// Length in `Scalar` elements.
     pub len: u32,
     /// Whether this slot is currently live (not freed).
     pub live: bool,
@@ -78,6 +78,9 @@ pub struct SparseSlab {
     retired_ranges: Vec<RetiredRange>,
     /// Segment ranges freed during the current tick (not yet safe to reuse).
     pending_retired: Vec<RetiredRange>,
+    /// Number of leading `pending_retired` entries freed before the current
+    /// tick. These are no longer published and may be promoted on demand.
+    flushable_pending: usize,
     /// Number of alloc() calls that successfully reused a retired range.
     reuse_hits: u32,
     /// Number of alloc() calls that fell through to bump allocation.
@@ -93,6 +96,7 @@ impl SparseSlab {
             live_map: indexmap::IndexMap::new(),
             retired_ranges: Vec::new(),
             pending_retired: Vec::new(),
+            flushable_pending: 0,
             reuse_hits: 0,
             reuse_misses: 0,
         }
@@ -112,15 +116,26 @@ impl SparseSlab {
         // Try to reuse a retired segment range of the exact size before
         // bump-allocating. Retired ranges are guaranteed safe (freed in a
         // previous tick, after publish).
-        let (segment_index, offset) =
-            if let Some(pos) = self.retired_ranges.iter().position(|r| r.len == len) {
-                let r = self.retired_ranges.swap_remove(pos);
-                self.reuse_hits = self.reuse_hits.saturating_add(1);
-                (r.segment_index, r.offset)
-            } else {
-                self.reuse_misses = self.reuse_misses.saturating_add(1);
-                segments.alloc(len)?
-            };
+        let (segment_index, offset) = if let Some(r) = self.take_retired(len) {
+            (r.segment_index, r.offset)
+        } else {
+            match segments.alloc(len) {
+                Ok(loc) => {
+                    self.reuse_misses = self.reuse_misses.saturating_add(1);
+                    loc
+                }
+                // Out of segment space: ranges held back by a deferred
+                // retirement policy may still fit, so promote them and retry.
+                Err(e) if self.flushable_pending > 0 => {
+                    let promoted = self.pending_retired.drain(..self.flushable_pending);
+                    self.retired_ranges.extend(promoted);
+                    self.flushable_pending = 0;
+                    let r = self.take_retired(len).ok_or(e)?;
+                    (r.segment_index, r.offset)
+                }
+                Err(e) => return Err(e),
+            }
+        };
 
         // Mark old allocation as dead if it exists.
         if let Some(&old_idx) = self.live_map.get(&field) {
@@ -159,6 +174,13 @@ impl SparseSlab {
         Ok(FieldHandle::new(generation, offset, len, location))
     }
 
+    /// Remove a retired range of exactly `len`, counting the reuse hit.
+    fn take_retired(&mut self, len: u32) -> Option<RetiredRange> {
+        let pos = self.retired_ranges.iter().position(|r| r.len == len)?;
+        self.reuse_hits = self.reuse_hits.saturating_add(1);
+        Some(self.retired_ranges.swap_remove(pos))
+    }
+
     /// Get the current live handle for a sparse field.
     pub fn get_handle(&self, field: FieldId) -> Option<FieldHandle> {
         let &idx = self.live_map.get(&field)?;
@@ -203,6 +225,16 @@ impl SparseSlab {
     /// references the pending ranges, so they are safe to hand out.
     pub fn flush_retired(&mut self) {
         self.retired_ranges.append(&mut self.pending_retired);
+        self.flushable_pending = 0;
+    }
+
+    /// Mark every pending range as promotable on demand without flushing.
+    ///
+    /// Called at the start of a tick whose retirement policy defers the
+    /// flush. If `alloc()` later runs out of segment space, the marked
+    /// ranges are promoted and the allocation is retried.
+    pub fn defer_retired(&mut self) {
+        self.flushable_pending = self.pending_retired.len();
     }
 
     /// Number of segment ranges available for reuse.
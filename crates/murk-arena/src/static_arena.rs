@@ -1,13 +1,18 @@
 //! Static generation-0 arena for immutable fields.
 //!
 //! [`StaticArena`] stores `Static`-mutability fields — data that is set once
-//! at world creation and never modified. It is a simple `Vec<f32>` with an
+//! at world creation and never modified. It is a simple `Vec<Scalar>` with an
 //! offset table, wrapped in `Arc` for sharing across vectorized environments.
+//! With the `mmap` feature, fields can instead be backed by a read-only
+//! memory-mapped file.
 
 use std::sync::Arc;
 
 use indexmap::IndexMap;
-use murk_core::FieldId;
+use murk_core::{FieldId, Scalar};
+
+#[cfg(all(feature = "mmap", unix))]
+use crate::mmap::MappedF32;
 
 /// Arena for static (generation-0) field data.
 ///
@@ -17,12 +22,15 @@ use murk_core::FieldId;
 /// `SharedStaticArena` allocation.
 ///
 /// The data is contiguous in memory — all static fields are packed into a
-/// single `Vec<f32>` with an offset table for O(1) lookup.
+/// single `Vec<Scalar>` with an offset table for O(1) lookup.
 pub struct StaticArena {
     /// Contiguous storage for all static fields.
-    data: Vec<f32>,
+    data: Vec<Scalar>,
     /// Maps FieldId to (offset, len) within `data`.
     field_offsets: IndexMap<FieldId, (usize, usize)>,
+    /// Read-only fields backed by a memory-mapped file instead of `data`.
+    #[cfg(all(feature = "mmap", unix))]
+    mapped: IndexMap<FieldId, MappedF32>,
 }
 
 /// Shared handle for cross-environment static data sharing.
@@ -32,7 +40,7 @@ impl StaticArena {
     /// Create a new static arena with space for the given fields.
     ///
     /// `static_fields` maps each static `FieldId` to its required length
-    /// (in f32 elements, i.e. `cell_count * components`).
+    /// (in `Scalar` elements, i.e. `cell_count * components`).
     /// The data is zero-initialised; callers should write initial values
     /// via [`StaticArena::write_field`] before sharing.
     pub fn new(static_fields: &[(FieldId, u32)]) -> Self {
@@ -61,11 +69,86 @@ impl StaticArena {
         Self {
             data,
             field_offsets,
+            #[cfg(all(feature = "mmap", unix))]
+            mapped: IndexMap::new(),
+        }
+    }
+
+    /// Create a static arena holding one field backed by a memory-mapped file.
+    ///
+    /// The first `len` native-endian `Scalar` values of the file at `path` are
+    /// mapped read-only. Sharing the arena via [`into_shared`](Self::into_shared)
+    /// lets every world read the same pages without copying them. Further
+    /// mapped fields can be added with [`map_field`](Self::map_field).
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other
+    /// process, while the arena (or any `Arc` sharing it) is alive. The
+    /// mapping is handed out as `&[Scalar]`, so a concurrent write breaks
+    /// the immutability those slices promise, and truncation makes reads
+    /// of the missing pages raise `SIGBUS`. Deleting or renaming the file
+    /// is fine: the mapping keeps the original contents alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the file cannot be opened or mapped, or is
+    /// shorter than `len * size_of::<Scalar>()` bytes.
+    #[cfg(all(feature = "mmap", unix))]
+    #[allow(unsafe_code)]
+    pub unsafe fn from_mmap(
+        field: FieldId,
+        path: impl AsRef<std::path::Path>,
+        len: u32,
+    ) -> std::io::Result<Self> {
+        let mut arena = Self::new(&[]);
+        // SAFETY: forwarded from this function's contract.
+        unsafe { arena.map_field(field, path, len)? };
+        Ok(arena)
+    }
+
+    /// Add a read-only field backed by a memory-mapped file.
+    ///
+    /// The first `len` native-endian `Scalar` values of the file at `path`
+    /// are mapped read-only. Mapped fields are not writable:
+    /// [`write_field`](Self::write_field) returns `None` for them.
+    ///
+    /// # Safety
+    ///
+    /// Same contract as `from_mmap`: the file must not be modified or
+    /// truncated while this arena (or any `Arc` sharing it) is alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if `field` is already registered, or if the
+    /// file cannot be opened or mapped, or is shorter than
+    /// `len * size_of::<Scalar>()` bytes.
+    #[cfg(all(feature = "mmap", unix))]
+    #[allow(unsafe_code)]
+    pub unsafe fn map_field(
+        &mut self,
+        field: FieldId,
+        path: impl AsRef<std::path::Path>,
+        len: u32,
+    ) -> std::io::Result<()> {
+        if self.contains(field) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("duplicate FieldId({}) in static arena", field.0),
+            ));
         }
+        // SAFETY: the caller upholds the no-modification contract above.
+        let mapping = unsafe { MappedF32::open(path.as_ref(), len as usize)? };
+        self.mapped.insert(field, mapping);
+        Ok(())
     }
 
     /// Read a static field's data.
-    pub fn read_field(&self, field: FieldId) -> Option<&[f32]> {
+    pub fn read_field(&self, field: FieldId) -> Option<&[Scalar]> {
+        #[cfg(all(feature = "mmap", unix))]
+        if let Some(mapping) = self.mapped.get(&field) {
+            return Some(mapping.as_slice());
+        }
         let &(offset, len) = self.field_offsets.get(&field)?;
         Some(&self.data[offset..offset + len])
     }
@@ -75,30 +158,46 @@ impl StaticArena {
     /// Returns `None` if the field is not registered as static.
     /// This should only be called during world construction, before the
     /// arena is shared via `Arc`.
-    pub fn write_field(&mut self, field: FieldId) -> Option<&mut [f32]> {
+    pub fn write_field(&mut self, field: FieldId) -> Option<&mut [Scalar]> {
         let &(offset, len) = self.field_offsets.get(&field)?;
         Some(&mut self.data[offset..offset + len])
     }
 
     /// Check whether a field is stored in this static arena.
     pub fn contains(&self, field: FieldId) -> bool {
+        #[cfg(all(feature = "mmap", unix))]
+        if self.mapped.contains_key(&field) {
+            return true;
+        }
         self.field_offsets.contains_key(&field)
     }
 
     /// Get the offset and length of a field within the static data vec.
+    ///
+    /// Memory-mapped fields live outside the vec and report offset 0.
     pub fn field_location(&self, field: FieldId) -> Option<(u32, u32)> {
+        #[cfg(all(feature = "mmap", unix))]
+        if let Some(mapping) = self.mapped.get(&field) {
+            return Some((0, mapping.as_slice().len() as u32));
+        }
         self.field_offsets
             .get(&field)
             .map(|&(off, len)| (off as u32, len as u32))
     }
 
-    /// Total memory usage in bytes.
+    /// Total heap memory usage in bytes.
+    ///
+    /// Memory-mapped fields are backed by the page cache and not counted.
     pub fn memory_bytes(&self) -> usize {
-        self.data.len() * std::mem::size_of::<f32>()
+        self.data.len() * std::mem::size_of::<Scalar>()
     }
 
     /// Number of static fields.
     pub fn field_count(&self) -> usize {
+        #[cfg(all(feature = "mmap", unix))]
+        if !self.mapped.is_empty() {
+            return self.field_offsets.len() + self.mapped.len();
+        }
         self.field_offsets.len()
     }
 
@@ -186,7 +285,7 @@ mod tests {
     #[test]
     fn memory_bytes_accounts_for_all_fields() {
         let arena = StaticArena::new(&[(FieldId(0), 100), (FieldId(1), 50)]);
-        assert_eq!(arena.memory_bytes(), 150 * 4);
+        assert_eq!(arena.memory_bytes(), 150 * std::mem::size_of::<Scalar>());
     }
 
     #[test]
@@ -201,10 +300,54 @@ mod tests {
         StaticArena::new(&[(FieldId(0), 10), (FieldId(1), 20), (FieldId(0), 30)]);
     }
 
+    #[cfg(all(feature = "mmap", unix))]
+    fn write_f32_file(name: &str, values: &[Scalar]) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("murk-arena-{}-{name}.bin", std::process::id()));
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_ne_bytes()).collect();
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    #[cfg(all(feature = "mmap", unix))]
+    #[allow(unsafe_code)]
+    fn mapped_field_reads_file_contents() {
+        let values: Vec<Scalar> = (0..64).map(|i| i as Scalar * 0.5).collect();
+        let path = write_f32_file("contents", &values);
+        // SAFETY: the file is private to this test and never written again.
+        let mut arena = unsafe { StaticArena::from_mmap(FieldId(3), &path, 64) }.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(arena.read_field(FieldId(3)).unwrap(), &values[..]);
+        assert!(arena.contains(FieldId(3)));
+        assert_eq!(arena.field_location(FieldId(3)), Some((0, 64)));
+        assert_eq!(arena.field_count(), 1);
+        assert_eq!(arena.memory_bytes(), 0);
+        assert!(
+            arena.write_field(FieldId(3)).is_none(),
+            "mapped is read-only"
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "mmap", unix))]
+    #[allow(unsafe_code)]
+    fn mapped_field_rejects_short_file_and_duplicates() {
+        let path = write_f32_file("short", &[1.0; 8]);
+        // SAFETY: the file is private to this test and never written again.
+        unsafe {
+            assert!(StaticArena::from_mmap(FieldId(0), &path, 9).is_err());
+            let mut arena = StaticArena::from_mmap(FieldId(0), &path, 8).unwrap();
+            assert!(arena.map_field(FieldId(0), &path, 8).is_err());
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+
     #[test]
     fn new_accepts_distinct_field_ids() {
         let arena = StaticArena::new(&[(FieldId(0), 10), (FieldId(1), 20), (FieldId(2), 30)]);
         assert_eq!(arena.field_count(), 3);
-        assert_eq!(arena.memory_bytes(), 60 * 4);
+        assert_eq!(arena.memory_bytes(), 60 * std::mem::size_of::<Scalar>());
     }
 }
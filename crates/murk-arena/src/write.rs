@@ -6,7 +6,7 @@
 //! before `publish()`.
 
 use murk_core::traits::FieldWriter;
-use murk_core::{FieldId, FieldMutability};
+use murk_core::{FieldId, FieldMutability, Scalar};
 
 use crate::descriptor::FieldDescriptor;
 use crate::handle::FieldLocation;
@@ -67,7 +67,7 @@ impl<'a> WriteArena<'a> {
     }
 
     /// Write a sparse field (CoW: allocate new copy in sparse segments).
-    fn write_sparse(&mut self, field: FieldId, total_len: u32) -> Option<&mut [f32]> {
+    fn write_sparse(&mut self, field: FieldId, total_len: u32) -> Option<&mut [Scalar]> {
         // Allocate new storage for this generation.
         let handle = self
             .sparse_slab
@@ -89,7 +89,7 @@ impl<'a> WriteArena<'a> {
                 // Copy old data to new allocation.
                 // We need to copy through a temp buffer to satisfy borrow checker
                 // (can't have &segments and &mut segments simultaneously).
-                let old_data: Vec<f32> = self
+                let old_data: Vec<Scalar> = self
                     .sparse_segments
                     .slice(old_seg, old_handle.offset, old_handle.len())?
                     .to_vec();
@@ -120,7 +120,7 @@ impl<'a> WriteArena<'a> {
     }
 
     /// Read a field's data from the staging buffer (for CoW copy-before-write).
-    pub fn read(&self, field: FieldId) -> Option<&[f32]> {
+    pub fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         let entry = self.descriptor.get(field)?;
         let handle = &entry.handle;
         match handle.location() {
@@ -141,7 +141,7 @@ impl<'a> WriteArena<'a> {
 }
 
 impl FieldWriter for WriteArena<'_> {
-    fn write(&mut self, field: FieldId) -> Option<&mut [f32]> {
+    fn write(&mut self, field: FieldId) -> Option<&mut [Scalar]> {
         let entry = self.descriptor.get(field)?;
         // Extract only the scalar values we need — avoids cloning the
         // entire FieldMeta (which previously heap-allocated a String
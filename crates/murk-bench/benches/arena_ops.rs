@@ -2,7 +2,10 @@
 //!
 //! Phase 3 baseline focus:
 //! - snapshot publish throughput
+//! - owned snapshot copy at stress-profile size (parallel with
+//!   `--features murk-arena/rayon`)
 //! - sparse write/reuse throughput
+//! - non-zero iteration over mostly-empty fields
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use murk_arena::config::ArenaConfig;
@@ -10,7 +13,7 @@ use murk_arena::static_arena::StaticArena;
 use murk_arena::PingPongArena;
 use murk_core::id::{FieldId, ParameterVersion, TickId};
 use murk_core::traits::{FieldWriter, SnapshotAccess};
-use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType};
+use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType, Scalar};
 
 /// Build 5 PerTick field definitions matching the reference pipeline component count.
 fn make_field_defs_5() -> Vec<(FieldId, FieldDef)> {
@@ -82,6 +85,14 @@ fn make_arena_10k() -> PingPongArena {
     PingPongArena::new(config, field_defs, static_arena).unwrap()
 }
 
+/// Build a PingPongArena at stress-profile size (316x316 cells) with 5 fields.
+fn make_arena_stress() -> PingPongArena {
+    let config = ArenaConfig::new(316 * 316);
+    let field_defs = make_field_defs_5();
+    let static_arena = StaticArena::new(&[]).into_shared();
+    PingPongArena::new(config, field_defs, static_arena).unwrap()
+}
+
 /// Build a sparse-heavy arena for sparse reuse measurements.
 fn make_sparse_arena_10k() -> PingPongArena {
     let cell_count = 10_000u32;
@@ -140,7 +151,7 @@ fn bench_arena_write_10k(c: &mut Criterion) {
             let mut guard = arena.begin_tick().unwrap();
             let data = guard.writer.write(FieldId(0)).unwrap();
             for (i, val) in data.iter_mut().enumerate() {
-                *val = i as f32;
+                *val = i as Scalar;
             }
             std::hint::black_box(data[0]);
             arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
@@ -188,7 +199,30 @@ fn bench_arena_owned_snapshot_10k(c: &mut Criterion) {
             {
                 let mut guard = arena.begin_tick().unwrap();
                 let data = guard.writer.write(FieldId(0)).unwrap();
-                data[0] = snap_tick as f32;
+                data[0] = snap_tick as Scalar;
+            }
+            arena
+                .publish(TickId(snap_tick), ParameterVersion(0))
+                .unwrap();
+            snap_tick += 1;
+            let owned = arena.owned_snapshot();
+            let data = owned.read_field(FieldId(0)).unwrap();
+            std::hint::black_box(data[0]);
+        });
+    });
+}
+
+/// Benchmark: owned snapshot creation on the stress profile (~100K cells).
+fn bench_arena_owned_snapshot_stress(c: &mut Criterion) {
+    let mut arena = make_arena_stress();
+    let mut snap_tick = 1u64;
+
+    c.bench_function("arena_owned_snapshot_stress", |b| {
+        b.iter(|| {
+            {
+                let mut guard = arena.begin_tick().unwrap();
+                let data = guard.writer.write(FieldId(0)).unwrap();
+                data[0] = snap_tick as Scalar;
             }
             arena
                 .publish(TickId(snap_tick), ParameterVersion(0))
@@ -221,12 +255,12 @@ fn bench_arena_sparse_reuse_10k(c: &mut Criterion) {
                             let sparse = guard.writer.write(FieldId(0)).unwrap();
                             for i in 0..active_cells {
                                 let idx = (i * 17) % sparse.len();
-                                sparse[idx] = tick as f32;
+                                sparse[idx] = tick as Scalar;
                             }
                         }
                         {
                             let dense = guard.writer.write(FieldId(1)).unwrap();
-                            dense[0] = tick as f32;
+                            dense[0] = tick as Scalar;
                         }
                     }
                     arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
@@ -243,12 +277,69 @@ fn bench_arena_sparse_reuse_10k(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark: per-cell work over a 100K-cell field holding 50 non-zero
+/// cells, via `iter_nonzero` vs. a full slice scan doing the work on every
+/// cell. Both scan all 100K cells; the difference is the per-cell work
+/// skipped on zero entries.
+fn bench_snapshot_iter_nonzero_100k(c: &mut Criterion) {
+    let cell_count = 100_000u32;
+    let field_defs = vec![(
+        FieldId(0),
+        FieldDef {
+            name: "agent_presence".into(),
+            field_type: FieldType::Scalar,
+            mutability: FieldMutability::PerTick,
+            units: None,
+            bounds: None,
+            boundary_behavior: BoundaryBehavior::Clamp,
+        },
+    )];
+    let static_arena = StaticArena::new(&[]).into_shared();
+    let mut arena =
+        PingPongArena::new(ArenaConfig::new(cell_count), field_defs, static_arena).unwrap();
+    {
+        let mut guard = arena.begin_tick().unwrap();
+        let data = guard.writer.write(FieldId(0)).unwrap();
+        for i in 0..50usize {
+            data[(i * 1999) % data.len()] = (i + 1) as Scalar;
+        }
+    }
+    arena.publish(TickId(1), ParameterVersion(0)).unwrap();
+    let snap = arena.snapshot();
+
+    let mut group = c.benchmark_group("snapshot_iter_nonzero");
+    group.throughput(Throughput::Elements(cell_count as u64));
+    group.bench_function("iter_nonzero_100k_50", |b| {
+        b.iter(|| {
+            let sum: Scalar = snap
+                .iter_nonzero(FieldId(0))
+                .unwrap()
+                .map(|(i, v)| v * (i as Scalar).sqrt())
+                .sum();
+            std::hint::black_box(sum);
+        });
+    });
+    group.bench_function("full_scan_100k_50", |b| {
+        b.iter(|| {
+            let data = snap.read_field(FieldId(0)).unwrap();
+            let mut sum: Scalar = 0.0;
+            for (i, &v) in data.iter().enumerate() {
+                sum += v * (i as Scalar).sqrt();
+            }
+            std::hint::black_box(sum);
+        });
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_arena_alloc_10k,
     bench_arena_write_10k,
     bench_arena_snapshot,
     bench_arena_owned_snapshot_10k,
-    bench_arena_sparse_reuse_10k
+    bench_arena_owned_snapshot_stress,
+    bench_arena_sparse_reuse_10k,
+    bench_snapshot_iter_nonzero_100k
 );
 criterion_main!(benches);
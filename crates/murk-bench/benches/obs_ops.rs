@@ -27,7 +27,9 @@ fn fixed_obs_spec() -> ObsSpec {
             pool: None,
             transform: ObsTransform::Identity,
             dtype: ObsDtype::F32,
+            tick_offset: 0,
         }],
+        pad_value: 0.0,
     }
 }
 
@@ -36,11 +38,17 @@ fn agent_obs_spec() -> ObsSpec {
     ObsSpec {
         entries: vec![ObsEntry {
             field_id: HEAT,
-            region: ObsRegion::AgentDisk { radius: 3 },
+            region: ObsRegion::AgentDisk {
+                radius: 3,
+                metric: None,
+                occlusion: None,
+            },
             pool: None,
             transform: ObsTransform::Identity,
             dtype: ObsDtype::F32,
+            tick_offset: 0,
         }],
+        pad_value: 0.0,
     }
 }
 
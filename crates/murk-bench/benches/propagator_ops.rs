@@ -0,0 +1,88 @@
+//! Criterion micro-benchmarks for propagator composition.
+//!
+//! Compares a diffusion + gradient pipeline on a 100x100 Hex2D run as two
+//! stages (each enumerating every cell's neighbours per tick) against the
+//! same pair in a `PropagatorGroup`, which builds the neighbour table once
+//! at world creation and shares it with both members.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use murk_core::{BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldType};
+use murk_engine::{LockstepWorld, WorldConfig};
+use murk_propagator::{Propagator, PropagatorGroup};
+use murk_propagators::{GradientCompute, ScalarDiffusion};
+use murk_space::{Hex2D, Space};
+
+const HEAT: FieldId = FieldId(0);
+const GRADIENT: FieldId = FieldId(1);
+
+fn field(name: &str, field_type: FieldType) -> FieldDef {
+    FieldDef {
+        name: name.to_string(),
+        field_type,
+        mutability: FieldMutability::PerTick,
+        units: None,
+        bounds: None,
+        boundary_behavior: BoundaryBehavior::Clamp,
+    }
+}
+
+/// Diffusion + gradient world on a 100x100 hex grid, optionally grouped.
+fn hex_world(grouped: bool) -> LockstepWorld {
+    let members: Vec<Box<dyn Propagator>> = vec![
+        Box::new(
+            ScalarDiffusion::builder()
+                .input_field(HEAT)
+                .output_field(HEAT)
+                .coefficient(0.05)
+                .sources(vec![(0, 100.0)])
+                .build()
+                .unwrap(),
+        ),
+        Box::new(
+            GradientCompute::builder()
+                .input_field(HEAT)
+                .output_field(GRADIENT)
+                .build()
+                .unwrap(),
+        ),
+    ];
+    let propagators: Vec<Box<dyn Propagator>> = if grouped {
+        vec![Box::new(PropagatorGroup::new("heat", members).unwrap())]
+    } else {
+        members
+    };
+    let config = WorldConfig::builder()
+        .space(Box::new(Hex2D::new(100, 100).unwrap()))
+        .fields(vec![
+            field("heat", FieldType::Scalar),
+            field("gradient", FieldType::Vector { dims: 2 }),
+        ])
+        .propagators(propagators)
+        .dt(0.1)
+        .seed(42)
+        .build()
+        .unwrap();
+    LockstepWorld::new(config).unwrap()
+}
+
+/// Benchmark: one tick of diffusion + gradient, separate vs grouped.
+fn bench_group_hex2d_10k(c: &mut Criterion) {
+    let mut group = c.benchmark_group("diffusion_gradient_hex2d_10k");
+    group.throughput(Throughput::Elements(
+        Hex2D::new(100, 100).unwrap().cell_count() as u64,
+    ));
+    for (label, grouped) in [("separate", false), ("grouped", true)] {
+        let mut world = hex_world(grouped);
+        world.step_sync(vec![]).unwrap();
+        group.bench_function(BenchmarkId::from_parameter(label), |b| {
+            b.iter(|| {
+                let result = world.step_sync(vec![]).unwrap();
+                std::hint::black_box(&result);
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_group_hex2d_10k);
+criterion_main!(benches);
@@ -3,10 +3,13 @@
 //! Phase 3 baseline focus:
 //! - coordinate-to-rank lookup latency
 //! - canonical ordering materialization cost
+//! - reusable neighbour buffers vs per-call neighbour lists
 
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
-use murk_space::{EdgeBehavior, Hex2D, Line1D, ProductSpace, Space, Square4};
-use smallvec::smallvec;
+use murk_space::{
+    EdgeBehavior, Fcc12, Hex2D, Line1D, ProductSpace, RegionSpec, Ring1D, Space, Square4,
+};
+use smallvec::{smallvec, SmallVec};
 
 /// Benchmark: call neighbours() on all 10K cells of a 100x100 Square4.
 fn bench_neighbours_square4_10k(c: &mut Criterion) {
@@ -42,6 +45,35 @@ fn bench_neighbours_hex2d_10k(c: &mut Criterion) {
     });
 }
 
+/// Benchmark: neighbours() and neighbours_into() on every cell of a
+/// Square4(32x32, Wrap) x Ring1D(16) product (16K cells).
+fn bench_neighbours_product_square_ring(c: &mut Criterion) {
+    let square = Square4::new(32, 32, EdgeBehavior::Wrap).unwrap();
+    let ring = Ring1D::new(16).unwrap();
+    let space = ProductSpace::new(vec![Box::new(square), Box::new(ring)]).unwrap();
+    let coords = space.canonical_ordering();
+
+    let mut group = c.benchmark_group("neighbours_product_square_ring_16k");
+    group.throughput(Throughput::Elements(coords.len() as u64));
+    group.bench_function("neighbours", |b| {
+        b.iter(|| {
+            for coord in &coords {
+                std::hint::black_box(space.neighbours(coord));
+            }
+        });
+    });
+    group.bench_function("neighbours_into", |b| {
+        let mut buf = SmallVec::new();
+        b.iter(|| {
+            for coord in &coords {
+                space.neighbours_into(coord, &mut buf);
+                std::hint::black_box(&buf);
+            }
+        });
+    });
+    group.finish();
+}
+
 /// Benchmark: compute distance() for 1000 deterministic pairs in a product space.
 fn bench_distance_product_space(c: &mut Criterion) {
     let hex = Hex2D::new(20, 20).unwrap();
@@ -138,13 +170,100 @@ fn bench_canonical_ordering_square4_10k(c: &mut Criterion) {
     });
 }
 
+/// Benchmark: neighbour-rank precompute as done by the generic diffusion
+/// path, on a 20x20x20 Fcc12 lattice (12 neighbours per interior cell).
+///
+/// `neighbours()` spills its 8-element inline buffer to the heap for every
+/// interior cell; `neighbours_into()` reuses one 12-element buffer.
+fn bench_neighbour_ranks_fcc12(c: &mut Criterion) {
+    let space = Fcc12::new(20, 20, 20, EdgeBehavior::Absorb).unwrap();
+    let ordering = space.canonical_ordering();
+
+    let mut group = c.benchmark_group("neighbour_ranks_fcc12");
+    group.throughput(Throughput::Elements(ordering.len() as u64));
+    group.bench_function("neighbours", |b| {
+        b.iter(|| {
+            let ranks: Vec<Vec<usize>> = ordering
+                .iter()
+                .map(|coord| {
+                    space
+                        .neighbours(coord)
+                        .iter()
+                        .filter_map(|nb| space.canonical_rank(nb))
+                        .collect()
+                })
+                .collect();
+            std::hint::black_box(ranks);
+        });
+    });
+    group.bench_function("neighbours_into", |b| {
+        let mut buf = SmallVec::new();
+        b.iter(|| {
+            let ranks: Vec<Vec<usize>> = ordering
+                .iter()
+                .map(|coord| {
+                    space.neighbours_into(coord, &mut buf);
+                    buf.iter()
+                        .filter_map(|nb| space.canonical_rank(nb))
+                        .collect()
+                })
+                .collect();
+            std::hint::black_box(ranks);
+        });
+    });
+    group.finish();
+}
+
+/// Benchmark: compiling 200 small `Coords` regions on a product space,
+/// one `compile_region` call each vs a single batched `compile_regions`.
+///
+/// The single-spec path rebuilds each component's canonical ordering per
+/// coordinate; the batched path builds it once for all specs.
+fn bench_compile_regions_product_space(c: &mut Criterion) {
+    let square = Square4::new(32, 32, EdgeBehavior::Absorb).unwrap();
+    let line = Line1D::new(8, EdgeBehavior::Absorb).unwrap();
+    let space = ProductSpace::new(vec![Box::new(square), Box::new(line)]).unwrap();
+
+    let specs: Vec<RegionSpec> = (0..200i32)
+        .map(|i| {
+            RegionSpec::Coords(
+                (0..4)
+                    .map(|k| smallvec![(i + k) % 32, (i * 3 + k) % 32, (i + k) % 8])
+                    .collect(),
+            )
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("compile_regions_product");
+    group.throughput(Throughput::Elements(specs.len() as u64));
+    group.bench_function("individual", |b| {
+        b.iter(|| {
+            let plans: Vec<_> = specs
+                .iter()
+                .map(|spec| space.compile_region(spec).unwrap())
+                .collect();
+            std::hint::black_box(plans);
+        });
+    });
+    group.bench_function("batched", |b| {
+        b.iter(|| {
+            let plans = space.compile_regions(&specs).unwrap();
+            std::hint::black_box(plans);
+        });
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_neighbours_square4_10k,
     bench_neighbours_hex2d_10k,
+    bench_neighbours_product_square_ring,
     bench_distance_product_space,
     bench_canonical_rank_square4_10k,
     bench_canonical_rank_product_space,
-    bench_canonical_ordering_square4_10k
+    bench_canonical_ordering_square4_10k,
+    bench_neighbour_ranks_fcc12,
+    bench_compile_regions_product_space
 );
 criterion_main!(benches);
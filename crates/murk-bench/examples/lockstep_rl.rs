@@ -5,7 +5,7 @@
 //! observations → reset → repeat.
 
 use murk_bench::reference_profile;
-use murk_core::FieldReader;
+use murk_core::{FieldReader, Scalar};
 use murk_engine::LockstepWorld;
 use murk_propagators::agent_movement::{new_action_buffer, AgentAction, Direction};
 #[allow(deprecated)]
@@ -47,9 +47,9 @@ fn main() {
             let heat = snap.read(HEAT).unwrap();
             let reward = snap.read(REWARD).unwrap();
 
-            let total_reward: f32 = reward.iter().sum();
-            let max_heat: f32 = heat.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-            let mean_heat: f32 = heat.iter().sum::<f32>() / heat.len() as f32;
+            let total_reward: Scalar = reward.iter().sum();
+            let max_heat: Scalar = heat.iter().cloned().fold(Scalar::NEG_INFINITY, Scalar::max);
+            let mean_heat: Scalar = heat.iter().sum::<Scalar>() / heat.len() as Scalar;
 
             println!(
                 "  tick {:>3}: total_reward={:>8.3}, max_heat={:>8.3}, mean_heat={:>8.5}, time={:>6}μs",
@@ -73,9 +73,9 @@ fn main() {
         if tick % 10 == 0 || tick == 49 {
             let snap = &result.snapshot;
             let heat = snap.read(HEAT).unwrap();
-            let mean_heat: f32 = heat.iter().sum::<f32>() / heat.len() as f32;
+            let mean_heat: Scalar = heat.iter().sum::<Scalar>() / heat.len() as Scalar;
             let reward = snap.read(REWARD).unwrap();
-            let total_reward: f32 = reward.iter().sum();
+            let total_reward: Scalar = reward.iter().sum();
 
             println!(
                 "  tick {:>3}: mean_heat={:>8.5}, total_reward={:>8.3}, time={:>6}μs",
@@ -19,7 +19,7 @@ use murk_space::{EdgeBehavior, Square4};
 
 /// Build a reference benchmark profile: 100x100 grid (10K cells).
 ///
-/// Pipeline: ScalarDiffusion(heat, D=0.1) + ScalarDiffusion(velocity, D=0.1)
+/// Pipeline: ScalarDiffusion(heat, D=0.1) + ScalarDiffusion(velocity, 2 components, D=0.1)
 /// + GradientCompute(heat→gradient) → AgentMovement → Reward(bonus=1.0, cost=-0.01).
 ///
 /// dt=0.1 (within CFL limit of 1/(4*0.1) = 2.5).
@@ -44,6 +44,7 @@ pub fn reference_profile(seed: u64, action_buffer: ActionBuffer) -> WorldConfig
                 ScalarDiffusion::builder()
                     .input_field(VELOCITY)
                     .output_field(VELOCITY)
+                    .components(2)
                     .coefficient(0.1)
                     .build()
                     .unwrap(),
@@ -91,6 +92,7 @@ pub fn stress_profile(seed: u64, action_buffer: ActionBuffer) -> WorldConfig {
                 ScalarDiffusion::builder()
                     .input_field(VELOCITY)
                     .output_field(VELOCITY)
+                    .components(2)
                     .coefficient(0.1)
                     .build()
                     .unwrap(),
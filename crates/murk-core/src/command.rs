@@ -1,5 +1,7 @@
 //! Command, command payload, and receipt types for the ingress pipeline.
 
+use std::time::{Duration, Instant};
+
 use crate::error::IngressError;
 use crate::id::{Coord, FieldId, ParameterKey, TickId};
 
@@ -21,6 +23,7 @@ use crate::id::{Coord, FieldId, ParameterKey, TickId};
 ///         value: 2.5,
 ///     },
 ///     expires_after_tick: TickId(100),
+///     expires_at: None,
 ///     source_id: Some(1),
 ///     source_seq: Some(0),
 ///     priority_class: 1,
@@ -36,6 +39,10 @@ pub struct Command {
     pub payload: CommandPayload,
     /// The command expires if not applied by this tick.
     pub expires_after_tick: TickId,
+    /// Optional wall-clock deadline. Only honored by ingress queues with
+    /// wall-clock expiry enabled (the realtime world); lockstep ignores it
+    /// so that replays stay deterministic.
+    pub expires_at: Option<Instant>,
     /// Optional source identifier for deduplication and ordering.
     pub source_id: Option<u64>,
     /// Optional per-source sequence number for ordering.
@@ -47,6 +54,107 @@ pub struct Command {
     pub arrival_seq: u64,
 }
 
+impl Command {
+    /// Create a command for `payload` with default metadata.
+    ///
+    /// Defaults: never expires (`expires_after_tick = TickId(u64::MAX)`,
+    /// no wall-clock deadline), no source, priority class 1 (user), and `arrival_seq = 0`, which the
+    /// ingress queue overwrites on submission. Override with
+    /// [`expires_after`](Self::expires_after),
+    /// [`expires_within`](Self::expires_within),
+    /// [`priority`](Self::priority), and [`source`](Self::source).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use murk_core::{Command, FieldId, TickId};
+    ///
+    /// let cmd = Command::set_field(vec![3i32, 7].into(), FieldId(0), 42.0)
+    ///     .expires_after(TickId(100))
+    ///     .priority(0);
+    /// assert_eq!(cmd.expires_after_tick, TickId(100));
+    /// assert_eq!(cmd.priority_class, 0);
+    /// ```
+    pub fn new(payload: CommandPayload) -> Self {
+        Self {
+            payload,
+            expires_after_tick: TickId(u64::MAX),
+            expires_at: None,
+            source_id: None,
+            source_seq: None,
+            priority_class: 1,
+            arrival_seq: 0,
+        }
+    }
+
+    /// A [`CommandPayload::SetField`] command with default metadata.
+    pub fn set_field(coord: Coord, field_id: FieldId, value: f32) -> Self {
+        Self::new(CommandPayload::SetField {
+            coord,
+            field_id,
+            value,
+        })
+    }
+
+    /// A [`CommandPayload::SetFieldBatch`] command with default metadata.
+    pub fn set_field_batch(field_id: FieldId, cells: Vec<(Coord, f32)>) -> Self {
+        Self::new(CommandPayload::SetFieldBatch { field_id, cells })
+    }
+
+    /// A [`CommandPayload::SetParameter`] command with default metadata.
+    pub fn set_parameter(key: ParameterKey, value: f64) -> Self {
+        Self::new(CommandPayload::SetParameter { key, value })
+    }
+
+    /// A [`CommandPayload::SetParameterBatch`] command with default metadata.
+    pub fn set_parameter_batch(params: Vec<(ParameterKey, f64)>) -> Self {
+        Self::new(CommandPayload::SetParameterBatch { params })
+    }
+
+    /// A [`CommandPayload::Custom`] command with default metadata.
+    pub fn custom(type_id: u32, data: Vec<u8>) -> Self {
+        Self::new(CommandPayload::Custom { type_id, data })
+    }
+
+    /// Expire the command if it is not applied by `tick`.
+    pub fn expires_after(mut self, tick: TickId) -> Self {
+        self.expires_after_tick = tick;
+        self
+    }
+
+    /// Expire the command if it is not applied by the wall-clock `deadline`.
+    ///
+    /// Honored only where wall-clock expiry is enabled; see
+    /// [`expires_at`](Self::expires_at).
+    pub fn expires_by(mut self, deadline: Instant) -> Self {
+        self.expires_at = Some(deadline);
+        self
+    }
+
+    /// Expire the command if it is not applied within `timeout` from now.
+    ///
+    /// Shorthand for [`expires_by`](Self::expires_by)`(Instant::now() + timeout)`.
+    /// A `timeout` too large to represent as an `Instant` leaves the command
+    /// without a wall-clock deadline.
+    pub fn expires_within(mut self, timeout: Duration) -> Self {
+        self.expires_at = Instant::now().checked_add(timeout);
+        self
+    }
+
+    /// Set the priority class (lower = higher priority).
+    pub fn priority(mut self, priority_class: u8) -> Self {
+        self.priority_class = priority_class;
+        self
+    }
+
+    /// Tag the command with a source identifier and per-source sequence.
+    pub fn source(mut self, source_id: u64, source_seq: u64) -> Self {
+        self.source_id = Some(source_id);
+        self.source_seq = Some(source_seq);
+        self
+    }
+}
+
 /// All command payloads.
 ///
 /// `WorldEvent` variants affect per-cell state; `GlobalParameter` variants
@@ -106,6 +214,18 @@ pub enum CommandPayload {
         /// The new value.
         value: f32,
     },
+    /// Set one field at many coordinates, applied atomically in a single
+    /// tick under one receipt.
+    ///
+    /// Out-of-bounds cells are skipped; if any are, the receipt stays
+    /// accepted but carries [`IngressError::PartiallyApplied`](crate::error::IngressError::PartiallyApplied)
+    /// with the number of skipped cells.
+    SetFieldBatch {
+        /// The field to modify.
+        field_id: FieldId,
+        /// `(coordinate, value)` pairs, applied in order.
+        cells: Vec<(Coord, f32)>,
+    },
     /// Extension point for domain-specific commands.
     Custom {
         /// User-registered type identifier.
@@ -161,3 +281,68 @@ pub struct Receipt {
     /// Index of this command within the submitted batch.
     pub command_index: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_field_matches_literal() {
+        let coord: Coord = vec![2i32, 5].into();
+        let built = Command::set_field(coord.clone(), FieldId(3), 1.5)
+            .expires_after(TickId(10))
+            .priority(0)
+            .source(7, 2);
+        let literal = Command {
+            payload: CommandPayload::SetField {
+                coord,
+                field_id: FieldId(3),
+                value: 1.5,
+            },
+            expires_after_tick: TickId(10),
+            expires_at: None,
+            source_id: Some(7),
+            source_seq: Some(2),
+            priority_class: 0,
+            arrival_seq: 0,
+        };
+        assert_eq!(built, literal);
+    }
+
+    #[test]
+    fn set_field_batch_matches_literal() {
+        let cells = vec![(Coord::from_elem(1, 2), 0.5), (Coord::from_elem(0, 2), 2.0)];
+        assert_eq!(
+            Command::set_field_batch(FieldId(4), cells.clone()).payload,
+            CommandPayload::SetFieldBatch {
+                field_id: FieldId(4),
+                cells
+            }
+        );
+    }
+
+    #[test]
+    fn expires_within_overflow_means_no_deadline() {
+        let cmd = Command::set_parameter(ParameterKey(1), 0.5);
+        assert_eq!(cmd.clone().expires_within(Duration::MAX).expires_at, None);
+        let soon = cmd.expires_within(Duration::from_secs(1));
+        assert!(soon.expires_at.is_some());
+    }
+
+    #[test]
+    fn defaults_never_expire_at_user_priority() {
+        let cmd = Command::set_parameter(ParameterKey(1), 0.5);
+        assert_eq!(cmd.expires_after_tick, TickId(u64::MAX));
+        assert_eq!(cmd.priority_class, 1);
+        assert_eq!(cmd.source_id, None);
+        assert_eq!(cmd.source_seq, None);
+        assert_eq!(cmd.arrival_seq, 0);
+        assert_eq!(
+            cmd.payload,
+            CommandPayload::SetParameter {
+                key: ParameterKey(1),
+                value: 0.5
+            }
+        );
+    }
+}
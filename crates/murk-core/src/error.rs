@@ -9,7 +9,9 @@ use std::fmt;
 /// Errors from the tick engine during `step()`.
 ///
 /// Corresponds to the TickEngine and Pipeline subsystem codes in HLD §9.7.
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// Floating-point diagnostics compare by bit pattern, so equality is total.
+#[derive(Clone, Debug)]
 pub enum StepError {
     /// A propagator returned an error during execution
     /// (`MURK_ERROR_PROPAGATOR_FAILED`).
@@ -31,6 +33,45 @@ pub enum StepError {
     /// The requested dt exceeds a propagator's `max_dt` constraint
     /// (`MURK_ERROR_DT_OUT_OF_RANGE`).
     DtOutOfRange,
+    /// The timestep exceeds a propagator's stability (CFL) limit
+    /// (`MURK_ERROR_CFL_VIOLATION`).
+    ///
+    /// Unlike [`DtOutOfRange`](Self::DtOutOfRange), this names the
+    /// constraining propagator and both timesteps.
+    CflViolation {
+        /// Name of the propagator with the tightest `max_dt`.
+        propagator: String,
+        /// The requested timestep.
+        dt: f64,
+        /// The propagator's `max_dt` for the current space.
+        max_dt: f64,
+    },
+    /// A field written this tick contains NaN or infinite values and
+    /// the tick was rolled back (`MURK_ERROR_NONFINITE_FIELD`).
+    ///
+    /// Only raised when non-finite detection is enabled in the world
+    /// configuration.
+    NonFiniteField {
+        /// The first field found to contain non-finite values.
+        field: crate::FieldId,
+        /// Number of non-finite values in that field.
+        count: usize,
+    },
+    /// A propagator that declares a field conserved changed its total
+    /// beyond the audit tolerance (`MURK_ERROR_CONSERVATION_VIOLATED`).
+    ///
+    /// Only raised when the conservation audit is enabled in the world
+    /// configuration.
+    ConservationViolated {
+        /// Name of the offending propagator.
+        propagator: String,
+        /// The conserved field whose total drifted.
+        field: crate::FieldId,
+        /// Sum of the field over all cells before the propagator ran.
+        before: f64,
+        /// Sum of the field over all cells after the propagator ran.
+        after: f64,
+    },
     /// The world is shutting down
     /// (`MURK_ERROR_SHUTTING_DOWN`, Decision E).
     ShuttingDown,
@@ -46,11 +87,94 @@ impl fmt::Display for StepError {
             Self::TickRollback => write!(f, "tick rolled back"),
             Self::TickDisabled => write!(f, "ticking disabled after consecutive rollbacks"),
             Self::DtOutOfRange => write!(f, "dt exceeds propagator max_dt constraint"),
+            Self::CflViolation {
+                propagator,
+                dt,
+                max_dt,
+            } => write!(
+                f,
+                "dt {dt} exceeds max_dt {max_dt} of propagator '{propagator}' \
+                 ({:.2}x the stable limit)",
+                dt / max_dt
+            ),
+            Self::NonFiniteField { field, count } => {
+                write!(f, "field {field} contains {count} non-finite values")
+            }
+            Self::ConservationViolated {
+                propagator,
+                field,
+                before,
+                after,
+            } => write!(
+                f,
+                "propagator '{propagator}' changed the total of conserved field {field} \
+                 from {before} to {after}"
+            ),
             Self::ShuttingDown => write!(f, "world is shutting down"),
         }
     }
 }
 
+impl PartialEq for StepError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Self::PropagatorFailed { name, reason },
+                Self::PropagatorFailed {
+                    name: other_name,
+                    reason: other_reason,
+                },
+            ) => name == other_name && reason == other_reason,
+            (
+                Self::CflViolation {
+                    propagator,
+                    dt,
+                    max_dt,
+                },
+                Self::CflViolation {
+                    propagator: other_propagator,
+                    dt: other_dt,
+                    max_dt: other_max_dt,
+                },
+            ) => {
+                propagator == other_propagator
+                    && dt.to_bits() == other_dt.to_bits()
+                    && max_dt.to_bits() == other_max_dt.to_bits()
+            }
+            (
+                Self::NonFiniteField { field, count },
+                Self::NonFiniteField {
+                    field: other_field,
+                    count: other_count,
+                },
+            ) => field == other_field && count == other_count,
+            (
+                Self::ConservationViolated {
+                    propagator,
+                    field,
+                    before,
+                    after,
+                },
+                Self::ConservationViolated {
+                    propagator: other_propagator,
+                    field: other_field,
+                    before: other_before,
+                    after: other_after,
+                },
+            ) => {
+                propagator == other_propagator
+                    && field == other_field
+                    && before.to_bits() == other_before.to_bits()
+                    && after.to_bits() == other_after.to_bits()
+            }
+            // Remaining variants carry no data.
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+}
+
+impl Eq for StepError {}
+
 impl Error for StepError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
@@ -119,6 +243,9 @@ pub enum IngressError {
     QueueFull,
     /// The command's `basis_tick_id` is too old (`MURK_ERROR_STALE`).
     Stale,
+    /// The command's wall-clock deadline (`Command::expires_at`) passed
+    /// before it was applied (`MURK_ERROR_EXPIRED`).
+    Expired,
     /// The tick was rolled back; commands were dropped
     /// (`MURK_ERROR_TICK_ROLLBACK`).
     TickRollback,
@@ -133,6 +260,14 @@ pub enum IngressError {
     /// The command was accepted but could not be applied (e.g. invalid
     /// coordinate or unknown field) (`MURK_ERROR_NOT_APPLIED`).
     NotApplied,
+    /// A batch command was applied, but some of its entries were skipped
+    /// (e.g. out-of-bounds coordinates) (`MURK_ERROR_PARTIALLY_APPLIED`).
+    ///
+    /// Unlike the other variants, the receipt remains accepted.
+    PartiallyApplied {
+        /// Number of entries that were skipped.
+        skipped: usize,
+    },
 }
 
 impl fmt::Display for IngressError {
@@ -140,6 +275,7 @@ impl fmt::Display for IngressError {
         match self {
             Self::QueueFull => write!(f, "command queue full"),
             Self::Stale => write!(f, "command basis_tick_id is stale"),
+            Self::Expired => write!(f, "command wall-clock deadline passed"),
             Self::TickRollback => write!(f, "tick rolled back"),
             Self::TickDisabled => write!(f, "ticking disabled"),
             Self::ShuttingDown => write!(f, "world is shutting down"),
@@ -148,6 +284,9 @@ impl fmt::Display for IngressError {
                 f,
                 "command accepted but not applied (invalid coordinate or unknown field)"
             ),
+            Self::PartiallyApplied { skipped } => {
+                write!(f, "batch applied with {skipped} entries skipped")
+            }
         }
     }
 }
@@ -214,6 +353,65 @@ impl Error for ObsError {}
 mod tests {
     use super::*;
 
+    #[test]
+    fn step_error_equality_is_reflexive_for_float_diagnostics() {
+        let cfl = StepError::CflViolation {
+            propagator: "diffusion".into(),
+            dt: f64::NAN,
+            max_dt: 0.25,
+        };
+        assert_eq!(cfl, cfl.clone());
+        assert_ne!(
+            cfl,
+            StepError::CflViolation {
+                propagator: "diffusion".into(),
+                dt: 0.5,
+                max_dt: 0.25,
+            }
+        );
+        assert_eq!(StepError::TickRollback, StepError::TickRollback);
+        assert_ne!(StepError::TickRollback, StepError::TickDisabled);
+        assert_ne!(cfl, StepError::DtOutOfRange);
+    }
+
+    #[test]
+    fn cfl_violation_display_names_propagator_and_ratio() {
+        let msg = StepError::CflViolation {
+            propagator: "ScalarDiffusion".into(),
+            dt: 0.5,
+            max_dt: 0.25,
+        }
+        .to_string();
+        assert!(msg.contains("ScalarDiffusion"), "{msg}");
+        assert!(msg.contains("0.5") && msg.contains("0.25"), "{msg}");
+        assert!(msg.contains("2.00x"), "{msg}");
+    }
+
+    #[test]
+    fn non_finite_field_display_names_field_and_count() {
+        let msg = StepError::NonFiniteField {
+            field: crate::FieldId(3),
+            count: 2,
+        }
+        .to_string();
+        assert!(msg.contains('3') && msg.contains("2 non-finite"), "{msg}");
+    }
+
+    #[test]
+    fn conservation_violated_display_names_propagator_and_totals() {
+        let msg = StepError::ConservationViolated {
+            propagator: "leaky".into(),
+            field: crate::FieldId(1),
+            before: 10.0,
+            after: 9.5,
+        }
+        .to_string();
+        assert!(
+            msg.contains("'leaky'") && msg.contains("10") && msg.contains("9.5"),
+            "{msg}"
+        );
+    }
+
     #[test]
     fn ingress_error_not_applied_display() {
         let msg = IngressError::NotApplied.to_string();
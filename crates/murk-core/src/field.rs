@@ -2,6 +2,76 @@
 
 use crate::id::FieldId;
 
+/// Element type of field storage.
+///
+/// `f32` by default; the `f64-fields` feature switches every field buffer,
+/// [`FieldReader`](crate::FieldReader), [`FieldWriter`](crate::FieldWriter)
+/// and [`SnapshotAccess`](crate::SnapshotAccess) to `f64`. Field metadata
+/// (bounds, commands) and observation outputs stay `f32`.
+#[cfg(not(feature = "f64-fields"))]
+pub type Scalar = f32;
+
+/// Element type of field storage.
+///
+/// `f32` by default; the `f64-fields` feature switches every field buffer,
+/// [`FieldReader`](crate::FieldReader), [`FieldWriter`](crate::FieldWriter)
+/// and [`SnapshotAccess`](crate::SnapshotAccess) to `f64`. Field metadata
+/// (bounds, commands) and observation outputs stay `f32`.
+#[cfg(feature = "f64-fields")]
+pub type Scalar = f64;
+
+/// Narrow a field value to `f32` for an `f32` boundary (FFI, observations).
+///
+/// The identity without `f64-fields`.
+#[inline]
+#[allow(clippy::unnecessary_cast)]
+pub fn scalar_to_f32(value: Scalar) -> f32 {
+    value as f32
+}
+
+/// Widen a field value to `f64` for intermediate arithmetic.
+///
+/// Lossless in both builds; the identity under `f64-fields`.
+#[inline]
+#[allow(clippy::useless_conversion)]
+pub fn scalar_to_f64(value: Scalar) -> f64 {
+    f64::from(value)
+}
+
+/// Borrow field data as `f32` without copying, if `Scalar` is `f32`.
+///
+/// Returns `None` under `f64-fields`; callers at an `f32` boundary then
+/// convert through a staging buffer instead.
+#[cfg(not(feature = "f64-fields"))]
+#[inline]
+pub fn scalar_slice_as_f32(values: &[Scalar]) -> Option<&[f32]> {
+    Some(values)
+}
+
+/// Borrow field data as `f32` without copying, if `Scalar` is `f32`.
+///
+/// Returns `None` under `f64-fields`; callers at an `f32` boundary then
+/// convert through a staging buffer instead.
+#[cfg(feature = "f64-fields")]
+#[inline]
+pub fn scalar_slice_as_f32(_values: &[Scalar]) -> Option<&[f32]> {
+    None
+}
+
+/// Mutable counterpart of [`scalar_slice_as_f32`].
+#[cfg(not(feature = "f64-fields"))]
+#[inline]
+pub fn scalar_slice_as_f32_mut(values: &mut [Scalar]) -> Option<&mut [f32]> {
+    Some(values)
+}
+
+/// Mutable counterpart of [`scalar_slice_as_f32`].
+#[cfg(feature = "f64-fields")]
+#[inline]
+pub fn scalar_slice_as_f32_mut(_values: &mut [Scalar]) -> Option<&mut [f32]> {
+    None
+}
+
 /// Classification of a field's data type.
 ///
 /// # Examples
@@ -78,6 +148,83 @@ pub enum BoundaryBehavior {
     Wrap,
 }
 
+impl BoundaryBehavior {
+    /// Map `value` into `[min, max]` according to this behavior.
+    ///
+    /// In-range values and NaN are returned unchanged. Requires
+    /// `min <= max` (as checked by [`FieldDef::validate`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use murk_core::BoundaryBehavior;
+    ///
+    /// assert_eq!(BoundaryBehavior::Clamp.apply(12.0, 0.0, 10.0), 10.0);
+    /// assert_eq!(BoundaryBehavior::Reflect.apply(12.0, 0.0, 10.0), 8.0);
+    /// assert_eq!(BoundaryBehavior::Wrap.apply(12.0, 0.0, 10.0), 2.0);
+    /// assert_eq!(BoundaryBehavior::Absorb.apply(-3.0, 0.0, 10.0), 0.0);
+    /// ```
+    pub fn apply(self, value: Scalar, min: Scalar, max: Scalar) -> Scalar {
+        if !(value < min || value > max) {
+            return value;
+        }
+        let span = max - min;
+        match self {
+            Self::Clamp | Self::Absorb => value.clamp(min, max),
+            Self::Reflect if span > 0.0 => {
+                // Fold onto a sawtooth of period 2 * span.
+                let t = (value - min).rem_euclid(2.0 * span);
+                let folded = if t <= span { t } else { 2.0 * span - t };
+                (min + folded).clamp(min, max)
+            }
+            Self::Wrap if span > 0.0 => (min + (value - min).rem_euclid(span)).clamp(min, max),
+            // Degenerate range: every behavior collapses to the single bound.
+            Self::Reflect | Self::Wrap => min,
+        }
+    }
+}
+
+/// Declared value range of a field, applied through its [`BoundaryBehavior`].
+///
+/// A single `(min, max)` pair converts into [`Uniform`](Self::Uniform), so
+/// scalar fields keep the short form `bounds: Some((0.0, 1.0).into())`.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::FieldBounds;
+///
+/// let uniform: FieldBounds = (0.0, 1.0).into();
+/// assert_eq!(uniform.component(3), Some((0.0, 1.0)));
+///
+/// let velocity = FieldBounds::PerComponent(vec![(-1.0, 1.0), (-2.0, 2.0)]);
+/// assert_eq!(velocity.component(1), Some((-2.0, 2.0)));
+/// assert_eq!(velocity.component(2), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldBounds {
+    /// One `(min, max)` range shared by every component.
+    Uniform(f32, f32),
+    /// One `(min, max)` range per component, in component order.
+    PerComponent(Vec<(f32, f32)>),
+}
+
+impl FieldBounds {
+    /// Range of component `c`, or `None` if a per-component list is too short.
+    pub fn component(&self, c: usize) -> Option<(f32, f32)> {
+        match self {
+            Self::Uniform(min, max) => Some((*min, *max)),
+            Self::PerComponent(ranges) => ranges.get(c).copied(),
+        }
+    }
+}
+
+impl From<(f32, f32)> for FieldBounds {
+    fn from((min, max): (f32, f32)) -> Self {
+        Self::Uniform(min, max)
+    }
+}
+
 /// How a field's allocation is managed across ticks.
 ///
 /// # Examples
@@ -111,7 +258,7 @@ pub enum FieldMutability {
 /// # Examples
 ///
 /// ```
-/// use murk_core::{FieldDef, FieldType, FieldMutability, BoundaryBehavior};
+/// use murk_core::{BoundaryBehavior, FieldBounds, FieldDef, FieldMutability, FieldType};
 ///
 /// // A scalar field that is reallocated every tick.
 /// let heat = FieldDef {
@@ -119,9 +266,20 @@ pub enum FieldMutability {
 ///     field_type: FieldType::Scalar,
 ///     mutability: FieldMutability::PerTick,
 ///     units: Some("kelvin".into()),
-///     bounds: Some((0.0, 1000.0)),
+///     bounds: Some((0.0, 1000.0).into()),
+///     boundary_behavior: BoundaryBehavior::Clamp,
+/// };
+///
+/// // A 2D velocity with a tighter range on x than on y.
+/// let velocity = FieldDef {
+///     name: "velocity".into(),
+///     field_type: FieldType::Vector { dims: 2 },
+///     mutability: FieldMutability::PerTick,
+///     units: None,
+///     bounds: Some(FieldBounds::PerComponent(vec![(-1.0, 1.0), (-2.0, 2.0)])),
 ///     boundary_behavior: BoundaryBehavior::Clamp,
 /// };
+/// assert!(velocity.validate().is_ok());
 ///
 /// // A 3D velocity vector allocated once (static terrain data).
 /// let velocity = FieldDef {
@@ -143,9 +301,15 @@ pub struct FieldDef {
     pub mutability: FieldMutability,
     /// Optional unit annotation (e.g., `"meters/sec"`).
     pub units: Option<String>,
-    /// Optional `(min, max)` bounds for field values.
-    pub bounds: Option<(f32, f32)>,
+    /// Optional value bounds, shared by all components or per component.
+    ///
+    /// A plain `(min, max)` pair needs `.into()`: `Some((0.0, 1.0).into())`.
+    pub bounds: Option<FieldBounds>,
     /// Behavior when values exceed declared bounds.
+    ///
+    /// Only takes effect when `bounds` is set: the engine applies it to
+    /// every value a propagator writes to this field, independent of the
+    /// space's edge behavior.
     pub boundary_behavior: BoundaryBehavior,
 }
 
@@ -156,7 +320,8 @@ impl FieldDef {
     /// is violated. Checks:
     /// - `Vector { dims: 0 }` is rejected (zero components is meaningless).
     /// - `Categorical { n_values: 0 }` is rejected (zero categories is meaningless).
-    /// - If `bounds` is `Some((min, max))`, requires `min <= max` and both finite.
+    /// - Every declared `(min, max)` range requires `min <= max` and both finite.
+    /// - [`FieldBounds::PerComponent`] must list exactly one range per component.
     pub fn validate(&self) -> Result<(), String> {
         match self.field_type {
             FieldType::Vector { dims: 0 } => {
@@ -170,7 +335,23 @@ impl FieldDef {
             }
             _ => {}
         }
-        if let Some((min, max)) = self.bounds {
+        let ranges = match &self.bounds {
+            None => return Ok(()),
+            Some(FieldBounds::Uniform(min, max)) => vec![(*min, *max)],
+            Some(FieldBounds::PerComponent(ranges)) => {
+                let components = self.field_type.components() as usize;
+                if ranges.len() != components {
+                    return Err(format!(
+                        "field '{}': {} per-component bounds for {} components",
+                        self.name,
+                        ranges.len(),
+                        components
+                    ));
+                }
+                ranges.clone()
+            }
+        };
+        for (min, max) in ranges {
             if !min.is_finite() || !max.is_finite() {
                 return Err(format!(
                     "field '{}': bounds must be finite, got ({}, {})",
@@ -421,6 +602,32 @@ mod tests {
     use super::*;
     use proptest::prelude::*;
 
+    fn vector_def(dims: u32, bounds: FieldBounds) -> FieldDef {
+        FieldDef {
+            name: "v".into(),
+            field_type: FieldType::Vector { dims },
+            mutability: FieldMutability::PerTick,
+            units: None,
+            bounds: Some(bounds),
+            boundary_behavior: BoundaryBehavior::Clamp,
+        }
+    }
+
+    #[test]
+    fn per_component_bounds_validate_length_and_ranges() {
+        let ok = vector_def(2, FieldBounds::PerComponent(vec![(-1.0, 1.0), (-2.0, 2.0)]));
+        assert!(ok.validate().is_ok());
+        let short = vector_def(3, FieldBounds::PerComponent(vec![(-1.0, 1.0), (-2.0, 2.0)]));
+        assert!(short
+            .validate()
+            .unwrap_err()
+            .contains("2 per-component bounds"));
+        let inverted = vector_def(2, FieldBounds::PerComponent(vec![(-1.0, 1.0), (2.0, -2.0)]));
+        assert!(inverted.validate().is_err());
+        // A single range still applies to every component.
+        assert!(vector_def(3, (0.0, 1.0).into()).validate().is_ok());
+    }
+
     fn arb_field_set() -> impl Strategy<Value = FieldSet> {
         prop::collection::vec(0u32..128, 0..32)
             .prop_map(|ids| ids.into_iter().map(FieldId).collect::<FieldSet>())
@@ -521,5 +728,27 @@ mod tests {
         fn len_matches_iter_count(a in arb_field_set()) {
             prop_assert_eq!(a.len(), a.iter().count());
         }
+
+        #[test]
+        fn boundary_apply_stays_in_bounds(
+            value in -1.0e4f32..1.0e4,
+            min in -100.0f32..100.0,
+            span in 0.0f32..50.0,
+        ) {
+            let (value, min) = (Scalar::from(value), Scalar::from(min));
+            let max = min + Scalar::from(span);
+            for behavior in [
+                BoundaryBehavior::Clamp,
+                BoundaryBehavior::Reflect,
+                BoundaryBehavior::Absorb,
+                BoundaryBehavior::Wrap,
+            ] {
+                let v = behavior.apply(value, min, max);
+                prop_assert!((min..=max).contains(&v), "{behavior:?}: {value} -> {v}");
+                if (min..=max).contains(&value) {
+                    prop_assert_eq!(v, value);
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,73 @@
+//! Deterministic 64-bit FNV-1a hashing.
+//!
+//! Used wherever the workspace needs a stable digest of simulation state or
+//! layout (replay snapshot hashes, plan space digests, per-field hashes).
+//! Not cryptographically secure. Callers feed explicit little-endian bytes,
+//! so digests are identical across platforms and runs.
+
+/// FNV-1a offset basis for 64-bit.
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+/// FNV-1a prime for 64-bit.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Incremental 64-bit FNV-1a hasher.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::Fnv1a;
+///
+/// let mut a = Fnv1a::new();
+/// a.write(b"murk");
+/// let mut b = Fnv1a::new();
+/// b.write(b"mu");
+/// b.write(b"rk");
+/// assert_eq!(a.finish(), b.finish());
+/// assert_eq!(Fnv1a::new().finish(), 0xcbf29ce484222325);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fnv1a {
+    /// Create a hasher initialised with the FNV-1a offset basis.
+    pub const fn new() -> Self {
+        Self(FNV_OFFSET)
+    }
+
+    /// Feed `bytes` into the hash state.
+    #[inline]
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// The current digest.
+    pub const fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_reference_vectors() {
+        // Published FNV-1a 64-bit test vectors.
+        let digest = |bytes: &[u8]| {
+            let mut h = Fnv1a::new();
+            h.write(bytes);
+            h.finish()
+        };
+        assert_eq!(digest(b""), 0xcbf29ce484222325);
+        assert_eq!(digest(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(digest(b"foobar"), 0x85944171f73967e8);
+    }
+}
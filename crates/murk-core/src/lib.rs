@@ -11,15 +11,26 @@
 pub mod command;
 pub mod error;
 pub mod field;
+pub mod hash;
 pub mod id;
+pub mod parallel;
+pub mod parameter;
 pub mod traits;
+pub mod units;
 
 // Re-export core types at crate root for convenience.
 pub use command::{Command, CommandPayload, Receipt};
 pub use error::{IngressError, ObsError, PropagatorError, StepError};
-pub use field::{BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldSetIter, FieldType};
+pub use field::{
+    scalar_slice_as_f32, scalar_slice_as_f32_mut, scalar_to_f32, scalar_to_f64, BoundaryBehavior,
+    FieldBounds, FieldDef, FieldMutability, FieldSet, FieldSetIter, FieldType, Scalar,
+};
+pub use hash::Fnv1a;
 pub use id::{
     Coord, FieldId, ParameterKey, ParameterVersion, SpaceId, SpaceInstanceId, TickId,
     WorldGenerationId,
 };
+pub use parallel::ParallelConfig;
+pub use parameter::ParameterStore;
 pub use traits::{FieldReader, FieldWriter, SnapshotAccess};
+pub use units::Units;
@@ -0,0 +1,63 @@
+//! Thread-count configuration for parallel execution.
+//!
+//! Work is only ever split across independent units (whole worlds, disjoint
+//! snapshot chunks), never across a floating-point reduction, so results
+//! are bit-identical for any thread count.
+
+use std::num::NonZeroUsize;
+
+/// Thread-pool sizing.
+///
+/// `threads = 0` means "one thread per available CPU". The default is a
+/// single thread.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::ParallelConfig;
+///
+/// assert_eq!(ParallelConfig::default().resolved_threads(), 1);
+/// assert!(ParallelConfig { threads: 0 }.resolved_threads() >= 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallelConfig {
+    /// Worker thread count. `0` resolves to the number of available CPUs.
+    pub threads: usize,
+}
+
+impl Default for ParallelConfig {
+    fn default() -> Self {
+        Self { threads: 1 }
+    }
+}
+
+impl ParallelConfig {
+    /// Effective thread count, resolving `0` to the available CPUs.
+    pub fn resolved_threads(&self) -> usize {
+        match self.threads {
+            0 => std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            n => n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_single_threaded() {
+        assert_eq!(ParallelConfig::default().resolved_threads(), 1);
+    }
+
+    #[test]
+    fn zero_threads_resolves_to_at_least_one() {
+        let cfg = ParallelConfig { threads: 0 };
+        assert!(cfg.resolved_threads() >= 1);
+    }
+
+    #[test]
+    fn explicit_thread_count_is_kept() {
+        assert_eq!(ParallelConfig { threads: 8 }.resolved_threads(), 8);
+    }
+}
@@ -0,0 +1,73 @@
+//! Global simulation parameters set via `SetParameter` commands.
+
+use std::collections::BTreeMap;
+
+use crate::id::ParameterKey;
+
+/// Key-value store of global simulation parameters.
+///
+/// Populated by `SetParameter` and `SetParameterBatch` commands. The engine
+/// applies parameter commands at the tick boundary, so the store seen by
+/// propagators is frozen for the duration of a tick. Keys iterate in
+/// ascending order, keeping any derived computation deterministic.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::{ParameterKey, ParameterStore};
+///
+/// let mut params = ParameterStore::new();
+/// assert_eq!(params.get(ParameterKey(0)), None);
+///
+/// params.set(ParameterKey(0), 0.25);
+/// assert_eq!(params.get(ParameterKey(0)), Some(0.25));
+/// assert_eq!(params.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParameterStore {
+    values: BTreeMap<ParameterKey, f64>,
+}
+
+impl ParameterStore {
+    /// Create an empty parameter store.
+    pub const fn new() -> Self {
+        Self {
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Current value of `key`, or `None` if it has never been set.
+    pub fn get(&self, key: ParameterKey) -> Option<f64> {
+        self.values.get(&key).copied()
+    }
+
+    /// Set `key` to `value`, returning the previous value if any.
+    pub fn set(&mut self, key: ParameterKey, value: f64) -> Option<f64> {
+        self.values.insert(key, value)
+    }
+
+    /// Whether `key` has been set.
+    pub fn contains(&self, key: ParameterKey) -> bool {
+        self.values.contains_key(&key)
+    }
+
+    /// Number of parameters that have been set.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether no parameters have been set.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterate over `(key, value)` pairs in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (ParameterKey, f64)> + '_ {
+        self.values.iter().map(|(&k, &v)| (k, v))
+    }
+
+    /// Remove all parameters.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
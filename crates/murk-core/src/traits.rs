@@ -1,6 +1,7 @@
 //! Core abstraction traits for field access and snapshot reading.
 
-use crate::id::{FieldId, ParameterVersion, TickId, WorldGenerationId};
+use crate::field::Scalar;
+use crate::id::{FieldId, ParameterKey, ParameterVersion, TickId, WorldGenerationId};
 
 /// Read-only access to field data within a simulation step.
 ///
@@ -8,10 +9,10 @@ use crate::id::{FieldId, ParameterVersion, TickId, WorldGenerationId};
 /// to field buffers. Returns `None` if the field is not readable in
 /// the current context.
 pub trait FieldReader {
-    /// Read the data for a field as a flat f32 slice.
+    /// Read the data for a field as a flat [`Scalar`] slice.
     ///
     /// Returns `None` if the field ID is invalid or not readable.
-    fn read(&self, field: FieldId) -> Option<&[f32]>;
+    fn read(&self, field: FieldId) -> Option<&[Scalar]>;
 }
 
 /// Mutable access to field data within a simulation step.
@@ -23,7 +24,7 @@ pub trait FieldWriter {
     /// Get a mutable slice for writing field data.
     ///
     /// Returns `None` if the field ID is invalid or not writable.
-    fn write(&mut self, field: FieldId) -> Option<&mut [f32]>;
+    fn write(&mut self, field: FieldId) -> Option<&mut [Scalar]>;
 }
 
 /// Read-only access to a published snapshot.
@@ -35,7 +36,7 @@ pub trait SnapshotAccess {
     /// Read field data from the snapshot.
     ///
     /// Returns `None` if the field ID is invalid or not present.
-    fn read_field(&self, field: FieldId) -> Option<&[f32]>;
+    fn read_field(&self, field: FieldId) -> Option<&[Scalar]>;
 
     /// The tick at which this snapshot was produced.
     fn tick_id(&self) -> TickId;
@@ -45,4 +46,12 @@ pub trait SnapshotAccess {
 
     /// The parameter version at the time of this snapshot.
     fn parameter_version(&self) -> ParameterVersion;
+
+    /// Value of the global parameter `key` as of this snapshot's tick.
+    ///
+    /// Returns `None` if the parameter was never set or the snapshot does
+    /// not carry parameters (the default).
+    fn parameter(&self, _key: ParameterKey) -> Option<f32> {
+        None
+    }
 }
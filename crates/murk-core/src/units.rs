@@ -0,0 +1,263 @@
+//! Physical units for [`FieldDef::units`](crate::FieldDef::units) annotations.
+//!
+//! Field units are free-form strings; [`Units`] recognises the common ones
+//! so that spellings like `"K"` and `"kelvin"` compare equal and values can
+//! be converted between scales of the same dimension.
+
+use std::fmt;
+
+/// Physical dimension of a [`Units`] value.
+///
+/// Conversion is only defined between units of the same dimension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    /// Pure number (ratios, probabilities, counts).
+    Dimensionless,
+    /// Length.
+    Length,
+    /// Time.
+    Time,
+    /// Speed (length per time).
+    Speed,
+    /// Thermodynamic temperature.
+    Temperature,
+}
+
+/// A recognised physical unit.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::units::{convert, Units};
+///
+/// let c = Units::parse("celsius").unwrap();
+/// assert_eq!(Units::parse("K"), Some(Units::Kelvin));
+/// assert_eq!(convert(0.0, c, Units::Kelvin), Some(273.15));
+/// assert_eq!(convert(1.0, c, Units::Meters), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Units {
+    /// No unit.
+    Dimensionless,
+    /// Metres (`m`).
+    Meters,
+    /// Kilometres (`km`).
+    Kilometers,
+    /// Seconds (`s`).
+    Seconds,
+    /// Milliseconds (`ms`).
+    Milliseconds,
+    /// Metres per second (`m/s`).
+    MetersPerSecond,
+    /// Kilometres per hour (`km/h`).
+    KilometersPerHour,
+    /// Kelvin (`K`).
+    Kelvin,
+    /// Degrees Celsius (`degC`).
+    Celsius,
+    /// Degrees Fahrenheit (`degF`).
+    Fahrenheit,
+}
+
+impl Units {
+    /// Parse a unit annotation, ignoring ASCII case for spelled-out names.
+    ///
+    /// Returns `None` for unrecognised strings.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        // Symbols are case-sensitive ("K" is kelvin, "k" is not a unit).
+        let by_symbol = match s {
+            "" | "1" => Some(Self::Dimensionless),
+            "m" => Some(Self::Meters),
+            "km" => Some(Self::Kilometers),
+            "s" => Some(Self::Seconds),
+            "ms" => Some(Self::Milliseconds),
+            "m/s" => Some(Self::MetersPerSecond),
+            "km/h" => Some(Self::KilometersPerHour),
+            "K" => Some(Self::Kelvin),
+            "degC" | "°C" => Some(Self::Celsius),
+            "degF" | "°F" => Some(Self::Fahrenheit),
+            _ => None,
+        };
+        if by_symbol.is_some() {
+            return by_symbol;
+        }
+        match s.to_ascii_lowercase().as_str() {
+            "dimensionless" | "none" => Some(Self::Dimensionless),
+            "meter" | "meters" | "metre" | "metres" => Some(Self::Meters),
+            "kilometer" | "kilometers" | "kilometre" | "kilometres" => Some(Self::Kilometers),
+            "second" | "seconds" => Some(Self::Seconds),
+            "millisecond" | "milliseconds" => Some(Self::Milliseconds),
+            "meters/sec" | "meters/second" | "metres/second" => Some(Self::MetersPerSecond),
+            "kph" | "kilometers/hour" | "kilometres/hour" => Some(Self::KilometersPerHour),
+            "kelvin" => Some(Self::Kelvin),
+            "celsius" => Some(Self::Celsius),
+            "fahrenheit" => Some(Self::Fahrenheit),
+            _ => None,
+        }
+    }
+
+    /// Canonical symbol, as accepted by [`parse`](Self::parse).
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Dimensionless => "1",
+            Self::Meters => "m",
+            Self::Kilometers => "km",
+            Self::Seconds => "s",
+            Self::Milliseconds => "ms",
+            Self::MetersPerSecond => "m/s",
+            Self::KilometersPerHour => "km/h",
+            Self::Kelvin => "K",
+            Self::Celsius => "degC",
+            Self::Fahrenheit => "degF",
+        }
+    }
+
+    /// Physical dimension of this unit.
+    pub fn dimension(self) -> Dimension {
+        match self {
+            Self::Dimensionless => Dimension::Dimensionless,
+            Self::Meters | Self::Kilometers => Dimension::Length,
+            Self::Seconds | Self::Milliseconds => Dimension::Time,
+            Self::MetersPerSecond | Self::KilometersPerHour => Dimension::Speed,
+            Self::Kelvin | Self::Celsius | Self::Fahrenheit => Dimension::Temperature,
+        }
+    }
+
+    /// `(scale, offset)` such that `base = value * scale + offset`, where
+    /// the base unit is the SI unit of this dimension.
+    fn to_base(self) -> (f64, f64) {
+        match self {
+            Self::Dimensionless
+            | Self::Meters
+            | Self::Seconds
+            | Self::MetersPerSecond
+            | Self::Kelvin => (1.0, 0.0),
+            Self::Kilometers => (1000.0, 0.0),
+            Self::Milliseconds => (0.001, 0.0),
+            Self::KilometersPerHour => (1.0 / 3.6, 0.0),
+            Self::Celsius => (1.0, 273.15),
+            Self::Fahrenheit => (5.0 / 9.0, 273.15 - 32.0 * 5.0 / 9.0),
+        }
+    }
+}
+
+impl fmt::Display for Units {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// Convert `value` from one unit to another.
+///
+/// Returns `None` if the units have different [`Dimension`]s.
+pub fn convert(value: f64, from: Units, to: Units) -> Option<f64> {
+    if from.dimension() != to.dimension() {
+        return None;
+    }
+    if from == to {
+        return Some(value);
+    }
+    let (from_scale, from_offset) = from.to_base();
+    let (to_scale, to_offset) = to.to_base();
+    let base = value * from_scale + from_offset;
+    Some((base - to_offset) / to_scale)
+}
+
+/// Whether two optional unit annotations are compatible.
+///
+/// Unannotated fields (`None`) are compatible with anything. Recognised
+/// units must be identical; unrecognised strings must match exactly.
+pub fn units_compatible(a: Option<&str>, b: Option<&str>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => match (Units::parse(a), Units::parse(b)) {
+            (Some(ua), Some(ub)) => ua == ub,
+            _ => a.trim() == b.trim(),
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn parse_symbols_and_names() {
+        assert_eq!(Units::parse("K"), Some(Units::Kelvin));
+        assert_eq!(Units::parse("Kelvin"), Some(Units::Kelvin));
+        assert_eq!(Units::parse("degC"), Some(Units::Celsius));
+        assert_eq!(Units::parse(" meters/sec "), Some(Units::MetersPerSecond));
+        assert_eq!(Units::parse("k"), None);
+        assert_eq!(Units::parse("furlongs"), None);
+    }
+
+    #[test]
+    fn symbol_roundtrips() {
+        for u in [
+            Units::Dimensionless,
+            Units::Meters,
+            Units::Kilometers,
+            Units::Seconds,
+            Units::Milliseconds,
+            Units::MetersPerSecond,
+            Units::KilometersPerHour,
+            Units::Kelvin,
+            Units::Celsius,
+            Units::Fahrenheit,
+        ] {
+            assert_eq!(Units::parse(u.symbol()), Some(u));
+        }
+    }
+
+    #[test]
+    fn convert_temperatures() {
+        assert!(close(
+            convert(0.0, Units::Celsius, Units::Kelvin).unwrap(),
+            273.15
+        ));
+        assert!(close(
+            convert(212.0, Units::Fahrenheit, Units::Celsius).unwrap(),
+            100.0
+        ));
+        assert!(close(
+            convert(300.0, Units::Kelvin, Units::Kelvin).unwrap(),
+            300.0
+        ));
+    }
+
+    #[test]
+    fn convert_scaled_units() {
+        assert!(close(
+            convert(2.5, Units::Kilometers, Units::Meters).unwrap(),
+            2500.0
+        ));
+        assert!(close(
+            convert(36.0, Units::KilometersPerHour, Units::MetersPerSecond).unwrap(),
+            10.0
+        ));
+        assert!(close(
+            convert(1500.0, Units::Milliseconds, Units::Seconds).unwrap(),
+            1.5
+        ));
+    }
+
+    #[test]
+    fn convert_across_dimensions_is_none() {
+        assert_eq!(convert(1.0, Units::Kelvin, Units::Meters), None);
+        assert_eq!(convert(1.0, Units::Seconds, Units::MetersPerSecond), None);
+    }
+
+    #[test]
+    fn compatibility_rules() {
+        assert!(units_compatible(Some("K"), Some("kelvin")));
+        assert!(!units_compatible(Some("celsius"), Some("kelvin")));
+        assert!(units_compatible(None, Some("kelvin")));
+        assert!(units_compatible(Some("widgets"), Some("widgets")));
+        assert!(!units_compatible(Some("widgets"), Some("gadgets")));
+    }
+}
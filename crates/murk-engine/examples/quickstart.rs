@@ -18,7 +18,7 @@
 
 use murk_core::{
     BoundaryBehavior, Command, CommandPayload, FieldDef, FieldId, FieldMutability, FieldReader,
-    FieldSet, FieldType, PropagatorError, SnapshotAccess, TickId,
+    FieldSet, FieldType, PropagatorError, Scalar, SnapshotAccess, TickId,
 };
 use murk_engine::{LockstepWorld, WorldConfig};
 use murk_propagator::{Propagator, StepContext, WriteMode};
@@ -97,8 +97,8 @@ impl Propagator for DiffusionPropagator {
 
         // Copy into local buffers — we need random access while
         // holding the mutable write buffer (split-borrow limitation).
-        let prev: Vec<f32> = prev_heat.to_vec();
-        let source: Vec<f32> = heat_source.to_vec();
+        let prev: Vec<Scalar> = prev_heat.to_vec();
+        let source: Vec<Scalar> = heat_source.to_vec();
         let dt = ctx.dt();
 
         let out = ctx
@@ -149,7 +149,7 @@ impl Propagator for DiffusionPropagator {
                 };
 
                 let laplacian = n + s + e + w - 4.0 * prev[idx];
-                let new_val = prev[idx] + (DIFFUSION * dt) as f32 * laplacian;
+                let new_val = prev[idx] + (DIFFUSION * dt) as Scalar * laplacian;
                 out[idx] = new_val.max(0.0);
             }
         }
@@ -223,8 +223,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         if tick % 10 == 0 {
             let heat = result.snapshot.read(HEAT).unwrap();
-            let mean: f32 = heat.iter().sum::<f32>() / CELL_COUNT as f32;
-            let max: f32 = heat.iter().cloned().fold(0.0_f32, f32::max);
+            let mean: Scalar = heat.iter().sum::<Scalar>() / CELL_COUNT as Scalar;
+            let max: Scalar = heat.iter().cloned().fold(0.0, Scalar::max);
             println!(
                 "  tick {:>3}: mean_heat={:.4}, max_heat={:.4}, time={}μs",
                 tick, mean, max, result.metrics.total_us,
@@ -250,6 +250,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             value: 10.0,
         },
         expires_after_tick: TickId(u64::MAX),
+        expires_at: None,
         source_id: None,
         source_seq: None,
         priority_class: 1,
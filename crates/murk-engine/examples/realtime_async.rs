@@ -29,7 +29,7 @@ use std::time::Duration;
 
 use murk_core::{
     BoundaryBehavior, Command, CommandPayload, FieldDef, FieldId, FieldMutability, FieldSet,
-    FieldType, PropagatorError, SnapshotAccess, TickId,
+    FieldType, PropagatorError, Scalar, SnapshotAccess, TickId,
 };
 use murk_engine::{AsyncConfig, RealtimeAsyncWorld, WorldConfig};
 use murk_obs::{ObsDtype, ObsEntry, ObsPlan, ObsRegion, ObsSpec, ObsTransform};
@@ -87,7 +87,7 @@ impl Propagator for DiffusionPropagator {
                 .ok_or_else(|| PropagatorError::ExecutionFailed {
                     reason: "heat field not readable".into(),
                 })?;
-        let prev: Vec<f32> = prev_heat.to_vec();
+        let prev: Vec<Scalar> = prev_heat.to_vec();
         let dt = ctx.dt();
 
         let out = ctx
@@ -128,7 +128,7 @@ impl Propagator for DiffusionPropagator {
                 };
 
                 let laplacian = n + s + e + w - 4.0 * prev[idx];
-                let new_val = prev[idx] + (DIFFUSION * dt) as f32 * laplacian;
+                let new_val = prev[idx] + (DIFFUSION * dt) as Scalar * laplacian;
                 out[idx] = new_val.max(0.0);
             }
         }
@@ -218,7 +218,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             pool: None,
             transform: ObsTransform::Identity,
             dtype: ObsDtype::F32,
+            tick_offset: 0,
         }],
+        pad_value: 0.0,
     };
     let plan_result = ObsPlan::compile(&spec, world.space())?;
     let plan = Arc::new(plan_result.plan);
@@ -269,6 +271,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             value: 10.0,
         },
         expires_after_tick: TickId(u64::MAX),
+        expires_at: None,
         source_id: None,
         source_seq: None,
         priority_class: 1,
@@ -11,7 +11,7 @@
 
 use murk_core::{
     BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldReader, FieldSet, FieldType,
-    PropagatorError, SnapshotAccess,
+    PropagatorError, Scalar, SnapshotAccess,
 };
 use murk_engine::{LockstepWorld, WorldConfig};
 use murk_propagator::{Propagator, StepContext, WriteMode};
@@ -67,7 +67,7 @@ impl Propagator for DiffusionPropagator {
                     reason: "heat field not readable".into(),
                 })?;
 
-        let prev: Vec<f32> = prev_heat.to_vec();
+        let prev: Vec<Scalar> = prev_heat.to_vec();
         let dt = ctx.dt();
 
         let out = ctx
@@ -108,7 +108,7 @@ impl Propagator for DiffusionPropagator {
                 };
 
                 let laplacian = n + s + e + w - 4.0 * prev[idx];
-                let new_val = prev[idx] + (DIFFUSION * dt) as f32 * laplacian;
+                let new_val = prev[idx] + (DIFFUSION * dt) as Scalar * laplacian;
                 out[idx] = new_val.max(0.0);
             }
         }
@@ -196,7 +196,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         if tick % 10 == 0 || tick == 1 {
             let heat = result.snapshot.read(HEAT).unwrap();
-            let max: f32 = heat.iter().cloned().fold(0.0_f32, f32::max);
+            let max: Scalar = heat.iter().cloned().fold(0.0, Scalar::max);
             let hash = snapshot_hash(&result.snapshot as &dyn SnapshotAccess, 1);
             println!(
                 "  tick {:>3}: max_heat={:.4}, hash={:#018x}",
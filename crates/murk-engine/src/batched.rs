@@ -7,17 +7,20 @@
 //!
 //! # Design
 //!
-//! The hot path is `step_and_observe`: step all worlds sequentially, then
-//! extract observations in batch. The GIL is released once at the Python
-//! layer, covering the entire operation. This reduces 2N GIL cycles (the
-//! current `MurkVecEnv` approach) to exactly 1.
+//! The hot path is `step_and_observe`: step all worlds, then extract
+//! observations in batch. The GIL is released once at the Python layer,
+//! covering the entire operation. This reduces 2N GIL cycles (the current
+//! `MurkVecEnv` approach) to exactly 1.
 //!
-//! Parallelism (rayon) is deferred to v2. The GIL elimination alone is
-//! the dominant win; adding `par_iter_mut` later is a 3-line change.
+//! Worlds are stepped sequentially unless the first world's
+//! [`ParallelConfig`] asks for more than one thread, in which case they are
+//! split into contiguous groups stepped on scoped threads. Worlds share no
+//! state, so per-world results are identical for any thread count.
 
 use murk_core::command::Command;
 use murk_core::error::ObsError;
 use murk_core::id::TickId;
+use murk_core::parallel::ParallelConfig;
 use murk_core::traits::SnapshotAccess;
 use murk_obs::metadata::ObsMetadata;
 use murk_obs::plan::ObsPlan;
@@ -97,6 +100,21 @@ pub struct BatchResult {
 
 // ── BatchedEngine ───────────────────────────────────────────────
 
+/// Step one world, annotating any failure with its batch index.
+fn step_world(
+    world: &mut LockstepWorld,
+    idx: usize,
+    commands: &[Command],
+) -> Result<(TickId, StepMetrics), BatchError> {
+    let result = world
+        .step_sync(commands.to_vec())
+        .map_err(|e| BatchError::Step {
+            world_index: idx,
+            error: e,
+        })?;
+    Ok((result.snapshot.tick_id(), result.metrics))
+}
+
 /// Batched simulation engine owning N lockstep worlds.
 ///
 /// Created from N [`WorldConfig`]s with an optional [`ObsSpec`].
@@ -111,6 +129,7 @@ pub struct BatchedEngine {
     obs_plan: Option<ObsPlan>,
     obs_output_len: usize,
     obs_mask_len: usize,
+    parallel: ParallelConfig,
 }
 
 impl BatchedEngine {
@@ -118,7 +137,9 @@ impl BatchedEngine {
     ///
     /// If `obs_spec` is provided, compiles an [`ObsPlan`] from the first
     /// world's space. All worlds must have the same `cell_count`
-    /// (defensive check).
+    /// (defensive check). The thread count used by
+    /// [`step_all()`](Self::step_all) is taken from the first config's
+    /// [`WorldConfig::parallel()`].
     ///
     /// # Errors
     ///
@@ -131,6 +152,7 @@ impl BatchedEngine {
             });
         }
 
+        let parallel = *configs[0].parallel();
         let mut worlds = Vec::with_capacity(configs.len());
         for config in configs {
             let world = LockstepWorld::new(config).map_err(BatchError::Config)?;
@@ -157,12 +179,14 @@ impl BatchedEngine {
         let (obs_plan, obs_output_len, obs_mask_len) = match obs_spec {
             Some(spec) => {
                 let result =
-                    ObsPlan::compile(spec, worlds[0].space()).map_err(BatchError::Observe)?;
+                    ObsPlan::compile_with_fields(spec, worlds[0].space(), worlds[0].field_defs())
+                        .map_err(BatchError::Observe)?;
 
                 // Validate all worlds have matching field schemas for observed fields.
-                // ObsPlan::compile only takes a Space (not a snapshot), so field
-                // existence isn't checked until execute(). Catching mismatches here
-                // prevents late observation failures after worlds have been stepped.
+                // The plan is compiled against world 0's space and field
+                // definitions only (not a snapshot), so field existence isn't
+                // checked until execute(). Catching mismatches here prevents
+                // late observation failures after worlds have been stepped.
                 let ref_snap = worlds[0].snapshot();
                 for entry in &spec.entries {
                     let fid = entry.field_id;
@@ -210,6 +234,7 @@ impl BatchedEngine {
             obs_plan,
             obs_output_len,
             obs_mask_len,
+            parallel,
         })
     }
 
@@ -241,6 +266,10 @@ impl BatchedEngine {
     }
 
     /// Step all worlds without observation extraction.
+    ///
+    /// Every world is stepped even if one fails, so the post-call state of
+    /// the batch does not depend on the thread count. The error reported
+    /// is that of the lowest failing world index.
     pub fn step_all(&mut self, commands: &[Vec<Command>]) -> Result<BatchResult, BatchError> {
         let n = self.worlds.len();
         if commands.len() != n {
@@ -249,20 +278,47 @@ impl BatchedEngine {
             });
         }
 
+        let threads = self.parallel.resolved_threads().min(n);
+        let results: Vec<Result<(TickId, StepMetrics), BatchError>> = if threads <= 1 {
+            self.worlds
+                .iter_mut()
+                .zip(commands)
+                .enumerate()
+                .map(|(idx, (world, c))| step_world(world, idx, c))
+                .collect()
+        } else {
+            let per_thread = n.div_ceil(threads);
+            std::thread::scope(|s| {
+                let handles: Vec<_> = self
+                    .worlds
+                    .chunks_mut(per_thread)
+                    .zip(commands.chunks(per_thread))
+                    .enumerate()
+                    .map(|(group, (worlds, cmds))| {
+                        s.spawn(move || {
+                            worlds
+                                .iter_mut()
+                                .zip(cmds)
+                                .enumerate()
+                                .map(|(i, (world, c))| step_world(world, group * per_thread + i, c))
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().expect("batched step worker panicked"))
+                    .collect()
+            })
+        };
+
         let mut tick_ids = Vec::with_capacity(n);
         let mut metrics = Vec::with_capacity(n);
-
-        for (idx, world) in self.worlds.iter_mut().enumerate() {
-            let result = world
-                .step_sync(commands[idx].clone())
-                .map_err(|e| BatchError::Step {
-                    world_index: idx,
-                    error: e,
-                })?;
-            tick_ids.push(result.snapshot.tick_id());
-            metrics.push(result.metrics);
+        for result in results {
+            let (tick_id, m) = result?;
+            tick_ids.push(tick_id);
+            metrics.push(m);
         }
-
         Ok(BatchResult { tick_ids, metrics })
     }
 
@@ -371,6 +427,7 @@ impl BatchedEngine {
 mod tests {
     use super::*;
     use murk_core::id::FieldId;
+    use murk_core::{scalar_to_f32, Scalar};
     use murk_core::traits::FieldReader;
     use murk_obs::spec::{ObsDtype, ObsEntry, ObsRegion, ObsTransform};
     use murk_space::{EdgeBehavior, Line1D, RegionSpec, Square4};
@@ -387,7 +444,7 @@ mod tests {
         }
     }
 
-    fn make_config(seed: u64, value: f32) -> WorldConfig {
+    fn make_config(seed: u64, value: Scalar) -> WorldConfig {
         WorldConfig::builder()
             .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
             .fields(vec![scalar_field("energy")])
@@ -398,7 +455,7 @@ mod tests {
             .unwrap()
     }
 
-    fn make_grid_config(seed: u64, value: f32) -> WorldConfig {
+    fn make_grid_config(seed: u64, value: Scalar) -> WorldConfig {
         WorldConfig::builder()
             .space(Box::new(Square4::new(4, 4, EdgeBehavior::Absorb).unwrap()))
             .fields(vec![scalar_field("energy")])
@@ -417,7 +474,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         }
     }
 
@@ -480,12 +539,24 @@ mod tests {
         let r0 = w0.step_sync(vec![]).unwrap();
         let r1 = w1.step_sync(vec![]).unwrap();
 
-        let d0 = r0.snapshot.read(FieldId(0)).unwrap();
-        let d1 = r1.snapshot.read(FieldId(0)).unwrap();
+        let d0: Vec<f32> = r0
+            .snapshot
+            .read(FieldId(0))
+            .unwrap()
+            .iter()
+            .map(|&v| scalar_to_f32(v))
+            .collect();
+        let d1: Vec<f32> = r1
+            .snapshot
+            .read(FieldId(0))
+            .unwrap()
+            .iter()
+            .map(|&v| scalar_to_f32(v))
+            .collect();
 
         // Batch output should be [world0_obs | world1_obs]
-        assert_eq!(&batch_output[..10], d0);
-        assert_eq!(&batch_output[10..20], d1);
+        assert_eq!(&batch_output[..10], &d0[..]);
+        assert_eq!(&batch_output[10..20], &d1[..]);
     }
 
     // ── Observation correctness ───────────────────────────────
@@ -710,11 +781,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![1, 1],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let configs = vec![make_grid_config(0, 1.0), make_grid_config(1, 1.0)];
         let mut engine = BatchedEngine::new(configs, Some(&spec)).unwrap();
@@ -751,6 +825,7 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
                 ObsEntry {
                     field_id: FieldId(1), // missing in both worlds
@@ -758,8 +833,10 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
             ],
+            pad_value: 0.0,
         };
 
         // Both worlds only have FieldId(0)
@@ -791,7 +868,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
 
         let configs = vec![make_config(1, 1.0)]; // only has FieldId(0)
@@ -814,6 +893,7 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
                 ObsEntry {
                     field_id: FieldId(1),
@@ -821,8 +901,10 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
             ],
+            pad_value: 0.0,
         };
 
         // World 0: has 2 fields (FieldId(0) and FieldId(1))
@@ -857,4 +939,89 @@ mod tests {
             Ok(_) => panic!("expected error for mismatched field schemas"),
         }
     }
+
+    /// Diffusing heat seeded differently per world.
+    fn diffusion_config(world: usize, parallel: ParallelConfig) -> WorldConfig {
+        WorldConfig::builder()
+            .space(Box::new(Square4::new(16, 16, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("heat")])
+            .propagators(vec![Box::new(
+                murk_propagators::ScalarDiffusion::builder()
+                    .input_field(FieldId(0))
+                    .output_field(FieldId(0))
+                    .coefficient(0.2)
+                    .source(world * 7 % 256, 50.0)
+                    .build()
+                    .unwrap(),
+            )])
+            .initializer(FieldId(0), move |c| {
+                ((c[0] * 31 + c[1] * 17 + world as i32) % 13) as Scalar * 0.37
+            })
+            .parallel(parallel)
+            .dt(0.1)
+            .seed(world as u64)
+            .build()
+            .unwrap()
+    }
+
+    fn run_hashes(threads: usize) -> Vec<u64> {
+        let parallel = ParallelConfig { threads };
+        let configs = (0..12).map(|w| diffusion_config(w, parallel)).collect();
+        let mut engine = BatchedEngine::new(configs, None).unwrap();
+        let commands = vec![vec![]; 12];
+        for _ in 0..20 {
+            engine.step_all(&commands).unwrap();
+        }
+        engine
+            .worlds
+            .iter()
+            .map(|w| murk_replay::hash::snapshot_hash(&w.snapshot(), 1))
+            .collect()
+    }
+
+    #[test]
+    fn snapshot_hashes_independent_of_thread_count() {
+        let sequential = run_hashes(1);
+        assert_eq!(run_hashes(8), sequential);
+        assert_eq!(run_hashes(5), sequential);
+        // Worlds were seeded differently, so the hashes must differ.
+        assert_ne!(sequential[0], sequential[1]);
+    }
+
+    /// Worlds 1 and 3 fail on their first step; the rest are healthy.
+    fn partly_failing_engine(threads: usize) -> BatchedEngine {
+        let parallel = ParallelConfig { threads };
+        let configs = (0..4)
+            .map(|w| {
+                let prop: Box<dyn murk_propagator::Propagator> = if w == 1 || w == 3 {
+                    Box::new(murk_test_utils::FailingPropagator::new("fail", FieldId(0), 0))
+                } else {
+                    Box::new(ConstPropagator::new("const", FieldId(0), 1.0))
+                };
+                WorldConfig::builder()
+                    .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+                    .fields(vec![scalar_field("energy")])
+                    .propagators(vec![prop])
+                    .parallel(parallel)
+                    .dt(0.1)
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        BatchedEngine::new(configs, None).unwrap()
+    }
+
+    #[test]
+    fn step_reports_lowest_failing_world_for_any_thread_count() {
+        for threads in [1, 2, 4] {
+            let mut engine = partly_failing_engine(threads);
+            match engine.step_all(&vec![vec![]; 4]) {
+                Err(BatchError::Step { world_index, .. }) => assert_eq!(world_index, 1),
+                other => panic!("expected step error, got {:?}", other.err()),
+            }
+            // Healthy worlds after the first failure were still stepped.
+            assert_eq!(engine.world_tick(0), Some(TickId(1)), "threads = {threads}");
+            assert_eq!(engine.world_tick(2), Some(TickId(1)), "threads = {threads}");
+        }
+    }
 }
@@ -11,10 +11,15 @@
 
 use std::error::Error;
 use std::fmt;
+use std::time::Duration;
 
+use indexmap::IndexMap;
 use murk_arena::ArenaError;
-use murk_core::{FieldDef, FieldId, FieldSet};
-use murk_propagator::{validate_pipeline, PipelineError, Propagator};
+use murk_core::units::units_compatible;
+use murk_core::{
+    Coord, FieldDef, FieldId, FieldMutability, FieldSet, ParallelConfig, PropagatorError, Scalar,
+};
+use murk_propagator::{validate_pipeline, validate_static_writes, PipelineError, Propagator};
 use murk_space::Space;
 
 // ── BackoffConfig ──────────────────────────────────────────────────
@@ -36,6 +41,45 @@ pub struct BackoffConfig {
     pub decay_rate: u64,
     /// Fraction of rejected commands that triggers proactive backoff. Default: 0.20.
     pub rejection_rate_threshold: f64,
+    /// How the realtime tick thread waits out the rest of each tick
+    /// budget. Default: [`BackoffStrategy::default()`].
+    pub strategy: BackoffStrategy,
+}
+
+/// How the [`RealtimeAsyncWorld`](crate::realtime::RealtimeAsyncWorld) tick
+/// thread waits between ticks once the current tick's work is done.
+///
+/// Every strategy wakes promptly on shutdown. Busier strategies trade CPU
+/// (and battery) for lower tick-start jitter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackoffStrategy {
+    /// Busy-wait until the next tick. Lowest jitter, one core at 100%.
+    Spin,
+    /// Repeatedly yield the CPU to other runnable threads until the next
+    /// tick.
+    Yield,
+    /// Park the thread in slices of the remaining budget clamped to
+    /// `[min, max]`. A nonzero `min` coarsens wakeups (and may overshoot
+    /// the tick deadline) to save power; a finite `max` bounds each park.
+    Sleep {
+        /// Shortest park.
+        min: Duration,
+        /// Longest park.
+        max: Duration,
+    },
+    /// Spin briefly, then yield, then park for exponentially growing
+    /// slices, restarting the ramp after every tick.
+    Adaptive,
+}
+
+impl Default for BackoffStrategy {
+    /// `Sleep` with no bounds: park once for the whole remaining budget.
+    fn default() -> Self {
+        Self::Sleep {
+            min: Duration::ZERO,
+            max: Duration::MAX,
+        }
+    }
 }
 
 impl Default for BackoffConfig {
@@ -46,6 +90,7 @@ impl Default for BackoffConfig {
             max_skew_cap: 10,
             decay_rate: 60,
             rejection_rate_threshold: 0.20,
+            strategy: BackoffStrategy::default(),
         }
     }
 }
@@ -97,6 +142,40 @@ impl AsyncConfig {
     }
 }
 
+// ── ConservationConfig ────────────────────────────────────────────
+
+/// What the conservation audit does when a propagator's totals drift.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConservationAction {
+    /// Commit the tick and report each violation in the tick's
+    /// `soft_failures`. The default.
+    #[default]
+    Report,
+    /// Roll the tick back with the first violation, counting towards the
+    /// consecutive-rollback limit.
+    Rollback,
+}
+
+/// Tolerance and reaction of the conservation audit enabled by
+/// [`WorldConfigBuilder::conservation_check`].
+#[derive(Clone, Debug)]
+pub struct ConservationConfig {
+    /// Allowed change in a conserved field's total, relative to the sum of
+    /// its absolute values before the step (floored at 1.0). Default: 1e-5.
+    pub tolerance: f64,
+    /// Reaction to a violation. Default: [`ConservationAction::Report`].
+    pub action: ConservationAction,
+}
+
+impl Default for ConservationConfig {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-5,
+            action: ConservationAction::Report,
+        }
+    }
+}
+
 // ── ConfigError ────────────────────────────────────────────────────
 
 /// Errors detected during [`WorldConfig::validate()`].
@@ -145,6 +224,18 @@ pub enum ConfigError {
     },
     /// `decay_rate` is zero.
     BackoffZeroDecayRate,
+    /// `BackoffStrategy::Sleep` has `min` greater than `max`.
+    BackoffInvalidSleepRange {
+        /// The configured shortest park.
+        min: Duration,
+        /// The configured longest park.
+        max: Duration,
+    },
+    /// Conservation `tolerance` is negative or not finite.
+    InvalidConservationTolerance {
+        /// The invalid value.
+        value: f64,
+    },
     /// Cell count exceeds `u32::MAX`.
     CellCountOverflow {
         /// The value that overflowed.
@@ -167,6 +258,27 @@ pub enum ConfigError {
         /// Description of which thread failed.
         reason: String,
     },
+    /// A propagator's [`init`](Propagator::init) hook failed.
+    PropagatorInit {
+        /// Name of the failing propagator.
+        propagator: String,
+        /// The error returned by `init`.
+        error: PropagatorError,
+    },
+    /// Strict units: a propagator reads and writes fields with
+    /// incompatible unit annotations.
+    UnitMismatch {
+        /// Name of the offending propagator.
+        propagator: String,
+        /// Field read by the propagator.
+        read_field: FieldId,
+        /// Units of the read field.
+        read_units: String,
+        /// Field written by the propagator.
+        write_field: FieldId,
+        /// Units of the written field.
+        write_units: String,
+    },
 }
 
 impl fmt::Display for ConfigError {
@@ -204,6 +316,18 @@ impl fmt::Display for ConfigError {
             Self::BackoffZeroDecayRate => {
                 write!(f, "invalid backoff config: decay_rate must be at least 1")
             }
+            Self::BackoffInvalidSleepRange { min, max } => {
+                write!(
+                    f,
+                    "invalid backoff config: sleep min ({min:?}) exceeds max ({max:?})"
+                )
+            }
+            Self::InvalidConservationTolerance { value } => {
+                write!(
+                    f,
+                    "conservation tolerance must be finite and non-negative, got {value}"
+                )
+            }
             Self::CellCountOverflow { value } => {
                 write!(f, "cell count {value} exceeds u32::MAX")
             }
@@ -219,6 +343,20 @@ impl fmt::Display for ConfigError {
             Self::ThreadSpawnFailed { reason } => {
                 write!(f, "thread spawn failed: {reason}")
             }
+            Self::PropagatorInit { propagator, error } => {
+                write!(f, "propagator '{propagator}' init failed: {error}")
+            }
+            Self::UnitMismatch {
+                propagator,
+                read_field,
+                read_units,
+                write_field,
+                write_units,
+            } => write!(
+                f,
+                "propagator '{propagator}' reads field {read_field} in '{read_units}' \
+                 but writes field {write_field} in '{write_units}'"
+            ),
         }
     }
 }
@@ -228,6 +366,7 @@ impl Error for ConfigError {
         match self {
             Self::Pipeline(e) => Some(e),
             Self::Arena(e) => Some(e),
+            Self::PropagatorInit { error, .. } => Some(error),
             _ => None,
         }
     }
@@ -247,6 +386,13 @@ impl From<ArenaError> for ConfigError {
 
 // ── WorldConfig ────────────────────────────────────────────────────
 
+/// Per-cell initial value for a field, as a function of cell coordinate.
+///
+/// Must be a pure function of the coordinate: it is called once per cell
+/// at world creation and again on every reset, and determinism depends on
+/// it returning the same values each time.
+pub type FieldInitializer = Box<dyn Fn(&Coord) -> Scalar + Send + Sync>;
+
 /// Complete configuration for constructing a simulation world.
 ///
 /// Passed to the world constructor (WP-5b). `validate()` checks all
@@ -270,6 +416,24 @@ pub struct WorldConfig {
     pub(crate) tick_rate_hz: Option<f64>,
     /// Adaptive backoff configuration.
     pub(crate) backoff: BackoffConfig,
+    /// Reject propagators whose read and write fields carry incompatible
+    /// unit annotations. Default: `false`.
+    pub(crate) strict_units: bool,
+    /// Scan written fields for NaN/infinity after the propagator pipeline
+    /// and roll the tick back if any are found. Default: `false`.
+    pub(crate) detect_nonfinite: bool,
+    /// Report which fields changed in each tick's result. Default: `false`.
+    pub(crate) track_changed_fields: bool,
+    /// Audit propagators' declared conserved fields every tick.
+    /// Default: `false`.
+    pub(crate) conservation_check: bool,
+    /// Tolerance and reaction of the conservation audit.
+    pub(crate) conservation: ConservationConfig,
+    /// Initial-state functions applied at world creation and reset.
+    pub(crate) initializers: IndexMap<FieldId, FieldInitializer>,
+    /// Thread-pool sizing and reduction policy. Default: 1 thread,
+    /// deterministic reductions.
+    pub(crate) parallel: ParallelConfig,
 }
 
 impl WorldConfig {
@@ -304,6 +468,14 @@ impl WorldConfig {
                 value: self.fields.len(),
             });
         }
+        // 2d. Initializers must target defined fields.
+        for &field in self.initializers.keys() {
+            if field.0 as usize >= self.fields.len() {
+                return Err(ConfigError::InvalidField {
+                    reason: format!("initializer targets undefined field {field}"),
+                });
+            }
+        }
         // 3. Ring buffer >= 2.
         if self.ring_buffer_size < 2 {
             return Err(ConfigError::RingBufferTooSmall {
@@ -346,16 +518,69 @@ impl WorldConfig {
         if b.decay_rate == 0 {
             return Err(ConfigError::BackoffZeroDecayRate);
         }
+        if let BackoffStrategy::Sleep { min, max } = b.strategy {
+            if min > max {
+                return Err(ConfigError::BackoffInvalidSleepRange { min, max });
+            }
+        }
+        // 6a. Conservation tolerance must be finite and non-negative.
+        let tolerance = self.conservation.tolerance;
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(ConfigError::InvalidConservationTolerance { value: tolerance });
+        }
 
         // 7. Pipeline validation (delegates to murk-propagator).
         //    The plan is intentionally discarded here — the world constructor
         //    calls validate_pipeline() again to obtain it.
         let defined = self.defined_field_set()?;
         let _ = validate_pipeline(&self.propagators, &defined, self.dt, &*self.space)?;
+        validate_static_writes(&self.propagators, &self.static_field_set())?;
+
+        // 8. Strict units: every field a propagator reads must carry units
+        //    compatible with every field it writes. Unannotated fields are
+        //    exempt.
+        if self.strict_units {
+            self.validate_units()?;
+        }
 
         Ok(())
     }
 
+    fn validate_units(&self) -> Result<(), ConfigError> {
+        let units_of = |id: FieldId| {
+            self.fields
+                .get(id.0 as usize)
+                .and_then(|f| f.units.as_deref())
+        };
+        for prop in &self.propagators {
+            let writes = prop.writes();
+            let reads = prop
+                .reads()
+                .union(&prop.optional_reads())
+                .union(&prop.reads_previous());
+            for read_field in reads.iter() {
+                let Some(read_units) = units_of(read_field) else {
+                    continue;
+                };
+                for &(write_field, _) in &writes {
+                    let Some(write_units) = units_of(write_field) else {
+                        continue;
+                    };
+                    if !units_compatible(Some(read_units), Some(write_units)) {
+                        return Err(ConfigError::UnitMismatch {
+                            propagator: prop.name().to_string(),
+                            read_field,
+                            read_units: read_units.to_string(),
+                            write_field,
+                            write_units: write_units.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     // ── Public accessors ──────────────────────────────────────
 
     /// The spatial topology for the simulation.
@@ -403,6 +628,41 @@ impl WorldConfig {
         &self.backoff
     }
 
+    /// Whether strict unit checking is enabled.
+    pub fn strict_units(&self) -> bool {
+        self.strict_units
+    }
+
+    /// Whether non-finite field detection is enabled.
+    pub fn detect_nonfinite(&self) -> bool {
+        self.detect_nonfinite
+    }
+
+    /// Whether per-tick changed-field reporting is enabled.
+    pub fn track_changed_fields(&self) -> bool {
+        self.track_changed_fields
+    }
+
+    /// Whether the conservation audit is enabled.
+    pub fn conservation_check(&self) -> bool {
+        self.conservation_check
+    }
+
+    /// The conservation audit's tolerance and reaction.
+    pub fn conservation(&self) -> &ConservationConfig {
+        &self.conservation
+    }
+
+    /// Whether `field` has an initializer.
+    pub fn has_initializer(&self, field: FieldId) -> bool {
+        self.initializers.contains_key(&field)
+    }
+
+    /// The thread-pool sizing and reduction policy.
+    pub fn parallel(&self) -> &ParallelConfig {
+        &self.parallel
+    }
+
     // ── Builder ──────────────────────────────────────────────
 
     /// Create a new [`WorldConfigBuilder`] with sensible defaults.
@@ -417,6 +677,13 @@ impl WorldConfig {
             max_ingress_queue: 1024,
             tick_rate_hz: None,
             backoff: BackoffConfig::default(),
+            strict_units: false,
+            detect_nonfinite: false,
+            track_changed_fields: false,
+            conservation_check: false,
+            conservation: ConservationConfig::default(),
+            initializers: IndexMap::new(),
+            parallel: ParallelConfig::default(),
         }
     }
 
@@ -435,6 +702,16 @@ impl WorldConfig {
             })
             .collect()
     }
+
+    /// Build a [`FieldSet`] of the fields declared [`FieldMutability::Static`].
+    pub(crate) fn static_field_set(&self) -> FieldSet {
+        self.fields
+            .iter()
+            .enumerate()
+            .filter(|(_, def)| def.mutability == FieldMutability::Static)
+            .filter_map(|(i, _)| u32::try_from(i).ok().map(FieldId))
+            .collect()
+    }
 }
 
 /// Fluent builder for [`WorldConfig`].
@@ -455,6 +732,13 @@ pub struct WorldConfigBuilder {
     max_ingress_queue: usize,
     tick_rate_hz: Option<f64>,
     backoff: BackoffConfig,
+    strict_units: bool,
+    detect_nonfinite: bool,
+    track_changed_fields: bool,
+    conservation_check: bool,
+    conservation: ConservationConfig,
+    initializers: IndexMap<FieldId, FieldInitializer>,
+    parallel: ParallelConfig,
 }
 
 impl WorldConfigBuilder {
@@ -527,6 +811,151 @@ impl WorldConfigBuilder {
         self
     }
 
+    /// Enable strict unit checking. If called multiple times, the last value wins.
+    ///
+    /// When enabled, [`WorldConfig::validate()`] returns
+    /// [`ConfigError::UnitMismatch`] for any propagator that reads a field
+    /// whose `units` differ from those of a field it writes (e.g. reads
+    /// `"celsius"`, writes `"kelvin"`). Fields without units are exempt.
+    pub fn strict_units(mut self, strict: bool) -> Self {
+        self.strict_units = strict;
+        self
+    }
+
+    /// Enable non-finite field detection. If called multiple times, the
+    /// last value wins.
+    ///
+    /// When enabled, every field written during a tick is scanned for NaN
+    /// or infinite values after the propagator pipeline finishes. A hit
+    /// rolls the tick back with [`StepError::NonFiniteField`] instead of
+    /// publishing the poisoned state. The scan costs one pass over each
+    /// written field per tick, so it is off by default.
+    ///
+    /// [`StepError::NonFiniteField`]: murk_core::error::StepError::NonFiniteField
+    pub fn detect_nonfinite(mut self, detect: bool) -> Self {
+        self.detect_nonfinite = detect;
+        self
+    }
+
+    /// Enable changed-field reporting. If called multiple times, the last
+    /// value wins.
+    ///
+    /// When enabled, each successful tick lists the fields whose published
+    /// contents differ from the previous generation in
+    /// `StepResult::changed_fields`. Every non-static field the tick
+    /// replaced is compared element-wise against the previous generation
+    /// before publish, so this is off by default.
+    pub fn track_changed_fields(mut self, track: bool) -> Self {
+        self.track_changed_fields = track;
+        self
+    }
+
+    /// Enable the conservation audit. If called multiple times, the last
+    /// value wins.
+    ///
+    /// When enabled, the engine sums every field a propagator declares in
+    /// [`Propagator::conserved_fields`] before and after its `step()`. A
+    /// change beyond [`ConservationConfig::tolerance`] is reported or rolls
+    /// the tick back with [`StepError::ConservationViolated`], as chosen by
+    /// [`conservation`](Self::conservation). Each audited field costs two
+    /// passes per tick, so the audit is off by default.
+    ///
+    /// [`StepError::ConservationViolated`]: murk_core::error::StepError::ConservationViolated
+    pub fn conservation_check(mut self, check: bool) -> Self {
+        self.conservation_check = check;
+        self
+    }
+
+    /// Set the conservation audit's tolerance and reaction. If called
+    /// multiple times, the last value wins. Has no effect unless
+    /// [`conservation_check`](Self::conservation_check) is enabled.
+    pub fn conservation(mut self, conservation: ConservationConfig) -> Self {
+        self.conservation = conservation;
+        self
+    }
+
+    /// Set the initial value of every cell of `field` from its coordinate.
+    /// If called multiple times for the same field, the last value wins.
+    ///
+    /// `init` runs once per cell in canonical order when the world is
+    /// created and again on every reset, replacing the default zero fill.
+    /// Vector fields receive the returned value in every component. The
+    /// closure must be a pure function of the coordinate.
+    pub fn initializer(
+        mut self,
+        field: FieldId,
+        init: impl Fn(&Coord) -> Scalar + Send + Sync + 'static,
+    ) -> Self {
+        self.initializers.insert(field, Box::new(init));
+        self
+    }
+
+    /// Set the thread-pool sizing. If called multiple times, the last value
+    /// wins.
+    ///
+    /// Results are bit-identical for any thread count. The thread count
+    /// bounds the threads stepping a [`BatchedEngine`](crate::BatchedEngine).
+    pub fn parallel(mut self, parallel: ParallelConfig) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Run the checks [`build()`](Self::build) would, without consuming
+    /// the builder.
+    ///
+    /// Returns the same error `build()` would return for the current
+    /// settings; on `Ok(())`, `build()` will succeed. The builder is left
+    /// unchanged either way.
+    pub fn validate(&mut self) -> Result<(), ConfigError> {
+        if self.space.is_none() {
+            return Err(ConfigError::MissingSpace);
+        }
+        let dt = self.dt.ok_or(ConfigError::MissingDt)?;
+        let space = self.space.take().expect("space checked above");
+
+        let config = WorldConfig {
+            space,
+            fields: std::mem::take(&mut self.fields),
+            propagators: std::mem::take(&mut self.propagators),
+            dt,
+            seed: self.seed,
+            ring_buffer_size: self.ring_buffer_size,
+            max_ingress_queue: self.max_ingress_queue,
+            tick_rate_hz: self.tick_rate_hz,
+            backoff: self.backoff.clone(),
+            strict_units: self.strict_units,
+            detect_nonfinite: self.detect_nonfinite,
+            track_changed_fields: self.track_changed_fields,
+            conservation_check: self.conservation_check,
+            conservation: self.conservation.clone(),
+            initializers: std::mem::take(&mut self.initializers),
+            parallel: self.parallel,
+        };
+        let result = config.validate();
+
+        self.space = Some(config.space);
+        self.fields = config.fields;
+        self.propagators = config.propagators;
+        self.initializers = config.initializers;
+        result
+    }
+
+    /// Take back the space, fields, and propagators handed to the builder,
+    /// discarding it.
+    ///
+    /// Lets a caller that moved owned parts in (e.g. the C API's config
+    /// handle) recover them after [`validate()`](Self::validate).
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(
+        self,
+    ) -> (
+        Option<Box<dyn Space>>,
+        Vec<FieldDef>,
+        Vec<Box<dyn Propagator>>,
+    ) {
+        (self.space, self.fields, self.propagators)
+    }
+
     /// Consume the builder and produce a validated [`WorldConfig`].
     ///
     /// Returns [`ConfigError::MissingSpace`] if `space` was never set,
@@ -546,6 +975,13 @@ impl WorldConfigBuilder {
             max_ingress_queue: self.max_ingress_queue,
             tick_rate_hz: self.tick_rate_hz,
             backoff: self.backoff,
+            strict_units: self.strict_units,
+            detect_nonfinite: self.detect_nonfinite,
+            track_changed_fields: self.track_changed_fields,
+            conservation_check: self.conservation_check,
+            conservation: self.conservation,
+            initializers: self.initializers,
+            parallel: self.parallel,
         };
 
         config.validate()?;
@@ -566,6 +1002,13 @@ impl fmt::Debug for WorldConfig {
             .field("max_ingress_queue", &self.max_ingress_queue)
             .field("tick_rate_hz", &self.tick_rate_hz)
             .field("backoff", &self.backoff)
+            .field("strict_units", &self.strict_units)
+            .field("detect_nonfinite", &self.detect_nonfinite)
+            .field("track_changed_fields", &self.track_changed_fields)
+            .field("conservation_check", &self.conservation_check)
+            .field("conservation", &self.conservation)
+            .field("initializers", &self.initializers.keys().collect::<Vec<_>>())
+            .field("parallel", &self.parallel)
             .finish()
     }
 }
@@ -573,7 +1016,7 @@ impl fmt::Debug for WorldConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use murk_core::{BoundaryBehavior, FieldMutability, FieldType};
+    use murk_core::{BoundaryBehavior, FieldType};
     use murk_space::{EdgeBehavior, Line1D};
     use murk_test_utils::ConstPropagator;
 
@@ -636,6 +1079,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn validate_static_field_write_fails() {
+        let mut cfg = valid_config();
+        cfg.fields[0].mutability = FieldMutability::Static;
+        match cfg.validate() {
+            Err(ConfigError::Pipeline(PipelineError::StaticFieldWrite { field_id, .. })) => {
+                assert_eq!(field_id, FieldId(0));
+            }
+            other => panic!("expected Pipeline(StaticFieldWrite), got {other:?}"),
+        }
+    }
+
     #[test]
     fn validate_dt_exceeds_max_dt_fails() {
         use murk_core::PropagatorError;
@@ -665,8 +1120,16 @@ mod tests {
         cfg.propagators = vec![Box::new(CflProp)];
         cfg.dt = 0.1;
         match cfg.validate() {
-            Err(ConfigError::Pipeline(PipelineError::DtTooLarge { .. })) => {}
-            other => panic!("expected Pipeline(DtTooLarge), got {other:?}"),
+            Err(ConfigError::Pipeline(PipelineError::CflViolation {
+                propagator,
+                dt,
+                max_dt,
+            })) => {
+                assert_eq!(propagator, "cfl");
+                assert_eq!(dt, 0.1);
+                assert_eq!(max_dt, 0.01);
+            }
+            other => panic!("expected Pipeline(CflViolation), got {other:?}"),
         }
     }
 
@@ -836,6 +1299,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn validate_backoff_inverted_sleep_range_fails() {
+        let mut cfg = valid_config();
+        cfg.backoff.strategy = BackoffStrategy::Sleep {
+            min: Duration::from_millis(5),
+            max: Duration::from_millis(1),
+        };
+        match cfg.validate() {
+            Err(ConfigError::BackoffInvalidSleepRange { .. }) => {}
+            other => panic!("expected BackoffInvalidSleepRange, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn validate_negative_conservation_tolerance_fails() {
+        let mut cfg = valid_config();
+        cfg.conservation.tolerance = -1e-3;
+        match cfg.validate() {
+            Err(ConfigError::InvalidConservationTolerance { value }) => assert_eq!(value, -1e-3),
+            other => panic!("expected InvalidConservationTolerance, got {other:?}"),
+        }
+    }
+
     /// BUG-103: ThreadSpawnFailed error variant exists and formats correctly.
     #[test]
     fn thread_spawn_failed_error_display() {
@@ -868,6 +1354,7 @@ mod tests {
             backoff_factor: 1.5,
             decay_rate: 60,
             rejection_rate_threshold: 0.20,
+            ..BackoffConfig::default()
         };
         assert!(cfg.validate().is_ok());
     }
@@ -931,6 +1418,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn builder_validate_reports_errors_without_consuming() {
+        let mut builder = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy")])
+            .dt(0.1);
+        match builder.validate() {
+            Err(ConfigError::Pipeline(PipelineError::EmptyPipeline)) => {}
+            other => panic!("expected EmptyPipeline, got {other:?}"),
+        }
+
+        let mut builder = builder.propagators(vec![Box::new(ConstPropagator::new(
+            "const",
+            FieldId(0),
+            1.0,
+        ))]);
+        builder
+            .validate()
+            .expect("complete builder should validate");
+        let config = builder.build().expect("validated builder should build");
+        assert_eq!(config.fields().len(), 1);
+        assert_eq!(config.propagators().len(), 1);
+    }
+
+    #[test]
+    fn builder_validate_checks_space_before_dt() {
+        let mut builder = WorldConfig::builder();
+        assert!(matches!(builder.validate(), Err(ConfigError::MissingSpace)));
+        let mut builder = builder.space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()));
+        assert!(matches!(builder.validate(), Err(ConfigError::MissingDt)));
+        let (space, fields, propagators) = builder.into_parts();
+        assert_eq!(space.unwrap().cell_count(), 10);
+        assert!(fields.is_empty() && propagators.is_empty());
+    }
+
     #[test]
     fn builder_with_defaults_succeeds() {
         let config = WorldConfig::builder()
@@ -944,6 +1466,14 @@ mod tests {
         assert_eq!(config.ring_buffer_size, 8);
         assert_eq!(config.max_ingress_queue, 1024);
         assert_eq!(config.tick_rate_hz, None);
+        assert!(!config.strict_units);
+        assert!(!config.detect_nonfinite);
+        assert!(!config.track_changed_fields);
+        assert!(!config.conservation_check);
+        assert_eq!(config.conservation.action, ConservationAction::Report);
+        assert!(config.initializers.is_empty());
+        // A minimal built config stays valid when re-checked.
+        assert!(config.validate().is_ok());
     }
 
     #[test]
@@ -963,6 +1493,7 @@ mod tests {
                 max_skew_cap: 20,
                 decay_rate: 120,
                 rejection_rate_threshold: 0.10,
+                ..BackoffConfig::default()
             })
             .build()
             .expect("builder with all options should succeed");
@@ -1068,4 +1599,60 @@ mod tests {
             other => panic!("expected Pipeline(InvalidDt), got {other:?}"),
         }
     }
+
+    fn units_field(name: &str, units: Option<&str>) -> FieldDef {
+        FieldDef {
+            units: units.map(str::to_string),
+            ..scalar_field(name)
+        }
+    }
+
+    /// Celsius source field (0) feeding a propagator that writes field 1.
+    fn units_builder(consumer_units: Option<&str>) -> WorldConfigBuilder {
+        use murk_test_utils::IdentityPropagator;
+        WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![
+                units_field("temp_c", Some("celsius")),
+                units_field("temp_out", consumer_units),
+            ])
+            .propagators(vec![Box::new(IdentityPropagator::new(
+                "to_kelvin",
+                FieldId(0),
+                FieldId(1),
+            ))])
+            .dt(0.1)
+    }
+
+    #[test]
+    fn strict_units_rejects_celsius_into_kelvin() {
+        match units_builder(Some("kelvin")).strict_units(true).build() {
+            Err(ConfigError::UnitMismatch {
+                propagator,
+                read_field,
+                write_field,
+                ..
+            }) => {
+                assert_eq!(propagator, "to_kelvin");
+                assert_eq!(read_field, FieldId(0));
+                assert_eq!(write_field, FieldId(1));
+            }
+            other => panic!("expected UnitMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn non_strict_units_allows_mismatch() {
+        let cfg = units_builder(Some("kelvin")).build().unwrap();
+        assert!(!cfg.strict_units());
+    }
+
+    #[test]
+    fn strict_units_accepts_equivalent_and_missing_units() {
+        assert!(units_builder(Some("degC"))
+            .strict_units(true)
+            .build()
+            .is_ok());
+        assert!(units_builder(None).strict_units(true).build().is_ok());
+    }
 }
@@ -8,6 +8,11 @@
 //! reply channel. The caller copies into its buffer. This maintains
 //! `#![forbid(unsafe_code)]` at the cost of a ~10μs memcpy (negligible
 //! vs the 16.7ms tick budget).
+//!
+//! Also hosts per-tick change subscriptions ([`SubscriptionId`],
+//! [`CellChange`]), which deliver only the field elements that changed
+//! since the previous tick, for UI updates that should not copy whole
+//! fields.
 
 use std::sync::Arc;
 
@@ -16,8 +21,9 @@ use crossbeam_channel::Receiver;
 use crate::epoch::{EpochCounter, WorkerEpoch};
 use crate::ring::SnapshotRing;
 
+use murk_arena::pingpong::PingPongArena;
 use murk_core::traits::SnapshotAccess;
-use murk_core::Coord;
+use murk_core::{Coord, FieldId, Scalar, TickId};
 use murk_obs::{ObsMetadata, ObsPlan};
 use murk_space::Space;
 
@@ -258,6 +264,159 @@ fn send_error(task: &ObsTask, err: murk_core::error::ObsError) {
     }
 }
 
+/// Handle for a change subscription, returned by
+/// [`TickEngine::subscribe_changes`](crate::TickEngine::subscribe_changes).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(pub u64);
+
+/// One field element whose value changed between consecutive ticks.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellChange {
+    /// Field the element belongs to.
+    pub field: FieldId,
+    /// Index into the field's flat buffer
+    /// (`canonical_rank * components + component`).
+    pub index: usize,
+    /// Value in the previously published snapshot.
+    pub old: Scalar,
+    /// Value in the newly published snapshot.
+    pub new: Scalar,
+}
+
+/// Callback invoked with the tick ID and the changed elements of one tick.
+pub(crate) type ChangeCallback = Box<dyn FnMut(TickId, &[CellChange]) + Send>;
+
+struct Subscription {
+    id: SubscriptionId,
+    fields: Vec<FieldId>,
+    /// Changes found by the last [`ChangeSubscriptions::diff`], awaiting
+    /// delivery.
+    pending: Vec<CellChange>,
+    callback: ChangeCallback,
+}
+
+/// Registered change subscriptions, owned by the tick engine.
+///
+/// Changes are found just before publish by diffing the staging buffer
+/// against the previously published generation still held by the arena,
+/// so no copy of the subscribed fields is kept and callbacks only ever see
+/// the elements that changed. Values are compared bitwise, so a NaN that
+/// stays NaN is not reported.
+#[derive(Default)]
+pub(crate) struct ChangeSubscriptions {
+    next_id: u64,
+    subs: Vec<Subscription>,
+}
+
+impl ChangeSubscriptions {
+    /// Register `callback` for `fields`.
+    ///
+    /// Callers must have checked that every field is readable.
+    pub(crate) fn subscribe(
+        &mut self,
+        fields: &[FieldId],
+        callback: ChangeCallback,
+    ) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.subs.push(Subscription {
+            id,
+            fields: fields.to_vec(),
+            pending: Vec::new(),
+            callback,
+        });
+        id
+    }
+
+    /// Remove a subscription. Returns `false` if `id` was not registered.
+    pub(crate) fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let before = self.subs.len();
+        self.subs.retain(|s| s.id != id);
+        self.subs.len() != before
+    }
+
+    /// Whether no subscription is registered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.subs.is_empty()
+    }
+
+    /// Record each subscription's changes in the in-progress tick.
+    ///
+    /// Call after the tick guard is dropped and before publish; the
+    /// changes are held until [`deliver`](Self::deliver).
+    pub(crate) fn diff(&mut self, arena: &PingPongArena) {
+        for sub in &mut self.subs {
+            sub.pending.clear();
+            for &field in &sub.fields {
+                let Some((staged, published)) = replaced_field(arena, field) else {
+                    continue;
+                };
+                for (index, (&old, &new)) in published.iter().zip(staged).enumerate() {
+                    if old.to_bits() != new.to_bits() {
+                        sub.pending.push(CellChange {
+                            field,
+                            index,
+                            old,
+                            new,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Invoke the callbacks that have at least one change recorded by the
+    /// last [`diff`](Self::diff).
+    pub(crate) fn deliver(&mut self, tick: TickId) {
+        for sub in &mut self.subs {
+            if !sub.pending.is_empty() {
+                (sub.callback)(tick, &sub.pending);
+                sub.pending.clear();
+            }
+        }
+    }
+}
+
+/// Fields reported as changed by a tick, compared like
+/// [`ChangeSubscriptions`] but reporting only field IDs.
+pub(crate) struct ChangedFieldTracker {
+    fields: Vec<FieldId>,
+}
+
+impl ChangedFieldTracker {
+    /// Track `fields`.
+    pub(crate) fn new(fields: Vec<FieldId>) -> Self {
+        Self { fields }
+    }
+
+    /// Fields whose staged contents differ from the published generation,
+    /// in field order. Call after the tick guard is dropped and before
+    /// publish.
+    pub(crate) fn changed(&self, arena: &PingPongArena) -> Vec<FieldId> {
+        self.fields
+            .iter()
+            .copied()
+            .filter(|&field| {
+                replaced_field(arena, field).is_some_and(|(staged, published)| {
+                    staged
+                        .iter()
+                        .zip(published)
+                        .any(|(new, old)| new.to_bits() != old.to_bits())
+                })
+            })
+            .collect()
+    }
+}
+
+/// `(staged, published)` contents of `field`, or `None` if the
+/// in-progress tick did not replace its storage (and so cannot have
+/// changed it).
+fn replaced_field(arena: &PingPongArena, field: FieldId) -> Option<(&[Scalar], &[Scalar])> {
+    arena
+        .staged_and_published(field)
+        .filter(|&(staged, published)| !std::ptr::eq(staged, published))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,7 +431,7 @@ mod tests {
     use murk_space::{EdgeBehavior, Line1D};
     use std::thread;
 
-    fn make_test_snapshot(tick: u64, value: f32, cells: u32) -> murk_arena::OwnedSnapshot {
+    fn make_test_snapshot(tick: u64, value: Scalar, cells: u32) -> murk_arena::OwnedSnapshot {
         let config = ArenaConfig::new(cells);
         let field_defs = vec![(
             FieldId(0),
@@ -349,7 +508,9 @@ mod tests {
                 pool: None,
                 transform: murk_obs::spec::ObsTransform::Identity,
                 dtype: murk_obs::spec::ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let plan_result = ObsPlan::compile(&spec, &space).unwrap();
         let plan = Arc::new(plan_result.plan);
@@ -437,7 +598,9 @@ mod tests {
                 pool: None,
                 transform: murk_obs::spec::ObsTransform::Identity,
                 dtype: murk_obs::spec::ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let plan_result = ObsPlan::compile(&spec, &space).unwrap();
 
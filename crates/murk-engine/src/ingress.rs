@@ -5,6 +5,14 @@
 //! evaluates TTL expiry, and sorts commands into a deterministic order for
 //! processing by the tick engine.
 //!
+//! # Expiry
+//!
+//! Commands always expire by tick (`expires_after_tick`). A queue with
+//! [wall-clock expiry](IngressQueue::set_wall_clock_expiry) enabled also
+//! expires commands whose `expires_at` deadline has passed, however few
+//! ticks have elapsed. The realtime world enables it; lockstep leaves it
+//! off so that tick outcomes never depend on timing.
+//!
 //! # Ordering
 //!
 //! Commands are sorted by the composite key:
@@ -17,6 +25,7 @@
 //! - Anonymous commands execute in arrival order.
 
 use std::collections::VecDeque;
+use std::time::Instant;
 
 use murk_core::command::{Command, Receipt};
 use murk_core::error::IngressError;
@@ -57,10 +66,18 @@ struct QueueEntry {
 /// Accepts batches of commands via [`submit()`](IngressQueue::submit),
 /// assigns monotonic arrival sequence numbers, and produces a sorted,
 /// TTL-filtered batch via [`drain()`](IngressQueue::drain).
+///
+/// Rejected submissions are tallied in cumulative drop statistics
+/// ([`dropped_commands()`](IngressQueue::dropped_commands),
+/// [`rejected_full_events()`](IngressQueue::rejected_full_events)) that
+/// persist across ticks until [`clear()`](IngressQueue::clear).
 pub struct IngressQueue {
     queue: VecDeque<QueueEntry>,
     capacity: usize,
     next_arrival_seq: u64,
+    dropped_commands: u64,
+    rejected_full_events: u64,
+    wall_clock_expiry: bool,
 }
 
 impl IngressQueue {
@@ -75,9 +92,25 @@ impl IngressQueue {
             queue: VecDeque::with_capacity(capacity),
             capacity,
             next_arrival_seq: 0,
+            dropped_commands: 0,
+            rejected_full_events: 0,
+            wall_clock_expiry: false,
         }
     }
 
+    /// Enable or disable wall-clock expiry via `Command::expires_at`.
+    ///
+    /// Disabled by default. When disabled, `expires_at` is ignored and only
+    /// `expires_after_tick` is checked.
+    pub fn set_wall_clock_expiry(&mut self, enabled: bool) {
+        self.wall_clock_expiry = enabled;
+    }
+
+    /// Whether [`drain()`](IngressQueue::drain) honors `Command::expires_at`.
+    pub fn wall_clock_expiry(&self) -> bool {
+        self.wall_clock_expiry
+    }
+
     /// Submit a batch of commands to the queue.
     ///
     /// Returns one [`Receipt`] per input command. Commands are accepted
@@ -90,9 +123,11 @@ impl IngressQueue {
     /// the caller may have set on `Command::arrival_seq`.
     pub fn submit(&mut self, commands: Vec<Command>, tick_disabled: bool) -> Vec<Receipt> {
         let mut receipts = Vec::with_capacity(commands.len());
+        let mut hit_full = false;
 
         for (i, mut cmd) in commands.into_iter().enumerate() {
             if tick_disabled {
+                self.dropped_commands = self.dropped_commands.saturating_add(1);
                 receipts.push(Receipt {
                     accepted: false,
                     applied_tick_id: None,
@@ -103,6 +138,8 @@ impl IngressQueue {
             }
 
             if self.queue.len() >= self.capacity {
+                hit_full = true;
+                self.dropped_commands = self.dropped_commands.saturating_add(1);
                 receipts.push(Receipt {
                     accepted: false,
                     applied_tick_id: None,
@@ -131,6 +168,9 @@ impl IngressQueue {
             });
         }
 
+        if hit_full {
+            self.rejected_full_events = self.rejected_full_events.saturating_add(1);
+        }
         receipts
     }
 
@@ -138,20 +178,35 @@ impl IngressQueue {
     ///
     /// A command is expired if `cmd.expires_after_tick < current_tick`.
     /// A command with `expires_after_tick == current_tick` is **valid**
-    /// during that tick.
+    /// during that tick. With wall-clock expiry enabled, a command is also
+    /// expired if its `expires_at` deadline is at or before the time of
+    /// the drain. Tick expiry produces a `Stale` receipt and a passed
+    /// deadline an `Expired` one; a command past both reports `Stale`.
     ///
     /// Returns a [`DrainResult`] containing the sorted valid commands
     /// and receipts for expired commands.
     pub fn drain(&mut self, current_tick: TickId) -> DrainResult {
         let mut valid = Vec::new();
         let mut expired_receipts = Vec::new();
+        let now = self.wall_clock_expiry.then(Instant::now);
 
         for entry in self.queue.drain(..) {
-            if entry.command.expires_after_tick.0 < current_tick.0 {
+            let past_deadline = match (now, entry.command.expires_at) {
+                (Some(now), Some(deadline)) => deadline <= now,
+                _ => false,
+            };
+            let reason = if entry.command.expires_after_tick.0 < current_tick.0 {
+                Some(IngressError::Stale)
+            } else if past_deadline {
+                Some(IngressError::Expired)
+            } else {
+                None
+            };
+            if let Some(reason) = reason {
                 expired_receipts.push(Receipt {
                     accepted: true,
                     applied_tick_id: None,
-                    reason_code: Some(IngressError::Stale),
+                    reason_code: Some(reason),
                     command_index: entry.command_index,
                 });
             } else {
@@ -199,12 +254,27 @@ impl IngressQueue {
         self.capacity
     }
 
-    /// Discard all pending commands.
+    /// Cumulative number of commands rejected by
+    /// [`submit()`](IngressQueue::submit), whether because the queue was
+    /// full or because ticking was disabled.
+    pub fn dropped_commands(&self) -> u64 {
+        self.dropped_commands
+    }
+
+    /// Cumulative number of `submit()` calls that found the queue full and
+    /// rejected at least one command.
+    pub fn rejected_full_events(&self) -> u64 {
+        self.rejected_full_events
+    }
+
+    /// Discard all pending commands and zero the drop statistics.
     ///
     /// Called during [`TickEngine::reset()`](crate::TickEngine::reset) so
     /// stale commands from previous ticks don't survive a reset.
     pub fn clear(&mut self) {
         self.queue.clear();
+        self.dropped_commands = 0;
+        self.rejected_full_events = 0;
     }
 }
 
@@ -221,6 +291,7 @@ mod tests {
                 value: 0.0,
             },
             expires_after_tick: TickId(expires),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: priority,
@@ -235,6 +306,7 @@ mod tests {
                 value: 0.0,
             },
             expires_after_tick: TickId(expires),
+            expires_at: None,
             source_id: Some(source_id),
             source_seq: Some(source_seq),
             priority_class: priority,
@@ -304,6 +376,29 @@ mod tests {
         assert_eq!(q.len(), 3);
     }
 
+    #[test]
+    fn drop_stats_accumulate_across_drains_until_clear() {
+        let mut q = IngressQueue::new(2);
+        q.submit(vec![make_cmd(1, 100); 5], false);
+        assert_eq!(q.dropped_commands(), 3);
+        assert_eq!(q.rejected_full_events(), 1);
+
+        // Draining frees capacity but keeps the running totals.
+        q.drain(TickId(0));
+        q.submit(vec![make_cmd(1, 100); 2], false);
+        assert_eq!(q.dropped_commands(), 3);
+        assert_eq!(q.rejected_full_events(), 1);
+
+        q.submit(vec![make_cmd(1, 100)], false);
+        q.submit(vec![make_cmd(1, 100)], true);
+        assert_eq!(q.dropped_commands(), 5);
+        assert_eq!(q.rejected_full_events(), 2);
+
+        q.clear();
+        assert_eq!(q.dropped_commands(), 0);
+        assert_eq!(q.rejected_full_events(), 0);
+    }
+
     #[test]
     fn arrival_seq_persists_across_submits() {
         let mut q = IngressQueue::new(10);
@@ -361,6 +456,61 @@ mod tests {
         assert!(result.expired_receipts.is_empty());
     }
 
+    #[test]
+    fn drain_expires_past_deadline_when_wall_clock_enabled() {
+        let mut q = IngressQueue::new(10);
+        q.set_wall_clock_expiry(true);
+        let past = Instant::now();
+        let mut late = make_cmd(1, 100);
+        late.expires_at = Some(past);
+        let mut fresh = make_cmd(1, 100);
+        fresh.expires_at = Some(past + std::time::Duration::from_secs(3600));
+        q.submit(vec![fresh, late], false);
+
+        // Tick 1 is far inside the tick TTL; only the deadline has passed.
+        let result = q.drain(TickId(1));
+        assert_eq!(result.commands.len(), 1);
+        assert_eq!(result.commands[0].command_index, 0);
+        assert_eq!(result.expired_receipts.len(), 1);
+        assert_eq!(result.expired_receipts[0].command_index, 1);
+        assert!(result.expired_receipts[0].accepted);
+        assert_eq!(
+            result.expired_receipts[0].reason_code,
+            Some(IngressError::Expired)
+        );
+
+        // Past both the tick TTL and the deadline: tick expiry wins.
+        let mut both = make_cmd(1, 1);
+        both.expires_at = Some(past);
+        q.submit(vec![both], false);
+        let result = q.drain(TickId(2));
+        assert_eq!(
+            result.expired_receipts[0].reason_code,
+            Some(IngressError::Stale)
+        );
+    }
+
+    #[test]
+    fn drain_ignores_deadline_when_wall_clock_disabled() {
+        let mut q = IngressQueue::new(10);
+        assert!(!q.wall_clock_expiry());
+        let mut cmd = make_cmd(1, 3);
+        cmd.expires_at = Some(Instant::now());
+        q.submit(vec![cmd.clone(), cmd], false);
+
+        let result = q.drain(TickId(3));
+        assert_eq!(result.commands.len(), 2);
+        assert!(result.expired_receipts.is_empty());
+
+        // Tick expiry still applies.
+        let mut cmd = make_cmd(1, 3);
+        cmd.expires_at = Some(Instant::now() + std::time::Duration::from_secs(3600));
+        q.submit(vec![cmd], false);
+        let result = q.drain(TickId(4));
+        assert!(result.commands.is_empty());
+        assert_eq!(result.expired_receipts.len(), 1);
+    }
+
     #[test]
     fn drain_sorts_by_priority() {
         let mut q = IngressQueue::new(10);
@@ -508,6 +658,7 @@ mod tests {
                         value: 0.0,
                     },
                     expires_after_tick: TickId(expires),
+                    expires_at: None,
                     source_id: src_id,
                     source_seq: src_seq,
                     priority_class: prio,
@@ -26,11 +26,17 @@ pub mod tick;
 pub(crate) mod tick_thread;
 
 pub use batched::{BatchError, BatchResult, BatchedEngine};
-pub use config::{AsyncConfig, BackoffConfig, ConfigError, WorldConfig, WorldConfigBuilder};
+pub use config::{
+    AsyncConfig, BackoffConfig, BackoffStrategy, ConfigError, ConservationAction,
+    ConservationConfig, FieldInitializer, WorldConfig, WorldConfigBuilder,
+};
+pub use egress::{CellChange, SubscriptionId};
 pub use epoch::{EpochCounter, WorkerEpoch, EPOCH_UNPINNED};
 pub use ingress::{DrainResult, DrainedCommand, IngressQueue};
 pub use lockstep::{LockstepWorld, StepResult};
-pub use metrics::StepMetrics;
-pub use realtime::{RealtimeAsyncWorld, RealtimePreflight, ShutdownReport, SubmitError};
+pub use metrics::{PropagatorMetrics, StepMetrics};
+pub use realtime::{
+    RealtimeAsyncWorld, RealtimePreflight, ShutdownReport, SubmitError, SubmitStatus,
+};
 pub use ring::SnapshotRing;
-pub use tick::{TickEngine, TickError, TickResult};
+pub use tick::{FailurePolicy, RollbackHook, TickEngine, TickError, TickResult};
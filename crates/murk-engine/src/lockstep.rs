@@ -20,13 +20,21 @@
 //! guarantees no outstanding borrows at drop time, cleanup is always safe
 //! (Decision E). No background threads are involved.
 
+use std::cell::RefCell;
+use std::sync::Arc;
+
 use murk_arena::read::Snapshot;
 use murk_core::command::{Command, Receipt};
-use murk_core::id::TickId;
+use murk_core::error::{ObsError, StepError};
+use murk_core::id::{FieldId, TickId};
+use murk_core::traits::FieldReader;
+use murk_core::{Coord, Fnv1a, Scalar};
+use murk_obs::{ObsPlan, ObsSpec};
 
 use crate::config::{ConfigError, WorldConfig};
+use crate::egress::{CellChange, SubscriptionId};
 use crate::metrics::StepMetrics;
-use crate::tick::{TickEngine, TickError};
+use crate::tick::{FailurePolicy, RollbackHook, TickEngine, TickError};
 
 // Compile-time assertion: LockstepWorld is Send but NOT Sync.
 // (dyn Propagator is Send + !Sync, which is the design intent.)
@@ -54,6 +62,15 @@ pub struct StepResult<'w> {
     pub receipts: Vec<Receipt>,
     /// Performance metrics for this tick.
     pub metrics: StepMetrics,
+    /// Absorbed failures of [`FailurePolicy::SkipFailed`] propagators and
+    /// conservation audit violations reported under
+    /// [`ConservationAction::Report`](crate::config::ConservationAction::Report).
+    pub soft_failures: Vec<StepError>,
+    /// Fields whose contents changed this tick, in field order. Always
+    /// empty unless
+    /// [`WorldConfigBuilder::track_changed_fields`](crate::config::WorldConfigBuilder::track_changed_fields)
+    /// is enabled.
+    pub changed_fields: Vec<FieldId>,
 }
 
 // ── LockstepWorld ───────────────────────────────────────────────
@@ -77,6 +94,38 @@ pub struct StepResult<'w> {
 pub struct LockstepWorld {
     engine: TickEngine,
     seed: u64,
+    obs_plans: RefCell<ObsPlanStore>,
+}
+
+/// Plans compiled by [`LockstepWorld::compile_obs`], evicting the least
+/// recently used one when full.
+///
+/// `ObsSpec` holds floats and cannot be hashed, so lookups scan the (at
+/// most [`CAPACITY`](Self::CAPACITY)) entries. Most recently used entries
+/// live at the back.
+#[derive(Default)]
+struct ObsPlanStore {
+    plans: Vec<(ObsSpec, Arc<ObsPlan>)>,
+}
+
+impl ObsPlanStore {
+    /// Maximum number of cached plans.
+    const CAPACITY: usize = 64;
+
+    fn get(&mut self, spec: &ObsSpec) -> Option<Arc<ObsPlan>> {
+        let index = self.plans.iter().position(|(cached, _)| cached == spec)?;
+        let entry = self.plans.remove(index);
+        let plan = Arc::clone(&entry.1);
+        self.plans.push(entry);
+        Some(plan)
+    }
+
+    fn insert(&mut self, spec: ObsSpec, plan: Arc<ObsPlan>) {
+        if self.plans.len() >= Self::CAPACITY {
+            self.plans.remove(0);
+        }
+        self.plans.push((spec, plan));
+    }
 }
 
 impl LockstepWorld {
@@ -90,6 +139,7 @@ impl LockstepWorld {
         Ok(Self {
             engine: TickEngine::new(config)?,
             seed,
+            obs_plans: RefCell::new(ObsPlanStore::default()),
         })
     }
 
@@ -126,6 +176,8 @@ impl LockstepWorld {
                     snapshot: self.engine.snapshot(),
                     receipts,
                     metrics: tick_result.metrics,
+                    soft_failures: tick_result.soft_failures,
+                    changed_fields: tick_result.changed_fields,
                 })
             }
             Err(mut tick_error) => {
@@ -153,16 +205,124 @@ impl LockstepWorld {
         Ok(self.engine.snapshot())
     }
 
+    /// Reset the world to tick 0 with a new seed and explicit initial
+    /// field state.
+    ///
+    /// Like [`reset()`](Self::reset), then overwrites each field in
+    /// `initial` with the given canonical-order buffer before the first
+    /// tick. Buffers are validated up front: on error (unknown or `Static`
+    /// field, wrong length) the world and seed are left unchanged.
+    pub fn reset_with(
+        &mut self,
+        seed: u64,
+        initial: &indexmap::IndexMap<FieldId, Vec<Scalar>>,
+    ) -> Result<Snapshot<'_>, ConfigError> {
+        self.engine.reset_with(initial)?;
+        self.seed = seed;
+        Ok(self.engine.snapshot())
+    }
+
     /// Get a read-only snapshot of the current published generation.
     pub fn snapshot(&self) -> Snapshot<'_> {
         self.engine.snapshot()
     }
 
+    /// FNV-1a hash of one field in the current snapshot.
+    ///
+    /// Uses the same scheme as `murk_replay::snapshot_hash`, restricted to
+    /// `field`: the field index is folded in first, then every value's
+    /// `to_bits()`. Cheap enough to assert mid-run determinism at a
+    /// chosen tick without a full replay comparison.
+    ///
+    /// Returns `None` if `field` is not defined.
+    pub fn field_hash(&self, field: FieldId) -> Option<u64> {
+        let snapshot = self.engine.snapshot();
+        let data = snapshot.read(field)?;
+        let mut hash = Fnv1a::new();
+        hash.write(&field.0.to_le_bytes());
+        for v in data {
+            hash.write(&v.to_bits().to_le_bytes());
+        }
+        Some(hash.finish())
+    }
+
+    /// Enable or disable the propagator at `index` (pipeline order).
+    ///
+    /// A disabled propagator is skipped on every subsequent tick and the
+    /// fields it writes retain their previous-generation values. Intended
+    /// for debugging: toggle a stage off without rebuilding the world.
+    /// Its [`StepMetrics::propagators`](crate::StepMetrics) entry stays in
+    /// place with zero cost.
+    ///
+    /// Returns `false` (and changes nothing) if `index` is out of range.
+    pub fn set_propagator_enabled(&mut self, index: usize, enabled: bool) -> bool {
+        self.engine.set_propagator_enabled(index, enabled)
+    }
+
+    /// Whether the propagator at `index` is enabled, or `None` if out of range.
+    pub fn is_propagator_enabled(&self, index: usize) -> Option<bool> {
+        self.engine.is_propagator_enabled(index)
+    }
+
+    /// Set how a `step()` error from the propagator at `index` is handled.
+    ///
+    /// Under [`FailurePolicy::SkipFailed`] the failing propagator's fields
+    /// keep their previous-generation values, the rest of the pipeline
+    /// runs, and the tick commits; the error is reported in
+    /// [`StepResult::soft_failures`].
+    ///
+    /// Returns `false` (and changes nothing) if `index` is out of range.
+    pub fn set_failure_policy(&mut self, index: usize, policy: FailurePolicy) -> bool {
+        self.engine.set_failure_policy(index, policy)
+    }
+
+    /// Failure policy of the propagator at `index`, or `None` if out of range.
+    pub fn failure_policy(&self, index: usize) -> Option<FailurePolicy> {
+        self.engine.failure_policy(index)
+    }
+
     /// Current tick ID (0 after construction or reset).
     pub fn current_tick(&self) -> TickId {
         self.engine.current_tick()
     }
 
+    /// Cumulative simulation time in seconds (0 after construction or reset).
+    pub fn sim_time(&self) -> f64 {
+        self.engine.sim_time()
+    }
+
+    /// The largest `dt` the pipeline accepts: the minimum of every
+    /// propagator's `max_dt` over this world's space, or `None` if no
+    /// propagator constrains `dt`.
+    pub fn max_safe_dt(&self) -> Option<f64> {
+        self.engine.max_safe_dt()
+    }
+
+    /// Subscribe to per-tick changes of `fields`; see
+    /// [`TickEngine::subscribe_changes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidField`] if any field is undefined.
+    pub fn subscribe_changes(
+        &mut self,
+        fields: &[FieldId],
+        callback: impl FnMut(TickId, &[CellChange]) + Send + 'static,
+    ) -> Result<SubscriptionId, ConfigError> {
+        self.engine.subscribe_changes(fields, callback)
+    }
+
+    /// Remove a change subscription. Returns `false` if `id` is unknown.
+    pub fn unsubscribe_changes(&mut self, id: SubscriptionId) -> bool {
+        self.engine.unsubscribe_changes(id)
+    }
+
+    /// Install a callback for rolled-back ticks; see
+    /// [`TickEngine::set_rollback_hook`].
+    pub fn set_rollback_hook(&mut self, hook: RollbackHook) {
+        self.engine.set_rollback_hook(hook);
+    }
+
     /// Whether ticking is disabled due to consecutive rollbacks.
     pub fn is_tick_disabled(&self) -> bool {
         self.engine.is_tick_disabled()
@@ -188,6 +348,50 @@ impl LockstepWorld {
         self.engine.space()
     }
 
+    /// Field definitions for this world, indexed by `FieldId`.
+    ///
+    /// Pass these to [`ObsPlan::compile_with_fields`] to resolve
+    /// `ObsTransform::NormalizeToBounds` entries.
+    pub fn field_defs(&self) -> &[murk_core::FieldDef] {
+        self.engine.field_defs()
+    }
+
+    /// Current `(agent_id, coord)` of every agent managed by a propagator
+    /// (e.g. `AgentMovementPropagator`), sorted by agent ID.
+    ///
+    /// Coordinates are in the space's canonical form, so they can be passed
+    /// straight to `ObsPlan::execute_agents` as agent centers instead of
+    /// being tracked separately by the caller.
+    pub fn agent_positions(&self) -> Vec<(u16, Coord)> {
+        self.engine.agent_positions()
+    }
+
+    /// Compile `spec` against this world's space, reusing a cached plan.
+    ///
+    /// Plans are cached by spec and survive [`reset()`](Self::reset) and
+    /// stepping, so an RL loop can call this after every episode reset
+    /// without recompiling. The cache holds the 64 most recently used
+    /// plans.
+    ///
+    /// The returned plan is not generation-bound: `WorldGenerationId`
+    /// advances every tick, so a plan from
+    /// [`ObsPlan::compile_bound`] would be invalidated by the next step.
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`ObsError`] from [`ObsPlan::compile_with_fields`]. Failed
+    /// compilations are not cached.
+    pub fn compile_obs(&self, spec: &ObsSpec) -> Result<Arc<ObsPlan>, ObsError> {
+        let mut store = self.obs_plans.borrow_mut();
+        if let Some(plan) = store.get(spec) {
+            return Ok(plan);
+        }
+        let (space, fields) = (self.engine.space(), self.engine.field_defs());
+        let plan = Arc::new(ObsPlan::compile_with_fields(spec, space, fields)?.plan);
+        store.insert(spec.clone(), Arc::clone(&plan));
+        Ok(plan)
+    }
+
     /// Number of command batches currently queued for the next tick.
     pub fn ingress_queue_depth(&self) -> usize {
         self.engine.ingress_queue_depth()
@@ -205,6 +409,7 @@ impl std::fmt::Debug for LockstepWorld {
             .field("current_tick", &self.engine.current_tick())
             .field("seed", &self.seed)
             .field("tick_disabled", &self.engine.is_tick_disabled())
+            .field("cached_obs_plans", &self.obs_plans.borrow().plans.len())
             .finish()
     }
 }
@@ -213,13 +418,14 @@ impl std::fmt::Debug for LockstepWorld {
 mod tests {
     use super::*;
     use murk_core::command::CommandPayload;
-    use murk_core::id::{Coord, FieldId};
+    use murk_core::id::Coord;
     use murk_core::traits::{FieldReader, SnapshotAccess};
     use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType};
     use murk_propagator::propagator::WriteMode;
     use murk_propagator::Propagator;
     use murk_space::{EdgeBehavior, Line1D, Square4};
     use murk_test_utils::{ConstPropagator, FailingPropagator, IdentityPropagator};
+    use std::sync::{Arc, Mutex};
 
     fn scalar_field(name: &str) -> FieldDef {
         FieldDef {
@@ -345,6 +551,7 @@ mod tests {
                 value: 1.0,
             },
             expires_after_tick: TickId(expires),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -392,6 +599,26 @@ mod tests {
         assert!(applied.iter().all(|r| r.applied_tick_id == Some(TickId(1))));
     }
 
+    #[test]
+    fn step_sync_ignores_wall_clock_deadline() {
+        let mut world = LockstepWorld::new(simple_config()).unwrap();
+        let mut past_deadline = make_cmd(100);
+        past_deadline.expires_at = Some(std::time::Instant::now());
+
+        let result = world.step_sync(vec![past_deadline, make_cmd(0)]).unwrap();
+        let receipts = &result.receipts;
+        assert_eq!(receipts.len(), 2);
+        let by_index = |i| receipts.iter().find(|r| r.command_index == i).unwrap();
+        // The deadline has passed, but lockstep only expires by tick.
+        assert_eq!(by_index(0).applied_tick_id, Some(TickId(1)));
+        assert_eq!(by_index(0).reason_code, None);
+        assert_eq!(by_index(1).applied_tick_id, None);
+        assert_eq!(
+            by_index(1).reason_code,
+            Some(murk_core::error::IngressError::Stale)
+        );
+    }
+
     #[test]
     fn step_sync_propagator_failure_returns_tick_error() {
         let config = WorldConfig::builder()
@@ -407,6 +634,53 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn rollback_hook_reports_attempted_tick_and_error() {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy")])
+            .propagators(vec![Box::new(FailingPropagator::new("fail", FieldId(0), 2))])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut world = LockstepWorld::new(config).unwrap();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&calls);
+        world.set_rollback_hook(Box::new(move |tick, err| {
+            sink.lock().unwrap().push((tick, err.clone()));
+        }));
+
+        world.step_sync(vec![]).unwrap();
+        world.step_sync(vec![]).unwrap();
+        assert!(calls.lock().unwrap().is_empty());
+
+        let Err(err) = world.step_sync(vec![]) else {
+            panic!("third tick should roll back");
+        };
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, TickId(3));
+        assert!(
+            matches!(&calls[0].1, StepError::PropagatorFailed { name, .. } if name == "fail"),
+            "{:?}",
+            calls[0].1
+        );
+        assert_eq!(calls[0].1, err.kind);
+    }
+
+    #[test]
+    fn rollback_hook_not_called_on_success() {
+        let mut world = LockstepWorld::new(simple_config()).unwrap();
+        let calls = Arc::new(Mutex::new(0u32));
+        let sink = Arc::clone(&calls);
+        world.set_rollback_hook(Box::new(move |_, _| *sink.lock().unwrap() += 1));
+        for _ in 0..5 {
+            world.step_sync(vec![]).unwrap();
+        }
+        assert_eq!(*calls.lock().unwrap(), 0);
+    }
+
     // ── Two-field overlay visibility ─────────────────────────
 
     #[test]
@@ -445,6 +719,83 @@ mod tests {
         assert!(data.iter().all(|&v| v == 42.0));
     }
 
+    #[test]
+    fn reset_with_applies_initial_fields() {
+        let mut world = LockstepWorld::new(two_field_config()).unwrap();
+        world.step_sync(vec![]).unwrap();
+
+        let ramp: Vec<Scalar> = (0..10).map(|i| i as Scalar).collect();
+        let initial: indexmap::IndexMap<FieldId, Vec<Scalar>> =
+            [(FieldId(1), ramp.clone())].into_iter().collect();
+        let snap = world.reset_with(5, &initial).unwrap();
+        assert_eq!(snap.tick_id(), TickId(0));
+        assert_eq!(snap.read(FieldId(1)).unwrap(), &ramp[..]);
+        assert!(snap.read(FieldId(0)).unwrap().iter().all(|&v| v == 0.0));
+        assert_eq!(world.seed(), 5);
+
+        // The pipeline runs normally from the provided state.
+        let result = world.step_sync(vec![]).unwrap();
+        assert_eq!(result.snapshot.read(FieldId(1)).unwrap()[9], 7.0);
+    }
+
+    #[test]
+    fn reset_with_wrong_length_leaves_world_untouched() {
+        let mut world = LockstepWorld::new(two_field_config()).unwrap();
+        world.step_sync(vec![]).unwrap();
+        world.step_sync(vec![]).unwrap();
+
+        let initial: indexmap::IndexMap<FieldId, Vec<Scalar>> =
+            [(FieldId(0), vec![1.0; 3])].into_iter().collect();
+        match world.reset_with(9, &initial) {
+            Err(ConfigError::InvalidField { reason }) => assert!(reason.contains("expected 10")),
+            Err(e) => panic!("expected InvalidField, got {e:?}"),
+            Ok(_) => panic!("expected InvalidField, got Ok"),
+        }
+        assert_eq!(world.current_tick(), TickId(2));
+        assert_eq!(world.seed(), 42);
+        assert!(world
+            .snapshot()
+            .read(FieldId(1))
+            .unwrap()
+            .iter()
+            .all(|&v| v == 7.0));
+
+        let unknown: indexmap::IndexMap<FieldId, Vec<Scalar>> =
+            [(FieldId(7), vec![0.0; 10])].into_iter().collect();
+        assert!(world.reset_with(9, &unknown).is_err());
+        assert_eq!(world.current_tick(), TickId(2));
+    }
+
+    // ── CFL query ────────────────────────────────────────────
+
+    #[test]
+    fn max_safe_dt_reports_diffusion_cfl_limit() {
+        let config = WorldConfig::builder()
+            .space(Box::new(Square4::new(5, 5, EdgeBehavior::Wrap).unwrap()))
+            .fields(vec![scalar_field("heat")])
+            .propagators(vec![Box::new(
+                murk_propagators::ScalarDiffusion::builder()
+                    .input_field(FieldId(0))
+                    .output_field(FieldId(0))
+                    .coefficient(0.5)
+                    .max_degree(0)
+                    .build()
+                    .unwrap(),
+            )])
+            .dt(0.1)
+            .build()
+            .unwrap();
+        let world = LockstepWorld::new(config).unwrap();
+        // Square4 degree 4: 1 / (4 * 0.5).
+        assert_eq!(world.max_safe_dt(), Some(0.5));
+    }
+
+    #[test]
+    fn max_safe_dt_is_none_for_unconstrained_pipeline() {
+        let world = LockstepWorld::new(two_field_config()).unwrap();
+        assert_eq!(world.max_safe_dt(), None);
+    }
+
     // ── 1000-step determinism (M0 quality gate) ──────────────
 
     #[test]
@@ -491,6 +842,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn field_hash_is_stable_across_runs() {
+        let mut world_a = LockstepWorld::new(square4_config()).unwrap();
+        let mut world_b = LockstepWorld::new(square4_config()).unwrap();
+        for _ in 0..5 {
+            world_a.step_sync(vec![]).unwrap();
+            world_b.step_sync(vec![]).unwrap();
+            for field in [FieldId(0), FieldId(1), FieldId(2)] {
+                assert_eq!(world_a.field_hash(field), world_b.field_hash(field));
+            }
+        }
+    }
+
+    #[test]
+    fn field_hash_tracks_field_contents() {
+        let mut world = LockstepWorld::new(square4_config()).unwrap();
+        let initial = world.field_hash(FieldId(0)).unwrap();
+        world.step_sync(vec![]).unwrap();
+        let stepped = world.field_hash(FieldId(0)).unwrap();
+        assert_ne!(initial, stepped, "field 0 goes from zeros to 3.0");
+
+        // Fields 0 and 1 hold identical data; the field index still
+        // distinguishes their hashes.
+        assert_eq!(
+            world.snapshot().read(FieldId(0)),
+            world.snapshot().read(FieldId(1))
+        );
+        assert_ne!(world.field_hash(FieldId(1)), Some(stepped));
+    }
+
+    #[test]
+    fn field_hash_unknown_field_is_none() {
+        let world = LockstepWorld::new(simple_config()).unwrap();
+        assert!(world.field_hash(FieldId(0)).is_some());
+        assert_eq!(world.field_hash(FieldId(99)), None);
+    }
+
     // ── Memory bound (M0 quality gate) ───────────────────────
 
     #[test]
@@ -638,4 +1026,441 @@ mod tests {
         assert_eq!(world.seed(), 7);
         assert_eq!(world.current_tick(), TickId(0));
     }
+
+    /// Heat diffuses from a hot cell; a counter field ticks up independently.
+    fn toggle_config() -> WorldConfig {
+        WorldConfig::builder()
+            .space(Box::new(Square4::new(5, 5, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("heat"), scalar_field("marker")])
+            .propagators(vec![
+                Box::new(
+                    murk_propagators::ScalarDiffusion::builder()
+                        .input_field(FieldId(0))
+                        .output_field(FieldId(0))
+                        .coefficient(0.5)
+                        .source(12, 100.0)
+                        .build()
+                        .unwrap(),
+                ),
+                Box::new(ConstPropagator::new("marker", FieldId(1), 1.0)),
+            ])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn disabled_propagator_freezes_its_fields() {
+        let mut world = LockstepWorld::new(toggle_config()).unwrap();
+        for _ in 0..3 {
+            world.step_sync(vec![]).unwrap();
+        }
+        let before = world.snapshot().read(FieldId(0)).unwrap().to_vec();
+        assert!(before[11] > 0.0, "heat should have spread before disabling");
+
+        assert!(world.set_propagator_enabled(0, false));
+        assert_eq!(world.is_propagator_enabled(0), Some(false));
+        for _ in 0..3 {
+            let result = world.step_sync(vec![]).unwrap();
+            assert_eq!(result.snapshot.read(FieldId(0)).unwrap(), &before[..]);
+            // The other propagator still runs.
+            assert!(result
+                .snapshot
+                .read(FieldId(1))
+                .unwrap()
+                .iter()
+                .all(|&v| v == 1.0));
+            // Metrics keep one entry per propagator in pipeline order.
+            let metrics = &result.metrics.propagators;
+            assert_eq!(metrics.len(), 2);
+            assert_eq!(metrics[0].us, 0);
+            assert_eq!(metrics[0].writes_cells, 0);
+            assert!(metrics[1].writes_cells > 0);
+        }
+    }
+
+    #[test]
+    fn reenabled_propagator_resumes() {
+        let mut world = LockstepWorld::new(toggle_config()).unwrap();
+        world.step_sync(vec![]).unwrap();
+        world.set_propagator_enabled(0, false);
+        world.step_sync(vec![]).unwrap();
+        let frozen = world.snapshot().read(FieldId(0)).unwrap().to_vec();
+
+        world.set_propagator_enabled(0, true);
+        let result = world.step_sync(vec![]).unwrap();
+        let resumed = result.snapshot.read(FieldId(0)).unwrap();
+        assert_ne!(resumed, &frozen[..], "diffusion should resume");
+        assert!(resumed[11] > frozen[11]);
+    }
+
+    #[test]
+    fn propagator_enabled_out_of_range_is_none() {
+        let world = LockstepWorld::new(simple_config()).unwrap();
+        assert_eq!(world.is_propagator_enabled(0), Some(true));
+        assert_eq!(world.is_propagator_enabled(1), None);
+    }
+
+    #[test]
+    fn set_propagator_enabled_out_of_range_is_rejected() {
+        let mut world = LockstepWorld::new(simple_config()).unwrap();
+        assert!(!world.set_propagator_enabled(5, false));
+        assert!(!world.set_failure_policy(5, FailurePolicy::SkipFailed));
+        assert_eq!(world.is_propagator_enabled(0), Some(true));
+        world.step_sync(vec![]).unwrap();
+    }
+
+    /// A flaky propagator (fails from its third call) writing field 0 and a
+    /// steady one writing field 1; each writes its call index.
+    fn flaky_config() -> WorldConfig {
+        WorldConfig::builder()
+            .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("flaky"), scalar_field("steady")])
+            .propagators(vec![
+                Box::new(FailingPropagator::new("flaky", FieldId(0), 2)),
+                Box::new(FailingPropagator::new("steady", FieldId(1), usize::MAX)),
+            ])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn soft_failing_propagator_does_not_block_pipeline() {
+        let mut world = LockstepWorld::new(flaky_config()).unwrap();
+        assert!(world.set_failure_policy(0, FailurePolicy::SkipFailed));
+        assert_eq!(world.failure_policy(0), Some(FailurePolicy::SkipFailed));
+        assert_eq!(world.failure_policy(1), Some(FailurePolicy::RollbackAll));
+        assert_eq!(world.failure_policy(2), None);
+
+        for _ in 0..2 {
+            assert!(world.step_sync(vec![]).unwrap().soft_failures.is_empty());
+        }
+        for tick in 3..=5u64 {
+            let result = world.step_sync(vec![]).unwrap();
+            assert_eq!(result.snapshot.tick_id(), TickId(tick));
+            assert!(matches!(
+                &result.soft_failures[..],
+                [StepError::PropagatorFailed { name, .. }] if name == "flaky"
+            ));
+            // Frozen at the last successful write (call index 1).
+            assert!(result
+                .snapshot
+                .read(FieldId(0))
+                .unwrap()
+                .iter()
+                .all(|&v| v == 1.0));
+            // The steady propagator keeps advancing.
+            let steady = (tick - 1) as Scalar;
+            assert!(result
+                .snapshot
+                .read(FieldId(1))
+                .unwrap()
+                .iter()
+                .all(|&v| v == steady));
+        }
+        assert!(!world.is_tick_disabled());
+        assert_eq!(world.last_metrics().rollback_events, 0);
+    }
+
+    #[test]
+    fn rollback_all_is_default_failure_policy() {
+        let mut world = LockstepWorld::new(flaky_config()).unwrap();
+        world.step_sync(vec![]).unwrap();
+        world.step_sync(vec![]).unwrap();
+        let Err(err) = world.step_sync(vec![]) else {
+            panic!("flaky propagator should fail the tick");
+        };
+        assert!(matches!(err.kind, StepError::PropagatorFailed { .. }));
+        assert_eq!(world.current_tick(), TickId(2));
+    }
+
+    /// Two agents on a grid driven by an `AgentMovementPropagator`. The
+    /// presence field sits at `FieldId(2)`, matching the propagator.
+    fn agent_world(
+        space: Box<dyn murk_space::Space>,
+        initial: Vec<(u16, usize)>,
+    ) -> (LockstepWorld, murk_propagators::ActionBuffer) {
+        let actions = murk_propagators::agent_movement::new_action_buffer();
+        let config = WorldConfig::builder()
+            .space(space)
+            .fields(vec![
+                scalar_field("a"),
+                scalar_field("b"),
+                scalar_field("presence"),
+            ])
+            .propagators(vec![Box::new(
+                murk_propagators::AgentMovementPropagator::new(actions.clone(), initial),
+            )])
+            .dt(0.1)
+            .build()
+            .unwrap();
+        (LockstepWorld::new(config).unwrap(), actions)
+    }
+
+    #[test]
+    fn agent_positions_track_movement() {
+        let grid = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
+        let (mut world, actions) = agent_world(Box::new(grid), vec![(3, 7), (1, 24)]);
+        assert!(world.agent_positions().is_empty(), "not placed before tick 1");
+
+        world.step_sync(vec![]).unwrap();
+        assert_eq!(
+            world.agent_positions(),
+            vec![
+                (1, Coord::from_slice(&[4, 4])),
+                (3, Coord::from_slice(&[1, 2]))
+            ]
+        );
+
+        actions.lock().unwrap().push(murk_propagators::AgentAction {
+            agent_id: 3,
+            direction: murk_propagators::Direction::North,
+        });
+        world.step_sync(vec![]).unwrap();
+        assert_eq!(world.agent_positions()[1], (3, Coord::from_slice(&[0, 2])));
+        assert_eq!(world.agent_positions()[0], (1, Coord::from_slice(&[4, 4])));
+
+        world.reset(0).unwrap();
+        assert!(world.agent_positions().is_empty());
+    }
+
+    #[test]
+    fn agent_positions_use_canonical_coords() {
+        let line = Line1D::new(6, EdgeBehavior::Absorb).unwrap();
+        let (mut world, _) = agent_world(Box::new(line), vec![(0, 4)]);
+        world.step_sync(vec![]).unwrap();
+        let positions = world.agent_positions();
+        assert_eq!(positions, vec![(0, Coord::from_slice(&[4]))]);
+        let rank = world.space().canonical_rank(&positions[0].1);
+        assert_eq!(rank, Some(4));
+    }
+
+    #[test]
+    fn agent_positions_empty_without_agent_propagators() {
+        let mut world = LockstepWorld::new(simple_config()).unwrap();
+        world.step_sync(vec![]).unwrap();
+        assert!(world.agent_positions().is_empty());
+    }
+
+    // ── Change subscription tests ───────────────────────────
+
+    /// `energy` is rewritten to 42.0 every tick; `marker` has no writer,
+    /// so only commands change it.
+    fn marker_world() -> LockstepWorld {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy"), scalar_field("marker")])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(0), 42.0))])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        LockstepWorld::new(config).unwrap()
+    }
+
+    fn set_marker(cell: i32, value: f32) -> Command {
+        Command {
+            payload: CommandPayload::SetField {
+                coord: Coord::from_elem(cell, 1),
+                field_id: FieldId(1),
+                value,
+            },
+            expires_after_tick: TickId(100),
+            expires_at: None,
+            source_id: None,
+            source_seq: None,
+            priority_class: 1,
+            arrival_seq: 0,
+        }
+    }
+
+    #[test]
+    fn change_subscription_delivers_single_set_field() {
+        let mut world = marker_world();
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        let sink = delivered.clone();
+        world
+            .subscribe_changes(&[FieldId(1)], move |tick, changes| {
+                sink.lock().unwrap().push((tick, changes.to_vec()));
+            })
+            .unwrap();
+
+        world.step_sync(vec![set_marker(3, 5.0)]).unwrap();
+
+        let delivered = delivered.lock().unwrap();
+        assert_eq!(delivered.len(), 1);
+        let (tick, changes) = &delivered[0];
+        assert_eq!(*tick, TickId(1));
+        assert_eq!(
+            changes,
+            &vec![CellChange {
+                field: FieldId(1),
+                index: 3,
+                old: 0.0,
+                new: 5.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn unsubscribe_stops_change_delivery() {
+        let mut world = marker_world();
+        let count = Arc::new(Mutex::new(0usize));
+        let sink = count.clone();
+        let id = world
+            .subscribe_changes(&[FieldId(0), FieldId(1)], move |_, changes| {
+                *sink.lock().unwrap() += changes.len();
+            })
+            .unwrap();
+
+        // Tick 1: all ten energy cells go 0 -> 42.
+        world.step_sync(vec![]).unwrap();
+        assert_eq!(*count.lock().unwrap(), 10);
+        // Tick 2: energy stays at 42, nothing is delivered.
+        world.step_sync(vec![]).unwrap();
+        assert_eq!(*count.lock().unwrap(), 10);
+
+        assert!(world.unsubscribe_changes(id));
+        assert!(!world.unsubscribe_changes(id));
+        world.step_sync(vec![set_marker(0, 1.0)]).unwrap();
+        assert_eq!(*count.lock().unwrap(), 10);
+    }
+
+    #[test]
+    fn change_delivery_after_reset_compares_with_reset_state() {
+        let mut world = marker_world();
+        let count = Arc::new(Mutex::new(0usize));
+        let sink = count.clone();
+        world
+            .subscribe_changes(&[FieldId(0)], move |_, changes| {
+                *sink.lock().unwrap() += changes.len();
+            })
+            .unwrap();
+
+        world.step_sync(vec![]).unwrap();
+        world.step_sync(vec![]).unwrap();
+        assert_eq!(*count.lock().unwrap(), 10);
+
+        // Reset zeroes energy, so the next tick sets all ten cells again.
+        world.reset(7).unwrap();
+        world.step_sync(vec![]).unwrap();
+        assert_eq!(*count.lock().unwrap(), 20);
+    }
+
+    #[test]
+    fn subscribe_to_undefined_field_rejected() {
+        let mut world = marker_world();
+        let result = world.subscribe_changes(&[FieldId(9)], |_, _| {});
+        assert!(matches!(result, Err(ConfigError::InvalidField { .. })));
+    }
+
+    // ── Observation plan cache tests ─────────────────────────
+
+    fn obs_spec(field: FieldId) -> ObsSpec {
+        ObsSpec {
+            entries: vec![murk_obs::ObsEntry {
+                field_id: field,
+                region: murk_obs::spec::ObsRegion::Fixed(murk_space::RegionSpec::All),
+                pool: None,
+                transform: murk_obs::spec::ObsTransform::Identity,
+                dtype: murk_obs::spec::ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn compile_obs_returns_cached_plan_across_reset() {
+        let mut world = LockstepWorld::new(two_field_config()).unwrap();
+        let first = world.compile_obs(&obs_spec(FieldId(0))).unwrap();
+        let second = world.compile_obs(&obs_spec(FieldId(0))).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        world.step_sync(vec![]).unwrap();
+        world.reset(7).unwrap();
+        let after_reset = world.compile_obs(&obs_spec(FieldId(0))).unwrap();
+        assert!(Arc::ptr_eq(&first, &after_reset));
+
+        let other = world.compile_obs(&obs_spec(FieldId(1))).unwrap();
+        assert!(!Arc::ptr_eq(&first, &other));
+    }
+
+    /// Distinct specs differing only in `pad_value`.
+    fn padded_spec(i: usize) -> ObsSpec {
+        ObsSpec {
+            pad_value: i as f32,
+            ..obs_spec(FieldId(0))
+        }
+    }
+
+    #[test]
+    fn compile_obs_evicts_least_recently_used() {
+        let world = LockstepWorld::new(simple_config()).unwrap();
+        let first = world.compile_obs(&padded_spec(0)).unwrap();
+        let second = world.compile_obs(&padded_spec(1)).unwrap();
+        for i in 2..ObsPlanStore::CAPACITY {
+            world.compile_obs(&padded_spec(i)).unwrap();
+        }
+        // Touch the oldest plan so the second one becomes the LRU entry.
+        let touched = world.compile_obs(&padded_spec(0)).unwrap();
+        assert!(Arc::ptr_eq(&first, &touched));
+
+        let overflow = padded_spec(ObsPlanStore::CAPACITY);
+        world.compile_obs(&overflow).unwrap();
+        let kept = world.compile_obs(&padded_spec(0)).unwrap();
+        assert!(Arc::ptr_eq(&first, &kept));
+        let recompiled = world.compile_obs(&padded_spec(1)).unwrap();
+        assert!(!Arc::ptr_eq(&second, &recompiled));
+    }
+
+    #[test]
+    fn compile_obs_does_not_cache_failures() {
+        let world = LockstepWorld::new(simple_config()).unwrap();
+        let empty = ObsSpec {
+            entries: vec![],
+            pad_value: 0.0,
+        };
+        let first = world.compile_obs(&padded_spec(0)).unwrap();
+        for i in 1..ObsPlanStore::CAPACITY {
+            world.compile_obs(&padded_spec(i)).unwrap();
+        }
+        // A failed compile must not take a slot (and evict the oldest plan).
+        assert!(world.compile_obs(&empty).is_err());
+        let kept = world.compile_obs(&padded_spec(0)).unwrap();
+        assert!(Arc::ptr_eq(&first, &kept));
+    }
+
+    #[test]
+    fn compile_obs_resolves_normalize_to_bounds() {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![FieldDef {
+                bounds: Some((0.0, 100.0).into()),
+                ..scalar_field("energy")
+            }])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(0), 42.0))])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut world = LockstepWorld::new(config).unwrap();
+        let mut spec = obs_spec(FieldId(0));
+        spec.entries[0].transform = murk_obs::spec::ObsTransform::NormalizeToBounds;
+        let plan = world.compile_obs(&spec).unwrap();
+
+        let result = world.step_sync(vec![]).unwrap();
+        let mut output = vec![0.0f32; 10];
+        let mut mask = vec![0u8; 10];
+        plan.execute(&result.snapshot, None, &mut output, &mut mask)
+            .unwrap();
+        assert!(
+            output.iter().all(|&v| (v - 0.42).abs() < 1e-6),
+            "{output:?}"
+        );
+        assert!(mask.iter().all(|&m| m == 1));
+    }
 }
@@ -3,6 +3,22 @@
 //! [`StepMetrics`] captures timing and memory data for a single tick,
 //! enabling telemetry, profiling, and adaptive backoff decisions.
 
+/// Execution cost of one propagator during a single tick.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PropagatorMetrics {
+    /// Propagator name.
+    pub name: String,
+    /// Execution time, in microseconds.
+    pub us: u64,
+    /// Cell values read through declared `reads()`, `reads_previous()`
+    /// and defined `optional_reads()` fields (`cell_count * components`
+    /// per field).
+    pub reads_cells: u64,
+    /// Cell values written through declared `writes()` fields
+    /// (`cell_count * components` per field).
+    pub writes_cells: u64,
+}
+
 /// Timing and memory metrics collected during a single tick.
 ///
 /// All durations are in microseconds. The engine populates these fields
@@ -14,8 +30,9 @@ pub struct StepMetrics {
     pub total_us: u64,
     /// Time spent processing the ingress command queue, in microseconds.
     pub command_processing_us: u64,
-    /// Per-propagator execution times: `(name, microseconds)`.
-    pub propagator_us: Vec<(String, u64)>,
+    /// Per-propagator execution time and cell traffic, one entry per
+    /// propagator in pipeline order. Disabled propagators report zeros.
+    pub propagators: Vec<PropagatorMetrics>,
     /// Time spent publishing the snapshot to the ring buffer, in microseconds.
     pub snapshot_publish_us: u64,
     /// Memory usage of the arena after the tick, in bytes.
@@ -32,6 +49,12 @@ pub struct StepMetrics {
     pub queue_full_rejections: u64,
     /// Cumulative number of ingress rejections due to tick-disabled state.
     pub tick_disabled_rejections: u64,
+    /// Cumulative number of commands rejected at ingress submission
+    /// (queue full or tick disabled). Reset by `reset()`.
+    pub dropped_commands: u64,
+    /// Cumulative number of submission batches that found the ingress
+    /// queue full. Reset by `reset()`.
+    pub rejected_full_events: u64,
     /// Cumulative number of rollback events.
     pub rollback_events: u64,
     /// Cumulative number of transitions into tick-disabled state.
@@ -57,7 +80,7 @@ mod tests {
         let m = StepMetrics::default();
         assert_eq!(m.total_us, 0);
         assert_eq!(m.command_processing_us, 0);
-        assert!(m.propagator_us.is_empty());
+        assert!(m.propagators.is_empty());
         assert_eq!(m.snapshot_publish_us, 0);
         assert_eq!(m.memory_bytes, 0);
         assert_eq!(m.sparse_retired_ranges, 0);
@@ -66,6 +89,8 @@ mod tests {
         assert_eq!(m.sparse_reuse_misses, 0);
         assert_eq!(m.queue_full_rejections, 0);
         assert_eq!(m.tick_disabled_rejections, 0);
+        assert_eq!(m.dropped_commands, 0);
+        assert_eq!(m.rejected_full_events, 0);
         assert_eq!(m.rollback_events, 0);
         assert_eq!(m.tick_disabled_transitions, 0);
         assert_eq!(m.worker_stall_events, 0);
@@ -80,7 +105,20 @@ mod tests {
         let m = StepMetrics {
             total_us: 100,
             command_processing_us: 20,
-            propagator_us: vec![("diffusion".to_string(), 50), ("decay".to_string(), 30)],
+            propagators: vec![
+                PropagatorMetrics {
+                    name: "diffusion".to_string(),
+                    us: 50,
+                    reads_cells: 100,
+                    writes_cells: 100,
+                },
+                PropagatorMetrics {
+                    name: "decay".to_string(),
+                    us: 30,
+                    reads_cells: 0,
+                    writes_cells: 100,
+                },
+            ],
             snapshot_publish_us: 10,
             memory_bytes: 4096,
             sparse_retired_ranges: 3,
@@ -89,6 +127,8 @@ mod tests {
             sparse_reuse_misses: 2,
             queue_full_rejections: 11,
             tick_disabled_rejections: 4,
+            dropped_commands: 15,
+            rejected_full_events: 6,
             rollback_events: 2,
             tick_disabled_transitions: 1,
             worker_stall_events: 3,
@@ -99,9 +139,10 @@ mod tests {
         };
         assert_eq!(m.total_us, 100);
         assert_eq!(m.command_processing_us, 20);
-        assert_eq!(m.propagator_us.len(), 2);
-        assert_eq!(m.propagator_us[0].0, "diffusion");
-        assert_eq!(m.propagator_us[0].1, 50);
+        assert_eq!(m.propagators.len(), 2);
+        assert_eq!(m.propagators[0].name, "diffusion");
+        assert_eq!(m.propagators[0].us, 50);
+        assert_eq!(m.propagators[1].reads_cells, 0);
         assert_eq!(m.snapshot_publish_us, 10);
         assert_eq!(m.memory_bytes, 4096);
         assert_eq!(m.sparse_retired_ranges, 3);
@@ -110,6 +151,8 @@ mod tests {
         assert_eq!(m.sparse_reuse_misses, 2);
         assert_eq!(m.queue_full_rejections, 11);
         assert_eq!(m.tick_disabled_rejections, 4);
+        assert_eq!(m.dropped_commands, 15);
+        assert_eq!(m.rejected_full_events, 6);
         assert_eq!(m.rollback_events, 2);
         assert_eq!(m.tick_disabled_transitions, 1);
         assert_eq!(m.worker_stall_events, 3);
@@ -16,6 +16,7 @@
 use indexmap::IndexMap;
 use murk_core::id::FieldId;
 use murk_core::traits::FieldReader;
+use murk_core::Scalar;
 use murk_propagator::pipeline::ReadSource;
 
 // ── BaseFieldSet ─────────────────────────────────────────────────
@@ -68,6 +69,15 @@ impl BaseFieldSet {
         }
     }
 
+    /// Add further fields to copy, skipping any already present.
+    pub(crate) fn extend(&mut self, fields: impl IntoIterator<Item = FieldId>) {
+        for field in fields {
+            if !self.field_ids.contains(&field) {
+                self.field_ids.push(field);
+            }
+        }
+    }
+
     /// The field IDs that must be copied from the base snapshot.
     pub(crate) fn field_ids(&self) -> &[FieldId] {
         &self.field_ids
@@ -81,10 +91,10 @@ impl BaseFieldSet {
 /// Populated once per tick before `begin_tick()` by reading from
 /// [`Snapshot`](murk_arena::Snapshot).
 ///
-/// Uses `Option<Vec<f32>>` to distinguish unpopulated/stale fields (`None`)
+/// Uses `Option<Vec<Scalar>>` to distinguish unpopulated/stale fields (`None`)
 /// from populated fields that happen to be empty (`Some(vec![])`).
 pub(crate) struct BaseFieldCache {
-    entries: IndexMap<FieldId, Option<Vec<f32>>>,
+    entries: IndexMap<FieldId, Option<Vec<Scalar>>>,
 }
 
 impl BaseFieldCache {
@@ -117,7 +127,7 @@ impl BaseFieldCache {
 }
 
 impl FieldReader for BaseFieldCache {
-    fn read(&self, field: FieldId) -> Option<&[f32]> {
+    fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         self.entries.get(&field).and_then(|v| v.as_deref())
     }
 }
@@ -128,10 +138,10 @@ impl FieldReader for BaseFieldCache {
 ///
 /// Cleared and refilled between propagators.
 ///
-/// Uses `Option<Vec<f32>>` to distinguish stale/cleared fields (`None`)
+/// Uses `Option<Vec<Scalar>>` to distinguish stale/cleared fields (`None`)
 /// from populated fields that happen to be empty (`Some(vec![])`).
 pub(crate) struct StagedFieldCache {
-    entries: IndexMap<FieldId, Option<Vec<f32>>>,
+    entries: IndexMap<FieldId, Option<Vec<Scalar>>>,
 }
 
 impl StagedFieldCache {
@@ -150,7 +160,7 @@ impl StagedFieldCache {
     }
 
     /// Insert (or replace) a field's data.
-    pub(crate) fn insert(&mut self, field: FieldId, data: &[f32]) {
+    pub(crate) fn insert(&mut self, field: FieldId, data: &[Scalar]) {
         let slot = self.entries.entry(field).or_insert(None);
         let buf = slot.get_or_insert_with(|| Vec::with_capacity(data.len()));
         buf.clear();
@@ -159,7 +169,7 @@ impl StagedFieldCache {
 }
 
 impl FieldReader for StagedFieldCache {
-    fn read(&self, field: FieldId) -> Option<&[f32]> {
+    fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         self.entries.get(&field).and_then(|v| v.as_deref())
     }
 }
@@ -192,7 +202,7 @@ impl<'a> OverlayReader<'a> {
 }
 
 impl FieldReader for OverlayReader<'_> {
-    fn read(&self, field: FieldId) -> Option<&[f32]> {
+    fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         match self.routes.get(&field)? {
             ReadSource::BaseGen => self.base_cache.read(field),
             ReadSource::Staged { .. } => self.staged_cache.read(field),
@@ -204,7 +214,7 @@ impl FieldReader for OverlayReader<'_> {
 mod tests {
     use super::*;
 
-    fn base_cache_with(fields: &[(FieldId, Vec<f32>)]) -> BaseFieldCache {
+    fn base_cache_with(fields: &[(FieldId, Vec<Scalar>)]) -> BaseFieldCache {
         let mut cache = BaseFieldCache::new();
         for (id, data) in fields {
             cache.entries.insert(*id, Some(data.clone()));
@@ -212,7 +222,7 @@ mod tests {
         cache
     }
 
-    fn staged_cache_with(fields: &[(FieldId, Vec<f32>)]) -> StagedFieldCache {
+    fn staged_cache_with(fields: &[(FieldId, Vec<Scalar>)]) -> StagedFieldCache {
         let mut cache = StagedFieldCache::new();
         for (id, data) in fields {
             cache.insert(*id, data);
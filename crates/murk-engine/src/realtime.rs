@@ -33,7 +33,7 @@ use std::time::{Duration, Instant};
 
 use murk_arena::OwnedSnapshot;
 use murk_core::command::{Command, Receipt};
-use murk_core::error::ObsError;
+use murk_core::error::{IngressError, ObsError};
 use murk_core::Coord;
 use murk_obs::{ObsMetadata, ObsPlan};
 use murk_space::Space;
@@ -54,6 +54,14 @@ pub enum SubmitError {
     Shutdown,
     /// The command channel is full (back-pressure).
     ChannelFull,
+    /// The tick engine's ingress queue rejected every command in the
+    /// batch because it was full.
+    QueueFull {
+        /// Commands buffered in ingress when the batch was rejected.
+        depth: usize,
+        /// Maximum number of commands ingress can buffer.
+        capacity: usize,
+    },
 }
 
 impl std::fmt::Display for SubmitError {
@@ -61,12 +69,26 @@ impl std::fmt::Display for SubmitError {
         match self {
             Self::Shutdown => write!(f, "tick thread has shut down"),
             Self::ChannelFull => write!(f, "command channel full"),
+            Self::QueueFull { depth, capacity } => {
+                write!(f, "ingress queue full ({depth}/{capacity})")
+            }
         }
     }
 }
 
 impl std::error::Error for SubmitError {}
 
+/// Outcome of [`RealtimeAsyncWorld::submit_with_status`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmitStatus {
+    /// One receipt per submitted command.
+    pub receipts: Vec<Receipt>,
+    /// Commands buffered in ingress immediately after this submit.
+    pub queue_depth: usize,
+    /// Maximum number of commands ingress can buffer.
+    pub queue_capacity: usize,
+}
+
 // ── ShutdownReport ───────────────────────────────────────────────
 
 /// Report from the shutdown state machine.
@@ -199,9 +221,17 @@ impl RealtimeAsyncWorld {
             max_ingress_queue: config.max_ingress_queue,
             tick_rate_hz: config.tick_rate_hz,
             backoff: backoff_config.clone(),
+            strict_units: config.strict_units,
+            detect_nonfinite: config.detect_nonfinite,
+            track_changed_fields: config.track_changed_fields,
+            conservation_check: config.conservation_check,
+            conservation: config.conservation,
+            initializers: config.initializers,
+            parallel: config.parallel,
         };
 
-        let engine = TickEngine::new(engine_config)?;
+        let mut engine = TickEngine::new(engine_config)?;
+        engine.set_wall_clock_expiry(true);
 
         let worker_count = async_config.resolved_worker_count();
         let ring = Arc::new(SnapshotRing::new(ring_size));
@@ -293,6 +323,39 @@ impl RealtimeAsyncWorld {
     /// Non-blocking: sends the batch via channel and blocks only for
     /// the receipt reply (which arrives within one tick period).
     pub fn submit_commands(&self, commands: Vec<Command>) -> Result<Vec<Receipt>, SubmitError> {
+        self.submit_raw(commands).map(|status| status.receipts)
+    }
+
+    /// Submit commands and report how full the ingress queue is afterwards.
+    ///
+    /// Like [`submit_commands`](Self::submit_commands), but the returned
+    /// [`SubmitStatus`] carries the post-submit queue depth and capacity so
+    /// callers can throttle before commands start bouncing. A batch that
+    /// is partly accepted still returns `Ok`; its tail receipts carry
+    /// `IngressError::QueueFull`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SubmitError::QueueFull`] if the queue was full and no
+    /// command in a non-empty batch was accepted, plus the errors of
+    /// [`submit_commands`](Self::submit_commands).
+    pub fn submit_with_status(&self, commands: Vec<Command>) -> Result<SubmitStatus, SubmitError> {
+        let status = self.submit_raw(commands)?;
+        let all_full = !status.receipts.is_empty()
+            && status
+                .receipts
+                .iter()
+                .all(|r| r.reason_code == Some(IngressError::QueueFull));
+        if all_full {
+            return Err(SubmitError::QueueFull {
+                depth: status.queue_depth,
+                capacity: status.queue_capacity,
+            });
+        }
+        Ok(status)
+    }
+
+    fn submit_raw(&self, commands: Vec<Command>) -> Result<SubmitStatus, SubmitError> {
         let cmd_tx = self.cmd_tx.as_ref().ok_or(SubmitError::Shutdown)?;
 
         let (reply_tx, reply_rx) = crossbeam_channel::bounded(1);
@@ -391,11 +454,13 @@ impl RealtimeAsyncWorld {
 
         let (reply_tx, reply_rx) = crossbeam_channel::bounded(1);
         let n_agents = agent_centers.len();
+        #[allow(clippy::manual_checked_ops)]
         let per_agent_output = if n_agents > 0 {
             output.len() / n_agents
         } else {
             0
         };
+        #[allow(clippy::manual_checked_ops)]
         let per_agent_mask = if n_agents > 0 {
             mask.len() / n_agents
         } else {
@@ -819,6 +884,10 @@ impl murk_space::Space for ArcSpaceWrapper {
             self.0.topology_eq(other)
         }
     }
+
+    fn topology_digest(&self) -> Option<u64> {
+        self.0.topology_digest()
+    }
 }
 
 // Delegate optional Space methods if they exist.
@@ -831,10 +900,13 @@ impl std::fmt::Debug for ArcSpaceWrapper {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use murk_core::id::FieldId;
-    use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType};
+    use murk_core::id::{FieldId, TickId};
+    use murk_core::{
+        BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldType, PropagatorError,
+    };
     use murk_obs::spec::ObsRegion;
     use murk_obs::{ObsEntry, ObsSpec};
+    use murk_propagator::{Propagator, StepContext, WriteMode};
     use murk_space::{EdgeBehavior, Line1D};
     use murk_test_utils::ConstPropagator;
 
@@ -904,7 +976,9 @@ mod tests {
                 pool: None,
                 transform: murk_obs::spec::ObsTransform::Identity,
                 dtype: murk_obs::spec::ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let plan_result = ObsPlan::compile(&spec, space).unwrap();
         let plan = Arc::new(plan_result.plan);
@@ -941,7 +1015,9 @@ mod tests {
                 pool: None,
                 transform: murk_obs::spec::ObsTransform::Identity,
                 dtype: murk_obs::spec::ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let plan_result = ObsPlan::compile(&spec, space).unwrap();
         let plan = Arc::new(plan_result.plan);
@@ -993,6 +1069,7 @@ mod tests {
                 value: 1.0,
             },
             expires_after_tick: murk_core::id::TickId(10000),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -1171,9 +1248,123 @@ mod tests {
         world.shutdown();
     }
 
+    /// Start a 0.5 Hz world and wait for its first publish, so batches
+    /// submitted next all land in the same drain of the command channel.
+    fn slow_world(max_ingress_queue: usize) -> RealtimeAsyncWorld {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy")])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(0), 1.0))])
+            .dt(0.1)
+            .seed(42)
+            .tick_rate_hz(0.5)
+            .max_ingress_queue(max_ingress_queue)
+            .build()
+            .unwrap();
+        let world = RealtimeAsyncWorld::new(config, AsyncConfig::default()).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while world.latest_snapshot().is_none() {
+            if Instant::now() > deadline {
+                panic!("no snapshot produced within 5s");
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        world
+    }
+
+    /// Submit one single-command batch from each of `n` threads at once.
+    fn submit_concurrently(
+        world: &RealtimeAsyncWorld,
+        n: usize,
+    ) -> Vec<Result<SubmitStatus, SubmitError>> {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..n)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let cmd = Command::set_parameter(murk_core::id::ParameterKey(0), 1.0);
+                        world.submit_with_status(vec![cmd])
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        })
+    }
+
+    #[test]
+    fn submit_with_status_reports_increasing_depth() {
+        let mut world = slow_world(4);
+        let mut depths: Vec<usize> = submit_concurrently(&world, 3)
+            .into_iter()
+            .map(|r| {
+                let status = r.unwrap();
+                assert!(status.receipts[0].accepted);
+                assert_eq!(status.queue_capacity, 4);
+                status.queue_depth
+            })
+            .collect();
+        depths.sort_unstable();
+        assert_eq!(depths, vec![1, 2, 3]);
+        world.shutdown();
+    }
+
+    #[test]
+    fn submit_with_status_overflow_is_queue_full() {
+        let mut world = slow_world(2);
+        let results = submit_concurrently(&world, 3);
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 2);
+        let errors: Vec<_> = results.into_iter().filter_map(Result::err).collect();
+        assert_eq!(
+            errors,
+            vec![SubmitError::QueueFull {
+                depth: 2,
+                capacity: 2
+            }]
+        );
+        world.shutdown();
+    }
+
+    /// Writes a constant like [`ConstPropagator`], but holds tick 1 while
+    /// `open` is false, so a test can observe a freshly reset world
+    /// before the respawned tick thread publishes.
+    struct GatedFirstTick {
+        inner: ConstPropagator,
+        open: Arc<AtomicBool>,
+    }
+
+    impl Propagator for GatedFirstTick {
+        fn name(&self) -> &str {
+            self.inner.name()
+        }
+        fn reads(&self) -> FieldSet {
+            self.inner.reads()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            self.inner.writes()
+        }
+        fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+            while ctx.tick_id() == TickId(1) && !self.open.load(Ordering::Acquire) {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            self.inner.step(ctx)
+        }
+    }
+
     #[test]
     fn reset_lifecycle() {
-        let mut world = RealtimeAsyncWorld::new(test_config(), AsyncConfig::default()).unwrap();
+        let open = Arc::new(AtomicBool::new(true));
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy")])
+            .propagators(vec![Box::new(GatedFirstTick {
+                inner: ConstPropagator::new("const", FieldId(0), 42.0),
+                open: Arc::clone(&open),
+            })])
+            .dt(0.1)
+            .seed(42)
+            .tick_rate_hz(60.0)
+            .build()
+            .unwrap();
+        let mut world = RealtimeAsyncWorld::new(config, AsyncConfig::default()).unwrap();
 
         // Wait for some ticks (generous timeout for slow CI runners).
         let deadline = Instant::now() + Duration::from_secs(5);
@@ -1186,11 +1377,13 @@ mod tests {
         let epoch_before = world.current_epoch();
         assert!(epoch_before >= 5);
 
-        // Reset with a new seed.
+        // Reset with a new seed, holding the respawned thread's first tick.
+        open.store(false, Ordering::Release);
         world.reset(99).unwrap();
 
         // After reset, epoch should restart from 0.
         assert_eq!(world.current_epoch(), 0);
+        open.store(true, Ordering::Release);
 
         // The world should produce new snapshots.
         let deadline = Instant::now() + Duration::from_secs(2);
@@ -262,7 +262,7 @@ mod tests {
     use murk_arena::static_arena::StaticArena;
     use murk_core::id::{FieldId, ParameterVersion, TickId};
     use murk_core::traits::{FieldWriter as _, SnapshotAccess};
-    use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType};
+    use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType, Scalar};
 
     fn make_test_snapshot(tick: u64) -> OwnedSnapshot {
         let cell_count = 10u32;
@@ -284,7 +284,7 @@ mod tests {
         {
             let mut guard = arena.begin_tick().unwrap();
             let data = guard.writer.write(FieldId(0)).unwrap();
-            data.fill(tick as f32);
+            data.fill(tick as Scalar);
         }
         arena.publish(TickId(tick), ParameterVersion(0)).unwrap();
         arena.owned_snapshot()
@@ -11,24 +11,29 @@
 //! a thread with a ring buffer.
 
 use std::fmt;
+use std::sync::Arc;
 use std::time::Instant;
 
 use murk_arena::config::ArenaConfig;
 use murk_arena::pingpong::PingPongArena;
 use murk_arena::read::Snapshot;
 use murk_arena::static_arena::StaticArena;
+use murk_arena::write::WriteArena;
 use murk_core::command::{Command, CommandPayload, Receipt};
 use murk_core::error::{IngressError, StepError};
 use murk_core::id::{FieldId, ParameterVersion, TickId};
 use murk_core::traits::{FieldReader, FieldWriter};
-use murk_core::FieldMutability;
+use murk_core::{scalar_to_f64, BoundaryBehavior, Coord, FieldMutability, ParameterStore, Scalar};
 use murk_propagator::pipeline::{ReadResolutionPlan, ReadSource};
 use murk_propagator::propagator::Propagator;
 use murk_propagator::scratch::ScratchRegion as PropagatorScratch;
 
-use crate::config::{ConfigError, WorldConfig};
+use crate::config::{
+    ConfigError, ConservationAction, ConservationConfig, FieldInitializer, WorldConfig,
+};
+use crate::egress::{CellChange, ChangeSubscriptions, ChangedFieldTracker, SubscriptionId};
 use crate::ingress::IngressQueue;
-use crate::metrics::StepMetrics;
+use crate::metrics::{PropagatorMetrics, StepMetrics};
 use crate::overlay::{BaseFieldCache, BaseFieldSet, OverlayReader, StagedFieldCache};
 
 // ── TickResult ───────────────────────────────────────────────────
@@ -40,6 +45,33 @@ pub struct TickResult {
     pub receipts: Vec<Receipt>,
     /// Performance metrics for this tick.
     pub metrics: StepMetrics,
+    /// Failures of [`FailurePolicy::SkipFailed`] propagators that this tick
+    /// absorbed, each a [`StepError::PropagatorFailed`], and conservation
+    /// audit violations reported under [`ConservationAction::Report`], each
+    /// a [`StepError::ConservationViolated`]. In pipeline order.
+    pub soft_failures: Vec<StepError>,
+    /// Fields whose published contents differ from the previous
+    /// generation, in field order. Always empty unless
+    /// [`WorldConfigBuilder::track_changed_fields`](crate::config::WorldConfigBuilder::track_changed_fields)
+    /// is enabled.
+    pub changed_fields: Vec<FieldId>,
+}
+
+// ── FailurePolicy ───────────────────────────────────────────────
+
+/// How [`TickEngine::execute_tick`] reacts when a propagator's `step()`
+/// returns an error.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FailurePolicy {
+    /// Abandon the whole tick and count toward the consecutive-rollback
+    /// limit. The default.
+    #[default]
+    RollbackAll,
+    /// Discard only this propagator's writes: every field it writes keeps
+    /// its previous-generation value, later propagators run as normal, and
+    /// the tick commits. The failure is reported in
+    /// [`TickResult::soft_failures`].
+    SkipFailed,
 }
 
 // ── TickError ───────────────────────────────────────────────────
@@ -102,6 +134,27 @@ struct FieldExpectations {
     read_previous: Vec<Vec<(FieldId, usize)>>,
     /// `write[propagator_index]` = Vec of (FieldId, expected_len).
     write: Vec<Vec<(FieldId, usize)>>,
+    /// `cells[propagator_index]` = (values read, values written) per tick,
+    /// reported in [`PropagatorMetrics`].
+    cells: Vec<(u64, u64)>,
+}
+
+/// Per-propagator metrics entry for a step that started at `start`.
+fn propagator_metrics(name: String, start: Instant, cells: (u64, u64)) -> PropagatorMetrics {
+    PropagatorMetrics {
+        name,
+        us: start.elapsed().as_micros() as u64,
+        reads_cells: cells.0,
+        writes_cells: cells.1,
+    }
+}
+
+/// A written field with declared `bounds`, post-processed after each step.
+struct BoundedWrite {
+    field: FieldId,
+    /// `(min, max)` per component; values are interleaved by component.
+    ranges: Vec<(f32, f32)>,
+    behavior: BoundaryBehavior,
 }
 
 /// Single-threaded tick engine for Lockstep mode.
@@ -112,31 +165,63 @@ struct FieldExpectations {
 pub struct TickEngine {
     arena: PingPongArena,
     propagators: Vec<Box<dyn Propagator>>,
+    /// `propagator_enabled[i]` is `false` if propagator `i` is skipped.
+    propagator_enabled: Vec<bool>,
+    /// `failure_policies[i]` governs a `step()` error from propagator `i`.
+    failure_policies: Vec<FailurePolicy>,
     plan: ReadResolutionPlan,
     expectations: FieldExpectations,
+    /// `write_bounds[i]` = bounded fields written by propagator `i`.
+    write_bounds: Vec<Vec<BoundedWrite>>,
     ingress: IngressQueue,
     space: Box<dyn murk_space::Space>,
+    /// Field definitions, indexed by `FieldId`.
+    fields: Vec<murk_core::FieldDef>,
     dt: f64,
     current_tick: TickId,
+    /// Cumulative `dt` over all committed ticks.
+    sim_time: f64,
     param_version: ParameterVersion,
+    parameters: ParameterStore,
     consecutive_rollback_count: u32,
     tick_disabled: bool,
     max_consecutive_rollbacks: u32,
+    /// Scan written fields for NaN/infinity before publishing.
+    detect_nonfinite: bool,
+    /// `conservation_audits[i]` = conserved fields audited around
+    /// propagator `i`, each flagged `true` if an earlier propagator stages
+    /// it first. Empty unless the conservation audit is enabled.
+    conservation_audits: Vec<Vec<(FieldId, bool)>>,
+    conservation: ConservationConfig,
     counters: CumulativeCounters,
-    propagator_scratch: PropagatorScratch,
+    /// `propagator_scratch[i]` = scratch region sized to propagator `i`'s
+    /// own `scratch_bytes()`.
+    propagator_scratch: Vec<PropagatorScratch>,
     base_field_set: BaseFieldSet,
     base_cache: BaseFieldCache,
     staged_cache: StagedFieldCache,
     last_metrics: StepMetrics,
+    /// Initializers for non-static fields, re-applied on reset.
+    initializers: Vec<(FieldId, FieldInitializer)>,
+    /// Per-tick changed-cell callbacks.
+    change_subscriptions: ChangeSubscriptions,
+    /// Changed-field baseline; `None` unless tracking is enabled.
+    changed_fields: Option<ChangedFieldTracker>,
+    /// Called with the attempted tick ID whenever a tick is rolled back.
+    rollback_hook: Option<RollbackHook>,
 }
 
+/// Callback invoked by [`TickEngine::set_rollback_hook`] on rollback.
+pub type RollbackHook = Box<dyn FnMut(TickId, &StepError) + Send>;
+
 impl TickEngine {
     /// Construct a new tick engine from a [`WorldConfig`].
     ///
     /// Validates the configuration, builds the read resolution plan,
-    /// constructs the arena, and pre-computes the base field set.
+    /// runs each propagator's [`init`](Propagator::init) hook, constructs
+    /// the arena, and pre-computes the base field set.
     /// Consumes the `WorldConfig`.
-    pub fn new(config: WorldConfig) -> Result<Self, ConfigError> {
+    pub fn new(mut config: WorldConfig) -> Result<Self, ConfigError> {
         // This validate() call is intentional even though WorldConfigBuilder::build()
         // also validates. TickEngine::new() may receive configs constructed via
         // pub(crate) struct literals (e.g., the ArcSpaceWrapper reconstruction in
@@ -151,6 +236,15 @@ impl TickEngine {
             &*config.space,
         )?;
 
+        // One-time, space-dependent propagator setup.
+        for prop in &mut config.propagators {
+            prop.init(&*config.space)
+                .map_err(|error| ConfigError::PropagatorInit {
+                    propagator: prop.name().to_string(),
+                    error,
+                })?;
+        }
+
         // Build arena field defs.
         let arena_field_defs: Vec<(FieldId, murk_core::FieldDef)> = config
             .fields
@@ -243,13 +337,57 @@ impl TickEngine {
                 read_prev_exp.push(reads_prev);
                 write_exp.push(writes);
             }
+            let cells = config
+                .propagators
+                .iter()
+                .enumerate()
+                .map(|(i, prop)| {
+                    let total = |exp: &[(FieldId, usize)]| -> u64 {
+                        exp.iter().map(|&(_, len)| len as u64).sum()
+                    };
+                    // Undefined optional reads are skipped, not errors.
+                    let optional: u64 = prop
+                        .optional_reads()
+                        .iter()
+                        .filter(|fid| !read_exp[i].iter().any(|(r, _)| r == fid))
+                        .filter_map(|fid| field_total_lens.get(&fid))
+                        .map(|&len| len as u64)
+                        .sum();
+                    let reads = total(&read_exp[i]) + total(&read_prev_exp[i]) + optional;
+                    (reads, total(&write_exp[i]))
+                })
+                .collect();
             FieldExpectations {
                 read: read_exp,
                 read_previous: read_prev_exp,
                 write: write_exp,
+                cells,
             }
         };
 
+        // Bounded fields each propagator writes, post-processed after step().
+        let write_bounds = config
+            .propagators
+            .iter()
+            .map(|prop| {
+                prop.writes()
+                    .iter()
+                    .filter_map(|&(field, _)| {
+                        let def = config.fields.get(field.0 as usize)?;
+                        let bounds = def.bounds.as_ref()?;
+                        let ranges = (0..def.field_type.components() as usize)
+                            .map(|c| bounds.component(c))
+                            .collect::<Option<Vec<_>>>()?;
+                        Some(BoundedWrite {
+                            field,
+                            ranges,
+                            behavior: def.boundary_behavior,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
         let arena_config = ArenaConfig::new(cell_count);
 
         // Build static arena for any Static fields.
@@ -265,43 +403,106 @@ impl TickEngine {
                 Ok((*id, len))
             })
             .collect::<Result<_, ConfigError>>()?;
-        let static_arena = StaticArena::new(&static_fields).into_shared();
-
-        let arena = PingPongArena::new(arena_config, arena_field_defs, static_arena)?;
+        // Static fields are initialised once; the shared static arena is
+        // immutable afterwards and survives reset() untouched.
+        let mut static_arena = StaticArena::new(&static_fields);
+        let mut initializers = Vec::with_capacity(config.initializers.len());
+        let mut ordering: Option<Vec<Coord>> = None;
+        for (field, init) in config.initializers {
+            match static_arena.write_field(field) {
+                Some(buf) => {
+                    let ordering =
+                        ordering.get_or_insert_with(|| config.space.canonical_ordering());
+                    fill_from_initializer(buf, ordering, &init);
+                }
+                None => initializers.push((field, init)),
+            }
+        }
+        let static_arena = static_arena.into_shared();
+
+        let mut arena = PingPongArena::new(arena_config, arena_field_defs, static_arena)?;
+        apply_initializers(&mut arena, &*config.space, &initializers);
+
+        // Conserved fields to audit per propagator. A field no earlier
+        // propagator writes is totalled from the base generation, so it must
+        // be in the base field set.
+        let mut conservation_audits = vec![Vec::new(); config.propagators.len()];
+        if config.conservation_check {
+            for (i, prop) in config.propagators.iter().enumerate() {
+                let conserved = prop.conserved_fields();
+                conservation_audits[i] = expectations.write[i]
+                    .iter()
+                    .filter(|&&(field, _)| conserved.contains(field))
+                    .map(|&(field, _)| {
+                        let staged = expectations.write[..i]
+                            .iter()
+                            .any(|w| w.iter().any(|&(f, _)| f == field));
+                        (field, staged)
+                    })
+                    .collect();
+            }
+        }
 
         // Pre-compute base field set.
-        let base_field_set = BaseFieldSet::from_plan(&plan, &config.propagators);
+        let mut base_field_set = BaseFieldSet::from_plan(&plan, &config.propagators);
+        base_field_set.extend(audited_base_fields(&conservation_audits));
 
-        // Compute max scratch bytes across all propagators.
-        let max_scratch = config
+        // One scratch region per propagator, sized to its own request;
+        // zero-scratch propagators get an unallocated region.
+        let propagator_scratch = config
             .propagators
             .iter()
-            .map(|p| p.scratch_bytes())
-            .max()
-            .unwrap_or(0);
-        let propagator_scratch = PropagatorScratch::with_byte_capacity(max_scratch);
+            .map(|p| PropagatorScratch::with_byte_capacity(p.scratch_bytes()))
+            .collect();
 
         let ingress = IngressQueue::new(config.max_ingress_queue);
 
+        let changed_fields = config.track_changed_fields.then(|| {
+            let tracked = config
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, def)| def.mutability != FieldMutability::Static)
+                .map(|(i, _)| FieldId(i as u32))
+                .collect();
+            ChangedFieldTracker::new(tracked)
+        });
+
+        let propagator_enabled = vec![true; config.propagators.len()];
+        let failure_policies = vec![FailurePolicy::RollbackAll; config.propagators.len()];
+
         Ok(Self {
             arena,
             propagators: config.propagators,
+            propagator_enabled,
+            failure_policies,
             plan,
             expectations,
+            write_bounds,
             ingress,
             space: config.space,
+            fields: config.fields,
             dt: config.dt,
             current_tick: TickId(0),
+            sim_time: 0.0,
             param_version: ParameterVersion(0),
+            parameters: ParameterStore::new(),
             consecutive_rollback_count: 0,
             tick_disabled: false,
             max_consecutive_rollbacks: 3,
+            detect_nonfinite: config.detect_nonfinite,
+            conservation_audits,
+            conservation: config.conservation,
             counters: CumulativeCounters::default(),
             propagator_scratch,
             base_field_set,
             base_cache: BaseFieldCache::new(),
             staged_cache: StagedFieldCache::new(),
             last_metrics: StepMetrics::default(),
+            initializers,
+            change_subscriptions: ChangeSubscriptions::default(),
+            changed_fields,
+            rollback_hook: None,
         })
     }
 
@@ -322,10 +523,12 @@ impl TickEngine {
                 }
                 // Accepted commands and other rejection types don't need counting.
                 Some(IngressError::Stale)
+                | Some(IngressError::Expired)
                 | Some(IngressError::TickRollback)
                 | Some(IngressError::ShuttingDown)
                 | Some(IngressError::UnsupportedCommand)
                 | Some(IngressError::NotApplied)
+                | Some(IngressError::PartiallyApplied { .. })
                 | None => {}
             }
         }
@@ -377,7 +580,9 @@ impl TickEngine {
                 command_index: dc.command_index,
             });
         }
-        // 3b. Apply commands to the staging writer.
+        // 3b. Apply commands to the staging writer. Parameter commands go
+        // to a copy of the store that is only committed if the tick succeeds.
+        let mut staged_params: Option<ParameterStore> = None;
         for (i, dc) in commands.iter().enumerate() {
             let receipt = &mut receipts[accepted_receipt_start + i];
             match &dc.command.payload {
@@ -386,28 +591,39 @@ impl TickEngine {
                     field_id,
                     value,
                 } => {
-                    let applied = if let Some(rank) = self.space.canonical_rank(coord) {
-                        if let Some(buf) = guard.writer.write(*field_id) {
-                            if rank < buf.len() {
-                                buf[rank] = *value;
-                                true
-                            } else {
-                                false
-                            }
-                        } else {
-                            false
-                        }
-                    } else {
-                        false
-                    };
+                    let applied =
+                        set_cell(&*self.space, &mut guard.writer, *field_id, coord, *value);
                     if !applied {
                         receipt.accepted = false;
                         receipt.reason_code = Some(IngressError::NotApplied);
                     }
                 }
-                CommandPayload::SetParameter { .. }
-                | CommandPayload::SetParameterBatch { .. }
-                | CommandPayload::Move { .. }
+                CommandPayload::SetFieldBatch { field_id, cells } => {
+                    let mut skipped = 0;
+                    for (coord, value) in cells {
+                        if !set_cell(&*self.space, &mut guard.writer, *field_id, coord, *value) {
+                            skipped += 1;
+                        }
+                    }
+                    if skipped == cells.len() && !cells.is_empty() {
+                        receipt.accepted = false;
+                        receipt.reason_code = Some(IngressError::NotApplied);
+                    } else if skipped > 0 {
+                        receipt.reason_code = Some(IngressError::PartiallyApplied { skipped });
+                    }
+                }
+                CommandPayload::SetParameter { key, value } => {
+                    staged_params
+                        .get_or_insert_with(|| self.parameters.clone())
+                        .set(*key, *value);
+                }
+                CommandPayload::SetParameterBatch { params } => {
+                    let store = staged_params.get_or_insert_with(|| self.parameters.clone());
+                    for &(key, value) in params {
+                        store.set(key, value);
+                    }
+                }
+                CommandPayload::Move { .. }
                 | CommandPayload::Spawn { .. }
                 | CommandPayload::Despawn { .. }
                 | CommandPayload::Custom { .. } => {
@@ -418,9 +634,41 @@ impl TickEngine {
         }
         let command_processing_us = cmd_start.elapsed().as_micros() as u64;
 
-        // 4. Run propagator pipeline.
-        let mut propagator_us = Vec::with_capacity(self.propagators.len());
+        // 4. Run propagator pipeline. The parameter store is frozen from here.
+        let parameters = staged_params.as_ref().unwrap_or(&self.parameters);
+        let mut propagators = Vec::with_capacity(self.propagators.len());
+        let mut soft_failures = Vec::new();
         for (i, prop) in self.propagators.iter().enumerate() {
+            // 4-. Disabled propagators are skipped; their write fields carry
+            // the previous generation forward unchanged.
+            if !self.propagator_enabled[i] {
+                let carried = carry_forward(
+                    &mut guard.writer,
+                    &self.base_cache,
+                    &self.expectations.write[i],
+                );
+                if let Err(field) = carried {
+                    let prop_name = prop.name().to_string();
+                    return self.handle_rollback(
+                        prop_name,
+                        murk_core::PropagatorError::ExecutionFailed {
+                            reason: format!(
+                                "cannot carry forward field {:?} of disabled propagator",
+                                field,
+                            ),
+                        },
+                        receipts,
+                        accepted_receipt_start,
+                    );
+                }
+                // Keep metrics indexed by pipeline position.
+                propagators.push(PropagatorMetrics {
+                    name: prop.name().to_string(),
+                    ..PropagatorMetrics::default()
+                });
+                continue;
+            }
+
             let prop_start = Instant::now();
 
             // 4a. Populate staged cache from guard.writer.read() per plan routes.
@@ -449,7 +697,7 @@ impl TickEngine {
                 };
                 // Copy through a temp buffer: base_cache borrows &self,
                 // guard.writer.write() borrows &mut guard.
-                let prev: Vec<f32> = prev_data.to_vec();
+                let prev: Vec<Scalar> = prev_data.to_vec();
                 let write_buf = match guard.writer.write(field) {
                     Some(buf) => buf,
                     None => {
@@ -488,7 +736,7 @@ impl TickEngine {
             }
 
             // 4d. Reset propagator scratch.
-            self.propagator_scratch.reset();
+            self.propagator_scratch[i].reset();
 
             // 4dx. Validate field buffer lengths before dispatch.
             for &(field_id, expected_len) in &self.expectations.read[i] {
@@ -598,23 +846,42 @@ impl TickEngine {
                 }
             }
 
+            // 4dy. Conservation audit: total each conserved field as seen
+            // before this propagator runs.
+            let totals_before: Vec<Option<(f64, f64)>> = self.conservation_audits[i]
+                .iter()
+                .map(|&(field, staged)| {
+                    let buf = if staged {
+                        guard.writer.read(field)
+                    } else {
+                        self.base_cache.read(field)
+                    };
+                    buf.map(field_totals)
+                })
+                .collect();
+
             // 4e. Construct StepContext and call step().
-            {
+            let step_result = {
                 let mut ctx = murk_propagator::StepContext::new(
                     &overlay,
                     &self.base_cache,
                     &mut guard.writer,
-                    &mut self.propagator_scratch,
+                    &mut self.propagator_scratch[i],
                     self.space.as_ref(),
                     next_tick,
                     self.dt,
-                );
+                )
+                .with_parameters(parameters)
+                .with_sim_time(self.sim_time + self.dt);
 
                 // 4f. Call propagator step.
-                if let Err(reason) = prop.step(&mut ctx) {
+                prop.step(&mut ctx)
+            };
+            if let Err(reason) = step_result {
+                let prop_name = prop.name().to_string();
+                if self.failure_policies[i] == FailurePolicy::RollbackAll {
                     // 4g. Rollback on error — guard goes out of scope,
                     // abandoning the staging buffer (free rollback).
-                    let prop_name = prop.name().to_string();
                     return self.handle_rollback(
                         prop_name,
                         reason,
@@ -622,32 +889,147 @@ impl TickEngine {
                         accepted_receipt_start,
                     );
                 }
+                // 4g'. Soft failure: discard this propagator's (possibly
+                // partial) writes and carry its fields forward instead.
+                let carried = carry_forward(
+                    &mut guard.writer,
+                    &self.base_cache,
+                    &self.expectations.write[i],
+                );
+                if let Err(field) = carried {
+                    return self.handle_rollback(
+                        prop_name,
+                        murk_core::PropagatorError::ExecutionFailed {
+                            reason: format!(
+                                "cannot carry forward field {:?} of soft-failed propagator \
+                                 ({reason})",
+                                field,
+                            ),
+                        },
+                        receipts,
+                        accepted_receipt_start,
+                    );
+                }
+                soft_failures.push(StepError::PropagatorFailed {
+                    name: prop_name.clone(),
+                    reason,
+                });
+                let cells = self.expectations.cells[i];
+                propagators.push(propagator_metrics(prop_name, prop_start, cells));
+                continue;
+            }
+
+            // 4h. Enforce declared bounds on the fields just written.
+            for b in &self.write_bounds[i] {
+                if let Some(buf) = guard.writer.write(b.field) {
+                    for cell in buf.chunks_mut(b.ranges.len()) {
+                        for (v, &(min, max)) in cell.iter_mut().zip(&b.ranges) {
+                            *v = b.behavior.apply(*v, Scalar::from(min), Scalar::from(max));
+                        }
+                    }
+                }
+            }
+
+            // 4h'. Conservation audit: compare totals after step and bounds.
+            for (&(field, _), before) in self.conservation_audits[i].iter().zip(totals_before) {
+                let (Some((before, scale)), Some(buf)) = (before, guard.writer.read(field)) else {
+                    continue;
+                };
+                let (after, _) = field_totals(buf);
+                if (after - before).abs() <= self.conservation.tolerance * scale.max(1.0) {
+                    continue;
+                }
+                let violation = StepError::ConservationViolated {
+                    propagator: prop.name().to_string(),
+                    field,
+                    before,
+                    after,
+                };
+                match self.conservation.action {
+                    ConservationAction::Report => soft_failures.push(violation),
+                    ConservationAction::Rollback => {
+                        return self.roll_back(violation, receipts, accepted_receipt_start);
+                    }
+                }
             }
 
-            propagator_us.push((
+            let cells = self.expectations.cells[i];
+            propagators.push(propagator_metrics(
                 prop.name().to_string(),
-                prop_start.elapsed().as_micros() as u64,
+                prop_start,
+                cells,
             ));
         }
 
+        // 4i. Optionally reject non-finite values in any written field.
+        if self.detect_nonfinite {
+            for (i, writes) in self.expectations.write.iter().enumerate() {
+                if !self.propagator_enabled[i] {
+                    continue;
+                }
+                for &(field, _) in writes {
+                    let count = guard
+                        .writer
+                        .read(field)
+                        .map_or(0, |buf| buf.iter().filter(|v| !v.is_finite()).count());
+                    if count > 0 {
+                        return self.roll_back(
+                            StepError::NonFiniteField { field, count },
+                            receipts,
+                            accepted_receipt_start,
+                        );
+                    }
+                }
+            }
+        }
+
         // 5. guard goes out of scope here (releases staging borrows).
 
+        // 5a. Diff staging against the published generation for change
+        //     subscribers and changed-field tracking, before publish
+        //     swaps it out.
+        if !self.change_subscriptions.is_empty() {
+            self.change_subscriptions.diff(&self.arena);
+        }
+        let changed_fields = match &self.changed_fields {
+            Some(tracker) => tracker.changed(&self.arena),
+            None => Vec::new(),
+        };
+
         // 6. Publish.
+        let param_version = if staged_params.is_some() {
+            ParameterVersion(self.param_version.0 + 1)
+        } else {
+            self.param_version
+        };
         let publish_start = Instant::now();
-        self.arena
-            .publish(next_tick, self.param_version)
-            .map_err(|_| {
-                self.arena.reset_sparse_reuse_counters();
-                TickError {
-                    kind: StepError::AllocationFailed,
-                    receipts: vec![],
-                }
-            })?;
+        let published = match &staged_params {
+            Some(params) => self.arena.publish_with_parameters(
+                next_tick,
+                param_version,
+                Arc::new(params.clone()),
+            ),
+            None => self.arena.publish(next_tick, param_version),
+        };
+        published.map_err(|_| {
+            self.arena.reset_sparse_reuse_counters();
+            TickError {
+                kind: StepError::AllocationFailed,
+                receipts: vec![],
+            }
+        })?;
         let snapshot_publish_us = publish_start.elapsed().as_micros() as u64;
 
         // 7. Update state.
         self.current_tick = next_tick;
+        self.sim_time += self.dt;
         self.consecutive_rollback_count = 0;
+        if let Some(params) = staged_params {
+            self.parameters = params;
+            self.param_version = param_version;
+        }
+
+        self.change_subscriptions.deliver(next_tick);
 
         // 8. Finalize receipts with applied_tick_id (only for actually executed commands).
         for receipt in &mut receipts[accepted_receipt_start..] {
@@ -661,7 +1043,7 @@ impl TickEngine {
         let metrics = StepMetrics {
             total_us,
             command_processing_us,
-            propagator_us,
+            propagators,
             snapshot_publish_us,
             memory_bytes: self.arena.memory_bytes(),
             sparse_retired_ranges: u32::try_from(self.arena.sparse_retired_range_count())
@@ -672,6 +1054,8 @@ impl TickEngine {
             sparse_reuse_misses: self.arena.sparse_reuse_misses(),
             queue_full_rejections: self.counters.queue_full_rejections,
             tick_disabled_rejections: self.counters.tick_disabled_rejections,
+            dropped_commands: self.ingress.dropped_commands(),
+            rejected_full_events: self.ingress.rejected_full_events(),
             rollback_events: self.counters.rollback_events,
             tick_disabled_transitions: self.counters.tick_disabled_transitions,
             worker_stall_events: self.counters.worker_stall_events,
@@ -683,7 +1067,12 @@ impl TickEngine {
         self.arena.reset_sparse_reuse_counters();
         self.last_metrics = metrics.clone();
 
-        Ok(TickResult { receipts, metrics })
+        Ok(TickResult {
+            receipts,
+            metrics,
+            soft_failures,
+            changed_fields,
+        })
     }
 
     /// Handle a propagator failure by rolling back the tick.
@@ -694,6 +1083,23 @@ impl TickEngine {
         &mut self,
         prop_name: String,
         reason: murk_core::PropagatorError,
+        receipts: Vec<Receipt>,
+        accepted_start: usize,
+    ) -> Result<TickResult, TickError> {
+        self.roll_back(
+            StepError::PropagatorFailed {
+                name: prop_name,
+                reason,
+            },
+            receipts,
+            accepted_start,
+        )
+    }
+
+    /// Roll back the in-progress tick, reporting `kind` as the cause.
+    fn roll_back(
+        &mut self,
+        kind: StepError,
         mut receipts: Vec<Receipt>,
         accepted_start: usize,
     ) -> Result<TickResult, TickError> {
@@ -711,6 +1117,9 @@ impl TickEngine {
             self.tick_disabled = true;
         }
         self.refresh_counter_metrics();
+        if let Some(hook) = &mut self.rollback_hook {
+            hook(TickId(self.current_tick.0 + 1), &kind);
+        }
 
         // Mark accepted command receipts as rolled back, but preserve
         // receipts that were already rejected (e.g. unsupported command
@@ -722,21 +1131,122 @@ impl TickEngine {
             }
         }
 
-        Err(TickError {
-            kind: StepError::PropagatorFailed {
-                name: prop_name,
-                reason,
-            },
-            receipts,
-        })
+        Err(TickError { kind, receipts })
+    }
+
+    /// Enable or disable the propagator at `index` (pipeline order).
+    ///
+    /// Disabled propagators are skipped by [`execute_tick`](Self::execute_tick);
+    /// every field they write keeps its previous-generation value, so later
+    /// propagators still see a consistent world. No pipeline re-validation is
+    /// needed because disabling only removes writers. The setting persists
+    /// across [`reset`](Self::reset).
+    ///
+    /// Returns `false` (and changes nothing) if `index` is out of range.
+    pub fn set_propagator_enabled(&mut self, index: usize, enabled: bool) -> bool {
+        let Some(current) = self.propagator_enabled.get_mut(index) else {
+            return false;
+        };
+        if *current != enabled {
+            *current = enabled;
+            self.refresh_base_field_set();
+        }
+        true
+    }
+
+    /// Whether the propagator at `index` is enabled.
+    ///
+    /// Returns `None` if `index` is out of range.
+    pub fn is_propagator_enabled(&self, index: usize) -> Option<bool> {
+        self.propagator_enabled.get(index).copied()
+    }
+
+    /// Set how a `step()` error from the propagator at `index` is handled.
+    ///
+    /// Only errors returned by the propagator's `step()` are affected;
+    /// structural failures (buffer length mismatches, missing fields)
+    /// always roll back. The setting persists across
+    /// [`reset`](Self::reset).
+    ///
+    /// Returns `false` (and changes nothing) if `index` is out of range.
+    pub fn set_failure_policy(&mut self, index: usize, policy: FailurePolicy) -> bool {
+        let Some(current) = self.failure_policies.get_mut(index) else {
+            return false;
+        };
+        if *current != policy {
+            *current = policy;
+            self.refresh_base_field_set();
+        }
+        true
+    }
+
+    /// Failure policy of the propagator at `index`.
+    ///
+    /// Returns `None` if `index` is out of range.
+    pub fn failure_policy(&self, index: usize) -> Option<FailurePolicy> {
+        self.failure_policies.get(index).copied()
+    }
+
+    /// Rebuild the base field set after an enable or policy change.
+    ///
+    /// Fields written by disabled or [`FailurePolicy::SkipFailed`]
+    /// propagators must be in the base cache so their previous values can
+    /// be carried forward.
+    fn refresh_base_field_set(&mut self) {
+        let mut base_field_set = BaseFieldSet::from_plan(&self.plan, &self.propagators);
+        for (i, writes) in self.expectations.write.iter().enumerate() {
+            if !self.propagator_enabled[i] || self.failure_policies[i] == FailurePolicy::SkipFailed
+            {
+                base_field_set.extend(writes.iter().map(|&(field, _)| field));
+            }
+        }
+        base_field_set.extend(audited_base_fields(&self.conservation_audits));
+        self.base_field_set = base_field_set;
+    }
+
+    /// Number of propagators in the pipeline.
+    pub fn propagator_count(&self) -> usize {
+        self.propagators.len()
+    }
+
+    /// Current agent positions reported by propagators that manage agents,
+    /// sorted by agent ID.
+    ///
+    /// Reads the most recently published generation through
+    /// [`Propagator::agent_positions`]. If several propagators report the
+    /// same agent, the earliest in pipeline order wins.
+    pub fn agent_positions(&self) -> Vec<(u16, Coord)> {
+        let snapshot = self.arena.snapshot();
+        let mut ranks: Vec<(u16, usize)> = Vec::new();
+        for prop in &self.propagators {
+            if let Some(agents) = prop.agent_positions(&snapshot) {
+                for (id, rank) in agents {
+                    if !ranks.iter().any(|&(seen, _)| seen == id) {
+                        ranks.push((id, rank));
+                    }
+                }
+            }
+        }
+        if ranks.is_empty() {
+            return Vec::new();
+        }
+        ranks.sort_unstable_by_key(|&(id, _)| id);
+        let ordering = self.space.canonical_ordering();
+        ranks
+            .into_iter()
+            .filter_map(|(id, rank)| ordering.get(rank).map(|c| (id, c.clone())))
+            .collect()
     }
 
     /// Reset the engine to its initial state.
     pub fn reset(&mut self) -> Result<(), ConfigError> {
         self.arena.reset().map_err(ConfigError::Arena)?;
+        apply_initializers(&mut self.arena, &*self.space, &self.initializers);
         self.ingress.clear();
         self.current_tick = TickId(0);
+        self.sim_time = 0.0;
         self.param_version = ParameterVersion(0);
+        self.parameters.clear();
         self.tick_disabled = false;
         self.consecutive_rollback_count = 0;
         self.counters = CumulativeCounters::default();
@@ -744,6 +1254,106 @@ impl TickEngine {
         Ok(())
     }
 
+    /// Reset the engine, then overwrite the initial state of the given
+    /// fields with explicit buffers.
+    ///
+    /// Each buffer must hold the field's full canonical-order contents
+    /// (`cell_count * components` values). Fields not listed keep their
+    /// configured initializer or zero fill. Every buffer is validated
+    /// before the reset, so an error leaves the engine untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidField`] if a buffer targets an
+    /// undefined or `Static` field or has the wrong length, or
+    /// [`ConfigError::Arena`] if the arena reset fails.
+    pub fn reset_with(
+        &mut self,
+        initial: &indexmap::IndexMap<FieldId, Vec<Scalar>>,
+    ) -> Result<(), ConfigError> {
+        {
+            let snapshot = self.arena.snapshot();
+            for (&field, buf) in initial {
+                let expected = snapshot
+                    .read(field)
+                    .ok_or_else(|| ConfigError::InvalidField {
+                        reason: format!("initial state targets undefined field {field}"),
+                    })?
+                    .len();
+                if self.arena.static_arena().read_field(field).is_some() {
+                    return Err(ConfigError::InvalidField {
+                        reason: format!("initial state cannot overwrite static field {field}"),
+                    });
+                }
+                if buf.len() != expected {
+                    return Err(ConfigError::InvalidField {
+                        reason: format!(
+                            "initial state for field {field} has {} values, expected {expected}",
+                            buf.len()
+                        ),
+                    });
+                }
+            }
+        }
+        self.reset()?;
+        for (&field, buf) in initial {
+            if let Some(dst) = self.arena.write_initial(field) {
+                dst.copy_from_slice(buf);
+            }
+        }
+        Ok(())
+    }
+
+    /// Subscribe to per-tick changes of `fields`.
+    ///
+    /// After every successfully published tick, `callback` receives the
+    /// tick ID and only the field elements whose value differs from the
+    /// previous published state (nothing is delivered for a tick with no
+    /// changes), so the first tick after a reset is compared with the reset
+    /// state. Changes are found by diffing each tick against the previous
+    /// generation before publish; no copy of the fields is kept. Callbacks
+    /// run on the ticking thread, inside
+    /// [`execute_tick`](Self::execute_tick).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidField`] if any field is undefined.
+    pub fn subscribe_changes(
+        &mut self,
+        fields: &[FieldId],
+        callback: impl FnMut(TickId, &[CellChange]) + Send + 'static,
+    ) -> Result<SubscriptionId, ConfigError> {
+        let snapshot = self.arena.snapshot();
+        if let Some(&field) = fields.iter().find(|&&f| snapshot.read(f).is_none()) {
+            return Err(ConfigError::InvalidField {
+                reason: format!("change subscription targets undefined field {field}"),
+            });
+        }
+        Ok(self
+            .change_subscriptions
+            .subscribe(fields, Box::new(callback)))
+    }
+
+    /// Remove a change subscription. Returns `false` if `id` is unknown
+    /// or was already removed.
+    pub fn unsubscribe_changes(&mut self, id: SubscriptionId) -> bool {
+        self.change_subscriptions.unsubscribe(id)
+    }
+
+    /// Install `hook`, replacing any previous one, to be told about
+    /// rolled-back ticks.
+    ///
+    /// Whenever [`execute_tick`](Self::execute_tick) rolls a tick back,
+    /// `hook` receives the ID of the tick that was attempted and the error
+    /// that caused the rollback, so external mirrors of world state can
+    /// discard whatever they speculatively applied. It runs on the ticking
+    /// thread before `execute_tick` returns; successful ticks and ticks
+    /// refused because ticking is disabled never call it. The hook
+    /// persists across [`reset`](Self::reset).
+    pub fn set_rollback_hook(&mut self, hook: RollbackHook) {
+        self.rollback_hook = Some(hook);
+    }
+
     pub(crate) fn record_worker_stall_events(&mut self, count: u64) {
         self.counters.worker_stall_events = self.counters.worker_stall_events.saturating_add(count);
         self.refresh_counter_metrics();
@@ -774,6 +1384,8 @@ impl TickEngine {
         let c = &self.counters;
         self.last_metrics.queue_full_rejections = c.queue_full_rejections;
         self.last_metrics.tick_disabled_rejections = c.tick_disabled_rejections;
+        self.last_metrics.dropped_commands = self.ingress.dropped_commands();
+        self.last_metrics.rejected_full_events = self.ingress.rejected_full_events();
         self.last_metrics.rollback_events = c.rollback_events;
         self.last_metrics.tick_disabled_transitions = c.tick_disabled_transitions;
         self.last_metrics.worker_stall_events = c.worker_stall_events;
@@ -801,6 +1413,17 @@ impl TickEngine {
         self.current_tick
     }
 
+    /// Cumulative simulation time: the sum of `dt` over all committed ticks.
+    pub fn sim_time(&self) -> f64 {
+        self.sim_time
+    }
+
+    /// The pipeline's binding CFL limit: the minimum `max_dt` over all
+    /// propagators for this space, or `None` if `dt` is unconstrained.
+    pub fn max_safe_dt(&self) -> Option<f64> {
+        self.plan.max_dt()
+    }
+
     /// Whether ticking is disabled due to consecutive rollbacks.
     pub fn is_tick_disabled(&self) -> bool {
         self.tick_disabled
@@ -816,11 +1439,27 @@ impl TickEngine {
         &self.last_metrics
     }
 
+    /// Global parameters as of the last successful tick.
+    pub fn parameters(&self) -> &ParameterStore {
+        &self.parameters
+    }
+
+    /// Version of the global parameters, bumped on every tick that
+    /// applies a parameter command.
+    pub fn param_version(&self) -> ParameterVersion {
+        self.param_version
+    }
+
     /// The spatial topology for this engine.
     pub fn space(&self) -> &dyn murk_space::Space {
         self.space.as_ref()
     }
 
+    /// Field definitions, indexed by `FieldId`.
+    pub fn field_defs(&self) -> &[murk_core::FieldDef] {
+        &self.fields
+    }
+
     /// Number of command batches currently buffered in ingress.
     pub fn ingress_queue_depth(&self) -> usize {
         self.ingress.len()
@@ -830,6 +1469,106 @@ impl TickEngine {
     pub fn ingress_queue_capacity(&self) -> usize {
         self.ingress.capacity()
     }
+
+    /// Enable or disable wall-clock command expiry (`Command::expires_at`).
+    ///
+    /// Off by default so that tick outcomes depend only on tick counts.
+    /// [`RealtimeAsyncWorld`](crate::RealtimeAsyncWorld) turns it on.
+    pub fn set_wall_clock_expiry(&mut self, enabled: bool) {
+        self.ingress.set_wall_clock_expiry(enabled);
+    }
+}
+
+/// Write each initializer's per-cell values into the generation-0 buffer.
+fn apply_initializers(
+    arena: &mut PingPongArena,
+    space: &dyn murk_space::Space,
+    initializers: &[(FieldId, FieldInitializer)],
+) {
+    if initializers.is_empty() {
+        return;
+    }
+    let ordering = space.canonical_ordering();
+    for (field, init) in initializers {
+        if let Some(buf) = arena.write_initial(*field) {
+            fill_from_initializer(buf, &ordering, init);
+        }
+    }
+}
+
+/// Fill `buf` (cell-major, `components` values per cell) in canonical order.
+fn fill_from_initializer(buf: &mut [Scalar], ordering: &[Coord], init: &FieldInitializer) {
+    let components = buf.len() / ordering.len().max(1);
+    if components == 0 {
+        return;
+    }
+    for (cell, coord) in buf.chunks_exact_mut(components).zip(ordering) {
+        cell.fill(init(coord));
+    }
+}
+
+/// Copy the previous-generation value of each field in `writes` into the
+/// staging buffer, discarding anything written there this tick.
+///
+/// Fields without a previous generation (the very first tick) are left as
+/// staged. Returns the first field whose staging buffer is missing or has
+/// a different length.
+fn carry_forward(
+    writer: &mut WriteArena<'_>,
+    base_cache: &BaseFieldCache,
+    writes: &[(FieldId, usize)],
+) -> Result<(), FieldId> {
+    for &(field, _) in writes {
+        let Some(prev) = base_cache.read(field) else {
+            // First tick: no previous generation to carry.
+            continue;
+        };
+        match writer.write(field) {
+            Some(buf) if buf.len() == prev.len() => buf.copy_from_slice(prev),
+            _ => return Err(field),
+        }
+    }
+    Ok(())
+}
+
+/// Write `value` to `field` at `coord` in the staging buffer.
+///
+/// Returns `false` if the coordinate is out of bounds or the field has
+/// no writable buffer.
+fn set_cell(
+    space: &dyn murk_space::Space,
+    writer: &mut WriteArena<'_>,
+    field: FieldId,
+    coord: &Coord,
+    value: f32,
+) -> bool {
+    let Some(rank) = space.canonical_rank(coord) else {
+        return false;
+    };
+    match writer.write(field) {
+        Some(buf) if rank < buf.len() => {
+            buf[rank] = Scalar::from(value);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Conserved fields that the audit totals from the base generation.
+fn audited_base_fields(audits: &[Vec<(FieldId, bool)>]) -> impl Iterator<Item = FieldId> + '_ {
+    audits
+        .iter()
+        .flatten()
+        .filter(|&&(_, staged)| !staged)
+        .map(|&(field, _)| field)
+}
+
+/// Sum and absolute sum of a field buffer, accumulated in `f64`.
+fn field_totals(buf: &[Scalar]) -> (f64, f64) {
+    buf.iter().fold((0.0, 0.0), |(sum, abs), &v| {
+        let v = scalar_to_f64(v);
+        (sum + v, abs + v.abs())
+    })
 }
 
 #[cfg(test)]
@@ -840,9 +1579,10 @@ mod tests {
     use murk_core::traits::SnapshotAccess;
     use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldType, PropagatorError};
     use murk_propagator::propagator::WriteMode;
-    use murk_space::{EdgeBehavior, Line1D};
+    use murk_space::{EdgeBehavior, Line1D, Space, Square4};
     use murk_test_utils::{ConstPropagator, FailingPropagator, IdentityPropagator};
     use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     fn scalar_field(name: &str) -> FieldDef {
         FieldDef {
@@ -866,13 +1606,15 @@ mod tests {
         }
     }
 
+    /// An unsupported command (rejected at apply time).
     fn make_cmd(expires: u64) -> Command {
         Command {
-            payload: CommandPayload::SetParameter {
-                key: ParameterKey(0),
-                value: 0.0,
+            payload: CommandPayload::Custom {
+                type_id: 0,
+                data: Vec::new(),
             },
             expires_after_tick: TickId(expires),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -1086,26 +1828,87 @@ mod tests {
         assert_eq!(snap.read(FieldId(1)).unwrap()[0], 99.0);
     }
 
+    /// Writes `1.0 + field1` into field0, treating an absent field1 as zero.
+    struct OptionalReadPropagator;
+    impl Propagator for OptionalReadPropagator {
+        fn name(&self) -> &str {
+            "optional_read"
+        }
+        fn reads(&self) -> murk_core::FieldSet {
+            murk_core::FieldSet::empty()
+        }
+        fn optional_reads(&self) -> murk_core::FieldSet {
+            [FieldId(1)].into_iter().collect()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(FieldId(0), WriteMode::Full)]
+        }
+        fn step(
+            &self,
+            ctx: &mut murk_propagator::StepContext<'_>,
+        ) -> Result<(), murk_core::PropagatorError> {
+            let extra = ctx.reads().read(FieldId(1)).map(|b| b.to_vec());
+            let out = ctx.writes().write(FieldId(0)).unwrap();
+            for (i, v) in out.iter_mut().enumerate() {
+                *v = 1.0 + extra.as_ref().map_or(0.0, |e| e[i]);
+            }
+            Ok(())
+        }
+    }
+
     #[test]
-    fn three_propagator_overlay_visibility() {
-        // A writes 7.0 to f0
-        // B reads f0 (staged → 7.0), copies to f1
-        // C reads f0 (staged → 7.0) + f1 (staged → 7.0), writes sum to f2
-        let mut engine = three_field_engine();
+    fn optional_read_of_absent_field_runs() {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("field0")])
+            .propagators(vec![Box::new(OptionalReadPropagator)])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut engine = TickEngine::new(config).unwrap();
         engine.execute_tick().unwrap();
-        let snap = engine.snapshot();
-
-        assert_eq!(snap.read(FieldId(0)).unwrap()[0], 7.0);
-        assert_eq!(snap.read(FieldId(1)).unwrap()[0], 7.0);
-        assert_eq!(snap.read(FieldId(2)).unwrap()[0], 14.0); // 7 + 7
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap()[0], 1.0);
     }
 
     #[test]
-    fn unwritten_field_reads_from_base_gen() {
-        // On tick 1, fields start as zero (base gen). A propagator
-        // reading a field nobody wrote should see zero.
-        let mut engine = three_field_engine();
-        engine.execute_tick().unwrap();
+    fn optional_read_of_present_field_is_used() {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("field0"), scalar_field("field1")])
+            .propagators(vec![
+                Box::new(ConstPropagator::new("write_f1", FieldId(1), 5.0)),
+                Box::new(OptionalReadPropagator),
+            ])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut engine = TickEngine::new(config).unwrap();
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap()[0], 6.0);
+    }
+
+    #[test]
+    fn three_propagator_overlay_visibility() {
+        // A writes 7.0 to f0
+        // B reads f0 (staged → 7.0), copies to f1
+        // C reads f0 (staged → 7.0) + f1 (staged → 7.0), writes sum to f2
+        let mut engine = three_field_engine();
+        engine.execute_tick().unwrap();
+        let snap = engine.snapshot();
+
+        assert_eq!(snap.read(FieldId(0)).unwrap()[0], 7.0);
+        assert_eq!(snap.read(FieldId(1)).unwrap()[0], 7.0);
+        assert_eq!(snap.read(FieldId(2)).unwrap()[0], 14.0); // 7 + 7
+    }
+
+    #[test]
+    fn unwritten_field_reads_from_base_gen() {
+        // On tick 1, fields start as zero (base gen). A propagator
+        // reading a field nobody wrote should see zero.
+        let mut engine = three_field_engine();
+        engine.execute_tick().unwrap();
         // All fields are written in this pipeline, so let's just
         // verify the snapshot is consistent.
         let snap = engine.snapshot();
@@ -1167,6 +1970,7 @@ mod tests {
                 value: 1.0,
             },
             expires_after_tick: TickId(100),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -1191,13 +1995,13 @@ mod tests {
 
     #[test]
     fn rollback_preserves_rejected_receipts() {
-        // Submit an unsupported command (SetParameter → rejected) alongside
+        // Submit an unsupported command (Custom → rejected) alongside
         // the tick. When the propagator fails and triggers rollback, the
         // rejected receipt must stay accepted=false, not be overwritten
         // with TickRollback.
         let mut engine = failing_engine(0);
 
-        // SetParameter is unsupported → should be rejected (accepted=false).
+        // Custom is unsupported → should be rejected (accepted=false).
         engine.submit_commands(vec![make_cmd(100)]);
 
         let result = engine.execute_tick();
@@ -1280,6 +2084,21 @@ mod tests {
         assert_eq!(engine.last_metrics().tick_disabled_transitions, 1);
     }
 
+    #[test]
+    fn wall_clock_expiry_drops_past_deadline_commands() {
+        let mut engine = simple_engine();
+        engine.set_wall_clock_expiry(true);
+        let mut cmd = make_cmd(100);
+        cmd.expires_at = Some(Instant::now());
+        engine.submit_commands(vec![cmd]);
+
+        let result = engine.execute_tick().unwrap();
+        assert_eq!(result.receipts.len(), 1);
+        assert!(result.receipts[0].accepted);
+        assert_eq!(result.receipts[0].applied_tick_id, None);
+        assert_eq!(result.receipts[0].reason_code, Some(IngressError::Expired));
+    }
+
     #[test]
     fn reset_clears_tick_disabled() {
         let mut engine = failing_engine(0);
@@ -1323,96 +2142,884 @@ mod tests {
     fn multi_tick_determinism() {
         let mut engine = simple_engine();
 
-        for _ in 0..10 {
-            engine.execute_tick().unwrap();
+        for _ in 0..10 {
+            engine.execute_tick().unwrap();
+        }
+
+        let snap = engine.snapshot();
+        let data = snap.read(FieldId(0)).unwrap();
+        assert!(data.iter().all(|&v| v == 42.0));
+        assert_eq!(engine.current_tick(), TickId(10));
+    }
+
+    #[test]
+    fn commands_flow_through_to_receipts() {
+        let mut engine = simple_engine();
+
+        // Use SetField commands — the only cell-level command type executed.
+        let coord: Coord = vec![0i32].into();
+        let cmds = vec![
+            Command {
+                payload: CommandPayload::SetField {
+                    coord: coord.clone(),
+                    field_id: FieldId(0),
+                    value: 1.0,
+                },
+                expires_after_tick: TickId(100),
+                expires_at: None,
+                source_id: None,
+                source_seq: None,
+                priority_class: 1,
+                arrival_seq: 0,
+            },
+            Command {
+                payload: CommandPayload::SetField {
+                    coord: coord.clone(),
+                    field_id: FieldId(0),
+                    value: 2.0,
+                },
+                expires_after_tick: TickId(100),
+                expires_at: None,
+                source_id: None,
+                source_seq: None,
+                priority_class: 1,
+                arrival_seq: 0,
+            },
+        ];
+        let submit_receipts = engine.submit_commands(cmds);
+        assert_eq!(submit_receipts.len(), 2);
+        assert!(submit_receipts.iter().all(|r| r.accepted));
+
+        let result = engine.execute_tick().unwrap();
+        // Should have receipts for the 2 commands.
+        let applied: Vec<_> = result
+            .receipts
+            .iter()
+            .filter(|r| r.applied_tick_id.is_some())
+            .collect();
+        assert_eq!(applied.len(), 2);
+        assert!(applied.iter().all(|r| r.applied_tick_id == Some(TickId(1))));
+    }
+
+    #[test]
+    fn non_setfield_commands_rejected_honestly() {
+        let mut engine = simple_engine();
+
+        // Submit Custom commands — not implemented by the engine.
+        let submit_receipts = engine.submit_commands(vec![make_cmd(100), make_cmd(100)]);
+        assert_eq!(submit_receipts.len(), 2);
+        assert!(submit_receipts.iter().all(|r| r.accepted));
+
+        let result = engine.execute_tick().unwrap();
+        assert_eq!(result.receipts.len(), 2);
+
+        // Non-SetField commands must NOT report as applied and must carry
+        // UnsupportedCommand reason so callers can distinguish the failure mode.
+        for receipt in &result.receipts {
+            assert!(
+                !receipt.accepted,
+                "unimplemented command type must be rejected"
+            );
+            assert_eq!(
+                receipt.applied_tick_id, None,
+                "unimplemented command must not have applied_tick_id"
+            );
+            assert_eq!(
+                receipt.reason_code,
+                Some(IngressError::UnsupportedCommand),
+                "rejected unsupported command must carry UnsupportedCommand reason"
+            );
+        }
+    }
+
+    // ── Parameter tests ──────────────────────────────────────
+
+    fn set_param_cmd(key: u32, value: f64) -> Command {
+        Command {
+            payload: CommandPayload::SetParameter {
+                key: ParameterKey(key),
+                value,
+            },
+            expires_after_tick: TickId(100),
+            expires_at: None,
+            source_id: None,
+            source_seq: None,
+            priority_class: 1,
+            arrival_seq: 0,
+        }
+    }
+
+    /// Writes parameter 0 to every cell of field 0 (-1.0 if unset).
+    struct ParamWriter;
+    impl Propagator for ParamWriter {
+        fn name(&self) -> &str {
+            "param_writer"
+        }
+        fn reads(&self) -> murk_core::FieldSet {
+            murk_core::FieldSet::empty()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(FieldId(0), WriteMode::Full)]
+        }
+        fn step(
+            &self,
+            ctx: &mut murk_propagator::StepContext<'_>,
+        ) -> Result<(), murk_core::PropagatorError> {
+            let v = ctx.parameter(ParameterKey(0)).unwrap_or(-1.0);
+            ctx.writes()
+                .write(FieldId(0))
+                .unwrap()
+                .fill(Scalar::from(v));
+            Ok(())
+        }
+    }
+
+    fn param_engine(props: Vec<Box<dyn Propagator>>) -> TickEngine {
+        let fields = vec![scalar_field("param"), scalar_field("other")];
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+            .fields(fields)
+            .propagators(props)
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn unset_parameter_reads_none() {
+        let mut engine = param_engine(vec![Box::new(ParamWriter)]);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[-1.0; 4]);
+        assert!(engine.parameters().is_empty());
+        assert_eq!(engine.param_version(), ParameterVersion(0));
+    }
+
+    #[test]
+    fn propagator_reads_parameter_set_last_tick() {
+        let mut engine = param_engine(vec![Box::new(ParamWriter)]);
+        engine.submit_commands(vec![set_param_cmd(0, 2.5)]);
+        let result = engine.execute_tick().unwrap();
+        assert!(result.receipts[0].accepted);
+        assert_eq!(result.receipts[0].applied_tick_id, Some(TickId(1)));
+        assert_eq!(engine.param_version(), ParameterVersion(1));
+
+        // Tick 2 has no commands; the value persists.
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[2.5; 4]);
+        assert_eq!(engine.parameters().get(ParameterKey(0)), Some(2.5));
+        assert_eq!(engine.param_version(), ParameterVersion(1));
+        assert_eq!(engine.snapshot().parameter_version(), ParameterVersion(1));
+    }
+
+    #[test]
+    fn snapshot_reports_parameters_frozen_at_its_tick() {
+        let mut engine = param_engine(vec![Box::new(ParamWriter)]);
+        assert_eq!(engine.snapshot().parameter(ParameterKey(0)), None);
+
+        engine.submit_commands(vec![set_param_cmd(0, 2.5)]);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().parameter(ParameterKey(0)), Some(2.5));
+        assert_eq!(engine.snapshot().parameter(ParameterKey(1)), None);
+        let tick_1 = engine.owned_snapshot();
+
+        engine.submit_commands(vec![set_param_cmd(0, 4.0)]);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().parameter(ParameterKey(0)), Some(4.0));
+        assert_eq!(tick_1.tick_id(), TickId(1));
+        assert_eq!(tick_1.parameter(ParameterKey(0)), Some(2.5));
+
+        // A tick without parameter commands keeps reporting the last value.
+        engine.execute_tick().unwrap();
+        assert_eq!(
+            engine.owned_snapshot().parameter(ParameterKey(0)),
+            Some(4.0)
+        );
+    }
+
+    #[test]
+    fn parameter_batch_applies_in_order() {
+        let mut engine = param_engine(vec![Box::new(ParamWriter)]);
+        let mut batch = set_param_cmd(0, 0.0);
+        batch.payload = CommandPayload::SetParameterBatch {
+            params: vec![
+                (ParameterKey(0), 1.0),
+                (ParameterKey(1), 3.0),
+                (ParameterKey(0), 4.0),
+            ],
+        };
+        engine.submit_commands(vec![batch]);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.parameters().get(ParameterKey(0)), Some(4.0));
+        assert_eq!(engine.parameters().get(ParameterKey(1)), Some(3.0));
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[4.0; 4]);
+    }
+
+    #[test]
+    fn rollback_discards_parameter_changes() {
+        let mut engine = param_engine(vec![
+            Box::new(ParamWriter),
+            Box::new(FailingPropagator::new("fail", FieldId(1), 1)),
+        ]);
+        engine.submit_commands(vec![set_param_cmd(0, 1.0)]);
+        engine.execute_tick().unwrap();
+
+        engine.submit_commands(vec![set_param_cmd(0, 9.0)]);
+        assert!(engine.execute_tick().is_err());
+        assert_eq!(engine.parameters().get(ParameterKey(0)), Some(1.0));
+        assert_eq!(engine.param_version(), ParameterVersion(1));
+    }
+
+    #[test]
+    fn reset_clears_parameters() {
+        let mut engine = param_engine(vec![Box::new(ParamWriter)]);
+        engine.submit_commands(vec![set_param_cmd(0, 1.0)]);
+        engine.execute_tick().unwrap();
+        engine.reset().unwrap();
+        assert!(engine.parameters().is_empty());
+        assert_eq!(engine.param_version(), ParameterVersion(0));
+        assert_eq!(engine.snapshot().parameter(ParameterKey(0)), None);
+    }
+
+    // ── Non-finite detection tests ───────────────────────────
+
+    fn nan_engine(detect: bool) -> TickEngine {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(5, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("ok"), scalar_field("poisoned")])
+            .propagators(vec![
+                Box::new(ConstPropagator::new("ok", FieldId(0), 1.0)),
+                Box::new(ConstPropagator::new("nan", FieldId(1), Scalar::NAN)),
+            ])
+            .dt(0.1)
+            .seed(42)
+            .detect_nonfinite(detect)
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn nonfinite_write_rolls_back_when_detection_enabled() {
+        let mut engine = nan_engine(true);
+        let tick_before = engine.snapshot().tick_id();
+        match engine.execute_tick() {
+            Err(TickError {
+                kind: StepError::NonFiniteField { field, count },
+                ..
+            }) => {
+                assert_eq!(field, FieldId(1));
+                assert_eq!(count, 5);
+            }
+            Err(other) => panic!("expected NonFiniteField, got {:?}", other.kind),
+            Ok(_) => panic!("expected NonFiniteField, got Ok"),
+        }
+        assert_eq!(engine.snapshot().tick_id(), tick_before);
+        assert_eq!(engine.consecutive_rollback_count(), 1);
+    }
+
+    #[test]
+    fn nonfinite_write_is_published_when_detection_disabled() {
+        let mut engine = nan_engine(false);
+        engine.execute_tick().unwrap();
+        let snap = engine.snapshot();
+        assert_eq!(snap.tick_id(), TickId(1));
+        assert!(snap.read(FieldId(1)).unwrap().iter().all(|v| v.is_nan()));
+    }
+
+    // ── Conservation audit tests ─────────────────────────────
+
+    /// Claims to conserve field 0 but loses 10% of it every tick.
+    struct LeakyPropagator;
+    impl Propagator for LeakyPropagator {
+        fn name(&self) -> &str {
+            "leaky"
+        }
+        fn reads(&self) -> murk_core::FieldSet {
+            murk_core::FieldSet::empty()
+        }
+        fn reads_previous(&self) -> murk_core::FieldSet {
+            [FieldId(0)].into_iter().collect()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(FieldId(0), WriteMode::Full)]
+        }
+        fn conserved_fields(&self) -> murk_core::FieldSet {
+            [FieldId(0)].into_iter().collect()
+        }
+        fn step(
+            &self,
+            ctx: &mut murk_propagator::StepContext<'_>,
+        ) -> Result<(), murk_core::PropagatorError> {
+            let prev: Vec<Scalar> = ctx.reads_previous().read(FieldId(0)).unwrap().to_vec();
+            let out = ctx.writes().write(FieldId(0)).unwrap();
+            for (o, p) in out.iter_mut().zip(&prev) {
+                *o = p * 0.9;
+            }
+            Ok(())
+        }
+    }
+
+    fn audited_engine(
+        prop: Box<dyn Propagator>,
+        check: bool,
+        action: ConservationAction,
+    ) -> TickEngine {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(8, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("mass")])
+            .propagators(vec![prop])
+            .initializer(FieldId(0), |c| c[0] as Scalar + 1.0)
+            .dt(0.1)
+            .seed(42)
+            .conservation_check(check)
+            .conservation(ConservationConfig {
+                tolerance: 1e-5,
+                action,
+            })
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn conservative_diffusion_passes_conservation_audit() {
+        let diffusion = murk_propagators::ScalarDiffusion::builder()
+            .input_field(FieldId(0))
+            .output_field(FieldId(0))
+            .coefficient(0.5)
+            .conservative(true)
+            .build()
+            .unwrap();
+        let mut engine = audited_engine(Box::new(diffusion), true, ConservationAction::Rollback);
+        for _ in 0..20 {
+            assert!(engine.execute_tick().unwrap().soft_failures.is_empty());
+        }
+        let total: Scalar = engine.snapshot().read(FieldId(0)).unwrap().iter().sum();
+        assert!((total - 36.0).abs() < 1e-3, "total drifted to {total}");
+    }
+
+    #[test]
+    fn leaky_propagator_is_reported_and_committed() {
+        let mut engine =
+            audited_engine(Box::new(LeakyPropagator), true, ConservationAction::Report);
+        let result = engine.execute_tick().unwrap();
+        match &result.soft_failures[..] {
+            [StepError::ConservationViolated {
+                propagator,
+                field,
+                before,
+                after,
+            }] => {
+                assert_eq!(propagator, "leaky");
+                assert_eq!(*field, FieldId(0));
+                assert!((before - 36.0).abs() < 1e-9);
+                assert!((after - 32.4).abs() < 1e-4);
+            }
+            other => panic!("expected one ConservationViolated, got {other:?}"),
+        }
+        assert_eq!(engine.snapshot().tick_id(), TickId(1));
+    }
+
+    #[test]
+    fn leaky_propagator_rolls_back_under_rollback_action() {
+        let mut engine = audited_engine(
+            Box::new(LeakyPropagator),
+            true,
+            ConservationAction::Rollback,
+        );
+        match engine.execute_tick() {
+            Err(TickError {
+                kind: StepError::ConservationViolated { propagator, .. },
+                ..
+            }) => assert_eq!(propagator, "leaky"),
+            Err(other) => panic!("expected ConservationViolated, got {:?}", other.kind),
+            Ok(_) => panic!("expected ConservationViolated, got Ok"),
+        }
+        assert_eq!(engine.snapshot().tick_id(), TickId(0));
+        assert_eq!(engine.consecutive_rollback_count(), 1);
+    }
+
+    #[test]
+    fn conservation_audit_is_off_by_default() {
+        let mut engine = audited_engine(
+            Box::new(LeakyPropagator),
+            false,
+            ConservationAction::Rollback,
+        );
+        assert!(engine.execute_tick().unwrap().soft_failures.is_empty());
+    }
+
+    // ── Changed-field tests ──────────────────────────────────
+
+    const HEAT: FieldId = FieldId(0);
+    const ENERGY: FieldId = FieldId(1);
+
+    /// Adds 1.0 to every cell of `HEAT` each tick.
+    struct HeatUp;
+
+    impl Propagator for HeatUp {
+        fn name(&self) -> &str {
+            "heat_up"
+        }
+
+        fn reads(&self) -> murk_core::FieldSet {
+            murk_core::FieldSet::empty()
+        }
+
+        fn reads_previous(&self) -> murk_core::FieldSet {
+            [HEAT].into_iter().collect()
+        }
+
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(HEAT, WriteMode::Full)]
+        }
+
+        fn step(
+            &self,
+            ctx: &mut murk_propagator::StepContext<'_>,
+        ) -> Result<(), murk_core::PropagatorError> {
+            let prev = ctx.reads_previous().read(HEAT).unwrap().to_vec();
+            let out = ctx.writes().write(HEAT).unwrap();
+            for (o, p) in out.iter_mut().zip(prev) {
+                *o = p + 1.0;
+            }
+            Ok(())
+        }
+    }
+
+    fn tracking_engine(props: Vec<Box<dyn Propagator>>, track: bool) -> TickEngine {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("heat"), scalar_field("energy")])
+            .propagators(props)
+            .dt(0.1)
+            .seed(42)
+            .track_changed_fields(track)
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn changed_fields_lists_only_the_field_that_changed() {
+        let mut engine = tracking_engine(
+            vec![
+                Box::new(HeatUp),
+                Box::new(ConstPropagator::new("energy", ENERGY, 5.0)),
+            ],
+            true,
+        );
+        // Tick 1: both fields leave their zero fill.
+        let first = engine.execute_tick().unwrap();
+        assert_eq!(first.changed_fields, vec![HEAT, ENERGY]);
+        // Tick 2: energy is rewritten with the same value.
+        let second = engine.execute_tick().unwrap();
+        assert_eq!(second.changed_fields, vec![HEAT]);
+    }
+
+    #[test]
+    fn changed_fields_is_empty_for_no_op_tick() {
+        let mut engine = tracking_engine(
+            vec![
+                Box::new(ConstPropagator::new("heat", HEAT, 3.0)),
+                Box::new(ConstPropagator::new("energy", ENERGY, 0.0)),
+            ],
+            true,
+        );
+        assert_eq!(engine.execute_tick().unwrap().changed_fields, vec![HEAT]);
+        assert!(engine.execute_tick().unwrap().changed_fields.is_empty());
+
+        // Reset re-baselines: the first tick after it changes heat again.
+        engine.reset().unwrap();
+        assert_eq!(engine.execute_tick().unwrap().changed_fields, vec![HEAT]);
+    }
+
+    #[test]
+    fn changed_fields_is_empty_when_tracking_disabled() {
+        let mut engine = tracking_engine(vec![Box::new(HeatUp)], false);
+        assert!(engine.execute_tick().unwrap().changed_fields.is_empty());
+    }
+
+    // ── Boundary behavior tests ──────────────────────────────
+
+    fn bounded_engine(value: Scalar, behavior: BoundaryBehavior) -> TickEngine {
+        let field = FieldDef {
+            bounds: Some((0.0, 10.0).into()),
+            boundary_behavior: behavior,
+            ..scalar_field("bounded")
+        };
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(5, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![field])
+            .propagators(vec![Box::new(ConstPropagator::new("overshoot", FieldId(0), value))])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn clamp_boundary_caps_overshoot_at_every_cell() {
+        let mut engine = bounded_engine(50.0, BoundaryBehavior::Clamp);
+        engine.execute_tick().unwrap();
+        let snap = engine.snapshot();
+        let data = snap.read(FieldId(0)).unwrap();
+        // Edge cells (0 and 4) included.
+        assert_eq!(data, &[10.0; 5]);
+
+        let mut engine = bounded_engine(-3.0, BoundaryBehavior::Clamp);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[0.0; 5]);
+    }
+
+    #[test]
+    fn wrap_and_reflect_boundaries_fold_into_range() {
+        let mut engine = bounded_engine(12.0, BoundaryBehavior::Wrap);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[2.0; 5]);
+
+        let mut engine = bounded_engine(12.0, BoundaryBehavior::Reflect);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[8.0; 5]);
+    }
+
+    #[test]
+    fn in_range_writes_are_untouched() {
+        let mut engine = bounded_engine(7.5, BoundaryBehavior::Clamp);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[7.5; 5]);
+    }
+
+    #[test]
+    fn per_component_bounds_clamp_each_channel() {
+        let velocity = FieldDef {
+            field_type: FieldType::Vector { dims: 2 },
+            bounds: Some(murk_core::FieldBounds::PerComponent(vec![
+                (-1.0, 1.0),
+                (-2.0, 2.0),
+            ])),
+            ..scalar_field("velocity")
+        };
+        for (value, expected) in [(5.0, [1.0, 2.0]), (-5.0, [-1.0, -2.0]), (1.5, [1.0, 1.5])] {
+            let config = WorldConfig::builder()
+                .space(Box::new(Line1D::new(3, EdgeBehavior::Absorb).unwrap()))
+                .fields(vec![velocity.clone()])
+                .propagators(vec![Box::new(ConstPropagator::new("push", FieldId(0), value))])
+                .dt(0.1)
+                .seed(42)
+                .build()
+                .unwrap();
+            let mut engine = TickEngine::new(config).unwrap();
+            engine.execute_tick().unwrap();
+            let snap = engine.snapshot();
+            let data = snap.read(FieldId(0)).unwrap();
+            assert_eq!(data.len(), 6);
+            for cell in data.chunks(2) {
+                assert_eq!(cell, &expected, "value = {value}");
+            }
+        }
+    }
+
+    // ── Metrics tests ────────────────────────────────────────
+
+    // ── Field initializer tests ──────────────────────────────
+
+    /// Field 0: per-tick, initialised to canonical rank.
+    /// Field 1: static, initialised to `10 * row`.
+    /// Field 2: written by a const propagator.
+    fn initialized_engine() -> TickEngine {
+        let grid = Square4::new(3, 4, EdgeBehavior::Absorb).unwrap();
+        let rank_of = grid.clone();
+        let config = WorldConfig::builder()
+            .space(Box::new(grid))
+            .fields(vec![
+                scalar_field("rank"),
+                FieldDef {
+                    mutability: FieldMutability::Static,
+                    ..scalar_field("terrain")
+                },
+                scalar_field("out"),
+            ])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(2), 1.0))])
+            .initializer(FieldId(0), move |c| {
+                rank_of.canonical_rank(c).unwrap() as Scalar
+            })
+            .initializer(FieldId(1), |c| 10.0 * c[0] as Scalar)
+            .dt(0.1)
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn initializer_fills_field_in_canonical_order() {
+        let engine = initialized_engine();
+        let snap = engine.snapshot();
+        let expected: Vec<Scalar> = (0..12).map(|i| i as Scalar).collect();
+        assert_eq!(snap.read(FieldId(0)).unwrap(), &expected[..]);
+        assert_eq!(
+            snap.read(FieldId(1)).unwrap(),
+            &[0.0, 0.0, 0.0, 0.0, 10.0, 10.0, 10.0, 10.0, 20.0, 20.0, 20.0, 20.0][..]
+        );
+        assert!(snap.read(FieldId(2)).unwrap().iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn reset_reapplies_initializers() {
+        let mut engine = initialized_engine();
+        engine.submit_commands(vec![Command {
+            payload: CommandPayload::SetField {
+                coord: Coord::from_slice(&[0, 0]),
+                field_id: FieldId(0),
+                value: 99.0,
+            },
+            expires_after_tick: TickId(100),
+            expires_at: None,
+            source_id: None,
+            source_seq: None,
+            priority_class: 1,
+            arrival_seq: 0,
+        }]);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap()[0], 99.0);
+
+        engine.reset().unwrap();
+        let snap = engine.snapshot();
+        let expected: Vec<Scalar> = (0..12).map(|i| i as Scalar).collect();
+        assert_eq!(snap.read(FieldId(0)).unwrap(), &expected[..]);
+        assert_eq!(snap.read(FieldId(1)).unwrap()[11], 20.0);
+    }
+
+    #[test]
+    fn initializer_for_undefined_field_rejected() {
+        let result = WorldConfig::builder()
+            .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy")])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(0), 1.0))])
+            .initializer(FieldId(5), |_| 1.0)
+            .dt(0.1)
+            .build();
+        match result {
+            Err(ConfigError::InvalidField { reason }) => assert!(reason.contains("initializer")),
+            other => panic!("expected InvalidField, got {other:?}"),
+        }
+    }
+
+    /// Writes, per cell, the sum of its neighbours' canonical ranks.
+    /// `cached` precomputes the neighbour table in `init`; otherwise it
+    /// is rebuilt from `ctx.space()` every step.
+    struct NeighbourRankSum {
+        cached: bool,
+        table: Option<Vec<Vec<usize>>>,
+    }
+
+    fn neighbour_rank_table(space: &dyn Space) -> Vec<Vec<usize>> {
+        space
+            .canonical_ordering()
+            .iter()
+            .map(|coord| {
+                space
+                    .neighbours(coord)
+                    .iter()
+                    .filter_map(|n| space.canonical_rank(n))
+                    .collect()
+            })
+            .collect()
+    }
+
+    impl Propagator for NeighbourRankSum {
+        fn name(&self) -> &str {
+            "neighbour_rank_sum"
+        }
+        fn reads(&self) -> FieldSet {
+            FieldSet::empty()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(FieldId(0), WriteMode::Full)]
+        }
+        fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+            if self.cached {
+                self.table = Some(neighbour_rank_table(space));
+            }
+            Ok(())
+        }
+        fn step(&self, ctx: &mut murk_propagator::StepContext<'_>) -> Result<(), PropagatorError> {
+            let table = match &self.table {
+                Some(table) => table.clone(),
+                None => neighbour_rank_table(ctx.space()),
+            };
+            let out = ctx.writes().write(FieldId(0)).unwrap();
+            for (cell, neighbours) in table.iter().enumerate() {
+                out[cell] = neighbours.iter().sum::<usize>() as Scalar;
+            }
+            Ok(())
+        }
+    }
+
+    fn neighbour_rank_engine(prop: Box<dyn Propagator>) -> Result<TickEngine, ConfigError> {
+        let config = WorldConfig::builder()
+            .space(Box::new(Square4::new(3, 4, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("sum")])
+            .propagators(vec![prop])
+            .dt(0.1)
+            .seed(42)
+            .build()?;
+        TickEngine::new(config)
+    }
+
+    #[test]
+    fn init_cached_neighbours_match_per_step() {
+        let mut cached = neighbour_rank_engine(Box::new(NeighbourRankSum {
+            cached: true,
+            table: None,
+        }))
+        .unwrap();
+        let mut uncached = neighbour_rank_engine(Box::new(NeighbourRankSum {
+            cached: false,
+            table: None,
+        }))
+        .unwrap();
+
+        for _ in 0..3 {
+            cached.execute_tick().unwrap();
+            uncached.execute_tick().unwrap();
+            let a = cached.snapshot().read(FieldId(0)).unwrap().to_vec();
+            let b = uncached.snapshot().read(FieldId(0)).unwrap().to_vec();
+            assert_eq!(a, b);
+        }
+        // Corner (0,0) on a 3x4 grid neighbours ranks 1 and 4.
+        assert_eq!(cached.snapshot().read(FieldId(0)).unwrap()[0], 5.0);
+    }
+
+    #[test]
+    fn init_error_aborts_engine_creation() {
+        struct FailsInit;
+        impl Propagator for FailsInit {
+            fn name(&self) -> &str {
+                "fails_init"
+            }
+            fn reads(&self) -> FieldSet {
+                FieldSet::empty()
+            }
+            fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+                vec![(FieldId(0), WriteMode::Full)]
+            }
+            fn init(&mut self, _space: &dyn Space) -> Result<(), PropagatorError> {
+                Err(PropagatorError::ExecutionFailed {
+                    reason: "no table".into(),
+                })
+            }
+            fn step(
+                &self,
+                _ctx: &mut murk_propagator::StepContext<'_>,
+            ) -> Result<(), PropagatorError> {
+                Ok(())
+            }
+        }
+
+        match neighbour_rank_engine(Box::new(FailsInit)) {
+            Err(ConfigError::PropagatorInit { propagator, .. }) => {
+                assert_eq!(propagator, "fails_init");
+            }
+            Err(other) => panic!("expected PropagatorInit, got {other:?}"),
+            Ok(_) => panic!("expected PropagatorInit, got Ok"),
+        }
+    }
+
+    /// Stages `slots` values through scratch memory and writes their sum
+    /// to every cell of `field`, recording the region capacity it saw.
+    struct ScratchUser {
+        field: FieldId,
+        slots: usize,
+        seen_capacity: Arc<AtomicUsize>,
+    }
+
+    impl Propagator for ScratchUser {
+        fn name(&self) -> &str {
+            "scratch_user"
+        }
+        fn reads(&self) -> FieldSet {
+            FieldSet::empty()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(self.field, WriteMode::Full)]
         }
+        fn scratch_bytes(&self) -> usize {
+            self.slots * std::mem::size_of::<Scalar>()
+        }
+        fn step(&self, ctx: &mut murk_propagator::StepContext<'_>) -> Result<(), PropagatorError> {
+            self.seen_capacity
+                .store(ctx.scratch().capacity(), Ordering::Relaxed);
+            let staged = ctx.scratch().alloc(self.slots).unwrap();
+            for (i, v) in staged.iter_mut().enumerate() {
+                *v = i as Scalar;
+            }
+            let total: Scalar = staged.iter().sum();
+            ctx.writes().write(self.field).unwrap().fill(total);
+            Ok(())
+        }
+    }
 
-        let snap = engine.snapshot();
-        let data = snap.read(FieldId(0)).unwrap();
-        assert!(data.iter().all(|&v| v == 42.0));
-        assert_eq!(engine.current_tick(), TickId(10));
+    fn scratch_engine(slots: [usize; 2]) -> (TickEngine, [Arc<AtomicUsize>; 2]) {
+        let seen = [Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0))];
+        let props: Vec<Box<dyn Propagator>> = (0..2)
+            .map(|i| {
+                Box::new(ScratchUser {
+                    field: FieldId(i as u32),
+                    slots: slots[i],
+                    seen_capacity: Arc::clone(&seen[i]),
+                }) as Box<dyn Propagator>
+            })
+            .collect();
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("a"), scalar_field("b")])
+            .propagators(props)
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        (TickEngine::new(config).unwrap(), seen)
     }
 
     #[test]
-    fn commands_flow_through_to_receipts() {
-        let mut engine = simple_engine();
-
-        // Use SetField commands — the only command type currently executed.
-        let coord: Coord = vec![0i32].into();
-        let cmds = vec![
-            Command {
-                payload: CommandPayload::SetField {
-                    coord: coord.clone(),
-                    field_id: FieldId(0),
-                    value: 1.0,
-                },
-                expires_after_tick: TickId(100),
-                source_id: None,
-                source_seq: None,
-                priority_class: 1,
-                arrival_seq: 0,
-            },
-            Command {
-                payload: CommandPayload::SetField {
-                    coord: coord.clone(),
-                    field_id: FieldId(0),
-                    value: 2.0,
-                },
-                expires_after_tick: TickId(100),
-                source_id: None,
-                source_seq: None,
-                priority_class: 1,
-                arrival_seq: 0,
-            },
-        ];
-        let submit_receipts = engine.submit_commands(cmds);
-        assert_eq!(submit_receipts.len(), 2);
-        assert!(submit_receipts.iter().all(|r| r.accepted));
-
-        let result = engine.execute_tick().unwrap();
-        // Should have receipts for the 2 commands.
-        let applied: Vec<_> = result
-            .receipts
+    fn each_propagator_gets_scratch_sized_to_its_request() {
+        let (mut engine, seen) = scratch_engine([1000, 3]);
+        let capacities: Vec<usize> = engine
+            .propagator_scratch
             .iter()
-            .filter(|r| r.applied_tick_id.is_some())
+            .map(|s| s.capacity())
             .collect();
-        assert_eq!(applied.len(), 2);
-        assert!(applied.iter().all(|r| r.applied_tick_id == Some(TickId(1))));
+        assert_eq!(capacities, vec![1000, 3]);
+
+        engine.execute_tick().unwrap();
+        assert!(seen[0].load(Ordering::Relaxed) >= 1000);
+        assert_eq!(seen[1].load(Ordering::Relaxed), 3);
     }
 
     #[test]
-    fn non_setfield_commands_rejected_honestly() {
-        let mut engine = simple_engine();
-
-        // Submit SetParameter commands — not yet implemented.
-        let submit_receipts = engine.submit_commands(vec![make_cmd(100), make_cmd(100)]);
-        assert_eq!(submit_receipts.len(), 2);
-        assert!(submit_receipts.iter().all(|r| r.accepted));
-
-        let result = engine.execute_tick().unwrap();
-        assert_eq!(result.receipts.len(), 2);
+    fn zero_scratch_propagator_allocates_nothing() {
+        let (mut engine, seen) = scratch_engine([8, 0]);
+        assert_eq!(engine.propagator_scratch[1].capacity(), 0);
+        engine.execute_tick().unwrap();
+        assert_eq!(seen[1].load(Ordering::Relaxed), 0);
+        assert_eq!(engine.snapshot().read(FieldId(1)).unwrap(), &[0.0; 4]);
+    }
 
-        // Non-SetField commands must NOT report as applied and must carry
-        // UnsupportedCommand reason so callers can distinguish the failure mode.
-        for receipt in &result.receipts {
-            assert!(
-                !receipt.accepted,
-                "unimplemented command type must be rejected"
-            );
-            assert_eq!(
-                receipt.applied_tick_id, None,
-                "unimplemented command must not have applied_tick_id"
-            );
-            assert_eq!(
-                receipt.reason_code,
-                Some(IngressError::UnsupportedCommand),
-                "rejected unsupported command must carry UnsupportedCommand reason"
-            );
+    #[test]
+    fn per_propagator_scratch_results_are_unchanged_across_ticks() {
+        let (mut engine, _) = scratch_engine([16, 5]);
+        for _ in 0..3 {
+            engine.execute_tick().unwrap();
+            // Sum of 0..16 and 0..5: scratch is re-zeroed and reused.
+            assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[120.0; 4]);
+            assert_eq!(engine.snapshot().read(FieldId(1)).unwrap(), &[10.0; 4]);
         }
     }
 
-    // ── Metrics tests ────────────────────────────────────────
-
     #[test]
     fn timing_fields_populated() {
         let mut engine = simple_engine();
@@ -1420,8 +3027,8 @@ mod tests {
 
         // total_us is u64, so it's always >= 0; just verify the struct is populated.
         let _ = result.metrics.total_us;
-        assert_eq!(result.metrics.propagator_us.len(), 1);
-        assert_eq!(result.metrics.propagator_us[0].0, "const");
+        assert_eq!(result.metrics.propagators.len(), 1);
+        assert_eq!(result.metrics.propagators[0].name, "const");
         assert_eq!(result.metrics.queue_full_rejections, 0);
         assert_eq!(result.metrics.tick_disabled_rejections, 0);
         assert_eq!(result.metrics.rollback_events, 0);
@@ -1433,6 +3040,53 @@ mod tests {
         assert_eq!(result.metrics.ring_skew_retry_events, 0);
     }
 
+    #[test]
+    fn propagator_metrics_count_cells() {
+        struct Observer;
+        impl Propagator for Observer {
+            fn name(&self) -> &str {
+                "observer"
+            }
+            fn reads(&self) -> murk_core::FieldSet {
+                [FieldId(0)].into_iter().collect()
+            }
+            fn optional_reads(&self) -> murk_core::FieldSet {
+                // FieldId(5) is not defined and must not be counted.
+                [FieldId(1), FieldId(5)].into_iter().collect()
+            }
+            fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+                vec![]
+            }
+            fn step(
+                &self,
+                _ctx: &mut murk_propagator::StepContext<'_>,
+            ) -> Result<(), murk_core::PropagatorError> {
+                Ok(())
+            }
+        }
+
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy"), vector_field("velocity", 3)])
+            .propagators(vec![
+                Box::new(ConstPropagator::new("const", FieldId(0), 42.0)),
+                Box::new(Observer),
+            ])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut engine = TickEngine::new(config).unwrap();
+        let result = engine.execute_tick().unwrap();
+
+        let writer = &result.metrics.propagators[0];
+        assert_eq!((writer.reads_cells, writer.writes_cells), (0, 10));
+        let reader = &result.metrics.propagators[1];
+        assert_eq!(reader.name, "observer");
+        // 10 scalar values plus 10 cells * 3 components of the optional read.
+        assert_eq!((reader.reads_cells, reader.writes_cells), (40, 0));
+    }
+
     #[test]
     fn memory_bytes_matches_arena() {
         let mut engine = simple_engine();
@@ -1468,6 +3122,42 @@ mod tests {
         assert_eq!(result.metrics.tick_disabled_rejections, 0);
     }
 
+    #[test]
+    fn ingress_drop_stats_persist_across_ticks_until_reset() {
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy")])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(0), 1.0))])
+            .dt(0.1)
+            .seed(42)
+            .max_ingress_queue(2)
+            .build()
+            .unwrap();
+        let mut engine = TickEngine::new(config).unwrap();
+
+        engine.submit_commands(vec![make_cmd(100); 5]);
+        assert_eq!(engine.last_metrics().dropped_commands, 3);
+        assert_eq!(engine.last_metrics().rejected_full_events, 1);
+
+        let result = engine.execute_tick().unwrap();
+        assert_eq!(result.metrics.dropped_commands, 3);
+        assert_eq!(result.metrics.rejected_full_events, 1);
+
+        // The queue drained, so a fitting batch adds nothing; the totals stay.
+        engine.submit_commands(vec![make_cmd(100); 2]);
+        let result = engine.execute_tick().unwrap();
+        assert_eq!(result.metrics.dropped_commands, 3);
+        assert_eq!(result.metrics.rejected_full_events, 1);
+
+        engine.submit_commands(vec![make_cmd(100); 3]);
+        assert_eq!(engine.last_metrics().dropped_commands, 4);
+        assert_eq!(engine.last_metrics().rejected_full_events, 2);
+
+        engine.reset().unwrap();
+        assert_eq!(engine.last_metrics().dropped_commands, 0);
+        assert_eq!(engine.last_metrics().rejected_full_events, 0);
+    }
+
     #[test]
     fn external_realtime_counters_are_reflected_in_metrics() {
         let mut engine = simple_engine();
@@ -1538,7 +3228,7 @@ mod tests {
                         reason: format!("field {:?} not writable", self.output),
                     }
                 })?;
-                output.fill(n as f32);
+                output.fill(n as Scalar);
                 Ok(())
             }
         }
@@ -1572,6 +3262,122 @@ mod tests {
         rollback_engine.execute_tick().unwrap();
     }
 
+    #[test]
+    fn soft_failure_discards_partial_writes() {
+        /// Writes `5.0` on its first call; afterwards scribbles `999.0`
+        /// over its output and then fails.
+        struct ScribbleThenFail {
+            calls: AtomicUsize,
+        }
+
+        impl Propagator for ScribbleThenFail {
+            fn name(&self) -> &str {
+                "scribble"
+            }
+
+            fn reads(&self) -> murk_core::FieldSet {
+                murk_core::FieldSet::empty()
+            }
+
+            fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+                vec![(FieldId(0), WriteMode::Full)]
+            }
+
+            fn step(
+                &self,
+                ctx: &mut murk_propagator::StepContext<'_>,
+            ) -> Result<(), murk_core::PropagatorError> {
+                let n = self.calls.fetch_add(1, Ordering::Relaxed);
+                let out = ctx.writes().write(FieldId(0)).unwrap();
+                if n == 0 {
+                    out.fill(5.0);
+                    return Ok(());
+                }
+                out.fill(999.0);
+                Err(murk_core::PropagatorError::ExecutionFailed {
+                    reason: "scribbled then failed".into(),
+                })
+            }
+        }
+
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("a"), scalar_field("copy")])
+            .propagators(vec![
+                Box::new(ScribbleThenFail {
+                    calls: AtomicUsize::new(0),
+                }),
+                Box::new(IdentityPropagator::new("copy", FieldId(0), FieldId(1))),
+            ])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut engine = TickEngine::new(config).unwrap();
+        assert!(engine.set_failure_policy(0, FailurePolicy::SkipFailed));
+
+        assert!(engine.execute_tick().unwrap().soft_failures.is_empty());
+        let result = engine.execute_tick().unwrap();
+        assert_eq!(result.soft_failures.len(), 1);
+
+        let snap = engine.snapshot();
+        assert_eq!(snap.read(FieldId(0)).unwrap(), &[5.0; 4]);
+        // The downstream reader saw the restored values, not the scribble.
+        assert_eq!(snap.read(FieldId(1)).unwrap(), &[5.0; 4]);
+        assert_eq!(engine.current_tick(), TickId(2));
+    }
+
+    #[test]
+    fn sim_time_accumulates_dt_and_resets() {
+        /// Writes `ctx.sim_time()` into every cell of field 0.
+        struct SimClock;
+
+        impl Propagator for SimClock {
+            fn name(&self) -> &str {
+                "clock"
+            }
+
+            fn reads(&self) -> murk_core::FieldSet {
+                murk_core::FieldSet::empty()
+            }
+
+            fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+                vec![(FieldId(0), WriteMode::Full)]
+            }
+
+            fn step(
+                &self,
+                ctx: &mut murk_propagator::StepContext<'_>,
+            ) -> Result<(), murk_core::PropagatorError> {
+                let t = ctx.sim_time() as Scalar;
+                ctx.writes().write(FieldId(0)).unwrap().fill(t);
+                Ok(())
+            }
+        }
+
+        let config = WorldConfig::builder()
+            .space(Box::new(Line1D::new(3, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("clock")])
+            .propagators(vec![Box::new(SimClock)])
+            .dt(0.25)
+            .seed(42)
+            .build()
+            .unwrap();
+        let mut engine = TickEngine::new(config).unwrap();
+        assert_eq!(engine.sim_time(), 0.0);
+
+        for _ in 0..8 {
+            engine.execute_tick().unwrap();
+        }
+        assert_eq!(engine.sim_time(), 8.0 * 0.25);
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[2.0; 3]);
+
+        engine.reset().unwrap();
+        assert_eq!(engine.sim_time(), 0.0);
+        engine.execute_tick().unwrap();
+        assert_eq!(engine.snapshot().read(FieldId(0)).unwrap(), &[0.25; 3]);
+    }
+
     // ── Bug-fix regression tests ─────────────────────────────
 
     #[test]
@@ -1602,6 +3408,7 @@ mod tests {
                     value: 1.0,
                 },
                 expires_after_tick: TickId(100),
+                expires_at: None,
                 source_id: None,
                 source_seq: None,
                 priority_class: 2, // low priority
@@ -1613,6 +3420,7 @@ mod tests {
                     value: 2.0,
                 },
                 expires_after_tick: TickId(100),
+                expires_at: None,
                 source_id: None,
                 source_seq: None,
                 priority_class: 0, // high priority — sorted first
@@ -1646,6 +3454,7 @@ mod tests {
                 value: 1.0,
             },
             expires_after_tick: TickId(100),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -1684,6 +3493,7 @@ mod tests {
                 value: 1.0,
             },
             expires_after_tick: TickId(100),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -1700,6 +3510,76 @@ mod tests {
         );
     }
 
+    /// 10x10 grid; `marker` (field 1) has no writer, so only commands
+    /// change it.
+    fn marker_grid_engine() -> TickEngine {
+        let config = WorldConfig::builder()
+            .space(Box::new(Square4::new(10, 10, EdgeBehavior::Absorb).unwrap()))
+            .fields(vec![scalar_field("energy"), scalar_field("marker")])
+            .propagators(vec![Box::new(ConstPropagator::new("const", FieldId(0), 1.0))])
+            .dt(0.1)
+            .seed(42)
+            .build()
+            .unwrap();
+        TickEngine::new(config).unwrap()
+    }
+
+    #[test]
+    fn set_field_batch_applies_in_one_tick_with_one_receipt() {
+        let mut engine = marker_grid_engine();
+        let cells: Vec<(Coord, f32)> = (0..10)
+            .flat_map(|r| (0..10).map(move |c| (vec![r, c].into(), (r * 10 + c) as f32)))
+            .collect();
+        engine.submit_commands(vec![Command::set_field_batch(FieldId(1), cells)]);
+
+        let result = engine.execute_tick().unwrap();
+        assert_eq!(result.receipts.len(), 1);
+        assert!(result.receipts[0].accepted);
+        assert_eq!(result.receipts[0].applied_tick_id, Some(TickId(1)));
+        assert_eq!(result.receipts[0].reason_code, None);
+        let marker = engine.snapshot().read(FieldId(1)).unwrap().to_vec();
+        let expected: Vec<Scalar> = (0..100).map(|i| i as Scalar).collect();
+        assert_eq!(marker, expected);
+    }
+
+    #[test]
+    fn set_field_batch_skips_and_flags_out_of_bounds_cells() {
+        let mut engine = marker_grid_engine();
+        let cells = vec![
+            (vec![0i32, 0].into(), 5.0),
+            (vec![99i32, 0].into(), 6.0),
+            (vec![9i32, 9].into(), 7.0),
+        ];
+        engine.submit_commands(vec![Command::set_field_batch(FieldId(1), cells)]);
+
+        let result = engine.execute_tick().unwrap();
+        let receipt = &result.receipts[0];
+        assert!(receipt.accepted, "partial batch must stay accepted");
+        assert_eq!(receipt.applied_tick_id, Some(TickId(1)));
+        assert_eq!(
+            receipt.reason_code,
+            Some(IngressError::PartiallyApplied { skipped: 1 })
+        );
+        let snap = engine.snapshot();
+        let marker = snap.read(FieldId(1)).unwrap();
+        assert_eq!((marker[0], marker[99]), (5.0, 7.0));
+        assert_eq!(marker.iter().filter(|&&v| v != 0.0).count(), 2);
+    }
+
+    #[test]
+    fn set_field_batch_with_no_valid_cells_is_not_applied() {
+        let mut engine = marker_grid_engine();
+        let cells = vec![(vec![-1i32, 0].into(), 1.0)];
+        engine.submit_commands(vec![Command::set_field_batch(FieldId(1), cells)]);
+
+        let result = engine.execute_tick().unwrap();
+        assert!(!result.receipts[0].accepted);
+        assert_eq!(
+            result.receipts[0].reason_code,
+            Some(IngressError::NotApplied)
+        );
+    }
+
     #[test]
     fn writemode_incremental_seeds_from_previous_gen() {
         // Regression test for BUG-015: WriteMode::Incremental buffers must
@@ -1994,17 +3874,20 @@ mod tests {
     fn static_field_overflow_returns_error() {
         let config = WorldConfig::builder()
             .space(Box::new(Line1D::new(3, EdgeBehavior::Absorb).unwrap()))
-            .fields(vec![FieldDef {
-                name: "huge_vec".to_string(),
-                field_type: FieldType::Vector {
-                    dims: u32::MAX / 2, // 3 * (u32::MAX/2) overflows u32
+            .fields(vec![
+                FieldDef {
+                    name: "huge_vec".to_string(),
+                    field_type: FieldType::Vector {
+                        dims: u32::MAX / 2, // 3 * (u32::MAX/2) overflows u32
+                    },
+                    mutability: FieldMutability::Static,
+                    units: None,
+                    bounds: None,
+                    boundary_behavior: BoundaryBehavior::Clamp,
                 },
-                mutability: FieldMutability::Static,
-                units: None,
-                bounds: None,
-                boundary_behavior: BoundaryBehavior::Clamp,
-            }])
-            .propagators(vec![Box::new(ConstPropagator::new("c", FieldId(0), 1.0))])
+                scalar_field("energy"),
+            ])
+            .propagators(vec![Box::new(ConstPropagator::new("c", FieldId(1), 1.0))])
             .dt(0.1)
             .seed(42)
             .build()
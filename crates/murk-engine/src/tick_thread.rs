@@ -12,18 +12,19 @@ use std::time::{Duration, Instant};
 
 use crossbeam_channel::Receiver;
 
-use crate::config::BackoffConfig;
+use crate::config::{BackoffConfig, BackoffStrategy};
 use crate::epoch::{EpochCounter, WorkerEpoch};
+use crate::realtime::SubmitStatus;
 use crate::ring::SnapshotRing;
 use crate::tick::TickEngine;
 
-use murk_core::command::{Command, Receipt};
+use murk_core::command::Command;
 
 /// A batch of commands submitted by a user thread, paired with a reply
-/// channel for the resulting receipts.
+/// channel for the resulting receipts and post-submit queue depth.
 pub(crate) struct IngressBatch {
     pub commands: Vec<Command>,
-    pub reply: crossbeam_channel::Sender<Vec<Receipt>>,
+    pub reply: crossbeam_channel::Sender<SubmitStatus>,
 }
 
 /// Adaptive backoff state machine for stalled-worker detection.
@@ -125,6 +126,73 @@ impl AdaptiveBackoff {
     }
 }
 
+/// Adaptive strategy: spin this many rounds before yielding.
+const ADAPTIVE_SPIN_ROUNDS: u32 = 64;
+/// Adaptive strategy: yield up to this round before parking.
+const ADAPTIVE_YIELD_ROUNDS: u32 = 128;
+/// Adaptive strategy: first park slice, doubled every further round.
+const ADAPTIVE_FIRST_PARK: Duration = Duration::from_micros(50);
+
+/// One step of an inter-tick wait.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum WaitStep {
+    Spin,
+    Yield,
+    Park(Duration),
+}
+
+/// Inter-tick wait driven by a [`BackoffStrategy`].
+pub(crate) struct IdleWait {
+    strategy: BackoffStrategy,
+    /// Rounds waited so far in the current wait (drives `Adaptive`).
+    round: u32,
+}
+
+impl IdleWait {
+    pub fn new(strategy: BackoffStrategy) -> Self {
+        Self { strategy, round: 0 }
+    }
+
+    /// The next wait step given `remaining` time until the deadline.
+    fn next_step(&mut self, remaining: Duration) -> WaitStep {
+        let round = self.round;
+        self.round = self.round.saturating_add(1);
+        match self.strategy {
+            BackoffStrategy::Spin => WaitStep::Spin,
+            BackoffStrategy::Yield => WaitStep::Yield,
+            BackoffStrategy::Sleep { min, max } => WaitStep::Park(remaining.clamp(min, max)),
+            BackoffStrategy::Adaptive if round < ADAPTIVE_SPIN_ROUNDS => WaitStep::Spin,
+            BackoffStrategy::Adaptive if round < ADAPTIVE_YIELD_ROUNDS => WaitStep::Yield,
+            BackoffStrategy::Adaptive => {
+                let doublings = (round - ADAPTIVE_YIELD_ROUNDS).min(16);
+                WaitStep::Park((ADAPTIVE_FIRST_PARK * (1 << doublings)).min(remaining))
+            }
+        }
+    }
+
+    /// Wait until `budget` has elapsed since `start` or until `shutdown`
+    /// is set, whichever comes first. Returns the number of wakeups (wait
+    /// steps taken).
+    pub fn wait_out(&mut self, start: Instant, budget: Duration, shutdown: &AtomicBool) -> u64 {
+        self.round = 0;
+        let mut wakeups = 0u64;
+        while !shutdown.load(Ordering::Acquire) {
+            let remaining = budget.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                break;
+            }
+            wakeups += 1;
+            match self.next_step(remaining) {
+                WaitStep::Spin => std::hint::spin_loop(),
+                WaitStep::Yield => std::thread::yield_now(),
+                // park_timeout, not sleep, so shutdown can unpark us.
+                WaitStep::Park(d) => std::thread::park_timeout(d),
+            }
+        }
+        wakeups
+    }
+}
+
 /// State held by the tick thread's main loop.
 pub(crate) struct TickThreadState {
     engine: TickEngine,
@@ -138,6 +206,7 @@ pub(crate) struct TickThreadState {
     max_epoch_hold_ns: u64,
     cancel_grace_ns: u64,
     backoff: AdaptiveBackoff,
+    idle: IdleWait,
 }
 
 impl TickThreadState {
@@ -168,6 +237,7 @@ impl TickThreadState {
             max_epoch_hold_ns: max_epoch_hold_ms.saturating_mul(1_000_000),
             cancel_grace_ns: cancel_grace_ms.saturating_mul(1_000_000),
             backoff: AdaptiveBackoff::new(backoff_config),
+            idle: IdleWait::new(backoff_config.strategy),
         }
     }
 
@@ -228,13 +298,10 @@ impl TickThreadState {
             self.engine
                 .set_ring_skew_retry_events(self.ring.skew_retry_events());
 
-            // 6. Sleep for remaining budget, interruptible by shutdown.
-            // Uses park_timeout instead of thread::sleep so the shutdown
-            // path can wake us immediately via thread::unpark().
-            let elapsed = tick_start.elapsed();
-            if let Some(remaining) = self.tick_budget.checked_sub(elapsed) {
-                std::thread::park_timeout(remaining);
-            }
+            // 6. Wait out the remaining budget per the backoff strategy,
+            // interruptible by shutdown.
+            self.idle
+                .wait_out(tick_start, self.tick_budget, &self.shutdown_flag);
         }
 
         // Signal that the tick thread has stopped.
@@ -247,7 +314,11 @@ impl TickThreadState {
         while let Ok(batch) = self.cmd_rx.try_recv() {
             let receipts = self.engine.submit_commands(batch.commands);
             // Best-effort reply — caller may have dropped their receiver.
-            let _ = batch.reply.send(receipts);
+            let _ = batch.reply.send(SubmitStatus {
+                receipts,
+                queue_depth: self.engine.ingress_queue_depth(),
+                queue_capacity: self.engine.ingress_queue_capacity(),
+            });
         }
     }
 
@@ -327,6 +398,7 @@ mod tests {
             max_skew_cap: 10,
             decay_rate: 60,
             rejection_rate_threshold: 0.20,
+            ..BackoffConfig::default()
         };
         let mut backoff = AdaptiveBackoff::new(&config);
         let skew = backoff.record_tick(true);
@@ -341,6 +413,7 @@ mod tests {
             max_skew_cap: 5,
             decay_rate: 60,
             rejection_rate_threshold: 0.20,
+            ..BackoffConfig::default()
         };
         let mut backoff = AdaptiveBackoff::new(&config);
         // Many rejections should cap at 5.
@@ -358,6 +431,7 @@ mod tests {
             max_skew_cap: 100,
             decay_rate: 10,
             rejection_rate_threshold: 0.20,
+            ..BackoffConfig::default()
         };
         let mut backoff = AdaptiveBackoff::new(&config);
 
@@ -399,6 +473,7 @@ mod tests {
             max_skew_cap: 10,
             decay_rate: 60,
             rejection_rate_threshold: 0.20,
+            ..BackoffConfig::default()
         };
         let mut backoff = AdaptiveBackoff::new(&config);
 
@@ -482,4 +557,82 @@ mod tests {
         let backoff = AdaptiveBackoff::new(&config);
         assert_eq!(backoff.initial_max_skew(), 5);
     }
+
+    // ── IdleWait tests ───────────────────────────────────────────
+
+    /// Wakeups spent idling through a 50 ms budget under `strategy`.
+    fn idle_wakeups(strategy: BackoffStrategy) -> u64 {
+        let shutdown = AtomicBool::new(false);
+        let start = Instant::now();
+        let wakeups = IdleWait::new(strategy).wait_out(start, Duration::from_millis(50), &shutdown);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        wakeups
+    }
+
+    #[test]
+    fn sleep_strategy_wakes_far_less_than_spin() {
+        let spin = idle_wakeups(BackoffStrategy::Spin);
+        let sleep = idle_wakeups(BackoffStrategy::Sleep {
+            min: Duration::from_millis(1),
+            max: Duration::from_millis(10),
+        });
+        // Park slices of at least 1 ms bound the wakeups to about 50.
+        assert!(sleep <= 60, "sleep woke {sleep} times");
+        assert!(
+            spin > sleep * 100,
+            "spin woke {spin} times vs sleep {sleep}"
+        );
+    }
+
+    #[test]
+    fn default_strategy_parks_for_whole_budget() {
+        let mut idle = IdleWait::new(BackoffStrategy::default());
+        let remaining = Duration::from_millis(16);
+        assert_eq!(idle.next_step(remaining), WaitStep::Park(remaining));
+    }
+
+    #[test]
+    fn adaptive_ramps_from_spin_to_growing_parks() {
+        let mut idle = IdleWait::new(BackoffStrategy::Adaptive);
+        let remaining = Duration::from_secs(1);
+        let steps: Vec<WaitStep> = (0..140).map(|_| idle.next_step(remaining)).collect();
+        assert!(steps[..64].iter().all(|&s| s == WaitStep::Spin));
+        assert!(steps[64..128].iter().all(|&s| s == WaitStep::Yield));
+        assert_eq!(steps[128], WaitStep::Park(Duration::from_micros(50)));
+        assert_eq!(steps[129], WaitStep::Park(Duration::from_micros(100)));
+        let parks: Vec<Duration> = steps[128..]
+            .iter()
+            .map(|s| match s {
+                WaitStep::Park(d) => *d,
+                other => panic!("expected Park, got {other:?}"),
+            })
+            .collect();
+        assert!(parks.windows(2).all(|w| w[0] <= w[1]));
+        // Parks never run past the deadline.
+        let short = Duration::from_micros(70);
+        assert_eq!(idle.next_step(short), WaitStep::Park(short));
+    }
+
+    #[test]
+    fn adaptive_ramp_restarts_each_wait() {
+        let shutdown = AtomicBool::new(false);
+        let mut idle = IdleWait::new(BackoffStrategy::Adaptive);
+        idle.wait_out(Instant::now(), Duration::from_millis(5), &shutdown);
+        assert!(idle.round > ADAPTIVE_YIELD_ROUNDS);
+        idle.wait_out(Instant::now(), Duration::ZERO, &shutdown);
+        assert_eq!(idle.next_step(Duration::from_secs(1)), WaitStep::Spin);
+    }
+
+    #[test]
+    fn wait_returns_immediately_on_shutdown() {
+        let shutdown = AtomicBool::new(true);
+        let start = Instant::now();
+        let wakeups = IdleWait::new(BackoffStrategy::Spin).wait_out(
+            start,
+            Duration::from_secs(10),
+            &shutdown,
+        );
+        assert_eq!(wakeups, 0);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
 }
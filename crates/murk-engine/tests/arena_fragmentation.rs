@@ -8,7 +8,7 @@
 use murk_core::command::{Command, CommandPayload};
 use murk_core::id::{FieldId, TickId};
 use murk_core::{
-    BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldType, PropagatorError,
+    BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldType, PropagatorError, Scalar,
 };
 use murk_engine::config::WorldConfig;
 use murk_engine::lockstep::LockstepWorld;
@@ -24,11 +24,11 @@ use smallvec::smallvec;
 struct FillPropagator {
     name: String,
     output: FieldId,
-    value: f32,
+    value: Scalar,
 }
 
 impl FillPropagator {
-    fn new(name: &str, output: FieldId, value: f32) -> Self {
+    fn new(name: &str, output: FieldId, value: Scalar) -> Self {
         Self {
             name: name.to_string(),
             output,
@@ -107,6 +107,7 @@ fn set_sparse_cmd(cell: i32, value: f32, expires: u64) -> Command {
             value,
         },
         expires_after_tick: TickId(expires),
+        expires_at: None,
         source_id: None,
         source_seq: None,
         priority_class: 1,
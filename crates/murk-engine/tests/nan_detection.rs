@@ -11,7 +11,7 @@ use murk_core::error::StepError;
 use murk_core::id::{FieldId, TickId};
 use murk_core::traits::SnapshotAccess;
 use murk_core::{
-    BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldType, PropagatorError,
+    BoundaryBehavior, FieldDef, FieldMutability, FieldSet, FieldType, PropagatorError, Scalar,
 };
 use murk_engine::config::WorldConfig;
 use murk_engine::lockstep::LockstepWorld;
@@ -68,7 +68,7 @@ impl Propagator for NanOnTickPropagator {
                 .ok_or_else(|| PropagatorError::ExecutionFailed {
                     reason: format!("field {:?} not writable", self.output),
                 })?;
-        out.fill((n + 1) as f32);
+        out.fill((n + 1) as Scalar);
 
         if n >= self.succeed_count {
             // Report NaN at cell index 0 in our output field.
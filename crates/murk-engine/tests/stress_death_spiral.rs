@@ -191,7 +191,9 @@ fn stress_death_spiral_resistance() {
             pool: None,
             transform: ObsTransform::Identity,
             dtype: ObsDtype::F32,
+            tick_offset: 0,
         }],
+        pad_value: 0.0,
     };
     let plan_result = ObsPlan::compile(&spec, space).unwrap();
     let plan = Arc::new(plan_result.plan);
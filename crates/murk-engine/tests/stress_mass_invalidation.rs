@@ -76,7 +76,9 @@ fn build_specs() -> Vec<ObsSpec> {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         });
     }
 
@@ -96,7 +98,9 @@ fn build_specs() -> Vec<ObsSpec> {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         });
     }
 
@@ -116,7 +120,9 @@ fn build_specs() -> Vec<ObsSpec> {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         });
     }
 
@@ -464,7 +470,9 @@ fn bound_plan_detects_invalidation_after_reset() {
             pool: None,
             transform: ObsTransform::Identity,
             dtype: ObsDtype::F32,
+            tick_offset: 0,
         }],
+        pad_value: 0.0,
     };
     let result = ObsPlan::compile_bound(&spec, world.space(), gen).unwrap();
 
@@ -35,6 +35,7 @@ fn make_agent_cmd(agent_id: u64, current_tick: TickId, seq: u64) -> Command {
             value: 0.0,
         },
         expires_after_tick: TickId(current_tick.0 + 2),
+        expires_at: None,
         source_id: Some(agent_id),
         source_seq: Some(seq),
         priority_class: 1,
@@ -87,6 +88,7 @@ fn stress_rejection_oscillation_stability() {
                 ScalarDiffusion::builder()
                     .input_field(VELOCITY)
                     .output_field(VELOCITY)
+                    .components(2)
                     .coefficient(0.1)
                     .build()
                     .unwrap(),
@@ -113,6 +115,7 @@ fn stress_rejection_oscillation_stability() {
             max_skew_cap: 10,
             decay_rate: 60,
             rejection_rate_threshold: 0.20,
+            ..BackoffConfig::default()
         })
         .build()
         .expect("failed to build config");
@@ -246,6 +246,27 @@ enum MurkStatus {
    * or unknown field).
    */
   MurkStatus_NotApplied = -22,
+  /**
+   * dt exceeds a propagator's stability (CFL) limit.
+   */
+  MurkStatus_CflViolation = -23,
+  /**
+   * A written field contained NaN or infinity and the tick was rolled back.
+   */
+  MurkStatus_NonFiniteField = -24,
+  /**
+   * A propagator changed the total of a conserved field beyond the
+   * audit tolerance.
+   */
+  MurkStatus_ConservationViolated = -25,
+  /**
+   * A batch command was applied with some entries skipped.
+   */
+  MurkStatus_PartiallyApplied = -26,
+  /**
+   * A command's wall-clock deadline passed before it was applied.
+   */
+  MurkStatus_Expired = -27,
   /**
    * A Rust panic was caught at the FFI boundary.
    */
@@ -275,6 +296,7 @@ typedef int32_t MurkWriteMode;
  * - 0: All (whole grid)
  * - 5: AgentDisk (radius in `region_params[0]`)
  * - 6: AgentRect (half-extents in `region_params[0..n_region_params]`)
+ * - 7: PositionChannel (agent position normalized to [0, 1] per axis; no params)
  *
  * Pool kernel values:
  * - 0: None (no pooling)
@@ -289,7 +311,7 @@ typedef struct MurkObsEntry {
    */
   uint32_t field_id;
   /**
-   * Region type: 0=All, 5=AgentDisk, 6=AgentRect.
+   * Region type: 0=All, 5=AgentDisk, 6=AgentRect, 7=PositionChannel.
    */
   int32_t region_type;
   /**
@@ -460,6 +482,16 @@ typedef struct MurkStepMetrics {
    * Cumulative number of reader retries caused by overwrite skew.
    */
   uint64_t ring_skew_retry_events;
+  /**
+   * Cumulative number of commands rejected at ingress submission
+   * (queue full or tick disabled).
+   */
+  uint64_t dropped_commands;
+  /**
+   * Cumulative number of submission batches that found the ingress
+   * queue full.
+   */
+  uint64_t rejected_full_events;
 } MurkStepMetrics;
 
 /**
@@ -645,7 +677,12 @@ int32_t murk_last_panic_message(char *buf, uintptr_t cap);
  * ABI version: major in upper 16 bits, minor in lower 16.
  *
  * Bump major on breaking changes, minor on additions.
- * Current: v3.1 (v3.0→v3.1: MurkStatus::NotApplied variant, batched _get functions)
+ * Current: v4.4 (v4.0→v4.1: MurkStatus::CflViolation;
+ * v4.1→v4.2: murk_step_metrics_propagator_cells;
+ * v4.2→v4.3: murk_world_generation_get, murk_world_param_version_get,
+ * murk_config_validate, murk_field_hash_get, MurkStatus::NonFiniteField,
+ * MurkStatus::ConservationViolated, MurkStatus::PartiallyApplied;
+ * v4.3→v4.4: MurkStatus::Expired)
  */
 uint32_t murk_abi_version(void);
 
@@ -830,6 +867,21 @@ int32_t murk_config_set_ring_buffer_size(uint64_t handle, uintptr_t size);
  */
 int32_t murk_config_set_max_ingress_queue(uint64_t handle, uintptr_t size);
 
+/**
+ * Check the config without consuming it, reporting why it is invalid.
+ *
+ * Runs the same validation `murk_lockstep_create` performs and returns
+ * its status: `MURK_OK` for a valid config, otherwise the specific error
+ * status (e.g. `MURK_ERROR_CONFIG_ERROR`, `MURK_ERROR_CFL_VIOLATION`).
+ * The handle stays valid and unchanged either way.
+ *
+ * If `out_reason` is non-null and `cap > 0`, a human-readable reason is
+ * copied into it (up to `cap - 1` bytes, null-terminated), following the
+ * `murk_last_panic_message` buffer convention. A valid config writes an
+ * empty string.
+ */
+int32_t murk_config_validate(uint64_t handle, char *out_reason, uintptr_t cap);
+
 /**
  * Query per-propagator timing from the most recent step on this thread.
  *
@@ -848,6 +900,19 @@ int32_t murk_step_metrics_propagator(uint64_t _world_handle,
                                      uintptr_t name_cap,
                                      uint64_t *us_out);
 
+/**
+ * Query per-propagator cell traffic from the most recent step on this thread.
+ *
+ * Reads the same thread-local snapshot as `murk_step_metrics_propagator`.
+ * `index` is 0-based. Writes the number of cell values the propagator read
+ * into `reads_out` and the number it wrote into `writes_out`
+ * (`cell_count * components` per declared field).
+ */
+int32_t murk_step_metrics_propagator_cells(uint64_t _world_handle,
+                                           uint32_t index,
+                                           uint64_t *reads_out,
+                                           uint64_t *writes_out);
+
 /**
  * Retrieve latest metrics for a world.
  */
@@ -1042,6 +1107,32 @@ uint64_t murk_seed(uint64_t world_handle);
  */
 int32_t murk_seed_get(uint64_t world_handle, uint64_t *out);
 
+/**
+ * World generation of the current snapshot.
+ *
+ * Writes the generation to `*out` and returns `MURK_OK`. Returns
+ * `InvalidHandle` or `InternalError` without writing to `out`.
+ */
+int32_t murk_world_generation_get(uint64_t world_handle, uint64_t *out);
+
+/**
+ * Parameter version of the current snapshot.
+ *
+ * Writes the version to `*out` and returns `MURK_OK`. Returns
+ * `InvalidHandle` or `InternalError` without writing to `out`.
+ */
+int32_t murk_world_param_version_get(uint64_t world_handle, uint64_t *out);
+
+/**
+ * FNV-1a hash of one field in the current snapshot.
+ *
+ * Writes the hash to `*out` and returns `MURK_OK`. The hash matches the
+ * replay snapshot hash restricted to `field_id`, for mid-run determinism
+ * checks. Returns `InvalidArgument` for an unknown field, `InvalidHandle`
+ * or `InternalError` without writing to `out`.
+ */
+int32_t murk_field_hash_get(uint64_t world_handle, uint32_t field_id, uint64_t *out);
+
 /**
  * Step multiple worlds sequentially. v1: no parallelism.
  *
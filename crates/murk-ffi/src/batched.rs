@@ -8,7 +8,6 @@ use std::sync::{Arc, Mutex};
 
 use murk_core::command::Command;
 use murk_engine::batched::BatchedEngine;
-use murk_engine::config::WorldConfig;
 use murk_obs::spec::ObsSpec;
 
 use crate::command::{convert_command, MurkCommand};
@@ -90,21 +89,7 @@ pub extern "C" fn murk_batched_create(
         // which validates all fields (space, fields, propagators, dt).
         let mut world_configs = Vec::with_capacity(n_worlds);
         for builder in builders {
-            let mut wcb = WorldConfig::builder()
-                .dt(builder.dt)
-                .seed(builder.seed)
-                .ring_buffer_size(builder.ring_buffer_size)
-                .max_ingress_queue(builder.max_ingress_queue);
-            if let Some(s) = builder.space {
-                wcb = wcb.space(s);
-            }
-            if !builder.fields.is_empty() {
-                wcb = wcb.fields(builder.fields);
-            }
-            if !builder.propagators.is_empty() {
-                wcb = wcb.propagators(builder.propagators);
-            }
-            match wcb.build() {
+            match builder.into_world_builder().build() {
                 Ok(c) => world_configs.push(c),
                 Err(_) => return MurkStatus::ConfigError as i32,
             }
@@ -125,6 +110,7 @@ pub extern "C" fn murk_batched_create(
             }
             Some(ObsSpec {
                 entries: rust_entries,
+                pad_value: 0.0,
             })
         } else {
             None
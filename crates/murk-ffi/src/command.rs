@@ -100,6 +100,7 @@ pub(crate) fn convert_command(cmd: &MurkCommand, index: usize) -> Result<Command
     Ok(Command {
         payload,
         expires_after_tick: TickId(cmd.expires_after_tick),
+        expires_at: None,
         source_id: if cmd.source_id == 0 {
             None
         } else {
@@ -8,6 +8,7 @@ use std::os::raw::c_char;
 use std::sync::Mutex;
 
 use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType};
+use murk_engine::config::{WorldConfig, WorldConfigBuilder};
 use murk_propagator::Propagator;
 use murk_space::{
     EdgeBehavior, Fcc12, Hex2D, Line1D, ProductSpace, Ring1D, Space, Square4, Square8,
@@ -46,6 +47,30 @@ impl Default for ConfigBuilder {
     }
 }
 
+impl ConfigBuilder {
+    /// Move the accumulated settings into a [`WorldConfigBuilder`].
+    ///
+    /// Empty field and propagator lists are left unset so `build()`
+    /// reports them through its own validation.
+    pub(crate) fn into_world_builder(self) -> WorldConfigBuilder {
+        let mut wcb = WorldConfig::builder()
+            .dt(self.dt)
+            .seed(self.seed)
+            .ring_buffer_size(self.ring_buffer_size)
+            .max_ingress_queue(self.max_ingress_queue);
+        if let Some(s) = self.space {
+            wcb = wcb.space(s);
+        }
+        if !self.fields.is_empty() {
+            wcb = wcb.fields(self.fields);
+        }
+        if !self.propagators.is_empty() {
+            wcb = wcb.propagators(self.propagators);
+        }
+        wcb
+    }
+}
+
 pub(crate) fn configs() -> &'static Mutex<HandleTable<ConfigBuilder>> {
     &CONFIGS
 }
@@ -420,6 +445,62 @@ pub extern "C" fn murk_config_set_max_ingress_queue(handle: u64, size: usize) ->
     })
 }
 
+/// Check the config without consuming it, reporting why it is invalid.
+///
+/// Runs the same validation `murk_lockstep_create` performs and returns
+/// its status: `MURK_OK` for a valid config, otherwise the specific error
+/// status (e.g. `MURK_ERROR_CONFIG_ERROR`, `MURK_ERROR_CFL_VIOLATION`).
+/// The handle stays valid and unchanged either way.
+///
+/// If `out_reason` is non-null and `cap > 0`, a human-readable reason is
+/// copied into it (up to `cap - 1` bytes, null-terminated), following the
+/// `murk_last_panic_message` buffer convention. A valid config writes an
+/// empty string.
+#[no_mangle]
+#[allow(unsafe_code)]
+pub extern "C" fn murk_config_validate(handle: u64, out_reason: *mut c_char, cap: usize) -> i32 {
+    ffi_guard!({
+        let mut table = ffi_lock!(CONFIGS);
+        let Some(cfg) = table.get_mut(handle) else {
+            return MurkStatus::InvalidHandle as i32;
+        };
+
+        // Move the owned parts into an engine builder, validate, then put
+        // them back so the handle can still be passed to create.
+        let builder = std::mem::take(cfg);
+        let (dt, seed, ring_buffer_size, max_ingress_queue) = (
+            builder.dt,
+            builder.seed,
+            builder.ring_buffer_size,
+            builder.max_ingress_queue,
+        );
+        let mut wcb = builder.into_world_builder();
+        let result = wcb.validate();
+        let (space, fields, propagators) = wcb.into_parts();
+        *cfg = ConfigBuilder {
+            space,
+            fields,
+            propagators,
+            dt,
+            seed,
+            ring_buffer_size,
+            max_ingress_queue,
+        };
+        drop(table);
+
+        match result {
+            Ok(()) => {
+                crate::write_c_string("", out_reason, cap);
+                MurkStatus::Ok as i32
+            }
+            Err(e) => {
+                crate::write_c_string(&e.to_string(), out_reason, cap);
+                MurkStatus::from(&e) as i32
+            }
+        }
+    })
+}
+
 // ── helpers ──────────────────────────────────────────────
 
 fn parse_edge_behavior(v: i32) -> Option<EdgeBehavior> {
@@ -658,6 +739,81 @@ mod tests {
         assert_eq!(f64_to_i32(f64::NEG_INFINITY), None);
     }
 
+    /// Validate `h` into a 128-byte buffer, returning the status and reason.
+    fn validate(h: u64) -> (i32, String) {
+        let mut buf = [0xffu8; 128];
+        let status = murk_config_validate(h, buf.as_mut_ptr() as *mut c_char, buf.len());
+        let reason = CStr::from_bytes_until_nul(&buf).unwrap();
+        (status, reason.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn validate_reports_missing_fields_and_keeps_handle() {
+        let mut h: u64 = 0;
+        assert_eq!(murk_config_create(&mut h), MurkStatus::Ok as i32);
+        let params = [10.0f64, 0.0]; // Line1D, len=10, Absorb
+        murk_config_set_space(h, MurkSpaceType::Line1D as i32, params.as_ptr(), 2);
+        murk_config_set_dt(h, 0.1);
+
+        let (status, reason) = validate(h);
+        assert_eq!(status, MurkStatus::ConfigError as i32);
+        assert_eq!(reason, "no fields registered");
+
+        // The handle survives validation and can be completed and used.
+        let name = CString::new("energy").unwrap();
+        murk_config_add_field(
+            h,
+            name.as_ptr(),
+            MurkFieldType::Scalar as i32,
+            MurkFieldMutability::PerTick as i32,
+            0,
+            MurkBoundaryBehavior::Clamp as i32,
+        );
+        let (status, reason) = validate(h);
+        assert_eq!(status, MurkStatus::ConfigError as i32);
+        assert!(reason.contains("pipeline"), "reason: {reason}");
+
+        configs()
+            .lock()
+            .unwrap()
+            .get_mut(h)
+            .unwrap()
+            .propagators
+            .push(Box::new(murk_test_utils::ConstPropagator::new(
+                "const",
+                murk_core::FieldId(0),
+                1.0,
+            )));
+        let (status, reason) = validate(h);
+        assert_eq!(status, MurkStatus::Ok as i32);
+        assert_eq!(reason, "");
+
+        let mut world: u64 = 0;
+        assert_eq!(
+            crate::world::murk_lockstep_create(h, &mut world),
+            MurkStatus::Ok as i32
+        );
+        crate::world::murk_lockstep_destroy(world);
+    }
+
+    #[test]
+    fn validate_with_null_reason_and_invalid_handle() {
+        let mut h: u64 = 0;
+        murk_config_create(&mut h);
+        assert_eq!(
+            murk_config_validate(h, std::ptr::null_mut(), 0),
+            MurkStatus::ConfigError as i32
+        );
+        murk_config_destroy(h);
+
+        let mut buf = [7u8; 8];
+        assert_eq!(
+            murk_config_validate(h, buf.as_mut_ptr() as *mut c_char, buf.len()),
+            MurkStatus::InvalidHandle as i32
+        );
+        assert_eq!(buf, [7u8; 8], "reason must be untouched for a bad handle");
+    }
+
     #[test]
     fn f64_to_i32_rejects_out_of_range() {
         assert_eq!(f64_to_i32(i32::MAX as f64 + 1.0), None);
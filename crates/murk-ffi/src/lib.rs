@@ -120,23 +120,30 @@ pub extern "C" fn murk_last_panic_message(buf: *mut std::ffi::c_char, cap: usize
         if msg.is_empty() {
             return 0i32;
         }
-        let len = msg.len();
-        let clamped_len = len.min(i32::MAX as usize) as i32;
-        if buf.is_null() {
-            return clamped_len;
-        }
-        let copy_len = if cap > 0 { len.min(cap - 1) } else { 0 };
-        // SAFETY: caller guarantees buf points to at least cap writable bytes.
-        if cap > 0 {
-            unsafe {
-                std::ptr::copy_nonoverlapping(msg.as_ptr(), buf as *mut u8, copy_len);
-                *buf.add(copy_len) = 0; // null terminator
-            }
-        }
-        clamped_len
+        write_c_string(&msg, buf, cap)
     })
 }
 
+/// Copy `msg` into a caller-provided C buffer, returning its full length.
+///
+/// If `buf` is null or `cap == 0`, nothing is written. Otherwise up to
+/// `cap - 1` bytes are copied and the buffer is null-terminated.
+#[allow(unsafe_code)]
+pub(crate) fn write_c_string(msg: &str, buf: *mut std::ffi::c_char, cap: usize) -> i32 {
+    let len = msg.len();
+    let clamped_len = len.min(i32::MAX as usize) as i32;
+    if buf.is_null() || cap == 0 {
+        return clamped_len;
+    }
+    let copy_len = len.min(cap - 1);
+    // SAFETY: caller guarantees buf points to at least cap writable bytes.
+    unsafe {
+        std::ptr::copy_nonoverlapping(msg.as_ptr(), buf as *mut u8, copy_len);
+        *buf.add(copy_len) = 0; // null terminator
+    }
+    clamped_len
+}
+
 pub mod batched;
 pub mod command;
 pub mod config;
@@ -159,9 +166,12 @@ pub use command::{MurkCommand, MurkCommandType, MurkReceipt};
 pub use config::{
     murk_config_add_field, murk_config_add_propagator, murk_config_create, murk_config_destroy,
     murk_config_set_dt, murk_config_set_max_ingress_queue, murk_config_set_ring_buffer_size,
-    murk_config_set_seed, murk_config_set_space,
+    murk_config_set_seed, murk_config_set_space, murk_config_validate,
+};
+pub use metrics::{
+    murk_step_metrics, murk_step_metrics_propagator, murk_step_metrics_propagator_cells,
+    MurkStepMetrics,
 };
-pub use metrics::{murk_step_metrics, murk_step_metrics_propagator, MurkStepMetrics};
 pub use obs::{
     murk_obsplan_compile, murk_obsplan_destroy, murk_obsplan_execute, murk_obsplan_execute_agents,
     murk_obsplan_mask_len, murk_obsplan_output_len, MurkObsEntry, MurkObsResult,
@@ -184,11 +194,16 @@ pub use world::{
 /// ABI version: major in upper 16 bits, minor in lower 16.
 ///
 /// Bump major on breaking changes, minor on additions.
-/// Current: v3.1 (v3.0→v3.1: MurkStatus::NotApplied variant, batched _get functions)
+/// Current: v4.4 (v4.0→v4.1: MurkStatus::CflViolation;
+/// v4.1→v4.2: murk_step_metrics_propagator_cells;
+/// v4.2→v4.3: murk_world_generation_get, murk_world_param_version_get,
+/// murk_config_validate, murk_field_hash_get, MurkStatus::NonFiniteField,
+/// MurkStatus::ConservationViolated, MurkStatus::PartiallyApplied;
+/// v4.3→v4.4: MurkStatus::Expired)
 #[no_mangle]
 #[allow(unsafe_code)]
 pub extern "C" fn murk_abi_version() -> u32 {
-    (3 << 16) | 1
+    (4 << 16) | 4
 }
 
 #[cfg(test)]
@@ -196,12 +211,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn abi_version_returns_v3_1() {
+    fn abi_version_returns_v4_4() {
         let v = murk_abi_version();
         let major = v >> 16;
         let minor = v & 0xFFFF;
-        assert_eq!(major, 3);
-        assert_eq!(minor, 1);
+        assert_eq!(major, 4);
+        assert_eq!(minor, 4);
     }
 
     #[test]
@@ -2,23 +2,26 @@
 //!
 //! Per-propagator timings are snapshotted into a thread-local buffer during
 //! `murk_lockstep_step` (while the world lock is held). This ensures that
-//! subsequent calls to `murk_step_metrics_propagator` return data from the
-//! same tick, even if another thread steps the same world concurrently.
+//! subsequent calls to `murk_step_metrics_propagator` and
+//! `murk_step_metrics_propagator_cells` return data from the same tick, even
+//! if another thread steps the same world concurrently.
 
 use std::cell::RefCell;
 use std::ffi::c_char;
 
+use murk_engine::PropagatorMetrics;
+
 use crate::status::MurkStatus;
 
 thread_local! {
-    /// Per-propagator timings snapshotted during the most recent
+    /// Per-propagator metrics snapshotted during the most recent
     /// `murk_lockstep_step` on this thread.
-    static LAST_PROPAGATOR_US: RefCell<Vec<(String, u64)>> = const { RefCell::new(Vec::new()) };
+    static LAST_PROPAGATOR_US: RefCell<Vec<PropagatorMetrics>> = const { RefCell::new(Vec::new()) };
 }
 
 /// Snapshot propagator timings from a step result into the thread-local
 /// buffer. Called by `murk_lockstep_step` while the world lock is held.
-pub(crate) fn snapshot_propagator_timings(us: &[(String, u64)]) {
+pub(crate) fn snapshot_propagator_timings(us: &[PropagatorMetrics]) {
     LAST_PROPAGATOR_US.with(|cell| {
         let mut buf = cell.borrow_mut();
         buf.clear();
@@ -67,12 +70,18 @@ pub struct MurkStepMetrics {
     pub ring_stale_read_events: u64,
     /// Cumulative number of reader retries caused by overwrite skew.
     pub ring_skew_retry_events: u64,
+    /// Cumulative number of commands rejected at ingress submission
+    /// (queue full or tick disabled).
+    pub dropped_commands: u64,
+    /// Cumulative number of submission batches that found the ingress
+    /// queue full.
+    pub rejected_full_events: u64,
 }
 
 // Compile-time layout assertions for ABI stability on 64-bit targets.
 // These verify that struct layout matches the C header (murk.h).
-// 4×u64 + 5×u32 + 4 bytes padding + 9×u64 = 128 bytes, align 8.
-const _: () = assert!(std::mem::size_of::<MurkStepMetrics>() == 128);
+// 4×u64 + 5×u32 + 4 bytes padding + 11×u64 = 144 bytes, align 8.
+const _: () = assert!(std::mem::size_of::<MurkStepMetrics>() == 144);
 const _: () = assert!(std::mem::align_of::<MurkStepMetrics>() == 8);
 
 impl MurkStepMetrics {
@@ -82,7 +91,7 @@ impl MurkStepMetrics {
             command_processing_us: m.command_processing_us,
             snapshot_publish_us: m.snapshot_publish_us,
             memory_bytes: m.memory_bytes as u64,
-            n_propagators: m.propagator_us.len() as u32,
+            n_propagators: m.propagators.len() as u32,
             sparse_retired_ranges: m.sparse_retired_ranges,
             sparse_pending_retired: m.sparse_pending_retired,
             sparse_reuse_hits: m.sparse_reuse_hits,
@@ -96,6 +105,8 @@ impl MurkStepMetrics {
             ring_eviction_events: m.ring_eviction_events,
             ring_stale_read_events: m.ring_stale_read_events,
             ring_skew_retry_events: m.ring_skew_retry_events,
+            dropped_commands: m.dropped_commands,
+            rejected_full_events: m.rejected_full_events,
         }
     }
 }
@@ -131,7 +142,7 @@ pub extern "C" fn murk_step_metrics_propagator(
                 return MurkStatus::InvalidArgument as i32;
             }
 
-            let (ref name, us) = data[idx];
+            let PropagatorMetrics { ref name, us, .. } = data[idx];
 
             // SAFETY: us_out is valid per caller contract.
             unsafe { *us_out = us };
@@ -152,6 +163,41 @@ pub extern "C" fn murk_step_metrics_propagator(
     })
 }
 
+/// Query per-propagator cell traffic from the most recent step on this thread.
+///
+/// Reads the same thread-local snapshot as `murk_step_metrics_propagator`.
+/// `index` is 0-based. Writes the number of cell values the propagator read
+/// into `reads_out` and the number it wrote into `writes_out`
+/// (`cell_count * components` per declared field).
+#[no_mangle]
+#[allow(unsafe_code)]
+pub extern "C" fn murk_step_metrics_propagator_cells(
+    _world_handle: u64,
+    index: u32,
+    reads_out: *mut u64,
+    writes_out: *mut u64,
+) -> i32 {
+    ffi_guard!({
+        if reads_out.is_null() || writes_out.is_null() {
+            return MurkStatus::InvalidArgument as i32;
+        }
+
+        LAST_PROPAGATOR_US.with(|cell| {
+            let data = cell.borrow();
+            let Some(m) = data.get(index as usize) else {
+                return MurkStatus::InvalidArgument as i32;
+            };
+
+            // SAFETY: reads_out and writes_out are valid per caller contract.
+            unsafe {
+                *reads_out = m.reads_cells;
+                *writes_out = m.writes_cells;
+            }
+            MurkStatus::Ok as i32
+        })
+    })
+}
+
 /// Retrieve latest metrics for a world.
 #[no_mangle]
 #[allow(unsafe_code)]
@@ -188,7 +234,12 @@ mod tests {
         let rust_metrics = murk_engine::StepMetrics {
             total_us: 500,
             command_processing_us: 100,
-            propagator_us: vec![("heat".to_string(), 200)],
+            propagators: vec![PropagatorMetrics {
+                name: "heat".to_string(),
+                us: 200,
+                reads_cells: 64,
+                writes_cells: 64,
+            }],
             snapshot_publish_us: 50,
             memory_bytes: 8192,
             sparse_retired_ranges: 7,
@@ -197,6 +248,8 @@ mod tests {
             sparse_reuse_misses: 3,
             queue_full_rejections: 11,
             tick_disabled_rejections: 4,
+            dropped_commands: 15,
+            rejected_full_events: 6,
             rollback_events: 2,
             tick_disabled_transitions: 1,
             worker_stall_events: 3,
@@ -206,6 +259,7 @@ mod tests {
             ring_skew_retry_events: 2,
         };
         let ffi = MurkStepMetrics::from_rust(&rust_metrics);
+        assert_eq!(ffi.n_propagators, 1);
         assert_eq!(ffi.sparse_retired_ranges, 7);
         assert_eq!(ffi.sparse_pending_retired, 2);
         assert_eq!(ffi.sparse_reuse_hits, 5);
@@ -219,6 +273,36 @@ mod tests {
         assert_eq!(ffi.ring_eviction_events, 9);
         assert_eq!(ffi.ring_stale_read_events, 4);
         assert_eq!(ffi.ring_skew_retry_events, 2);
+        assert_eq!(ffi.dropped_commands, 15);
+        assert_eq!(ffi.rejected_full_events, 6);
+    }
+
+    #[test]
+    fn propagator_cells_read_from_thread_local_snapshot() {
+        snapshot_propagator_timings(&[
+            PropagatorMetrics {
+                name: "writer".to_string(),
+                us: 5,
+                reads_cells: 0,
+                writes_cells: 100,
+            },
+            PropagatorMetrics {
+                name: "reader".to_string(),
+                us: 3,
+                reads_cells: 200,
+                writes_cells: 0,
+            },
+        ]);
+
+        let (mut reads, mut writes) = (u64::MAX, u64::MAX);
+        let rc = murk_step_metrics_propagator_cells(0, 1, &mut reads, &mut writes);
+        assert_eq!(rc, MurkStatus::Ok as i32);
+        assert_eq!((reads, writes), (200, 0));
+
+        let rc = murk_step_metrics_propagator_cells(0, 2, &mut reads, &mut writes);
+        assert_eq!(rc, MurkStatus::InvalidArgument as i32);
+        let rc = murk_step_metrics_propagator_cells(0, 0, std::ptr::null_mut(), &mut writes);
+        assert_eq!(rc, MurkStatus::InvalidArgument as i32);
     }
 
     #[test]
@@ -237,5 +321,7 @@ mod tests {
         assert_eq!(m.ring_eviction_events, 0);
         assert_eq!(m.ring_stale_read_events, 0);
         assert_eq!(m.ring_skew_retry_events, 0);
+        assert_eq!(m.dropped_commands, 0);
+        assert_eq!(m.rejected_full_events, 0);
     }
 }
@@ -69,6 +69,8 @@ pub(crate) fn convert_obs_entry(e: &MurkObsEntry) -> Option<ObsEntry> {
             }
             ObsRegion::AgentDisk {
                 radius: e.region_params[0] as u32,
+                metric: None,
+                occlusion: None,
             }
         }
         6 => {
@@ -81,8 +83,12 @@ pub(crate) fn convert_obs_entry(e: &MurkObsEntry) -> Option<ObsEntry> {
             }
             let half_extent: SmallVec<[u32; 4]> =
                 e.region_params[..n].iter().map(|&v| v as u32).collect();
-            ObsRegion::AgentRect { half_extent }
+            ObsRegion::AgentRect {
+                half_extent,
+                occlusion: None,
+            }
         }
+        7 => ObsRegion::PositionChannel,
         _ => return None,
     };
 
@@ -128,6 +134,7 @@ pub(crate) fn convert_obs_entry(e: &MurkObsEntry) -> Option<ObsEntry> {
         pool,
         transform,
         dtype,
+        tick_offset: 0,
     })
 }
 
@@ -141,6 +148,7 @@ struct ObsPlanState {
 /// - 0: All (whole grid)
 /// - 5: AgentDisk (radius in `region_params[0]`)
 /// - 6: AgentRect (half-extents in `region_params[0..n_region_params]`)
+/// - 7: PositionChannel (agent position normalized to [0, 1] per axis; no params)
 ///
 /// Pool kernel values:
 /// - 0: None (no pooling)
@@ -153,7 +161,7 @@ struct ObsPlanState {
 pub struct MurkObsEntry {
     /// Field ID to observe.
     pub field_id: u32,
-    /// Region type: 0=All, 5=AgentDisk, 6=AgentRect.
+    /// Region type: 0=All, 5=AgentDisk, 6=AgentRect, 7=PositionChannel.
     pub region_type: i32,
     /// Transform type: 0 = Identity, 1 = Normalize.
     pub transform_type: i32,
@@ -218,6 +226,7 @@ pub extern "C" fn murk_obsplan_compile(
         }
         let spec = ObsSpec {
             entries: obs_entries,
+            pad_value: 0.0,
         };
 
         // Get the space from the world to trigger initial compilation.
@@ -230,7 +239,7 @@ pub extern "C" fn murk_obsplan_compile(
         };
         let world = ffi_lock!(world_arc);
 
-        let mut cache = ObsPlanCache::new(spec);
+        let mut cache = ObsPlanCache::with_fields(spec, world.field_defs().to_vec());
         // Compile eagerly so we detect errors now rather than at execute time.
         if let Err(e) = cache.get_or_compile(world.space()) {
             return MurkStatus::from(&e) as i32;
@@ -6,7 +6,10 @@
 
 use std::ffi::{c_char, c_void, CStr};
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{
+    scalar_slice_as_f32, scalar_slice_as_f32_mut, scalar_to_f32, FieldId, FieldSet,
+    PropagatorError, Scalar,
+};
 use murk_propagator::propagator::WriteMode;
 use murk_propagator::StepContext;
 
@@ -137,19 +140,27 @@ impl murk_propagator::Propagator for CallbackPropagator {
     #[allow(unsafe_code)]
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
         // Build the MurkStepContext with trampolines that call back into Rust.
-        let mut wrapper = StepContextWrapper { ctx };
+        let tick_id = ctx.tick_id().0;
+        let dt = ctx.dt();
+        let cell_count = ctx.space().cell_count() as u64;
+        let mut wrapper = StepContextWrapper {
+            ctx,
+            staged_reads: Vec::new(),
+            staged_writes: Vec::new(),
+        };
         let c_ctx = MurkStepContext {
             opaque: &mut wrapper as *mut StepContextWrapper<'_, '_> as *mut c_void,
             read_fn: trampoline_read,
             read_previous_fn: trampoline_read_previous,
             write_fn: trampoline_write,
-            tick_id: ctx.tick_id().0,
-            dt: ctx.dt(),
-            cell_count: ctx.space().cell_count() as u64,
+            tick_id,
+            dt,
+            cell_count,
         };
 
         // SAFETY: step_fn and user_data are valid per FFI contract.
         let rc = unsafe { (self.step_fn)(self.user_data, &c_ctx) };
+        wrapper.flush_staged_writes();
 
         if rc == 0 {
             Ok(())
@@ -162,8 +173,33 @@ impl murk_propagator::Propagator for CallbackPropagator {
 }
 
 // Trampoline wrapper to recover StepContext from opaque pointer.
+//
+// When field storage is not `f32` (`f64-fields`), the trampolines hand C
+// narrowed copies instead of the arena buffers; staged writes are widened
+// back into the arena once the callback returns.
 struct StepContextWrapper<'a, 'b> {
     ctx: &'a mut StepContext<'b>,
+    staged_reads: Vec<Vec<f32>>,
+    staged_writes: Vec<(FieldId, Vec<f32>)>,
+}
+
+impl StepContextWrapper<'_, '_> {
+    fn stage_read(&mut self, staged: Vec<f32>) -> (*const f32, usize) {
+        // The heap buffer does not move when the outer Vec grows.
+        let out = (staged.as_ptr(), staged.len());
+        self.staged_reads.push(staged);
+        out
+    }
+
+    fn flush_staged_writes(&mut self) {
+        for (field, staged) in self.staged_writes.drain(..) {
+            if let Some(data) = self.ctx.writes().write(field) {
+                for (dst, &src) in data.iter_mut().zip(&staged) {
+                    *dst = Scalar::from(src);
+                }
+            }
+        }
+    }
 }
 
 #[allow(unsafe_code)]
@@ -177,15 +213,20 @@ unsafe extern "C" fn trampoline_read(
         return MurkStatus::InvalidArgument as i32;
     }
     // SAFETY: opaque was set to &mut StepContextWrapper in step() above.
-    let wrapper = &*(opaque as *const StepContextWrapper<'_, '_>);
-    match wrapper.ctx.reads().read(FieldId(field_id)) {
-        Some(data) => {
-            *out_ptr = data.as_ptr();
-            *out_len = data.len();
-            0
+    let wrapper = &mut *(opaque as *mut StepContextWrapper<'_, '_>);
+    let Some(data) = wrapper.ctx.reads().read(FieldId(field_id)) else {
+        return MurkStatus::InvalidArgument as i32;
+    };
+    let (ptr, len) = match scalar_slice_as_f32(data) {
+        Some(f) => (f.as_ptr(), f.len()),
+        None => {
+            let staged = data.iter().map(|&v| scalar_to_f32(v)).collect();
+            wrapper.stage_read(staged)
         }
-        None => MurkStatus::InvalidArgument as i32,
-    }
+    };
+    *out_ptr = ptr;
+    *out_len = len;
+    0
 }
 
 #[allow(unsafe_code)]
@@ -199,15 +240,20 @@ unsafe extern "C" fn trampoline_read_previous(
         return MurkStatus::InvalidArgument as i32;
     }
     // SAFETY: opaque was set to &mut StepContextWrapper in step() above.
-    let wrapper = &*(opaque as *const StepContextWrapper<'_, '_>);
-    match wrapper.ctx.reads_previous().read(FieldId(field_id)) {
-        Some(data) => {
-            *out_ptr = data.as_ptr();
-            *out_len = data.len();
-            0
+    let wrapper = &mut *(opaque as *mut StepContextWrapper<'_, '_>);
+    let Some(data) = wrapper.ctx.reads_previous().read(FieldId(field_id)) else {
+        return MurkStatus::InvalidArgument as i32;
+    };
+    let (ptr, len) = match scalar_slice_as_f32(data) {
+        Some(f) => (f.as_ptr(), f.len()),
+        None => {
+            let staged = data.iter().map(|&v| scalar_to_f32(v)).collect();
+            wrapper.stage_read(staged)
         }
-        None => MurkStatus::InvalidArgument as i32,
-    }
+    };
+    *out_ptr = ptr;
+    *out_len = len;
+    0
 }
 
 #[allow(unsafe_code)]
@@ -222,14 +268,25 @@ unsafe extern "C" fn trampoline_write(
     }
     // SAFETY: opaque was set to &mut StepContextWrapper in step() above.
     let wrapper = &mut *(opaque as *mut StepContextWrapper<'_, '_>);
-    match wrapper.ctx.writes().write(FieldId(field_id)) {
-        Some(data) => {
-            *out_ptr = data.as_mut_ptr();
-            *out_len = data.len();
-            0
-        }
-        None => MurkStatus::InvalidArgument as i32,
+    let field = FieldId(field_id);
+    if let Some((_, staged)) = wrapper.staged_writes.iter_mut().find(|(f, _)| *f == field) {
+        *out_ptr = staged.as_mut_ptr();
+        *out_len = staged.len();
+        return 0;
+    }
+    let Some(data) = wrapper.ctx.writes().write(field) else {
+        return MurkStatus::InvalidArgument as i32;
+    };
+    if let Some(f) = scalar_slice_as_f32_mut(data) {
+        *out_ptr = f.as_mut_ptr();
+        *out_len = f.len();
+        return 0;
     }
+    let mut staged: Vec<f32> = data.iter().map(|&v| scalar_to_f32(v)).collect();
+    *out_ptr = staged.as_mut_ptr();
+    *out_len = staged.len();
+    wrapper.staged_writes.push((field, staged));
+    0
 }
 
 /// Create a propagator from a C definition.
@@ -7,6 +7,7 @@
 use murk_core::error::{IngressError, ObsError, StepError};
 use murk_engine::config::ConfigError;
 use murk_engine::tick::TickError;
+use murk_propagator::PipelineError;
 
 /// C-compatible status code returned by all FFI functions.
 ///
@@ -61,6 +62,17 @@ pub enum MurkStatus {
     /// Command was accepted but could not be applied (e.g. invalid coordinate
     /// or unknown field).
     NotApplied = -22,
+    /// dt exceeds a propagator's stability (CFL) limit.
+    CflViolation = -23,
+    /// A written field contained NaN or infinity and the tick was rolled back.
+    NonFiniteField = -24,
+    /// A propagator changed the total of a conserved field beyond the
+    /// audit tolerance.
+    ConservationViolated = -25,
+    /// A batch command was applied with some entries skipped.
+    PartiallyApplied = -26,
+    /// A command's wall-clock deadline passed before it was applied.
+    Expired = -27,
     /// A Rust panic was caught at the FFI boundary.
     Panicked = -128,
 }
@@ -73,6 +85,9 @@ impl From<&StepError> for MurkStatus {
             StepError::TickRollback => MurkStatus::TickRollback,
             StepError::TickDisabled => MurkStatus::TickDisabled,
             StepError::DtOutOfRange => MurkStatus::DtOutOfRange,
+            StepError::CflViolation { .. } => MurkStatus::CflViolation,
+            StepError::NonFiniteField { .. } => MurkStatus::NonFiniteField,
+            StepError::ConservationViolated { .. } => MurkStatus::ConservationViolated,
             StepError::ShuttingDown => MurkStatus::ShuttingDown,
         }
     }
@@ -99,8 +114,11 @@ impl From<&ObsError> for MurkStatus {
 }
 
 impl From<&ConfigError> for MurkStatus {
-    fn from(_e: &ConfigError) -> Self {
-        MurkStatus::ConfigError
+    fn from(e: &ConfigError) -> Self {
+        match e {
+            ConfigError::Pipeline(PipelineError::CflViolation { .. }) => MurkStatus::CflViolation,
+            _ => MurkStatus::ConfigError,
+        }
     }
 }
 
@@ -109,11 +127,13 @@ impl From<&IngressError> for MurkStatus {
         match e {
             IngressError::QueueFull => MurkStatus::QueueFull,
             IngressError::Stale => MurkStatus::Stale,
+            IngressError::Expired => MurkStatus::Expired,
             IngressError::TickRollback => MurkStatus::TickRollback,
             IngressError::TickDisabled => MurkStatus::TickDisabled,
             IngressError::ShuttingDown => MurkStatus::ShuttingDown,
             IngressError::UnsupportedCommand => MurkStatus::UnsupportedCommand,
             IngressError::NotApplied => MurkStatus::NotApplied,
+            IngressError::PartiallyApplied { .. } => MurkStatus::PartiallyApplied,
         }
     }
 }
@@ -148,6 +168,11 @@ mod tests {
         assert_eq!(MurkStatus::InternalError as i32, -20);
         assert_eq!(MurkStatus::UnsupportedCommand as i32, -21);
         assert_eq!(MurkStatus::NotApplied as i32, -22);
+        assert_eq!(MurkStatus::CflViolation as i32, -23);
+        assert_eq!(MurkStatus::NonFiniteField as i32, -24);
+        assert_eq!(MurkStatus::ConservationViolated as i32, -25);
+        assert_eq!(MurkStatus::PartiallyApplied as i32, -26);
+        assert_eq!(MurkStatus::Expired as i32, -27);
     }
 
     #[test]
@@ -177,12 +202,57 @@ mod tests {
             MurkStatus::from(&StepError::DtOutOfRange),
             MurkStatus::DtOutOfRange
         );
+        assert_eq!(
+            MurkStatus::from(&StepError::CflViolation {
+                propagator: "diffusion".into(),
+                dt: 0.5,
+                max_dt: 0.25,
+            }),
+            MurkStatus::CflViolation
+        );
+        assert_eq!(
+            MurkStatus::from(&StepError::NonFiniteField {
+                field: murk_core::FieldId(0),
+                count: 1,
+            }),
+            MurkStatus::NonFiniteField
+        );
+        assert_eq!(
+            MurkStatus::from(&StepError::ConservationViolated {
+                propagator: "leaky".into(),
+                field: murk_core::FieldId(0),
+                before: 1.0,
+                after: 0.5,
+            }),
+            MurkStatus::ConservationViolated
+        );
         assert_eq!(
             MurkStatus::from(&StepError::ShuttingDown),
             MurkStatus::ShuttingDown
         );
     }
 
+    #[test]
+    fn config_error_to_status() {
+        assert_eq!(
+            MurkStatus::from(&ConfigError::MissingSpace),
+            MurkStatus::ConfigError
+        );
+        assert_eq!(
+            MurkStatus::from(&ConfigError::Pipeline(PipelineError::EmptyPipeline)),
+            MurkStatus::ConfigError
+        );
+        let cfl = PipelineError::CflViolation {
+            propagator: "diffusion".into(),
+            dt: 0.5,
+            max_dt: 0.25,
+        };
+        assert_eq!(
+            MurkStatus::from(&ConfigError::Pipeline(cfl)),
+            MurkStatus::CflViolation
+        );
+    }
+
     #[test]
     fn obs_error_to_status() {
         assert_eq!(
@@ -222,6 +292,10 @@ mod tests {
             MurkStatus::QueueFull
         );
         assert_eq!(MurkStatus::from(&IngressError::Stale), MurkStatus::Stale);
+        assert_eq!(
+            MurkStatus::from(&IngressError::Expired),
+            MurkStatus::Expired
+        );
         assert_eq!(
             MurkStatus::from(&IngressError::TickRollback),
             MurkStatus::TickRollback
@@ -242,6 +316,10 @@ mod tests {
             MurkStatus::from(&IngressError::NotApplied),
             MurkStatus::NotApplied
         );
+        assert_eq!(
+            MurkStatus::from(&IngressError::PartiallyApplied { skipped: 3 }),
+            MurkStatus::PartiallyApplied
+        );
     }
 
     #[test]
@@ -9,7 +9,7 @@ use std::sync::{Arc, Mutex};
 
 use murk_core::id::FieldId;
 use murk_core::traits::SnapshotAccess;
-use murk_engine::config::WorldConfig;
+use murk_core::scalar_to_f32;
 use murk_engine::LockstepWorld;
 
 use crate::command::{convert_command, convert_receipt, MurkCommand, MurkReceipt};
@@ -89,27 +89,13 @@ pub extern "C" fn murk_lockstep_create(config_handle: u64, world_out: *mut u64)
         // The murk-ffi ConfigBuilder (murk-ffi/src/config.rs) is intentionally
         // unvalidated — C callers cannot receive Rust Result values during
         // incremental builder calls. All validation happens here at world-
-        // creation time via WorldConfigBuilder::build().
+        // creation time via WorldConfigBuilder::build(); murk_config_validate
+        // runs the same checks ahead of time without consuming the config.
         //
         // Note: the FFI ConfigBuilder defaults dt to 0.016 (60Hz). This is a
         // convenience default for C/Python callers. The Rust-level
         // WorldConfigBuilder requires dt to be set explicitly.
-        let mut wcb = WorldConfig::builder()
-            .dt(builder.dt)
-            .seed(builder.seed)
-            .ring_buffer_size(builder.ring_buffer_size)
-            .max_ingress_queue(builder.max_ingress_queue);
-        if let Some(s) = builder.space {
-            wcb = wcb.space(s);
-        }
-        if !builder.fields.is_empty() {
-            wcb = wcb.fields(builder.fields);
-        }
-        if !builder.propagators.is_empty() {
-            wcb = wcb.propagators(builder.propagators);
-        }
-
-        let config = match wcb.build() {
+        let config = match builder.into_world_builder().build() {
             Ok(c) => c,
             Err(e) => return MurkStatus::from(&e) as i32,
         };
@@ -188,7 +174,7 @@ pub extern "C" fn murk_lockstep_step(
                 // Snapshot propagator timings into thread-local while the
                 // world lock is still held, so murk_step_metrics_propagator
                 // returns data from the same tick as the aggregate metrics.
-                crate::metrics::snapshot_propagator_timings(&result.metrics.propagator_us);
+                crate::metrics::snapshot_propagator_timings(&result.metrics.propagators);
 
                 // Write metrics.
                 if !metrics_out.is_null() {
@@ -269,8 +255,9 @@ pub extern "C" fn murk_snapshot_read_field(
         }
 
         // SAFETY: buf points to buf_len valid f32 values.
-        unsafe {
-            std::ptr::copy_nonoverlapping(data.as_ptr(), buf, data.len());
+        let out = unsafe { std::slice::from_raw_parts_mut(buf, data.len()) };
+        for (dst, &src) in out.iter_mut().zip(data) {
+            *dst = scalar_to_f32(src);
         }
 
         MurkStatus::Ok as i32
@@ -502,6 +489,77 @@ pub extern "C" fn murk_seed_get(world_handle: u64, out: *mut u64) -> i32 {
     })
 }
 
+/// World generation of the current snapshot.
+///
+/// Writes the generation to `*out` and returns `MURK_OK`. Returns
+/// `InvalidHandle` or `InternalError` without writing to `out`.
+#[no_mangle]
+#[allow(unsafe_code)]
+pub extern "C" fn murk_world_generation_get(world_handle: u64, out: *mut u64) -> i32 {
+    ffi_guard!({
+        if out.is_null() {
+            return MurkStatus::InvalidArgument as i32;
+        }
+        let world_arc = match get_world(world_handle) {
+            Ok(Some(arc)) => arc,
+            Ok(None) => return MurkStatus::InvalidHandle as i32,
+            Err(()) => return MurkStatus::InternalError as i32,
+        };
+        let world = ffi_lock!(world_arc);
+        unsafe { *out = world.snapshot().world_generation_id().0 };
+        MurkStatus::Ok as i32
+    })
+}
+
+/// Parameter version of the current snapshot.
+///
+/// Writes the version to `*out` and returns `MURK_OK`. Returns
+/// `InvalidHandle` or `InternalError` without writing to `out`.
+#[no_mangle]
+#[allow(unsafe_code)]
+pub extern "C" fn murk_world_param_version_get(world_handle: u64, out: *mut u64) -> i32 {
+    ffi_guard!({
+        if out.is_null() {
+            return MurkStatus::InvalidArgument as i32;
+        }
+        let world_arc = match get_world(world_handle) {
+            Ok(Some(arc)) => arc,
+            Ok(None) => return MurkStatus::InvalidHandle as i32,
+            Err(()) => return MurkStatus::InternalError as i32,
+        };
+        let world = ffi_lock!(world_arc);
+        unsafe { *out = world.snapshot().parameter_version().0 };
+        MurkStatus::Ok as i32
+    })
+}
+
+/// FNV-1a hash of one field in the current snapshot.
+///
+/// Writes the hash to `*out` and returns `MURK_OK`. The hash matches the
+/// replay snapshot hash restricted to `field_id`, for mid-run determinism
+/// checks. Returns `InvalidArgument` for an unknown field, `InvalidHandle`
+/// or `InternalError` without writing to `out`.
+#[no_mangle]
+#[allow(unsafe_code)]
+pub extern "C" fn murk_field_hash_get(world_handle: u64, field_id: u32, out: *mut u64) -> i32 {
+    ffi_guard!({
+        if out.is_null() {
+            return MurkStatus::InvalidArgument as i32;
+        }
+        let world_arc = match get_world(world_handle) {
+            Ok(Some(arc)) => arc,
+            Ok(None) => return MurkStatus::InvalidHandle as i32,
+            Err(()) => return MurkStatus::InternalError as i32,
+        };
+        let world = ffi_lock!(world_arc);
+        let Some(hash) = world.field_hash(FieldId(field_id)) else {
+            return MurkStatus::InvalidArgument as i32;
+        };
+        unsafe { *out = hash };
+        MurkStatus::Ok as i32
+    })
+}
+
 /// Step multiple worlds sequentially. v1: no parallelism.
 ///
 /// If any world fails, returns the first error. All preceding worlds'
@@ -557,7 +615,7 @@ pub extern "C" fn murk_lockstep_step_vec(
 
             match world.step_sync(rust_cmds) {
                 Ok(result) => {
-                    crate::metrics::snapshot_propagator_timings(&result.metrics.propagator_us);
+                    crate::metrics::snapshot_propagator_timings(&result.metrics.propagators);
                     if !metrics_out.is_null() {
                         let m = MurkStepMetrics::from_rust(&result.metrics);
                         unsafe { *metrics_out.add(i) = m };
@@ -1225,6 +1283,96 @@ mod tests {
         murk_lockstep_destroy(world_h);
     }
 
+    #[test]
+    fn generation_and_param_version_match_snapshot() {
+        let world_h = create_test_world();
+        for _ in 0..3 {
+            assert_eq!(
+                murk_lockstep_step(
+                    world_h,
+                    std::ptr::null(),
+                    0,
+                    std::ptr::null_mut(),
+                    0,
+                    std::ptr::null_mut(),
+                    std::ptr::null_mut(),
+                ),
+                MurkStatus::Ok as i32
+            );
+        }
+
+        let mut generation: u64 = u64::MAX;
+        assert_eq!(
+            murk_world_generation_get(world_h, &mut generation),
+            MurkStatus::Ok as i32
+        );
+        let mut param_version: u64 = u64::MAX;
+        assert_eq!(
+            murk_world_param_version_get(world_h, &mut param_version),
+            MurkStatus::Ok as i32
+        );
+
+        let world_arc = get_world(world_h).unwrap().unwrap();
+        let world = world_arc.lock().unwrap();
+        let snap = world.snapshot();
+        assert_eq!(generation, snap.world_generation_id().0);
+        assert!(generation > 0, "stepping advances the generation");
+        assert_eq!(param_version, snap.parameter_version().0);
+        drop(world);
+
+        murk_lockstep_destroy(world_h);
+    }
+
+    #[test]
+    fn field_hash_get_matches_world_and_rejects_unknown_field() {
+        let world_h = create_test_world();
+        let mut before: u64 = 0;
+        assert_eq!(
+            murk_field_hash_get(world_h, 0, &mut before),
+            MurkStatus::Ok as i32
+        );
+        assert_eq!(
+            murk_lockstep_step(
+                world_h,
+                std::ptr::null(),
+                0,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            ),
+            MurkStatus::Ok as i32
+        );
+        let mut after: u64 = 0;
+        assert_eq!(
+            murk_field_hash_get(world_h, 0, &mut after),
+            MurkStatus::Ok as i32
+        );
+        assert_ne!(before, after, "stepping writes the field");
+        let world_arc = get_world(world_h).unwrap().unwrap();
+        assert_eq!(
+            Some(after),
+            world_arc.lock().unwrap().field_hash(FieldId(0))
+        );
+
+        let mut untouched: u64 = 7;
+        assert_eq!(
+            murk_field_hash_get(world_h, 99, &mut untouched),
+            MurkStatus::InvalidArgument as i32
+        );
+        assert_eq!(untouched, 7);
+        assert_eq!(
+            murk_field_hash_get(world_h, 0, std::ptr::null_mut()),
+            MurkStatus::InvalidArgument as i32
+        );
+
+        murk_lockstep_destroy(world_h);
+        assert_eq!(
+            murk_field_hash_get(world_h, 0, &mut untouched),
+            MurkStatus::InvalidHandle as i32
+        );
+    }
+
     #[test]
     fn accessor_get_variants_detect_invalid_handle() {
         let world_h = create_test_world();
@@ -1259,6 +1407,20 @@ mod tests {
         );
         assert_eq!(seed, 999, "out must not be written on error");
 
+        let mut generation: u64 = 999;
+        assert_eq!(
+            murk_world_generation_get(world_h, &mut generation),
+            MurkStatus::InvalidHandle as i32
+        );
+        assert_eq!(generation, 999, "out must not be written on error");
+
+        let mut param_version: u64 = 999;
+        assert_eq!(
+            murk_world_param_version_get(world_h, &mut param_version),
+            MurkStatus::InvalidHandle as i32
+        );
+        assert_eq!(param_version, 999, "out must not be written on error");
+
         let mut preflight = MurkWorldPreflight {
             ingress_queue_depth: 99,
             ingress_queue_capacity: 99,
@@ -1436,6 +1598,14 @@ mod tests {
             murk_seed_get(world_h, std::ptr::null_mut()),
             MurkStatus::InvalidArgument as i32
         );
+        assert_eq!(
+            murk_world_generation_get(world_h, std::ptr::null_mut()),
+            MurkStatus::InvalidArgument as i32
+        );
+        assert_eq!(
+            murk_world_param_version_get(world_h, std::ptr::null_mut()),
+            MurkStatus::InvalidArgument as i32
+        );
         assert_eq!(
             murk_world_preflight_get(world_h, std::ptr::null_mut()),
             MurkStatus::InvalidArgument as i32
@@ -12,7 +12,7 @@
 //! canonical ordering), not on per-tick state.
 
 use murk_core::error::ObsError;
-use murk_core::{Coord, SnapshotAccess, SpaceInstanceId, TickId};
+use murk_core::{Coord, FieldDef, SnapshotAccess, SpaceInstanceId, TickId};
 use murk_space::Space;
 
 use crate::metadata::ObsMetadata;
@@ -50,6 +50,8 @@ use crate::ObsPlan;
 #[derive(Debug)]
 pub struct ObsPlanCache {
     spec: ObsSpec,
+    /// Field definitions for resolving `NormalizeToBounds`, if supplied.
+    fields: Option<Vec<FieldDef>>,
     cached: Option<CachedPlan>,
 }
 
@@ -88,7 +90,23 @@ impl ObsPlanCache {
     /// The plan is not compiled until the first call to
     /// [`execute`](Self::execute) or [`get_or_compile`](Self::get_or_compile).
     pub fn new(spec: ObsSpec) -> Self {
-        Self { spec, cached: None }
+        Self {
+            spec,
+            fields: None,
+            cached: None,
+        }
+    }
+
+    /// Create a new cache that compiles with the world's field definitions.
+    ///
+    /// Plans are built with [`ObsPlan::compile_with_fields`], so
+    /// `ObsTransform::NormalizeToBounds` entries resolve against `fields`.
+    pub fn with_fields(spec: ObsSpec, fields: Vec<FieldDef>) -> Self {
+        Self {
+            spec,
+            fields: Some(fields),
+            cached: None,
+        }
     }
 
     /// Get the cached plan, recompiling if needed.
@@ -105,7 +123,10 @@ impl ObsPlanCache {
         };
 
         if needs_recompile {
-            let result = ObsPlan::compile(&self.spec, space)?;
+            let result = match &self.fields {
+                Some(fields) => ObsPlan::compile_with_fields(&self.spec, space, fields)?,
+                None => ObsPlan::compile(&self.spec, space)?,
+            };
             self.cached = Some(CachedPlan {
                 plan: result.plan,
                 fingerprint,
@@ -205,7 +226,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         }
     }
 
@@ -419,6 +442,33 @@ mod tests {
         assert!(cache.is_compiled());
     }
 
+    #[test]
+    fn with_fields_resolves_normalize_to_bounds() {
+        let space = space();
+        let mut spec = spec();
+        spec.entries[0].transform = ObsTransform::NormalizeToBounds;
+        let heat = FieldDef {
+            name: "heat".into(),
+            field_type: murk_core::FieldType::Scalar,
+            mutability: murk_core::FieldMutability::PerTick,
+            units: None,
+            bounds: Some((0.0, 4.0).into()),
+            boundary_behavior: murk_core::BoundaryBehavior::Clamp,
+        };
+
+        assert!(ObsPlanCache::new(spec.clone())
+            .get_or_compile(&space)
+            .is_err());
+
+        let mut cache = ObsPlanCache::with_fields(spec, vec![heat]);
+        let mut output = vec![0.0f32; 9];
+        let mut mask = vec![0u8; 9];
+        cache
+            .execute(&space, &snap(1, 10), None, &mut output, &mut mask)
+            .unwrap();
+        assert!(output.iter().all(|&v| v == 0.25));
+    }
+
     // ── SpaceFingerprint tests ───────────────────────────────
 
     #[test]
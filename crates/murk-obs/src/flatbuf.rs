@@ -21,13 +21,16 @@
 //! [1 byte]  transform_type
 //! [8 bytes] normalize_min (LE f64, if Normalize)
 //! [8 bytes] normalize_max (LE f64, if Normalize)
+//!            (NormalizeToBounds carries no parameters)
 //! [1 byte]  dtype
 //! [1 byte]  pool_kernel (0=None)
 //! [4 bytes] pool_kernel_size (LE u32, if pool_kernel != 0)
 //! [4 bytes] pool_stride (LE u32, if pool_kernel != 0)
 //! ```
 
-use crate::spec::{ObsDtype, ObsEntry, ObsRegion, ObsSpec, ObsTransform, PoolConfig, PoolKernel};
+use crate::spec::{
+    DiskMetric, ObsDtype, ObsEntry, ObsRegion, ObsSpec, ObsTransform, PoolConfig, PoolKernel,
+};
 use murk_core::error::ObsError;
 use murk_core::FieldId;
 use murk_space::RegionSpec;
@@ -44,10 +47,19 @@ const REGION_NEIGHBOURS: u8 = 3;
 const REGION_COORDS: u8 = 4;
 const REGION_AGENT_DISK: u8 = 5;
 const REGION_AGENT_RECT: u8 = 6;
+const REGION_POSITION_CHANNEL: u8 = 7;
+const REGION_AGENT_DISK_REAL: u8 = 8;
+
+// AgentDisk metric tags (optional second region param)
+const METRIC_NATIVE: i32 = 0;
+const METRIC_EUCLIDEAN: i32 = 1;
+const METRIC_MANHATTAN: i32 = 2;
+const METRIC_CHEBYSHEV: i32 = 3;
 
 // Transform type tags
 const TRANSFORM_IDENTITY: u8 = 0;
 const TRANSFORM_NORMALIZE: u8 = 1;
+const TRANSFORM_NORMALIZE_TO_BOUNDS: u8 = 2;
 
 // Pool kernel tags
 const POOL_NONE: u8 = 0;
@@ -59,8 +71,19 @@ const POOL_SUM: u8 = 4;
 /// Serialize an [`ObsSpec`] to binary bytes.
 ///
 /// Returns `Err` if any value exceeds its wire-format range
-/// (e.g. more than `u16::MAX` entries).
+/// (e.g. more than `u16::MAX` entries), or if the spec uses a feature
+/// version 1 cannot carry (a non-zero `tick_offset` or `pad_value`).
 pub fn serialize(spec: &ObsSpec) -> Result<Vec<u8>, ObsError> {
+    // Version 1 has no pad_value slot either; refuse rather than drop it.
+    if spec.pad_value.to_bits() != 0 {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!(
+                "pad_value {} cannot be serialized in format version {VERSION}",
+                spec.pad_value
+            ),
+        });
+    }
+
     let mut buf = Vec::with_capacity(128);
 
     // Header
@@ -83,6 +106,19 @@ pub fn serialize(spec: &ObsSpec) -> Result<Vec<u8>, ObsError> {
 }
 
 fn write_entry(buf: &mut Vec<u8>, entry: &ObsEntry) -> Result<(), ObsError> {
+    // Version 1 has no tick_offset slot. Dropping the field would make the
+    // deserialized entry read the current tick instead of a past one,
+    // silently changing the observation, so refuse instead.
+    if entry.tick_offset != 0 {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!(
+                "tick_offset {} cannot be serialized in format version {VERSION}, \
+                 which has no slot for it and would deserialize as offset 0",
+                entry.tick_offset
+            ),
+        });
+    }
+
     buf.extend_from_slice(&entry.field_id.0.to_le_bytes());
 
     // Region
@@ -109,6 +145,9 @@ fn write_entry(buf: &mut Vec<u8>, entry: &ObsEntry) -> Result<(), ObsError> {
             buf.extend_from_slice(&min.to_le_bytes());
             buf.extend_from_slice(&max.to_le_bytes());
         }
+        ObsTransform::NormalizeToBounds => {
+            buf.push(TRANSFORM_NORMALIZE_TO_BOUNDS);
+        }
     }
 
     // Dtype
@@ -174,13 +213,32 @@ fn encode_region(region: &ObsRegion) -> Result<(u8, Vec<i32>), ObsError> {
             }
             Ok((REGION_COORDS, params))
         }
-        ObsRegion::AgentDisk { radius } => {
+        ObsRegion::Fixed(RegionSpec::Product(_)) => Err(ObsError::InvalidObsSpec {
+            reason: "Product regions are not representable in the binary format".into(),
+        }),
+        // Version 1 has no occlusion slot; refuse rather than drop it.
+        ObsRegion::AgentDisk {
+            occlusion: Some(_), ..
+        }
+        | ObsRegion::AgentDiskReal {
+            occlusion: Some(_), ..
+        }
+        | ObsRegion::AgentRect {
+            occlusion: Some(_), ..
+        } => Err(ObsError::InvalidObsSpec {
+            reason: format!("occluded regions cannot be serialized in format version {VERSION}"),
+        }),
+        ObsRegion::AgentDisk { radius, metric, .. } => {
             let r = i32::try_from(*radius).map_err(|_| ObsError::InvalidObsSpec {
                 reason: format!("AgentDisk radius {radius} exceeds i32::MAX"),
             })?;
-            Ok((REGION_AGENT_DISK, vec![r]))
+            let mut params = vec![r];
+            if let Some(metric) = metric {
+                params.push(disk_metric_tag(*metric));
+            }
+            Ok((REGION_AGENT_DISK, params))
         }
-        ObsRegion::AgentRect { half_extent } => {
+        ObsRegion::AgentRect { half_extent, .. } => {
             let params: Vec<i32> = half_extent
                 .iter()
                 .map(|&h| {
@@ -191,6 +249,33 @@ fn encode_region(region: &ObsRegion) -> Result<(u8, Vec<i32>), ObsError> {
                 .collect::<Result<_, _>>()?;
             Ok((REGION_AGENT_RECT, params))
         }
+        ObsRegion::AgentFoveated { .. } => Err(ObsError::InvalidObsSpec {
+            reason: "AgentFoveated regions are not representable in the binary format".into(),
+        }),
+        ObsRegion::PositionChannel => Ok((REGION_POSITION_CHANNEL, vec![])),
+        // The radius travels as its raw f32 bits.
+        ObsRegion::AgentDiskReal { pixel_radius, .. } => {
+            Ok((REGION_AGENT_DISK_REAL, vec![pixel_radius.to_bits() as i32]))
+        }
+    }
+}
+
+fn disk_metric_tag(metric: DiskMetric) -> i32 {
+    match metric {
+        DiskMetric::Native => METRIC_NATIVE,
+        DiskMetric::Euclidean => METRIC_EUCLIDEAN,
+        DiskMetric::Manhattan => METRIC_MANHATTAN,
+        DiskMetric::Chebyshev => METRIC_CHEBYSHEV,
+    }
+}
+
+fn disk_metric_from_tag(tag: i32) -> Option<DiskMetric> {
+    match tag {
+        METRIC_NATIVE => Some(DiskMetric::Native),
+        METRIC_EUCLIDEAN => Some(DiskMetric::Euclidean),
+        METRIC_MANHATTAN => Some(DiskMetric::Manhattan),
+        METRIC_CHEBYSHEV => Some(DiskMetric::Chebyshev),
+        _ => None,
     }
 }
 
@@ -234,7 +319,10 @@ pub fn deserialize(bytes: &[u8]) -> Result<ObsSpec, ObsError> {
         });
     }
 
-    Ok(ObsSpec { entries })
+    Ok(ObsSpec {
+        entries,
+        pad_value: 0.0,
+    })
 }
 
 fn read_entry(r: &mut Reader<'_>, idx: usize) -> Result<ObsEntry, ObsError> {
@@ -258,6 +346,7 @@ fn read_entry(r: &mut Reader<'_>, idx: usize) -> Result<ObsEntry, ObsError> {
             let max = r.read_f64().map_err(|e| truncated(idx, &e))?;
             ObsTransform::Normalize { min, max }
         }
+        TRANSFORM_NORMALIZE_TO_BOUNDS => ObsTransform::NormalizeToBounds,
         other => {
             return Err(ObsError::InvalidObsSpec {
                 reason: format!("entry {idx}: unknown transform type {other}"),
@@ -312,6 +401,7 @@ fn read_entry(r: &mut Reader<'_>, idx: usize) -> Result<ObsEntry, ObsError> {
         pool,
         transform,
         dtype,
+        tick_offset: 0,
     })
 }
 
@@ -391,15 +481,27 @@ fn decode_region(tag: u8, params: &[i32], idx: usize) -> Result<ObsRegion, ObsEr
             Ok(ObsRegion::Fixed(RegionSpec::Coords(coords)))
         }
         REGION_AGENT_DISK => {
-            if params.len() != 1 {
+            if params.is_empty() || params.len() > 2 {
                 return Err(ObsError::InvalidObsSpec {
-                    reason: format!("entry {idx}: AgentDisk needs exactly 1 param (radius)"),
+                    reason: format!("entry {idx}: AgentDisk needs 1 or 2 params (radius, metric)"),
                 });
             }
             let radius = u32::try_from(params[0]).map_err(|_| ObsError::InvalidObsSpec {
                 reason: format!("entry {idx}: negative AgentDisk radius {}", params[0]),
             })?;
-            Ok(ObsRegion::AgentDisk { radius })
+            let metric = params
+                .get(1)
+                .map(|&tag| {
+                    disk_metric_from_tag(tag).ok_or_else(|| ObsError::InvalidObsSpec {
+                        reason: format!("entry {idx}: unknown AgentDisk metric {tag}"),
+                    })
+                })
+                .transpose()?;
+            Ok(ObsRegion::AgentDisk {
+                radius,
+                metric,
+                occlusion: None,
+            })
         }
         REGION_AGENT_RECT => {
             if params.is_empty() {
@@ -415,7 +517,29 @@ fn decode_region(tag: u8, params: &[i32], idx: usize) -> Result<ObsRegion, ObsEr
                     })
                 })
                 .collect::<Result<_, _>>()?;
-            Ok(ObsRegion::AgentRect { half_extent })
+            Ok(ObsRegion::AgentRect {
+                half_extent,
+                occlusion: None,
+            })
+        }
+        REGION_POSITION_CHANNEL => {
+            if !params.is_empty() {
+                return Err(ObsError::InvalidObsSpec {
+                    reason: format!("entry {idx}: PositionChannel takes no params"),
+                });
+            }
+            Ok(ObsRegion::PositionChannel)
+        }
+        REGION_AGENT_DISK_REAL => {
+            let &[bits] = params else {
+                return Err(ObsError::InvalidObsSpec {
+                    reason: format!("entry {idx}: AgentDiskReal needs 1 param (pixel_radius)"),
+                });
+            };
+            Ok(ObsRegion::AgentDiskReal {
+                pixel_radius: f32::from_bits(bits as u32),
+                occlusion: None,
+            })
         }
         other => Err(ObsError::InvalidObsSpec {
             reason: format!("entry {idx}: unknown region type {other}"),
@@ -430,17 +554,19 @@ fn truncated(idx: usize, _inner: &ObsError) -> ObsError {
 }
 
 /// Simple cursor reader for safe byte parsing.
-struct Reader<'a> {
+/// Little-endian cursor over a byte slice. Running off the end is an
+/// [`ObsError::InvalidObsSpec`].
+pub(crate) struct Reader<'a> {
     data: &'a [u8],
     pos: usize,
 }
 
 impl<'a> Reader<'a> {
-    fn new(data: &'a [u8]) -> Self {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
         Self { data, pos: 0 }
     }
 
-    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], ObsError> {
+    pub(crate) fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], ObsError> {
         if self.pos + n > self.data.len() {
             return Err(ObsError::InvalidObsSpec {
                 reason: "unexpected end of data".into(),
@@ -451,36 +577,49 @@ impl<'a> Reader<'a> {
         Ok(slice)
     }
 
-    fn read_u8(&mut self) -> Result<u8, ObsError> {
+    pub(crate) fn read_u8(&mut self) -> Result<u8, ObsError> {
         Ok(self.read_bytes(1)?[0])
     }
 
-    fn read_u16(&mut self) -> Result<u16, ObsError> {
+    pub(crate) fn read_u16(&mut self) -> Result<u16, ObsError> {
         let b = self.read_bytes(2)?;
         Ok(u16::from_le_bytes([b[0], b[1]]))
     }
 
-    fn read_u32(&mut self) -> Result<u32, ObsError> {
+    pub(crate) fn read_u32(&mut self) -> Result<u32, ObsError> {
         let b = self.read_bytes(4)?;
         Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
     }
 
-    fn read_i32(&mut self) -> Result<i32, ObsError> {
+    pub(crate) fn read_i32(&mut self) -> Result<i32, ObsError> {
         let b = self.read_bytes(4)?;
         Ok(i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
     }
 
-    fn read_f64(&mut self) -> Result<f64, ObsError> {
+    pub(crate) fn read_u64(&mut self) -> Result<u64, ObsError> {
+        let b = self.read_bytes(8)?;
+        Ok(u64::from_le_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7],
+        ]))
+    }
+
+    pub(crate) fn read_f64(&mut self) -> Result<f64, ObsError> {
         let b = self.read_bytes(8)?;
         Ok(f64::from_le_bytes([
             b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7],
         ]))
     }
+
+    /// Bytes left to read.
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::spec::Occlusion;
     use smallvec::smallvec;
 
     fn round_trip(spec: &ObsSpec) -> ObsSpec {
@@ -497,7 +636,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -514,7 +655,25 @@ mod tests {
                     max: 5.0,
                 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
+        };
+        assert_eq!(round_trip(&spec), spec);
+    }
+
+    #[test]
+    fn round_trip_normalize_to_bounds() {
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(2),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform: ObsTransform::NormalizeToBounds,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -531,7 +690,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -548,7 +709,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -565,7 +728,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -583,7 +748,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -593,15 +760,48 @@ mod tests {
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
-                region: ObsRegion::AgentDisk { radius: 3 },
+                region: ObsRegion::AgentDisk {
+                    radius: 3,
+                    metric: None,
+                    occlusion: None,
+                },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
 
+    #[test]
+    fn round_trip_agent_disk_metric() {
+        for metric in [
+            DiskMetric::Native,
+            DiskMetric::Euclidean,
+            DiskMetric::Manhattan,
+            DiskMetric::Chebyshev,
+        ] {
+            let spec = ObsSpec {
+                entries: vec![ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::AgentDisk {
+                        radius: 2,
+                        metric: Some(metric),
+                        occlusion: None,
+                    },
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                }],
+                pad_value: 0.0,
+            };
+            assert_eq!(round_trip(&spec), spec);
+        }
+    }
+
     #[test]
     fn round_trip_agent_rect() {
         let spec = ObsSpec {
@@ -609,11 +809,49 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec![3, 4],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        assert_eq!(round_trip(&spec), spec);
+    }
+
+    #[test]
+    fn round_trip_position_channel() {
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::PositionChannel,
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
+        };
+        assert_eq!(round_trip(&spec), spec);
+    }
+
+    #[test]
+    fn round_trip_agent_disk_real() {
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(1),
+                region: ObsRegion::AgentDiskReal {
+                    pixel_radius: 2.75,
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -623,7 +861,11 @@ mod tests {
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(2),
-                region: ObsRegion::AgentDisk { radius: 5 },
+                region: ObsRegion::AgentDisk {
+                    radius: 5,
+                    metric: None,
+                    occlusion: None,
+                },
                 pool: Some(PoolConfig {
                     kernel: PoolKernel::Mean,
                     kernel_size: 2,
@@ -634,7 +876,9 @@ mod tests {
                     max: 100.0,
                 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -649,10 +893,15 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
                 ObsEntry {
                     field_id: FieldId(1),
-                    region: ObsRegion::AgentDisk { radius: 3 },
+                    region: ObsRegion::AgentDisk {
+                        radius: 3,
+                        metric: None,
+                        occlusion: None,
+                    },
                     pool: Some(PoolConfig {
                         kernel: PoolKernel::Max,
                         kernel_size: 3,
@@ -663,8 +912,10 @@ mod tests {
                         max: 5.0,
                     },
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
             ],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
@@ -688,7 +939,9 @@ mod tests {
                     }),
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 }],
+                pad_value: 0.0,
             };
             assert_eq!(round_trip(&spec), spec, "failed for kernel {kernel:?}");
         }
@@ -703,7 +956,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let mut bytes = serialize(&spec).unwrap();
         // Set version to 99
@@ -730,9 +985,13 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             })
             .collect();
-        let spec = ObsSpec { entries };
+        let spec = ObsSpec {
+            entries,
+            pad_value: 0.0,
+        };
         // After fix, serialize returns Result and this must be Err.
         // Currently this silently truncates — the bug we're fixing.
         assert!(serialize(&spec).is_err());
@@ -747,7 +1006,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let mut bytes = serialize(&spec).unwrap();
         // Append garbage trailing byte.
@@ -776,7 +1037,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert!(serialize(&spec).is_err());
     }
@@ -795,7 +1058,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let mut bytes = serialize(&spec).unwrap();
         // The Disk region params are: [center0, center1, radius] as i32 LE.
@@ -819,11 +1084,15 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentDisk {
                     radius: i32::MAX as u32 + 1,
+                    metric: None,
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert!(serialize(&spec).is_err());
     }
@@ -835,11 +1104,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec![u32::MAX],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert!(serialize(&spec).is_err());
     }
@@ -863,8 +1135,57 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert_eq!(round_trip(&spec), spec);
     }
+
+    #[test]
+    fn nonzero_tick_offset_rejected() {
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 1,
+            }],
+            pad_value: 0.0,
+        };
+        assert!(serialize(&spec).is_err());
+    }
+
+    #[test]
+    fn nonzero_pad_value_rejected() {
+        let spec = ObsSpec {
+            entries: vec![],
+            pad_value: -1.0,
+        };
+        assert!(serialize(&spec).is_err());
+    }
+
+    #[test]
+    fn occluded_region_rejected() {
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec![2, 2],
+                    occlusion: Some(Occlusion {
+                        field_id: FieldId(1),
+                        threshold: 0.5,
+                    }),
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        assert!(serialize(&spec).is_err());
+    }
 }
@@ -6,7 +6,8 @@
 //! agent-centered foveation.
 
 use murk_core::error::ObsError;
-use murk_space::Space;
+use murk_core::Coord;
+use murk_space::{MemoryOrder, Space};
 
 /// Grid connectivity type, determines graph-distance metric.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -42,6 +43,14 @@ pub struct GridGeometry {
     pub connectivity: GridConnectivity,
 }
 
+/// Per-axis strides of a `[row, col]` grid under the given memory order.
+fn grid_strides(rows: u32, cols: u32, order: MemoryOrder) -> Vec<usize> {
+    match order {
+        MemoryOrder::RowMajor => vec![cols as usize, 1],
+        MemoryOrder::ColumnMajor => vec![1, rows as usize],
+    }
+}
+
 impl GridGeometry {
     /// Try to extract grid geometry from a `&dyn Space` via downcast.
     ///
@@ -50,23 +59,24 @@ impl GridGeometry {
     /// or any unknown Space implementation.
     pub fn from_space(space: &dyn Space) -> Option<Self> {
         // Try Square4: coord = [row, col], rank = row * cols + col
+        // (or col * rows + row when column-major)
         if let Some(sq4) = space.downcast_ref::<murk_space::Square4>() {
-            let all_wrap = sq4.edge_behavior() == murk_space::EdgeBehavior::Wrap;
+            let all_wrap = sq4.edge_behaviors() == [murk_space::EdgeBehavior::Wrap; 2];
             return Some(GridGeometry {
                 coord_dims: vec![sq4.rows(), sq4.cols()],
-                coord_strides: vec![sq4.cols() as usize, 1],
+                coord_strides: grid_strides(sq4.rows(), sq4.cols(), sq4.memory_order()),
                 ndim: 2,
                 all_wrap,
                 connectivity: GridConnectivity::FourWay,
             });
         }
 
-        // Try Square8: coord = [row, col], rank = row * cols + col
+        // Try Square8: same layout as Square4
         if let Some(sq8) = space.downcast_ref::<murk_space::Square8>() {
-            let all_wrap = sq8.edge_behavior() == murk_space::EdgeBehavior::Wrap;
+            let all_wrap = sq8.edge_behaviors() == [murk_space::EdgeBehavior::Wrap; 2];
             return Some(GridGeometry {
                 coord_dims: vec![sq8.rows(), sq8.cols()],
-                coord_strides: vec![sq8.cols() as usize, 1],
+                coord_strides: grid_strides(sq8.rows(), sq8.cols(), sq8.memory_order()),
                 ndim: 2,
                 all_wrap,
                 connectivity: GridConnectivity::EightWay,
@@ -145,6 +155,104 @@ impl GridGeometry {
         true
     }
 
+    /// Squared Euclidean distance from the origin to a relative coordinate
+    /// in pixel space, with adjacent cell centres one unit apart.
+    ///
+    /// - `FourWay`/`EightWay`: the coordinate offsets themselves, `Σ d²`
+    /// - `Hex`: the pointy-top layout of `Hex2D::to_pixel`,
+    ///   `dq² + dq·dr + dr²` (axial coords)
+    ///
+    /// Both are integers, so comparisons against a squared radius are exact.
+    pub fn pixel_distance_sq(&self, relative: &[i32]) -> f64 {
+        match self.connectivity {
+            GridConnectivity::FourWay | GridConnectivity::EightWay => {
+                relative.iter().map(|&d| (d as f64) * (d as f64)).sum()
+            }
+            GridConnectivity::Hex => {
+                let dq = relative[0] as f64;
+                let dr = relative[1] as f64;
+                dq * dq + dq * dr + dr * dr
+            }
+        }
+    }
+
+    /// Largest per-axis coordinate offset of any cell within pixel
+    /// distance `radius` of the origin (the half-extent of the bounding
+    /// box of a pixel-space disk).
+    ///
+    /// On a hex grid an axial offset of `d` is at least `d·√3/2` away, so
+    /// the reach is `⌊2·radius/√3⌋`; on square grids it is `⌊radius⌋`.
+    pub fn pixel_reach(&self, radius: f64) -> u32 {
+        let limit = radius * radius;
+        let mut reach = match self.connectivity {
+            GridConnectivity::FourWay | GridConnectivity::EightWay => radius.floor(),
+            GridConnectivity::Hex => (2.0 * radius / 3f64.sqrt()).floor(),
+        } as u32;
+        // Undo any rounding across an exact boundary.
+        let axis_min = |d: f64| match self.connectivity {
+            GridConnectivity::Hex => 0.75 * d * d,
+            _ => d * d,
+        };
+        while axis_min(reach as f64 + 1.0) <= limit {
+            reach += 1;
+        }
+        while reach > 0 && axis_min(reach as f64) > limit {
+            reach -= 1;
+        }
+        reach
+    }
+
+    /// Cells strictly between the origin and a relative coordinate on the
+    /// straight segment joining their centres, nearest first.
+    ///
+    /// - `FourWay`/`EightWay`: one cell per step of `max |d|`, each axis
+    ///   rounded independently (ties round away from the origin)
+    /// - `Hex`: one cell per step of cube distance, interpolated in cube
+    ///   coordinates and cube-rounded (axial coords)
+    ///
+    /// Adjacent cells (and the origin itself) have nothing between them.
+    pub fn sight_line(&self, relative: &[i32]) -> Vec<Coord> {
+        match self.connectivity {
+            GridConnectivity::FourWay | GridConnectivity::EightWay => {
+                let steps = relative.iter().map(|d| d.unsigned_abs()).max().unwrap_or(0);
+                (1..steps)
+                    .map(|k| {
+                        let t = k as f64 / steps as f64;
+                        relative
+                            .iter()
+                            .map(|&d| (d as f64 * t).round() as i32)
+                            .collect()
+                    })
+                    .collect()
+            }
+            GridConnectivity::Hex => {
+                let (dq, dr) = (relative[0], relative[1]);
+                let steps = dq
+                    .unsigned_abs()
+                    .max(dr.unsigned_abs())
+                    .max((dq + dr).unsigned_abs());
+                // A tiny nudge keeps segments along cell edges from
+                // rounding to different sides at different steps.
+                let (q1, r1) = (dq as f64 + 1e-6, dr as f64 + 2e-6);
+                (1..steps)
+                    .map(|k| {
+                        let t = k as f64 / steps as f64;
+                        let (q, r) = (q1 * t, r1 * t);
+                        let s = -q - r;
+                        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+                        let (eq, er, es) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+                        if eq > er && eq > es {
+                            rq = -rr - rs;
+                        } else if er > es {
+                            rr = -rq - rs;
+                        }
+                        Coord::from_slice(&[rq as i32, rr as i32])
+                    })
+                    .collect()
+            }
+        }
+    }
+
     /// Compute the graph distance from origin for a relative coordinate.
     ///
     /// This uses the distance metric appropriate for the grid connectivity:
@@ -196,6 +304,21 @@ mod tests {
         assert!(!geo.all_wrap);
     }
 
+    #[test]
+    fn extract_square4_column_major() {
+        let s = Square4::new(10, 8, EdgeBehavior::Absorb)
+            .unwrap()
+            .with_memory_order(MemoryOrder::ColumnMajor);
+        let geo = GridGeometry::from_space(&s).unwrap();
+        assert_eq!(geo.coord_dims, vec![10, 8]);
+        assert_eq!(geo.coord_strides, vec![1, 10]);
+        let coord: murk_core::Coord = smallvec::smallvec![3, 5];
+        assert_eq!(
+            geo.canonical_rank(&coord),
+            s.canonical_rank(&coord).unwrap()
+        );
+    }
+
     #[test]
     fn extract_square4_wrap() {
         let s = Square4::new(5, 5, EdgeBehavior::Wrap).unwrap();
@@ -327,6 +450,27 @@ mod tests {
         assert_eq!(geo.graph_distance(&[5, -3]).unwrap(), 5);
     }
 
+    #[test]
+    fn sight_line_excludes_endpoints() {
+        let sq =
+            GridGeometry::from_space(&Square4::new(10, 10, EdgeBehavior::Absorb).unwrap()).unwrap();
+        assert_eq!(
+            sq.sight_line(&[0, 3]),
+            vec![Coord::from_slice(&[0, 1]), Coord::from_slice(&[0, 2])]
+        );
+        assert_eq!(sq.sight_line(&[-2, -2]), vec![Coord::from_slice(&[-1, -1])]);
+        assert!(sq.sight_line(&[1, 1]).is_empty());
+        assert!(sq.sight_line(&[0, 0]).is_empty());
+
+        let hex = GridGeometry::from_space(&Hex2D::new(10, 10).unwrap()).unwrap();
+        assert_eq!(hex.sight_line(&[2, 0]), vec![Coord::from_slice(&[1, 0])]);
+        assert_eq!(
+            hex.sight_line(&[3, -3]),
+            vec![Coord::from_slice(&[1, -1]), Coord::from_slice(&[2, -2])]
+        );
+        assert!(hex.sight_line(&[1, -1]).is_empty());
+    }
+
     #[test]
     fn graph_distance_hex_cube() {
         let s = Hex2D::new(10, 10).unwrap();
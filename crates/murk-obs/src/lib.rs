@@ -26,11 +26,16 @@ pub mod cache;
 pub mod flatbuf;
 pub mod geometry;
 pub mod metadata;
+pub mod normalize;
 pub mod plan;
 pub mod pool;
 pub mod spec;
 
 pub use cache::ObsPlanCache;
 pub use metadata::ObsMetadata;
-pub use plan::{ObsPlan, ObsPlanResult};
-pub use spec::{ObsDtype, ObsEntry, ObsRegion, ObsSpec, ObsTransform, PoolConfig, PoolKernel};
+pub use normalize::ObsNormalizer;
+pub use plan::{AgentOrder, ObsPlan, ObsPlanResult};
+pub use spec::{
+    DiskMetric, ObsDtype, ObsEntry, ObsRegion, ObsSpec, ObsTransform, Occlusion, PoolConfig,
+    PoolKernel,
+};
@@ -5,7 +5,7 @@ use murk_core::{ParameterVersion, TickId, WorldGenerationId};
 /// Metadata accompanying an observation extraction.
 ///
 /// Populated by [`ObsPlan::execute`](crate::ObsPlan::execute) from the
-/// snapshot being observed. All five fields are guaranteed to be set.
+/// snapshot being observed. All fields except `agent_id` are always set.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ObsMetadata {
     /// Tick at which the observed snapshot was produced.
@@ -17,8 +17,23 @@ pub struct ObsMetadata {
     /// Fraction of the observation tensor filled with valid data
     /// (i.e., `valid_ratio` from the region plan).
     pub coverage: f64,
+    /// Number of valid (mask = 1) elements in the observation.
+    ///
+    /// For [`ObsPlan::execute_agents`](crate::ObsPlan::execute_agents) this
+    /// is per agent, so boundary agents report fewer valid cells than
+    /// interior ones without callers re-summing the mask.
+    pub valid_count: usize,
     /// Arena generation of the observed snapshot.
     pub world_generation_id: WorldGenerationId,
     /// Parameter version at the time of the snapshot.
     pub parameter_version: ParameterVersion,
+    /// Id of the agent this row belongs to. Set only by
+    /// [`ObsPlan::execute_agents_with_ids`](crate::ObsPlan::execute_agents_with_ids).
+    pub agent_id: Option<u16>,
+    /// `true` if this row was skipped because the snapshot's generation
+    /// did not match the plan. Set only by
+    /// [`ObsPlan::execute_batch_lenient`](crate::ObsPlan::execute_batch_lenient);
+    /// the row's output is filled with the spec's `pad_value` and its mask
+    /// is zeroed.
+    pub stale: bool,
 }
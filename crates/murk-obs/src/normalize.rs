@@ -0,0 +1,240 @@
+//! Running observation statistics for online normalization.
+//!
+//! [`ObsNormalizer`] keeps a per-element running mean and variance over
+//! the observation tensors it is fed, in the style of `VecNormalize`.
+//! It is independent of the compile-time
+//! [`ObsTransform::Normalize`](crate::ObsTransform::Normalize), which
+//! rescales by fixed bounds inside the plan: the normalizer runs on the
+//! finished output buffer, after [`ObsPlan::execute`](crate::ObsPlan::execute).
+//!
+//! Statistics are accumulated in `f64` with Welford's algorithm, so the
+//! result depends only on the sequence of observations fed in.
+
+/// Default variance floor added before taking the square root.
+pub const DEFAULT_EPSILON: f64 = 1e-8;
+
+/// Per-element running mean/variance accumulator.
+///
+/// # Example
+///
+/// ```
+/// use murk_obs::ObsNormalizer;
+///
+/// let mut norm = ObsNormalizer::new(2);
+/// norm.update(&[1.0, 10.0]);
+/// norm.update(&[3.0, 30.0]);
+///
+/// let mut obs = [3.0, 30.0];
+/// norm.normalize(&mut obs);
+/// assert!((obs[0] - 1.0).abs() < 1e-4);
+/// assert!((obs[1] - 1.0).abs() < 1e-4);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObsNormalizer {
+    count: u64,
+    mean: Vec<f64>,
+    m2: Vec<f64>,
+    epsilon: f64,
+}
+
+impl ObsNormalizer {
+    /// Create an empty accumulator for observations of `len` elements.
+    pub fn new(len: usize) -> Self {
+        Self {
+            count: 0,
+            mean: vec![0.0; len],
+            m2: vec![0.0; len],
+            epsilon: DEFAULT_EPSILON,
+        }
+    }
+
+    /// Set the variance floor used by [`normalize`](Self::normalize)
+    /// (default [`DEFAULT_EPSILON`]).
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
+    }
+
+    /// Number of elements per observation.
+    pub fn len(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// Whether observations have zero elements.
+    pub fn is_empty(&self) -> bool {
+        self.mean.is_empty()
+    }
+
+    /// Number of observations accumulated so far.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Running mean of each element.
+    pub fn mean(&self) -> &[f64] {
+        &self.mean
+    }
+
+    /// Population variance of each element (all zeros before any update).
+    pub fn variance(&self) -> Vec<f64> {
+        if self.count == 0 {
+            return vec![0.0; self.m2.len()];
+        }
+        let n = self.count as f64;
+        self.m2.iter().map(|m2| m2 / n).collect()
+    }
+
+    /// Fold one observation into the running statistics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `obs.len()` differs from [`len`](Self::len).
+    pub fn update(&mut self, obs: &[f32]) {
+        assert_eq!(
+            obs.len(),
+            self.mean.len(),
+            "observation length {} does not match normalizer length {}",
+            obs.len(),
+            self.mean.len()
+        );
+        self.count += 1;
+        let n = self.count as f64;
+        for ((mean, m2), &x) in self.mean.iter_mut().zip(&mut self.m2).zip(obs) {
+            let x = x as f64;
+            let delta = x - *mean;
+            *mean += delta / n;
+            *m2 += delta * (x - *mean);
+        }
+    }
+
+    /// Rescale `obs` in place to `(x - mean) / sqrt(var + epsilon)`.
+    ///
+    /// Does nothing until at least one observation has been accumulated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `obs.len()` differs from [`len`](Self::len).
+    pub fn normalize(&self, obs: &mut [f32]) {
+        assert_eq!(
+            obs.len(),
+            self.mean.len(),
+            "observation length {} does not match normalizer length {}",
+            obs.len(),
+            self.mean.len()
+        );
+        if self.count == 0 {
+            return;
+        }
+        let n = self.count as f64;
+        for ((x, &mean), &m2) in obs.iter_mut().zip(&self.mean).zip(&self.m2) {
+            let std = (m2 / n + self.epsilon).sqrt();
+            *x = ((*x as f64 - mean) / std) as f32;
+        }
+    }
+
+    /// Discard all accumulated statistics.
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.mean.fill(0.0);
+        self.m2.fill(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo-random sequence (xorshift) for test inputs.
+    fn samples(n: usize, len: usize) -> Vec<Vec<f32>> {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        (0..n)
+            .map(|_| {
+                (0..len)
+                    .map(|i| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        let u = (state >> 11) as f64 / (1u64 << 53) as f64;
+                        (u * 10.0 + i as f64 * 5.0) as f32
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn constant_input_has_constant_mean_and_zero_variance() {
+        let mut norm = ObsNormalizer::new(3);
+        for _ in 0..50 {
+            norm.update(&[2.5, -1.0, 0.0]);
+        }
+        assert_eq!(norm.count(), 50);
+        assert_eq!(norm.mean(), &[2.5, -1.0, 0.0]);
+        assert_eq!(norm.variance(), vec![0.0; 3]);
+
+        let mut obs = [2.5, -1.0, 0.0];
+        norm.normalize(&mut obs);
+        assert_eq!(obs, [0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn normalized_output_has_zero_mean_unit_variance() {
+        let data = samples(500, 4);
+        let mut norm = ObsNormalizer::new(4);
+        for obs in &data {
+            norm.update(obs);
+        }
+
+        let mut sum = [0.0f64; 4];
+        let mut sum_sq = [0.0f64; 4];
+        for obs in &data {
+            let mut out = obs.clone();
+            norm.normalize(&mut out);
+            for (i, &v) in out.iter().enumerate() {
+                sum[i] += v as f64;
+                sum_sq[i] += (v as f64) * (v as f64);
+            }
+        }
+        let n = data.len() as f64;
+        for i in 0..4 {
+            let mean = sum[i] / n;
+            let var = sum_sq[i] / n - mean * mean;
+            assert!(mean.abs() < 1e-4, "element {i}: mean {mean}");
+            assert!((var - 1.0).abs() < 1e-3, "element {i}: var {var}");
+        }
+    }
+
+    #[test]
+    fn same_sequence_gives_identical_statistics() {
+        let data = samples(100, 5);
+        let mut a = ObsNormalizer::new(5);
+        let mut b = ObsNormalizer::new(5);
+        for obs in &data {
+            a.update(obs);
+            b.update(obs);
+        }
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn normalize_is_identity_before_any_update() {
+        let norm = ObsNormalizer::new(2);
+        let mut obs = [3.0, -4.0];
+        norm.normalize(&mut obs);
+        assert_eq!(obs, [3.0, -4.0]);
+    }
+
+    #[test]
+    fn reset_clears_statistics() {
+        let mut norm = ObsNormalizer::new(2);
+        norm.update(&[1.0, 2.0]);
+        norm.reset();
+        assert_eq!(norm, ObsNormalizer::new(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match normalizer length")]
+    fn update_rejects_wrong_length() {
+        ObsNormalizer::new(3).update(&[1.0, 2.0]);
+    }
+}
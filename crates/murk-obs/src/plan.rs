@@ -7,16 +7,24 @@
 //! `(field_data_index, tensor_index)` pairs, read the field value,
 //! optionally transform it, and write to the caller-allocated buffer.
 
+use std::sync::{Arc, Mutex};
+
 use indexmap::IndexMap;
 
 use murk_core::error::ObsError;
-use murk_core::{Coord, FieldId, SnapshotAccess, TickId, WorldGenerationId};
-use murk_space::Space;
+use murk_core::{
+    scalar_to_f32, scalar_to_f64, Coord, FieldBounds, FieldDef, FieldId, Fnv1a, Scalar,
+    SnapshotAccess, TickId, WorldGenerationId,
+};
+use murk_space::{RegionPlan, RegionPlanCache, RegionSpec, Space, SpaceError};
 
+use crate::flatbuf::Reader;
 use crate::geometry::GridGeometry;
 use crate::metadata::ObsMetadata;
 use crate::pool::pool_2d_into;
-use crate::spec::{ObsDtype, ObsRegion, ObsSpec, ObsTransform, PoolConfig};
+use crate::spec::{
+    DiskMetric, ObsDtype, ObsRegion, ObsSpec, ObsTransform, Occlusion, PoolConfig, PoolKernel,
+};
 
 /// Coverage threshold: warn if valid_ratio < this.
 const COVERAGE_WARN_THRESHOLD: f64 = 0.5;
@@ -33,10 +41,43 @@ pub struct ObsPlanResult {
     pub output_len: usize,
     /// Shape per entry (each entry's region bounding shape dimensions).
     pub entry_shapes: Vec<Vec<usize>>,
+    /// `(start, len)` of each entry's elements in the output tensor, in
+    /// spec order. Entries are contiguous and the lengths sum to
+    /// `output_len`; see [`ObsPlan::entry_slice`].
+    pub entry_offsets: Vec<(usize, usize)>,
+    /// Total size of the output tensor in bytes, summing each entry's
+    /// element count times its [`ObsDtype::size_bytes`].
+    pub output_bytes: usize,
+    /// `(start, len)` of each entry in bytes, in spec order. The byte
+    /// counterpart of `entry_offsets`; lengths sum to `output_bytes`.
+    pub entry_byte_offsets: Vec<(usize, usize)>,
     /// Length of the validity mask in bytes.
     pub mask_len: usize,
 }
 
+impl ObsPlanResult {
+    /// Output plus mask bytes needed to observe `batch` environments.
+    ///
+    /// Sizes one observation as [`output_bytes`](Self::output_bytes) plus
+    /// [`mask_len`](Self::mask_len). Standard plans produce one observation
+    /// per agent, so the total scales by `n_agents`; Simple plans produce
+    /// one per environment and ignore `n_agents`.
+    pub fn estimated_bytes(&self, n_agents: usize, batch: usize) -> usize {
+        let per_env = if self.plan.is_standard() { n_agents } else { 1 };
+        batch * per_env * (self.output_bytes + self.mask_len)
+    }
+}
+
+/// Row layout for [`ObsPlan::execute_agents_with_ids`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AgentOrder {
+    /// One row per agent in the order the centers were given.
+    #[default]
+    Input,
+    /// Rows sorted by ascending agent id, independent of input order.
+    SortedById,
+}
+
 /// Compiled observation plan: either Simple or Standard class.
 ///
 /// **Simple** (all `Fixed` regions): pre-computed gather indices, branch-free
@@ -51,8 +92,73 @@ pub struct ObsPlan {
     output_len: usize,
     /// Total mask bytes across all entries (per agent for Standard).
     mask_len: usize,
+    /// `(start, len)` per spec entry within one output of `output_len`.
+    entry_offsets: Vec<(usize, usize)>,
     /// Generation at compile time (for PLAN_INVALIDATED detection).
     compiled_generation: Option<WorldGenerationId>,
+    /// Digest of the compile-time space, checked by [`ObsPlan::deserialize`].
+    space_digest: u64,
+    /// Value written to masked-out output elements ([`ObsSpec::pad_value`]).
+    pad_value: f32,
+    /// Reusable execution buffers; see [`ExecScratch`].
+    scratch: ExecScratch,
+}
+
+/// Execution buffers sized at compile time and reused across calls.
+///
+/// Each buffer is taken with `try_lock`, so a plan executed from several
+/// threads at once falls back to a fresh allocation instead of waiting.
+#[derive(Debug)]
+struct ExecScratch {
+    /// Largest `pre_pool_mask` of any pooled Simple entry.
+    gathered_len: usize,
+    /// Pre-pool gather buffer for pooled Simple entries.
+    gathered: Mutex<Vec<f32>>,
+    /// Previous-snapshot output and mask for [`ObsPlan::execute_delta`].
+    delta: Mutex<(Vec<f32>, Vec<u8>)>,
+}
+
+impl ExecScratch {
+    fn new(strategy: &PlanStrategy, output_len: usize, mask_len: usize) -> Self {
+        let gathered_len = match strategy {
+            PlanStrategy::Simple(simple) => simple
+                .entries
+                .iter()
+                .map(|e| e.pre_pool_mask.len())
+                .max()
+                .unwrap_or(0),
+            PlanStrategy::Standard(_) => 0,
+        };
+        Self {
+            gathered_len,
+            gathered: Mutex::new(vec![0.0; gathered_len]),
+            delta: Mutex::new((vec![0.0; output_len], vec![0; mask_len])),
+        }
+    }
+
+    /// Run `f` with the gather buffer.
+    fn with_gathered<R>(&self, f: impl FnOnce(&mut [f32]) -> R) -> R {
+        match self.gathered.try_lock() {
+            Ok(mut buf) => f(&mut buf),
+            Err(_) => f(&mut vec![0.0; self.gathered_len]),
+        }
+    }
+
+    /// Run `f` with the delta buffers, `output_len` and `mask_len` long.
+    fn with_delta<R>(
+        &self,
+        output_len: usize,
+        mask_len: usize,
+        f: impl FnOnce(&mut [f32], &mut [u8]) -> R,
+    ) -> R {
+        match self.delta.try_lock() {
+            Ok(mut buf) => {
+                let (out, mask) = &mut *buf;
+                f(out, mask)
+            }
+            Err(_) => f(&mut vec![0.0; output_len], &mut vec![0; mask_len]),
+        }
+    }
 }
 
 /// Pre-computed gather instruction for a single cell.
@@ -74,6 +180,8 @@ struct CompiledEntry {
     transform: ObsTransform,
     #[allow(dead_code)]
     dtype: ObsDtype,
+    /// Ticks back into the snapshot history this entry reads from.
+    tick_offset: usize,
     /// Offset into the output buffer where this entry starts.
     output_offset: usize,
     /// Offset into the validity mask where this entry starts.
@@ -81,9 +189,16 @@ struct CompiledEntry {
     /// Number of elements this entry contributes to the output.
     element_count: usize,
     /// Pre-computed gather operations (one per valid cell in the region).
+    /// For pooled entries `tensor_idx` addresses the pre-pool bounding box.
     gather_ops: Vec<GatherOp>,
-    /// Pre-computed validity mask for this entry's bounding box.
+    /// Pre-computed validity mask for this entry's output (post-pool).
     valid_mask: Vec<u8>,
+    /// Optional pooling of the gathered bounding box (Simple plans only).
+    pool: Option<PoolConfig>,
+    /// Shape of the pre-pool bounding box. Empty if not pooled.
+    pre_pool_shape: Vec<usize>,
+    /// Validity mask of the pre-pool bounding box. Empty if not pooled.
+    pre_pool_mask: Vec<u8>,
     /// Number of valid cells in `valid_mask`.
     valid_count: usize,
     /// Valid ratio for this entry's region.
@@ -136,6 +251,37 @@ struct AgentCompiledEntry {
     active_ops: Vec<TemplateOp>,
     /// Radius for `is_interior` check.
     radius: u32,
+    /// Line-of-sight masking, if the region requested it.
+    occlusion: Option<OcclusionTemplate>,
+}
+
+/// Compiled [`Occlusion`]: the cells between the agent and each active op.
+#[derive(Debug)]
+struct OcclusionTemplate {
+    field_id: FieldId,
+    threshold: f32,
+    /// `lines[k]` lists the cells strictly between the agent and
+    /// `active_ops[k]`, checked per agent at execute time.
+    lines: Vec<Vec<SightCell>>,
+}
+
+/// One cell on a sight line, relative to the agent center.
+#[derive(Debug)]
+struct SightCell {
+    relative: Coord,
+    /// Precomputed stride offset for the interior fast path.
+    stride_offset: isize,
+}
+
+/// Compiled `ObsRegion::PositionChannel` entry: `ndim` normalized
+/// coordinates of the agent's center.
+#[derive(Debug)]
+struct PositionEntry {
+    transform: ObsTransform,
+    /// Offset into the per-agent output buffer.
+    output_offset: usize,
+    /// Offset into the per-agent mask buffer.
+    mask_offset: usize,
 }
 
 /// Data for the Standard plan class (agent-centered foveation + pooling).
@@ -145,6 +291,11 @@ struct StandardPlanData {
     fixed_entries: Vec<CompiledEntry>,
     /// Entries with agent-relative regions (resolved per-agent).
     agent_entries: Vec<AgentCompiledEntry>,
+    /// Position-channel entries (written per-agent, no gather).
+    position_entries: Vec<PositionEntry>,
+    /// `(min, max)` coordinate per axis over the space, for normalizing
+    /// position channels. Empty if there are no position entries.
+    axis_bounds: Vec<(i32, i32)>,
     /// Grid geometry for interior/boundary dispatch (`None` → all slow path).
     geometry: Option<GridGeometry>,
 }
@@ -153,7 +304,6 @@ struct StandardPlanData {
 #[derive(Debug)]
 struct SimplePlanData {
     entries: Vec<CompiledEntry>,
-    total_valid: usize,
     total_elements: usize,
 }
 
@@ -172,14 +322,70 @@ impl ObsPlan {
     /// Detects whether the spec contains agent-relative regions and
     /// dispatches to the appropriate plan class:
     /// - All `Fixed` → **Simple** (pre-computed gather)
-    /// - Any `AgentDisk`/`AgentRect` → **Standard** (template-based)
+    /// - Any `AgentDisk`/`AgentRect`/`AgentFoveated` → **Standard** (template-based)
     pub fn compile(spec: &ObsSpec, space: &dyn Space) -> Result<ObsPlanResult, ObsError> {
+        Self::compile_inner(spec, space, None, None)
+    }
+
+    /// Compile an [`ObsSpec`], resolving
+    /// [`ObsTransform::NormalizeToBounds`] from `fields`.
+    ///
+    /// `fields` is indexed by [`FieldId`], as in
+    /// `WorldConfig::fields`. Each `NormalizeToBounds` entry becomes
+    /// `Normalize` over its field's uniform bounds; otherwise identical to
+    /// [`compile`](Self::compile).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObsError::InvalidObsSpec`] if a `NormalizeToBounds` entry
+    /// names a field that is missing from `fields`, has no bounds, or has
+    /// per-component bounds that differ between components.
+    pub fn compile_with_fields(
+        spec: &ObsSpec,
+        space: &dyn Space,
+        fields: &[FieldDef],
+    ) -> Result<ObsPlanResult, ObsError> {
+        Self::compile_inner(spec, space, None, Some(fields))
+    }
+
+    /// Compile an [`ObsSpec`], sharing `Fixed` region plans through `cache`.
+    ///
+    /// Identical to [`compile`](Self::compile) except that each
+    /// `ObsRegion::Fixed` region is looked up in (or inserted into) the
+    /// [`RegionPlanCache`], so compiling the same spec for many environments
+    /// over identical spaces only compiles each region once.
+    pub fn compile_cached(
+        spec: &ObsSpec,
+        space: &dyn Space,
+        cache: &RegionPlanCache,
+    ) -> Result<ObsPlanResult, ObsError> {
+        Self::compile_inner(spec, space, Some(cache), None)
+    }
+
+    fn compile_inner(
+        spec: &ObsSpec,
+        space: &dyn Space,
+        cache: Option<&RegionPlanCache>,
+        fields: Option<&[FieldDef]>,
+    ) -> Result<ObsPlanResult, ObsError> {
         if spec.entries.is_empty() {
             return Err(ObsError::InvalidObsSpec {
                 reason: "ObsSpec has no entries".into(),
             });
         }
 
+        let resolved;
+        let spec = if spec
+            .entries
+            .iter()
+            .any(|e| e.transform == ObsTransform::NormalizeToBounds)
+        {
+            resolved = resolve_bounds(spec, fields)?;
+            &resolved
+        } else {
+            spec
+        };
+
         // Validate transform parameters.
         for (i, entry) in spec.entries.iter().enumerate() {
             if let ObsTransform::Normalize { min, max } = &entry.transform {
@@ -201,20 +407,29 @@ impl ObsPlan {
         let has_agent = spec.entries.iter().any(|e| {
             matches!(
                 e.region,
-                ObsRegion::AgentDisk { .. } | ObsRegion::AgentRect { .. }
+                ObsRegion::AgentDisk { .. }
+                    | ObsRegion::AgentDiskReal { .. }
+                    | ObsRegion::AgentRect { .. }
+                    | ObsRegion::AgentFoveated { .. }
+                    | ObsRegion::PositionChannel
             )
         });
 
         if has_agent {
-            Self::compile_standard(spec, space)
+            Self::compile_standard(spec, space, cache)
         } else {
-            Self::compile_simple(spec, space)
+            Self::compile_simple(spec, space, cache)
         }
     }
 
     /// Compile a Simple plan (all `Fixed` regions, no agent-relative entries).
-    fn compile_simple(spec: &ObsSpec, space: &dyn Space) -> Result<ObsPlanResult, ObsError> {
+    fn compile_simple(
+        spec: &ObsSpec,
+        space: &dyn Space,
+        cache: Option<&RegionPlanCache>,
+    ) -> Result<ObsPlanResult, ObsError> {
         let canonical = space.canonical_ordering();
+        let space_digest = space_digest(space, &canonical);
         let coord_to_field_idx: IndexMap<Coord, usize> = canonical
             .into_iter()
             .enumerate()
@@ -222,35 +437,30 @@ impl ObsPlan {
             .collect();
 
         let mut entries = Vec::with_capacity(spec.entries.len());
-        let mut total_valid = 0usize;
         let mut total_elements = 0usize;
         let mut output_offset = 0usize;
         let mut mask_offset = 0usize;
         let mut entry_shapes = Vec::with_capacity(spec.entries.len());
+        let mut entry_offsets = Vec::with_capacity(spec.entries.len());
 
         for (i, entry) in spec.entries.iter().enumerate() {
             let fixed_region = match &entry.region {
                 ObsRegion::Fixed(spec) => spec,
-                ObsRegion::AgentDisk { .. } | ObsRegion::AgentRect { .. } => {
+                ObsRegion::AgentDisk { .. }
+                | ObsRegion::AgentDiskReal { .. }
+                | ObsRegion::AgentRect { .. }
+                | ObsRegion::AgentFoveated { .. }
+                | ObsRegion::PositionChannel => {
                     return Err(ObsError::InvalidObsSpec {
                         reason: format!("entry {i}: agent-relative region in Simple plan"),
                     });
                 }
             };
-            if entry.pool.is_some() {
-                return Err(ObsError::InvalidObsSpec {
-                    reason: format!(
-                        "entry {i}: pooling requires a Standard plan (use agent-relative region)"
-                    ),
-                });
-            }
-
-            let mut region_plan =
-                space
-                    .compile_region(fixed_region)
-                    .map_err(|e| ObsError::InvalidObsSpec {
-                        reason: format!("entry {i}: region compile failed: {e}"),
-                    })?;
+            let region_plan = compile_fixed_region(space, fixed_region, cache).map_err(|e| {
+                ObsError::InvalidObsSpec {
+                    reason: format!("entry {i}: region compile failed: {e}"),
+                }
+            })?;
 
             let ratio = region_plan.valid_ratio();
             if ratio < COVERAGE_ERROR_THRESHOLD {
@@ -281,48 +491,80 @@ impl ObsPlan {
                 });
             }
 
-            let element_count = region_plan.bounding_shape().total_elements();
             let shape = match region_plan.bounding_shape() {
                 murk_space::BoundingShape::Rect(dims) => dims.clone(),
             };
-            entry_shapes.push(shape);
+            let region_mask = into_valid_mask(region_plan);
+
+            // Pooling reduces the gathered bounding box; the pooled mask
+            // depends only on the static region mask, so compute it once.
+            let (element_count, valid_mask, pre_pool_shape, pre_pool_mask) =
+                if let Some(pool) = &entry.pool {
+                    let (element_count, output_shape) = pooled_output_shape(i, pool, &shape)?;
+                    let mut pooled = vec![0.0f32; element_count];
+                    let mut pooled_mask = vec![0u8; element_count];
+                    pool_2d_into(
+                        &vec![0.0f32; region_mask.len()],
+                        &region_mask,
+                        &shape,
+                        pool,
+                        &mut pooled,
+                        &mut pooled_mask,
+                    )?;
+                    entry_shapes.push(output_shape);
+                    (element_count, pooled_mask, shape, region_mask)
+                } else {
+                    entry_shapes.push(shape);
+                    (region_mask.len(), region_mask, Vec::new(), Vec::new())
+                };
 
-            let valid_mask = region_plan.take_valid_mask();
             let valid_count = valid_mask.iter().filter(|&&v| v == 1).count();
             entries.push(CompiledEntry {
                 field_id: entry.field_id,
                 transform: entry.transform.clone(),
                 dtype: entry.dtype,
+                tick_offset: entry.tick_offset,
                 output_offset,
                 mask_offset,
                 element_count,
                 gather_ops,
                 valid_mask,
+                pool: entry.pool.clone(),
+                pre_pool_shape,
+                pre_pool_mask,
                 valid_count,
                 valid_ratio: ratio,
             });
-            total_valid += valid_count;
             total_elements += element_count;
 
+            entry_offsets.push((output_offset, element_count));
             output_offset += element_count;
             mask_offset += element_count;
         }
 
+        let strategy = PlanStrategy::Simple(SimplePlanData {
+            entries,
+            total_elements,
+        });
         let plan = ObsPlan {
-            strategy: PlanStrategy::Simple(SimplePlanData {
-                entries,
-                total_valid,
-                total_elements,
-            }),
+            scratch: ExecScratch::new(&strategy, output_offset, mask_offset),
+            strategy,
             output_len: output_offset,
             mask_len: mask_offset,
+            entry_offsets,
             compiled_generation: None,
+            space_digest,
+            pad_value: spec.pad_value,
         };
 
+        let entry_byte_offsets = entry_byte_offsets(spec, &plan.entry_offsets);
         Ok(ObsPlanResult {
             output_len: plan.output_len,
             mask_len: plan.mask_len,
             entry_shapes,
+            entry_offsets: plan.entry_offsets.clone(),
+            output_bytes: entry_byte_offsets.last().map_or(0, |&(s, l)| s + l),
+            entry_byte_offsets,
             plan,
         })
     }
@@ -331,8 +573,13 @@ impl ObsPlan {
     ///
     /// Fixed entries are compiled with pre-computed gather (same for all agents).
     /// Agent entries are compiled as templates (resolved per-agent at execute time).
-    fn compile_standard(spec: &ObsSpec, space: &dyn Space) -> Result<ObsPlanResult, ObsError> {
+    fn compile_standard(
+        spec: &ObsSpec,
+        space: &dyn Space,
+        cache: Option<&RegionPlanCache>,
+    ) -> Result<ObsPlanResult, ObsError> {
         let canonical = space.canonical_ordering();
+        let space_digest = space_digest(space, &canonical);
         let coord_to_field_idx: IndexMap<Coord, usize> = canonical
             .into_iter()
             .enumerate()
@@ -341,14 +588,34 @@ impl ObsPlan {
 
         let geometry = GridGeometry::from_space(space);
         let ndim = space.ndim();
+        let axis_bounds = if spec
+            .entries
+            .iter()
+            .any(|e| matches!(e.region, ObsRegion::PositionChannel))
+        {
+            axis_bounds(coord_to_field_idx.keys(), ndim)
+        } else {
+            Vec::new()
+        };
 
         let mut fixed_entries = Vec::new();
         let mut agent_entries = Vec::new();
+        let mut position_entries = Vec::new();
         let mut output_offset = 0usize;
         let mut mask_offset = 0usize;
         let mut entry_shapes = Vec::new();
+        let mut entry_offsets = Vec::with_capacity(spec.entries.len());
 
         for (i, entry) in spec.entries.iter().enumerate() {
+            if entry.tick_offset != 0 {
+                return Err(ObsError::InvalidObsSpec {
+                    reason: format!(
+                        "entry {i}: tick_offset is not supported in agent-relative plans, \
+                         which execute against a single snapshot"
+                    ),
+                });
+            }
+            let entry_start = output_offset;
             match &entry.region {
                 ObsRegion::Fixed(region_spec) => {
                     if entry.pool.is_some() {
@@ -357,11 +624,12 @@ impl ObsPlan {
                         });
                     }
 
-                    let mut region_plan = space.compile_region(region_spec).map_err(|e| {
-                        ObsError::InvalidObsSpec {
-                            reason: format!("entry {i}: region compile failed: {e}"),
-                        }
-                    })?;
+                    let region_plan =
+                        compile_fixed_region(space, region_spec, cache).map_err(|e| {
+                            ObsError::InvalidObsSpec {
+                                reason: format!("entry {i}: region compile failed: {e}"),
+                            }
+                        })?;
 
                     let ratio = region_plan.valid_ratio();
                     if ratio < COVERAGE_ERROR_THRESHOLD {
@@ -394,17 +662,21 @@ impl ObsPlan {
                     };
                     entry_shapes.push(shape);
 
-                    let valid_mask = region_plan.take_valid_mask();
+                    let valid_mask = into_valid_mask(region_plan);
                     let valid_count = valid_mask.iter().filter(|&&v| v == 1).count();
                     fixed_entries.push(CompiledEntry {
                         field_id: entry.field_id,
                         transform: entry.transform.clone(),
                         dtype: entry.dtype,
+                        tick_offset: 0,
                         output_offset,
                         mask_offset,
                         element_count,
                         gather_ops,
                         valid_mask,
+                        pool: None,
+                        pre_pool_shape: Vec::new(),
+                        pre_pool_mask: Vec::new(),
                         valid_count,
                         valid_ratio: ratio,
                     });
@@ -413,26 +685,79 @@ impl ObsPlan {
                     mask_offset += element_count;
                 }
 
-                ObsRegion::AgentDisk { radius } => {
+                ObsRegion::AgentDisk {
+                    radius,
+                    metric,
+                    occlusion,
+                } => {
                     let half_ext: smallvec::SmallVec<[u32; 4]> =
                         (0..ndim).map(|_| *radius).collect();
-                    let (ae, shape) = Self::compile_agent_entry(
+                    let (mut ae, shape) = Self::compile_agent_entry(
                         i,
                         entry,
                         &half_ext,
                         *radius,
                         &geometry,
                         Some(*radius),
+                        None,
+                        metric.unwrap_or_default(),
+                        output_offset,
+                        mask_offset,
+                    )?;
+                    ae.occlusion = compile_occlusion(i, occlusion, &geometry, &ae.active_ops)?;
+                    entry_shapes.push(shape);
+                    output_offset += ae.element_count;
+                    mask_offset += ae.element_count;
+                    agent_entries.push(ae);
+                }
+
+                ObsRegion::AgentDiskReal {
+                    pixel_radius,
+                    occlusion,
+                } => {
+                    let Some(geo) = &geometry else {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!("entry {i}: AgentDiskReal requires a grid space"),
+                        });
+                    };
+                    if !pixel_radius.is_finite() || *pixel_radius < 0.0 {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!(
+                                "entry {i}: AgentDiskReal pixel_radius must be finite and >= 0, got {pixel_radius}"
+                            ),
+                        });
+                    }
+                    // Compile the bounding box, then keep only the cells
+                    // whose centres lie within the pixel-space circle.
+                    let pixel_radius = *pixel_radius as f64;
+                    let reach = geo.pixel_reach(pixel_radius);
+                    let half_ext: smallvec::SmallVec<[u32; 4]> = (0..ndim).map(|_| reach).collect();
+                    let (mut ae, shape) = Self::compile_agent_entry(
+                        i,
+                        entry,
+                        &half_ext,
+                        reach,
+                        &geometry,
+                        None,
+                        None,
+                        DiskMetric::Native,
                         output_offset,
                         mask_offset,
                     )?;
+                    let limit = pixel_radius * pixel_radius;
+                    ae.active_ops
+                        .retain(|op| geo.pixel_distance_sq(&op.relative) <= limit);
+                    ae.occlusion = compile_occlusion(i, occlusion, &geometry, &ae.active_ops)?;
                     entry_shapes.push(shape);
                     output_offset += ae.element_count;
                     mask_offset += ae.element_count;
                     agent_entries.push(ae);
                 }
 
-                ObsRegion::AgentRect { half_extent } => {
+                ObsRegion::AgentRect {
+                    half_extent,
+                    occlusion,
+                } => {
                     if half_extent.len() != ndim {
                         return Err(ObsError::InvalidObsSpec {
                             reason: format!(
@@ -442,39 +767,136 @@ impl ObsPlan {
                         });
                     }
                     let radius = *half_extent.iter().max().unwrap_or(&0);
-                    let (ae, shape) = Self::compile_agent_entry(
+                    let (mut ae, shape) = Self::compile_agent_entry(
                         i,
                         entry,
                         half_extent,
                         radius,
                         &geometry,
                         None,
+                        None,
+                        DiskMetric::Native,
                         output_offset,
                         mask_offset,
                     )?;
+                    ae.occlusion = compile_occlusion(i, occlusion, &geometry, &ae.active_ops)?;
                     entry_shapes.push(shape);
                     output_offset += ae.element_count;
                     mask_offset += ae.element_count;
                     agent_entries.push(ae);
                 }
+
+                ObsRegion::AgentFoveated { rings } => {
+                    if entry.pool.is_some() {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!(
+                                "entry {i}: AgentFoveated pools per ring; entry pool must be None"
+                            ),
+                        });
+                    }
+                    if rings.is_empty() {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!("entry {i}: AgentFoveated requires at least one ring"),
+                        });
+                    }
+                    if rings.windows(2).any(|w| w[0].0 >= w[1].0) {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!(
+                                "entry {i}: AgentFoveated ring radii must be strictly increasing"
+                            ),
+                        });
+                    }
+                    if geometry.is_none() {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!("entry {i}: AgentFoveated requires a grid space"),
+                        });
+                    }
+
+                    // Each ring compiles as its own pooled-or-direct disk
+                    // template with the previous ring's cells masked out.
+                    let mut inner_radius = None;
+                    let mut total = 0usize;
+                    for (radius, pool) in rings {
+                        let ring = crate::spec::ObsEntry {
+                            field_id: entry.field_id,
+                            region: ObsRegion::AgentDisk {
+                                radius: *radius,
+                                metric: None,
+                                occlusion: None,
+                            },
+                            pool: pool.clone(),
+                            transform: entry.transform.clone(),
+                            dtype: entry.dtype,
+                            tick_offset: 0,
+                        };
+                        let half_ext: smallvec::SmallVec<[u32; 4]> =
+                            (0..ndim).map(|_| *radius).collect();
+                        let (ae, _) = Self::compile_agent_entry(
+                            i,
+                            &ring,
+                            &half_ext,
+                            *radius,
+                            &geometry,
+                            Some(*radius),
+                            inner_radius,
+                            DiskMetric::Native,
+                            output_offset,
+                            mask_offset,
+                        )?;
+                        total += ae.element_count;
+                        output_offset += ae.element_count;
+                        mask_offset += ae.element_count;
+                        agent_entries.push(ae);
+                        inner_radius = Some(*radius);
+                    }
+                    entry_shapes.push(vec![total]);
+                }
+
+                ObsRegion::PositionChannel => {
+                    if entry.pool.is_some() {
+                        return Err(ObsError::InvalidObsSpec {
+                            reason: format!("entry {i}: PositionChannel cannot be pooled"),
+                        });
+                    }
+                    position_entries.push(PositionEntry {
+                        transform: entry.transform.clone(),
+                        output_offset,
+                        mask_offset,
+                    });
+                    entry_shapes.push(vec![ndim]);
+                    output_offset += ndim;
+                    mask_offset += ndim;
+                }
             }
+            entry_offsets.push((entry_start, output_offset - entry_start));
         }
 
+        let strategy = PlanStrategy::Standard(StandardPlanData {
+            fixed_entries,
+            agent_entries,
+            position_entries,
+            axis_bounds,
+            geometry,
+        });
         let plan = ObsPlan {
-            strategy: PlanStrategy::Standard(StandardPlanData {
-                fixed_entries,
-                agent_entries,
-                geometry,
-            }),
+            scratch: ExecScratch::new(&strategy, output_offset, mask_offset),
+            strategy,
             output_len: output_offset,
             mask_len: mask_offset,
+            entry_offsets,
             compiled_generation: None,
+            space_digest,
+            pad_value: spec.pad_value,
         };
 
+        let entry_byte_offsets = entry_byte_offsets(spec, &plan.entry_offsets);
         Ok(ObsPlanResult {
             output_len: plan.output_len,
             mask_len: plan.mask_len,
             entry_shapes,
+            entry_offsets: plan.entry_offsets.clone(),
+            output_bytes: entry_byte_offsets.last().map_or(0, |&(s, l)| s + l),
+            entry_byte_offsets,
             plan,
         })
     }
@@ -483,6 +905,9 @@ impl ObsPlan {
     ///
     /// `disk_radius`: if `Some(r)`, template ops outside graph-distance `r`
     /// are marked `in_disk = false` (for `AgentDisk`). `None` for `AgentRect`.
+    /// `inner_radius`: if `Some(r)`, ops within graph-distance `r` are also
+    /// excluded, leaving an annulus (for `AgentFoveated` outer rings).
+    /// `metric` selects the distance both radii are measured in.
     #[allow(clippy::too_many_arguments)]
     fn compile_agent_entry(
         entry_idx: usize,
@@ -491,6 +916,8 @@ impl ObsPlan {
         radius: u32,
         geometry: &Option<GridGeometry>,
         disk_radius: Option<u32>,
+        inner_radius: Option<u32>,
+        metric: DiskMetric,
         output_offset: usize,
         mask_offset: usize,
     ) -> Result<(AgentCompiledEntry, Vec<usize>), ObsError> {
@@ -498,7 +925,8 @@ impl ObsPlan {
             half_extent.iter().map(|&he| 2 * he as usize + 1).collect();
         let pre_pool_element_count: usize = pre_pool_shape.iter().product();
 
-        let template_ops = generate_template_ops(half_extent, geometry, disk_radius)?;
+        let template_ops =
+            generate_template_ops(half_extent, geometry, disk_radius, inner_radius, metric)?;
         let active_ops = template_ops
             .iter()
             .filter(|op| op.in_disk)
@@ -506,34 +934,7 @@ impl ObsPlan {
             .collect();
 
         let (element_count, output_shape) = if let Some(pool) = &entry.pool {
-            if pre_pool_shape.len() != 2 {
-                return Err(ObsError::InvalidObsSpec {
-                    reason: format!(
-                        "entry {entry_idx}: pooling requires 2D region, got {}D",
-                        pre_pool_shape.len()
-                    ),
-                });
-            }
-            let h = pre_pool_shape[0];
-            let w = pre_pool_shape[1];
-            let ks = pool.kernel_size;
-            let stride = pool.stride;
-            if ks == 0 || stride == 0 {
-                return Err(ObsError::InvalidObsSpec {
-                    reason: format!("entry {entry_idx}: pool kernel_size and stride must be > 0"),
-                });
-            }
-            let out_h = if h >= ks { (h - ks) / stride + 1 } else { 0 };
-            let out_w = if w >= ks { (w - ks) / stride + 1 } else { 0 };
-            if out_h == 0 || out_w == 0 {
-                return Err(ObsError::InvalidObsSpec {
-                    reason: format!(
-                        "entry {entry_idx}: pool produces empty output \
-                         (region [{h},{w}], kernel_size {ks}, stride {stride})"
-                    ),
-                });
-            }
-            (out_h * out_w, vec![out_h, out_w])
+            pooled_output_shape(entry_idx, pool, &pre_pool_shape)?
         } else {
             (pre_pool_element_count, pre_pool_shape.clone())
         };
@@ -551,6 +952,7 @@ impl ObsPlan {
                 pre_pool_shape,
                 active_ops,
                 radius,
+                occlusion: None,
             },
             output_shape,
         ))
@@ -580,15 +982,54 @@ impl ObsPlan {
         self.mask_len
     }
 
+    /// `(start, len)` of each spec entry within one output tensor.
+    pub fn entry_offsets(&self) -> &[(usize, usize)] {
+        &self.entry_offsets
+    }
+
+    /// The elements of `output` belonging to spec entry `entry_idx`.
+    ///
+    /// `output` is one observation of [`output_len`](Self::output_len)
+    /// elements; for [`execute_agents`](Self::execute_agents) pass one
+    /// agent's chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entry_idx` is out of range or `output` is shorter than
+    /// the entry's end offset.
+    pub fn entry_slice<'a>(&self, output: &'a [f32], entry_idx: usize) -> &'a [f32] {
+        let (start, len) = self.entry_offsets[entry_idx];
+        &output[start..start + len]
+    }
+
     /// The generation this plan was compiled against, if bound.
     pub fn compiled_generation(&self) -> Option<WorldGenerationId> {
         self.compiled_generation
     }
 
+    /// Value written to masked-out output elements.
+    pub fn pad_value(&self) -> f32 {
+        self.pad_value
+    }
+
+    /// Overwrite every element whose mask is 0 with the pad value.
+    ///
+    /// Gathers leave invalid elements at `0.0`, so the pass is skipped
+    /// for the default pad.
+    fn pad_invalid(&self, output: &mut [f32], mask: &[u8]) {
+        if self.pad_value.to_bits() == 0 {
+            return;
+        }
+        for (out, _) in output.iter_mut().zip(mask).filter(|(_, &m)| m == 0) {
+            *out = self.pad_value;
+        }
+    }
+
     /// Execute the observation plan against a snapshot.
     ///
     /// Fills `output` with gathered and transformed field values, and
-    /// `mask` with validity flags (1 = valid, 0 = padding). Both
+    /// `mask` with validity flags (1 = valid, 0 = padding), setting padding
+    /// elements to the spec's [`pad_value`](ObsSpec::pad_value). Both
     /// buffers must be pre-allocated to [`output_len`](Self::output_len)
     /// and [`mask_len`](Self::mask_len) respectively.
     ///
@@ -597,6 +1038,10 @@ impl ObsPlan {
     /// (age is always 0). In RealtimeAsync mode, pass the current
     /// engine tick so age reflects snapshot staleness.
     ///
+    /// Entries with a non-zero [`tick_offset`](crate::ObsEntry::tick_offset)
+    /// have no history to read from here, so they are padded and
+    /// masked out; use [`execute_history`](Self::execute_history) instead.
+    ///
     /// Returns [`ObsMetadata`] on success.
     ///
     /// # Errors
@@ -609,6 +1054,32 @@ impl ObsPlan {
         engine_tick: Option<TickId>,
         output: &mut [f32],
         mask: &mut [u8],
+    ) -> Result<ObsMetadata, ObsError> {
+        self.execute_history(&[snapshot], engine_tick, output, mask)
+    }
+
+    /// Execute the plan against a window of recent snapshots.
+    ///
+    /// `history[0]` is the current snapshot and `history[k]` the one from
+    /// `k` ticks earlier. Each entry gathers from
+    /// `history[entry.tick_offset]`, so one plan can stack a field across
+    /// several ticks. Entries whose offset reaches past the end of
+    /// `history` are padded and masked out, as at the start of an
+    /// episode.
+    ///
+    /// The generation check and the returned metadata use `history[0]`;
+    /// `valid_count` and `coverage` exclude masked-out entries.
+    ///
+    /// # Errors
+    ///
+    /// - [`ObsError::ExecutionFailed`] if `history` is empty.
+    /// - Otherwise the same as [`execute`](Self::execute).
+    pub fn execute_history(
+        &self,
+        history: &[&dyn SnapshotAccess],
+        engine_tick: Option<TickId>,
+        output: &mut [f32],
+        mask: &mut [u8],
     ) -> Result<ObsMetadata, ObsError> {
         let simple = match &self.strategy {
             PlanStrategy::Simple(data) => data,
@@ -618,6 +1089,9 @@ impl ObsPlan {
                 });
             }
         };
+        let snapshot = *history.first().ok_or_else(|| ObsError::ExecutionFailed {
+            reason: "snapshot history is empty".into(),
+        })?;
 
         if output.len() < self.output_len {
             return Err(ObsError::ExecutionFailed {
@@ -647,12 +1121,15 @@ impl ObsPlan {
             }
         }
 
-        Self::execute_simple_entries(&simple.entries, snapshot, output, mask)?;
+        let valid_count = self.scratch.with_gathered(|gathered| {
+            Self::execute_simple_entries(&simple.entries, history, output, mask, gathered)
+        })?;
+        self.pad_invalid(&mut output[..self.output_len], &mask[..self.mask_len]);
 
         let coverage = if simple.total_elements == 0 {
             0.0
         } else {
-            simple.total_valid as f64 / simple.total_elements as f64
+            valid_count as f64 / simple.total_elements as f64
         };
 
         let age_ticks = match engine_tick {
@@ -664,11 +1141,65 @@ impl ObsPlan {
             tick_id: snapshot.tick_id(),
             age_ticks,
             coverage,
+            valid_count,
             world_generation_id: snapshot.world_generation_id(),
             parameter_version: snapshot.parameter_version(),
+            agent_id: None,
+            stale: false,
         })
     }
 
+    /// Execute the plan against two snapshots and write `curr - prev`.
+    ///
+    /// Gathers and transforms both snapshots as [`execute`](Self::execute)
+    /// would, then writes the per-element difference into `output`. An
+    /// element is valid in `mask` only if it is valid in both snapshots;
+    /// invalid elements are written as the pad value. The returned metadata
+    /// describes `curr`, with `valid_count` and `coverage` reflecting the
+    /// combined mask.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`execute`](Self::execute), for either snapshot.
+    pub fn execute_delta(
+        &self,
+        curr: &dyn SnapshotAccess,
+        prev: &dyn SnapshotAccess,
+        output: &mut [f32],
+        mask: &mut [u8],
+    ) -> Result<ObsMetadata, ObsError> {
+        let (mut meta, valid_count) =
+            self.scratch
+                .with_delta(self.output_len, self.mask_len, |prev_output, prev_mask| {
+                    self.execute(prev, None, prev_output, prev_mask)?;
+                    let meta = self.execute(curr, None, output, mask)?;
+
+                    let mut valid_count = 0usize;
+                    for ((out, m), (&p, &pm)) in output[..self.output_len]
+                        .iter_mut()
+                        .zip(mask[..self.mask_len].iter_mut())
+                        .zip(prev_output.iter().zip(prev_mask.iter()))
+                    {
+                        if *m == 1 && pm == 1 {
+                            *out -= p;
+                            valid_count += 1;
+                        } else {
+                            *out = self.pad_value;
+                            *m = 0;
+                        }
+                    }
+                    Ok::<_, ObsError>((meta, valid_count))
+                })?;
+
+        meta.valid_count = valid_count;
+        meta.coverage = if self.output_len == 0 {
+            0.0
+        } else {
+            valid_count as f64 / self.output_len as f64
+        };
+        Ok(meta)
+    }
+
     /// Execute the plan for a batch of `N` identical environments.
     ///
     /// Each snapshot in the batch fills `output_len()` elements in the
@@ -686,6 +1217,35 @@ impl ObsPlan {
         engine_tick: Option<TickId>,
         output: &mut [f32],
         mask: &mut [u8],
+    ) -> Result<Vec<ObsMetadata>, ObsError> {
+        self.execute_batch_impl(snapshots, engine_tick, output, mask, false)
+    }
+
+    /// Like [`execute_batch`](Self::execute_batch), but a generation
+    /// mismatch masks out that environment instead of failing the batch.
+    ///
+    /// For asynchronous vec-envs whose snapshots may straddle a world
+    /// reset. A stale snapshot's output slots are padded, its mask zeroed
+    /// and its [`ObsMetadata::stale`] flag is set (with `coverage` and
+    /// `valid_count` zero); all other snapshots are processed normally.
+    /// Buffer-size and execution errors still fail the whole batch.
+    pub fn execute_batch_lenient(
+        &self,
+        snapshots: &[&dyn SnapshotAccess],
+        engine_tick: Option<TickId>,
+        output: &mut [f32],
+        mask: &mut [u8],
+    ) -> Result<Vec<ObsMetadata>, ObsError> {
+        self.execute_batch_impl(snapshots, engine_tick, output, mask, true)
+    }
+
+    fn execute_batch_impl(
+        &self,
+        snapshots: &[&dyn SnapshotAccess],
+        engine_tick: Option<TickId>,
+        output: &mut [f32],
+        mask: &mut [u8],
+        lenient: bool,
     ) -> Result<Vec<ObsMetadata>, ObsError> {
         let simple = match &self.strategy {
             PlanStrategy::Simple(data) => data,
@@ -719,31 +1279,46 @@ impl ObsPlan {
             });
         }
 
-        let coverage = if simple.total_elements == 0 {
-            0.0
-        } else {
-            simple.total_valid as f64 / simple.total_elements as f64
-        };
-
         let mut metadata = Vec::with_capacity(batch_size);
         for (i, snap) in snapshots.iter().enumerate() {
+            let out_start = i * self.output_len;
+            let mask_start = i * self.mask_len;
+            let out_slice = &mut output[out_start..out_start + self.output_len];
+            let mask_slice = &mut mask[mask_start..mask_start + self.mask_len];
+
+            let mut stale = false;
             if let Some(compiled_gen) = self.compiled_generation {
                 let snapshot_gen = snap.world_generation_id();
                 if compiled_gen != snapshot_gen {
-                    return Err(ObsError::PlanInvalidated {
-                        reason: format!(
-                            "plan compiled for generation {}, snapshot is generation {}",
-                            compiled_gen.0, snapshot_gen.0
-                        ),
-                    });
+                    if !lenient {
+                        return Err(ObsError::PlanInvalidated {
+                            reason: format!(
+                                "plan compiled for generation {}, snapshot is generation {}",
+                                compiled_gen.0, snapshot_gen.0
+                            ),
+                        });
+                    }
+                    stale = true;
                 }
             }
 
-            let out_start = i * self.output_len;
-            let mask_start = i * self.mask_len;
-            let out_slice = &mut output[out_start..out_start + self.output_len];
-            let mask_slice = &mut mask[mask_start..mask_start + self.mask_len];
-            Self::execute_simple_entries(&simple.entries, *snap, out_slice, mask_slice)?;
+            // pad_invalid below fills a stale row's output with pad_value.
+            let valid_count = if stale {
+                out_slice.fill(0.0);
+                mask_slice.fill(0);
+                0
+            } else {
+                self.scratch.with_gathered(|gathered| {
+                    Self::execute_simple_entries(
+                        &simple.entries,
+                        &[*snap],
+                        out_slice,
+                        mask_slice,
+                        gathered,
+                    )
+                })?
+            };
+            self.pad_invalid(out_slice, mask_slice);
 
             let age_ticks = match engine_tick {
                 Some(tick) => tick.0.saturating_sub(snap.tick_id().0),
@@ -752,9 +1327,16 @@ impl ObsPlan {
             metadata.push(ObsMetadata {
                 tick_id: snap.tick_id(),
                 age_ticks,
-                coverage,
+                coverage: if simple.total_elements == 0 {
+                    0.0
+                } else {
+                    valid_count as f64 / simple.total_elements as f64
+                },
+                valid_count,
                 world_generation_id: snap.world_generation_id(),
                 parameter_version: snap.parameter_version(),
+                agent_id: None,
+                stale,
             });
         }
         Ok(metadata)
@@ -858,6 +1440,23 @@ impl ObsPlan {
                     })?;
             agent_field_data.push(data);
         }
+        let mut occlusion_field_data = Vec::with_capacity(standard.agent_entries.len());
+        for entry in &standard.agent_entries {
+            let data = match &entry.occlusion {
+                Some(occlusion) => {
+                    Some(snapshot.read_field(occlusion.field_id).ok_or_else(|| {
+                        ObsError::ExecutionFailed {
+                            reason: format!(
+                                "occlusion field {:?} not in snapshot",
+                                occlusion.field_id
+                            ),
+                        }
+                    })?)
+                }
+                None => None,
+            };
+            occlusion_field_data.push(data);
+        }
 
         // ── Compute fixed entries ONCE (identical for all agents) ──
         // Allocate scratch buffers for the fixed-entry output and mask, gather
@@ -952,10 +1551,11 @@ impl ObsPlan {
             let mut total_elements = fixed_elements;
 
             // ── Agent-relative entries ───────────────────────────
-            for (entry, field_data) in standard
+            for ((entry, field_data), occlusion_data) in standard
                 .agent_entries
                 .iter()
                 .zip(agent_field_data.iter().copied())
+                .zip(occlusion_field_data.iter().copied())
             {
                 // Fast path: stride arithmetic works only for non-wrapping
                 // grids where all cells in the bounding box are in-bounds.
@@ -976,6 +1576,7 @@ impl ObsPlan {
                     entry,
                     center,
                     field_data,
+                    occlusion_data,
                     &standard.geometry,
                     space,
                     use_fast_path,
@@ -991,6 +1592,24 @@ impl ObsPlan {
                 total_elements += entry.element_count;
             }
 
+            // ── Position channels ────────────────────────────────
+            for entry in &standard.position_entries {
+                for (axis, (&c, &(lo, hi))) in center.iter().zip(&standard.axis_bounds).enumerate()
+                {
+                    let norm = if hi > lo {
+                        ((c as f64 - lo as f64) / (hi as f64 - lo as f64)) as Scalar
+                    } else {
+                        0.0
+                    };
+                    agent_output[entry.output_offset + axis] =
+                        apply_transform(norm, &entry.transform);
+                    agent_mask[entry.mask_offset + axis] = 1;
+                }
+                total_valid += standard.axis_bounds.len();
+                total_elements += standard.axis_bounds.len();
+            }
+            self.pad_invalid(agent_output, agent_mask);
+
             let coverage = if total_elements == 0 {
                 0.0
             } else {
@@ -1006,11 +1625,64 @@ impl ObsPlan {
                 tick_id: snapshot.tick_id(),
                 age_ticks,
                 coverage,
+                valid_count: total_valid,
                 world_generation_id: snapshot.world_generation_id(),
                 parameter_version: snapshot.parameter_version(),
+                agent_id: None,
+                stale: false,
+            });
+        }
+
+        Ok(metadata)
+    }
+
+    /// [`execute_agents`](Self::execute_agents) with a stable agent id per
+    /// center.
+    ///
+    /// `agent_ids[i]` identifies the agent at `agent_centers[i]`; ids must
+    /// be unique. Each returned [`ObsMetadata`] carries its row's
+    /// `agent_id`. With [`AgentOrder::SortedById`] the output, mask, and
+    /// metadata rows are laid out in ascending id order, so a given agent
+    /// lands in the same row every tick however the caller orders its
+    /// centers.
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute_agents_with_ids(
+        &self,
+        snapshot: &dyn SnapshotAccess,
+        space: &dyn Space,
+        agent_centers: &[Coord],
+        agent_ids: &[u16],
+        order: AgentOrder,
+        engine_tick: Option<TickId>,
+        output: &mut [f32],
+        mask: &mut [u8],
+    ) -> Result<Vec<ObsMetadata>, ObsError> {
+        if agent_ids.len() != agent_centers.len() {
+            return Err(ObsError::ExecutionFailed {
+                reason: format!(
+                    "{} agent ids for {} agent centers",
+                    agent_ids.len(),
+                    agent_centers.len()
+                ),
+            });
+        }
+        let mut rows: Vec<usize> = (0..agent_ids.len()).collect();
+        rows.sort_unstable_by_key(|&i| agent_ids[i]);
+        if let Some(w) = rows.windows(2).find(|w| agent_ids[w[0]] == agent_ids[w[1]]) {
+            return Err(ObsError::ExecutionFailed {
+                reason: format!("duplicate agent id {}", agent_ids[w[0]]),
             });
         }
+        if order == AgentOrder::Input {
+            rows.sort_unstable();
+        }
 
+        let centers: Vec<Coord> = rows.iter().map(|&i| agent_centers[i].clone()).collect();
+        let mut metadata =
+            self.execute_agents(snapshot, space, &centers, engine_tick, output, mask)?;
+        for (meta, &i) in metadata.iter_mut().zip(&rows) {
+            meta.agent_id = Some(agent_ids[i]);
+        }
         Ok(metadata)
     }
 
@@ -1020,13 +1692,29 @@ impl ObsPlan {
     }
 
     /// Execute pre-compiled Simple plan entries into caller-provided buffers.
+    ///
+    /// Returns the number of valid output elements written.
     fn execute_simple_entries(
         entries: &[CompiledEntry],
-        snapshot: &dyn SnapshotAccess,
+        history: &[&dyn SnapshotAccess],
         output: &mut [f32],
         mask: &mut [u8],
-    ) -> Result<(), ObsError> {
+        gathered: &mut [f32],
+    ) -> Result<usize, ObsError> {
+        let mut valid_count = 0;
         for entry in entries {
+            let out_slice =
+                &mut output[entry.output_offset..entry.output_offset + entry.element_count];
+            let mask_slice = &mut mask[entry.mask_offset..entry.mask_offset + entry.element_count];
+
+            // Offsets past the supplied history read as absent.
+            let Some(snapshot) = history.get(entry.tick_offset) else {
+                out_slice.fill(0.0);
+                mask_slice.fill(0);
+                continue;
+            };
+            valid_count += entry.valid_count;
+
             let field_data =
                 snapshot
                     .read_field(entry.field_id)
@@ -1034,14 +1722,15 @@ impl ObsPlan {
                         reason: format!("field {:?} not in snapshot", entry.field_id),
                     })?;
 
-            let out_slice =
-                &mut output[entry.output_offset..entry.output_offset + entry.element_count];
-            let mask_slice = &mut mask[entry.mask_offset..entry.mask_offset + entry.element_count];
-
             // Initialize to zero/padding.
             out_slice.fill(0.0);
             mask_slice.copy_from_slice(&entry.valid_mask);
 
+            // Pooled entries gather raw values into the full bounding box,
+            // then pool and transform into the output.
+            let gathered = &mut gathered[..entry.pre_pool_mask.len()];
+            gathered.fill(0.0);
+
             // Branch-free gather: pre-computed (field_data_idx, tensor_idx) pairs.
             for op in &entry.gather_ops {
                 let raw = *field_data.get(op.field_data_idx).ok_or_else(|| {
@@ -1054,11 +1743,66 @@ impl ObsPlan {
                         ),
                     }
                 })?;
-                out_slice[op.tensor_idx] = apply_transform(raw, &entry.transform);
+                if entry.pool.is_some() {
+                    gathered[op.tensor_idx] = scalar_to_f32(raw);
+                } else {
+                    out_slice[op.tensor_idx] = apply_transform(raw, &entry.transform);
+                }
+            }
+
+            if let Some(pool) = &entry.pool {
+                pool_2d_into(
+                    gathered,
+                    &entry.pre_pool_mask,
+                    &entry.pre_pool_shape,
+                    pool,
+                    out_slice,
+                    mask_slice,
+                )?;
+                for v in out_slice.iter_mut() {
+                    *v = apply_transform(Scalar::from(*v), &entry.transform);
+                }
             }
         }
-        Ok(())
+        Ok(valid_count)
+    }
+}
+
+/// Validate pooling of a `pre_pool_shape` bounding box and return the
+/// pooled `(element_count, shape)`.
+fn pooled_output_shape(
+    entry_idx: usize,
+    pool: &PoolConfig,
+    pre_pool_shape: &[usize],
+) -> Result<(usize, Vec<usize>), ObsError> {
+    if pre_pool_shape.len() != 2 {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!(
+                "entry {entry_idx}: pooling requires 2D region, got {}D",
+                pre_pool_shape.len()
+            ),
+        });
+    }
+    let h = pre_pool_shape[0];
+    let w = pre_pool_shape[1];
+    let ks = pool.kernel_size;
+    let stride = pool.stride;
+    if ks == 0 || stride == 0 {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!("entry {entry_idx}: pool kernel_size and stride must be > 0"),
+        });
+    }
+    let out_h = if h >= ks { (h - ks) / stride + 1 } else { 0 };
+    let out_w = if w >= ks { (w - ks) / stride + 1 } else { 0 };
+    if out_h == 0 || out_w == 0 {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!(
+                "entry {entry_idx}: pool produces empty output \
+                 (region [{h},{w}], kernel_size {ks}, stride {stride})"
+            ),
+        });
     }
+    Ok((out_h * out_w, vec![out_h, out_w]))
 }
 
 /// Execute a single agent-relative entry for one agent.
@@ -1072,7 +1816,8 @@ impl ObsPlan {
 fn execute_agent_entry(
     entry: &AgentCompiledEntry,
     center: &Coord,
-    field_data: &[f32],
+    field_data: &[Scalar],
+    occlusion_data: Option<&[Scalar]>,
     geometry: &Option<GridGeometry>,
     space: &dyn Space,
     use_fast_path: bool,
@@ -1088,6 +1833,7 @@ fn execute_agent_entry(
             entry,
             center,
             field_data,
+            occlusion_data,
             geometry,
             space,
             use_fast_path,
@@ -1103,6 +1849,7 @@ fn execute_agent_entry(
             entry,
             center,
             field_data,
+            occlusion_data,
             geometry,
             space,
             use_fast_path,
@@ -1117,7 +1864,8 @@ fn execute_agent_entry(
 fn execute_agent_entry_direct(
     entry: &AgentCompiledEntry,
     center: &Coord,
-    field_data: &[f32],
+    field_data: &[Scalar],
+    occlusion_data: Option<&[Scalar]>,
     geometry: &Option<GridGeometry>,
     space: &dyn Space,
     use_fast_path: bool,
@@ -1133,7 +1881,18 @@ fn execute_agent_entry_direct(
         let geo = geometry.as_ref().unwrap();
         let base_rank = geo.canonical_rank(center) as isize;
         let mut valid = 0;
-        for op in &entry.active_ops {
+        for (k, op) in entry.active_ops.iter().enumerate() {
+            if is_occluded(
+                entry,
+                k,
+                center,
+                occlusion_data,
+                Some(base_rank),
+                geometry,
+                space,
+            ) {
+                continue;
+            }
             let field_idx = (base_rank + op.stride_offset) as usize;
             if let Some(&val) = field_data.get(field_idx) {
                 out_slice[op.tensor_idx] = apply_transform(val, &entry.transform);
@@ -1145,7 +1904,10 @@ fn execute_agent_entry_direct(
     } else {
         // SLOW PATH: bounds-check each offset (or modular wrap for torus).
         let mut valid = 0;
-        for op in &entry.active_ops {
+        for (k, op) in entry.active_ops.iter().enumerate() {
+            if is_occluded(entry, k, center, occlusion_data, None, geometry, space) {
+                continue;
+            }
             let field_idx = resolve_field_index(center, &op.relative, geometry, space);
             if let Some(idx) = field_idx {
                 if idx < field_data.len() {
@@ -1169,7 +1931,8 @@ fn execute_agent_entry_direct(
 fn execute_agent_entry_pooled(
     entry: &AgentCompiledEntry,
     center: &Coord,
-    field_data: &[f32],
+    field_data: &[Scalar],
+    occlusion_data: Option<&[Scalar]>,
     geometry: &Option<GridGeometry>,
     space: &dyn Space,
     use_fast_path: bool,
@@ -1183,19 +1946,33 @@ fn execute_agent_entry_pooled(
     if use_fast_path {
         let geo = geometry.as_ref().unwrap();
         let base_rank = geo.canonical_rank(center) as isize;
-        for op in &entry.active_ops {
+        for (k, op) in entry.active_ops.iter().enumerate() {
+            if is_occluded(
+                entry,
+                k,
+                center,
+                occlusion_data,
+                Some(base_rank),
+                geometry,
+                space,
+            ) {
+                continue;
+            }
             let field_idx = (base_rank + op.stride_offset) as usize;
             if let Some(&val) = field_data.get(field_idx) {
-                scratch[op.tensor_idx] = val;
+                scratch[op.tensor_idx] = scalar_to_f32(val);
                 scratch_mask[op.tensor_idx] = 1;
             }
         }
     } else {
-        for op in &entry.active_ops {
+        for (k, op) in entry.active_ops.iter().enumerate() {
+            if is_occluded(entry, k, center, occlusion_data, None, geometry, space) {
+                continue;
+            }
             let field_idx = resolve_field_index(center, &op.relative, geometry, space);
             if let Some(idx) = field_idx {
                 if idx < field_data.len() {
-                    scratch[op.tensor_idx] = field_data[idx];
+                    scratch[op.tensor_idx] = scalar_to_f32(field_data[idx]);
                     scratch_mask[op.tensor_idx] = 1;
                 }
             }
@@ -1218,7 +1995,7 @@ fn execute_agent_entry_pooled(
 
     let n = (out_h * out_w).min(entry.element_count);
     for i in 0..n {
-        out_slice[i] = apply_transform(pooled[i], &entry.transform);
+        out_slice[i] = apply_transform(Scalar::from(pooled[i]), &entry.transform);
     }
     mask_slice[..n].copy_from_slice(&pooled_mask[..n]);
 
@@ -1234,12 +2011,16 @@ fn execute_agent_entry_pooled(
 /// `stride_offset` for the interior fast path.
 ///
 /// If `disk_radius` is `Some(r)`, cells with graph distance > `r` are marked
-/// `in_disk = false`. The `geometry` is required to compute graph distance.
-/// When `geometry` is `None`, all cells are treated as in-disk (conservative).
+/// `in_disk = false`; if `inner_radius` is `Some(r)`, so are cells with graph
+/// distance <= `r`. Distances use `metric`; the `geometry` is required to
+/// compute them. When `geometry` is `None`, all cells are treated as
+/// in-disk (conservative).
 fn generate_template_ops(
     half_extent: &[u32],
     geometry: &Option<GridGeometry>,
     disk_radius: Option<u32>,
+    inner_radius: Option<u32>,
+    metric: DiskMetric,
 ) -> Result<Vec<TemplateOp>, ObsError> {
     let ndim = half_extent.len();
     let shape: Vec<usize> = half_extent.iter().map(|&he| 2 * he as usize + 1).collect();
@@ -1273,7 +2054,13 @@ fn generate_template_ops(
 
         let in_disk = match disk_radius {
             Some(r) => match geometry {
-                Some(geo) => geo.graph_distance(&relative)? <= r,
+                Some(geo) => {
+                    let outside_inner = match inner_radius {
+                        Some(inner) => !within_radius(geo, metric, &relative, inner)?,
+                        None => true,
+                    };
+                    within_radius(geo, metric, &relative, r)? && outside_inner
+                }
                 None => true, // no geometry → conservative (include all)
             },
             None => true, // AgentRect → all cells valid
@@ -1290,6 +2077,143 @@ fn generate_template_ops(
     Ok(ops)
 }
 
+/// Whether `relative` lies within distance `radius` of the origin under
+/// `metric`.
+fn within_radius(
+    geo: &GridGeometry,
+    metric: DiskMetric,
+    relative: &[i32],
+    radius: u32,
+) -> Result<bool, ObsError> {
+    let abs = relative.iter().map(|&d| d.unsigned_abs() as u64);
+    let radius = radius as u64;
+    Ok(match metric {
+        DiskMetric::Native => geo.graph_distance(relative)? as u64 <= radius,
+        DiskMetric::Euclidean => abs.map(|d| d * d).sum::<u64>() <= radius * radius,
+        DiskMetric::Manhattan => abs.sum::<u64>() <= radius,
+        DiskMetric::Chebyshev => abs.max().unwrap_or(0) <= radius,
+    })
+}
+
+/// Precompute the sight line to every active op of an occluded region.
+fn compile_occlusion(
+    entry_idx: usize,
+    occlusion: &Option<Occlusion>,
+    geometry: &Option<GridGeometry>,
+    active_ops: &[TemplateOp],
+) -> Result<Option<OcclusionTemplate>, ObsError> {
+    let Some(occlusion) = occlusion else {
+        return Ok(None);
+    };
+    let Some(geo) = geometry else {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!("entry {entry_idx}: occlusion requires a grid space"),
+        });
+    };
+    if occlusion.threshold.is_nan() {
+        return Err(ObsError::InvalidObsSpec {
+            reason: format!("entry {entry_idx}: occlusion threshold must not be NaN"),
+        });
+    }
+    Ok(Some(OcclusionTemplate {
+        field_id: occlusion.field_id,
+        threshold: occlusion.threshold,
+        lines: sight_lines(geo, active_ops),
+    }))
+}
+
+/// Sight lines from the agent to each of `active_ops`, aligned by index.
+fn sight_lines(geo: &GridGeometry, active_ops: &[TemplateOp]) -> Vec<Vec<SightCell>> {
+    active_ops
+        .iter()
+        .map(|op| {
+            geo.sight_line(&op.relative)
+                .into_iter()
+                .map(|relative| SightCell {
+                    stride_offset: relative
+                        .iter()
+                        .zip(&geo.coord_strides)
+                        .map(|(&r, &s)| r as isize * s as isize)
+                        .sum(),
+                    relative,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Whether the sight line from `center` to `active_ops[op_idx]` crosses an
+/// opaque cell. `base_rank` is the center's rank on the interior fast path.
+fn is_occluded(
+    entry: &AgentCompiledEntry,
+    op_idx: usize,
+    center: &Coord,
+    occlusion_data: Option<&[Scalar]>,
+    base_rank: Option<isize>,
+    geometry: &Option<GridGeometry>,
+    space: &dyn Space,
+) -> bool {
+    let (Some(occlusion), Some(data)) = (&entry.occlusion, occlusion_data) else {
+        return false;
+    };
+    occlusion.lines[op_idx].iter().any(|cell| {
+        let idx = match base_rank {
+            Some(base) => Some((base + cell.stride_offset) as usize),
+            None => resolve_field_index(center, &cell.relative, geometry, space),
+        };
+        idx.and_then(|i| data.get(i))
+            .is_some_and(|&v| v > Scalar::from(occlusion.threshold))
+    })
+}
+
+/// Compile a `Fixed` region, going through `cache` when one is supplied.
+fn compile_fixed_region(
+    space: &dyn Space,
+    spec: &RegionSpec,
+    cache: Option<&RegionPlanCache>,
+) -> Result<Arc<RegionPlan>, SpaceError> {
+    match cache {
+        Some(cache) => cache.get_or_compile(space, spec),
+        None => space.compile_region(spec).map(Arc::new),
+    }
+}
+
+/// `(min, max)` coordinate per axis over `coords`.
+fn axis_bounds<'a>(coords: impl Iterator<Item = &'a Coord>, ndim: usize) -> Vec<(i32, i32)> {
+    let mut bounds = vec![(i32::MAX, i32::MIN); ndim];
+    for coord in coords {
+        for (b, &c) in bounds.iter_mut().zip(coord.iter()) {
+            b.0 = b.0.min(c);
+            b.1 = b.1.max(c);
+        }
+    }
+    bounds
+}
+
+/// Extract a region plan's validity mask, copying only if the plan is shared.
+fn into_valid_mask(plan: Arc<RegionPlan>) -> Vec<u8> {
+    match Arc::try_unwrap(plan) {
+        Ok(mut plan) => plan.take_valid_mask(),
+        Err(shared) => shared.valid_mask().to_vec(),
+    }
+}
+
+/// Byte `(start, len)` of each entry, packing entries back to back at
+/// their dtype's element size.
+fn entry_byte_offsets(spec: &ObsSpec, entry_offsets: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut start = 0;
+    spec.entries
+        .iter()
+        .zip(entry_offsets)
+        .map(|(entry, &(_, len))| {
+            let bytes = len * entry.dtype.size_bytes();
+            let range = (start, bytes);
+            start += bytes;
+            range
+        })
+        .collect()
+}
+
 /// Resolve the field data index for an absolute coordinate.
 ///
 /// Handles three cases:
@@ -1338,118 +2262,883 @@ fn resolve_field_index(
     }
 }
 
+/// Replace every [`ObsTransform::NormalizeToBounds`] in `spec` with
+/// `Normalize` over the field's declared bounds.
+fn resolve_bounds(spec: &ObsSpec, fields: Option<&[FieldDef]>) -> Result<ObsSpec, ObsError> {
+    let mut resolved = spec.clone();
+    for (i, entry) in resolved.entries.iter_mut().enumerate() {
+        if entry.transform != ObsTransform::NormalizeToBounds {
+            continue;
+        }
+        let invalid = |reason: String| ObsError::InvalidObsSpec {
+            reason: format!("entry {i}: NormalizeToBounds {reason}"),
+        };
+        let fields = fields.ok_or_else(|| {
+            invalid("needs field definitions; compile with ObsPlan::compile_with_fields".into())
+        })?;
+        let def = fields
+            .get(entry.field_id.0 as usize)
+            .ok_or_else(|| invalid(format!("field {:?} is not defined", entry.field_id)))?;
+        let (min, max) = match &def.bounds {
+            None => {
+                return Err(invalid(format!(
+                    "field '{}' has no declared bounds",
+                    def.name
+                )))
+            }
+            Some(FieldBounds::Uniform(min, max)) => (*min, *max),
+            Some(FieldBounds::PerComponent(ranges)) => match ranges.split_first() {
+                Some((&first, rest)) if rest.iter().all(|&r| r == first) => first,
+                _ => {
+                    return Err(invalid(format!(
+                        "field '{}' has per-component bounds that differ",
+                        def.name
+                    )))
+                }
+            },
+        };
+        entry.transform = ObsTransform::Normalize {
+            min: min as f64,
+            max: max as f64,
+        };
+    }
+    Ok(resolved)
+}
+
 /// Apply a transform to a raw field value.
-fn apply_transform(raw: f32, transform: &ObsTransform) -> f32 {
+fn apply_transform(raw: Scalar, transform: &ObsTransform) -> f32 {
     match transform {
-        ObsTransform::Identity => raw,
+        ObsTransform::Identity => scalar_to_f32(raw),
         ObsTransform::Normalize { min, max } => {
             let range = max - min;
             if range == 0.0 {
                 0.0
             } else {
-                let normalized = (raw as f64 - min) / range;
+                let normalized = (scalar_to_f64(raw) - min) / range;
                 normalized.clamp(0.0, 1.0) as f32
             }
         }
+        ObsTransform::NormalizeToBounds => {
+            unreachable!("NormalizeToBounds is resolved at compile time")
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::spec::{
-        ObsDtype, ObsEntry, ObsRegion, ObsSpec, ObsTransform, PoolConfig, PoolKernel,
-    };
-    use murk_core::{FieldId, ParameterVersion, TickId, WorldGenerationId};
-    use murk_space::{EdgeBehavior, Hex2D, RegionSpec, Square4, Square8};
-    use murk_test_utils::MockSnapshot;
+// ── Serialization ──────────────────────────────────────────────
+//
+// Wire format (all integers little-endian, `usize` as u64):
+//
+// [4 bytes] magic "MOBP"
+// [2 bytes] version (u16)
+// [8 bytes] space digest
+// [8 bytes] output_len, [8 bytes] mask_len
+// [vec]     entry_offsets as (start, len) pairs
+// [1 byte]  strategy (0 = Simple, 1 = Standard)
+// Simple:   [8 bytes] total_elements, [vec] compiled entries
+// Standard: [vec] fixed compiled entries, [vec] agent entries,
+//           [vec] position entries (transform, output/mask offsets)
+//
+// Every `[vec]` is a u64 length followed by its elements.
+
+/// Magic bytes identifying a serialized [`ObsPlan`].
+const PLAN_MAGIC: &[u8; 4] = b"MOBP";
+/// Serialized plan format version.
+const PLAN_VERSION: u16 = 2;
+
+const STRATEGY_SIMPLE: u8 = 0;
+const STRATEGY_STANDARD: u8 = 1;
+
+const TRANSFORM_IDENTITY: u8 = 0;
+const TRANSFORM_NORMALIZE: u8 = 1;
+
+const POOL_NONE: u8 = 0;
+const POOL_MEAN: u8 = 1;
+const POOL_MAX: u8 = 2;
+const POOL_MIN: u8 = 3;
+const POOL_SUM: u8 = 4;
 
-    fn square4_space() -> Square4 {
-        Square4::new(3, 3, EdgeBehavior::Absorb).unwrap()
+impl ObsPlan {
+    /// Serialize the compiled plan for reuse in another process.
+    ///
+    /// The bytes carry the pre-computed gather and template operations,
+    /// so a worker can [`deserialize`](Self::deserialize) the plan against
+    /// its own copy of the space instead of recompiling the spec. A digest
+    /// of the compile-time space is included for validation. The
+    /// generation binding is not: deserialized plans are unbound.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(256);
+        buf.extend_from_slice(PLAN_MAGIC);
+        buf.extend_from_slice(&PLAN_VERSION.to_le_bytes());
+        buf.extend_from_slice(&self.space_digest.to_le_bytes());
+        put_usize(&mut buf, self.output_len);
+        put_usize(&mut buf, self.mask_len);
+        buf.extend_from_slice(&self.pad_value.to_bits().to_le_bytes());
+        put_usize(&mut buf, self.entry_offsets.len());
+        for &(start, len) in &self.entry_offsets {
+            put_usize(&mut buf, start);
+            put_usize(&mut buf, len);
+        }
+        match &self.strategy {
+            PlanStrategy::Simple(data) => {
+                buf.push(STRATEGY_SIMPLE);
+                put_usize(&mut buf, data.total_elements);
+                put_usize(&mut buf, data.entries.len());
+                for entry in &data.entries {
+                    write_compiled_entry(&mut buf, entry);
+                }
+            }
+            PlanStrategy::Standard(data) => {
+                buf.push(STRATEGY_STANDARD);
+                put_usize(&mut buf, data.fixed_entries.len());
+                for entry in &data.fixed_entries {
+                    write_compiled_entry(&mut buf, entry);
+                }
+                put_usize(&mut buf, data.agent_entries.len());
+                for entry in &data.agent_entries {
+                    write_agent_entry(&mut buf, entry);
+                }
+                put_usize(&mut buf, data.position_entries.len());
+                for entry in &data.position_entries {
+                    write_transform(&mut buf, &entry.transform);
+                    put_usize(&mut buf, entry.output_offset);
+                    put_usize(&mut buf, entry.mask_offset);
+                }
+            }
+        }
+        buf
     }
 
-    fn snapshot_with_field(field: FieldId, data: Vec<f32>) -> MockSnapshot {
-        let mut snap = MockSnapshot::new(TickId(5), WorldGenerationId(1), ParameterVersion(0));
-        snap.set_field(field, data);
-        snap
-    }
+    /// Reconstruct a plan produced by [`serialize`](Self::serialize).
+    ///
+    /// `space` must have the same topology as the space the plan was
+    /// compiled against. Grid geometry for the interior fast path is
+    /// recomputed from it. Every offset and gather index is
+    /// bounds-checked, so a plan that deserializes successfully cannot
+    /// index outside its buffers at execution time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObsError::InvalidObsSpec`] if the bytes are truncated,
+    /// malformed, or of an unsupported version, or if `space` does not
+    /// match the compile-time space.
+    pub fn deserialize(bytes: &[u8], space: &dyn Space) -> Result<ObsPlan, ObsError> {
+        let mut r = Reader::new(bytes);
+
+        let magic = r.read_bytes(4)?;
+        if magic != PLAN_MAGIC {
+            return Err(malformed(format!("invalid plan magic {magic:?}")));
+        }
+        let version = r.read_u16()?;
+        if version != PLAN_VERSION {
+            return Err(malformed(format!(
+                "unsupported plan version {version} (expected {PLAN_VERSION})"
+            )));
+        }
+        let digest = r.read_u64()?;
+        let canonical = space.canonical_ordering();
+        if digest != space_digest(space, &canonical) {
+            return Err(malformed(
+                "plan was compiled for a different space topology".into(),
+            ));
+        }
+        let cell_count = canonical.len();
+
+        let output_len = read_usize(&mut r)?;
+        let mask_len = read_usize(&mut r)?;
+        let pad_value = f32::from_bits(r.read_u32()?);
+        let n_offsets = read_len(&mut r, 16)?;
+        let mut entry_offsets = Vec::with_capacity(n_offsets);
+        for _ in 0..n_offsets {
+            let start = read_usize(&mut r)?;
+            let len = read_usize(&mut r)?;
+            check_span(start, len, output_len, "entry offset")?;
+            entry_offsets.push((start, len));
+        }
 
-    // ── Compilation tests ────────────────────────────────────
+        let bounds = PlanBounds {
+            output_len,
+            mask_len,
+            cell_count,
+            ndim: space.ndim(),
+        };
+        let strategy = match r.read_u8()? {
+            STRATEGY_SIMPLE => {
+                let total_elements = read_usize(&mut r)?;
+                let entries = read_compiled_entries(&mut r, &bounds)?;
+                PlanStrategy::Simple(SimplePlanData {
+                    entries,
+                    total_elements,
+                })
+            }
+            STRATEGY_STANDARD => {
+                let geometry = GridGeometry::from_space(space);
+                let fixed_entries = read_compiled_entries(&mut r, &bounds)?;
+                let n_agent = read_len(&mut r, 1)?;
+                let mut agent_entries = Vec::with_capacity(n_agent);
+                for _ in 0..n_agent {
+                    agent_entries.push(read_agent_entry(&mut r, &bounds, geometry.as_ref())?);
+                }
+                let n_position = read_len(&mut r, 17)?;
+                let mut position_entries = Vec::with_capacity(n_position);
+                for _ in 0..n_position {
+                    let transform = read_transform(&mut r)?;
+                    let output_offset = read_usize(&mut r)?;
+                    let mask_offset = read_usize(&mut r)?;
+                    check_span(output_offset, bounds.ndim, output_len, "position output")?;
+                    check_span(mask_offset, bounds.ndim, mask_len, "position mask")?;
+                    position_entries.push(PositionEntry {
+                        transform,
+                        output_offset,
+                        mask_offset,
+                    });
+                }
+                let axis_bounds = if position_entries.is_empty() {
+                    Vec::new()
+                } else {
+                    axis_bounds(canonical.iter(), bounds.ndim)
+                };
+                PlanStrategy::Standard(StandardPlanData {
+                    fixed_entries,
+                    agent_entries,
+                    position_entries,
+                    axis_bounds,
+                    geometry,
+                })
+            }
+            other => return Err(malformed(format!("unknown plan strategy {other}"))),
+        };
+        if r.remaining() != 0 {
+            return Err(malformed(format!(
+                "{} trailing bytes after plan",
+                r.remaining()
+            )));
+        }
 
-    #[test]
-    fn compile_empty_spec_errors() {
-        let space = square4_space();
-        let spec = ObsSpec { entries: vec![] };
-        let err = ObsPlan::compile(&spec, &space).unwrap_err();
-        assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
+        Ok(ObsPlan {
+            scratch: ExecScratch::new(&strategy, output_len, mask_len),
+            strategy,
+            output_len,
+            mask_len,
+            entry_offsets,
+            compiled_generation: None,
+            space_digest: digest,
+            pad_value,
+        })
     }
+}
 
-    #[test]
-    fn compile_all_region_square4() {
-        let space = square4_space();
-        let spec = ObsSpec {
-            entries: vec![ObsEntry {
-                field_id: FieldId(0),
-                region: ObsRegion::Fixed(RegionSpec::All),
-                pool: None,
-                transform: ObsTransform::Identity,
-                dtype: ObsDtype::F32,
-            }],
-        };
-        let result = ObsPlan::compile(&spec, &space).unwrap();
-        assert_eq!(result.output_len, 9); // 3x3
-        assert_eq!(result.mask_len, 9);
-        assert_eq!(result.entry_shapes, vec![vec![3, 3]]);
+/// Limits a deserialized plan's indices must respect.
+struct PlanBounds {
+    output_len: usize,
+    mask_len: usize,
+    cell_count: usize,
+    ndim: usize,
+}
+
+/// FNV-1a digest of a space's dimensionality, canonical ordering, and
+/// grid geometry: enough to tell whether a compiled plan's indices and
+/// stride offsets are valid for `space`.
+fn space_digest(space: &dyn Space, canonical: &[Coord]) -> u64 {
+    let mut hash = Fnv1a::new();
+    hash.write(&(space.ndim() as u64).to_le_bytes());
+    hash.write(&(canonical.len() as u64).to_le_bytes());
+    for coord in canonical {
+        for &c in coord {
+            hash.write(&c.to_le_bytes());
+        }
+    }
+    match GridGeometry::from_space(space) {
+        None => hash.write(&[0]),
+        Some(geo) => {
+            hash.write(&[1, geo.all_wrap as u8, geo.connectivity as u8]);
+            for &d in &geo.coord_dims {
+                hash.write(&d.to_le_bytes());
+            }
+            for &s in &geo.coord_strides {
+                hash.write(&(s as u64).to_le_bytes());
+            }
+        }
     }
+    hash.finish()
+}
 
-    #[test]
-    fn compile_rect_region() {
-        let space = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
-        let spec = ObsSpec {
-            entries: vec![ObsEntry {
-                field_id: FieldId(0),
-                region: ObsRegion::Fixed(RegionSpec::Rect {
-                    min: smallvec::smallvec![1, 1],
-                    max: smallvec::smallvec![2, 3],
-                }),
-                pool: None,
-                transform: ObsTransform::Identity,
-                dtype: ObsDtype::F32,
-            }],
-        };
-        let result = ObsPlan::compile(&spec, &space).unwrap();
-        // 2 rows x 3 cols = 6 cells
-        assert_eq!(result.output_len, 6);
-        assert_eq!(result.entry_shapes, vec![vec![2, 3]]);
+fn malformed(reason: String) -> ObsError {
+    ObsError::InvalidObsSpec {
+        reason: format!("serialized plan: {reason}"),
     }
+}
 
-    #[test]
-    fn compile_two_entries_offsets() {
-        let space = square4_space();
-        let spec = ObsSpec {
-            entries: vec![
-                ObsEntry {
-                    field_id: FieldId(0),
-                    region: ObsRegion::Fixed(RegionSpec::All),
-                    pool: None,
-                    transform: ObsTransform::Identity,
-                    dtype: ObsDtype::F32,
-                },
-                ObsEntry {
-                    field_id: FieldId(1),
-                    region: ObsRegion::Fixed(RegionSpec::All),
-                    pool: None,
-                    transform: ObsTransform::Identity,
-                    dtype: ObsDtype::F32,
-                },
-            ],
-        };
-        let result = ObsPlan::compile(&spec, &space).unwrap();
-        assert_eq!(result.output_len, 18); // 9 + 9
-        assert_eq!(result.mask_len, 18);
+fn put_usize(buf: &mut Vec<u8>, v: usize) {
+    buf.extend_from_slice(&(v as u64).to_le_bytes());
+}
+
+fn put_bytes(buf: &mut Vec<u8>, bytes: &[u8]) {
+    put_usize(buf, bytes.len());
+    buf.extend_from_slice(bytes);
+}
+
+fn put_usizes(buf: &mut Vec<u8>, values: &[usize]) {
+    put_usize(buf, values.len());
+    for &v in values {
+        put_usize(buf, v);
     }
+}
 
-    #[test]
+fn write_transform(buf: &mut Vec<u8>, transform: &ObsTransform) {
+    match transform {
+        ObsTransform::Identity => buf.push(TRANSFORM_IDENTITY),
+        ObsTransform::Normalize { min, max } => {
+            buf.push(TRANSFORM_NORMALIZE);
+            buf.extend_from_slice(&min.to_le_bytes());
+            buf.extend_from_slice(&max.to_le_bytes());
+        }
+        ObsTransform::NormalizeToBounds => {
+            unreachable!("NormalizeToBounds is resolved at compile time")
+        }
+    }
+}
+
+fn write_pool(buf: &mut Vec<u8>, pool: &Option<PoolConfig>) {
+    match pool {
+        None => buf.push(POOL_NONE),
+        Some(cfg) => {
+            buf.push(match cfg.kernel {
+                PoolKernel::Mean => POOL_MEAN,
+                PoolKernel::Max => POOL_MAX,
+                PoolKernel::Min => POOL_MIN,
+                PoolKernel::Sum => POOL_SUM,
+            });
+            put_usize(buf, cfg.kernel_size);
+            put_usize(buf, cfg.stride);
+        }
+    }
+}
+
+fn write_dtype(buf: &mut Vec<u8>, dtype: ObsDtype) {
+    match dtype {
+        ObsDtype::F32 => buf.push(0),
+    }
+}
+
+fn write_compiled_entry(buf: &mut Vec<u8>, entry: &CompiledEntry) {
+    buf.extend_from_slice(&entry.field_id.0.to_le_bytes());
+    write_transform(buf, &entry.transform);
+    write_dtype(buf, entry.dtype);
+    put_usize(buf, entry.tick_offset);
+    put_usize(buf, entry.output_offset);
+    put_usize(buf, entry.mask_offset);
+    put_usize(buf, entry.element_count);
+    put_usize(buf, entry.gather_ops.len());
+    for op in &entry.gather_ops {
+        put_usize(buf, op.field_data_idx);
+        put_usize(buf, op.tensor_idx);
+    }
+    put_bytes(buf, &entry.valid_mask);
+    write_pool(buf, &entry.pool);
+    put_usizes(buf, &entry.pre_pool_shape);
+    put_bytes(buf, &entry.pre_pool_mask);
+    put_usize(buf, entry.valid_count);
+    buf.extend_from_slice(&entry.valid_ratio.to_le_bytes());
+}
+
+fn write_agent_entry(buf: &mut Vec<u8>, entry: &AgentCompiledEntry) {
+    buf.extend_from_slice(&entry.field_id.0.to_le_bytes());
+    write_pool(buf, &entry.pool);
+    write_transform(buf, &entry.transform);
+    write_dtype(buf, entry.dtype);
+    put_usize(buf, entry.output_offset);
+    put_usize(buf, entry.mask_offset);
+    put_usize(buf, entry.element_count);
+    put_usize(buf, entry.pre_pool_element_count);
+    put_usizes(buf, &entry.pre_pool_shape);
+    put_usize(buf, entry.active_ops.len());
+    for op in &entry.active_ops {
+        put_usize(buf, op.relative.len());
+        for &c in &op.relative {
+            buf.extend_from_slice(&c.to_le_bytes());
+        }
+        put_usize(buf, op.tensor_idx);
+        buf.extend_from_slice(&(op.stride_offset as i64).to_le_bytes());
+        buf.push(op.in_disk as u8);
+    }
+    buf.extend_from_slice(&entry.radius.to_le_bytes());
+    // Sight lines are rebuilt from the ops on load.
+    match &entry.occlusion {
+        Some(occlusion) => {
+            buf.push(1);
+            buf.extend_from_slice(&occlusion.field_id.0.to_le_bytes());
+            buf.extend_from_slice(&occlusion.threshold.to_bits().to_le_bytes());
+        }
+        None => buf.push(0),
+    }
+}
+
+fn read_usize(r: &mut Reader<'_>) -> Result<usize, ObsError> {
+    let v = r.read_u64()?;
+    usize::try_from(v).map_err(|_| malformed(format!("value {v} exceeds usize::MAX")))
+}
+
+/// Read a collection length, rejecting lengths the remaining bytes
+/// cannot hold at `min_elem_size` bytes per element.
+fn read_len(r: &mut Reader<'_>, min_elem_size: usize) -> Result<usize, ObsError> {
+    let len = read_usize(r)?;
+    if len.saturating_mul(min_elem_size) > r.remaining() {
+        return Err(malformed(format!(
+            "length {len} exceeds the {} remaining bytes",
+            r.remaining()
+        )));
+    }
+    Ok(len)
+}
+
+fn read_byte_vec(r: &mut Reader<'_>) -> Result<Vec<u8>, ObsError> {
+    let len = read_len(r, 1)?;
+    Ok(r.read_bytes(len)?.to_vec())
+}
+
+fn read_usize_vec(r: &mut Reader<'_>) -> Result<Vec<usize>, ObsError> {
+    let len = read_len(r, 8)?;
+    (0..len).map(|_| read_usize(r)).collect()
+}
+
+fn read_transform(r: &mut Reader<'_>) -> Result<ObsTransform, ObsError> {
+    match r.read_u8()? {
+        TRANSFORM_IDENTITY => Ok(ObsTransform::Identity),
+        TRANSFORM_NORMALIZE => {
+            let min = r.read_f64()?;
+            let max = r.read_f64()?;
+            Ok(ObsTransform::Normalize { min, max })
+        }
+        other => Err(malformed(format!("unknown transform type {other}"))),
+    }
+}
+
+fn read_pool(r: &mut Reader<'_>) -> Result<Option<PoolConfig>, ObsError> {
+    let kernel = match r.read_u8()? {
+        POOL_NONE => return Ok(None),
+        POOL_MEAN => PoolKernel::Mean,
+        POOL_MAX => PoolKernel::Max,
+        POOL_MIN => PoolKernel::Min,
+        POOL_SUM => PoolKernel::Sum,
+        other => return Err(malformed(format!("unknown pool kernel {other}"))),
+    };
+    let kernel_size = read_usize(r)?;
+    let stride = read_usize(r)?;
+    if kernel_size == 0 || stride == 0 {
+        return Err(malformed("pool kernel_size and stride must be > 0".into()));
+    }
+    Ok(Some(PoolConfig {
+        kernel,
+        kernel_size,
+        stride,
+    }))
+}
+
+fn read_dtype(r: &mut Reader<'_>) -> Result<ObsDtype, ObsError> {
+    match r.read_u8()? {
+        0 => Ok(ObsDtype::F32),
+        other => Err(malformed(format!("unknown dtype {other}"))),
+    }
+}
+
+/// Check that `start..start + len` lies within `0..limit`.
+fn check_span(start: usize, len: usize, limit: usize, what: &str) -> Result<(), ObsError> {
+    match start.checked_add(len) {
+        Some(end) if end <= limit => Ok(()),
+        _ => Err(malformed(format!(
+            "{what} {start}..+{len} exceeds buffer length {limit}"
+        ))),
+    }
+}
+
+fn read_compiled_entries(
+    r: &mut Reader<'_>,
+    bounds: &PlanBounds,
+) -> Result<Vec<CompiledEntry>, ObsError> {
+    let n = read_len(r, 1)?;
+    let mut entries = Vec::with_capacity(n);
+    for _ in 0..n {
+        entries.push(read_compiled_entry(r, bounds)?);
+    }
+    Ok(entries)
+}
+
+fn read_compiled_entry(r: &mut Reader<'_>, bounds: &PlanBounds) -> Result<CompiledEntry, ObsError> {
+    let field_id = FieldId(r.read_u32()?);
+    let transform = read_transform(r)?;
+    let dtype = read_dtype(r)?;
+    let tick_offset = read_usize(r)?;
+    let output_offset = read_usize(r)?;
+    let mask_offset = read_usize(r)?;
+    let element_count = read_usize(r)?;
+    check_span(
+        output_offset,
+        element_count,
+        bounds.output_len,
+        "entry output",
+    )?;
+    check_span(mask_offset, element_count, bounds.mask_len, "entry mask")?;
+
+    let n_ops = read_len(r, 16)?;
+    let mut gather_ops = Vec::with_capacity(n_ops);
+    for _ in 0..n_ops {
+        gather_ops.push(GatherOp {
+            field_data_idx: read_usize(r)?,
+            tensor_idx: read_usize(r)?,
+        });
+    }
+    let valid_mask = read_byte_vec(r)?;
+    let pool = read_pool(r)?;
+    let pre_pool_shape = read_usize_vec(r)?;
+    let pre_pool_mask = read_byte_vec(r)?;
+    let valid_count = read_usize(r)?;
+    let valid_ratio = r.read_f64()?;
+
+    if valid_mask.len() != element_count {
+        return Err(malformed(format!(
+            "valid mask length {} != element count {element_count}",
+            valid_mask.len()
+        )));
+    }
+    let gather_len = if pool.is_some() {
+        if pre_pool_shape.iter().product::<usize>() != pre_pool_mask.len() {
+            return Err(malformed(format!(
+                "pre-pool shape {pre_pool_shape:?} does not match mask length {}",
+                pre_pool_mask.len()
+            )));
+        }
+        pre_pool_mask.len()
+    } else {
+        element_count
+    };
+    for op in &gather_ops {
+        if op.field_data_idx >= bounds.cell_count || op.tensor_idx >= gather_len {
+            return Err(malformed(format!(
+                "gather op ({}, {}) out of range",
+                op.field_data_idx, op.tensor_idx
+            )));
+        }
+    }
+
+    Ok(CompiledEntry {
+        field_id,
+        transform,
+        dtype,
+        tick_offset,
+        output_offset,
+        mask_offset,
+        element_count,
+        gather_ops,
+        valid_mask,
+        pool,
+        pre_pool_shape,
+        pre_pool_mask,
+        valid_count,
+        valid_ratio,
+    })
+}
+
+fn read_agent_entry(
+    r: &mut Reader<'_>,
+    bounds: &PlanBounds,
+    geometry: Option<&GridGeometry>,
+) -> Result<AgentCompiledEntry, ObsError> {
+    let field_id = FieldId(r.read_u32()?);
+    let pool = read_pool(r)?;
+    let transform = read_transform(r)?;
+    let dtype = read_dtype(r)?;
+    let output_offset = read_usize(r)?;
+    let mask_offset = read_usize(r)?;
+    let element_count = read_usize(r)?;
+    let pre_pool_element_count = read_usize(r)?;
+    check_span(
+        output_offset,
+        element_count,
+        bounds.output_len,
+        "entry output",
+    )?;
+    check_span(mask_offset, element_count, bounds.mask_len, "entry mask")?;
+    let pre_pool_shape = read_usize_vec(r)?;
+    if pre_pool_shape.iter().product::<usize>() != pre_pool_element_count {
+        return Err(malformed(format!(
+            "pre-pool shape {pre_pool_shape:?} does not match element count \
+             {pre_pool_element_count}"
+        )));
+    }
+    if pool.is_none() && element_count != pre_pool_element_count {
+        return Err(malformed(format!(
+            "unpooled entry has {element_count} outputs for \
+             {pre_pool_element_count} gathered cells"
+        )));
+    }
+
+    let n_ops = read_len(r, 1)?;
+    let mut active_ops = Vec::with_capacity(n_ops);
+    for _ in 0..n_ops {
+        let n_rel = read_len(r, 4)?;
+        if n_rel != bounds.ndim {
+            return Err(malformed(format!(
+                "template op has {n_rel} axes, space has {}",
+                bounds.ndim
+            )));
+        }
+        let relative = (0..n_rel)
+            .map(|_| r.read_i32())
+            .collect::<Result<Coord, _>>()?;
+        let tensor_idx = read_usize(r)?;
+        if tensor_idx >= pre_pool_element_count {
+            return Err(malformed(format!(
+                "template op tensor index {tensor_idx} out of range"
+            )));
+        }
+        let stride_offset = r.read_u64()? as i64;
+        let stride_offset = isize::try_from(stride_offset)
+            .map_err(|_| malformed(format!("stride offset {stride_offset} out of range")))?;
+        let in_disk = r.read_u8()? != 0;
+        active_ops.push(TemplateOp {
+            relative,
+            tensor_idx,
+            stride_offset,
+            in_disk,
+        });
+    }
+    let radius = r.read_u32()?;
+    let occlusion = match r.read_u8()? {
+        0 => None,
+        1 => {
+            let field_id = FieldId(r.read_u32()?);
+            let threshold = f32::from_bits(r.read_u32()?);
+            let geo =
+                geometry.ok_or_else(|| malformed("occluded entry on a non-grid space".into()))?;
+            Some(OcclusionTemplate {
+                field_id,
+                threshold,
+                lines: sight_lines(geo, &active_ops),
+            })
+        }
+        other => return Err(malformed(format!("unknown occlusion tag {other}"))),
+    };
+
+    Ok(AgentCompiledEntry {
+        field_id,
+        pool,
+        transform,
+        dtype,
+        output_offset,
+        mask_offset,
+        element_count,
+        pre_pool_element_count,
+        pre_pool_shape,
+        active_ops,
+        radius,
+        occlusion,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::{
+        ObsDtype, ObsEntry, ObsRegion, ObsSpec, ObsTransform, Occlusion, PoolConfig, PoolKernel,
+    };
+    use murk_core::{FieldId, ParameterVersion, TickId, WorldGenerationId};
+    use murk_space::{EdgeBehavior, Hex2D, Line1D, Square4, Square8};
+    use murk_test_utils::MockSnapshot;
+
+    fn square4_space() -> Square4 {
+        Square4::new(3, 3, EdgeBehavior::Absorb).unwrap()
+    }
+
+    fn snapshot_with_field(field: FieldId, data: Vec<Scalar>) -> MockSnapshot {
+        let mut snap = MockSnapshot::new(TickId(5), WorldGenerationId(1), ParameterVersion(0));
+        snap.set_field(field, data);
+        snap
+    }
+
+    // ── Compilation tests ────────────────────────────────────
+
+    #[test]
+    fn compile_empty_spec_errors() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![],
+            pad_value: 0.0,
+        };
+        let err = ObsPlan::compile(&spec, &space).unwrap_err();
+        assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
+    }
+
+    #[test]
+    fn compile_all_region_square4() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.output_len, 9); // 3x3
+        assert_eq!(result.mask_len, 9);
+        assert_eq!(result.entry_shapes, vec![vec![3, 3]]);
+    }
+
+    #[test]
+    fn compile_rect_region() {
+        let space = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::Rect {
+                    min: smallvec::smallvec![1, 1],
+                    max: smallvec::smallvec![2, 3],
+                }),
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        // 2 rows x 3 cols = 6 cells
+        assert_eq!(result.output_len, 6);
+        assert_eq!(result.entry_shapes, vec![vec![2, 3]]);
+    }
+
+    #[test]
+    fn compile_cached_shares_region_plans() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let cache = RegionPlanCache::new();
+        let a = ObsPlan::compile_cached(&spec, &space, &cache).unwrap();
+        let b = ObsPlan::compile_cached(&spec, &space, &cache).unwrap();
+        assert_eq!(cache.len(), 1);
+
+        let data: Vec<Scalar> = (1..=9).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+        let (mut out_a, mut mask_a) = (vec![0.0; a.output_len], vec![0u8; a.mask_len]);
+        let (mut out_b, mut mask_b) = (vec![0.0; b.output_len], vec![0u8; b.mask_len]);
+        a.plan
+            .execute(&snap, None, &mut out_a, &mut mask_a)
+            .unwrap();
+        b.plan
+            .execute(&snap, None, &mut out_b, &mut mask_b)
+            .unwrap();
+        assert_eq!(out_a, out_b);
+        assert_eq!(mask_a, vec![1u8; 9]);
+        assert_eq!(mask_a, mask_b);
+    }
+
+    #[test]
+    fn compile_two_entries_offsets() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::Fixed(RegionSpec::All),
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+                ObsEntry {
+                    field_id: FieldId(1),
+                    region: ObsRegion::Fixed(RegionSpec::All),
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+            ],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.output_len, 18); // 9 + 9
+        assert_eq!(result.mask_len, 18);
+    }
+
+    #[test]
+    fn entry_slice_returns_each_entry_block() {
+        let space = square4_space();
+        let entry = |field: u32, region: RegionSpec| ObsEntry {
+            field_id: FieldId(field),
+            region: ObsRegion::Fixed(region),
+            pool: None,
+            transform: ObsTransform::Identity,
+            dtype: ObsDtype::F32,
+            tick_offset: 0,
+        };
+        let spec = ObsSpec {
+            entries: vec![
+                entry(0, RegionSpec::All),
+                entry(
+                    1,
+                    RegionSpec::Rect {
+                        min: smallvec::smallvec![0, 0],
+                        max: smallvec::smallvec![0, 2],
+                    },
+                ),
+                entry(
+                    2,
+                    RegionSpec::Disk {
+                        center: smallvec::smallvec![1, 1],
+                        radius: 1,
+                    },
+                ),
+            ],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.entry_offsets, vec![(0, 9), (9, 3), (12, 5)]);
+        assert_eq!(result.plan.entry_offsets(), &result.entry_offsets[..]);
+        let mut next = 0;
+        for &(start, len) in &result.entry_offsets {
+            assert_eq!(start, next);
+            next += len;
+        }
+        assert_eq!(next, result.output_len);
+
+        // All-F32 spec: byte layout is the element layout scaled by 4.
+        assert_eq!(result.output_bytes, 4 * result.output_len);
+        assert_eq!(result.entry_byte_offsets, vec![(0, 36), (36, 12), (48, 20)]);
+
+        let mut snap = snapshot_with_field(FieldId(0), (0..9).map(|x| x as Scalar).collect());
+        snap.set_field(FieldId(1), (100..109).map(|x| x as Scalar).collect());
+        snap.set_field(FieldId(2), (200..209).map(|x| x as Scalar).collect());
+        let mut output = vec![0.0; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        result
+            .plan
+            .execute(&snap, None, &mut output, &mut mask)
+            .unwrap();
+
+        let plan = &result.plan;
+        assert_eq!(plan.entry_slice(&output, 0), &output[..9]);
+        assert_eq!(plan.entry_slice(&output, 1), &[100.0, 101.0, 102.0]);
+        assert_eq!(
+            plan.entry_slice(&output, 2),
+            &[201.0, 203.0, 204.0, 205.0, 207.0]
+        );
+    }
+
+    #[test]
     fn compile_invalid_region_errors() {
         let space = square4_space();
         let spec = ObsSpec {
@@ -1459,7 +3148,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let err = ObsPlan::compile(&spec, &space).unwrap_err();
         assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
@@ -1473,11 +3164,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![1], // 1D on 2D space
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let err = ObsPlan::compile(&spec, &space).unwrap_err();
         assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
@@ -1491,11 +3185,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![1, 2], // 2D on 2D space
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert!(ObsPlan::compile(&spec, &space).is_ok());
     }
@@ -1507,7 +3204,7 @@ mod tests {
         let space = square4_space();
         // Field data in canonical (row-major) order for 3x3:
         // (0,0)=1, (0,1)=2, (0,2)=3, (1,0)=4, ..., (2,2)=9
-        let data: Vec<f32> = (1..=9).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (1..=9).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1517,7 +3214,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1543,7 +3242,7 @@ mod tests {
     fn execute_normalize_transform() {
         let space = square4_space();
         // Values 0..8 mapped to [0,1] with min=0, max=8.
-        let data: Vec<f32> = (0..9).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..9).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1553,7 +3252,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Normalize { min: 0.0, max: 8.0 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1578,7 +3279,7 @@ mod tests {
     fn execute_normalize_clamps_out_of_range() {
         let space = square4_space();
         // Values -5, 0, 5, 10, 15 etc.
-        let data: Vec<f32> = (-4..5).map(|x| x as f32 * 5.0).collect();
+        let data: Vec<Scalar> = (-4..5).map(|x| x as Scalar * 5.0).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1591,7 +3292,9 @@ mod tests {
                     max: 10.0,
                 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1610,7 +3313,7 @@ mod tests {
     #[test]
     fn execute_normalize_zero_range() {
         let space = square4_space();
-        let data = vec![5.0f32; 9];
+        let data = vec![5.0; 9];
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1620,7 +3323,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Normalize { min: 5.0, max: 5.0 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1635,11 +3340,90 @@ mod tests {
         assert!(output.iter().all(|&v| v == 0.0));
     }
 
+    fn heat_def(bounds: Option<FieldBounds>) -> FieldDef {
+        FieldDef {
+            name: "heat".into(),
+            field_type: murk_core::FieldType::Scalar,
+            mutability: murk_core::FieldMutability::PerTick,
+            units: None,
+            bounds,
+            boundary_behavior: murk_core::BoundaryBehavior::Clamp,
+        }
+    }
+
+    fn single_entry_spec(transform: ObsTransform) -> ObsSpec {
+        ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn normalize_to_bounds_matches_explicit_normalize() {
+        let space = square4_space();
+        let data: Vec<Scalar> = (-1..8).map(|x| x as Scalar * 20.0).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+        let fields = [heat_def(Some((0.0, 100.0).into()))];
+
+        let run = |result: ObsPlanResult| {
+            let mut output = vec![0.0f32; result.output_len];
+            let mut mask = vec![0u8; result.mask_len];
+            result
+                .plan
+                .execute(&snap, None, &mut output, &mut mask)
+                .unwrap();
+            output
+        };
+        let from_bounds = ObsPlan::compile_with_fields(
+            &single_entry_spec(ObsTransform::NormalizeToBounds),
+            &space,
+            &fields,
+        )
+        .unwrap();
+        let explicit = ObsPlan::compile(
+            &single_entry_spec(ObsTransform::Normalize {
+                min: 0.0,
+                max: 100.0,
+            }),
+            &space,
+        )
+        .unwrap();
+        assert_eq!(run(from_bounds), run(explicit));
+    }
+
+    #[test]
+    fn normalize_to_bounds_rejects_boundless_field() {
+        let space = square4_space();
+        let spec = single_entry_spec(ObsTransform::NormalizeToBounds);
+
+        let err = ObsPlan::compile_with_fields(&spec, &space, &[heat_def(None)]).unwrap_err();
+        assert!(
+            err.to_string().contains("'heat' has no declared bounds"),
+            "{err}"
+        );
+
+        let uneven = FieldBounds::PerComponent(vec![(0.0, 1.0), (0.0, 2.0)]);
+        let err =
+            ObsPlan::compile_with_fields(&spec, &space, &[heat_def(Some(uneven))]).unwrap_err();
+        assert!(err.to_string().contains("differ"), "{err}");
+
+        // Without field definitions the transform cannot be resolved.
+        let err = ObsPlan::compile(&spec, &space).unwrap_err();
+        assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
+    }
+
     #[test]
     fn execute_rect_subregion_correct_values() {
         let space = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
         // 4x4 field: value = row * 4 + col + 1.
-        let data: Vec<f32> = (1..=16).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (1..=16).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1652,7 +3436,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert_eq!(result.output_len, 4); // 2x2
@@ -1672,8 +3458,8 @@ mod tests {
     #[test]
     fn execute_two_fields() {
         let space = square4_space();
-        let data_a: Vec<f32> = (1..=9).map(|x| x as f32).collect();
-        let data_b: Vec<f32> = (10..=18).map(|x| x as f32).collect();
+        let data_a: Vec<Scalar> = (1..=9).map(|x| x as Scalar).collect();
+        let data_b: Vec<Scalar> = (10..=18).map(|x| x as Scalar).collect();
         let mut snap = MockSnapshot::new(TickId(1), WorldGenerationId(1), ParameterVersion(0));
         snap.set_field(FieldId(0), data_a);
         snap.set_field(FieldId(1), data_b);
@@ -1686,6 +3472,7 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
                 ObsEntry {
                     field_id: FieldId(1),
@@ -1693,8 +3480,10 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
             ],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert_eq!(result.output_len, 18);
@@ -1725,7 +3514,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1741,7 +3532,7 @@ mod tests {
     #[test]
     fn execute_buffer_too_small_errors() {
         let space = square4_space();
-        let data: Vec<f32> = vec![0.0; 9];
+        let data: Vec<Scalar> = vec![0.0; 9];
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1751,7 +3542,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1764,12 +3557,271 @@ mod tests {
         assert!(matches!(err, ObsError::ExecutionFailed { .. }));
     }
 
-    // ── Validity / coverage tests ────────────────────────────
-
-    #[test]
-    fn valid_ratio_one_for_square_all() {
+    fn all_region_spec() -> ObsSpec {
+        ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn execute_delta_static_field_is_zero() {
+        let space = square4_space();
+        let data: Vec<Scalar> = (1..=9).map(|x| x as Scalar).collect();
+        let prev = snapshot_with_field(FieldId(0), data.clone());
+        let curr = snapshot_with_field(FieldId(0), data);
+        let result = ObsPlan::compile(&all_region_spec(), &space).unwrap();
+
+        let mut output = vec![f32::NAN; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        let meta = result
+            .plan
+            .execute_delta(&curr, &prev, &mut output, &mut mask)
+            .unwrap();
+        assert_eq!(output, vec![0.0; 9]);
+        assert_eq!(mask, vec![1u8; 9]);
+        assert_eq!(meta.valid_count, 9);
+    }
+
+    #[test]
+    fn execute_delta_single_changed_cell() {
+        let space = square4_space();
+        let data: Vec<Scalar> = (1..=9).map(|x| x as Scalar).collect();
+        let prev = snapshot_with_field(FieldId(0), data.clone());
+        let mut changed = data;
+        changed[4] += 2.5; // cell (1,1)
+        let curr = snapshot_with_field(FieldId(0), changed);
+        let result = ObsPlan::compile(&all_region_spec(), &space).unwrap();
+
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        result
+            .plan
+            .execute_delta(&curr, &prev, &mut output, &mut mask)
+            .unwrap();
+        for (i, &v) in output.iter().enumerate() {
+            let expected = if i == 4 { 2.5 } else { 0.0 };
+            assert_eq!(v, expected, "tensor index {i}");
+        }
+    }
+
+    fn stacked_spec(offsets: &[usize]) -> ObsSpec {
+        ObsSpec {
+            entries: offsets
+                .iter()
+                .map(|&tick_offset| ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::Fixed(RegionSpec::All),
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset,
+                })
+                .collect(),
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn execute_history_stacks_current_and_previous_tick() {
+        let space = square4_space();
+        let prev = snapshot_with_field(FieldId(0), vec![1.0; 9]);
+        let curr = snapshot_with_field(FieldId(0), vec![2.0; 9]);
+        let result = ObsPlan::compile(&stacked_spec(&[0, 1]), &space).unwrap();
+
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        let meta = result
+            .plan
+            .execute_history(&[&curr, &prev], None, &mut output, &mut mask)
+            .unwrap();
+        assert_eq!(&output[..9], &[2.0; 9]);
+        assert_eq!(&output[9..], &[1.0; 9]);
+        assert_eq!(mask, vec![1u8; 18]);
+        assert_eq!(meta.valid_count, 18);
+    }
+
+    #[test]
+    fn execute_history_offset_beyond_history_is_masked() {
+        let space = square4_space();
+        let curr = snapshot_with_field(FieldId(0), vec![2.0; 9]);
+        let result = ObsPlan::compile(&stacked_spec(&[0, 3]), &space).unwrap();
+
+        let mut output = vec![f32::NAN; result.output_len];
+        let mut mask = vec![1u8; result.mask_len];
+        let meta = result
+            .plan
+            .execute_history(&[&curr], None, &mut output, &mut mask)
+            .unwrap();
+        assert_eq!(&output[..9], &[2.0; 9]);
+        assert_eq!(&output[9..], &[0.0; 9]);
+        assert_eq!(&mask[..9], &[1u8; 9]);
+        assert_eq!(&mask[9..], &[0u8; 9]);
+        assert_eq!(meta.valid_count, 9);
+        assert!((meta.coverage - 0.5).abs() < 1e-12);
+
+        let err = result
+            .plan
+            .execute_history(&[], None, &mut output, &mut mask)
+            .unwrap_err();
+        assert!(matches!(err, ObsError::ExecutionFailed { .. }));
+    }
+
+    #[test]
+    fn estimated_bytes_for_simple_plan_ignores_agents() {
+        let space = square4_space();
+        let result = ObsPlan::compile(&all_region_spec(), &space).unwrap();
+        let per_env = result.output_len * 4 + result.mask_len;
+        assert_eq!(result.estimated_bytes(1, 8), 8 * per_env);
+        assert_eq!(result.estimated_bytes(16, 8), 8 * per_env);
+        assert_eq!(result.estimated_bytes(16, 0), 0);
+    }
+
+    #[test]
+    fn estimated_bytes_for_standard_plan_scales_by_agents() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentDisk {
+                    radius: 1,
+                    metric: None,
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        let per_agent = result.output_len * 4 + result.mask_len;
+        assert_eq!(result.estimated_bytes(1, 1), per_agent);
+        assert_eq!(result.estimated_bytes(3, 1), 3 * per_agent);
+        assert_eq!(result.estimated_bytes(3, 4), 12 * per_agent);
+        assert_eq!(result.estimated_bytes(0, 4), 0);
+    }
+
+    #[test]
+    fn tick_offset_rejected_in_agent_plans() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentDisk {
+                    radius: 1,
+                    metric: None,
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 1,
+            }],
+            pad_value: 0.0,
+        };
+        let err = ObsPlan::compile(&spec, &space).unwrap_err();
+        assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
+    }
+
+    #[test]
+    fn column_major_space_yields_transposed_tensor() {
+        use murk_space::MemoryOrder;
+
+        let row = Square4::new(3, 4, EdgeBehavior::Absorb).unwrap();
+        let col = Square4::new(3, 4, EdgeBehavior::Absorb)
+            .unwrap()
+            .with_memory_order(MemoryOrder::ColumnMajor);
+        // Same logical field value(r, c) = 10r + c, stored in each
+        // space's canonical order.
+        let field = |space: &Square4| -> Vec<Scalar> {
+            space
+                .canonical_ordering()
+                .iter()
+                .map(|co| (co[0] * 10 + co[1]) as Scalar)
+                .collect()
+        };
+
+        let mut outputs = Vec::new();
+        for space in [&row, &col] {
+            let snap = snapshot_with_field(FieldId(0), field(space));
+            let result = ObsPlan::compile(&all_region_spec(), space).unwrap();
+            assert_eq!(result.entry_shapes, vec![vec![3, 4]]);
+            let mut output = vec![0.0f32; result.output_len];
+            let mut mask = vec![0u8; result.mask_len];
+            result
+                .plan
+                .execute(&snap, None, &mut output, &mut mask)
+                .unwrap();
+            outputs.push(output);
+        }
+        for r in 0..3 {
+            for c in 0..4 {
+                assert_eq!(outputs[0][r * 4 + c], (r * 10 + c) as f32);
+                assert_eq!(outputs[1][c * 3 + r], outputs[0][r * 4 + c]);
+            }
+        }
+    }
+
+    #[test]
+    fn column_major_agent_patch_matches_row_major() {
+        use murk_space::MemoryOrder;
+
+        let row = Square4::new(8, 8, EdgeBehavior::Absorb).unwrap();
+        let col = Square4::new(8, 8, EdgeBehavior::Absorb)
+            .unwrap()
+            .with_memory_order(MemoryOrder::ColumnMajor);
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        // Interior and boundary agents exercise both gather paths.
+        let centers: Vec<Coord> = vec![smallvec::smallvec![4, 3], smallvec::smallvec![0, 7]];
+
+        let mut outputs = Vec::new();
+        for space in [&row, &col] {
+            let data: Vec<Scalar> = space
+                .canonical_ordering()
+                .iter()
+                .map(|co| (co[0] * 10 + co[1]) as Scalar)
+                .collect();
+            let snap = snapshot_with_field(FieldId(0), data);
+            let result = ObsPlan::compile(&spec, space).unwrap();
+            let mut output = vec![0.0f32; result.output_len * 2];
+            let mut mask = vec![0u8; result.mask_len * 2];
+            result
+                .plan
+                .execute_agents(&snap, space, &centers, None, &mut output, &mut mask)
+                .unwrap();
+            outputs.push((output, mask));
+        }
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    // ── Validity / coverage tests ────────────────────────────
+
+    #[test]
+    fn valid_ratio_one_for_square_all() {
         let space = square4_space();
-        let data: Vec<f32> = vec![1.0; 9];
+        let data: Vec<Scalar> = vec![1.0; 9];
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1779,7 +3831,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1798,7 +3852,7 @@ mod tests {
     #[test]
     fn plan_invalidated_on_generation_mismatch() {
         let space = square4_space();
-        let data: Vec<f32> = vec![1.0; 9];
+        let data: Vec<Scalar> = vec![1.0; 9];
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1808,7 +3862,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         // Compile bound to generation 99, but snapshot is generation 1.
         let result = ObsPlan::compile_bound(&spec, &space, WorldGenerationId(99)).unwrap();
@@ -1825,7 +3881,7 @@ mod tests {
     #[test]
     fn generation_match_succeeds() {
         let space = square4_space();
-        let data: Vec<f32> = vec![1.0; 9];
+        let data: Vec<Scalar> = vec![1.0; 9];
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1835,7 +3891,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile_bound(&spec, &space, WorldGenerationId(1)).unwrap();
 
@@ -1850,7 +3908,7 @@ mod tests {
     #[test]
     fn unbound_plan_ignores_generation() {
         let space = square4_space();
-        let data: Vec<f32> = vec![1.0; 9];
+        let data: Vec<Scalar> = vec![1.0; 9];
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -1860,7 +3918,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         // Unbound plan — no generation check.
         let result = ObsPlan::compile(&spec, &space).unwrap();
@@ -1878,7 +3938,7 @@ mod tests {
     #[test]
     fn metadata_fields_populated() {
         let space = square4_space();
-        let data: Vec<f32> = vec![1.0; 9];
+        let data: Vec<Scalar> = vec![1.0; 9];
         let mut snap = MockSnapshot::new(TickId(42), WorldGenerationId(7), ParameterVersion(3));
         snap.set_field(FieldId(0), data);
 
@@ -1889,7 +3949,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1912,7 +3974,7 @@ mod tests {
     #[test]
     fn execute_batch_n1_matches_execute() {
         let space = square4_space();
-        let data: Vec<f32> = (1..=9).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (1..=9).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data.clone());
 
         let spec = ObsSpec {
@@ -1922,7 +3984,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1958,7 +4022,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -1988,7 +4054,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -2013,7 +4081,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         // Compile bound to generation 5.
         let result = ObsPlan::compile_bound(&spec, &space, WorldGenerationId(5)).unwrap();
@@ -2030,6 +4100,80 @@ mod tests {
         assert!(matches!(err, ObsError::PlanInvalidated { .. }));
     }
 
+    #[test]
+    fn execute_batch_lenient_zero_fills_stale_snapshot() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile_bound(&spec, &space, WorldGenerationId(1)).unwrap();
+
+        let snap_a = snapshot_with_field(FieldId(0), vec![3.0; 9]);
+        let mut snap_stale =
+            MockSnapshot::new(TickId(5), WorldGenerationId(99), ParameterVersion(0));
+        snap_stale.set_field(FieldId(0), vec![5.0; 9]);
+        let snap_c = snapshot_with_field(FieldId(0), vec![7.0; 9]);
+
+        let snaps: Vec<&dyn SnapshotAccess> = vec![&snap_a, &snap_stale, &snap_c];
+        // Pre-fill with garbage to prove the stale slice is overwritten.
+        let mut output = vec![-1.0f32; result.output_len * 3];
+        let mut mask = vec![9u8; result.mask_len * 3];
+        let meta = result
+            .plan
+            .execute_batch_lenient(&snaps, None, &mut output, &mut mask)
+            .unwrap();
+
+        assert_eq!(meta.len(), 3);
+        assert!(output[..9].iter().all(|&v| v == 3.0));
+        assert!(output[9..18].iter().all(|&v| v == 0.0));
+        assert!(output[18..].iter().all(|&v| v == 7.0));
+        assert!(mask[..9].iter().all(|&m| m == 1));
+        assert!(mask[9..18].iter().all(|&m| m == 0));
+        assert!(mask[18..].iter().all(|&m| m == 1));
+
+        assert!(!meta[0].stale && !meta[2].stale);
+        assert!(meta[1].stale);
+        assert_eq!(meta[1].valid_count, 0);
+        assert_eq!(meta[1].coverage, 0.0);
+        assert_eq!(meta[1].world_generation_id, WorldGenerationId(99));
+        assert_eq!(meta[2].valid_count, 9);
+    }
+
+    #[test]
+    fn execute_batch_lenient_pads_stale_snapshot_with_pad_value() {
+        let space = square4_space();
+        let spec = ObsSpec {
+            pad_value: -2.5,
+            ..single_entry_spec(ObsTransform::Identity)
+        };
+        let result = ObsPlan::compile_bound(&spec, &space, WorldGenerationId(1)).unwrap();
+
+        let fresh = snapshot_with_field(FieldId(0), vec![3.0; 9]);
+        let mut stale = MockSnapshot::new(TickId(5), WorldGenerationId(99), ParameterVersion(0));
+        stale.set_field(FieldId(0), vec![5.0; 9]);
+
+        let snaps: Vec<&dyn SnapshotAccess> = vec![&fresh, &stale];
+        let mut output = vec![0.0f32; result.output_len * 2];
+        let mut mask = vec![9u8; result.mask_len * 2];
+        let meta = result
+            .plan
+            .execute_batch_lenient(&snaps, None, &mut output, &mut mask)
+            .unwrap();
+
+        assert!(meta[1].stale);
+        assert!(output[..9].iter().all(|&v| v == 3.0));
+        assert!(output[9..].iter().all(|&v| v == -2.5));
+        assert!(mask[9..].iter().all(|&m| m == 0));
+    }
+
     // ── Field length mismatch tests ──────────────────────────
 
     #[test]
@@ -2042,7 +4186,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -2067,11 +4213,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert!(result.plan.is_standard());
@@ -2083,17 +4232,23 @@ mod tests {
     #[test]
     fn execute_on_standard_plan_errors() {
         let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
-                region: ObsRegion::AgentDisk { radius: 2 },
+                region: ObsRegion::AgentDisk {
+                    radius: 2,
+                    metric: None,
+                    occlusion: None,
+                },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -2111,7 +4266,7 @@ mod tests {
         // An INTERIOR agent using Standard plan should produce identical
         // output to a Simple plan with an explicit Rect at the same position.
         let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..400).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let radius = 3u32;
@@ -2123,11 +4278,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![radius, radius],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let std_result = ObsPlan::compile(&standard_spec, &space).unwrap();
         let mut std_output = vec![0.0f32; std_result.output_len];
@@ -2156,7 +4314,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let simple_result = ObsPlan::compile(&simple_spec, &space).unwrap();
         let mut simple_output = vec![0.0f32; simple_result.output_len];
@@ -2176,7 +4336,7 @@ mod tests {
     fn boundary_agent_gets_padding() {
         // Agent at corner (0,0) with radius 2: many cells out-of-bounds.
         let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32 + 1.0).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar + 1.0).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -2184,11 +4344,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         let center: Coord = smallvec::smallvec![0, 0];
@@ -2218,117 +4381,374 @@ mod tests {
         assert_eq!(output[12], 1.0);
     }
 
-    #[test]
-    fn hex_foveation_interior() {
-        // Test agent-centered observation on Hex2D grid.
-        let space = Hex2D::new(20, 20).unwrap(); // 20 rows, 20 cols
-        let data: Vec<f32> = (0..400).map(|x| x as f32).collect();
-        let snap = snapshot_with_field(FieldId(0), data);
+    /// Observe a radius-3 rect around agents at (5,5) (interior fast path)
+    /// and (0,0) (boundary slow path) on a 10x10 `Square4`, occluded by
+    /// field 1 with walls at the given ranks.
+    fn occluded_obs(walls: &[usize]) -> (ObsPlanResult, Vec<f32>, Vec<u8>) {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let mut snap = snapshot_with_field(FieldId(0), (0..100).map(|x| x as Scalar).collect());
+        let mut opacity = vec![0.0; 100];
+        for &w in walls {
+            opacity[w] = 1.0;
+        }
+        snap.set_field(FieldId(1), opacity);
 
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
-                region: ObsRegion::AgentDisk { radius: 2 },
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec::smallvec![3, 3],
+                    occlusion: Some(Occlusion {
+                        field_id: FieldId(1),
+                        threshold: 0.5,
+                    }),
+                },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
-        assert_eq!(result.output_len, 25); // 5x5 bounding box (tensor shape)
-
-        // Interior agent: q=10, r=10
-        let center: Coord = smallvec::smallvec![10, 10];
-        let mut output = vec![0.0f32; result.output_len];
-        let mut mask = vec![0u8; result.mask_len];
+        let centers: Vec<Coord> = vec![smallvec::smallvec![5, 5], smallvec::smallvec![0, 0]];
+        let mut output = vec![0.0f32; 2 * result.output_len];
+        let mut mask = vec![0u8; 2 * result.mask_len];
         result
             .plan
-            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .execute_agents(&snap, &space, &centers, None, &mut output, &mut mask)
             .unwrap();
+        (result, output, mask)
+    }
 
-        // Hex disk of radius 2: 19 of 25 cells are within hex distance.
-        // The 6 corners of the 5x5 bounding box exceed hex distance 2.
-        // Hex distance = max(|dq|, |dr|, |dq+dr|) for axial coordinates.
-        let valid_count = mask.iter().filter(|&&v| v == 1).count();
-        assert_eq!(valid_count, 19);
-
-        // Corners that should be masked out (distance > 2):
-        // tensor_idx 0: dq=-2,dr=-2 → max(2,2,4)=4
-        // tensor_idx 1: dq=-2,dr=-1 → max(2,1,3)=3
-        // tensor_idx 5: dq=-1,dr=-2 → max(1,2,3)=3
-        // tensor_idx 19: dq=+1,dr=+2 → max(1,2,3)=3
-        // tensor_idx 23: dq=+2,dr=+1 → max(2,1,3)=3
-        // tensor_idx 24: dq=+2,dr=+2 → max(2,2,4)=4
-        for &idx in &[0, 1, 5, 19, 23, 24] {
-            assert_eq!(mask[idx], 0, "tensor_idx {idx} should be outside hex disk");
-            assert_eq!(output[idx], 0.0, "tensor_idx {idx} should be zero-padded");
-        }
-
-        // Center cell is at tensor_idx = 2*5+2 = 12 (relative [0,0]).
-        // Hex2D canonical_rank([q,r]) = r*cols + q = 10*20 + 10 = 210
-        assert_eq!(output[12], 210.0);
+    #[test]
+    fn occlusion_masks_cells_behind_a_wall() {
+        // Walls at (5,6) and (0,1), one step east of each agent.
+        let (result, output, mask) = occluded_obs(&[56, 1]);
+        let n = result.output_len;
+        // Relative (0,+1) is tensor index 3*7+4 = 25, (0,+3) is 27.
+        // The wall cell itself stays visible.
+        assert_eq!(mask[25], 1);
+        assert_eq!(output[25], 56.0);
+        // Cells behind it are hidden, for both agents.
+        assert_eq!(mask[26], 0);
+        assert_eq!(mask[27], 0);
+        assert_eq!(mask[n + 27], 0);
+        // Other directions are unaffected: (0,-3) is 21, (+3,0) is 45.
+        assert_eq!(mask[21], 1);
+        assert_eq!(mask[45], 1);
+        assert_eq!(mask[n + 45], 1);
+    }
 
-        // Cell at relative [1, 0] (dq=+1, dr=0) → absolute [11, 10]
-        // rank = 10*20 + 11 = 211
-        // In row-major bounding box: dim0_idx=3, dim1_idx=2 → tensor_idx = 3*5+2 = 17
-        assert_eq!(output[17], 211.0);
+    #[test]
+    fn removing_the_wall_restores_visibility() {
+        let (result, output, mask) = occluded_obs(&[]);
+        let n = result.output_len;
+        assert_eq!(mask[27], 1);
+        assert_eq!(output[27], 58.0);
+        assert_eq!(mask[n + 27], 1);
+        assert_eq!(output[n + 27], 3.0);
     }
 
     #[test]
-    fn wrap_space_all_interior() {
-        // Wrapped (torus) space: all agents are interior.
-        let space = Square4::new(10, 10, EdgeBehavior::Wrap).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
-        let snap = snapshot_with_field(FieldId(0), data);
+    fn occlusion_survives_serialization() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let (result, _, _) = occluded_obs(&[56]);
+        let plan = ObsPlan::deserialize(&result.plan.serialize(), &space).unwrap();
+        let mut snap = snapshot_with_field(FieldId(0), (0..100).map(|x| x as Scalar).collect());
+        let mut opacity = vec![0.0; 100];
+        opacity[56] = 1.0;
+        snap.set_field(FieldId(1), opacity);
+        let center: Coord = smallvec::smallvec![5, 5];
+        let mut output = vec![0.0f32; plan.output_len()];
+        let mut mask = vec![0u8; plan.mask_len()];
+        plan.execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+        assert_eq!(mask[25], 1);
+        assert_eq!(mask[27], 0);
+    }
 
+    #[test]
+    fn occlusion_requires_grid_space() {
+        let space = Line1D::new(10, EdgeBehavior::Absorb).unwrap();
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
-                    half_extent: smallvec::smallvec![2, 2],
+                    half_extent: smallvec::smallvec![2],
+                    occlusion: Some(Occlusion {
+                        field_id: FieldId(1),
+                        threshold: 0.5,
+                    }),
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
-        let result = ObsPlan::compile(&spec, &space).unwrap();
-
-        // Agent at corner (0,0) — still interior on torus.
-        let center: Coord = smallvec::smallvec![0, 0];
-        let mut output = vec![0.0f32; result.output_len];
-        let mut mask = vec![0u8; result.mask_len];
-        result
-            .plan
-            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
-            .unwrap();
-
-        // All 25 cells valid (torus wraps).
-        assert!(mask.iter().all(|&v| v == 1));
-        assert_eq!(output[12], 0.0); // center (0,0) → rank 0
+        let err = ObsPlan::compile(&spec, &space).unwrap_err();
+        assert!(format!("{err:?}").contains("occlusion requires a grid space"));
     }
 
-    #[test]
-    fn execute_agents_multiple() {
+    /// Run a single corner agent (radius-2 rect, Normalize transform) on a
+    /// 10x10 grid with the given pad value.
+    fn padded_corner_obs(pad_value: f32) -> (Vec<f32>, Vec<u8>) {
         let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar + 1.0).collect();
         let snap = snapshot_with_field(FieldId(0), data);
-
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
-                    half_extent: smallvec::smallvec![1, 1],
+                    half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
                 },
                 pool: None,
-                transform: ObsTransform::Identity,
+                transform: ObsTransform::Normalize {
+                    min: 0.0,
+                    max: 100.0,
+                },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value,
         };
-        let result = ObsPlan::compile(&spec, &space).unwrap();
-        assert_eq!(result.output_len, 9); // 3x3
+        let plan = ObsPlan::compile(&spec, &space).unwrap().plan;
+        assert_eq!(plan.pad_value(), pad_value);
+        let center: Coord = smallvec::smallvec![0, 0];
+        let mut output = vec![0.0f32; plan.output_len()];
+        let mut mask = vec![0u8; plan.mask_len()];
+        plan.execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+        (output, mask)
+    }
 
-        // Two agents: one interior, one at edge.
+    #[test]
+    fn pad_value_fills_boundary_padding() {
+        let (zero_out, zero_mask) = padded_corner_obs(0.0);
+        let (out, mask) = padded_corner_obs(-1.0);
+
+        // The mask is unaffected by the pad value.
+        assert_eq!(mask, zero_mask);
+        assert_eq!(mask.iter().filter(|&&m| m == 0).count(), 16);
+        for (i, (&v, &m)) in out.iter().zip(&mask).enumerate() {
+            if m == 0 {
+                // Padding is written after the transform, not normalized.
+                assert_eq!(v, -1.0, "padding element {i}");
+            } else {
+                assert_eq!(v, zero_out[i], "valid element {i}");
+            }
+        }
+        // Relative (0, 0) is absolute (0, 0), value 1.0 normalized by 100.
+        assert_eq!(out[12], 0.01);
+    }
+
+    #[test]
+    fn pad_value_applies_to_history_padding_and_survives_serialization() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), (0..100).map(|x| x as Scalar).collect());
+        let spec = ObsSpec {
+            entries: vec![
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::Fixed(RegionSpec::Disk {
+                        center: smallvec::smallvec![5, 5],
+                        radius: 1,
+                    }),
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+                // No history is supplied for this entry, so it is padded.
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::Fixed(RegionSpec::Coords(vec![smallvec::smallvec![0, 0]])),
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 1,
+                },
+            ],
+            pad_value: -1.0,
+        };
+        let original = ObsPlan::compile(&spec, &space).unwrap().plan;
+        let restored = ObsPlan::deserialize(&original.serialize(), &space).unwrap();
+        assert_eq!(restored.pad_value(), -1.0);
+
+        for plan in [&original, &restored] {
+            let mut output = vec![0.0f32; plan.output_len()];
+            let mut mask = vec![0u8; plan.mask_len()];
+            plan.execute(&snap, None, &mut output, &mut mask).unwrap();
+            assert_eq!(output, [45.0, 54.0, 55.0, 56.0, 65.0, -1.0]);
+            assert_eq!(mask, [1, 1, 1, 1, 1, 0]);
+        }
+    }
+
+    #[test]
+    fn execute_agents_reports_per_agent_valid_count() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        let centers: Vec<Coord> = vec![smallvec::smallvec![5, 5], smallvec::smallvec![0, 9]];
+        let n = result.output_len;
+        let mut output = vec![0.0f32; n * 2];
+        let mut mask = vec![0u8; result.mask_len * 2];
+        let metas = result
+            .plan
+            .execute_agents(&snap, &space, &centers, None, &mut output, &mut mask)
+            .unwrap();
+
+        // Interior agent sees the full 5x5; corner agent only 3x3.
+        assert_eq!(metas[0].valid_count, 25);
+        assert_eq!(metas[1].valid_count, 9);
+        for (i, meta) in metas.iter().enumerate() {
+            let mask_sum = mask[i * n..(i + 1) * n].iter().filter(|&&v| v == 1).count();
+            assert_eq!(meta.valid_count, mask_sum);
+        }
+    }
+
+    #[test]
+    fn hex_foveation_interior() {
+        // Test agent-centered observation on Hex2D grid.
+        let space = Hex2D::new(20, 20).unwrap(); // 20 rows, 20 cols
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentDisk {
+                    radius: 2,
+                    metric: None,
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.output_len, 25); // 5x5 bounding box (tensor shape)
+
+        // Interior agent: q=10, r=10
+        let center: Coord = smallvec::smallvec![10, 10];
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        result
+            .plan
+            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+
+        // Hex disk of radius 2: 19 of 25 cells are within hex distance.
+        // The 6 corners of the 5x5 bounding box exceed hex distance 2.
+        // Hex distance = max(|dq|, |dr|, |dq+dr|) for axial coordinates.
+        let valid_count = mask.iter().filter(|&&v| v == 1).count();
+        assert_eq!(valid_count, 19);
+
+        // Corners that should be masked out (distance > 2):
+        // tensor_idx 0: dq=-2,dr=-2 → max(2,2,4)=4
+        // tensor_idx 1: dq=-2,dr=-1 → max(2,1,3)=3
+        // tensor_idx 5: dq=-1,dr=-2 → max(1,2,3)=3
+        // tensor_idx 19: dq=+1,dr=+2 → max(1,2,3)=3
+        // tensor_idx 23: dq=+2,dr=+1 → max(2,1,3)=3
+        // tensor_idx 24: dq=+2,dr=+2 → max(2,2,4)=4
+        for &idx in &[0, 1, 5, 19, 23, 24] {
+            assert_eq!(mask[idx], 0, "tensor_idx {idx} should be outside hex disk");
+            assert_eq!(output[idx], 0.0, "tensor_idx {idx} should be zero-padded");
+        }
+
+        // Center cell is at tensor_idx = 2*5+2 = 12 (relative [0,0]).
+        // Hex2D canonical_rank([q,r]) = r*cols + q = 10*20 + 10 = 210
+        assert_eq!(output[12], 210.0);
+
+        // Cell at relative [1, 0] (dq=+1, dr=0) → absolute [11, 10]
+        // rank = 10*20 + 11 = 211
+        // In row-major bounding box: dim0_idx=3, dim1_idx=2 → tensor_idx = 3*5+2 = 17
+        assert_eq!(output[17], 211.0);
+    }
+
+    #[test]
+    fn wrap_space_all_interior() {
+        // Wrapped (torus) space: all agents are interior.
+        let space = Square4::new(10, 10, EdgeBehavior::Wrap).unwrap();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+
+        // Agent at corner (0,0) — still interior on torus.
+        let center: Coord = smallvec::smallvec![0, 0];
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        result
+            .plan
+            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+
+        // All 25 cells valid (torus wraps).
+        assert!(mask.iter().all(|&v| v == 1));
+        assert_eq!(output[12], 0.0); // center (0,0) → rank 0
+    }
+
+    #[test]
+    fn execute_agents_multiple() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec::smallvec![1, 1],
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.output_len, 9); // 3x3
+
+        // Two agents: one interior, one at edge.
         let centers = vec![
             smallvec::smallvec![5, 5], // interior
             smallvec::smallvec![0, 5], // top edge
@@ -2353,10 +4773,109 @@ mod tests {
         assert_eq!(valid_count, 6); // 2 rows in-bounds × 3 cols
     }
 
+    #[test]
+    fn execute_agents_with_ids_sorts_rows_by_id() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentRect {
+                    half_extent: smallvec::smallvec![1, 1],
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let plan = ObsPlan::compile(&spec, &space).unwrap().plan;
+        let run = |centers: &[Coord], ids: &[u16], order: AgentOrder| {
+            let mut output = vec![0.0f32; plan.output_len() * centers.len()];
+            let mut mask = vec![0u8; plan.mask_len() * centers.len()];
+            let metas = plan
+                .execute_agents_with_ids(
+                    &snap,
+                    &space,
+                    centers,
+                    ids,
+                    order,
+                    None,
+                    &mut output,
+                    &mut mask,
+                )
+                .unwrap();
+            (output, mask, metas)
+        };
+
+        let by_id: Vec<Coord> = vec![
+            smallvec::smallvec![0, 5],
+            smallvec::smallvec![5, 5],
+            smallvec::smallvec![9, 9],
+        ];
+        let (out_ref, mask_ref, metas_ref) = run(&by_id, &[2, 7, 11], AgentOrder::SortedById);
+
+        let shuffled = vec![by_id[2].clone(), by_id[0].clone(), by_id[1].clone()];
+        let (out, mask, metas) = run(&shuffled, &[11, 2, 7], AgentOrder::SortedById);
+        assert_eq!(out, out_ref);
+        assert_eq!(mask, mask_ref);
+        assert_eq!(metas, metas_ref);
+        let ids: Vec<_> = metas.iter().map(|m| m.agent_id).collect();
+        assert_eq!(ids, vec![Some(2), Some(7), Some(11)]);
+
+        // Input order keeps the caller's rows but still tags each one.
+        let (out, _, metas) = run(&shuffled, &[11, 2, 7], AgentOrder::Input);
+        assert_eq!(out[4], 99.0);
+        assert_eq!(metas[0].agent_id, Some(11));
+    }
+
+    #[test]
+    fn execute_agents_with_ids_rejects_bad_ids() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), vec![0.0; 100]);
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentDisk {
+                    radius: 1,
+                    metric: None,
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let plan = ObsPlan::compile(&spec, &space).unwrap().plan;
+        let centers: Vec<Coord> = vec![smallvec::smallvec![1, 1], smallvec::smallvec![5, 5]];
+        let mut output = vec![0.0f32; plan.output_len() * 2];
+        let mut mask = vec![0u8; plan.mask_len() * 2];
+        for ids in [&[3u16][..], &[4, 4]] {
+            let err = plan
+                .execute_agents_with_ids(
+                    &snap,
+                    &space,
+                    &centers,
+                    ids,
+                    AgentOrder::SortedById,
+                    None,
+                    &mut output,
+                    &mut mask,
+                )
+                .unwrap_err();
+            assert!(matches!(err, ObsError::ExecutionFailed { .. }));
+        }
+    }
+
     #[test]
     fn execute_agents_with_normalize() {
         let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -2364,6 +4883,7 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![1, 1],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Normalize {
@@ -2371,7 +4891,9 @@ mod tests {
                     max: 99.0,
                 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -2391,7 +4913,7 @@ mod tests {
     #[test]
     fn execute_agents_with_pooling() {
         let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..400).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         // AgentRect with half_extent=3 → 7x7 bounding box.
@@ -2401,6 +4923,7 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![3, 3],
+                    occlusion: None,
                 },
                 pool: Some(PoolConfig {
                     kernel: PoolKernel::Mean,
@@ -2409,7 +4932,9 @@ mod tests {
                 }),
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert_eq!(result.output_len, 9); // 3x3
@@ -2434,10 +4959,131 @@ mod tests {
         assert!((output[0] - 157.5).abs() < 1e-4);
     }
 
+    #[test]
+    fn fixed_all_region_with_mean_pooling() {
+        let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+
+        // 20x20 grid, mean pool 4x4 stride 4 → 5x5 heatmap.
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: Some(PoolConfig {
+                    kernel: PoolKernel::Mean,
+                    kernel_size: 4,
+                    stride: 4,
+                }),
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.output_len, 25);
+        assert_eq!(result.entry_shapes, vec![vec![5, 5]]);
+
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        let meta = result
+            .plan
+            .execute(&snap, None, &mut output, &mut mask)
+            .unwrap();
+        assert!(mask.iter().all(|&v| v == 1));
+        assert_eq!(meta.valid_count, 25);
+
+        // Block (br, bc) covers rows 4br..4br+4, cols 4bc..4bc+4; the mean
+        // of r*20 + c over it is (4br + 1.5) * 20 + 4bc + 1.5.
+        for br in 0..5 {
+            for bc in 0..5 {
+                let expected = (4.0 * br as f32 + 1.5) * 20.0 + 4.0 * bc as f32 + 1.5;
+                assert!(
+                    (output[br * 5 + bc] - expected).abs() < 1e-4,
+                    "block ({br},{bc}): {} vs {expected}",
+                    output[br * 5 + bc]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pooled_and_delta_scratch_reuse_matches_fresh_buffers() {
+        let space = Square4::new(8, 8, EdgeBehavior::Absorb).unwrap();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: Some(PoolConfig {
+                    kernel: PoolKernel::Max,
+                    kernel_size: 2,
+                    stride: 2,
+                }),
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let plan = ObsPlan::compile(&spec, &space).unwrap().plan;
+        let a = snapshot_with_field(FieldId(0), (0..64).map(|x| x as Scalar).collect());
+        let b = snapshot_with_field(FieldId(0), vec![-1.0; 64]);
+
+        let run = |plan: &ObsPlan, delta: bool| {
+            let mut output = vec![0.0f32; plan.output_len()];
+            let mut mask = vec![0u8; plan.mask_len()];
+            if delta {
+                plan.execute_delta(&a, &b, &mut output, &mut mask).unwrap();
+            } else {
+                plan.execute(&b, None, &mut output, &mut mask).unwrap();
+            }
+            output
+        };
+        // Dirty the scratch buffers with `a`, then execute `b`: the result
+        // must match the fallback path, which uses fresh buffers.
+        run(&plan, true);
+        let reused = (run(&plan, false), run(&plan, true));
+        let held = (
+            plan.scratch.gathered.lock().unwrap(),
+            plan.scratch.delta.lock().unwrap(),
+        );
+        let fresh = (run(&plan, false), run(&plan, true));
+        drop(held);
+        assert_eq!(reused, fresh);
+        assert!(reused.0.iter().all(|&v| v == -1.0));
+    }
+
+    #[test]
+    fn fixed_pooling_rejects_non_2d_region() {
+        let space = Line1D::new(20, EdgeBehavior::Absorb).unwrap();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::Fixed(RegionSpec::All),
+                pool: Some(PoolConfig {
+                    kernel: PoolKernel::Mean,
+                    kernel_size: 4,
+                    stride: 4,
+                }),
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        match ObsPlan::compile(&spec, &space) {
+            Err(ObsError::InvalidObsSpec { reason }) => {
+                assert!(reason.contains("pooling requires 2D region"), "{reason}");
+            }
+            other => panic!("expected InvalidObsSpec, got {other:?}"),
+        }
+    }
+
     #[test]
     fn mixed_fixed_and_agent_entries() {
         let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -2449,18 +5095,22 @@ mod tests {
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
                 // Agent entry: 3x3 rect around agent.
                 ObsEntry {
                     field_id: FieldId(0),
                     region: ObsRegion::AgentRect {
                         half_extent: smallvec::smallvec![1, 1],
+                        occlusion: None,
                     },
                     pool: None,
                     transform: ObsTransform::Identity,
                     dtype: ObsDtype::F32,
+                    tick_offset: 0,
                 },
             ],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert!(result.plan.is_standard());
@@ -2488,17 +5138,23 @@ mod tests {
     fn wrong_dimensionality_returns_error() {
         // 2D space but 1D agent center → should error, not panic.
         let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
-                region: ObsRegion::AgentDisk { radius: 1 },
+                region: ObsRegion::AgentDisk {
+                    radius: 1,
+                    metric: None,
+                    occlusion: None,
+                },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -2522,17 +5178,23 @@ mod tests {
         // On a 4-connected grid, AgentDisk radius=2 should use Manhattan distance.
         // Bounding box is 5x5 = 25, but Manhattan disk has 13 cells (diamond shape).
         let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..400).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
-                region: ObsRegion::AgentDisk { radius: 2 },
+                region: ObsRegion::AgentDisk {
+                    radius: 2,
+                    metric: None,
+                    occlusion: None,
+                },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert_eq!(result.output_len, 25); // tensor shape is still 5x5
@@ -2580,7 +5242,7 @@ mod tests {
     fn agent_rect_no_disk_filtering() {
         // AgentRect should NOT filter any cells — full rectangle is valid.
         let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..400).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
@@ -2588,11 +5250,14 @@ mod tests {
                 field_id: FieldId(0),
                 region: ObsRegion::AgentRect {
                     half_extent: smallvec::smallvec![2, 2],
+                    occlusion: None,
                 },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
 
@@ -2608,22 +5273,256 @@ mod tests {
         assert!(mask.iter().all(|&v| v == 1));
     }
 
+    fn position_spec(before: Option<ObsRegion>) -> ObsSpec {
+        let entry = |region| ObsEntry {
+            field_id: FieldId(0),
+            region,
+            pool: None,
+            transform: ObsTransform::Identity,
+            dtype: ObsDtype::F32,
+            tick_offset: 0,
+        };
+        ObsSpec {
+            entries: before
+                .into_iter()
+                .chain([ObsRegion::PositionChannel])
+                .map(entry)
+                .collect(),
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn position_channel_normalizes_agent_coordinates() {
+        let space = Square4::new(11, 11, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), vec![0.0; 121]);
+        let result = ObsPlan::compile(&position_spec(None), &space).unwrap();
+        assert_eq!(result.output_len, 2);
+        assert_eq!(result.entry_shapes, vec![vec![2]]);
+
+        let centers: Vec<Coord> = vec![
+            smallvec::smallvec![5, 5],
+            smallvec::smallvec![0, 0],
+            smallvec::smallvec![10, 10],
+            smallvec::smallvec![0, 10],
+        ];
+        let mut output = vec![-1.0f32; 8];
+        let mut mask = vec![0u8; 8];
+        result
+            .plan
+            .execute_agents(&snap, &space, &centers, None, &mut output, &mut mask)
+            .unwrap();
+        assert_eq!(output, [0.5, 0.5, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0]);
+        assert!(mask.iter().all(|&v| v == 1));
+    }
+
+    #[test]
+    fn position_channel_follows_preceding_entries() {
+        let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), (0..400).map(|x| x as Scalar).collect());
+        let spec = position_spec(Some(ObsRegion::AgentRect {
+            half_extent: smallvec::smallvec![1, 1],
+            occlusion: None,
+        }));
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.entry_offsets, vec![(0, 9), (9, 2)]);
+
+        let center: Coord = smallvec::smallvec![19, 0];
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        result
+            .plan
+            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+        assert_eq!(&output[9..], &[1.0, 0.0]);
+        assert_eq!(&mask[9..], &[1, 1]);
+        // The centre cell of the rect is still the agent's own cell.
+        assert_eq!(output[4], 380.0);
+    }
+
+    #[test]
+    fn position_channel_rejects_pooling_and_compiles_standard() {
+        let space = square4_space();
+        let mut spec = position_spec(None);
+        spec.entries[0].pool = Some(PoolConfig {
+            kernel: PoolKernel::Mean,
+            kernel_size: 2,
+            stride: 2,
+        });
+        assert!(ObsPlan::compile(&spec, &space).is_err());
+
+        let plan = ObsPlan::compile(&position_spec(None), &space).unwrap().plan;
+        assert!(plan.is_standard());
+    }
+
+    /// Compile a single-entry agent spec and return the mask around an
+    /// interior agent on a 30x30 hex grid, with the box half-extent.
+    fn hex_disk_mask(region: ObsRegion) -> (Vec<u8>, i32) {
+        let space = Hex2D::new(30, 30).unwrap();
+        let snap = snapshot_with_field(FieldId(0), vec![1.0; 900]);
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region,
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        let side = result.entry_shapes[0][0];
+        let center: Coord = smallvec::smallvec![15, 15];
+        let mut output = vec![0.0f32; result.output_len];
+        let mut mask = vec![0u8; result.mask_len];
+        result
+            .plan
+            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+        (mask, (side as i32 - 1) / 2)
+    }
+
+    #[test]
+    fn agent_disk_real_masks_hex_corners_beyond_pixel_radius() {
+        // Both regions span a 7x7 box. Axial (3, 0) is at hex distance 3
+        // but pixel distance 3.0 > 2.9; (2, 1) is at pixel distance
+        // sqrt(7) ~= 2.65 and so lies in both.
+        let (real, reach) = hex_disk_mask(ObsRegion::AgentDiskReal {
+            pixel_radius: 2.9,
+            occlusion: None,
+        });
+        let (cube, cube_reach) = hex_disk_mask(ObsRegion::AgentDisk {
+            radius: 3,
+            metric: None,
+            occlusion: None,
+        });
+        assert_eq!((reach, cube_reach), (3, 3));
+        let idx = |dq: i32, dr: i32| ((dq + 3) * 7 + (dr + 3)) as usize;
+        assert_eq!((real[idx(3, 0)], cube[idx(3, 0)]), (0, 1));
+        assert_eq!((real[idx(2, 1)], cube[idx(2, 1)]), (1, 1));
+        assert_eq!(real[idx(0, 0)], 1);
+    }
+
+    #[test]
+    fn agent_disk_real_on_hex_is_closer_to_a_circle() {
+        // Count cells whose membership disagrees with the ideal circle of
+        // radius 8 in pixel space (dq^2 + dq*dr + dr^2 <= 64).
+        let radius = 8.0f32;
+        let mismatches = |(mask, reach): (Vec<u8>, i32)| {
+            let side = 2 * reach + 1;
+            let mut count = 0;
+            for dq in -10i32..=10 {
+                for dr in -10i32..=10 {
+                    let ideal = dq * dq + dq * dr + dr * dr <= 64;
+                    let inside = dq.abs() <= reach
+                        && dr.abs() <= reach
+                        && mask[((dq + reach) * side + (dr + reach)) as usize] == 1;
+                    count += usize::from(ideal != inside);
+                }
+            }
+            count
+        };
+        let real = mismatches(hex_disk_mask(ObsRegion::AgentDiskReal {
+            pixel_radius: radius,
+            occlusion: None,
+        }));
+        let cube = mismatches(hex_disk_mask(ObsRegion::AgentDisk {
+            radius: radius as u32,
+            metric: None,
+            occlusion: None,
+        }));
+        assert_eq!(real, 0);
+        assert!(
+            cube > 0,
+            "cube-distance disk should deviate from the circle"
+        );
+    }
+
+    #[test]
+    fn agent_disk_real_on_square_grid_uses_coordinate_distance() {
+        let space = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let compile = |pixel_radius| {
+            let spec = ObsSpec {
+                entries: vec![ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::AgentDiskReal {
+                        pixel_radius,
+                        occlusion: None,
+                    },
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                }],
+                pad_value: 0.0,
+            };
+            ObsPlan::compile(&spec, &space)
+        };
+        let snap = snapshot_with_field(FieldId(0), vec![1.0; 100]);
+        for (radius, valid) in [(1.0, 5), (1.5, 9), (0.0, 1)] {
+            let result = compile(radius).unwrap();
+            assert_eq!(result.output_len, if radius < 1.0 { 1 } else { 9 });
+            let center: Coord = smallvec::smallvec![5, 5];
+            let mut output = vec![0.0f32; result.output_len];
+            let mut mask = vec![0u8; result.mask_len];
+            result
+                .plan
+                .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+                .unwrap();
+            assert_eq!(
+                mask.iter().filter(|&&v| v == 1).count(),
+                valid,
+                "radius {radius}"
+            );
+        }
+        assert!(compile(-1.0).is_err());
+        assert!(compile(f32::NAN).is_err());
+        assert!(compile(f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn agent_disk_real_requires_grid_space() {
+        let space = Line1D::new(10, EdgeBehavior::Absorb).unwrap();
+        let spec = ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentDiskReal {
+                    pixel_radius: 2.0,
+                    occlusion: None,
+                },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        };
+        assert!(ObsPlan::compile(&spec, &space).is_err());
+    }
+
     #[test]
     fn agent_disk_square8_chebyshev() {
         // On an 8-connected grid, AgentDisk radius=1 uses Chebyshev distance.
         // Bounding box is 3x3 = 9, Chebyshev disk radius=1 = full 3x3 → 9 cells.
         let space = Square8::new(10, 10, EdgeBehavior::Absorb).unwrap();
-        let data: Vec<f32> = (0..100).map(|x| x as f32).collect();
+        let data: Vec<Scalar> = (0..100).map(|x| x as Scalar).collect();
         let snap = snapshot_with_field(FieldId(0), data);
 
         let spec = ObsSpec {
             entries: vec![ObsEntry {
                 field_id: FieldId(0),
-                region: ObsRegion::AgentDisk { radius: 1 },
+                region: ObsRegion::AgentDisk {
+                    radius: 1,
+                    metric: None,
+                    occlusion: None,
+                },
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let result = ObsPlan::compile(&spec, &space).unwrap();
         assert_eq!(result.output_len, 9);
@@ -2641,6 +5540,142 @@ mod tests {
         assert_eq!(valid_count, 9, "Chebyshev disk radius=1 = full 3x3");
     }
 
+    #[test]
+    fn agent_disk_metric_override_on_square8() {
+        // Radius 2 on Square8: the 5x5 box corners sit at Euclidean
+        // distance sqrt(8) > 2 but Chebyshev distance 2.
+        let space = Square8::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), vec![1.0; 100]);
+        let run = |metric: Option<DiskMetric>| {
+            let spec = ObsSpec {
+                entries: vec![ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::AgentDisk {
+                        radius: 2,
+                        metric,
+                        occlusion: None,
+                    },
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                }],
+                pad_value: 0.0,
+            };
+            let result = ObsPlan::compile(&spec, &space).unwrap();
+            assert_eq!(result.output_len, 25);
+            let center: Coord = smallvec::smallvec![5, 5];
+            let mut output = vec![0.0f32; 25];
+            let mut mask = vec![0u8; 25];
+            result
+                .plan
+                .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+                .unwrap();
+            mask
+        };
+
+        let euclidean = run(Some(DiskMetric::Euclidean));
+        assert_eq!(euclidean.iter().filter(|&&v| v == 1).count(), 13);
+        for corner in [0, 4, 20, 24] {
+            assert_eq!(
+                euclidean[corner], 0,
+                "corner {corner} is outside the circle"
+            );
+        }
+        // (1, 2) offset: sqrt(5) > 2.
+        assert_eq!(euclidean[3 * 5 + 4], 0);
+
+        let chebyshev = run(Some(DiskMetric::Chebyshev));
+        assert!(chebyshev.iter().all(|&v| v == 1));
+        assert_eq!(run(None), chebyshev, "Native on Square8 is Chebyshev");
+        assert_eq!(run(Some(DiskMetric::Manhattan))[3 * 5 + 4], 0);
+    }
+
+    fn foveated_spec(rings: Vec<(u32, Option<PoolConfig>)>) -> ObsSpec {
+        ObsSpec {
+            entries: vec![ObsEntry {
+                field_id: FieldId(0),
+                region: ObsRegion::AgentFoveated { rings },
+                pool: None,
+                transform: ObsTransform::Identity,
+                dtype: ObsDtype::F32,
+                tick_offset: 0,
+            }],
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn agent_foveated_two_rings() {
+        // Square8 → Chebyshev rings, so each annulus is a square band.
+        let space = Square8::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        let data: Vec<Scalar> = (0..400).map(|x| x as Scalar).collect();
+        let snap = snapshot_with_field(FieldId(0), data);
+
+        // Inner ring r=1 unpooled → 3x3 = 9.
+        // Outer ring r=3 → 7x7, mean pool 2x2 stride 2 → 3x3 = 9.
+        let spec = foveated_spec(vec![
+            (1, None),
+            (
+                3,
+                Some(PoolConfig {
+                    kernel: PoolKernel::Mean,
+                    kernel_size: 2,
+                    stride: 2,
+                }),
+            ),
+        ]);
+        let result = ObsPlan::compile(&spec, &space).unwrap();
+        assert_eq!(result.output_len, 18);
+        assert_eq!(result.mask_len, 18);
+        assert_eq!(result.entry_shapes, vec![vec![18]]);
+        assert_eq!(result.entry_offsets, vec![(0, 18)]);
+        assert_eq!(result.entry_byte_offsets, vec![(0, 72)]);
+        assert_eq!(result.output_bytes, 72);
+
+        let center: Coord = smallvec::smallvec![10, 10];
+        let mut output = vec![0.0f32; 18];
+        let mut mask = vec![0u8; 18];
+        result
+            .plan
+            .execute_agents(&snap, &space, &[center], None, &mut output, &mut mask)
+            .unwrap();
+
+        // Inner ring is full resolution: rows 9..=11, cols 9..=11.
+        let expected: Vec<f32> = (9..=11)
+            .flat_map(|r| (9..=11).map(move |c| (r * 20 + c) as f32))
+            .collect();
+        assert_eq!(&output[..9], expected.as_slice());
+        assert!(mask[..9].iter().all(|&v| v == 1));
+
+        // Outer top-left window: (7,7), (7,8), (8,7), (8,8) → mean 157.5.
+        assert!((output[9] - 157.5).abs() < 1e-4);
+        assert_eq!(mask[9], 1);
+        // Outer centre window lies entirely inside the inner ring → masked.
+        assert_eq!(mask[9 + 4], 0);
+    }
+
+    #[test]
+    fn agent_foveated_rejects_bad_rings() {
+        let space = Square8::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        for rings in [
+            vec![],
+            vec![(2, None), (2, None)],
+            vec![(3, None), (1, None)],
+        ] {
+            let err = ObsPlan::compile(&foveated_spec(rings), &space).unwrap_err();
+            assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
+        }
+
+        let mut spec = foveated_spec(vec![(1, None)]);
+        spec.entries[0].pool = Some(PoolConfig {
+            kernel: PoolKernel::Max,
+            kernel_size: 2,
+            stride: 1,
+        });
+        assert!(ObsPlan::compile(&spec, &space).is_err());
+    }
+
     #[test]
     fn compile_rejects_inverted_normalize_range() {
         let space = square4_space();
@@ -2654,7 +5689,9 @@ mod tests {
                     max: 5.0,
                 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         let err = ObsPlan::compile(&spec, &space).unwrap_err();
         assert!(matches!(err, ObsError::InvalidObsSpec { .. }));
@@ -2673,7 +5710,9 @@ mod tests {
                     max: 1.0,
                 },
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         assert!(ObsPlan::compile(&spec, &space).is_err());
     }
@@ -2688,7 +5727,9 @@ mod tests {
                 pool: None,
                 transform: ObsTransform::Identity,
                 dtype: ObsDtype::F32,
+                tick_offset: 0,
             }],
+            pad_value: 0.0,
         };
         // Compile bound to generation 1.
         let result = ObsPlan::compile_bound(&spec, &space, WorldGenerationId(1)).unwrap();
@@ -2716,4 +5757,154 @@ mod tests {
             "first snapshot should be written despite batch error"
         );
     }
+
+    // ── Serialization tests ──────────────────────────────────
+
+    fn serialization_spec() -> ObsSpec {
+        ObsSpec {
+            entries: vec![
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::Fixed(RegionSpec::All),
+                    pool: None,
+                    transform: ObsTransform::Normalize {
+                        min: 0.0,
+                        max: 400.0,
+                    },
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::AgentDisk {
+                        radius: 2,
+                        metric: None,
+                        occlusion: None,
+                    },
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::AgentRect {
+                        half_extent: smallvec::smallvec![3, 3],
+                        occlusion: None,
+                    },
+                    pool: Some(PoolConfig {
+                        kernel: PoolKernel::Mean,
+                        kernel_size: 2,
+                        stride: 2,
+                    }),
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+                ObsEntry {
+                    field_id: FieldId(0),
+                    region: ObsRegion::PositionChannel,
+                    pool: None,
+                    transform: ObsTransform::Identity,
+                    dtype: ObsDtype::F32,
+                    tick_offset: 0,
+                },
+            ],
+            pad_value: 0.0,
+        }
+    }
+
+    #[test]
+    fn deserialized_simple_plan_matches_original() {
+        let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), (0..400).map(|x| x as Scalar).collect());
+        let mut spec = serialization_spec();
+        spec.entries.truncate(1);
+        spec.entries.push(ObsEntry {
+            region: ObsRegion::Fixed(RegionSpec::Disk {
+                center: smallvec::smallvec![10, 10],
+                radius: 3,
+            }),
+            ..spec.entries[0].clone()
+        });
+        spec.entries[0].pool = Some(PoolConfig {
+            kernel: PoolKernel::Max,
+            kernel_size: 4,
+            stride: 4,
+        });
+        let original = ObsPlan::compile(&spec, &space).unwrap().plan;
+        let restored = ObsPlan::deserialize(&original.serialize(), &space).unwrap();
+
+        let run = |plan: &ObsPlan| {
+            let mut output = vec![0.0f32; plan.output_len()];
+            let mut mask = vec![0u8; plan.mask_len()];
+            plan.execute(&snap, None, &mut output, &mut mask).unwrap();
+            (output.iter().map(|v| v.to_bits()).collect::<Vec<_>>(), mask)
+        };
+        assert_eq!(run(&original), run(&restored));
+        assert_eq!(original.entry_offsets, restored.entry_offsets);
+        assert_eq!(restored.serialize(), original.serialize());
+    }
+
+    #[test]
+    fn deserialized_standard_plan_matches_original() {
+        let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        let snap = snapshot_with_field(FieldId(0), (0..400).map(|x| x as Scalar).collect());
+        let original = ObsPlan::compile(&serialization_spec(), &space)
+            .unwrap()
+            .plan;
+        let restored = ObsPlan::deserialize(&original.serialize(), &space).unwrap();
+        assert!(restored.is_standard());
+
+        // One interior agent and two on the boundary.
+        let centers: Vec<Coord> = vec![
+            smallvec::smallvec![10, 10],
+            smallvec::smallvec![0, 0],
+            smallvec::smallvec![19, 5],
+        ];
+        let run = |plan: &ObsPlan| {
+            let mut output = vec![0.0f32; plan.output_len() * centers.len()];
+            let mut mask = vec![0u8; plan.mask_len() * centers.len()];
+            plan.execute_agents(&snap, &space, &centers, None, &mut output, &mut mask)
+                .unwrap();
+            (output.iter().map(|v| v.to_bits()).collect::<Vec<_>>(), mask)
+        };
+        assert_eq!(run(&original), run(&restored));
+    }
+
+    #[test]
+    fn deserialize_rejects_mismatched_space() {
+        let space = Square4::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        let bytes = ObsPlan::compile(&serialization_spec(), &space)
+            .unwrap()
+            .plan
+            .serialize();
+
+        let smaller = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let wrapped = Square4::new(20, 20, EdgeBehavior::Wrap).unwrap();
+        let eight_way = Square8::new(20, 20, EdgeBehavior::Absorb).unwrap();
+        for other in [&smaller as &dyn Space, &wrapped, &eight_way] {
+            let err = ObsPlan::deserialize(&bytes, other).unwrap_err();
+            assert!(matches!(err, ObsError::InvalidObsSpec { .. }), "{err:?}");
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_truncated_or_corrupt_bytes() {
+        let space = square4_space();
+        let bytes = ObsPlan::compile(&all_region_spec(), &space)
+            .unwrap()
+            .plan
+            .serialize();
+
+        for len in [0, 3, 6, bytes.len() / 2, bytes.len() - 1] {
+            assert!(ObsPlan::deserialize(&bytes[..len], &space).is_err());
+        }
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(ObsPlan::deserialize(&bad_magic, &space).is_err());
+        let mut trailing = bytes;
+        trailing.push(0);
+        assert!(ObsPlan::deserialize(&trailing, &space).is_err());
+    }
 }
@@ -30,15 +30,22 @@ use smallvec::SmallVec;
 ///             pool: None,
 ///             transform: ObsTransform::Identity,
 ///             dtype: ObsDtype::F32,
+///             tick_offset: 0,
 ///         },
 ///         ObsEntry {
 ///             field_id: FieldId(1),
-///             region: ObsRegion::AgentDisk { radius: 3 },
+///             region: ObsRegion::AgentDisk {
+///                 radius: 3,
+///                 metric: None,
+///                 occlusion: None,
+///             },
 ///             pool: None,
 ///             transform: ObsTransform::Normalize { min: 0.0, max: 100.0 },
 ///             dtype: ObsDtype::F32,
+///             tick_offset: 0,
 ///         },
 ///     ],
+///     pad_value: 0.0,
 /// };
 ///
 /// assert_eq!(spec.entries.len(), 2);
@@ -48,27 +55,123 @@ use smallvec::SmallVec;
 pub struct ObsSpec {
     /// Ordered observation entries.
     pub entries: Vec<ObsEntry>,
+    /// Value written to every output element whose mask is 0 (cells
+    /// outside the grid or region, offsets past the supplied history,
+    /// stale batch slots).
+    ///
+    /// Applied after the entry's transform: invalid elements read exactly
+    /// `pad_value` regardless of normalization. A sentinel such as `-1.0`
+    /// lets a network tell "out of bounds" from a legitimate zero without
+    /// consulting the mask. Conventionally `0.0`.
+    pub pad_value: f32,
 }
 
 /// Observation region — how to select spatial cells for an entry.
 ///
 /// `Fixed` regions are resolved at plan-compile time (like the existing
-/// `RegionSpec`). `AgentDisk` and `AgentRect` are resolved at execute
-/// time relative to each agent's position (foveation).
+/// `RegionSpec`). `AgentDisk`, `AgentDiskReal`, `AgentRect` and
+/// `AgentFoveated` are resolved at execute time relative to each agent's
+/// position (foveation).
 #[derive(Clone, Debug, PartialEq)]
 pub enum ObsRegion {
     /// Absolute region, compiled at plan-compile time.
     Fixed(RegionSpec),
     /// Disk centered on the agent, resolved at execute time.
     AgentDisk {
-        /// Maximum graph distance from agent center (inclusive).
+        /// Maximum distance from agent center (inclusive).
         radius: u32,
+        /// Distance metric deciding which cells of the bounding box are
+        /// inside the disk. `None` behaves as [`DiskMetric::Native`].
+        metric: Option<DiskMetric>,
+        /// Line-of-sight masking: cells hidden behind an opaque cell are
+        /// masked out. `None` observes every cell of the footprint.
+        occlusion: Option<Occlusion>,
+    },
+    /// Disk centered on the agent with a real-valued radius measured in
+    /// pixel space, resolved at execute time.
+    ///
+    /// A cell is inside when the Euclidean distance between its centre and
+    /// the agent's centre is at most `pixel_radius`, with adjacent cell
+    /// centres one unit apart: the [`Hex2D::to_pixel`](murk_space::Hex2D::to_pixel)
+    /// layout on `Hex2D`, the coordinate grid itself on `Square4`/`Square8`.
+    /// On a hex grid this gives a visually circular footprint where
+    /// [`AgentDisk`](Self::AgentDisk) gives a hexagon. The output covers
+    /// the smallest bounding box holding the disk, with cells outside it
+    /// masked. Requires a grid space and a finite, non-negative radius.
+    AgentDiskReal {
+        /// Maximum pixel-space distance from the agent's centre (inclusive).
+        pixel_radius: f32,
+        /// Line-of-sight masking: cells hidden behind an opaque cell are
+        /// masked out. `None` observes every cell of the footprint.
+        occlusion: Option<Occlusion>,
     },
     /// Axis-aligned rectangle centered on the agent, resolved at execute time.
     AgentRect {
         /// Half-extent per dimension (the full extent is `2 * half_extent + 1`).
         half_extent: SmallVec<[u32; 4]>,
+        /// Line-of-sight masking: cells hidden behind an opaque cell are
+        /// masked out. `None` observes every cell of the footprint.
+        occlusion: Option<Occlusion>,
+    },
+    /// Concentric annuli centered on the agent, each optionally pooled.
+    ///
+    /// Ring `i` covers cells with graph distance in `(r[i-1], r[i]]` (the
+    /// first ring is a full disk of radius `r[0]`), gathered over a
+    /// `(2*r[i]+1)`-wide bounding box with inner cells masked out, then
+    /// pooled if a [`PoolConfig`] is given. Rings are concatenated in order
+    /// into one contiguous output; the entry's shape in `entry_shapes` is
+    /// the flat `[total_elements]`. Radii must be strictly increasing, the
+    /// entry-level `pool` must be `None`, and the space must be a grid.
+    AgentFoveated {
+        /// `(radius, pool)` per ring, innermost first.
+        rings: Vec<(u32, Option<PoolConfig>)>,
     },
+    /// The agent's own position as `space.ndim()` channels.
+    ///
+    /// Each coordinate is normalized to `[0, 1]` over the space's extent on
+    /// that axis: `0` at the lowest coordinate, `1` at the highest (`0` on
+    /// an axis with a single value). Only filled by
+    /// [`ObsPlan::execute_agents`](crate::ObsPlan::execute_agents). The
+    /// entry's `field_id` is not read, `pool` must be `None`, and
+    /// `transform` applies to the normalized values. The entry's shape in
+    /// `entry_shapes` is `[ndim]`.
+    PositionChannel,
+}
+
+/// Distance metric for the footprint of an [`ObsRegion::AgentDisk`].
+///
+/// The non-native metrics are evaluated on the raw coordinate offset from
+/// the agent, so they are meant for square grids: `Euclidean` gives a
+/// circular footprint even on an 8-connected `Square8`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiskMetric {
+    /// The space's own graph distance (Manhattan on `Square4`, Chebyshev
+    /// on `Square8`, cube distance on `Hex2D`).
+    #[default]
+    Native,
+    /// Straight-line distance: `sqrt(sum(d²)) <= radius`.
+    Euclidean,
+    /// Sum of absolute per-axis offsets.
+    Manhattan,
+    /// Largest absolute per-axis offset.
+    Chebyshev,
+}
+
+/// Line-of-sight masking for an agent-relative region.
+///
+/// A cell is masked invalid for an agent when the straight segment from
+/// the agent's cell to it passes through a cell whose `field_id` value
+/// exceeds `threshold`. The agent's own cell and the target cell never
+/// block, so a wall cell is itself visible. Segments are rasterised in
+/// the grid's own layout (cube coordinates on `Hex2D`), which requires a
+/// grid space; the occlusion field is read from the same snapshot as
+/// the observed field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Occlusion {
+    /// Scalar field holding per-cell opacity.
+    pub field_id: FieldId,
+    /// Cells with an opacity strictly above this value block the view.
+    pub threshold: f32,
 }
 
 impl From<RegionSpec> for ObsRegion {
@@ -132,6 +235,7 @@ pub struct PoolConfig {
 ///     pool: None,
 ///     transform: ObsTransform::Identity,
 ///     dtype: ObsDtype::F32,
+///     tick_offset: 0,
 /// };
 ///
 /// assert_eq!(entry.field_id, FieldId(0));
@@ -150,12 +254,24 @@ pub struct ObsEntry {
     pub transform: ObsTransform,
     /// Output data type.
     pub dtype: ObsDtype,
+    /// How many ticks back to read the field from (`0` = current tick).
+    ///
+    /// Non-zero offsets are resolved by
+    /// [`ObsPlan::execute_history`](crate::ObsPlan::execute_history); an
+    /// offset past the end of the supplied history yields zero-filled,
+    /// masked-out output.
+    ///
+    /// Only plans without agent-relative entries support history: agent
+    /// plans execute against a single snapshot, so compiling one with a
+    /// non-zero offset on any entry fails with
+    /// [`ObsError::InvalidObsSpec`](murk_core::error::ObsError::InvalidObsSpec).
+    pub tick_offset: usize,
 }
 
 /// Transform applied to raw field values before output.
 ///
-/// v1 supports `Identity` and `Normalize`. Additional transforms
-/// are deferred to v1.5+.
+/// v1 supports `Identity`, `Normalize`, and `NormalizeToBounds`.
+/// Additional transforms are deferred to v1.5+.
 ///
 /// # Examples
 ///
@@ -182,6 +298,16 @@ pub enum ObsTransform {
         /// Upper bound of the input range.
         max: f64,
     },
+    /// [`Normalize`](Self::Normalize) over the field's declared
+    /// [`FieldDef::bounds`](murk_core::FieldDef::bounds).
+    ///
+    /// Resolved by [`ObsPlan::compile_with_fields`](crate::ObsPlan::compile_with_fields),
+    /// which rejects fields without a single declared range. Plain
+    /// [`ObsPlan::compile`](crate::ObsPlan::compile) rejects this transform;
+    /// `LockstepWorld::compile_obs`, `BatchedEngine`, and
+    /// [`ObsPlanCache::with_fields`](crate::ObsPlanCache::with_fields) pass
+    /// the world's field definitions through.
+    NormalizeToBounds,
 }
 
 /// Output data type for observation values.
@@ -192,3 +318,12 @@ pub enum ObsDtype {
     /// 32-bit float.
     F32,
 }
+
+impl ObsDtype {
+    /// Size of one element of this dtype in bytes.
+    pub fn size_bytes(self) -> usize {
+        match self {
+            ObsDtype::F32 => 4,
+        }
+    }
+}
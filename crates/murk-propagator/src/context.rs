@@ -2,10 +2,12 @@
 //!
 //! [`StepContext`] provides split-borrow field access through two read views
 //! (in-tick overlay and frozen tick-start) plus mutable write access, scratch
-//! memory, and the spatial topology.
+//! memory, the spatial topology, the global parameter store, and the
+//! simulation clock.
 
+use crate::group::NeighbourCache;
 use crate::scratch::ScratchRegion;
-use murk_core::{FieldReader, FieldWriter, TickId};
+use murk_core::{FieldReader, FieldWriter, ParameterKey, ParameterStore, TickId};
 use murk_space::Space;
 
 /// Execution context passed to each propagator's `step()` method.
@@ -24,6 +26,24 @@ use murk_space::Space;
 /// - **`reads_previous()`** returns the frozen tick-start view. Always sees
 ///   the base generation regardless of prior writes. This enables
 ///   Jacobi-style parallel integration.
+///
+/// # Parameters
+///
+/// **`parameters()`** exposes the global [`ParameterStore`]. Parameter
+/// commands are applied at the tick boundary, so the store is frozen for
+/// the whole tick and every propagator sees the same values.
+///
+/// # Time
+///
+/// **`sim_time()`** is the cumulative simulated time at the end of the
+/// current tick, `sum(dt)` over ticks `1..=tick_id`, for time-varying
+/// sources that need absolute time rather than the step size.
+///
+/// # Shared topology
+///
+/// **`neighbour_cache()`** is set when the propagator runs inside a
+/// [`PropagatorGroup`](crate::PropagatorGroup), giving every member the
+/// same precomputed neighbour ranks instead of each enumerating them.
 pub struct StepContext<'a> {
     reads: &'a dyn FieldReader,
     reads_previous: &'a dyn FieldReader,
@@ -32,13 +52,23 @@ pub struct StepContext<'a> {
     space: &'a dyn Space,
     tick_id: TickId,
     dt: f64,
+    sim_time: f64,
+    parameters: &'a ParameterStore,
+    neighbour_cache: Option<&'a NeighbourCache>,
 }
 
+/// Shared empty store for contexts built without parameters.
+static EMPTY_PARAMETERS: ParameterStore = ParameterStore::new();
+
 impl<'a> StepContext<'a> {
     /// Construct a new step context.
     ///
     /// Typically called by the engine, not by propagators directly.
     /// For testing, construct with mock readers/writers from `murk-test-utils`.
+    /// The parameter store starts empty; attach one with
+    /// [`with_parameters`](Self::with_parameters). The simulation time
+    /// defaults to `tick_id * dt`, which is exact for a constant timestep;
+    /// override it with [`with_sim_time`](Self::with_sim_time).
     pub fn new(
         reads: &'a dyn FieldReader,
         reads_previous: &'a dyn FieldReader,
@@ -56,6 +86,43 @@ impl<'a> StepContext<'a> {
             space,
             tick_id,
             dt,
+            sim_time: tick_id.0 as f64 * dt,
+            parameters: &EMPTY_PARAMETERS,
+            neighbour_cache: None,
+        }
+    }
+
+    /// Attach the global parameter store visible to the propagator.
+    pub fn with_parameters(mut self, parameters: &'a ParameterStore) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Set the cumulative simulation time at the end of this tick.
+    pub fn with_sim_time(mut self, sim_time: f64) -> Self {
+        self.sim_time = sim_time;
+        self
+    }
+
+    /// Attach a precomputed neighbour table for the context's space.
+    pub fn with_neighbour_cache(mut self, cache: &'a NeighbourCache) -> Self {
+        self.neighbour_cache = Some(cache);
+        self
+    }
+
+    /// Reborrow this context for a nested propagator, sharing `cache`.
+    pub(crate) fn nested<'b>(&'b mut self, cache: &'b NeighbourCache) -> StepContext<'b> {
+        StepContext {
+            reads: self.reads,
+            reads_previous: self.reads_previous,
+            writes: &mut *self.writes,
+            scratch: &mut *self.scratch,
+            space: self.space,
+            tick_id: self.tick_id,
+            dt: self.dt,
+            sim_time: self.sim_time,
+            parameters: self.parameters,
+            neighbour_cache: Some(cache),
         }
     }
 
@@ -98,6 +165,29 @@ impl<'a> StepContext<'a> {
     pub fn dt(&self) -> f64 {
         self.dt
     }
+
+    /// Cumulative simulation time in seconds at the end of this tick.
+    pub fn sim_time(&self) -> f64 {
+        self.sim_time
+    }
+
+    /// Global parameter store, frozen for the duration of the tick.
+    pub fn parameters(&self) -> &ParameterStore {
+        self.parameters
+    }
+
+    /// Current value of the parameter `key`, or `None` if it was never set.
+    pub fn parameter(&self, key: ParameterKey) -> Option<f32> {
+        self.parameters.get(key).map(|v| v as f32)
+    }
+
+    /// Neighbour table shared by the enclosing
+    /// [`PropagatorGroup`](crate::PropagatorGroup), if any.
+    ///
+    /// When `None`, enumerate neighbours through [`space()`](Self::space).
+    pub fn neighbour_cache(&self) -> Option<&'a NeighbourCache> {
+        self.neighbour_cache
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +283,53 @@ mod tests {
         let buf = ctx.scratch().alloc(8).unwrap();
         assert_eq!(buf.len(), 8);
     }
+
+    #[test]
+    fn parameters_default_empty_and_attachable() {
+        let reader = MockFieldReader::new();
+        let mut writer = MockFieldWriter::new();
+        let mut scratch = ScratchRegion::new(0);
+        let space = Line1D::new(2, EdgeBehavior::Absorb).unwrap();
+
+        let ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            &space,
+            TickId(0),
+            0.1,
+        );
+        assert!(ctx.parameters().is_empty());
+        assert_eq!(ctx.parameter(ParameterKey(0)), None);
+
+        let mut params = ParameterStore::new();
+        params.set(ParameterKey(3), 0.5);
+        let ctx = ctx.with_parameters(&params);
+        assert_eq!(ctx.parameter(ParameterKey(3)), Some(0.5));
+        assert_eq!(ctx.parameter(ParameterKey(0)), None);
+        assert_eq!(ctx.parameters().len(), 1);
+    }
+
+    #[test]
+    fn sim_time_defaults_to_tick_times_dt() {
+        let reader = MockFieldReader::new();
+        let mut writer = MockFieldWriter::new();
+        let mut scratch = ScratchRegion::new(0);
+        let space = Line1D::new(2, EdgeBehavior::Absorb).unwrap();
+
+        let ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            &space,
+            TickId(4),
+            0.25,
+        );
+        assert_eq!(ctx.tick_id(), TickId(4));
+        assert_eq!(ctx.sim_time(), 1.0);
+        let ctx = ctx.with_sim_time(7.5);
+        assert_eq!(ctx.sim_time(), 7.5);
+    }
 }
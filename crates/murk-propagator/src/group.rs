@@ -0,0 +1,393 @@
+//! The [`PropagatorGroup`] combinator and its shared [`NeighbourCache`].
+//!
+//! Propagators on non-grid fast paths each enumerate every cell's
+//! neighbours through the [`Space`] on every tick. Grouping them runs the
+//! members back to back as a single pipeline stage and hands each one the
+//! same precomputed neighbour table through
+//! [`StepContext::neighbour_cache`], so the enumeration happens once.
+
+use crate::context::StepContext;
+use crate::propagator::{Propagator, WriteMode};
+use murk_core::{Coord, FieldId, FieldReader, FieldSet, PropagatorError};
+use murk_space::Space;
+
+/// Canonical ordering and neighbour ranks of every cell in a space.
+///
+/// Neighbours are listed in the order [`Space::neighbours`] returns them,
+/// resolved to canonical ranks; neighbours without a rank are omitted.
+/// Stored in compressed form: one flat rank array plus per-cell offsets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NeighbourCache {
+    ordering: Vec<Coord>,
+    offsets: Vec<usize>,
+    ranks: Vec<usize>,
+}
+
+impl NeighbourCache {
+    /// Enumerate the neighbours of every cell in `space`.
+    pub fn new(space: &dyn Space) -> Self {
+        let ordering = space.canonical_ordering();
+        let mut offsets = Vec::with_capacity(ordering.len() + 1);
+        let mut ranks = Vec::new();
+        let mut neighbours = Default::default();
+        offsets.push(0);
+        for coord in &ordering {
+            space.neighbours_into(coord, &mut neighbours);
+            ranks.extend(neighbours.iter().filter_map(|nb| space.canonical_rank(nb)));
+            offsets.push(ranks.len());
+        }
+        Self {
+            ordering,
+            offsets,
+            ranks,
+        }
+    }
+
+    /// Number of cells covered.
+    pub fn cell_count(&self) -> usize {
+        self.ordering.len()
+    }
+
+    /// All cell coordinates in canonical order.
+    pub fn ordering(&self) -> &[Coord] {
+        &self.ordering
+    }
+
+    /// Coordinate of the cell at canonical `rank`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rank >= cell_count()`.
+    pub fn coord(&self, rank: usize) -> &Coord {
+        &self.ordering[rank]
+    }
+
+    /// Canonical ranks of the neighbours of the cell at `rank`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rank >= cell_count()`.
+    pub fn neighbours(&self, rank: usize) -> &[usize] {
+        &self.ranks[self.offsets[rank]..self.offsets[rank + 1]]
+    }
+}
+
+/// Several propagators run as one pipeline stage over a shared
+/// [`NeighbourCache`].
+///
+/// The group declares the union of its members' reads, writes and
+/// conserved fields, the tightest member `max_dt`, and enough scratch for
+/// its largest member. Each tick it steps the members in order, resetting
+/// scratch between them and exposing the cache through
+/// [`StepContext::neighbour_cache`]. The cache is built in
+/// [`init`](Propagator::init); a group stepped without `init` builds a
+/// temporary one per tick.
+///
+/// The engine routes reads for the group as a whole, so a member's
+/// overlay [`reads`](Propagator::reads) cannot see fields written by an
+/// earlier member. [`new`](Self::new) rejects such groups, as well as two
+/// members writing the same field. Reading an earlier member's output
+/// through `reads_previous` is allowed and sees the tick-start value.
+pub struct PropagatorGroup {
+    name: String,
+    members: Vec<Box<dyn Propagator>>,
+    cache: Option<NeighbourCache>,
+}
+
+impl PropagatorGroup {
+    /// Group `members`, to be stepped in the given order.
+    ///
+    /// Fails if `members` is empty, two members write the same field, or a
+    /// member reads through the overlay a field an earlier member writes.
+    pub fn new(name: impl Into<String>, members: Vec<Box<dyn Propagator>>) -> Result<Self, String> {
+        let name = name.into();
+        if members.is_empty() {
+            return Err(format!("propagator group '{name}' has no members"));
+        }
+        let mut written = FieldSet::empty();
+        for member in &members {
+            let overlay = member.reads().union(&member.optional_reads());
+            if let Some(field) = overlay.intersection(&written).iter().next() {
+                return Err(format!(
+                    "propagator group '{name}': '{}' reads field {field:?} written earlier in the group",
+                    member.name()
+                ));
+            }
+            for (field, _) in member.writes() {
+                if written.contains(field) {
+                    return Err(format!(
+                        "propagator group '{name}': field {field:?} written by more than one member"
+                    ));
+                }
+                written.insert(field);
+            }
+        }
+        Ok(Self {
+            name,
+            members,
+            cache: None,
+        })
+    }
+
+    /// The grouped propagators, in step order.
+    pub fn members(&self) -> &[Box<dyn Propagator>] {
+        &self.members
+    }
+
+    /// The neighbour table built by [`init`](Propagator::init), if it has run.
+    pub fn neighbour_cache(&self) -> Option<&NeighbourCache> {
+        self.cache.as_ref()
+    }
+
+    fn union_of(&self, sets: impl Fn(&dyn Propagator) -> FieldSet) -> FieldSet {
+        self.members
+            .iter()
+            .fold(FieldSet::empty(), |acc, m| acc.union(&sets(m.as_ref())))
+    }
+}
+
+impl Propagator for PropagatorGroup {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn reads(&self) -> FieldSet {
+        self.union_of(|m| m.reads())
+    }
+
+    fn optional_reads(&self) -> FieldSet {
+        self.union_of(|m| m.optional_reads())
+    }
+
+    fn reads_previous(&self) -> FieldSet {
+        self.union_of(|m| m.reads_previous())
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        self.members.iter().flat_map(|m| m.writes()).collect()
+    }
+
+    fn conserved_fields(&self) -> FieldSet {
+        self.union_of(|m| m.conserved_fields())
+    }
+
+    fn max_dt(&self, space: &dyn Space) -> Option<f64> {
+        self.members
+            .iter()
+            .filter_map(|m| m.max_dt(space))
+            .reduce(f64::min)
+    }
+
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        for member in &mut self.members {
+            member.init(space)?;
+        }
+        self.cache = Some(NeighbourCache::new(space));
+        Ok(())
+    }
+
+    fn scratch_bytes(&self) -> usize {
+        self.members
+            .iter()
+            .map(|m| m.scratch_bytes())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn agent_positions(&self, state: &dyn FieldReader) -> Option<Vec<(u16, usize)>> {
+        self.members.iter().find_map(|m| m.agent_positions(state))
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let built;
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => {
+                built = NeighbourCache::new(ctx.space());
+                &built
+            }
+        };
+        for member in &self.members {
+            ctx.scratch().reset();
+            member.step(&mut ctx.nested(cache)).map_err(|e| match e {
+                PropagatorError::ExecutionFailed { reason } => PropagatorError::ExecutionFailed {
+                    reason: format!("{}: {reason}", member.name()),
+                },
+                other => other,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchRegion;
+    use murk_core::{Scalar, TickId};
+    use murk_space::{EdgeBehavior, Hex2D, Line1D};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+
+    /// Writes, per cell, its neighbour count as seen through the shared
+    /// cache (or -1 everywhere when no cache is attached).
+    struct CacheProbe {
+        output: FieldId,
+        reads: FieldSet,
+        max_dt: Option<f64>,
+    }
+
+    impl CacheProbe {
+        fn new(output: u32) -> Box<Self> {
+            Box::new(Self {
+                output: FieldId(output),
+                reads: FieldSet::empty(),
+                max_dt: None,
+            })
+        }
+    }
+
+    impl Propagator for CacheProbe {
+        fn name(&self) -> &str {
+            "cache_probe"
+        }
+
+        fn reads(&self) -> FieldSet {
+            self.reads.clone()
+        }
+
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(self.output, WriteMode::Full)]
+        }
+
+        fn max_dt(&self, _space: &dyn Space) -> Option<f64> {
+            self.max_dt
+        }
+
+        fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+            let counts: Vec<Scalar> = match ctx.neighbour_cache() {
+                Some(cache) => (0..cache.cell_count())
+                    .map(|i| cache.neighbours(i).len() as Scalar)
+                    .collect(),
+                None => vec![-1.0; ctx.space().cell_count()],
+            };
+            ctx.writes()
+                .write(self.output)
+                .ok_or_else(|| PropagatorError::ExecutionFailed {
+                    reason: "output not writable".into(),
+                })?
+                .copy_from_slice(&counts);
+            Ok(())
+        }
+    }
+
+    fn run(prop: &dyn Propagator, space: &dyn Space, outputs: &[FieldId]) -> Vec<Vec<Scalar>> {
+        let reader = MockFieldReader::new();
+        let mut writer = MockFieldWriter::new();
+        for &field in outputs {
+            writer.add_field(field, space.cell_count());
+        }
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            space,
+            TickId(1),
+            0.1,
+        );
+        prop.step(&mut ctx).unwrap();
+        outputs
+            .iter()
+            .map(|&f| writer.get_field(f).unwrap().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn cache_matches_space_neighbours() {
+        let space = Line1D::new(4, EdgeBehavior::Wrap).unwrap();
+        let cache = NeighbourCache::new(&space);
+        assert_eq!(cache.cell_count(), 4);
+        assert_eq!(cache.ordering(), space.canonical_ordering().as_slice());
+        for (i, coord) in space.canonical_ordering().iter().enumerate() {
+            let expected: Vec<usize> = space
+                .neighbours(coord)
+                .iter()
+                .filter_map(|nb| space.canonical_rank(nb))
+                .collect();
+            assert_eq!(cache.neighbours(i), expected.as_slice());
+            assert_eq!(cache.coord(i), coord);
+        }
+    }
+
+    #[test]
+    fn members_share_the_cache() {
+        let space = Hex2D::new(3, 3).unwrap();
+        let mut group =
+            PropagatorGroup::new("probes", vec![CacheProbe::new(0), CacheProbe::new(1)]).unwrap();
+
+        // Without init the group still attaches a per-tick cache.
+        let outputs = run(&group, &space, &[FieldId(0), FieldId(1)]);
+        assert_eq!(outputs[0], outputs[1]);
+        assert_eq!(outputs[0][4], 6.0);
+
+        group.init(&space).unwrap();
+        assert_eq!(group.neighbour_cache(), Some(&NeighbourCache::new(&space)));
+        assert_eq!(run(&group, &space, &[FieldId(0), FieldId(1)]), outputs);
+
+        // Outside a group there is no cache.
+        let alone = run(&*CacheProbe::new(0), &space, &[FieldId(0)]);
+        assert!(alone[0].iter().all(|&v| v == -1.0));
+    }
+
+    #[test]
+    fn declares_union_of_members() {
+        let mut a = CacheProbe::new(1);
+        a.reads = [FieldId(0)].into_iter().collect();
+        a.max_dt = Some(0.5);
+        let mut b = CacheProbe::new(2);
+        b.reads = [FieldId(3)].into_iter().collect();
+        b.max_dt = Some(0.25);
+        let group = PropagatorGroup::new("g", vec![a, b, CacheProbe::new(4)]).unwrap();
+
+        let space = Line1D::new(4, EdgeBehavior::Absorb).unwrap();
+        assert_eq!(group.name(), "g");
+        assert_eq!(group.members().len(), 3);
+        assert_eq!(
+            group.reads(),
+            [FieldId(0), FieldId(3)].into_iter().collect()
+        );
+        assert_eq!(
+            group.writes(),
+            vec![
+                (FieldId(1), WriteMode::Full),
+                (FieldId(2), WriteMode::Full),
+                (FieldId(4), WriteMode::Full)
+            ]
+        );
+        assert_eq!(group.max_dt(&space), Some(0.25));
+    }
+
+    #[test]
+    fn rejects_invalid_membership() {
+        assert!(PropagatorGroup::new("empty", vec![]).is_err());
+
+        let err = PropagatorGroup::new("dup", vec![CacheProbe::new(1), CacheProbe::new(1)])
+            .err()
+            .unwrap();
+        assert!(err.contains("more than one member"), "{err}");
+
+        // A later member reading an earlier member's output via the overlay.
+        let mut reader = CacheProbe::new(2);
+        reader.reads = [FieldId(1)].into_iter().collect();
+        let err = PropagatorGroup::new("chain", vec![CacheProbe::new(1), reader])
+            .err()
+            .unwrap();
+        assert!(err.contains("written earlier"), "{err}");
+
+        // The reverse order reads the tick's incoming value and is fine.
+        let mut reader = CacheProbe::new(2);
+        reader.reads = [FieldId(1)].into_iter().collect();
+        assert!(PropagatorGroup::new("ok", vec![reader, CacheProbe::new(1)]).is_ok());
+    }
+}
@@ -4,7 +4,7 @@
 //! tracks which cells have been written. On drop it logs a diagnostic if
 //! coverage is incomplete. Release builds pay zero overhead.
 
-use murk_core::FieldId;
+use murk_core::{FieldId, Scalar};
 
 /// Guard that tracks write coverage for [`WriteMode::Full`](crate::WriteMode) fields.
 ///
@@ -13,7 +13,7 @@ use murk_core::FieldId;
 ///
 /// In release builds, this is a transparent wrapper with zero overhead.
 pub struct FullWriteGuard<'a> {
-    data: &'a mut [f32],
+    data: &'a mut [Scalar],
     #[cfg(debug_assertions)]
     written: Vec<bool>,
     #[cfg(debug_assertions)]
@@ -28,7 +28,7 @@ impl<'a> FullWriteGuard<'a> {
     /// `propagator_name` and `field_id` are used for diagnostic messages
     /// in debug builds.
     pub fn new(
-        data: &'a mut [f32],
+        data: &'a mut [Scalar],
         #[cfg_attr(not(debug_assertions), allow(unused_variables))] propagator_name: &str,
         #[cfg_attr(not(debug_assertions), allow(unused_variables))] field_id: FieldId,
     ) -> Self {
@@ -44,7 +44,7 @@ impl<'a> FullWriteGuard<'a> {
     }
 
     /// Write a single value at the given index.
-    pub fn write_at(&mut self, index: usize, value: f32) {
+    pub fn write_at(&mut self, index: usize, value: Scalar) {
         self.data[index] = value;
         #[cfg(debug_assertions)]
         {
@@ -55,7 +55,7 @@ impl<'a> FullWriteGuard<'a> {
     /// Get the underlying slice for bulk writes.
     ///
     /// Marks ALL cells as written — assumes the caller fills the entire slice.
-    pub fn as_mut_slice(&mut self) -> &mut [f32] {
+    pub fn as_mut_slice(&mut self) -> &mut [Scalar] {
         #[cfg(debug_assertions)]
         {
             self.written.fill(true);
@@ -128,7 +128,7 @@ mod tests {
         let mut buf = vec![0.0; 4];
         let mut guard = FullWriteGuard::new(&mut buf, "test", FieldId(0));
         for i in 0..4 {
-            guard.write_at(i, i as f32);
+            guard.write_at(i, i as Scalar);
         }
         assert_eq!(guard.coverage(), 1.0);
     }
@@ -162,7 +162,7 @@ mod tests {
 
     #[test]
     fn empty_buffer_full_coverage() {
-        let mut buf: Vec<f32> = vec![];
+        let mut buf: Vec<Scalar> = vec![];
         let guard = FullWriteGuard::new(&mut buf, "test", FieldId(0));
         assert!(guard.is_empty());
         assert_eq!(guard.coverage(), 1.0);
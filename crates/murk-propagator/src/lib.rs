@@ -21,15 +21,22 @@
 #![forbid(unsafe_code)]
 
 pub mod context;
+pub mod group;
 pub mod guard;
 pub mod pipeline;
 pub mod propagator;
+pub mod region;
 pub mod scratch;
+pub mod stencil;
 
 pub use context::StepContext;
+pub use group::{NeighbourCache, PropagatorGroup};
 pub use guard::FullWriteGuard;
 pub use pipeline::{
-    validate_pipeline, PipelineError, ReadResolutionPlan, ReadSource, WriteConflict,
+    validate_pipeline, validate_static_writes, PipelineDiagnostic, PipelineError,
+    ReadResolutionPlan, ReadSource, WriteConflict,
 };
 pub use propagator::{Propagator, WriteMode};
+pub use region::RegionRestricted;
 pub use scratch::ScratchRegion;
+pub use stencil::StencilPropagator;
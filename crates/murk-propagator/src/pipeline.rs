@@ -6,7 +6,7 @@
 //! per-tick hot path.
 
 use indexmap::IndexMap;
-use murk_core::{FieldId, FieldSet};
+use murk_core::{FieldId, FieldSet, StepError};
 
 use crate::propagator::{Propagator, WriteMode};
 
@@ -33,6 +33,9 @@ pub enum ReadSource {
 /// Built once by [`validate_pipeline`]. The engine consults this plan
 /// to configure each propagator's `FieldReader` before calling `step()`
 /// and to seed [`WriteMode::Incremental`] buffers from the previous generation.
+///
+/// The plan also carries non-fatal [`diagnostics`](Self::diagnostics) about
+/// the pipeline's structure.
 #[derive(Debug)]
 #[must_use]
 pub struct ReadResolutionPlan {
@@ -40,6 +43,10 @@ pub struct ReadResolutionPlan {
     routes: Vec<IndexMap<FieldId, ReadSource>>,
     /// `write_modes[propagator_index]` maps `FieldId → WriteMode`.
     write_modes: Vec<IndexMap<FieldId, WriteMode>>,
+    /// Non-fatal findings, in pipeline order.
+    diagnostics: Vec<PipelineDiagnostic>,
+    /// Tightest `max_dt` over all propagators, if any is constrained.
+    max_dt: Option<f64>,
 }
 
 impl ReadResolutionPlan {
@@ -90,6 +97,53 @@ impl ReadResolutionPlan {
             None => Vec::new(),
         }
     }
+
+    /// Non-fatal findings from validation, in pipeline order.
+    ///
+    /// These never cause [`validate_pipeline`] to fail; they flag
+    /// configurations that are legal but usually unintended.
+    pub fn diagnostics(&self) -> &[PipelineDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// The binding CFL limit: the minimum `max_dt` over all propagators
+    /// for the validated space, or `None` if no propagator constrains `dt`.
+    pub fn max_dt(&self) -> Option<f64> {
+        self.max_dt
+    }
+}
+
+// ── Diagnostics ────────────────────────────────────────────────────
+
+/// A non-fatal finding reported on a [`ReadResolutionPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineDiagnostic {
+    /// A field is written but no propagator reads it, through `reads()`,
+    /// `optional_reads()`, or `reads_previous()`.
+    ///
+    /// Usually a configuration bug. The pipeline cannot see observation
+    /// specs, so a field that exists only to be observed is also reported;
+    /// callers that know their observed fields can filter those out.
+    UnreadWrite {
+        /// The unconsumed field.
+        field_id: FieldId,
+        /// Name of the propagator that writes it.
+        propagator: String,
+    },
+}
+
+impl fmt::Display for PipelineDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnreadWrite {
+                field_id,
+                propagator,
+            } => write!(
+                f,
+                "field {field_id:?} written by propagator '{propagator}' is never consumed"
+            ),
+        }
+    }
 }
 
 // ── Errors ─────────────────────────────────────────────────────────
@@ -122,14 +176,26 @@ pub enum PipelineError {
         field_id: FieldId,
     },
 
-    /// The configured dt exceeds a propagator's `max_dt`.
-    DtTooLarge {
-        /// The dt that was requested.
-        configured_dt: f64,
-        /// The tightest `max_dt` constraint.
-        max_supported: f64,
-        /// Which propagator constrains it.
-        constraining_propagator: String,
+    /// A propagator declares a write to a `Static` field.
+    ///
+    /// Static fields live in the shared generation-0 arena and are
+    /// immutable once the world is built.
+    StaticFieldWrite {
+        /// Which propagator.
+        propagator: String,
+        /// The static field.
+        field_id: FieldId,
+    },
+
+    /// The configured dt exceeds a propagator's `max_dt` (stability/CFL
+    /// limit). Converts into [`StepError::CflViolation`].
+    CflViolation {
+        /// Name of the propagator with the tightest `max_dt`.
+        propagator: String,
+        /// The requested timestep.
+        dt: f64,
+        /// The propagator's `max_dt` for the validated space.
+        max_dt: f64,
     },
 
     /// The configured dt is not a valid timestep (NaN, infinity, zero, or negative).
@@ -174,17 +240,25 @@ impl fmt::Display for PipelineError {
                     "propagator '{propagator}' references undefined field {field_id:?}"
                 )
             }
-            Self::DtTooLarge {
-                configured_dt,
-                max_supported,
-                constraining_propagator,
+            Self::StaticFieldWrite {
+                propagator,
+                field_id,
             } => {
                 write!(
                     f,
-                    "dt {configured_dt} exceeds max_dt {max_supported} \
-                     (constrained by '{constraining_propagator}')"
+                    "propagator '{propagator}' writes static field {field_id:?}"
                 )
             }
+            Self::CflViolation {
+                propagator,
+                dt,
+                max_dt,
+            } => write!(
+                f,
+                "dt {dt} exceeds max_dt {max_dt} of propagator '{propagator}' \
+                 ({:.2}x the stable limit)",
+                dt / max_dt
+            ),
             Self::InvalidDt { value } => {
                 write!(f, "dt must be finite and positive, got {value}")
             }
@@ -201,6 +275,28 @@ impl fmt::Display for PipelineError {
 
 impl Error for PipelineError {}
 
+/// Converts [`PipelineError::CflViolation`] into the matching
+/// [`StepError::CflViolation`]; other pipeline errors have no step-level
+/// counterpart and are returned unchanged.
+impl TryFrom<PipelineError> for StepError {
+    type Error = PipelineError;
+
+    fn try_from(e: PipelineError) -> Result<Self, PipelineError> {
+        match e {
+            PipelineError::CflViolation {
+                propagator,
+                dt,
+                max_dt,
+            } => Ok(StepError::CflViolation {
+                propagator,
+                dt,
+                max_dt,
+            }),
+            other => Err(other),
+        }
+    }
+}
+
 // ── Validation ─────────────────────────────────────────────────────
 
 /// Validate a propagator pipeline and build the [`ReadResolutionPlan`].
@@ -209,10 +305,14 @@ impl Error for PipelineError {}
 ///
 /// 1. Pipeline is non-empty.
 /// 2. No write-write conflicts (two propagators writing the same field).
-/// 3. All referenced field IDs exist in `defined_fields`.
-/// 4. `dt <= min(max_dt)` across all propagators.
+/// 3. All referenced field IDs exist in `defined_fields`. Optional reads
+///    of undefined fields are dropped from the plan rather than rejected.
+/// 4. `dt <= min(max_dt)` across all propagators, reported as
+///    [`PipelineError::CflViolation`].
 ///
-/// Returns the precomputed routing plan on success.
+/// Returns the precomputed routing plan on success. Written fields that no
+/// propagator reads are recorded as [`PipelineDiagnostic::UnreadWrite`] in
+/// [`ReadResolutionPlan::diagnostics`] without failing validation.
 pub fn validate_pipeline(
     propagators: &[Box<dyn Propagator>],
     defined_fields: &FieldSet,
@@ -235,6 +335,7 @@ pub fn validate_pipeline(
     struct PropMeta {
         name: String,
         reads: FieldSet,
+        optional_reads: FieldSet,
         reads_previous: FieldSet,
         writes: Vec<(FieldId, WriteMode)>,
         max_dt: Option<f64>,
@@ -245,6 +346,7 @@ pub fn validate_pipeline(
         .map(|p| PropMeta {
             name: p.name().to_string(),
             reads: p.reads(),
+            optional_reads: p.optional_reads().intersection(defined_fields),
             reads_previous: p.reads_previous(),
             writes: p.writes(),
             max_dt: p.max_dt(space),
@@ -302,7 +404,7 @@ pub fn validate_pipeline(
     }
 
     // 4. dt validation
-    {
+    let max_dt = {
         let mut min_max_dt = f64::INFINITY;
         let mut constraining = String::new();
         for meta in &metas {
@@ -320,13 +422,14 @@ pub fn validate_pipeline(
             }
         }
         if dt > min_max_dt {
-            return Err(PipelineError::DtTooLarge {
-                configured_dt: dt,
-                max_supported: min_max_dt,
-                constraining_propagator: constraining,
+            return Err(PipelineError::CflViolation {
+                propagator: constraining,
+                dt,
+                max_dt: min_max_dt,
             });
         }
-    }
+        min_max_dt.is_finite().then_some(min_max_dt)
+    };
 
     // 5. Build ReadResolutionPlan
     let mut last_writer: IndexMap<FieldId, usize> = IndexMap::new();
@@ -337,8 +440,8 @@ pub fn validate_pipeline(
         let mut prop_routes = IndexMap::new();
         let mut prop_write_modes = IndexMap::new();
 
-        // Route reads() through overlay
-        for field_id in meta.reads.iter() {
+        // Route reads() and defined optional_reads() through overlay
+        for field_id in meta.reads.iter().chain(meta.optional_reads.iter()) {
             let source = if let Some(&j) = last_writer.get(&field_id) {
                 ReadSource::Staged { writer_index: j }
             } else {
@@ -361,12 +464,62 @@ pub fn validate_pipeline(
         write_modes.push(prop_write_modes);
     }
 
+    // 6. Diagnostics: written fields no propagator consumes
+    let consumed: FieldSet = metas
+        .iter()
+        .flat_map(|m| {
+            m.reads
+                .iter()
+                .chain(m.optional_reads.iter())
+                .chain(m.reads_previous.iter())
+        })
+        .collect();
+    let diagnostics = metas
+        .iter()
+        .flat_map(|meta| {
+            meta.writes
+                .iter()
+                .filter(|&&(field_id, _)| !consumed.contains(field_id))
+                .map(|&(field_id, _)| PipelineDiagnostic::UnreadWrite {
+                    field_id,
+                    propagator: meta.name.clone(),
+                })
+        })
+        .collect();
+
     Ok(ReadResolutionPlan {
         routes,
         write_modes,
+        diagnostics,
+        max_dt,
     })
 }
 
+/// Check that no propagator writes a field in `static_fields`.
+///
+/// Static fields live in the shared generation-0 arena and cannot be
+/// written once the world is built. Run alongside [`validate_pipeline`];
+/// the first offending write is reported as
+/// [`PipelineError::StaticFieldWrite`].
+pub fn validate_static_writes(
+    propagators: &[Box<dyn Propagator>],
+    static_fields: &FieldSet,
+) -> Result<(), PipelineError> {
+    for prop in propagators {
+        if let Some((field_id, _)) = prop
+            .writes()
+            .into_iter()
+            .find(|(id, _)| static_fields.contains(*id))
+        {
+            return Err(PipelineError::StaticFieldWrite {
+                propagator: prop.name().to_string(),
+                field_id,
+            });
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -486,6 +639,47 @@ mod tests {
         }
     }
 
+    /// Reads field C via reads_previous, writes field A — closes the
+    /// PropAB → PropBC chain into a cycle.
+    struct PropCA;
+    impl Propagator for PropCA {
+        fn name(&self) -> &str {
+            "PropCA"
+        }
+        fn reads(&self) -> FieldSet {
+            FieldSet::empty()
+        }
+        fn reads_previous(&self) -> FieldSet {
+            [FieldId(2)].into_iter().collect()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(FieldId(0), WriteMode::Full)]
+        }
+        fn step(&self, _ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+            Ok(())
+        }
+    }
+
+    /// Optionally reads field 2 via the overlay, writes field 0.
+    struct PropOptional;
+    impl Propagator for PropOptional {
+        fn name(&self) -> &str {
+            "PropOptional"
+        }
+        fn reads(&self) -> FieldSet {
+            FieldSet::empty()
+        }
+        fn optional_reads(&self) -> FieldSet {
+            [FieldId(2)].into_iter().collect()
+        }
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(FieldId(0), WriteMode::Full)]
+        }
+        fn step(&self, _ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+            Ok(())
+        }
+    }
+
     fn fields_0_1_2() -> FieldSet {
         [FieldId(0), FieldId(1), FieldId(2)].into_iter().collect()
     }
@@ -525,6 +719,34 @@ mod tests {
         assert_eq!(plan.source(0, FieldId(0)), None);
     }
 
+    // ── Diagnostics ────────────────────────────────────────────
+
+    #[test]
+    fn orphan_write_reported_as_diagnostic() {
+        // PropBC writes field 2, which nothing reads.
+        let props: Vec<Box<dyn Propagator>> = vec![Box::new(PropAB), Box::new(PropBC)];
+        let plan = validate_pipeline(&props, &fields_0_1_2(), 0.1, &*test_space()).unwrap();
+        assert_eq!(
+            plan.diagnostics(),
+            &[PipelineDiagnostic::UnreadWrite {
+                field_id: FieldId(2),
+                propagator: "PropBC".to_string(),
+            }]
+        );
+        assert_eq!(
+            plan.diagnostics()[0].to_string(),
+            "field FieldId(2) written by propagator 'PropBC' is never consumed"
+        );
+    }
+
+    #[test]
+    fn fully_consumed_pipeline_has_no_diagnostics() {
+        let props: Vec<Box<dyn Propagator>> =
+            vec![Box::new(PropAB), Box::new(PropBC), Box::new(PropCA)];
+        let plan = validate_pipeline(&props, &fields_0_1_2(), 0.1, &*test_space()).unwrap();
+        assert!(plan.diagnostics().is_empty(), "{:?}", plan.diagnostics());
+    }
+
     // ── Empty pipeline ─────────────────────────────────────────
 
     #[test]
@@ -578,6 +800,25 @@ mod tests {
         assert!(matches!(result, Err(PipelineError::UndefinedField { .. })));
     }
 
+    #[test]
+    fn static_field_write_rejected() {
+        // PropAB writes field 1, which is declared Static
+        let props: Vec<Box<dyn Propagator>> = vec![Box::new(PropAB)];
+        let statics: FieldSet = [FieldId(1)].into_iter().collect();
+        assert!(validate_pipeline(&props, &fields_0_1_2(), 0.1, &*test_space()).is_ok());
+        assert!(validate_static_writes(&props, &FieldSet::empty()).is_ok());
+        match validate_static_writes(&props, &statics) {
+            Err(PipelineError::StaticFieldWrite {
+                propagator,
+                field_id,
+            }) => {
+                assert_eq!(propagator, "PropAB");
+                assert_eq!(field_id, FieldId(1));
+            }
+            other => panic!("expected StaticFieldWrite, got {other:?}"),
+        }
+    }
+
     #[test]
     fn undefined_reads_previous_field_rejected() {
         // PropJacobi reads_previous field 0 — define only field 1
@@ -587,6 +828,27 @@ mod tests {
         assert!(matches!(result, Err(PipelineError::UndefinedField { .. })));
     }
 
+    #[test]
+    fn undefined_optional_read_is_dropped_from_plan() {
+        let props: Vec<Box<dyn Propagator>> = vec![Box::new(PropOptional)];
+        let fields = [FieldId(0)].into_iter().collect();
+        let plan = validate_pipeline(&props, &fields, 0.1, &*test_space()).unwrap();
+        assert_eq!(plan.source(0, FieldId(2)), None);
+    }
+
+    #[test]
+    fn defined_optional_read_is_routed_like_a_read() {
+        // PropBC writes field 2 before PropOptional reads it.
+        let props: Vec<Box<dyn Propagator>> =
+            vec![Box::new(PropAB), Box::new(PropBC), Box::new(PropOptional)];
+        let plan = validate_pipeline(&props, &fields_0_1_2(), 0.1, &*test_space()).unwrap();
+        assert_eq!(
+            plan.source(2, FieldId(2)),
+            Some(ReadSource::Staged { writer_index: 1 })
+        );
+        assert!(plan.diagnostics().is_empty(), "{:?}", plan.diagnostics());
+    }
+
     // ── dt validation ──────────────────────────────────────────
 
     #[test]
@@ -594,7 +856,8 @@ mod tests {
         let props: Vec<Box<dyn Propagator>> = vec![Box::new(PropDtConstrained { max: 0.5 })];
         let fields = [FieldId(0)].into_iter().collect();
         assert!(validate_pipeline(&props, &fields, 0.5, &*test_space()).is_ok());
-        assert!(validate_pipeline(&props, &fields, 0.1, &*test_space()).is_ok());
+        let plan = validate_pipeline(&props, &fields, 0.1, &*test_space()).unwrap();
+        assert_eq!(plan.max_dt(), Some(0.5));
     }
 
     #[test]
@@ -603,19 +866,44 @@ mod tests {
         let fields = [FieldId(0)].into_iter().collect();
         let result = validate_pipeline(&props, &fields, 1.0, &*test_space());
         match result {
-            Err(PipelineError::DtTooLarge {
-                configured_dt,
-                max_supported,
-                constraining_propagator,
+            Err(PipelineError::CflViolation {
+                propagator,
+                dt,
+                max_dt,
             }) => {
-                assert_eq!(configured_dt, 1.0);
-                assert_eq!(max_supported, 0.5);
-                assert_eq!(constraining_propagator, "PropDtConstrained");
+                assert_eq!(dt, 1.0);
+                assert_eq!(max_dt, 0.5);
+                assert_eq!(propagator, "PropDtConstrained");
             }
-            other => panic!("expected DtTooLarge, got {other:?}"),
+            other => panic!("expected CflViolation, got {other:?}"),
         }
     }
 
+    #[test]
+    fn cfl_violation_converts_to_step_error() {
+        let cfl = PipelineError::CflViolation {
+            propagator: "diffusion".into(),
+            dt: 0.5,
+            max_dt: 0.25,
+        };
+        assert_eq!(
+            StepError::try_from(cfl.clone()),
+            Ok(StepError::CflViolation {
+                propagator: "diffusion".into(),
+                dt: 0.5,
+                max_dt: 0.25,
+            })
+        );
+        assert_eq!(
+            cfl.to_string(),
+            StepError::try_from(cfl).unwrap().to_string()
+        );
+        assert_eq!(
+            StepError::try_from(PipelineError::EmptyPipeline),
+            Err(PipelineError::EmptyPipeline)
+        );
+    }
+
     #[test]
     fn dt_constrained_by_tightest() {
         // Two propagators: max_dt 0.5 and 0.2. dt=0.3 should fail.
@@ -660,13 +948,10 @@ mod tests {
         let fields = [FieldId(0), FieldId(1)].into_iter().collect();
         let result = validate_pipeline(&props, &fields, 0.3, &*test_space());
         match result {
-            Err(PipelineError::DtTooLarge {
-                constraining_propagator,
-                ..
-            }) => {
-                assert_eq!(constraining_propagator, "PropDt02");
+            Err(PipelineError::CflViolation { propagator, .. }) => {
+                assert_eq!(propagator, "PropDt02");
             }
-            other => panic!("expected DtTooLarge, got {other:?}"),
+            other => panic!("expected CflViolation, got {other:?}"),
         }
     }
 
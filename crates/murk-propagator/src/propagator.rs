@@ -5,7 +5,7 @@
 //! engine to validate the pipeline and precompute overlay routing.
 
 use crate::context::StepContext;
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{FieldId, FieldReader, FieldSet, PropagatorError};
 
 /// Write initialization strategy for a field.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -42,11 +42,11 @@ pub enum WriteMode {
 ///
 /// ```
 /// use murk_propagator::{Propagator, StepContext, WriteMode};
-/// use murk_core::{FieldId, FieldSet, PropagatorError};
+/// use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 ///
 /// struct ConstantFill {
 ///     field: FieldId,
-///     value: f32,
+///     value: Scalar,
 /// }
 ///
 /// impl Propagator for ConstantFill {
@@ -78,6 +78,18 @@ pub trait Propagator: Send + 'static {
     /// in the current tick (Euler-style sequential integration).
     fn reads(&self) -> FieldSet;
 
+    /// Fields this propagator reads via `ctx.reads()` only if they exist.
+    ///
+    /// Unlike [`reads()`](Self::reads), an optional read of a field the
+    /// world does not define is not a validation error: `ctx.reads().read()`
+    /// simply returns `None` for it. Defined optional fields are routed
+    /// through the overlay exactly like ordinary reads.
+    ///
+    /// Default: empty set.
+    fn optional_reads(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
     /// Fields this propagator reads from the frozen tick-start view.
     ///
     /// Reading through `ctx.reads_previous()` always sees the base
@@ -93,6 +105,19 @@ pub trait Propagator: Send + 'static {
     /// Called once at pipeline construction, not per-tick.
     fn writes(&self) -> Vec<(FieldId, WriteMode)>;
 
+    /// Written fields whose total over all cells this propagator preserves.
+    ///
+    /// A propagator that only moves quantity between cells (no sources,
+    /// sinks, or clamping) declares the fields here. When the world's
+    /// conservation audit is enabled, the engine sums each declared field
+    /// before and after `step()` and flags any drift beyond tolerance.
+    /// Fields not in [`writes()`](Self::writes) are ignored.
+    ///
+    /// Default: empty set.
+    fn conserved_fields(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
     /// Maximum stable timestep for this propagator (e.g., CFL).
     ///
     /// The `space` parameter provides topology information (e.g., neighbor
@@ -105,19 +130,44 @@ pub trait Propagator: Send + 'static {
         None
     }
 
-    /// Scratch memory required **in bytes** (not f32 slots).
+    /// One-time setup against the world's space.
+    ///
+    /// Called by the engine exactly once, after pipeline validation and
+    /// before the first tick (not again on reset). Use it to precompute
+    /// space-dependent data, such as neighbour rank tables, that `step()`
+    /// would otherwise rebuild every tick. An error aborts world creation.
+    ///
+    /// Default: no-op.
+    fn init(&mut self, _space: &dyn murk_space::Space) -> Result<(), PropagatorError> {
+        Ok(())
+    }
+
+    /// Scratch memory required **in bytes** (not `Scalar` slots).
     ///
-    /// The engine allocates `max(scratch_bytes())` across all propagators
-    /// and converts to f32 slots via `ScratchRegion::with_byte_capacity()`.
-    /// The bump pointer is reset between each `step()` call.
+    /// The engine allocates a separate region of at least this many bytes
+    /// for this propagator (nothing when it returns 0), converting to `Scalar`
+    /// slots via `ScratchRegion::with_byte_capacity()`. The bump pointer is
+    /// reset before each `step()` call.
     ///
     /// **Important:** Do not pass the return value directly to
-    /// `ScratchRegion::new()`, which expects f32 slot counts.
+    /// `ScratchRegion::new()`, which expects `Scalar` slot counts.
     /// Use `ScratchRegion::with_byte_capacity()` instead.
     fn scratch_bytes(&self) -> usize {
         0
     }
 
+    /// Agents this propagator moves, as `(agent_id, canonical_rank)` pairs
+    /// sorted by `agent_id`.
+    ///
+    /// `state` reads the current (most recently published) generation.
+    /// Propagators that track agents in a field decode it here so callers
+    /// can query positions without mirroring them externally.
+    ///
+    /// Default: `None` (this propagator does not manage agents).
+    fn agent_positions(&self, _state: &dyn FieldReader) -> Option<Vec<(u16, usize)>> {
+        None
+    }
+
     /// Execute the propagator for one tick.
     ///
     /// Called once per tick in dependency order. The [`StepContext`]
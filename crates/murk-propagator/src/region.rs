@@ -0,0 +1,263 @@
+//! The [`RegionRestricted`] combinator: run a propagator inside a region.
+//!
+//! The wrapped propagator steps as usual, but only cells inside the region
+//! keep its output. Every written field is declared
+//! [`WriteMode::Incremental`], so the engine seeds the buffer from the
+//! previous generation; cells outside the region are restored to that seed
+//! after the inner step and stay frozen.
+
+use crate::context::StepContext;
+use crate::propagator::{Propagator, WriteMode};
+use murk_core::{FieldId, FieldReader, FieldSet, PropagatorError, Scalar};
+use murk_space::{RegionSpec, Space};
+
+/// A propagator whose writes are confined to a [`RegionSpec`].
+///
+/// Declares the inner propagator's reads, `max_dt` and scratch unchanged.
+/// Writes are all [`WriteMode::Incremental`] and no fields are declared
+/// conserved, since exchange across the region boundary is one-sided.
+///
+/// The region is compiled in [`init`](Propagator::init); a wrapper
+/// stepped without `init` compiles it every tick.
+pub struct RegionRestricted<P: Propagator> {
+    name: String,
+    inner: P,
+    region: RegionSpec,
+    outside: Option<Vec<usize>>,
+}
+
+impl<P: Propagator> RegionRestricted<P> {
+    /// Restrict `inner` to the cells of `region`.
+    pub fn new(inner: P, region: RegionSpec) -> Self {
+        Self {
+            name: format!("{} (region-restricted)", inner.name()),
+            inner,
+            region,
+            outside: None,
+        }
+    }
+
+    /// The wrapped propagator.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// The region writes are confined to.
+    pub fn region(&self) -> &RegionSpec {
+        &self.region
+    }
+
+    /// Canonical ranks of the cells outside `region`, ascending.
+    fn outside_ranks(&self, space: &dyn Space) -> Result<Vec<usize>, PropagatorError> {
+        let plan =
+            space
+                .compile_region(&self.region)
+                .map_err(|e| PropagatorError::ExecutionFailed {
+                    reason: format!("{}: region does not compile: {e}", self.name),
+                })?;
+        let mut inside = vec![false; space.cell_count()];
+        for coord in plan.coords() {
+            if let Some(rank) = space.canonical_rank(coord) {
+                inside[rank] = true;
+            }
+        }
+        Ok((0..inside.len()).filter(|&r| !inside[r]).collect())
+    }
+}
+
+impl<P: Propagator> Propagator for RegionRestricted<P> {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn reads(&self) -> FieldSet {
+        self.inner.reads()
+    }
+
+    fn optional_reads(&self) -> FieldSet {
+        self.inner.optional_reads()
+    }
+
+    fn reads_previous(&self) -> FieldSet {
+        self.inner.reads_previous()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        self.inner
+            .writes()
+            .into_iter()
+            .map(|(field, _)| (field, WriteMode::Incremental))
+            .collect()
+    }
+
+    fn max_dt(&self, space: &dyn Space) -> Option<f64> {
+        self.inner.max_dt(space)
+    }
+
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        self.inner.init(space)?;
+        self.outside = Some(self.outside_ranks(space)?);
+        Ok(())
+    }
+
+    fn scratch_bytes(&self) -> usize {
+        self.inner.scratch_bytes()
+    }
+
+    fn agent_positions(&self, state: &dyn FieldReader) -> Option<Vec<(u16, usize)>> {
+        self.inner.agent_positions(state)
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let built;
+        let outside = match &self.outside {
+            Some(outside) => outside,
+            None => {
+                built = self.outside_ranks(ctx.space())?;
+                &built
+            }
+        };
+        let cell_count = ctx.space().cell_count();
+
+        // Save the seeded out-of-region values of every written field.
+        let mut saved: Vec<(FieldId, usize, Vec<Scalar>)> = Vec::new();
+        for (field, _) in self.inner.writes() {
+            let Some(data) = ctx.writes().write(field) else {
+                continue;
+            };
+            let comps = data.len().checked_div(cell_count).unwrap_or(0);
+            let values = outside
+                .iter()
+                .flat_map(|&r| data[r * comps..(r + 1) * comps].iter().copied())
+                .collect();
+            saved.push((field, comps, values));
+        }
+
+        self.inner.step(ctx)?;
+
+        for (field, comps, values) in saved {
+            if let Some(data) = ctx.writes().write(field) {
+                for (i, &r) in outside.iter().enumerate() {
+                    data[r * comps..(r + 1) * comps]
+                        .copy_from_slice(&values[i * comps..(i + 1) * comps]);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchRegion;
+    use murk_core::{FieldWriter, TickId};
+    use murk_space::{EdgeBehavior, Square4};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+
+    /// Writes `cell_rank + 100` to every cell of `output`.
+    struct FillRank {
+        output: FieldId,
+    }
+
+    impl Propagator for FillRank {
+        fn name(&self) -> &str {
+            "fill_rank"
+        }
+
+        fn reads(&self) -> FieldSet {
+            FieldSet::empty()
+        }
+
+        fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+            vec![(self.output, WriteMode::Full)]
+        }
+
+        fn max_dt(&self, _space: &dyn Space) -> Option<f64> {
+            Some(0.5)
+        }
+
+        fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+            let out = ctx.writes().write(self.output).unwrap();
+            for (i, v) in out.iter_mut().enumerate() {
+                *v = i as Scalar + 100.0;
+            }
+            Ok(())
+        }
+    }
+
+    fn central_rect() -> RegionSpec {
+        RegionSpec::Rect {
+            min: vec![1, 1].into(),
+            max: vec![2, 2].into(),
+        }
+    }
+
+    fn run(prop: &RegionRestricted<FillRank>, space: &Square4) -> Vec<Scalar> {
+        let reader = MockFieldReader::new();
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(FieldId(0), space.cell_count());
+        writer.write(FieldId(0)).unwrap().fill(-1.0);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            space,
+            TickId(1),
+            0.1,
+        );
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(FieldId(0)).unwrap().to_vec()
+    }
+
+    #[test]
+    fn declares_inner_fields_with_incremental_writes() {
+        let space = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let prop = RegionRestricted::new(FillRank { output: FieldId(0) }, central_rect());
+        assert_eq!(prop.name(), "fill_rank (region-restricted)");
+        assert_eq!(prop.writes(), vec![(FieldId(0), WriteMode::Incremental)]);
+        assert_eq!(prop.max_dt(&space), Some(0.5));
+        assert!(prop.conserved_fields().is_empty());
+    }
+
+    #[test]
+    fn only_in_region_cells_are_updated() {
+        let space = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let mut prop = RegionRestricted::new(FillRank { output: FieldId(0) }, central_rect());
+        prop.init(&space).unwrap();
+        let out = run(&prop, &space);
+        for (rank, &v) in out.iter().enumerate() {
+            let (r, c) = (rank / 4, rank % 4);
+            if (1..=2).contains(&r) && (1..=2).contains(&c) {
+                assert_eq!(v, rank as Scalar + 100.0, "cell {rank} inside");
+            } else {
+                assert_eq!(v, -1.0, "cell {rank} outside");
+            }
+        }
+    }
+
+    #[test]
+    fn step_without_init_matches_initialized() {
+        let space = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let lazy = RegionRestricted::new(FillRank { output: FieldId(0) }, central_rect());
+        let mut eager = RegionRestricted::new(FillRank { output: FieldId(0) }, central_rect());
+        eager.init(&space).unwrap();
+        assert_eq!(run(&lazy, &space), run(&eager, &space));
+    }
+
+    #[test]
+    fn invalid_region_fails_init() {
+        let space = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let mut prop = RegionRestricted::new(
+            FillRank { output: FieldId(0) },
+            RegionSpec::Rect {
+                min: vec![0, 0].into(),
+                max: vec![9, 9].into(),
+            },
+        );
+        let err = prop.init(&space).unwrap_err();
+        assert!(err.to_string().contains("region does not compile"), "{err}");
+    }
+}
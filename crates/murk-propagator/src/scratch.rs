@@ -1,20 +1,23 @@
 //! Pre-allocated scratch memory for propagators.
 //!
 //! Each propagator declares [`scratch_bytes()`](crate::Propagator::scratch_bytes)
-//! at registration. The engine pre-allocates the maximum across all propagators
-//! and resets the bump pointer between each `step()` call.
+//! at registration. The engine pre-allocates one region per propagator, sized
+//! to that propagator's own request, and resets the bump pointer before each
+//! `step()` call.
+
+use murk_core::Scalar;
 
 /// Bump-allocated scratch region reset between propagators.
 ///
-/// Prevents heap allocation in the inner loop. All scratch data is `f32`
-/// (matching field storage), allocated as contiguous slices.
+/// Prevents heap allocation in the inner loop. All scratch data is
+/// [`Scalar`] (matching field storage), allocated as contiguous slices.
 pub struct ScratchRegion {
-    buf: Vec<f32>,
+    buf: Vec<Scalar>,
     offset: usize,
 }
 
 impl ScratchRegion {
-    /// Create a new scratch region with the given capacity **in f32 slots**
+    /// Create a new scratch region with the given capacity **in `Scalar` slots**
     /// (not bytes).
     ///
     /// If you have a byte count (e.g., from `Propagator::scratch_bytes()`),
@@ -26,20 +29,20 @@ impl ScratchRegion {
         }
     }
 
-    /// Create from a **byte** capacity (rounded up to whole f32 slots).
+    /// Create from a **byte** capacity (rounded up to whole `Scalar` slots).
     ///
     /// This is the correct constructor when using the value from
     /// [`Propagator::scratch_bytes()`](crate::Propagator::scratch_bytes).
     pub fn with_byte_capacity(bytes: usize) -> Self {
-        let slot_size = std::mem::size_of::<f32>();
+        let slot_size = std::mem::size_of::<Scalar>();
         // Overflow-safe ceiling division (avoids bytes + slot_size - 1 wrapping).
         Self::new(bytes / slot_size + usize::from(!bytes.is_multiple_of(slot_size)))
     }
 
-    /// Allocate `count` contiguous f32 slots, zero-initialized.
+    /// Allocate `count` contiguous `Scalar` slots, zero-initialized.
     ///
     /// Returns `None` if insufficient capacity remains.
-    pub fn alloc(&mut self, count: usize) -> Option<&mut [f32]> {
+    pub fn alloc(&mut self, count: usize) -> Option<&mut [Scalar]> {
         let new_offset = self.offset.checked_add(count)?;
         if new_offset > self.buf.len() {
             return None;
@@ -55,7 +58,7 @@ impl ScratchRegion {
         self.offset = 0;
     }
 
-    /// Total capacity in f32 slots.
+    /// Total capacity in `Scalar` slots.
     pub fn capacity(&self) -> usize {
         self.buf.len()
     }
@@ -100,14 +103,16 @@ mod tests {
 
     #[test]
     fn from_byte_capacity() {
-        let s = ScratchRegion::with_byte_capacity(16);
-        assert_eq!(s.capacity(), 4); // 16 bytes / 4 bytes per f32
+        let slot_size = std::mem::size_of::<Scalar>();
+        let s = ScratchRegion::with_byte_capacity(4 * slot_size);
+        assert_eq!(s.capacity(), 4);
     }
 
     #[test]
     fn from_byte_capacity_rounds_up() {
-        // 5 bytes should yield 2 f32 slots (8 bytes), not 1 (4 bytes).
-        let s = ScratchRegion::with_byte_capacity(5);
+        // One byte past a slot should yield 2 slots, not 1.
+        let slot_size = std::mem::size_of::<Scalar>();
+        let s = ScratchRegion::with_byte_capacity(slot_size + 1);
         assert_eq!(s.capacity(), 2);
         // 1 byte should still get 1 slot.
         let s = ScratchRegion::with_byte_capacity(1);
@@ -116,7 +121,7 @@ mod tests {
         let s = ScratchRegion::with_byte_capacity(0);
         assert_eq!(s.capacity(), 0);
         // Exact multiple is unchanged.
-        let s = ScratchRegion::with_byte_capacity(8);
+        let s = ScratchRegion::with_byte_capacity(2 * slot_size);
         assert_eq!(s.capacity(), 2);
     }
 
@@ -124,8 +129,8 @@ mod tests {
     fn from_byte_capacity_no_overflow_at_usize_max() {
         // The old (bytes + slot_size - 1) / slot_size formula would overflow.
         // Verify the safe formula produces the correct ceiling division.
-        let slot_size = std::mem::size_of::<f32>(); // 4
-        let expected = usize::MAX / slot_size + 1; // ceil(usize::MAX / 4)
+        let slot_size = std::mem::size_of::<Scalar>();
+        let expected = usize::MAX / slot_size + 1; // ceil(usize::MAX / slot_size)
                                                    // We can't actually allocate this, but verify the arithmetic is correct.
                                                    // Use the same formula as with_byte_capacity directly:
         let slots = usize::MAX / slot_size + usize::from(usize::MAX % slot_size != 0);
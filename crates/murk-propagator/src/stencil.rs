@@ -0,0 +1,247 @@
+//! The [`StencilPropagator`] helper trait.
+//!
+//! Many propagators follow the same shape: read a field from the frozen
+//! tick-start view, compute each cell from its own value and its
+//! neighbours' values, and write the result in full. Implementing
+//! [`StencilPropagator`] supplies only the per-cell kernel and the field
+//! pair; a blanket [`Propagator`] impl does the rest.
+
+use crate::context::StepContext;
+use crate::propagator::{Propagator, WriteMode};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
+
+/// A Jacobi-style per-cell stencil over one scalar field.
+///
+/// Every `StencilPropagator` is a [`Propagator`] through a blanket impl
+/// that:
+///
+/// - reads [`input_field`](Self::input_field) via `reads_previous()`,
+/// - writes [`output_field`](Self::output_field) with [`WriteMode::Full`],
+/// - calls [`kernel`](Self::kernel) once per cell in canonical order, with
+///   the values of the cell's neighbours as resolved by the space (cells
+///   beyond an absorbing edge are omitted, wrapped edges resolve to the
+///   opposite side).
+///
+/// Input and output may be the same field. Both must be scalar
+/// (`cell_count` values); a length mismatch fails the step.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::{FieldId, Scalar};
+/// use murk_propagator::StencilPropagator;
+///
+/// /// Replace each cell with the mean of itself and its neighbours.
+/// struct BoxBlur;
+///
+/// impl StencilPropagator for BoxBlur {
+///     fn name(&self) -> &str { "box_blur" }
+///     fn input_field(&self) -> FieldId { FieldId(0) }
+///     fn output_field(&self) -> FieldId { FieldId(0) }
+///
+///     fn kernel(&self, center: Scalar, neighbours: &[Scalar], _dt: f64) -> Scalar {
+///         (center + neighbours.iter().sum::<Scalar>()) / (neighbours.len() + 1) as Scalar
+///     }
+/// }
+/// ```
+pub trait StencilPropagator: Send + 'static {
+    /// Human-readable name for error reporting and telemetry.
+    fn name(&self) -> &str;
+
+    /// Field read from the frozen tick-start view.
+    fn input_field(&self) -> FieldId;
+
+    /// Field written with the kernel's results.
+    fn output_field(&self) -> FieldId;
+
+    /// New value of a cell given its previous value and its neighbours'
+    /// previous values (in the space's neighbour order).
+    fn kernel(&self, center: Scalar, neighbours: &[Scalar], dt: f64) -> Scalar;
+
+    /// Maximum stable timestep; see [`Propagator::max_dt`].
+    ///
+    /// Default: `None`.
+    fn max_dt(&self, _space: &dyn murk_space::Space) -> Option<f64> {
+        None
+    }
+}
+
+impl<T: StencilPropagator> Propagator for T {
+    fn name(&self) -> &str {
+        StencilPropagator::name(self)
+    }
+
+    fn reads(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
+    fn reads_previous(&self) -> FieldSet {
+        [self.input_field()].into_iter().collect()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        vec![(self.output_field(), WriteMode::Full)]
+    }
+
+    fn max_dt(&self, space: &dyn murk_space::Space) -> Option<f64> {
+        StencilPropagator::max_dt(self, space)
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let input = self.input_field();
+        let output = self.output_field();
+        let dt = ctx.dt();
+
+        let ordering = ctx.space().canonical_ordering();
+        let cell_count = ordering.len();
+        let prev = ctx
+            .reads_previous()
+            .read(input)
+            .ok_or_else(|| PropagatorError::ExecutionFailed {
+                reason: format!("input field {input:?} not readable"),
+            })?
+            .to_vec();
+        if prev.len() != cell_count {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "input field length {} != cell_count {cell_count}",
+                    prev.len()
+                ),
+            });
+        }
+
+        let mut next = Vec::with_capacity(cell_count);
+        let mut values = Vec::new();
+        for (i, coord) in ordering.iter().enumerate() {
+            values.clear();
+            values.extend(
+                ctx.space()
+                    .neighbours(coord)
+                    .iter()
+                    .filter_map(|nb| ctx.space().canonical_rank(nb))
+                    .map(|rank| prev[rank]),
+            );
+            next.push(self.kernel(prev[i], &values, dt));
+        }
+
+        let out = ctx
+            .writes()
+            .write(output)
+            .ok_or_else(|| PropagatorError::ExecutionFailed {
+                reason: format!("output field {output:?} not writable"),
+            })?;
+        if out.len() != cell_count {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "output field length {} != cell_count {cell_count}",
+                    out.len()
+                ),
+            });
+        }
+        out.copy_from_slice(&next);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scratch::ScratchRegion;
+    use murk_core::TickId;
+    use murk_space::{EdgeBehavior, Line1D};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+
+    /// Writes the sum of the neighbours minus the centre.
+    struct NeighbourSum {
+        output: FieldId,
+    }
+
+    impl StencilPropagator for NeighbourSum {
+        fn name(&self) -> &str {
+            "neighbour_sum"
+        }
+
+        fn input_field(&self) -> FieldId {
+            FieldId(0)
+        }
+
+        fn output_field(&self) -> FieldId {
+            self.output
+        }
+
+        fn kernel(&self, center: Scalar, neighbours: &[Scalar], _dt: f64) -> Scalar {
+            neighbours.iter().sum::<Scalar>() - center
+        }
+    }
+
+    fn run(
+        prop: &dyn Propagator,
+        edge: EdgeBehavior,
+        output: FieldId,
+        out_len: usize,
+    ) -> Vec<Scalar> {
+        let space = Line1D::new(4, edge).unwrap();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(FieldId(0), vec![1.0, 2.0, 4.0, 8.0]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(output, out_len);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            &space,
+            TickId(1),
+            0.1,
+        );
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(output).unwrap().to_vec()
+    }
+
+    #[test]
+    fn declares_jacobi_read_and_full_write() {
+        let prop = NeighbourSum { output: FieldId(1) };
+        assert_eq!(Propagator::name(&prop), "neighbour_sum");
+        assert!(prop.reads().is_empty());
+        assert_eq!(prop.reads_previous(), [FieldId(0)].into_iter().collect());
+        assert_eq!(prop.writes(), vec![(FieldId(1), WriteMode::Full)]);
+    }
+
+    #[test]
+    fn kernel_sees_resolved_neighbours() {
+        let prop = NeighbourSum { output: FieldId(1) };
+        let absorb = run(&prop, EdgeBehavior::Absorb, FieldId(1), 4);
+        assert_eq!(absorb, vec![1.0, 3.0, 6.0, -4.0]);
+        let wrap = run(&prop, EdgeBehavior::Wrap, FieldId(1), 4);
+        assert_eq!(wrap, vec![9.0, 3.0, 6.0, -3.0]);
+    }
+
+    #[test]
+    fn in_place_update_reads_previous_values() {
+        let prop = NeighbourSum { output: FieldId(0) };
+        let out = run(&prop, EdgeBehavior::Absorb, FieldId(0), 4);
+        assert_eq!(out, vec![1.0, 3.0, 6.0, -4.0]);
+    }
+
+    #[test]
+    fn output_length_mismatch_is_error() {
+        let prop = NeighbourSum { output: FieldId(1) };
+        let space = Line1D::new(4, EdgeBehavior::Absorb).unwrap();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(FieldId(0), vec![0.0; 4]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(FieldId(1), 8);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            &space,
+            TickId(1),
+            0.1,
+        );
+        assert!(prop.step(&mut ctx).is_err());
+    }
+}
@@ -0,0 +1,368 @@
+//! Agent density propagator.
+//!
+//! Reads one or more agent presence fields and writes a per-cell agent
+//! count into a scalar field each tick. Presence fields use the
+//! `agent_id + 1` encoding written by
+//! [`AgentMovementPropagator`](crate::AgentMovementPropagator) and
+//! [`GradientFollow`](crate::GradientFollow), so each holds at most one agent
+//! per cell; agents from different presence fields (for example two teams)
+//! that share a cell are summed.
+//!
+//! An [`EmissionKernel`] splats each agent over a spatial footprint (for
+//! example a Gaussian blob) instead of a single cell. Footprint weights are
+//! normalised over the in-bounds cells, so every agent contributes a total
+//! of exactly `1.0` and the field always sums to the agent count. As in
+//! [`AgentEmission`](crate::AgentEmission), footprints are computed once in
+//! [`Propagator::init`].
+//!
+//! # Construction
+//!
+//! ```
+//! use murk_core::FieldId;
+//! use murk_propagators::AgentDensity;
+//!
+//! let prop = AgentDensity::builder()
+//!     .presence_fields(vec![FieldId(1), FieldId(2)])
+//!     .density_field(FieldId(0))
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::agent_emission::{EmissionKernel, FootprintTable};
+use crate::agent_movement::occupied_cells;
+use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_propagator::context::StepContext;
+use murk_propagator::propagator::{Propagator, WriteMode};
+use murk_space::Space;
+
+/// A propagator that writes per-cell agent density from presence fields.
+///
+/// Presence is read through [`StepContext::reads`], so placing this after
+/// the movement propagators counts agents where they stand this tick. The
+/// density field is zeroed every tick and each agent adds `1.0` (spread by
+/// the configured [`EmissionKernel`]), so cells with no agents read `0.0`
+/// and cells shared by several agents read their sum.
+#[derive(Debug)]
+pub struct AgentDensity {
+    presence_fields: Vec<FieldId>,
+    density_field: FieldId,
+    kernel: EmissionKernel,
+    /// Per-cell kernel footprints, built in `init`.
+    footprints: Option<FootprintTable>,
+}
+
+/// Builder for [`AgentDensity`].
+///
+/// Required fields: `presence_fields`, `density_field`.
+/// Defaults: `kernel = PointSource`.
+pub struct AgentDensityBuilder {
+    presence_fields: Vec<FieldId>,
+    density_field: Option<FieldId>,
+    kernel: EmissionKernel,
+}
+
+impl AgentDensity {
+    /// Create a new builder for configuring an `AgentDensity` propagator.
+    pub fn builder() -> AgentDensityBuilder {
+        AgentDensityBuilder {
+            presence_fields: Vec::new(),
+            density_field: None,
+            kernel: EmissionKernel::PointSource,
+        }
+    }
+}
+
+impl AgentDensityBuilder {
+    /// Set the presence fields agents are counted from.
+    pub fn presence_fields(mut self, fields: Vec<FieldId>) -> Self {
+        self.presence_fields = fields;
+        self
+    }
+
+    /// Set the output density field to write.
+    pub fn density_field(mut self, field: FieldId) -> Self {
+        self.density_field = Some(field);
+        self
+    }
+
+    /// Set the spatial footprint each agent is splatted over.
+    /// Default: [`EmissionKernel::PointSource`].
+    pub fn kernel(mut self, kernel: EmissionKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
+    /// Build the propagator, validating all configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// - `presence_fields` is empty or lists a field twice
+    /// - `density_field` is not set or is one of the presence fields
+    /// - a Gaussian `kernel` has `sigma` not > 0
+    pub fn build(self) -> Result<AgentDensity, String> {
+        if self.presence_fields.is_empty() {
+            return Err("presence_fields is required".to_string());
+        }
+        let density_field = self
+            .density_field
+            .ok_or_else(|| "density_field is required".to_string())?;
+
+        for (i, field) in self.presence_fields.iter().enumerate() {
+            if self.presence_fields[..i].contains(field) {
+                return Err(format!("presence field {field:?} listed twice"));
+            }
+        }
+        if self.presence_fields.contains(&density_field) {
+            return Err(format!(
+                "density_field {density_field:?} must not be a presence field"
+            ));
+        }
+
+        if let EmissionKernel::Gaussian { sigma } = self.kernel {
+            if !sigma.is_finite() || sigma <= 0.0 {
+                return Err(format!(
+                    "Gaussian kernel sigma must be finite and > 0, got {sigma}"
+                ));
+            }
+        }
+
+        Ok(AgentDensity {
+            presence_fields: self.presence_fields,
+            density_field,
+            kernel: self.kernel,
+            footprints: None,
+        })
+    }
+}
+
+impl Propagator for AgentDensity {
+    fn name(&self) -> &str {
+        "AgentDensity"
+    }
+
+    fn reads(&self) -> FieldSet {
+        self.presence_fields.iter().copied().collect()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        vec![(self.density_field, WriteMode::Full)]
+    }
+
+    fn max_dt(&self, _space: &dyn murk_space::Space) -> Option<f64> {
+        None
+    }
+
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        if self.kernel != EmissionKernel::PointSource {
+            self.footprints = Some(FootprintTable::new(self.kernel, 1.0, space)?);
+        }
+        Ok(())
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let cell_count = ctx.space().cell_count();
+
+        // Occupied cells in field order, then rank order.
+        let mut positions = Vec::new();
+        for &field in &self.presence_fields {
+            let presence =
+                ctx.reads()
+                    .read(field)
+                    .ok_or_else(|| PropagatorError::ExecutionFailed {
+                        reason: format!("presence field {field:?} not readable"),
+                    })?;
+            if presence.len() != cell_count {
+                return Err(PropagatorError::ExecutionFailed {
+                    reason: format!(
+                        "presence field {field:?} length ({}) != space cell count ({cell_count})",
+                        presence.len()
+                    ),
+                });
+            }
+            positions.extend(occupied_cells(presence));
+        }
+
+        // Resolve kernel footprints before taking the write borrow.
+        let deposits = match &self.footprints {
+            _ if self.kernel == EmissionKernel::PointSource => {
+                positions.iter().map(|&rank| (rank, 1.0)).collect()
+            }
+            Some(table) if table.cell_count() == cell_count => positions
+                .iter()
+                .flat_map(|&rank| table.get(rank).iter().copied())
+                .collect(),
+            // Not initialised against this space: resolve per agent.
+            _ => {
+                let space = ctx.space();
+                let ordering = space.canonical_ordering();
+                let mut deposits = Vec::new();
+                for &rank in &positions {
+                    deposits.extend(self.kernel.footprint(1.0, space, &ordering[rank])?);
+                }
+                deposits
+            }
+        };
+
+        let out = ctx.writes().write(self.density_field).ok_or_else(|| {
+            PropagatorError::ExecutionFailed {
+                reason: format!("density field {:?} not writable", self.density_field),
+            }
+        })?;
+        if out.len() != cell_count {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "density field length ({}) != space cell count ({cell_count})",
+                    out.len()
+                ),
+            });
+        }
+
+        out.fill(0.0);
+        for (i, amount) in deposits {
+            out[i] += amount;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use murk_core::{Scalar, TickId};
+    use murk_propagator::scratch::ScratchRegion;
+    use murk_space::{EdgeBehavior, Space, Square4};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+
+    const F_TEAM_A: FieldId = FieldId(1);
+    const F_TEAM_B: FieldId = FieldId(2);
+    const F_DENS: FieldId = FieldId(100);
+
+    /// Presence buffer holding `agent_id + 1` at each listed rank.
+    fn presence(cells: usize, ranks: &[usize]) -> Vec<Scalar> {
+        let mut buf = vec![0.0; cells];
+        for (id, &rank) in ranks.iter().enumerate() {
+            buf[rank] = id as Scalar + 1.0;
+        }
+        buf
+    }
+
+    fn run(
+        prop: &AgentDensity,
+        grid: &Square4,
+        teams: &[&[usize]],
+    ) -> Result<Vec<Scalar>, PropagatorError> {
+        let mut reader = MockFieldReader::new();
+        for (field, ranks) in [F_TEAM_A, F_TEAM_B].into_iter().zip(teams) {
+            reader.set_field(field, presence(grid.cell_count(), ranks));
+        }
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_DENS, grid.cell_count());
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            grid,
+            TickId(1),
+            0.1,
+        );
+        prop.step(&mut ctx)?;
+        Ok(writer.get_field(F_DENS).unwrap().to_vec())
+    }
+
+    fn density_with(kernel: EmissionKernel) -> AgentDensity {
+        AgentDensity::builder()
+            .presence_fields(vec![F_TEAM_A, F_TEAM_B])
+            .density_field(F_DENS)
+            .kernel(kernel)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn builder_rejects_bad_configuration() {
+        let result = AgentDensity::builder().density_field(F_DENS).build();
+        assert!(result.unwrap_err().contains("presence_fields"));
+        let result = AgentDensity::builder()
+            .presence_fields(vec![F_TEAM_A])
+            .build();
+        assert!(result.unwrap_err().contains("density_field"));
+        let result = AgentDensity::builder()
+            .presence_fields(vec![F_TEAM_A, F_TEAM_A])
+            .density_field(F_DENS)
+            .build();
+        assert!(result.unwrap_err().contains("twice"));
+        let result = AgentDensity::builder()
+            .presence_fields(vec![F_TEAM_A])
+            .density_field(F_TEAM_A)
+            .build();
+        assert!(result.unwrap_err().contains("presence field"));
+        let result = AgentDensity::builder()
+            .presence_fields(vec![F_TEAM_A])
+            .density_field(F_DENS)
+            .kernel(EmissionKernel::Gaussian { sigma: 0.0 })
+            .build();
+        assert!(result.unwrap_err().contains("sigma"));
+    }
+
+    #[test]
+    fn reads_every_presence_field() {
+        let prop = density_with(EmissionKernel::PointSource);
+        let expected: FieldSet = [F_TEAM_A, F_TEAM_B].into_iter().collect();
+        assert_eq!(prop.reads(), expected);
+    }
+
+    #[test]
+    fn distinct_agents_write_one_each_and_zero_elsewhere() {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let prop = density_with(EmissionKernel::PointSource);
+        let dens = run(&prop, &grid, &[&[0, 4, 8], &[]]).unwrap();
+        for (i, &v) in dens.iter().enumerate() {
+            let expected = if [0, 4, 8].contains(&i) { 1.0 } else { 0.0 };
+            assert_eq!(v, expected, "cell {i}");
+        }
+    }
+
+    #[test]
+    fn colocated_agents_sum() {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let prop = density_with(EmissionKernel::PointSource);
+        let dens = run(&prop, &grid, &[&[5, 2], &[5]]).unwrap();
+        assert_eq!(dens[5], 2.0);
+        assert_eq!(dens[2], 1.0);
+        assert_eq!(dens.iter().sum::<Scalar>(), 3.0);
+    }
+
+    #[test]
+    fn gaussian_splat_conserves_agent_count() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        let prop = density_with(EmissionKernel::Gaussian { sigma: 1.0 });
+        let dens = run(&prop, &grid, &[&[0, 14], &[14]]).unwrap();
+        let total: Scalar = dens.iter().sum();
+        assert!((total - 3.0).abs() < 1e-4, "total {total}");
+        assert!(dens[14] > dens[15] && dens[15] > 0.0);
+    }
+
+    #[test]
+    fn init_footprints_match_per_step_resolution() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Wrap).unwrap();
+        let teams: &[&[usize]] = &[&[0, 14], &[14]];
+        let kernel = EmissionKernel::Disk { radius: 1 };
+        let lazy = run(&density_with(kernel), &grid, teams).unwrap();
+        let mut prop = density_with(kernel);
+        prop.init(&grid).unwrap();
+        assert!(prop.footprints.is_some());
+        assert_eq!(run(&prop, &grid, teams).unwrap(), lazy);
+    }
+
+    #[test]
+    fn missing_presence_field_is_rejected() {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let prop = density_with(EmissionKernel::PointSource);
+        let err = run(&prop, &grid, &[&[0]]).unwrap_err();
+        assert!(matches!(err, PropagatorError::ExecutionFailed { .. }));
+    }
+}
@@ -7,6 +7,13 @@
 //! - **Additive**: copies the previous emission forward, then adds `intensity`
 //!   at each cell where presence is nonzero.
 //!
+//! An [`EmissionKernel`] spreads each agent's `intensity` over a spatial
+//! footprint (a disk or Gaussian blob) instead of a single cell. The
+//! footprint weights are normalised over the in-bounds cells, so the total
+//! deposited per agent is always `intensity`. Footprints for every cell are
+//! computed once in [`Propagator::init`]; the table grows with
+//! `cell_count * footprint size`.
+//!
 //! # Borrow contract
 //!
 //! In Additive mode the propagator both reads and writes the emission field.
@@ -29,9 +36,10 @@
 //!     .unwrap();
 //! ```
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{scalar_to_f64, Coord, FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
+use murk_space::{RegionSpec, Space};
 
 /// Emission mode controlling how the propagator combines new emissions with
 /// existing values.
@@ -45,6 +53,118 @@ pub enum EmissionMode {
     Set,
 }
 
+/// Spatial footprint over which each agent's emission is spread.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EmissionKernel {
+    /// Deposit the full intensity at the agent's cell.
+    PointSource,
+    /// Split the intensity equally over all cells within `radius` graph
+    /// distance of the agent.
+    Disk {
+        /// Maximum graph distance from the agent (inclusive).
+        radius: u32,
+    },
+    /// Weight cells by `exp(-d² / 2σ²)`, where `d` is
+    /// [`Space::distance`] from the agent, truncated at `ceil(3σ)` graph
+    /// distance.
+    Gaussian {
+        /// Standard deviation in distance units. Must be > 0.
+        sigma: Scalar,
+    },
+}
+
+impl EmissionKernel {
+    /// Graph radius of the footprint.
+    fn radius(self) -> u32 {
+        match self {
+            EmissionKernel::PointSource => 0,
+            EmissionKernel::Disk { radius } => radius,
+            EmissionKernel::Gaussian { sigma } => (3.0 * sigma).ceil() as u32,
+        }
+    }
+
+    /// Unnormalised weight of a cell at distance `d` from the agent.
+    fn weight(self, d: f64) -> f64 {
+        match self {
+            EmissionKernel::PointSource | EmissionKernel::Disk { .. } => 1.0,
+            EmissionKernel::Gaussian { sigma } => {
+                let sigma = scalar_to_f64(sigma);
+                (-(d * d) / (2.0 * sigma * sigma)).exp()
+            }
+        }
+    }
+
+    /// Cells and amounts deposited by an agent at `center`.
+    ///
+    /// The amounts sum to `amount` (up to rounding); cells cut off by an
+    /// absorbing boundary are excluded before normalisation.
+    pub(crate) fn footprint(
+        self,
+        amount: Scalar,
+        space: &dyn Space,
+        center: &Coord,
+    ) -> Result<Vec<(usize, Scalar)>, PropagatorError> {
+        let plan = space
+            .compile_region(&RegionSpec::Disk {
+                center: center.clone(),
+                radius: self.radius(),
+            })
+            .map_err(|e| PropagatorError::ExecutionFailed {
+                reason: format!("emission kernel region failed to compile: {e}"),
+            })?;
+        let weighted: Vec<(usize, f64)> = plan
+            .coords()
+            .iter()
+            .filter_map(|coord| {
+                let rank = space.canonical_rank(coord)?;
+                Some((rank, self.weight(space.distance(center, coord))))
+            })
+            .collect();
+        let total: f64 = weighted.iter().map(|&(_, w)| w).sum();
+        Ok(weighted
+            .into_iter()
+            .map(|(rank, w)| (rank, (scalar_to_f64(amount) * w / total) as Scalar))
+            .collect())
+    }
+}
+
+/// The [`EmissionKernel::footprint`] of every cell of a space, indexed by
+/// canonical rank.
+#[derive(Debug)]
+pub(crate) struct FootprintTable {
+    offsets: Vec<usize>,
+    deposits: Vec<(usize, Scalar)>,
+}
+
+impl FootprintTable {
+    /// Compute the footprint of `amount` centred on each cell of `space`.
+    pub(crate) fn new(
+        kernel: EmissionKernel,
+        amount: Scalar,
+        space: &dyn Space,
+    ) -> Result<Self, PropagatorError> {
+        let ordering = space.canonical_ordering();
+        let mut offsets = Vec::with_capacity(ordering.len() + 1);
+        let mut deposits = Vec::new();
+        offsets.push(0);
+        for coord in &ordering {
+            deposits.extend(kernel.footprint(amount, space, coord)?);
+            offsets.push(deposits.len());
+        }
+        Ok(Self { offsets, deposits })
+    }
+
+    /// Number of cells covered.
+    pub(crate) fn cell_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Footprint of the cell at canonical `rank`.
+    pub(crate) fn get(&self, rank: usize) -> &[(usize, Scalar)] {
+        &self.deposits[self.offsets[rank]..self.offsets[rank + 1]]
+    }
+}
+
 /// A propagator that emits a scalar value at cells where agents are present.
 ///
 /// Reads a presence field from the previous tick and writes an emission field.
@@ -56,19 +176,23 @@ pub enum EmissionMode {
 pub struct AgentEmission {
     presence_field: FieldId,
     emission_field: FieldId,
-    intensity: f32,
+    intensity: Scalar,
     mode: EmissionMode,
+    kernel: EmissionKernel,
+    /// Per-cell kernel footprints, built in `init`.
+    footprints: Option<FootprintTable>,
 }
 
 /// Builder for [`AgentEmission`].
 ///
 /// Required fields: `presence_field`, `emission_field`.
-/// Defaults: `intensity = 1.0`, `mode = Additive`.
+/// Defaults: `intensity = 1.0`, `mode = Additive`, `kernel = PointSource`.
 pub struct AgentEmissionBuilder {
     presence_field: Option<FieldId>,
     emission_field: Option<FieldId>,
-    intensity: f32,
+    intensity: Scalar,
     mode: EmissionMode,
+    kernel: EmissionKernel,
 }
 
 impl AgentEmission {
@@ -79,6 +203,7 @@ impl AgentEmission {
             emission_field: None,
             intensity: 1.0,
             mode: EmissionMode::Additive,
+            kernel: EmissionKernel::PointSource,
         }
     }
 }
@@ -98,7 +223,7 @@ impl AgentEmissionBuilder {
 
     /// Set the emission intensity. Must be > 0.
     /// Default: `1.0`.
-    pub fn intensity(mut self, intensity: f32) -> Self {
+    pub fn intensity(mut self, intensity: Scalar) -> Self {
         self.intensity = intensity;
         self
     }
@@ -110,6 +235,13 @@ impl AgentEmissionBuilder {
         self
     }
 
+    /// Set the spatial footprint of each agent's emission.
+    /// Default: [`EmissionKernel::PointSource`].
+    pub fn kernel(mut self, kernel: EmissionKernel) -> Self {
+        self.kernel = kernel;
+        self
+    }
+
     /// Build the propagator, validating all configuration.
     ///
     /// # Errors
@@ -118,6 +250,7 @@ impl AgentEmissionBuilder {
     /// - `presence_field` is not set
     /// - `emission_field` is not set
     /// - `intensity` is not > 0
+    /// - a Gaussian `kernel` has `sigma` not > 0
     pub fn build(self) -> Result<AgentEmission, String> {
         let presence_field = self
             .presence_field
@@ -132,12 +265,21 @@ impl AgentEmissionBuilder {
                 self.intensity
             ));
         }
+        if let EmissionKernel::Gaussian { sigma } = self.kernel {
+            if !sigma.is_finite() || sigma <= 0.0 {
+                return Err(format!(
+                    "Gaussian kernel sigma must be finite and > 0, got {sigma}"
+                ));
+            }
+        }
 
         Ok(AgentEmission {
             presence_field,
             emission_field,
             intensity: self.intensity,
             mode: self.mode,
+            kernel: self.kernel,
+            footprints: None,
         })
     }
 }
@@ -168,6 +310,13 @@ impl Propagator for AgentEmission {
         None
     }
 
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        if self.kernel != EmissionKernel::PointSource {
+            self.footprints = Some(FootprintTable::new(self.kernel, self.intensity, space)?);
+        }
+        Ok(())
+    }
+
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
         // Read presence from the previous tick and copy to a local Vec.
         let presence = ctx
@@ -178,6 +327,43 @@ impl Propagator for AgentEmission {
             })?
             .to_vec();
 
+        // Resolve kernel footprints before taking the write borrow.
+        let deposits = if self.kernel == EmissionKernel::PointSource {
+            None
+        } else {
+            let space = ctx.space();
+            let cell_count = space.cell_count();
+            if cell_count != presence.len() {
+                return Err(PropagatorError::ExecutionFailed {
+                    reason: format!(
+                        "presence field length ({}) != space cell count ({cell_count})",
+                        presence.len(),
+                    ),
+                });
+            }
+            let occupied = presence.iter().enumerate().filter(|&(_, &p)| p != 0.0);
+            let mut deposits = Vec::new();
+            match &self.footprints {
+                Some(table) if table.cell_count() == cell_count => {
+                    for (rank, _) in occupied {
+                        deposits.extend_from_slice(table.get(rank));
+                    }
+                }
+                // Not initialised against this space: resolve per agent.
+                _ => {
+                    let ordering = space.canonical_ordering();
+                    for (rank, _) in occupied {
+                        deposits.extend(self.kernel.footprint(
+                            self.intensity,
+                            space,
+                            &ordering[rank],
+                        )?);
+                    }
+                }
+            }
+            Some(deposits)
+        };
+
         match self.mode {
             EmissionMode::Additive => {
                 // Read previous emission and copy to a local Vec BEFORE
@@ -221,9 +407,15 @@ impl Propagator for AgentEmission {
                 out.copy_from_slice(&prev_emission);
 
                 // Add intensity where agents are present.
-                for (i, &p) in presence.iter().enumerate() {
-                    if p != 0.0 {
-                        out[i] += self.intensity;
+                if let Some(deposits) = &deposits {
+                    for &(i, amount) in deposits {
+                        out[i] += amount;
+                    }
+                } else {
+                    for (i, &p) in presence.iter().enumerate() {
+                        if p != 0.0 {
+                            out[i] += self.intensity;
+                        }
                     }
                 }
             }
@@ -248,10 +440,17 @@ impl Propagator for AgentEmission {
 
                 out.fill(0.0);
 
-                // Set intensity where agents are present.
-                for (i, &p) in presence.iter().enumerate() {
-                    if p != 0.0 {
-                        out[i] = self.intensity;
+                // Set intensity where agents are present. Overlapping
+                // kernel footprints accumulate.
+                if let Some(deposits) = &deposits {
+                    for &(i, amount) in deposits {
+                        out[i] += amount;
+                    }
+                } else {
+                    for (i, &p) in presence.iter().enumerate() {
+                        if p != 0.0 {
+                            out[i] = self.intensity;
+                        }
                     }
                 }
             }
@@ -353,7 +552,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         presence[4] = 1.0;
 
         let mut reader = MockFieldReader::new();
@@ -387,10 +586,10 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         presence[4] = 1.0;
 
-        let mut prev_emission = vec![0.0f32; n];
+        let mut prev_emission = vec![0.0; n];
         prev_emission[4] = 10.0;
 
         let mut reader = MockFieldReader::new();
@@ -424,7 +623,7 @@ mod tests {
             .unwrap();
 
         let mut reader = MockFieldReader::new();
-        reader.set_field(F_PRES, vec![0.0f32; n]);
+        reader.set_field(F_PRES, vec![0.0; n]);
 
         let mut writer = MockFieldWriter::new();
         writer.add_field(F_EMIT, n);
@@ -445,7 +644,7 @@ mod tests {
         let result = AgentEmission::builder()
             .presence_field(F_PRES)
             .emission_field(F_EMIT)
-            .intensity(f32::NAN)
+            .intensity(Scalar::NAN)
             .build();
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("intensity"));
@@ -466,13 +665,13 @@ mod tests {
             .unwrap();
 
         // Previous emission has non-zero values but no agents are present.
-        let mut prev_emission = vec![0.0f32; n];
+        let mut prev_emission = vec![0.0; n];
         prev_emission[0] = 7.0;
         prev_emission[4] = 3.0;
         prev_emission[8] = 1.5;
 
         let mut reader = MockFieldReader::new();
-        reader.set_field(F_PRES, vec![0.0f32; n]);
+        reader.set_field(F_PRES, vec![0.0; n]);
         reader.set_field(F_EMIT, prev_emission.clone());
 
         let mut writer = MockFieldWriter::new();
@@ -507,12 +706,12 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         presence[4] = 1.0;
 
         let mut reader = MockFieldReader::new();
         reader.set_field(F_PRES, presence);
-        reader.set_field(F_EMIT, vec![0.0f32; n]); // prev_emission: 9 elements
+        reader.set_field(F_EMIT, vec![0.0; n]); // prev_emission: 9 elements
 
         let mut writer = MockFieldWriter::new();
         writer.add_field(F_EMIT, 4); // output buffer: only 4 elements — mismatch
@@ -545,4 +744,139 @@ mod tests {
             "Set mode should not read previous emission"
         );
     }
+
+    // ---------------------------------------------------------------
+    // Kernel tests
+    // ---------------------------------------------------------------
+
+    fn run_set(prop: &AgentEmission, grid: &Square4, presence: Vec<Scalar>) -> Vec<Scalar> {
+        let n = grid.cell_count();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_PRES, presence);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_EMIT, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, grid);
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(F_EMIT).unwrap().to_vec()
+    }
+
+    #[test]
+    fn builder_rejects_bad_gaussian_sigma() {
+        for sigma in [0.0, -1.0, Scalar::NAN] {
+            let result = AgentEmission::builder()
+                .presence_field(F_PRES)
+                .emission_field(F_EMIT)
+                .kernel(EmissionKernel::Gaussian { sigma })
+                .build();
+            assert!(result.unwrap_err().contains("sigma"));
+        }
+    }
+
+    #[test]
+    fn disk_kernel_deposits_within_radius() {
+        // 7x7 grid, agent at (3,3), radius 1: centre plus 4 neighbours.
+        let grid = Square4::new(7, 7, EdgeBehavior::Absorb).unwrap();
+        let prop = AgentEmission::builder()
+            .presence_field(F_PRES)
+            .emission_field(F_EMIT)
+            .intensity(10.0)
+            .mode(EmissionMode::Set)
+            .kernel(EmissionKernel::Disk { radius: 1 })
+            .build()
+            .unwrap();
+
+        let mut presence = vec![0.0; 49];
+        presence[3 * 7 + 3] = 1.0;
+        let emit = run_set(&prop, &grid, presence);
+
+        let footprint = [3 * 7 + 3, 2 * 7 + 3, 4 * 7 + 3, 3 * 7 + 2, 3 * 7 + 4];
+        for (i, &v) in emit.iter().enumerate() {
+            if footprint.contains(&i) {
+                assert!((v - 2.0).abs() < 1e-6, "cell {i} should get 10/5, got {v}");
+            } else {
+                assert_eq!(v, 0.0, "cell {i} is outside the disk");
+            }
+        }
+    }
+
+    #[test]
+    fn kernel_total_equals_intensity() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        // One agent in the interior, one in a corner (footprint clipped).
+        let mut presence = vec![0.0; 36];
+        presence[2 * 6 + 3] = 1.0;
+        presence[0] = 1.0;
+
+        for kernel in [
+            EmissionKernel::PointSource,
+            EmissionKernel::Disk { radius: 2 },
+            EmissionKernel::Gaussian { sigma: 1.5 },
+        ] {
+            let prop = AgentEmission::builder()
+                .presence_field(F_PRES)
+                .emission_field(F_EMIT)
+                .intensity(3.0)
+                .mode(EmissionMode::Set)
+                .kernel(kernel)
+                .build()
+                .unwrap();
+            let emit = run_set(&prop, &grid, presence.clone());
+            let total: Scalar = emit.iter().sum();
+            assert!((total - 6.0).abs() < 1e-4, "{kernel:?}: total {total}");
+            assert!(emit.iter().all(|&v| v >= 0.0));
+        }
+    }
+
+    #[test]
+    fn init_footprints_match_per_step_resolution() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Clamp).unwrap();
+        let mut presence = vec![0.0; 36];
+        presence[0] = 1.0;
+        presence[14] = 1.0;
+        let build = || {
+            AgentEmission::builder()
+                .presence_field(F_PRES)
+                .emission_field(F_EMIT)
+                .intensity(2.0)
+                .mode(EmissionMode::Set)
+                .kernel(EmissionKernel::Gaussian { sigma: 1.0 })
+                .build()
+                .unwrap()
+        };
+        let lazy = run_set(&build(), &grid, presence.clone());
+
+        let mut prop = build();
+        prop.init(&grid).unwrap();
+        let table = prop.footprints.as_ref().unwrap();
+        assert_eq!(table.cell_count(), 36);
+        assert_eq!(
+            table.get(14),
+            &EmissionKernel::Gaussian { sigma: 1.0 }
+                .footprint(2.0, &grid, &Coord::from_slice(&[2, 2]))
+                .unwrap()[..]
+        );
+        assert_eq!(run_set(&prop, &grid, presence), lazy);
+    }
+
+    #[test]
+    fn gaussian_kernel_peaks_at_agent() {
+        let grid = Square4::new(9, 9, EdgeBehavior::Wrap).unwrap();
+        let prop = AgentEmission::builder()
+            .presence_field(F_PRES)
+            .emission_field(F_EMIT)
+            .mode(EmissionMode::Set)
+            .kernel(EmissionKernel::Gaussian { sigma: 1.0 })
+            .build()
+            .unwrap();
+        let mut presence = vec![0.0; 81];
+        presence[4 * 9 + 4] = 1.0;
+        let emit = run_set(&prop, &grid, presence);
+
+        let centre = emit[4 * 9 + 4];
+        let adjacent = emit[4 * 9 + 5];
+        let diagonal = emit[5 * 9 + 5];
+        assert!(centre > adjacent && adjacent > diagonal && diagonal > 0.0);
+        assert_eq!(emit[0], 0.0, "corner lies beyond the 3-sigma cutoff");
+    }
 }
@@ -2,13 +2,16 @@
 //!
 //! Reads actions from a shared [`ActionBuffer`] and updates agent presence
 //! using [`WriteMode::Incremental`] (seeded from previous generation).
+//! Agents can be spawned and despawned between ticks through an attached
+//! [`AgentSlab`](crate::AgentSlab).
 
+use crate::agent_slab::SharedAgentSlab;
 #[allow(deprecated)]
 use crate::fields::AGENT_PRESENCE;
-use murk_core::{FieldId, FieldSet, PropagatorError, TickId};
+use crate::grid_helpers::row_major_square4;
+use murk_core::{FieldId, FieldReader, FieldSet, PropagatorError, Scalar, TickId};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
-use murk_space::Square4;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
@@ -50,6 +53,21 @@ pub struct AgentAction {
     pub direction: Direction,
 }
 
+/// Order in which queued actions are resolved within a tick.
+///
+/// Actions are applied one at a time, so when two agents target the same
+/// empty cell the one resolved first takes it and the other stays put.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Lowest `agent_id` first (the default).
+    #[default]
+    AscendingId,
+    /// Highest `agent_id` first.
+    DescendingId,
+    /// The order actions were pushed to the [`ActionBuffer`].
+    ArrivalOrder,
+}
+
 /// Thread-safe buffer for injecting agent actions into the propagator pipeline.
 pub type ActionBuffer = Arc<Mutex<Vec<AgentAction>>>;
 
@@ -58,15 +76,64 @@ pub fn new_action_buffer() -> ActionBuffer {
     Arc::new(Mutex::new(Vec::new()))
 }
 
+/// Decode a presence buffer into an `agent_id → flat index` map.
+///
+/// Presence cells hold `agent_id + 1` for an occupied cell and `0.0` for an
+/// empty one. Shared by every propagator that moves agents so they agree on
+/// the encoding.
+pub(crate) fn decode_agent_positions(presence: &[Scalar]) -> HashMap<u16, usize> {
+    let mut positions = HashMap::new();
+    for (idx, &v) in presence.iter().enumerate() {
+        if v > 0.5 {
+            let agent_id = (v - 1.0 + 0.5) as u16;
+            positions.insert(agent_id, idx);
+        }
+    }
+    positions
+}
+
+/// Flat indices of the occupied cells of a presence buffer, in index order.
+pub(crate) fn occupied_cells(presence: &[Scalar]) -> impl Iterator<Item = usize> + '_ {
+    presence
+        .iter()
+        .enumerate()
+        .filter(|&(_, &v)| v > 0.5)
+        .map(|(idx, _)| idx)
+}
+
+/// Decode `field` from `state` into `(agent_id, flat_index)` pairs sorted by
+/// `agent_id`, or `None` if the field is not readable.
+pub(crate) fn sorted_agent_positions(
+    state: &dyn FieldReader,
+    field: FieldId,
+) -> Option<Vec<(u16, usize)>> {
+    let mut agents: Vec<(u16, usize)> = decode_agent_positions(state.read(field)?)
+        .into_iter()
+        .collect();
+    agents.sort_unstable_by_key(|&(id, _)| id);
+    Some(agents)
+}
+
 /// Incremental agent movement propagator.
 ///
 /// On tick 0 (when presence is all zeros), places agents at their initial
 /// positions. On subsequent ticks, reads the action buffer and moves agents.
-/// Collision resolution: if target cell is occupied, agent stays.
+/// Collision resolution: if target cell is occupied, agent stays. Contention
+/// for the same empty cell is resolved by the configured [`TieBreak`]
+/// (ascending agent id unless overridden with
+/// [`with_tie_break`](Self::with_tie_break)).
 /// Boundary resolution: if target cell is OOB, agent stays.
+///
+/// With an [`AgentSlab`](crate::AgentSlab) attached via
+/// [`with_slab`](Self::with_slab), the slab is the source of truth: each
+/// tick the presence field is rebuilt from the live agents (picking up
+/// spawns and despawns made since the last tick), and moves are written
+/// back to the slab.
 pub struct AgentMovementPropagator {
     action_buffer: ActionBuffer,
     initial_positions: Vec<(u16, usize)>,
+    tie_break: TieBreak,
+    slab: Option<SharedAgentSlab>,
 }
 
 impl AgentMovementPropagator {
@@ -78,8 +145,26 @@ impl AgentMovementPropagator {
         Self {
             action_buffer,
             initial_positions,
+            tie_break: TieBreak::default(),
+            slab: None,
         }
     }
+
+    /// Back agent positions with a shared slab.
+    ///
+    /// `initial_positions` is ignored once a slab is attached; spawn the
+    /// starting agents into the slab instead.
+    pub fn with_slab(mut self, slab: SharedAgentSlab) -> Self {
+        self.slab = Some(slab);
+        self
+    }
+
+    /// Set the order in which contending actions are resolved.
+    /// Default: [`TieBreak::AscendingId`].
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
 }
 
 impl Propagator for AgentMovementPropagator {
@@ -99,6 +184,10 @@ impl Propagator for AgentMovementPropagator {
         vec![(AGENT_PRESENCE, WriteMode::Incremental)]
     }
 
+    fn agent_positions(&self, state: &dyn FieldReader) -> Option<Vec<(u16, usize)>> {
+        sorted_agent_positions(state, AGENT_PRESENCE)
+    }
+
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
         let cell_count = ctx.space().cell_count();
 
@@ -106,10 +195,8 @@ impl Propagator for AgentMovementPropagator {
         let tick_id = ctx.tick_id();
 
         // Precompute grid dimensions before taking the mutable writer borrow
-        let grid_dims = ctx
-            .space()
-            .downcast_ref::<Square4>()
-            .map(|g| (g.rows() as usize, g.cols() as usize));
+        let grid_dims =
+            row_major_square4(ctx.space()).map(|g| (g.rows() as usize, g.cols() as usize));
 
         // For generic spaces, precompute a direction-offset → target-rank map
         // for every cell. Key: (cell_rank, dr, dc) → target_rank.
@@ -143,10 +230,22 @@ impl Propagator for AgentMovementPropagator {
                 .map_err(|_| PropagatorError::ExecutionFailed {
                     reason: "action buffer lock poisoned".into(),
                 })?;
-        actions.sort_by_key(|a| a.agent_id);
+        // Stable sorts keep arrival order among one agent's actions.
+        match self.tie_break {
+            TieBreak::AscendingId => actions.sort_by_key(|a| a.agent_id),
+            TieBreak::DescendingId => actions.sort_by_key(|a| std::cmp::Reverse(a.agent_id)),
+            TieBreak::ArrivalOrder => {}
+        }
         let actions_snapshot: Vec<AgentAction> = actions.drain(..).collect();
         drop(actions);
 
+        let mut slab = match &self.slab {
+            Some(slab) => Some(slab.lock().map_err(|_| PropagatorError::ExecutionFailed {
+                reason: "agent slab lock poisoned".into(),
+            })?),
+            None => None,
+        };
+
         // Now take the mutable write borrow
         let presence =
             ctx.writes()
@@ -155,13 +254,21 @@ impl Propagator for AgentMovementPropagator {
                     reason: "agent_presence field not writable".into(),
                 })?;
 
-        // First-tick init: place agents at initial positions.
-        // TickId(1) is the first executed tick (engine starts at TickId(0)).
-        // Early return prevents queued actions from running during init.
-        if tick_id == TickId(1) && !self.initial_positions.is_empty() {
+        if let Some(slab) = &slab {
+            // Rebuild from the slab so spawns and despawns take effect.
+            presence.fill(0.0);
+            for (agent_id, flat_idx) in slab.iter() {
+                if flat_idx < cell_count {
+                    presence[flat_idx] = (agent_id as Scalar) + 1.0;
+                }
+            }
+        } else if tick_id == TickId(1) && !self.initial_positions.is_empty() {
+            // First-tick init: place agents at initial positions.
+            // TickId(1) is the first executed tick (engine starts at TickId(0)).
+            // Early return prevents queued actions from running during init.
             for &(agent_id, flat_idx) in &self.initial_positions {
                 if flat_idx < cell_count {
-                    presence[flat_idx] = (agent_id as f32) + 1.0;
+                    presence[flat_idx] = (agent_id as Scalar) + 1.0;
                 }
             }
             return Ok(());
@@ -175,13 +282,7 @@ impl Propagator for AgentMovementPropagator {
 
         // Build O(1) lookup: agent_id → flat index (fixes #94 hotspot 2).
         // Replaces O(n) linear scan per action with O(n + k) total.
-        let mut agent_positions: HashMap<u16, usize> = HashMap::new();
-        for (idx, &v) in presence.iter().enumerate() {
-            if v > 0.5 {
-                let agent_id = (v - 1.0 + 0.5) as u16;
-                agent_positions.insert(agent_id, idx);
-            }
-        }
+        let mut agent_positions = decode_agent_positions(presence);
 
         for action in &actions_snapshot {
             if action.direction == Direction::Stay {
@@ -227,6 +328,9 @@ impl Propagator for AgentMovementPropagator {
             presence[target] = presence[current_pos];
             presence[current_pos] = 0.0;
             agent_positions.insert(action.agent_id, target);
+            if let Some(slab) = slab.as_mut() {
+                slab.set_position(action.agent_id, target);
+            }
         }
 
         Ok(())
@@ -238,7 +342,7 @@ mod tests {
     use super::*;
     use murk_core::{FieldWriter, TickId};
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, Space};
+    use murk_space::{EdgeBehavior, Space, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
 
     fn make_ctx<'a>(
@@ -268,9 +372,9 @@ mod tests {
         let reader = MockFieldReader::new();
         let mut writer = MockFieldWriter::new();
 
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         for &(agent_id, pos) in initial {
-            presence[pos] = (agent_id as f32) + 1.0;
+            presence[pos] = (agent_id as Scalar) + 1.0;
         }
         writer.add_field(AGENT_PRESENCE, n);
         // Simulate Incremental mode: pre-fill writer with presence data
@@ -435,6 +539,49 @@ mod tests {
         assert_eq!(presence[1], 2.0); // agent 1 still there (marker 2.0)
     }
 
+    /// Agents 0 and 1 both step into the empty centre of a 3x3 grid from
+    /// opposite sides; returns the marker left in the centre.
+    fn contest_centre(tie_break: TieBreak, push_order: [u16; 2]) -> Scalar {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let ab = new_action_buffer();
+        // Agent 0 at (1,0)=3 moving east, agent 1 at (1,2)=5 moving west.
+        let (reader, mut writer) = setup_presence(&grid, &[(0, 3), (1, 5)]);
+        for id in push_order {
+            let direction = if id == 0 {
+                Direction::East
+            } else {
+                Direction::West
+            };
+            ab.lock().unwrap().push(AgentAction {
+                agent_id: id,
+                direction,
+            });
+        }
+
+        let prop = AgentMovementPropagator::new(ab, vec![]).with_tie_break(tie_break);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx_at_tick(&reader, &mut writer, &mut scratch, &grid, TickId(2));
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(AGENT_PRESENCE).unwrap()[4]
+    }
+
+    #[test]
+    fn tie_break_selects_contested_cell_winner() {
+        // Markers are agent_id + 1.
+        assert_eq!(contest_centre(TieBreak::AscendingId, [1, 0]), 1.0);
+        assert_eq!(contest_centre(TieBreak::DescendingId, [0, 1]), 2.0);
+        assert_eq!(contest_centre(TieBreak::ArrivalOrder, [1, 0]), 2.0);
+        assert_eq!(contest_centre(TieBreak::ArrivalOrder, [0, 1]), 1.0);
+    }
+
+    #[test]
+    fn tie_break_is_stable_across_ticks() {
+        for _ in 0..5 {
+            assert_eq!(contest_centre(TieBreak::DescendingId, [0, 1]), 2.0);
+            assert_eq!(contest_centre(TieBreak::DescendingId, [1, 0]), 2.0);
+        }
+    }
+
     #[test]
     fn action_buffer_cleared_after_processing() {
         let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
@@ -530,4 +677,47 @@ mod tests {
             "reads_previous() must declare AGENT_PRESENCE for Incremental write mode"
         );
     }
+
+    #[test]
+    fn agent_positions_decodes_presence_sorted_by_id() {
+        let prop = AgentMovementPropagator::new(new_action_buffer(), vec![]);
+        let mut reader = MockFieldReader::new();
+        reader.set_field(AGENT_PRESENCE, vec![0.0, 3.0, 0.0, 1.0, 2.0]);
+        assert_eq!(
+            prop.agent_positions(&reader),
+            Some(vec![(0, 3), (1, 4), (2, 1)])
+        );
+        assert_eq!(prop.agent_positions(&MockFieldReader::new()), None);
+    }
+
+    #[test]
+    fn slab_spawn_and_despawn_update_presence() {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let ab = new_action_buffer();
+        let slab = crate::new_agent_slab(4).unwrap();
+        slab.lock().unwrap().spawn(4).unwrap();
+        slab.lock().unwrap().spawn(0).unwrap();
+        let prop = AgentMovementPropagator::new(ab.clone(), vec![]).with_slab(slab.clone());
+
+        // Stale markers in the incremental buffer are replaced by the slab.
+        let (reader, mut writer) = setup_presence(&grid, &[(7, 8)]);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid);
+        prop.step(&mut ctx).unwrap();
+        let presence = writer.get_field(AGENT_PRESENCE).unwrap();
+        assert_eq!(presence, &[2.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+
+        // Despawn agent 0 and spawn a new agent into its slot; move agent 1.
+        assert_eq!(slab.lock().unwrap().despawn(0), Some(4));
+        assert_eq!(slab.lock().unwrap().spawn(2).unwrap(), 0);
+        ab.lock().unwrap().push(AgentAction {
+            agent_id: 1,
+            direction: Direction::South,
+        });
+        let mut ctx = make_ctx_at_tick(&reader, &mut writer, &mut scratch, &grid, TickId(2));
+        prop.step(&mut ctx).unwrap();
+        let presence = writer.get_field(AGENT_PRESENCE).unwrap();
+        assert_eq!(presence, &[0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(slab.lock().unwrap().position(1), Some(3));
+    }
 }
@@ -0,0 +1,244 @@
+//! Fixed-capacity agent slab with deterministic slot reuse.
+//!
+//! An [`AgentSlab`] holds up to `max_agents` agents, each identified by its
+//! slot index (the `agent_id` used in presence fields and
+//! [`AgentAction`](crate::AgentAction)s). Spawning takes the lowest free slot
+//! and despawning returns the slot to the free list, so the same sequence of
+//! spawns and despawns always yields the same ids. Storage is sized once at
+//! construction; spawn and despawn never allocate.
+//!
+//! Share a slab with [`AgentMovementPropagator`](crate::AgentMovementPropagator)
+//! through [`with_slab`](crate::AgentMovementPropagator::with_slab) to add and
+//! remove agents between ticks.
+//!
+//! # Example
+//!
+//! ```
+//! use murk_propagators::AgentSlab;
+//!
+//! let mut slab = AgentSlab::new(4).unwrap();
+//! let a = slab.spawn(10).unwrap();
+//! let b = slab.spawn(11).unwrap();
+//! assert_eq!((a, b), (0, 1));
+//!
+//! slab.despawn(a);
+//! assert_eq!(slab.spawn(12).unwrap(), 0);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Mutex};
+
+/// Thread-safe handle to an [`AgentSlab`] shared with a propagator.
+pub type SharedAgentSlab = Arc<Mutex<AgentSlab>>;
+
+/// Creates a shared slab holding at most `max_agents` agents.
+///
+/// # Errors
+///
+/// Returns `Err` under the same conditions as [`AgentSlab::new`].
+pub fn new_agent_slab(max_agents: usize) -> Result<SharedAgentSlab, String> {
+    Ok(Arc::new(Mutex::new(AgentSlab::new(max_agents)?)))
+}
+
+/// Pre-sized table of live agents and the cell each one occupies.
+#[derive(Clone, Debug)]
+pub struct AgentSlab {
+    /// Flat cell index per slot; `None` for a free slot.
+    cells: Vec<Option<usize>>,
+    /// Slots released by `despawn`, lowest first. Every entry is below
+    /// `next_unused`.
+    free: BinaryHeap<Reverse<u16>>,
+    /// First slot that has never been handed out.
+    next_unused: usize,
+    live: usize,
+}
+
+impl AgentSlab {
+    /// Create an empty slab holding at most `max_agents` agents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `max_agents` is zero or exceeds the `u16` agent id
+    /// range (65536).
+    pub fn new(max_agents: usize) -> Result<Self, String> {
+        if max_agents == 0 {
+            return Err("max_agents must be at least 1".into());
+        }
+        if max_agents > u16::MAX as usize + 1 {
+            return Err(format!(
+                "max_agents must be at most {}, got {max_agents}",
+                u16::MAX as usize + 1
+            ));
+        }
+        Ok(Self {
+            cells: vec![None; max_agents],
+            free: BinaryHeap::with_capacity(max_agents),
+            next_unused: 0,
+            live: 0,
+        })
+    }
+
+    /// Maximum number of live agents.
+    pub fn max_agents(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of live agents.
+    pub fn len(&self) -> usize {
+        self.live
+    }
+
+    /// Whether the slab holds no live agents.
+    pub fn is_empty(&self) -> bool {
+        self.live == 0
+    }
+
+    /// Spawn an agent at flat cell index `cell`, returning its id.
+    ///
+    /// The id is the lowest free slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the slab already holds `max_agents` agents or if
+    /// another live agent occupies `cell`.
+    pub fn spawn(&mut self, cell: usize) -> Result<u16, String> {
+        if self.live == self.cells.len() {
+            return Err(format!("agent slab is full ({} agents)", self.cells.len()));
+        }
+        if let Some(other) = self.agent_at(cell) {
+            return Err(format!("cell {cell} is occupied by agent {other}"));
+        }
+        let slot = match self.free.pop() {
+            Some(Reverse(slot)) => slot,
+            None => {
+                let slot = self.next_unused as u16;
+                self.next_unused += 1;
+                slot
+            }
+        };
+        self.cells[slot as usize] = Some(cell);
+        self.live += 1;
+        Ok(slot)
+    }
+
+    /// Remove agent `id`, returning the cell it occupied.
+    ///
+    /// Returns `None` (and changes nothing) if `id` is not live.
+    pub fn despawn(&mut self, id: u16) -> Option<usize> {
+        let cell = self.cells.get_mut(id as usize)?.take()?;
+        self.free.push(Reverse(id));
+        self.live -= 1;
+        Some(cell)
+    }
+
+    /// Cell occupied by agent `id`, or `None` if `id` is not live.
+    pub fn position(&self, id: u16) -> Option<usize> {
+        self.cells.get(id as usize).copied().flatten()
+    }
+
+    /// Id of the live agent at `cell`, if any.
+    pub fn agent_at(&self, cell: usize) -> Option<u16> {
+        self.iter().find(|&(_, c)| c == cell).map(|(id, _)| id)
+    }
+
+    /// Live agents as `(agent_id, cell)` pairs in ascending slot order.
+    pub fn iter(&self) -> impl Iterator<Item = (u16, usize)> + '_ {
+        self.cells[..self.next_unused]
+            .iter()
+            .enumerate()
+            .filter_map(|(id, cell)| cell.map(|c| (id as u16, c)))
+    }
+
+    /// Move live agent `id` to `cell`.
+    pub(crate) fn set_position(&mut self, id: u16, cell: usize) {
+        if let Some(slot @ Some(_)) = self.cells.get_mut(id as usize) {
+            *slot = Some(cell);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_zero_and_oversized_capacity() {
+        assert!(AgentSlab::new(0).unwrap_err().contains("at least 1"));
+        assert!(AgentSlab::new(65_537).unwrap_err().contains("65536"));
+        assert_eq!(AgentSlab::new(65_536).unwrap().max_agents(), 65_536);
+    }
+
+    #[test]
+    fn spawn_hands_out_ascending_ids() {
+        let mut slab = AgentSlab::new(3).unwrap();
+        assert_eq!(slab.spawn(5).unwrap(), 0);
+        assert_eq!(slab.spawn(6).unwrap(), 1);
+        assert_eq!(slab.spawn(7).unwrap(), 2);
+        assert_eq!(slab.len(), 3);
+        assert_eq!(slab.position(1), Some(6));
+    }
+
+    #[test]
+    fn spawn_after_despawn_reuses_lowest_freed_slot() {
+        let mut slab = AgentSlab::new(4).unwrap();
+        for cell in 0..4 {
+            slab.spawn(cell).unwrap();
+        }
+        assert_eq!(slab.despawn(2), Some(2));
+        assert_eq!(slab.despawn(1), Some(1));
+        assert_eq!(slab.position(1), None);
+
+        assert_eq!(slab.spawn(10).unwrap(), 1);
+        assert_eq!(slab.spawn(11).unwrap(), 2);
+        assert_eq!(slab.position(2), Some(11));
+    }
+
+    #[test]
+    fn spawn_beyond_max_agents_is_rejected() {
+        let mut slab = AgentSlab::new(2).unwrap();
+        slab.spawn(0).unwrap();
+        slab.spawn(1).unwrap();
+        let err = slab.spawn(2).unwrap_err();
+        assert!(err.contains("full"), "{err}");
+        assert_eq!(slab.len(), 2);
+
+        slab.despawn(0);
+        assert_eq!(slab.spawn(2).unwrap(), 0);
+    }
+
+    #[test]
+    fn spawn_into_occupied_cell_is_rejected() {
+        let mut slab = AgentSlab::new(2).unwrap();
+        slab.spawn(3).unwrap();
+        assert!(slab.spawn(3).unwrap_err().contains("occupied by agent 0"));
+        assert_eq!(slab.len(), 1);
+    }
+
+    #[test]
+    fn despawn_of_free_slot_is_a_no_op() {
+        let mut slab = AgentSlab::new(2).unwrap();
+        assert_eq!(slab.despawn(0), None);
+        assert_eq!(slab.despawn(9), None);
+        slab.spawn(4).unwrap();
+        slab.despawn(0);
+        assert_eq!(slab.despawn(0), None);
+        assert!(slab.is_empty());
+        assert_eq!(slab.spawn(4).unwrap(), 0);
+    }
+
+    #[test]
+    fn iteration_is_in_slot_order() {
+        let mut slab = AgentSlab::new(5).unwrap();
+        for cell in [40, 30, 20, 10] {
+            slab.spawn(cell).unwrap();
+        }
+        slab.despawn(1);
+        slab.set_position(3, 0);
+        assert_eq!(slab.spawn(50).unwrap(), 1);
+        slab.despawn(0);
+
+        let live: Vec<(u16, usize)> = slab.iter().collect();
+        assert_eq!(live, vec![(1, 50), (2, 20), (3, 0)]);
+    }
+}
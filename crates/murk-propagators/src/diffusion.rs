@@ -6,11 +6,11 @@
 
 #[allow(deprecated)]
 use crate::fields::{HEAT, HEAT_GRADIENT, VELOCITY};
-use crate::grid_helpers::{neighbours_flat, resolve_axis};
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use crate::grid_helpers::{neighbours_flat, resolve_axis, row_major_square4};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
-use murk_space::{EdgeBehavior, Square4};
+use murk_space::EdgeBehavior;
 
 /// Jacobi diffusion propagator for heat and velocity fields.
 ///
@@ -80,9 +80,9 @@ impl DiffusionPropagator {
                 let nbs = neighbours_flat(r, c, rows_i, cols_i, edge);
                 let count = nbs.len() as u32;
                 if count > 0 {
-                    let sum: f32 = nbs.iter().map(|&ni| heat_prev[ni]).sum();
-                    let alpha = (self.diffusivity * dt * count as f64).min(1.0) as f32;
-                    let mean = sum / count as f32;
+                    let sum: Scalar = nbs.iter().map(|&ni| heat_prev[ni]).sum();
+                    let alpha = (self.diffusivity * dt * count as f64).min(1.0) as Scalar;
+                    let mean = sum / count as Scalar;
                     heat_out[i] = (1.0 - alpha) * heat_prev[i] + alpha * mean;
                 } else {
                     heat_out[i] = heat_prev[i];
@@ -105,9 +105,9 @@ impl DiffusionPropagator {
                 for comp in 0..2 {
                     let idx = i * 2 + comp;
                     if count > 0 {
-                        let sum: f32 = nbs.iter().map(|&ni| vel_prev[ni * 2 + comp]).sum();
-                        let alpha = (self.diffusivity * dt * count as f64).min(1.0) as f32;
-                        let mean = sum / count as f32;
+                        let sum: Scalar = nbs.iter().map(|&ni| vel_prev[ni * 2 + comp]).sum();
+                        let alpha = (self.diffusivity * dt * count as f64).min(1.0) as Scalar;
+                        let mean = sum / count as Scalar;
                         vel_out[idx] = (1.0 - alpha) * vel_prev[idx] + alpha * mean;
                     } else {
                         vel_out[idx] = vel_prev[idx];
@@ -246,17 +246,17 @@ impl DiffusionPropagator {
         check_field_arity(&heat_prev, &vel_prev, cell_count)?;
 
         // Compute outputs into local buffers, then write all at once
-        let mut heat_new = vec![0.0f32; cell_count];
-        let mut vel_new = vec![0.0f32; cell_count * 2];
-        let mut grad_new = vec![0.0f32; cell_count * 2];
+        let mut heat_new = vec![0.0; cell_count];
+        let mut vel_new = vec![0.0; cell_count * 2];
+        let mut grad_new = vec![0.0; cell_count * 2];
 
         for i in 0..cell_count {
             let nbs = &neighbour_ranks[i];
             let count = nbs.len() as u32;
             if count > 0 {
-                let sum: f32 = nbs.iter().map(|&r| heat_prev[r]).sum();
-                let alpha = (self.diffusivity * dt * count as f64).min(1.0) as f32;
-                let mean = sum / count as f32;
+                let sum: Scalar = nbs.iter().map(|&r| heat_prev[r]).sum();
+                let alpha = (self.diffusivity * dt * count as f64).min(1.0) as Scalar;
+                let mean = sum / count as Scalar;
                 heat_new[i] = (1.0 - alpha) * heat_prev[i] + alpha * mean;
             } else {
                 heat_new[i] = heat_prev[i];
@@ -265,9 +265,9 @@ impl DiffusionPropagator {
             for comp in 0..2 {
                 let idx = i * 2 + comp;
                 if count > 0 {
-                    let sum: f32 = nbs.iter().map(|&r| vel_prev[r * 2 + comp]).sum();
-                    let alpha = (self.diffusivity * dt * count as f64).min(1.0) as f32;
-                    let mean = sum / count as f32;
+                    let sum: Scalar = nbs.iter().map(|&r| vel_prev[r * 2 + comp]).sum();
+                    let alpha = (self.diffusivity * dt * count as f64).min(1.0) as Scalar;
+                    let mean = sum / count as Scalar;
                     vel_new[idx] = (1.0 - alpha) * vel_prev[idx] + alpha * mean;
                 } else {
                     vel_new[idx] = vel_prev[idx];
@@ -275,23 +275,23 @@ impl DiffusionPropagator {
             }
 
             let gi = &grad_info[i];
-            let mut gx = 0.0f32;
-            let mut gy = 0.0f32;
+            let mut gx = 0.0;
+            let mut gy = 0.0;
             let mut xc = 0u32;
             let mut yc = 0u32;
             for &(rank, dc, dr) in gi {
                 let dh = heat_prev[rank] - heat_prev[i];
                 if dc != 0 {
-                    gx += dh / dc as f32;
+                    gx += dh / dc as Scalar;
                     xc += 1;
                 }
                 if dr != 0 {
-                    gy += dh / dr as f32;
+                    gy += dh / dr as Scalar;
                     yc += 1;
                 }
             }
-            grad_new[i * 2] = if xc > 0 { gx / xc as f32 } else { 0.0 };
-            grad_new[i * 2 + 1] = if yc > 0 { gy / yc as f32 } else { 0.0 };
+            grad_new[i * 2] = if xc > 0 { gx / xc as Scalar } else { 0.0 };
+            grad_new[i * 2 + 1] = if yc > 0 { gy / yc as Scalar } else { 0.0 };
         }
 
         // Write results
@@ -325,8 +325,8 @@ impl DiffusionPropagator {
 
 /// Validate that field slices have the expected lengths for this propagator.
 fn check_field_arity(
-    heat: &[f32],
-    velocity: &[f32],
+    heat: &[Scalar],
+    velocity: &[Scalar],
     cell_count: usize,
 ) -> Result<(), PropagatorError> {
     if heat.len() != cell_count {
@@ -385,7 +385,7 @@ impl Propagator for DiffusionPropagator {
     }
 
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        if let Some(grid) = ctx.space().downcast_ref::<Square4>() {
+        if let Some(grid) = row_major_square4(ctx.space()) {
             // Extract scalars before the mutable borrow
             let rows = grid.rows();
             let cols = grid.cols();
@@ -404,7 +404,7 @@ mod tests {
     use crate::fields::{HEAT, HEAT_GRADIENT, VELOCITY};
     use murk_core::TickId;
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, Fcc12, Space};
+    use murk_space::{EdgeBehavior, Fcc12, Space, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
 
     fn make_ctx<'a>(
@@ -452,7 +452,7 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(0.1);
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[12] = 100.0; // center of 5x5
 
         let mut reader = MockFieldReader::new();
@@ -501,9 +501,9 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(0.1);
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[12] = 100.0;
-        let total_before: f32 = heat.iter().sum();
+        let total_before: Scalar = heat.iter().sum();
 
         let mut reader = MockFieldReader::new();
         reader.set_field(HEAT, heat);
@@ -520,7 +520,7 @@ mod tests {
         prop.step(&mut ctx).unwrap();
 
         let result = writer.get_field(HEAT).unwrap();
-        let total_after: f32 = result.iter().sum();
+        let total_after: Scalar = result.iter().sum();
         assert!(
             (total_before - total_after).abs() < 1e-3,
             "energy not conserved: before={total_before}, after={total_after}"
@@ -553,10 +553,10 @@ mod tests {
         let prop = DiffusionPropagator::new(0.0); // zero diffusivity to not change heat
 
         // Linear gradient in x: col 0=0, col 1=10, col 2=20
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         for r in 0..3 {
             for c in 0..3 {
-                heat[r * 3 + c] = (c as f32) * 10.0;
+                heat[r * 3 + c] = (c as Scalar) * 10.0;
             }
         }
 
@@ -596,7 +596,7 @@ mod tests {
         let prop = DiffusionPropagator::new(0.0);
 
         // All zeros except corner
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[0] = 50.0;
 
         let mut reader = MockFieldReader::new();
@@ -659,7 +659,7 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(0.1);
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[0] = 100.0; // corner (0,0)
 
         let mut reader = MockFieldReader::new();
@@ -694,7 +694,7 @@ mod tests {
         assert!(result[1] > 0.0, "east (0,1) should warm: {}", result[1]);
 
         // Energy conservation
-        let total: f32 = result.iter().sum();
+        let total: Scalar = result.iter().sum();
         assert!(
             (total - 100.0).abs() < 1e-3,
             "wrap: energy not conserved: {total}"
@@ -707,9 +707,9 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(0.1);
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[0] = 100.0;
-        let total_before: f32 = heat.iter().sum();
+        let total_before: Scalar = heat.iter().sum();
 
         let mut reader = MockFieldReader::new();
         reader.set_field(HEAT, heat);
@@ -725,7 +725,7 @@ mod tests {
         prop.step(&mut ctx).unwrap();
 
         let result = writer.get_field(HEAT).unwrap();
-        let total_after: f32 = result.iter().sum();
+        let total_after: Scalar = result.iter().sum();
         assert!(
             (total_before - total_after).abs() < 1e-3,
             "wrap: energy not conserved: before={total_before}, after={total_after}"
@@ -768,7 +768,7 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(0.1);
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[0] = 100.0;
 
         let mut reader = MockFieldReader::new();
@@ -809,7 +809,7 @@ mod tests {
         let dt = 0.01;
 
         // --- Initial field data ---
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[12] = 100.0; // hot center
 
         // --- Run old DiffusionPropagator ---
@@ -903,10 +903,10 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(0.0);
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         for r in 0..4 {
             for c in 0..4 {
-                heat[r * 4 + c] = (c as f32) * 10.0;
+                heat[r * 4 + c] = (c as Scalar) * 10.0;
             }
         }
 
@@ -947,7 +947,7 @@ mod tests {
         let prop = DiffusionPropagator::new(0.0); // zero diffusion, only test gradient
 
         // Linear heat ramp: [0, 10, 20, 30]
-        let heat: Vec<f32> = (0..n).map(|i| i as f32 * 10.0).collect();
+        let heat: Vec<Scalar> = (0..n).map(|i| i as Scalar * 10.0).collect();
 
         let mut reader = MockFieldReader::new();
         reader.set_field(HEAT, heat);
@@ -1069,7 +1069,7 @@ mod tests {
         let n = grid.cell_count();
         let prop = DiffusionPropagator::new(10.0); // very high diffusivity
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[4] = 100.0; // center of 3x3
 
         let mut reader = MockFieldReader::new();
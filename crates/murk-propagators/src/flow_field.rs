@@ -9,11 +9,11 @@
 //!
 //! Constructed via the builder pattern: [`FlowField::builder`].
 
-use crate::grid_helpers::resolve_axis;
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use crate::grid_helpers::{resolve_axis, row_major_square4};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
-use murk_space::{EdgeBehavior, Square4};
+use murk_space::EdgeBehavior;
 
 /// A negative-gradient flow field propagator.
 ///
@@ -174,28 +174,28 @@ impl FlowField {
             .to_vec();
 
         // Compute negated gradient into local buffer
-        let mut flow_buf = vec![0.0f32; cell_count * 2];
+        let mut flow_buf = vec![0.0; cell_count * 2];
 
         for i in 0..cell_count {
             let gi = &grad_info[i];
-            let mut gx = 0.0f32;
-            let mut gy = 0.0f32;
+            let mut gx = 0.0;
+            let mut gy = 0.0;
             let mut xc = 0u32;
             let mut yc = 0u32;
             for &(rank, dc, dr) in gi {
                 let dh = prev[rank] - prev[i];
                 if dc != 0 {
-                    gx += dh / dc as f32;
+                    gx += dh / dc as Scalar;
                     xc += 1;
                 }
                 if dr != 0 {
-                    gy += dh / dr as f32;
+                    gy += dh / dr as Scalar;
                     yc += 1;
                 }
             }
             // Negate the gradient to get flow direction (toward lower potential)
-            let mut fx = if xc > 0 { -(gx / xc as f32) } else { 0.0 };
-            let mut fy = if yc > 0 { -(gy / yc as f32) } else { 0.0 };
+            let mut fx = if xc > 0 { -(gx / xc as Scalar) } else { 0.0 };
+            let mut fy = if yc > 0 { -(gy / yc as Scalar) } else { 0.0 };
 
             if self.normalize {
                 let mag = (fx * fx + fy * fy).sqrt();
@@ -286,7 +286,7 @@ impl Propagator for FlowField {
     }
 
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        if let Some(grid) = ctx.space().downcast_ref::<Square4>() {
+        if let Some(grid) = row_major_square4(ctx.space()) {
             let rows = grid.rows();
             let cols = grid.cols();
             let edge = grid.edge_behavior();
@@ -302,7 +302,7 @@ mod tests {
     use super::*;
     use murk_core::TickId;
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, Space};
+    use murk_space::{EdgeBehavior, Space, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
 
     // Test field IDs far from the hardcoded constants to avoid collision.
@@ -429,11 +429,11 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut potential = vec![0.0f32; n];
+        let mut potential = vec![0.0; n];
         for r in 0..3 {
             for c in 0..3 {
                 // Potential decreases eastward
-                potential[r * 3 + c] = (2 - c) as f32 * 10.0;
+                potential[r * 3 + c] = (2 - c) as Scalar * 10.0;
             }
         }
 
@@ -490,10 +490,10 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut potential = vec![0.0f32; n];
+        let mut potential = vec![0.0; n];
         for r in 0..3 {
             for c in 0..3 {
-                potential[r * 3 + c] = (2 - c) as f32 * 10.0 + (2 - r) as f32 * 10.0;
+                potential[r * 3 + c] = (2 - c) as Scalar * 10.0 + (2 - r) as Scalar * 10.0;
             }
         }
 
@@ -0,0 +1,466 @@
+//! Gaussian blur (isotropic smoothing) propagator.
+//!
+//! Reads a scalar field from the previous tick (`reads_previous`) and
+//! writes a Gaussian-smoothed copy. Unlike [`ScalarDiffusion`], this is a
+//! pure spatial filter: the amount of smoothing is set by `sigma` alone
+//! and does not depend on `dt`, so there is no CFL constraint.
+//!
+//! The kernel is truncated at `radius = ceil(3 * sigma)` cells and its
+//! weights are renormalised over the taps that resolve to a cell, so a
+//! uniform field is left unchanged and Absorb edges do not darken.
+//!
+//! Has a [`Square4`] fast path that applies the separable kernel as a row
+//! pass followed by a column pass, padding out-of-range taps according to
+//! the grid's [`EdgeBehavior`] (Absorb drops them, Clamp repeats the edge
+//! cell, Wrap is periodic). Other spaces take a generic path that weights
+//! every cell within `radius` hops by its hop distance, using a neighbour
+//! table built once in [`Propagator::init`].
+//!
+//! [`ScalarDiffusion`]: crate::ScalarDiffusion
+//! [`Square4`]: murk_space::Square4
+//!
+//! Constructed via the builder pattern: [`GaussianBlur::builder`].
+
+use crate::grid_helpers::{neighbour_ranks, resolve_axis, row_major_square4};
+use murk_core::{scalar_to_f64, FieldId, FieldSet, PropagatorError, Scalar};
+use murk_propagator::context::StepContext;
+use murk_propagator::propagator::{Propagator, WriteMode};
+use murk_space::{EdgeBehavior, Space};
+use std::collections::VecDeque;
+
+/// A Gaussian blur propagator.
+///
+/// Each tick convolves a scalar field from the previous tick with a
+/// Gaussian of standard deviation `sigma` (in cells) and writes the result
+/// to the output field.
+///
+/// # Construction
+///
+/// ```
+/// use murk_core::FieldId;
+/// use murk_propagators::GaussianBlur;
+///
+/// let prop = GaussianBlur::builder()
+///     .input_field(FieldId(0))
+///     .output_field(FieldId(1))
+///     .sigma(1.5)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct GaussianBlur {
+    input_field: FieldId,
+    output_field: FieldId,
+    sigma: f64,
+    /// Generic-path neighbour table, built in `init`.
+    neighbours: Option<Vec<Vec<usize>>>,
+}
+
+/// Builder for [`GaussianBlur`].
+///
+/// Required fields: `input_field` and `output_field`.
+/// Defaults: `sigma = 1.0`.
+pub struct GaussianBlurBuilder {
+    input_field: Option<FieldId>,
+    output_field: Option<FieldId>,
+    sigma: f64,
+}
+
+impl GaussianBlur {
+    /// Largest accepted `sigma`, in cells. Bounds the kernel radius (and so
+    /// the per-cell cost of the fast path) at `3 * MAX_SIGMA` taps.
+    pub const MAX_SIGMA: f64 = 256.0;
+
+    /// Create a new builder for configuring a `GaussianBlur` propagator.
+    pub fn builder() -> GaussianBlurBuilder {
+        GaussianBlurBuilder {
+            input_field: None,
+            output_field: None,
+            sigma: 1.0,
+        }
+    }
+
+    /// Kernel truncation radius in cells: `ceil(3 * sigma)`.
+    fn radius(&self) -> usize {
+        (3.0 * self.sigma).ceil() as usize
+    }
+
+    /// Unnormalised kernel weight at distance `d` cells.
+    fn weight(&self, d: usize) -> f64 {
+        let d = d as f64;
+        (-d * d / (2.0 * self.sigma * self.sigma)).exp()
+    }
+
+    /// Separable row-then-column blur on a row-major Square4 grid.
+    fn blur_square4(
+        &self,
+        prev: &[Scalar],
+        rows: usize,
+        cols: usize,
+        edge: EdgeBehavior,
+    ) -> Vec<Scalar> {
+        let radius = self.radius() as i32;
+        let weights: Vec<f64> = (0..=radius as usize).map(|d| self.weight(d)).collect();
+
+        // 1D pass along one axis; `index(line, pos)` maps to a flat index.
+        let pass =
+            |src: &[f64], lines: usize, len: usize, index: &dyn Fn(usize, usize) -> usize| {
+                let mut dst = vec![0.0f64; src.len()];
+                for line in 0..lines {
+                    for pos in 0..len {
+                        let mut sum = 0.0;
+                        let mut norm = 0.0;
+                        for k in -radius..=radius {
+                            let Some(p) = resolve_axis(pos as i32 + k, len as i32, edge) else {
+                                continue;
+                            };
+                            let w = weights[k.unsigned_abs() as usize];
+                            sum += w * src[index(line, p as usize)];
+                            norm += w;
+                        }
+                        dst[index(line, pos)] = sum / norm;
+                    }
+                }
+                dst
+            };
+
+        let src: Vec<f64> = prev.iter().map(|&v| scalar_to_f64(v)).collect();
+        let horizontal = pass(&src, rows, cols, &|r, c| r * cols + c);
+        let vertical = pass(&horizontal, cols, rows, &|c, r| r * cols + c);
+        vertical.into_iter().map(|v| v as Scalar).collect()
+    }
+
+    /// Hop-distance blur for arbitrary spaces.
+    fn blur_generic(&self, prev: &[Scalar], neighbours: &[Vec<usize>]) -> Vec<Scalar> {
+        let radius = self.radius();
+        let mut out = vec![0.0; prev.len()];
+        let mut depth = vec![usize::MAX; prev.len()];
+        let mut visited = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for (i, out_cell) in out.iter_mut().enumerate() {
+            for &rank in &visited {
+                depth[rank] = usize::MAX;
+            }
+            visited.clear();
+            queue.clear();
+            depth[i] = 0;
+            visited.push(i);
+            queue.push_back(i);
+
+            let mut sum = 0.0f64;
+            let mut norm = 0.0f64;
+            while let Some(rank) = queue.pop_front() {
+                let d = depth[rank];
+                let w = self.weight(d);
+                sum += w * scalar_to_f64(prev[rank]);
+                norm += w;
+                if d < radius {
+                    for &nb in &neighbours[rank] {
+                        if depth[nb] == usize::MAX {
+                            depth[nb] = d + 1;
+                            visited.push(nb);
+                            queue.push_back(nb);
+                        }
+                    }
+                }
+            }
+            *out_cell = (sum / norm) as Scalar;
+        }
+        out
+    }
+}
+
+impl GaussianBlurBuilder {
+    /// Set the input scalar field.
+    pub fn input_field(mut self, field: FieldId) -> Self {
+        self.input_field = Some(field);
+        self
+    }
+
+    /// Set the output (smoothed) scalar field.
+    pub fn output_field(mut self, field: FieldId) -> Self {
+        self.output_field = Some(field);
+        self
+    }
+
+    /// Set the kernel standard deviation in cells (default: 1.0).
+    /// Must be finite, > 0 and at most [`GaussianBlur::MAX_SIGMA`].
+    pub fn sigma(mut self, sigma: f64) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    /// Build the propagator, validating all configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// - `input_field` is not set
+    /// - `output_field` is not set
+    /// - `sigma` is not finite and > 0
+    /// - `sigma` exceeds [`GaussianBlur::MAX_SIGMA`]
+    pub fn build(self) -> Result<GaussianBlur, String> {
+        let input_field = self
+            .input_field
+            .ok_or_else(|| "input_field is required".to_string())?;
+        let output_field = self
+            .output_field
+            .ok_or_else(|| "output_field is required".to_string())?;
+
+        if !self.sigma.is_finite() || self.sigma <= 0.0 {
+            return Err(format!("sigma must be finite and > 0, got {}", self.sigma));
+        }
+        if self.sigma > GaussianBlur::MAX_SIGMA {
+            return Err(format!(
+                "sigma must be <= {}, got {}",
+                GaussianBlur::MAX_SIGMA,
+                self.sigma
+            ));
+        }
+
+        Ok(GaussianBlur {
+            input_field,
+            output_field,
+            sigma: self.sigma,
+            neighbours: None,
+        })
+    }
+}
+
+impl Propagator for GaussianBlur {
+    fn name(&self) -> &str {
+        "GaussianBlur"
+    }
+
+    fn reads(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
+    fn reads_previous(&self) -> FieldSet {
+        [self.input_field].into_iter().collect()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        vec![(self.output_field, WriteMode::Full)]
+    }
+
+    fn max_dt(&self, _space: &dyn murk_space::Space) -> Option<f64> {
+        None // pure spatial filter, independent of dt
+    }
+
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        if row_major_square4(space).is_none() {
+            self.neighbours = Some(neighbour_ranks(space));
+        }
+        Ok(())
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let cell_count = ctx.space().cell_count();
+        let prev = ctx
+            .reads_previous()
+            .read(self.input_field)
+            .ok_or_else(|| PropagatorError::ExecutionFailed {
+                reason: format!("input field {:?} not readable", self.input_field),
+            })?
+            .to_vec();
+
+        if prev.len() != cell_count {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "input field {:?} length {} != cell_count {}",
+                    self.input_field,
+                    prev.len(),
+                    cell_count,
+                ),
+            });
+        }
+
+        let out_buf = if let Some(grid) = row_major_square4(ctx.space()) {
+            let (rows, cols) = (grid.rows() as usize, grid.cols() as usize);
+            self.blur_square4(&prev, rows, cols, grid.edge_behavior())
+        } else {
+            match &self.neighbours {
+                Some(neighbours) if neighbours.len() == cell_count => {
+                    self.blur_generic(&prev, neighbours)
+                }
+                _ => self.blur_generic(&prev, &neighbour_ranks(ctx.space())),
+            }
+        };
+
+        let out = ctx.writes().write(self.output_field).ok_or_else(|| {
+            PropagatorError::ExecutionFailed {
+                reason: format!("output field {:?} not writable", self.output_field),
+            }
+        })?;
+        if out.len() != cell_count {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "output field {:?} length {} != cell_count {}",
+                    self.output_field,
+                    out.len(),
+                    cell_count,
+                ),
+            });
+        }
+        out.copy_from_slice(&out_buf);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use murk_core::TickId;
+    use murk_propagator::scratch::ScratchRegion;
+    use murk_space::{Hex2D, Space, Square4};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+
+    const F_IN: FieldId = FieldId(100);
+    const F_OUT: FieldId = FieldId(101);
+
+    fn blur(sigma: f64) -> GaussianBlur {
+        GaussianBlur::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .sigma(sigma)
+            .build()
+            .unwrap()
+    }
+
+    fn run(prop: &GaussianBlur, space: &dyn Space, input: Vec<Scalar>) -> Vec<Scalar> {
+        let n = space.cell_count();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_IN, input);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_OUT, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            space,
+            TickId(1),
+            0.1,
+        );
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(F_OUT).unwrap().to_vec()
+    }
+
+    #[test]
+    fn builder_minimal() {
+        let prop = blur(1.0);
+        assert_eq!(prop.name(), "GaussianBlur");
+        assert!(prop.reads().is_empty());
+        assert!(prop.reads_previous().contains(F_IN));
+        assert_eq!(prop.writes(), vec![(F_OUT, WriteMode::Full)]);
+        let space = crate::test_helpers::test_space();
+        assert!(prop.max_dt(&space).is_none());
+    }
+
+    #[test]
+    fn builder_rejects_missing_fields_and_bad_sigma() {
+        let result = GaussianBlur::builder().output_field(F_OUT).build();
+        assert!(result.unwrap_err().contains("input_field"));
+        let result = GaussianBlur::builder().input_field(F_IN).build();
+        assert!(result.unwrap_err().contains("output_field"));
+        for sigma in [0.0, -1.0, f64::NAN, f64::INFINITY, 1e9] {
+            let result = GaussianBlur::builder()
+                .input_field(F_IN)
+                .output_field(F_OUT)
+                .sigma(sigma)
+                .build();
+            assert!(result.unwrap_err().contains("sigma"), "sigma {sigma}");
+        }
+    }
+
+    #[test]
+    fn spike_produces_normalized_gaussian_profile() {
+        // 13x13 grid, spike at the centre: every cell the spike reaches
+        // also has its full radius-3 window in bounds.
+        let grid = Square4::new(13, 13, EdgeBehavior::Absorb).unwrap();
+        let mut input = vec![0.0; 169];
+        input[6 * 13 + 6] = 1.0;
+        let out = run(&blur(1.0), &grid, input);
+
+        let g = |d: i32| (-(d * d) as f64 / 2.0).exp();
+        let norm: f64 = (-3..=3).map(g).sum();
+        for dr in -4..=4i32 {
+            for dc in -4..=4i32 {
+                let expected = if dr.abs() <= 3 && dc.abs() <= 3 {
+                    g(dr) * g(dc) / (norm * norm)
+                } else {
+                    0.0
+                };
+                let v = out[((6 + dr) * 13 + 6 + dc) as usize] as f64;
+                assert!(
+                    (v - expected).abs() < 1e-6,
+                    "({dr},{dc}): {v} vs {expected}"
+                );
+            }
+        }
+        let total: Scalar = out.iter().sum();
+        assert!((total - 1.0).abs() < 1e-5, "total {total}");
+    }
+
+    #[test]
+    fn uniform_field_is_unchanged() {
+        for edge in [
+            EdgeBehavior::Absorb,
+            EdgeBehavior::Clamp,
+            EdgeBehavior::Wrap,
+        ] {
+            let grid = Square4::new(5, 6, edge).unwrap();
+            let out = run(&blur(1.5), &grid, vec![2.5; 30]);
+            assert!(out.iter().all(|&v| (v - 2.5).abs() < 1e-5), "{edge:?}");
+        }
+        let hex = Hex2D::new(4, 4).unwrap();
+        let out = run(&blur(1.0), &hex, vec![2.5; hex.cell_count()]);
+        assert!(out.iter().all(|&v| (v - 2.5).abs() < 1e-5), "hex");
+    }
+
+    #[test]
+    fn init_neighbour_table_matches_per_step_build() {
+        let hex = Hex2D::new(5, 4).unwrap();
+        let input: Vec<Scalar> = (0..hex.cell_count()).map(|i| (i % 5) as Scalar).collect();
+        let lazy = run(&blur(1.2), &hex, input.clone());
+        let mut prop = blur(1.2);
+        prop.init(&hex).unwrap();
+        assert!(prop.neighbours.is_some());
+        assert_eq!(run(&prop, &hex, input), lazy);
+
+        // The Square4 fast path needs no table.
+        let mut prop = blur(1.2);
+        prop.init(&Square4::new(4, 4, EdgeBehavior::Wrap).unwrap())
+            .unwrap();
+        assert!(prop.neighbours.is_none());
+    }
+
+    #[test]
+    fn result_is_independent_of_dt() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Wrap).unwrap();
+        let input: Vec<Scalar> = (0..36).map(|i| (i % 7) as Scalar).collect();
+        let prop = blur(0.8);
+        let a = run(&prop, &grid, input.clone());
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_IN, input);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_OUT, 36);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            &grid,
+            TickId(1),
+            5.0,
+        );
+        prop.step(&mut ctx).unwrap();
+        assert_eq!(writer.get_field(F_OUT).unwrap(), &a[..]);
+    }
+}
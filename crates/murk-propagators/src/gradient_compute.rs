@@ -1,17 +1,41 @@
 //! Standalone finite-difference gradient propagator.
 //!
 //! Reads a scalar field from the previous tick (`reads_previous`) and
-//! computes the central-difference gradient into a 2-component vector field.
-//! Has a [`Square4`] fast path for direct index arithmetic and a generic
-//! fallback using `Space::canonical_ordering()`.
+//! computes the finite-difference gradient into a 2-component vector field.
+//! Has a [`Square4`] fast path for direct index arithmetic with a selectable
+//! [`GradientStencil`], a [`Hex2D`] path that fits the gradient in Cartesian
+//! pixel space (with neighbour displacements computed once in
+//! [`Propagator::init`]), and a generic fallback using
+//! `Space::canonical_ordering()`.
+//!
+//! [`Square4`]: murk_space::Square4
 //!
 //! Constructed via the builder pattern: [`GradientCompute::builder`].
 
-use crate::grid_helpers::resolve_axis;
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use crate::grid_helpers::{cached_neighbours, resolve_axis, row_major_square4};
+use murk_core::{scalar_to_f64, FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
-use murk_space::{EdgeBehavior, Square4};
+use murk_propagator::NeighbourCache;
+use murk_space::{EdgeBehavior, Hex2D, Space};
+
+/// Per-cell `(nb_rank, dx, dy)` pixel displacements to each neighbour.
+type HexGradInfo = Vec<Vec<(usize, f64, f64)>>;
+
+/// Finite-difference stencil used on [`Square4`](murk_space::Square4) grids.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GradientStencil {
+    /// Second-order central difference: `(h[+1] - h[-1]) / 2`.
+    #[default]
+    CentralDiff2,
+    /// Fourth-order five-point difference:
+    /// `(-h[+2] + 8 h[+1] - 8 h[-1] + h[-2]) / 12`.
+    ///
+    /// Exact for polynomials up to degree four. Where an absorbing boundary
+    /// cuts off a `±2` sample, that axis falls back to
+    /// [`CentralDiff2`](Self::CentralDiff2).
+    Fivepoint4,
+}
 
 /// A standalone finite-difference gradient propagator.
 ///
@@ -28,6 +52,19 @@ use murk_space::{EdgeBehavior, Square4};
 /// For Absorb boundaries where a neighbour is out-of-bounds, falls back
 /// to `prev[i]` (self value), producing a one-sided difference.
 ///
+/// # Hex2D path
+///
+/// Each neighbour is placed at its [`Hex2D::to_pixel`] centre and the
+/// gradient is the least-squares fit to the neighbour differences:
+///
+/// ```text
+/// g = (Σ d dᵀ)⁻¹ Σ d (h_nb - h_i),   d = pixel(nb) - pixel(i)
+/// ```
+///
+/// With all six neighbours this reduces to `(1/3) Σ d Δh`; at the boundary
+/// the fit uses whichever neighbours exist. Linear ramps in Cartesian
+/// `x`/`y` are reproduced exactly everywhere.
+///
 /// # Generic fallback
 ///
 /// ```text
@@ -57,6 +94,9 @@ use murk_space::{EdgeBehavior, Square4};
 pub struct GradientCompute {
     input_field: FieldId,
     output_field: FieldId,
+    stencil: GradientStencil,
+    /// Hex2D neighbour displacements, built in `init`.
+    hex_grad_info: Option<HexGradInfo>,
 }
 
 /// Builder for [`GradientCompute`].
@@ -65,6 +105,7 @@ pub struct GradientCompute {
 pub struct GradientComputeBuilder {
     input_field: Option<FieldId>,
     output_field: Option<FieldId>,
+    stencil: GradientStencil,
 }
 
 impl GradientCompute {
@@ -73,6 +114,7 @@ impl GradientCompute {
         GradientComputeBuilder {
             input_field: None,
             output_field: None,
+            stencil: GradientStencil::CentralDiff2,
         }
     }
 
@@ -113,26 +155,118 @@ impl GradientCompute {
             });
         }
 
+        // Samples `k` cells along an axis, or `None` past an absorbing edge.
+        let at = |r: i32, c: i32| prev[r as usize * cols as usize + c as usize];
+        let along_col =
+            |r: i32, c: i32, k: i32| resolve_axis(c + k, cols_i, edge).map(|nc| at(r, nc));
+        let along_row =
+            |r: i32, c: i32, k: i32| resolve_axis(r + k, rows_i, edge).map(|nr| at(nr, c));
+
         for r in 0..rows_i {
             for c in 0..cols_i {
                 let i = r as usize * cols as usize + c as usize;
+                grad_out[i * 2] = self.axis_derivative(prev[i], |k| along_col(r, c, k));
+                grad_out[i * 2 + 1] = self.axis_derivative(prev[i], |k| along_row(r, c, k));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Derivative along one axis of the configured stencil, given the
+    /// centre value and a sampler for the cell `k` steps away.
+    ///
+    /// Missing `±1` samples fall back to the centre value (a one-sided
+    /// difference); missing `±2` samples drop to the central difference.
+    fn axis_derivative(&self, centre: Scalar, sample: impl Fn(i32) -> Option<Scalar>) -> Scalar {
+        let (p1, m1) = (sample(1), sample(-1));
+        if self.stencil == GradientStencil::Fivepoint4 {
+            if let (Some(p1), Some(m1), Some(p2), Some(m2)) = (p1, m1, sample(2), sample(-2)) {
+                return (-p2 + 8.0 * p1 - 8.0 * m1 + m2) / 12.0;
+            }
+        }
+        (p1.unwrap_or(centre) - m1.unwrap_or(centre)) / 2.0
+    }
+
+    /// Pixel displacements from every cell of `hex` to its neighbours.
+    fn hex_grad_info(hex: &Hex2D, topology: &NeighbourCache) -> HexGradInfo {
+        let ordering = topology.ordering();
+        ordering
+            .iter()
+            .enumerate()
+            .map(|(i, coord)| {
+                let (x0, y0) = hex.to_pixel(coord);
+                topology
+                    .neighbours(i)
+                    .iter()
+                    .map(|&rank| {
+                        let (x, y) = hex.to_pixel(&ordering[rank]);
+                        (rank, x - x0, y - y0)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Hex2D path: least-squares gradient over the axial neighbours,
+    /// expressed in Cartesian pixel space.
+    fn step_hex(
+        &self,
+        ctx: &mut StepContext<'_>,
+        grad_info: &[Vec<(usize, f64, f64)>],
+    ) -> Result<(), PropagatorError> {
+        let cell_count = grad_info.len();
+
+        let prev = ctx
+            .reads_previous()
+            .read(self.input_field)
+            .ok_or_else(|| PropagatorError::ExecutionFailed {
+                reason: format!("input field {:?} not readable", self.input_field),
+            })?
+            .to_vec();
+
+        let grad_out = ctx.writes().write(self.output_field).ok_or_else(|| {
+            PropagatorError::ExecutionFailed {
+                reason: format!("output field {:?} not writable", self.output_field),
+            }
+        })?;
+        if grad_out.len() != cell_count * 2 || prev.len() != cell_count {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "output field {:?} has {} elements, need {} (2 per cell for gradient); input has {}",
+                    self.output_field,
+                    grad_out.len(),
+                    cell_count * 2,
+                    prev.len(),
+                ),
+            });
+        }
 
-                let h_east = resolve_axis(c + 1, cols_i, edge)
-                    .map(|nc| prev[r as usize * cols as usize + nc as usize])
-                    .unwrap_or(prev[i]);
-                let h_west = resolve_axis(c - 1, cols_i, edge)
-                    .map(|nc| prev[r as usize * cols as usize + nc as usize])
-                    .unwrap_or(prev[i]);
-                let h_south = resolve_axis(r + 1, rows_i, edge)
-                    .map(|nr| prev[nr as usize * cols as usize + c as usize])
-                    .unwrap_or(prev[i]);
-                let h_north = resolve_axis(r - 1, rows_i, edge)
-                    .map(|nr| prev[nr as usize * cols as usize + c as usize])
-                    .unwrap_or(prev[i]);
-
-                grad_out[i * 2] = (h_east - h_west) / 2.0;
-                grad_out[i * 2 + 1] = (h_south - h_north) / 2.0;
+        for (i, nbs) in grad_info.iter().enumerate() {
+            // Normal equations: [sxx sxy; sxy syy] g = [bx; by].
+            let (mut sxx, mut sxy, mut syy, mut bx, mut by) = (0.0f64, 0.0, 0.0, 0.0, 0.0);
+            for &(rank, dx, dy) in nbs {
+                let dh = scalar_to_f64(prev[rank] - prev[i]);
+                sxx += dx * dx;
+                sxy += dx * dy;
+                syy += dy * dy;
+                bx += dx * dh;
+                by += dy * dh;
             }
+            let det = sxx * syy - sxy * sxy;
+            let (gx, gy) = if det.abs() > 1e-9 {
+                ((syy * bx - sxy * by) / det, (sxx * by - sxy * bx) / det)
+            } else if sxx + syy > 0.0 {
+                // Collinear neighbours (single-row or single-column grid):
+                // only the component along that line is observable, and
+                // its least-squares estimate is b / Σ|d|².
+                let n2 = sxx + syy;
+                (bx / n2, by / n2)
+            } else {
+                (0.0, 0.0)
+            };
+            grad_out[i * 2] = gx as Scalar;
+            grad_out[i * 2 + 1] = gy as Scalar;
         }
 
         Ok(())
@@ -140,23 +274,24 @@ impl GradientCompute {
 
     /// Generic fallback using `Space::canonical_ordering()`.
     fn step_generic(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        // Precompute spatial topology before taking any mutable borrows
-        let ordering = ctx.space().canonical_ordering();
+        // Spatial topology, shared with the rest of a propagator group
+        let topology = cached_neighbours(ctx);
+        let ordering = topology.ordering();
         let cell_count = ordering.len();
 
         // Precompute gradient neighbour info: (nb_rank, delta_col, delta_row)
         let grad_info: Vec<Vec<(usize, i32, i32)>> = ordering
             .iter()
-            .map(|coord| {
-                let neighbours = ctx.space().neighbours(coord);
-                neighbours
+            .enumerate()
+            .map(|(i, coord)| {
+                topology
+                    .neighbours(i)
                     .iter()
-                    .filter_map(|nb| {
-                        ctx.space().canonical_rank(nb).map(|rank| {
-                            let dc = if nb.len() >= 2 { nb[1] - coord[1] } else { 0 };
-                            let dr = nb[0] - coord[0];
-                            (rank, dc, dr)
-                        })
+                    .map(|&rank| {
+                        let nb = &ordering[rank];
+                        let dc = if nb.len() >= 2 { nb[1] - coord[1] } else { 0 };
+                        let dr = nb[0] - coord[0];
+                        (rank, dc, dr)
                     })
                     .collect()
             })
@@ -172,27 +307,27 @@ impl GradientCompute {
             .to_vec();
 
         // Compute gradient into local buffer
-        let mut grad_buf = vec![0.0f32; cell_count * 2];
+        let mut grad_buf = vec![0.0; cell_count * 2];
 
         for i in 0..cell_count {
             let gi = &grad_info[i];
-            let mut gx = 0.0f32;
-            let mut gy = 0.0f32;
+            let mut gx = 0.0;
+            let mut gy = 0.0;
             let mut xc = 0u32;
             let mut yc = 0u32;
             for &(rank, dc, dr) in gi {
                 let dh = prev[rank] - prev[i];
                 if dc != 0 {
-                    gx += dh / dc as f32;
+                    gx += dh / dc as Scalar;
                     xc += 1;
                 }
                 if dr != 0 {
-                    gy += dh / dr as f32;
+                    gy += dh / dr as Scalar;
                     yc += 1;
                 }
             }
-            grad_buf[i * 2] = if xc > 0 { gx / xc as f32 } else { 0.0 };
-            grad_buf[i * 2 + 1] = if yc > 0 { gy / yc as f32 } else { 0.0 };
+            grad_buf[i * 2] = if xc > 0 { gx / xc as Scalar } else { 0.0 };
+            grad_buf[i * 2 + 1] = if yc > 0 { gy / yc as Scalar } else { 0.0 };
         }
 
         // Write results
@@ -230,6 +365,16 @@ impl GradientComputeBuilder {
         self
     }
 
+    /// Set the finite-difference stencil used on `Square4` grids.
+    /// Default: [`GradientStencil::CentralDiff2`].
+    ///
+    /// `Hex2D` always uses its neighbour least-squares fit, and other
+    /// spaces use the generic nearest-neighbour difference.
+    pub fn stencil(mut self, stencil: GradientStencil) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
     /// Build the propagator, validating all configuration.
     ///
     /// # Errors
@@ -248,6 +393,8 @@ impl GradientComputeBuilder {
         Ok(GradientCompute {
             input_field,
             output_field,
+            stencil: self.stencil,
+            hex_grad_info: None,
         })
     }
 }
@@ -273,12 +420,30 @@ impl Propagator for GradientCompute {
         None // gradient computation has no stability constraint
     }
 
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        if let Some(hex) = space.downcast_ref::<Hex2D>() {
+            let topology = NeighbourCache::new(space);
+            self.hex_grad_info = Some(Self::hex_grad_info(hex, &topology));
+        }
+        Ok(())
+    }
+
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        if let Some(grid) = ctx.space().downcast_ref::<Square4>() {
+        if let Some(grid) = row_major_square4(ctx.space()) {
             let rows = grid.rows();
             let cols = grid.cols();
             let edge = grid.edge_behavior();
             self.step_square4(ctx, rows, cols, edge)
+        } else if let Some(hex) = ctx.space().downcast_ref::<Hex2D>() {
+            let built;
+            let grad_info = match &self.hex_grad_info {
+                Some(info) if info.len() == hex.cell_count() => info,
+                _ => {
+                    built = Self::hex_grad_info(hex, &cached_neighbours(ctx));
+                    &built
+                }
+            };
+            self.step_hex(ctx, grad_info)
         } else {
             self.step_generic(ctx)
         }
@@ -290,7 +455,7 @@ mod tests {
     use super::*;
     use murk_core::TickId;
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, Space};
+    use murk_space::{EdgeBehavior, Space, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
 
     // Test field IDs far from the hardcoded constants to avoid collision.
@@ -340,6 +505,7 @@ mod tests {
         let result = GradientComputeBuilder {
             input_field: None,
             output_field: None,
+            stencil: GradientStencil::CentralDiff2,
         }
         .build();
         assert!(result.is_err());
@@ -371,10 +537,10 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut scalar = vec![0.0f32; n];
+        let mut scalar = vec![0.0; n];
         for r in 0..3 {
             for c in 0..3 {
-                scalar[r * 3 + c] = (c as f32) * 10.0;
+                scalar[r * 3 + c] = (c as Scalar) * 10.0;
             }
         }
 
@@ -453,7 +619,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut scalar = vec![0.0f32; n];
+        let mut scalar = vec![0.0; n];
         scalar[0] = 50.0;
 
         let mut reader = MockFieldReader::new();
@@ -498,10 +664,10 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut scalar = vec![0.0f32; n];
+        let mut scalar = vec![0.0; n];
         for r in 0..4 {
             for c in 0..4 {
-                scalar[r * 4 + c] = (c as f32) * 10.0;
+                scalar[r * 4 + c] = (c as Scalar) * 10.0;
             }
         }
 
@@ -573,4 +739,150 @@ mod tests {
             other => panic!("expected ExecutionFailed, got {other:?}"),
         }
     }
+
+    // ---------------------------------------------------------------
+    // Stencil and Hex2D tests
+    // ---------------------------------------------------------------
+
+    fn run(prop: &GradientCompute, space: &dyn Space, scalar: Vec<Scalar>) -> Vec<Scalar> {
+        let n = space.cell_count();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_SCALAR, scalar);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_GRAD, n * 2);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            space,
+            TickId(1),
+            0.01,
+        );
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(F_GRAD).unwrap().to_vec()
+    }
+
+    fn with_stencil(stencil: GradientStencil) -> GradientCompute {
+        GradientCompute::builder()
+            .input_field(F_SCALAR)
+            .output_field(F_GRAD)
+            .stencil(stencil)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn linear_ramp_constant_gradient_square4() {
+        // h = 2*col + 3*row on a 7x7 Absorb grid.
+        let grid = Square4::new(7, 7, EdgeBehavior::Absorb).unwrap();
+        let scalar: Vec<Scalar> = (0..49)
+            .map(|i| 2.0 * (i % 7) as Scalar + 3.0 * (i / 7) as Scalar)
+            .collect();
+
+        for (stencil, margin) in [
+            (GradientStencil::CentralDiff2, 1),
+            (GradientStencil::Fivepoint4, 1),
+        ] {
+            let grad = run(&with_stencil(stencil), &grid, scalar.clone());
+            for r in margin..7 - margin {
+                for c in margin..7 - margin {
+                    let i = r * 7 + c;
+                    assert!(
+                        (grad[i * 2] - 2.0).abs() < 1e-5,
+                        "{stencil:?} gx at ({r},{c})"
+                    );
+                    assert!(
+                        (grad[i * 2 + 1] - 3.0).abs() < 1e-5,
+                        "{stencil:?} gy at ({r},{c})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fivepoint_exact_for_cubic() {
+        // h = col^3 on a 1x9 Absorb grid; dh/dx at col 4 is 48.
+        let grid = Square4::new(1, 9, EdgeBehavior::Absorb).unwrap();
+        let scalar: Vec<Scalar> = (0..9).map(|c| (c * c * c) as Scalar).collect();
+
+        let central = run(
+            &with_stencil(GradientStencil::CentralDiff2),
+            &grid,
+            scalar.clone(),
+        );
+        let five = run(&with_stencil(GradientStencil::Fivepoint4), &grid, scalar);
+        assert_eq!(central[4 * 2], 49.0);
+        assert_eq!(five[4 * 2], 48.0);
+
+        // Col 1 has no col -1 sample: falls back to the central difference.
+        assert_eq!(five[2], central[2]);
+    }
+
+    #[test]
+    fn linear_ramp_constant_gradient_hex2d() {
+        // h = 1.5*x - 0.5*y in pixel space; every cell, boundary included,
+        // should recover (1.5, -0.5).
+        let hex = Hex2D::new(5, 6).unwrap();
+        let scalar: Vec<Scalar> = hex
+            .canonical_ordering()
+            .iter()
+            .map(|coord| {
+                let (x, y) = hex.to_pixel(coord);
+                (1.5 * x - 0.5 * y) as Scalar
+            })
+            .collect();
+
+        let grad = run(&with_stencil(GradientStencil::CentralDiff2), &hex, scalar);
+        for i in 0..hex.cell_count() {
+            assert!(
+                (grad[i * 2] - 1.5).abs() < 1e-4,
+                "gx at {i}: {}",
+                grad[i * 2]
+            );
+            assert!(
+                (grad[i * 2 + 1] + 0.5).abs() < 1e-4,
+                "gy at {i}: {}",
+                grad[i * 2 + 1]
+            );
+        }
+    }
+
+    #[test]
+    fn init_hex_grad_info_matches_per_step_build() {
+        let hex = Hex2D::new(4, 5).unwrap();
+        let scalar: Vec<Scalar> = (0..hex.cell_count())
+            .map(|i| ((i * 7) % 5) as Scalar)
+            .collect();
+        let lazy = run(
+            &with_stencil(GradientStencil::CentralDiff2),
+            &hex,
+            scalar.clone(),
+        );
+
+        let mut prop = with_stencil(GradientStencil::CentralDiff2);
+        prop.init(&hex).unwrap();
+        assert_eq!(prop.hex_grad_info.as_ref().unwrap().len(), hex.cell_count());
+        assert_eq!(run(&prop, &hex, scalar), lazy);
+
+        let mut square = with_stencil(GradientStencil::CentralDiff2);
+        square
+            .init(&Square4::new(3, 3, EdgeBehavior::Wrap).unwrap())
+            .unwrap();
+        assert!(square.hex_grad_info.is_none());
+    }
+
+    #[test]
+    fn single_row_hex2d_recovers_along_row_slope() {
+        // All neighbours are collinear along x; only gx is observable.
+        let hex = Hex2D::new(1, 5).unwrap();
+        let scalar: Vec<Scalar> = (0..5).map(|q| 4.0 * q as Scalar).collect();
+        let grad = run(&with_stencil(GradientStencil::CentralDiff2), &hex, scalar);
+        for i in 0..5 {
+            assert!((grad[i * 2] - 4.0).abs() < 1e-5);
+            assert_eq!(grad[i * 2 + 1], 0.0);
+        }
+    }
 }
@@ -0,0 +1,394 @@
+//! Gradient-following agent movement propagator.
+//!
+//! Moves every agent one step per tick toward the neighbouring cell with the
+//! highest (or lowest) value of a scalar field. Agents are read from and
+//! written back to a presence field using the same `agent_id + 1` encoding
+//! as [`AgentMovementPropagator`](crate::AgentMovementPropagator).
+//!
+//! # Movement rules
+//!
+//! - Agents are processed in ascending `agent_id` order.
+//! - An agent only moves if the best neighbour is *strictly* better than its
+//!   current cell, so agents on a flat field (or at a local extremum) stay put.
+//! - Ties between equally good neighbours are broken by lowest canonical rank.
+//! - If the chosen cell is occupied (including by an agent that moved earlier
+//!   this tick), the agent stays.
+//!
+//! Uses `Space::neighbours()` and `Space::canonical_rank()`, so it works on
+//! any topology.
+//!
+//! # Construction
+//!
+//! ```
+//! use murk_core::FieldId;
+//! use murk_propagators::{FollowMode, GradientFollow};
+//!
+//! let prop = GradientFollow::builder()
+//!     .field(FieldId(0))
+//!     .presence_field(FieldId(1))
+//!     .mode(FollowMode::Ascend)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::agent_movement::{decode_agent_positions, sorted_agent_positions};
+use murk_core::{FieldId, FieldReader, FieldSet, PropagatorError, Scalar};
+use murk_propagator::context::StepContext;
+use murk_propagator::propagator::{Propagator, WriteMode};
+
+/// Which way [`GradientFollow`] moves agents along the field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FollowMode {
+    /// Move toward the neighbour with the highest value.
+    Ascend,
+    /// Move toward the neighbour with the lowest value.
+    Descend,
+}
+
+/// A propagator that moves agents one cell per tick up or down a scalar field.
+///
+/// Reads the scalar field and agent presence from the previous tick and
+/// writes the updated presence with [`WriteMode::Incremental`].
+#[derive(Debug)]
+pub struct GradientFollow {
+    field: FieldId,
+    presence_field: FieldId,
+    mode: FollowMode,
+}
+
+/// Builder for [`GradientFollow`].
+///
+/// Required fields: `field` and `presence_field`.
+/// Defaults: `mode = Ascend`.
+pub struct GradientFollowBuilder {
+    field: Option<FieldId>,
+    presence_field: Option<FieldId>,
+    mode: FollowMode,
+}
+
+impl GradientFollow {
+    /// Create a new builder for configuring a `GradientFollow` propagator.
+    pub fn builder() -> GradientFollowBuilder {
+        GradientFollowBuilder {
+            field: None,
+            presence_field: None,
+            mode: FollowMode::Ascend,
+        }
+    }
+
+    /// Returns `true` if `candidate` is strictly preferable to `current`.
+    fn better(&self, candidate: Scalar, current: Scalar) -> bool {
+        match self.mode {
+            FollowMode::Ascend => candidate > current,
+            FollowMode::Descend => candidate < current,
+        }
+    }
+}
+
+impl GradientFollowBuilder {
+    /// Set the scalar field agents follow.
+    pub fn field(mut self, field: FieldId) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// Set the agent presence field (read and written).
+    pub fn presence_field(mut self, field: FieldId) -> Self {
+        self.presence_field = Some(field);
+        self
+    }
+
+    /// Set the follow direction (default: [`FollowMode::Ascend`]).
+    pub fn mode(mut self, mode: FollowMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Build the propagator, validating all configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// - `field` is not set
+    /// - `presence_field` is not set
+    /// - `field` and `presence_field` are the same
+    pub fn build(self) -> Result<GradientFollow, String> {
+        let field = self.field.ok_or_else(|| "field is required".to_string())?;
+        let presence_field = self
+            .presence_field
+            .ok_or_else(|| "presence_field is required".to_string())?;
+
+        if field == presence_field {
+            return Err(format!(
+                "field and presence_field must differ, both are {field:?}"
+            ));
+        }
+
+        Ok(GradientFollow {
+            field,
+            presence_field,
+            mode: self.mode,
+        })
+    }
+}
+
+impl Propagator for GradientFollow {
+    fn name(&self) -> &str {
+        "GradientFollow"
+    }
+
+    fn reads(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
+    fn reads_previous(&self) -> FieldSet {
+        [self.field, self.presence_field].into_iter().collect()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        vec![(self.presence_field, WriteMode::Incremental)]
+    }
+
+    fn max_dt(&self, _space: &dyn murk_space::Space) -> Option<f64> {
+        None
+    }
+
+    fn agent_positions(&self, state: &dyn FieldReader) -> Option<Vec<(u16, usize)>> {
+        sorted_agent_positions(state, self.presence_field)
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let values = ctx
+            .reads_previous()
+            .read(self.field)
+            .ok_or_else(|| PropagatorError::ExecutionFailed {
+                reason: format!("field {:?} not readable", self.field),
+            })?
+            .to_vec();
+        let mut presence = ctx
+            .reads_previous()
+            .read(self.presence_field)
+            .ok_or_else(|| PropagatorError::ExecutionFailed {
+                reason: format!("presence field {:?} not readable", self.presence_field),
+            })?
+            .to_vec();
+
+        if values.len() != presence.len() {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "field length ({}) != presence field length ({})",
+                    values.len(),
+                    presence.len()
+                ),
+            });
+        }
+
+        let mut agents: Vec<(u16, usize)> = decode_agent_positions(&presence).into_iter().collect();
+        agents.sort_unstable_by_key(|&(id, _)| id);
+
+        if !agents.is_empty() {
+            let ordering = ctx.space().canonical_ordering();
+            for &(_, pos) in &agents {
+                let current = values[pos];
+                let mut best: Option<(usize, Scalar)> = None;
+                for nb in ctx.space().neighbours(&ordering[pos]).iter() {
+                    let Some(rank) = ctx.space().canonical_rank(nb) else {
+                        continue;
+                    };
+                    let v = values[rank];
+                    let replace = match best {
+                        None => true,
+                        Some((best_rank, best_v)) => {
+                            self.better(v, best_v) || (v == best_v && rank < best_rank)
+                        }
+                    };
+                    if replace {
+                        best = Some((rank, v));
+                    }
+                }
+
+                if let Some((target, v)) = best {
+                    if self.better(v, current) && presence[target] == 0.0 {
+                        presence[target] = presence[pos];
+                        presence[pos] = 0.0;
+                    }
+                }
+            }
+        }
+
+        let out = ctx.writes().write(self.presence_field).ok_or_else(|| {
+            PropagatorError::ExecutionFailed {
+                reason: format!("presence field {:?} not writable", self.presence_field),
+            }
+        })?;
+        if out.len() != presence.len() {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "presence output length ({}) != input length ({})",
+                    out.len(),
+                    presence.len()
+                ),
+            });
+        }
+        out.copy_from_slice(&presence);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use murk_core::TickId;
+    use murk_propagator::scratch::ScratchRegion;
+    use murk_space::{EdgeBehavior, Space, Square4};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+
+    const F_HEAT: FieldId = FieldId(100);
+    const F_PRES: FieldId = FieldId(101);
+
+    fn ascend() -> GradientFollow {
+        GradientFollow::builder()
+            .field(F_HEAT)
+            .presence_field(F_PRES)
+            .build()
+            .unwrap()
+    }
+
+    /// Run one tick and return the new presence buffer.
+    fn step_once(
+        prop: &GradientFollow,
+        grid: &Square4,
+        heat: &[Scalar],
+        presence: &[Scalar],
+    ) -> Vec<Scalar> {
+        let n = grid.cell_count();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_HEAT, heat.to_vec());
+        reader.set_field(F_PRES, presence.to_vec());
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_PRES, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            grid,
+            TickId(1),
+            0.1,
+        );
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(F_PRES).unwrap().to_vec()
+    }
+
+    // ---------------------------------------------------------------
+    // Builder tests
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn builder_minimal() {
+        let prop = ascend();
+        assert_eq!(prop.name(), "GradientFollow");
+        assert!(prop.reads().is_empty(), "reads() should be empty");
+        let space = crate::test_helpers::test_space();
+        assert!(prop.max_dt(&space).is_none());
+
+        let rp = prop.reads_previous();
+        assert!(rp.contains(F_HEAT));
+        assert!(rp.contains(F_PRES));
+
+        let w = prop.writes();
+        assert_eq!(w, vec![(F_PRES, WriteMode::Incremental)]);
+    }
+
+    #[test]
+    fn builder_rejects_missing_fields() {
+        let r = GradientFollow::builder().presence_field(F_PRES).build();
+        assert!(r.unwrap_err().contains("field"));
+        let r = GradientFollow::builder().field(F_HEAT).build();
+        assert!(r.unwrap_err().contains("presence_field"));
+    }
+
+    #[test]
+    fn builder_rejects_same_field() {
+        let r = GradientFollow::builder()
+            .field(F_HEAT)
+            .presence_field(F_HEAT)
+            .build();
+        assert!(r.is_err());
+    }
+
+    // ---------------------------------------------------------------
+    // Step logic tests
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn agents_climb_to_heat_peak() {
+        let grid = Square4::new(7, 7, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        // Peak at (3,3); heat falls off with Manhattan distance.
+        let heat: Vec<Scalar> = (0..n)
+            .map(|i| {
+                let (r, c) = ((i / 7) as i32, (i % 7) as i32);
+                10.0 - ((r - 3).abs() + (c - 3).abs()) as Scalar
+            })
+            .collect();
+        let mut presence = vec![0.0; n];
+        presence[0] = 1.0; // agent 0 at (0,0)
+
+        let prop = ascend();
+        for _ in 0..6 {
+            presence = step_once(&prop, &grid, &heat, &presence);
+        }
+        assert_eq!(presence[3 * 7 + 3], 1.0, "agent should reach the peak");
+        assert_eq!(presence.iter().filter(|&&v| v != 0.0).count(), 1);
+
+        // Once at the peak, the agent stays.
+        let again = step_once(&prop, &grid, &heat, &presence);
+        assert_eq!(again, presence);
+    }
+
+    #[test]
+    fn descend_moves_downhill() {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let heat = vec![0.0, 1.0, 2.0, 1.0, 2.0, 3.0, 2.0, 3.0, 4.0];
+        let mut presence = vec![0.0; 9];
+        presence[8] = 1.0;
+
+        let prop = GradientFollow::builder()
+            .field(F_HEAT)
+            .presence_field(F_PRES)
+            .mode(FollowMode::Descend)
+            .build()
+            .unwrap();
+        let out = step_once(&prop, &grid, &heat, &presence);
+        // Neighbours 5 and 7 tie at 3.0; lower canonical rank wins.
+        assert_eq!(out[5], 1.0);
+        assert_eq!(out[8], 0.0);
+    }
+
+    #[test]
+    fn flat_field_no_agent_moves() {
+        let grid = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let heat = vec![1.0; n];
+        let mut presence = vec![0.0; n];
+        presence[0] = 1.0;
+        presence[12] = 2.0;
+        presence[24] = 3.0;
+
+        let out = step_once(&ascend(), &grid, &heat, &presence);
+        assert_eq!(out, presence, "flat field must not move any agent");
+    }
+
+    #[test]
+    fn occupied_target_blocks_move() {
+        let grid = Square4::new(3, 1, EdgeBehavior::Absorb).unwrap();
+        let heat = vec![0.0, 1.0, 2.0];
+        // Agent 0 at cell 1 wants cell 2, occupied by agent 1 at the peak.
+        let presence = vec![0.0, 1.0, 2.0];
+        let out = step_once(&ascend(), &grid, &heat, &presence);
+        assert_eq!(out, presence);
+    }
+}
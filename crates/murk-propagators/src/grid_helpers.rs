@@ -1,10 +1,23 @@
 //! Shared grid-topology helpers for Square4 propagators.
 //!
-//! Provides axis resolution (absorb/clamp/wrap) and 4-connected neighbour
-//! lookup used by multiple propagators. Centralised here to eliminate
-//! copy-paste duplication.
+//! Provides axis resolution (absorb/clamp/wrap), 4-connected neighbour
+//! lookup, and a per-cell neighbour-rank table for arbitrary spaces, used by
+//! multiple propagators. Centralised here to eliminate copy-paste
+//! duplication.
 
-use murk_space::EdgeBehavior;
+use murk_propagator::{NeighbourCache, StepContext};
+use murk_space::{EdgeBehavior, MemoryOrder, Space, Square4};
+use std::borrow::Cow;
+
+/// Downcast to a row-major [`Square4`] with one edge behavior on both
+/// axes, the layout the index-arithmetic fast paths assume. Column-major
+/// and per-axis-edge grids take the generic path.
+pub(crate) fn row_major_square4(space: &dyn Space) -> Option<&Square4> {
+    space.downcast_ref::<Square4>().filter(|g| {
+        let [row_edge, col_edge] = g.edge_behaviors();
+        g.memory_order() == MemoryOrder::RowMajor && row_edge == col_edge
+    })
+}
 
 /// Resolve a single axis value under the given edge behavior.
 /// Returns `Some(resolved)` or `None` for Absorb out-of-bounds.
@@ -40,6 +53,43 @@ pub(crate) fn neighbours_flat(
     result
 }
 
+/// The neighbour table shared by an enclosing
+/// [`PropagatorGroup`](murk_propagator::PropagatorGroup), or one built
+/// from the context's space when running standalone.
+pub(crate) fn cached_neighbours<'a>(ctx: &StepContext<'a>) -> Cow<'a, NeighbourCache> {
+    match ctx.neighbour_cache() {
+        Some(cache) => Cow::Borrowed(cache),
+        None => Cow::Owned(NeighbourCache::new(ctx.space())),
+    }
+}
+
+/// Canonical ranks of each cell's neighbours, indexed by canonical rank.
+///
+/// Row-major [`Square4`] grids use [`neighbours_flat`]; every other space
+/// goes through [`Space::neighbours_into`] and [`Space::canonical_rank`].
+pub(crate) fn neighbour_ranks(space: &dyn Space) -> Vec<Vec<usize>> {
+    if let Some(grid) = row_major_square4(space) {
+        let rows = grid.rows() as i32;
+        let cols = grid.cols() as i32;
+        let edge = grid.edge_behavior();
+        return (0..rows)
+            .flat_map(|r| (0..cols).map(move |c| neighbours_flat(r, c, rows, cols, edge).to_vec()))
+            .collect();
+    }
+    let mut neighbours = smallvec::SmallVec::new();
+    space
+        .canonical_ordering()
+        .iter()
+        .map(|coord| {
+            space.neighbours_into(coord, &mut neighbours);
+            neighbours
+                .iter()
+                .filter_map(|nb| space.canonical_rank(nb))
+                .collect()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +149,43 @@ mod tests {
         assert!(nbs.contains(&2));
         assert!(nbs.contains(&1));
     }
+
+    #[test]
+    fn neighbour_ranks_fast_path_matches_generic() {
+        use murk_space::{Hex2D, Line1D};
+
+        let grid = Square4::new(4, 5, EdgeBehavior::Absorb).unwrap();
+        let fast = neighbour_ranks(&grid);
+        assert_eq!(fast.len(), 20);
+        for (i, coord) in grid.canonical_ordering().iter().enumerate() {
+            let mut expected: Vec<usize> = grid
+                .neighbours(coord)
+                .iter()
+                .filter_map(|nb| grid.canonical_rank(nb))
+                .collect();
+            let mut got = fast[i].clone();
+            expected.sort_unstable();
+            got.sort_unstable();
+            assert_eq!(got, expected, "cell {i}");
+        }
+
+        let hex = Hex2D::new(3, 3).unwrap();
+        assert_eq!(neighbour_ranks(&hex)[4].len(), 6);
+        let line = Line1D::new(3, EdgeBehavior::Absorb).unwrap();
+        assert_eq!(neighbour_ranks(&line), vec![vec![1], vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn per_axis_edge_grid_takes_generic_path() {
+        let mixed =
+            Square4::new_per_axis(3, 4, [EdgeBehavior::Wrap, EdgeBehavior::Absorb]).unwrap();
+        assert!(row_major_square4(&mixed).is_none());
+        // Rank 1 = [0, 1]: north wraps to [2, 1] (rank 9); no column wrap.
+        let mut ranks = neighbour_ranks(&mixed)[1].clone();
+        ranks.sort_unstable();
+        assert_eq!(ranks, vec![0, 2, 5, 9]);
+
+        let uniform = Square4::new(3, 4, EdgeBehavior::Wrap).unwrap();
+        assert!(row_major_square4(&uniform).is_some());
+    }
 }
@@ -9,7 +9,9 @@
 //! - Reads from the **previous tick** (`reads_previous`) via the frozen
 //!   tick-start view (Jacobi-style).
 //! - Writes the **same field** (`WriteMode::Full`) in the current tick.
-//! - No math, no parameters beyond the field ID.
+//! - By default no math: the output is bit-identical to the input. An
+//!   optional affine map `scale * input + offset` can be configured through
+//!   [`IdentityCopy::builder`], e.g. to convert units mid-pipeline.
 //!
 //! This differs from the test-utils `IdentityPropagator`, which reads from
 //! the current tick (`reads()`) and copies to a *different* field.
@@ -21,21 +23,42 @@
 //! use murk_propagators::IdentityCopy;
 //!
 //! let prop = IdentityCopy::new(FieldId(5));
+//!
+//! // Celsius to Kelvin in place.
+//! let to_kelvin = IdentityCopy::builder()
+//!     .field(FieldId(6))
+//!     .offset(273.15)
+//!     .build()
+//!     .unwrap();
 //! ```
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
 
 /// A propagator that copies a single field verbatim from the previous tick
 /// to the current tick.
 ///
-/// This is the simplest production propagator: no arithmetic, no spatial
-/// queries, no scratch memory. It exists to carry forward state that no
-/// other propagator writes (e.g., agent positions between movement ticks).
+/// This is the simplest production propagator: no spatial queries, no
+/// scratch memory, and by default no arithmetic. It exists to carry forward
+/// state that no other propagator writes (e.g., agent positions between
+/// movement ticks). A non-default `scale` or `offset` writes
+/// `scale * input + offset` instead.
 #[derive(Debug)]
 pub struct IdentityCopy {
     field: FieldId,
+    scale: Scalar,
+    offset: Scalar,
+}
+
+/// Builder for [`IdentityCopy`].
+///
+/// Required fields: `field`.
+/// Defaults: `scale = 1.0`, `offset = 0.0` (verbatim copy).
+pub struct IdentityCopyBuilder {
+    field: Option<FieldId>,
+    scale: Scalar,
+    offset: Scalar,
 }
 
 impl IdentityCopy {
@@ -44,7 +67,62 @@ impl IdentityCopy {
     /// The propagator will read `field` from the previous tick and write
     /// it unchanged to the current tick.
     pub fn new(field: FieldId) -> Self {
-        Self { field }
+        Self {
+            field,
+            scale: 1.0,
+            offset: 0.0,
+        }
+    }
+
+    /// Create a new builder for configuring an `IdentityCopy` propagator.
+    pub fn builder() -> IdentityCopyBuilder {
+        IdentityCopyBuilder {
+            field: None,
+            scale: 1.0,
+            offset: 0.0,
+        }
+    }
+}
+
+impl IdentityCopyBuilder {
+    /// Set the field to carry forward (read previous, write current).
+    pub fn field(mut self, field: FieldId) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// Set the multiplicative factor applied to each value. Default: `1.0`.
+    pub fn scale(mut self, scale: Scalar) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Set the constant added after scaling. Default: `0.0`.
+    pub fn offset(mut self, offset: Scalar) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Build the propagator, validating all configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// - `field` is not set
+    /// - `scale` or `offset` is not finite
+    pub fn build(self) -> Result<IdentityCopy, String> {
+        let field = self.field.ok_or_else(|| "field is required".to_string())?;
+        if !self.scale.is_finite() {
+            return Err(format!("scale must be finite, got {}", self.scale));
+        }
+        if !self.offset.is_finite() {
+            return Err(format!("offset must be finite, got {}", self.offset));
+        }
+        Ok(IdentityCopy {
+            field,
+            scale: self.scale,
+            offset: self.offset,
+        })
     }
 }
 
@@ -83,7 +161,13 @@ impl Propagator for IdentityCopy {
                 .ok_or_else(|| PropagatorError::ExecutionFailed {
                     reason: format!("field {:?} not writable", self.field),
                 })?;
-        out.copy_from_slice(&prev);
+        if self.scale == 1.0 && self.offset == 0.0 {
+            out.copy_from_slice(&prev);
+        } else {
+            for (o, &p) in out.iter_mut().zip(&prev) {
+                *o = self.scale * p + self.offset;
+            }
+        }
         Ok(())
     }
 }
@@ -177,7 +261,7 @@ mod tests {
         let space = Line1D::new(n_cells as u32, EdgeBehavior::Absorb).unwrap();
         let prop = IdentityCopy::new(F_FIELD);
 
-        let input: Vec<f32> = (0..buf_len).map(|i| (i as f32) * 10.0).collect();
+        let input: Vec<Scalar> = (0..buf_len).map(|i| (i as Scalar) * 10.0).collect();
         let mut reader = MockFieldReader::new();
         reader.set_field(F_FIELD, input.clone());
 
@@ -196,4 +280,76 @@ mod tests {
             "multi-component vector field must be copied verbatim"
         );
     }
+
+    #[test]
+    fn builder_defaults_match_new() {
+        let space = Line1D::new(4, EdgeBehavior::Absorb).unwrap();
+        let built = IdentityCopy::builder().field(F_FIELD).build().unwrap();
+
+        let input = vec![-0.0, 1.5, Scalar::MIN_POSITIVE, -3.25];
+        let mut outputs = Vec::new();
+        for prop in [IdentityCopy::new(F_FIELD), built] {
+            let mut reader = MockFieldReader::new();
+            reader.set_field(F_FIELD, input.clone());
+            let mut writer = MockFieldWriter::new();
+            writer.add_field(F_FIELD, 4);
+            let mut scratch = ScratchRegion::new(0);
+            let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &space);
+            prop.step(&mut ctx).unwrap();
+            let out: Vec<_> = writer
+                .get_field(F_FIELD)
+                .unwrap()
+                .iter()
+                .map(|v| v.to_bits())
+                .collect();
+            outputs.push(out);
+        }
+        let expected: Vec<_> = input.iter().map(|v| v.to_bits()).collect();
+        assert_eq!(outputs[0], expected);
+        assert_eq!(outputs[1], expected);
+    }
+
+    #[test]
+    fn scale_and_offset_apply_affine_map() {
+        let space = Line1D::new(4, EdgeBehavior::Absorb).unwrap();
+        let prop = IdentityCopy::builder()
+            .field(F_FIELD)
+            .scale(2.0)
+            .offset(-1.0)
+            .build()
+            .unwrap();
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_FIELD, vec![0.0, 1.0, 2.5, -4.0]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_FIELD, 4);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &space);
+        prop.step(&mut ctx).unwrap();
+
+        assert_eq!(
+            writer.get_field(F_FIELD).unwrap(),
+            &[-1.0, 1.0, 4.0, -9.0][..]
+        );
+    }
+
+    #[test]
+    fn builder_rejects_invalid_config() {
+        assert!(IdentityCopy::builder()
+            .build()
+            .unwrap_err()
+            .contains("field"));
+        assert!(IdentityCopy::builder()
+            .field(F_FIELD)
+            .scale(Scalar::NAN)
+            .build()
+            .unwrap_err()
+            .contains("scale"));
+        assert!(IdentityCopy::builder()
+            .field(F_FIELD)
+            .offset(Scalar::INFINITY)
+            .build()
+            .unwrap_err()
+            .contains("offset"));
+    }
 }
@@ -1,10 +1,16 @@
-//! Morphological erosion/dilation propagator.
+//! Morphological erosion/dilation/opening/closing propagator.
 //!
 //! Operates on a scalar field binarized by a threshold: values above the
 //! threshold are "present" (1), at or below are "absent" (0).
 //!
 //! - **Dilate**: output is 1.0 if *any* cell within `radius` hops is present.
 //! - **Erode**: output is 1.0 only if *all* cells within `radius` hops are present.
+//! - **Open**: erode then dilate — removes isolated specks.
+//! - **Close**: dilate then erode — fills small holes.
+//!
+//! Each primitive pass is repeated `iterations` times; for `Open` and
+//! `Close` both halves are repeated, so `Open` with 2 iterations is
+//! erode, erode, dilate, dilate.
 //!
 //! Useful for computing reachability, expanding danger zones, shrinking
 //! safe zones, and smoothing binary masks.
@@ -13,7 +19,7 @@
 //!
 //! Constructed via the builder pattern: [`MorphologicalOp::builder`].
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
 use std::collections::{HashSet, VecDeque};
@@ -25,20 +31,25 @@ pub enum MorphOp {
     Dilate,
     /// Output is 1.0 only if all cells in the neighborhood are present.
     Erode,
+    /// Erode then dilate: removes specks smaller than the structuring element.
+    Open,
+    /// Dilate then erode: fills holes smaller than the structuring element.
+    Close,
 }
 
 /// A morphological erosion/dilation propagator.
 ///
 /// Reads a scalar field from the previous tick, binarizes it using
 /// `threshold`, applies the morphological operation within `radius`
-/// hops, and writes the result as a binary field (0.0 or 1.0).
+/// hops (repeated `iterations` times), and writes the result as a binary field (0.0 or 1.0).
 #[derive(Debug)]
 pub struct MorphologicalOp {
     input_field: FieldId,
     output_field: FieldId,
     op: MorphOp,
     radius: u32,
-    threshold: f32,
+    threshold: Scalar,
+    iterations: u32,
 }
 
 /// Builder for [`MorphologicalOp`].
@@ -49,7 +60,8 @@ pub struct MorphologicalOpBuilder {
     output_field: Option<FieldId>,
     op: MorphOp,
     radius: u32,
-    threshold: f32,
+    threshold: Scalar,
+    iterations: u32,
 }
 
 impl MorphologicalOp {
@@ -61,7 +73,47 @@ impl MorphologicalOp {
             op: MorphOp::Dilate,
             radius: 1,
             threshold: 0.5,
+            iterations: 1,
+        }
+    }
+
+    /// Apply one primitive dilate (`dilate == true`) or erode pass within
+    /// `radius` hops of every cell.
+    fn pass(&self, binary: &[bool], neighbour_ranks: &[Vec<usize>], dilate: bool) -> Vec<bool> {
+        // Pre-allocate BFS containers outside the loop; clear() between
+        // iterations to amortise allocation cost (fixes #94 hotspot 1).
+        let mut out = vec![false; binary.len()];
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        for (i, out_cell) in out.iter_mut().enumerate() {
+            visited.clear();
+            queue.clear();
+            visited.insert(i);
+            queue.push_back((i, 0u32));
+
+            let mut all_present = true;
+            let mut any_present = false;
+
+            while let Some((rank, depth)) = queue.pop_front() {
+                if binary[rank] {
+                    any_present = true;
+                } else {
+                    all_present = false;
+                }
+
+                if depth < self.radius {
+                    for &nb_rank in &neighbour_ranks[rank] {
+                        if visited.insert(nb_rank) {
+                            queue.push_back((nb_rank, depth + 1));
+                        }
+                    }
+                }
+            }
+
+            *out_cell = if dilate { any_present } else { all_present };
         }
+        out
     }
 }
 
@@ -92,11 +144,18 @@ impl MorphologicalOpBuilder {
 
     /// Set the binarization threshold (default: 0.5).
     /// Values strictly above threshold are "present".
-    pub fn threshold(mut self, threshold: f32) -> Self {
+    pub fn threshold(mut self, threshold: Scalar) -> Self {
         self.threshold = threshold;
         self
     }
 
+    /// Set how many times each primitive pass is applied (default: 1).
+    /// Must be >= 1.
+    pub fn iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
     /// Build the propagator, validating all configuration.
     ///
     /// # Errors
@@ -105,6 +164,7 @@ impl MorphologicalOpBuilder {
     /// - `input_field` is not set
     /// - `output_field` is not set
     /// - `radius` is 0
+    /// - `iterations` is 0
     pub fn build(self) -> Result<MorphologicalOp, String> {
         let input_field = self
             .input_field
@@ -117,12 +177,17 @@ impl MorphologicalOpBuilder {
             return Err("radius must be >= 1".to_string());
         }
 
+        if self.iterations == 0 {
+            return Err("iterations must be >= 1".to_string());
+        }
+
         Ok(MorphologicalOp {
             input_field,
             output_field,
             op: self.op,
             radius: self.radius,
             threshold: self.threshold,
+            iterations: self.iterations,
         })
     }
 }
@@ -184,57 +249,24 @@ impl Propagator for MorphologicalOp {
         }
 
         // Binarize input
-        let binary: Vec<bool> = prev.iter().map(|&v| v > self.threshold).collect();
-
-        // Compute output into local buffer.
-        // Pre-allocate BFS containers outside the loop; clear() between
-        // iterations to amortise allocation cost (fixes #94 hotspot 1).
-        let mut out_buf = vec![0.0f32; cell_count];
-        let mut visited = HashSet::new();
-        let mut queue = VecDeque::new();
-
-        for (i, out_cell) in out_buf.iter_mut().enumerate() {
-            visited.clear();
-            queue.clear();
-            visited.insert(i);
-            queue.push_back((i, 0u32));
-
-            let mut all_present = true;
-            let mut any_present = false;
-
-            while let Some((rank, depth)) = queue.pop_front() {
-                if binary[rank] {
-                    any_present = true;
-                } else {
-                    all_present = false;
-                }
-
-                if depth < self.radius {
-                    for &nb_rank in &neighbour_ranks[rank] {
-                        if visited.insert(nb_rank) {
-                            queue.push_back((nb_rank, depth + 1));
-                        }
-                    }
-                }
+        let mut binary: Vec<bool> = prev.iter().map(|&v| v > self.threshold).collect();
+
+        // Compound operations expand into a sequence of primitive passes.
+        let passes: &[bool] = match self.op {
+            MorphOp::Dilate => &[true],
+            MorphOp::Erode => &[false],
+            MorphOp::Open => &[false, true],
+            MorphOp::Close => &[true, false],
+        };
+        for &dilate in passes {
+            for _ in 0..self.iterations {
+                binary = self.pass(&binary, &neighbour_ranks, dilate);
             }
-
-            *out_cell = match self.op {
-                MorphOp::Dilate => {
-                    if any_present {
-                        1.0
-                    } else {
-                        0.0
-                    }
-                }
-                MorphOp::Erode => {
-                    if all_present {
-                        1.0
-                    } else {
-                        0.0
-                    }
-                }
-            };
         }
+        let out_buf: Vec<Scalar> = binary
+            .iter()
+            .map(|&present| if present { 1.0 } else { 0.0 })
+            .collect();
 
         let out = ctx.writes().write(self.output_field).ok_or_else(|| {
             PropagatorError::ExecutionFailed {
@@ -327,10 +359,46 @@ mod tests {
         assert!(result.unwrap_err().contains("radius"));
     }
 
+    #[test]
+    fn builder_rejects_zero_iterations() {
+        let result = MorphologicalOp::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .iterations(0)
+            .build();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("iterations"));
+    }
+
     // ---------------------------------------------------------------
     // Step logic tests
     // ---------------------------------------------------------------
 
+    /// Run `prop` once on a 7x7 Absorb grid with the given present cells.
+    fn run_7x7(prop: &MorphologicalOp, present: &[(usize, usize)]) -> Vec<Scalar> {
+        let grid = Square4::new(7, 7, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let mut input = vec![0.0; n];
+        for &(r, c) in present {
+            input[r * 7 + c] = 1.0;
+        }
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_IN, input);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_OUT, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid);
+        prop.step(&mut ctx).unwrap();
+        writer.get_field(F_OUT).unwrap().to_vec()
+    }
+
+    fn block_3x3_at(r0: usize, c0: usize) -> Vec<(usize, usize)> {
+        (r0..r0 + 3)
+            .flat_map(|r| (c0..c0 + 3).map(move |c| (r, c)))
+            .collect()
+    }
+
     #[test]
     fn dilate_expands_single_cell() {
         // 3x3 grid, single cell present at center (cell 4)
@@ -346,7 +414,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut input = vec![0.0f32; n];
+        let mut input = vec![0.0; n];
         input[4] = 1.0; // center
 
         let mut reader = MockFieldReader::new();
@@ -419,7 +487,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut input = vec![0.0f32; n];
+        let mut input = vec![0.0; n];
         input[4] = 1.0;
 
         let mut reader = MockFieldReader::new();
@@ -452,7 +520,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut input = vec![0.0f32; n];
+        let mut input = vec![0.0; n];
         input[12] = 1.0;
 
         let mut reader = MockFieldReader::new();
@@ -574,4 +642,86 @@ mod tests {
             other => panic!("expected ExecutionFailed, got {other:?}"),
         }
     }
+
+    #[test]
+    fn erode_shrinks_interior_block() {
+        let prop = MorphologicalOp::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .op(MorphOp::Erode)
+            .build()
+            .unwrap();
+
+        let out = run_7x7(&prop, &block_3x3_at(2, 2));
+        // Only the block centre has all four neighbours present.
+        assert_eq!(out[3 * 7 + 3], 1.0, "block centre survives");
+        assert_eq!(out.iter().filter(|&&v| v == 1.0).count(), 1);
+    }
+
+    #[test]
+    fn open_removes_isolated_speck() {
+        let prop = MorphologicalOp::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .op(MorphOp::Open)
+            .build()
+            .unwrap();
+
+        let mut present = block_3x3_at(1, 1);
+        present.push((5, 5)); // isolated speck
+        let out = run_7x7(&prop, &present);
+
+        assert_eq!(out[5 * 7 + 5], 0.0, "speck should be removed");
+        // Opening a square by the 4-connected cross leaves the cross.
+        for (r, c) in [(2, 2), (1, 2), (3, 2), (2, 1), (2, 3)] {
+            assert_eq!(out[r * 7 + c], 1.0, "block cross ({r},{c}) retained");
+        }
+        assert_eq!(out.iter().filter(|&&v| v == 1.0).count(), 5);
+    }
+
+    #[test]
+    fn close_fills_single_hole() {
+        let prop = MorphologicalOp::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .op(MorphOp::Close)
+            .build()
+            .unwrap();
+
+        // 5x5 block with a one-cell hole in the middle.
+        let present: Vec<(usize, usize)> = (1..6)
+            .flat_map(|r| (1..6).map(move |c| (r, c)))
+            .filter(|&rc| rc != (3, 3))
+            .collect();
+        let out = run_7x7(&prop, &present);
+
+        assert_eq!(out[3 * 7 + 3], 1.0, "hole should be filled");
+        for &(r, c) in &present {
+            assert_eq!(out[r * 7 + c], 1.0, "block cell ({r},{c}) retained");
+        }
+    }
+
+    #[test]
+    fn iterations_repeat_dilation() {
+        let once = MorphologicalOp::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .op(MorphOp::Dilate)
+            .iterations(2)
+            .build()
+            .unwrap();
+        let radius_two = MorphologicalOp::builder()
+            .input_field(F_IN)
+            .output_field(F_OUT)
+            .op(MorphOp::Dilate)
+            .radius(2)
+            .build()
+            .unwrap();
+
+        // Two radius-1 dilations equal one radius-2 dilation.
+        let a = run_7x7(&once, &[(3, 3)]);
+        let b = run_7x7(&radius_two, &[(3, 3)]);
+        assert_eq!(a, b);
+        assert_eq!(a.iter().filter(|&&v| v == 1.0).count(), 13);
+    }
 }
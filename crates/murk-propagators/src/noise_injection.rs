@@ -12,11 +12,16 @@
 //! - **Uniform**: `v += scale * U(-1,1)`
 //! - **SaltPepper**: with probability `scale`, set to 0.0 or 1.0
 //!
+//! An optional [`RegionSpec`] mask confines noise to a subset of cells;
+//! cells outside the mask are copied through unchanged. The mask is
+//! compiled once in [`init`](Propagator::init).
+//!
 //! Constructed via the builder pattern: [`NoiseInjection::builder`].
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
+use murk_space::{RegionSpec, Space};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 
@@ -43,6 +48,10 @@ pub struct NoiseInjection {
     noise_type: NoiseType,
     scale: f64,
     seed_offset: u64,
+    components: usize,
+    mask: Option<RegionSpec>,
+    /// Per-cell membership of `mask` by canonical rank, compiled in `init`.
+    compiled_mask: Option<Vec<bool>>,
 }
 
 /// Builder for [`NoiseInjection`].
@@ -53,6 +62,8 @@ pub struct NoiseInjectionBuilder {
     noise_type: NoiseType,
     scale: f64,
     seed_offset: u64,
+    components: usize,
+    mask: Option<RegionSpec>,
 }
 
 impl NoiseInjection {
@@ -63,6 +74,8 @@ impl NoiseInjection {
             noise_type: NoiseType::Gaussian,
             scale: 0.1,
             seed_offset: 0,
+            components: 1,
+            mask: None,
         }
     }
 
@@ -73,6 +86,26 @@ impl NoiseInjection {
         let u2: f64 = rng.gen();
         (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
     }
+
+    /// Compile the mask region into a per-cell membership table indexed
+    /// by canonical rank.
+    fn compile_mask(space: &dyn Space, spec: &RegionSpec) -> Result<Vec<bool>, PropagatorError> {
+        let plan = space
+            .compile_region(spec)
+            .map_err(|e| PropagatorError::ExecutionFailed {
+                reason: format!("noise mask region failed to compile: {e}"),
+            })?;
+        let cell_count = space.cell_count();
+        let mut mask = vec![false; cell_count];
+        for coord in plan.coords() {
+            if let Some(rank) = space.canonical_rank(coord) {
+                if rank < cell_count {
+                    mask[rank] = true;
+                }
+            }
+        }
+        Ok(mask)
+    }
 }
 
 impl NoiseInjectionBuilder {
@@ -103,6 +136,29 @@ impl NoiseInjectionBuilder {
         self
     }
 
+    /// Set the number of interleaved components per cell (default 1).
+    ///
+    /// Only the [`mask`](Self::mask) uses it: a masked field must hold
+    /// `cell_count * components` values, and every component of a
+    /// masked-in cell receives noise (e.g. 2 for a `Vector { dims: 2 }`
+    /// velocity field).
+    pub fn components(mut self, components: usize) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Confine noise to the cells of `region` (default: all cells).
+    ///
+    /// The region is compiled against the world's space in
+    /// [`init`](Propagator::init); a propagator stepped without `init`
+    /// compiles it every tick. Cells outside the region keep their previous-tick value. The RNG
+    /// stream is drawn for every cell regardless of the mask, so masked
+    /// cells receive the same noise they would without a mask.
+    pub fn mask(mut self, region: RegionSpec) -> Self {
+        self.mask = Some(region);
+        self
+    }
+
     /// Build the propagator, validating all configuration.
     ///
     /// # Errors
@@ -111,6 +167,7 @@ impl NoiseInjectionBuilder {
     /// - `field` is not set
     /// - `scale` is negative or NaN
     /// - `noise_type` is SaltPepper and `scale` > 1.0
+    /// - `components` is zero
     pub fn build(self) -> Result<NoiseInjection, String> {
         let field = self.field.ok_or_else(|| "field is required".to_string())?;
 
@@ -125,11 +182,18 @@ impl NoiseInjectionBuilder {
             ));
         }
 
+        if self.components == 0 {
+            return Err("components must be >= 1".to_string());
+        }
+
         Ok(NoiseInjection {
             field,
             noise_type: self.noise_type,
             scale: self.scale,
             seed_offset: self.seed_offset,
+            components: self.components,
+            mask: self.mask,
+            compiled_mask: None,
         })
     }
 }
@@ -151,10 +215,17 @@ impl Propagator for NoiseInjection {
         vec![(self.field, WriteMode::Full)]
     }
 
-    fn max_dt(&self, _space: &dyn murk_space::Space) -> Option<f64> {
+    fn max_dt(&self, _space: &dyn Space) -> Option<f64> {
         None
     }
 
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        if let Some(spec) = &self.mask {
+            self.compiled_mask = Some(Self::compile_mask(space, spec)?);
+        }
+        Ok(())
+    }
+
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
         let tick = ctx.tick_id().0;
 
@@ -166,6 +237,38 @@ impl Propagator for NoiseInjection {
             })?
             .to_vec();
 
+        let built;
+        let mask = match (&self.mask, &self.compiled_mask) {
+            (_, Some(compiled)) => Some(compiled),
+            (Some(spec), None) => {
+                built = Self::compile_mask(ctx.space(), spec)?;
+                Some(&built)
+            }
+            (None, None) => None,
+        };
+        if let Some(mask) = mask {
+            let cell_count = ctx.space().cell_count();
+            if mask.len() != cell_count {
+                return Err(PropagatorError::ExecutionFailed {
+                    reason: format!(
+                        "noise mask covers {} cells but space has {cell_count}",
+                        mask.len()
+                    ),
+                });
+            }
+            if prev.len() != cell_count * self.components {
+                return Err(PropagatorError::ExecutionFailed {
+                    reason: format!(
+                        "field length {} != cell_count {cell_count} * components {}",
+                        prev.len(),
+                        self.components
+                    ),
+                });
+            }
+        }
+        let comps = self.components;
+        let in_mask = |i: usize| mask.is_none_or(|m| m[i / comps]);
+
         let out =
             ctx.writes()
                 .write(self.field)
@@ -191,22 +294,30 @@ impl Propagator for NoiseInjection {
 
         match self.noise_type {
             NoiseType::Gaussian => {
-                for v in out.iter_mut() {
-                    *v += (self.scale * Self::box_muller(&mut rng)) as f32;
+                for (i, v) in out.iter_mut().enumerate() {
+                    let n = (self.scale * Self::box_muller(&mut rng)) as Scalar;
+                    if in_mask(i) {
+                        *v += n;
+                    }
                 }
             }
             NoiseType::Uniform => {
-                for v in out.iter_mut() {
+                for (i, v) in out.iter_mut().enumerate() {
                     let u: f64 = rng.gen::<f64>() * 2.0 - 1.0;
-                    *v += (self.scale * u) as f32;
+                    if in_mask(i) {
+                        *v += (self.scale * u) as Scalar;
+                    }
                 }
             }
             NoiseType::SaltPepper => {
-                for v in out.iter_mut() {
+                for (i, v) in out.iter_mut().enumerate() {
                     let p: f64 = rng.gen();
                     if p < self.scale {
                         // Coin flip for salt (1.0) or pepper (0.0)
-                        *v = if rng.gen::<bool>() { 1.0 } else { 0.0 };
+                        let salt = rng.gen::<bool>();
+                        if in_mask(i) {
+                            *v = if salt { 1.0 } else { 0.0 };
+                        }
                     }
                 }
             }
@@ -219,9 +330,9 @@ impl Propagator for NoiseInjection {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use murk_core::TickId;
+    use murk_core::{scalar_to_f64, TickId};
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, Space, Square4};
+    use murk_space::{EdgeBehavior, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
 
     const F_DATA: FieldId = FieldId(100);
@@ -291,6 +402,15 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn builder_rejects_zero_components() {
+        let result = NoiseInjection::builder()
+            .field(F_DATA)
+            .components(0)
+            .build();
+        assert!(result.is_err());
+    }
+
     // ---------------------------------------------------------------
     // Step logic tests
     // ---------------------------------------------------------------
@@ -307,7 +427,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let run = |tick: u64| -> Vec<f32> {
+        let run = |tick: u64| -> Vec<Scalar> {
             let mut reader = MockFieldReader::new();
             reader.set_field(F_DATA, vec![10.0; n]);
             let mut writer = MockFieldWriter::new();
@@ -335,7 +455,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let run = |tick: u64| -> Vec<f32> {
+        let run = |tick: u64| -> Vec<Scalar> {
             let mut reader = MockFieldReader::new();
             reader.set_field(F_DATA, vec![10.0; n]);
             let mut writer = MockFieldWriter::new();
@@ -403,7 +523,7 @@ mod tests {
         let out = writer.get_field(F_DATA).unwrap();
         for &v in out {
             assert!(
-                v >= 10.0 - scale as f32 && v <= 10.0 + scale as f32,
+                v >= 10.0 - scale as Scalar && v <= 10.0 + scale as Scalar,
                 "uniform noise should be bounded: 10 +/- {scale}, got {v}"
             );
         }
@@ -437,4 +557,250 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn gaussian_noise_mean_near_zero() {
+        let grid = Square4::new(100, 100, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let prop = NoiseInjection::builder()
+            .field(F_DATA)
+            .noise_type(NoiseType::Gaussian)
+            .scale(1.0)
+            .seed_offset(7)
+            .build()
+            .unwrap();
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_DATA, vec![0.0; n]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_DATA, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 3);
+        prop.step(&mut ctx).unwrap();
+
+        let out = writer.get_field(F_DATA).unwrap();
+        let mean = out.iter().map(|&v| scalar_to_f64(v)).sum::<f64>() / n as f64;
+        // Standard error of the mean for N(0,1) over 10k samples is 0.01.
+        assert!(
+            mean.abs() < 0.05,
+            "Gaussian noise mean should be ~0, got {mean}"
+        );
+    }
+
+    #[test]
+    fn uniform_noise_mean_near_zero() {
+        let grid = Square4::new(100, 100, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let prop = NoiseInjection::builder()
+            .field(F_DATA)
+            .noise_type(NoiseType::Uniform)
+            .scale(1.0)
+            .seed_offset(7)
+            .build()
+            .unwrap();
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_DATA, vec![0.0; n]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_DATA, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 3);
+        prop.step(&mut ctx).unwrap();
+
+        let out = writer.get_field(F_DATA).unwrap();
+        let mean = out.iter().map(|&v| scalar_to_f64(v)).sum::<f64>() / n as f64;
+        assert!(
+            mean.abs() < 0.05,
+            "Uniform noise mean should be ~0, got {mean}"
+        );
+    }
+
+    // ---------------------------------------------------------------
+    // Mask tests
+    // ---------------------------------------------------------------
+
+    #[test]
+    fn masked_out_cells_untouched() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let prop = NoiseInjection::builder()
+            .field(F_DATA)
+            .noise_type(NoiseType::Gaussian)
+            .scale(1.0)
+            .seed_offset(42)
+            .mask(RegionSpec::Rect {
+                min: smallvec::smallvec![1, 1],
+                max: smallvec::smallvec![2, 3],
+            })
+            .build()
+            .unwrap();
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_DATA, vec![5.0; n]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_DATA, n);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 1);
+        prop.step(&mut ctx).unwrap();
+
+        let out = writer.get_field(F_DATA).unwrap();
+        for r in 0..6 {
+            for c in 0..6 {
+                let v = out[r * 6 + c];
+                let inside = (1..=2).contains(&r) && (1..=3).contains(&c);
+                if inside {
+                    assert_ne!(v, 5.0, "masked-in cell ({r},{c}) should receive noise");
+                } else {
+                    assert_eq!(v, 5.0, "masked-out cell ({r},{c}) must be untouched");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mask_covers_every_component_of_masked_cells() {
+        let grid = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let prop = NoiseInjection::builder()
+            .field(F_DATA)
+            .scale(1.0)
+            .components(2)
+            .mask(RegionSpec::Rect {
+                min: smallvec::smallvec![0, 0],
+                max: smallvec::smallvec![0, 3],
+            })
+            .build()
+            .unwrap();
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_DATA, vec![5.0; n * 2]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_DATA, n * 2);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 1);
+        prop.step(&mut ctx).unwrap();
+
+        // Row 0 (cells 0..4) is masked in; both components get noise.
+        let out = writer.get_field(F_DATA).unwrap();
+        for (i, &v) in out.iter().enumerate() {
+            if i / 2 < 4 {
+                assert_ne!(v, 5.0, "masked-in element {i} should receive noise");
+            } else {
+                assert_eq!(v, 5.0, "masked-out element {i} must be untouched");
+            }
+        }
+    }
+
+    #[test]
+    fn mask_rejects_field_length_not_matching_components() {
+        let grid = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let prop = NoiseInjection::builder()
+            .field(F_DATA)
+            .components(3)
+            .mask(RegionSpec::All)
+            .build()
+            .unwrap();
+
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_DATA, vec![0.0; n * 2]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_DATA, n * 2);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 1);
+        let err = prop.step(&mut ctx).unwrap_err();
+        assert!(err.to_string().contains("components 3"), "{err}");
+    }
+
+    #[test]
+    fn mask_preserves_noise_stream_for_masked_cells() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let base = || {
+            NoiseInjection::builder()
+                .field(F_DATA)
+                .noise_type(NoiseType::Uniform)
+                .scale(1.0)
+                .seed_offset(9)
+        };
+        let unmasked = base().build().unwrap();
+        let masked = base()
+            .mask(RegionSpec::Disk {
+                center: smallvec::smallvec![3, 3],
+                radius: 1,
+            })
+            .build()
+            .unwrap();
+
+        let run = |prop: &NoiseInjection| -> Vec<Scalar> {
+            let mut reader = MockFieldReader::new();
+            reader.set_field(F_DATA, vec![0.0; n]);
+            let mut writer = MockFieldWriter::new();
+            writer.add_field(F_DATA, n);
+            let mut scratch = ScratchRegion::new(0);
+            let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 4);
+            prop.step(&mut ctx).unwrap();
+            writer.get_field(F_DATA).unwrap().to_vec()
+        };
+
+        let full = run(&unmasked);
+        let part = run(&masked);
+        for i in [3 * 6 + 3, 2 * 6 + 3, 4 * 6 + 3, 3 * 6 + 2, 3 * 6 + 4] {
+            assert_eq!(
+                part[i], full[i],
+                "masked cell {i} should match unmasked noise"
+            );
+        }
+        assert_eq!(part[0], 0.0);
+    }
+
+    #[test]
+    fn init_compiles_mask_once_and_matches_lazy_compile() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let build = || {
+            NoiseInjection::builder()
+                .field(F_DATA)
+                .noise_type(NoiseType::Gaussian)
+                .scale(1.0)
+                .seed_offset(3)
+                .mask(RegionSpec::Rect {
+                    min: smallvec::smallvec![0, 2],
+                    max: smallvec::smallvec![5, 3],
+                })
+                .build()
+                .unwrap()
+        };
+        let lazy = build();
+        let mut eager = build();
+        eager.init(&grid).unwrap();
+        assert_eq!(eager.compiled_mask.as_ref().map(Vec::len), Some(n));
+
+        let run = |prop: &NoiseInjection| -> Vec<Scalar> {
+            let mut reader = MockFieldReader::new();
+            reader.set_field(F_DATA, vec![1.0; n]);
+            let mut writer = MockFieldWriter::new();
+            writer.add_field(F_DATA, n);
+            let mut scratch = ScratchRegion::new(0);
+            let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 2);
+            prop.step(&mut ctx).unwrap();
+            writer.get_field(F_DATA).unwrap().to_vec()
+        };
+        assert_eq!(run(&lazy), run(&eager));
+    }
+
+    #[test]
+    fn init_rejects_mask_that_does_not_compile() {
+        let grid = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let mut prop = NoiseInjection::builder()
+            .field(F_DATA)
+            .mask(RegionSpec::Rect {
+                min: smallvec::smallvec![0, 0],
+                max: smallvec::smallvec![9, 9],
+            })
+            .build()
+            .unwrap();
+        let err = prop.init(&grid).unwrap_err();
+        assert!(err.to_string().contains("mask region"), "{err}");
+    }
 }
@@ -0,0 +1,434 @@
+//! Time-varying source propagator for forced-oscillation experiments.
+//!
+//! Each tick, sets a fixed list of cells to
+//! `amplitude * w(2π·frequency·sim_time + phase)`, where `w` is the
+//! configured [`Waveform`] and `sim_time` is the cumulative simulated time
+//! at the end of the tick ([`StepContext::sim_time`]). All other cells keep
+//! their previous value ([`WriteMode::Incremental`]).
+//!
+//! Cells default to a shared `phase`; [`PeriodicSourceBuilder::phases`]
+//! gives each cell its own, e.g. to drive a phased array.
+//!
+//! # Construction
+//!
+//! ```
+//! use murk_core::FieldId;
+//! use murk_propagators::{PeriodicSource, Waveform};
+//!
+//! let prop = PeriodicSource::builder()
+//!     .field(FieldId(0))
+//!     .cells(vec![smallvec::smallvec![2, 2]])
+//!     .amplitude(1.5)
+//!     .frequency(0.25)
+//!     .waveform(Waveform::Square)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use murk_core::{Coord, FieldId, FieldSet, PropagatorError, Scalar};
+use murk_propagator::context::StepContext;
+use murk_propagator::propagator::{Propagator, WriteMode};
+use std::f64::consts::TAU;
+
+/// Periodic shape emitted by [`PeriodicSource`], each with unit amplitude
+/// and period `2π` in its phase argument.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Waveform {
+    /// `sin(θ)`.
+    #[default]
+    Sine,
+    /// `+1` where `sin(θ) >= 0`, `-1` otherwise.
+    Square,
+    /// Rises linearly from `-1` at `θ = 0` to `+1` just before `θ = 2π`.
+    Saw,
+}
+
+impl Waveform {
+    /// Evaluate the waveform at phase angle `theta` (radians).
+    pub fn eval(self, theta: f64) -> f64 {
+        match self {
+            Waveform::Sine => theta.sin(),
+            Waveform::Square => {
+                if theta.sin() >= 0.0 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Saw => 2.0 * (theta / TAU).rem_euclid(1.0) - 1.0,
+        }
+    }
+}
+
+/// A propagator that drives a set of cells with a periodic waveform.
+#[derive(Debug)]
+pub struct PeriodicSource {
+    field: FieldId,
+    cells: Vec<Coord>,
+    phases: Vec<f64>,
+    amplitude: f64,
+    frequency: f64,
+    waveform: Waveform,
+}
+
+/// Builder for [`PeriodicSource`].
+///
+/// Required fields: `field` and `cells`.
+pub struct PeriodicSourceBuilder {
+    field: Option<FieldId>,
+    cells: Vec<Coord>,
+    phase: f64,
+    phases: Option<Vec<f64>>,
+    amplitude: f64,
+    frequency: f64,
+    waveform: Waveform,
+}
+
+impl PeriodicSource {
+    /// Create a new builder for configuring a `PeriodicSource` propagator.
+    pub fn builder() -> PeriodicSourceBuilder {
+        PeriodicSourceBuilder {
+            field: None,
+            cells: Vec::new(),
+            phase: 0.0,
+            phases: None,
+            amplitude: 1.0,
+            frequency: 1.0,
+            waveform: Waveform::Sine,
+        }
+    }
+
+    /// Value emitted at cell index `i` at simulated time `t`.
+    fn value_at(&self, i: usize, t: f64) -> Scalar {
+        let theta = TAU * self.frequency * t + self.phases[i];
+        (self.amplitude * self.waveform.eval(theta)) as Scalar
+    }
+}
+
+impl PeriodicSourceBuilder {
+    /// Set the field the source writes to.
+    pub fn field(mut self, field: FieldId) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// Set the driven cells. Must be non-empty.
+    pub fn cells(mut self, cells: Vec<Coord>) -> Self {
+        self.cells = cells;
+        self
+    }
+
+    /// Set the peak value (default: 1.0).
+    pub fn amplitude(mut self, amplitude: f64) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    /// Set the frequency in cycles per unit of simulated time
+    /// (default: 1.0). Must be >= 0.
+    pub fn frequency(mut self, frequency: f64) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Set the phase offset in radians shared by every cell (default: 0.0).
+    pub fn phase(mut self, phase: f64) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Give each cell its own phase offset in radians, replacing `phase`.
+    ///
+    /// Must have one entry per cell, in the order passed to `cells`.
+    pub fn phases(mut self, phases: Vec<f64>) -> Self {
+        self.phases = Some(phases);
+        self
+    }
+
+    /// Set the waveform (default: [`Waveform::Sine`]).
+    pub fn waveform(mut self, waveform: Waveform) -> Self {
+        self.waveform = waveform;
+        self
+    }
+
+    /// Build the propagator, validating all configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if:
+    /// - `field` is not set
+    /// - `cells` is empty
+    /// - `amplitude` or `phase` is not finite
+    /// - `frequency` is negative or not finite
+    /// - `phases` is set with a length other than `cells.len()`, or holds
+    ///   a non-finite value
+    pub fn build(self) -> Result<PeriodicSource, String> {
+        let field = self.field.ok_or_else(|| "field is required".to_string())?;
+
+        if self.cells.is_empty() {
+            return Err("cells must not be empty".to_string());
+        }
+        if !self.amplitude.is_finite() {
+            return Err(format!("amplitude must be finite, got {}", self.amplitude));
+        }
+        if !self.frequency.is_finite() || self.frequency < 0.0 {
+            return Err(format!(
+                "frequency must be finite and >= 0, got {}",
+                self.frequency
+            ));
+        }
+        if !self.phase.is_finite() {
+            return Err(format!("phase must be finite, got {}", self.phase));
+        }
+
+        let phases = match self.phases {
+            Some(phases) => {
+                if phases.len() != self.cells.len() {
+                    return Err(format!(
+                        "phases has {} entries but there are {} cells",
+                        phases.len(),
+                        self.cells.len()
+                    ));
+                }
+                if let Some(bad) = phases.iter().find(|p| !p.is_finite()) {
+                    return Err(format!("phases must be finite, got {bad}"));
+                }
+                phases
+            }
+            None => vec![self.phase; self.cells.len()],
+        };
+
+        Ok(PeriodicSource {
+            field,
+            cells: self.cells,
+            phases,
+            amplitude: self.amplitude,
+            frequency: self.frequency,
+            waveform: self.waveform,
+        })
+    }
+}
+
+impl Propagator for PeriodicSource {
+    fn name(&self) -> &str {
+        "PeriodicSource"
+    }
+
+    fn reads(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        vec![(self.field, WriteMode::Incremental)]
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let t = ctx.sim_time();
+
+        let ranks = self
+            .cells
+            .iter()
+            .map(|coord| {
+                ctx.space()
+                    .canonical_rank(coord)
+                    .ok_or_else(|| PropagatorError::ExecutionFailed {
+                        reason: format!("source cell {coord:?} is outside the space"),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let out =
+            ctx.writes()
+                .write(self.field)
+                .ok_or_else(|| PropagatorError::ExecutionFailed {
+                    reason: format!("field {:?} not writable", self.field),
+                })?;
+
+        for (i, &rank) in ranks.iter().enumerate() {
+            out[rank] = self.value_at(i, t);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use murk_core::{scalar_to_f64, TickId};
+    use murk_propagator::scratch::ScratchRegion;
+    use murk_space::{EdgeBehavior, Line1D, Space};
+    use murk_test_utils::{MockFieldReader, MockFieldWriter};
+    use smallvec::smallvec;
+
+    const F_SRC: FieldId = FieldId(100);
+    const DT: f64 = 0.1;
+
+    /// Run `ticks` steps at constant `DT` on a fresh 8-cell line, feeding
+    /// each tick's output into the next, and return every tick's field.
+    fn run(prop: &PeriodicSource, ticks: u64) -> Vec<Vec<Scalar>> {
+        let space = Line1D::new(8, EdgeBehavior::Absorb).unwrap();
+        let n = space.cell_count();
+        let reader = MockFieldReader::new();
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_SRC, n);
+        let mut history = Vec::new();
+        for tick in 1..=ticks {
+            let mut scratch = ScratchRegion::new(0);
+            let mut ctx = StepContext::new(
+                &reader,
+                &reader,
+                &mut writer,
+                &mut scratch,
+                &space,
+                TickId(tick),
+                DT,
+            )
+            .with_sim_time(tick as f64 * DT);
+            prop.step(&mut ctx).unwrap();
+            history.push(writer.get_field(F_SRC).unwrap().to_vec());
+        }
+        history
+    }
+
+    fn source(waveform: Waveform) -> PeriodicSourceBuilder {
+        PeriodicSource::builder()
+            .field(F_SRC)
+            .cells(vec![smallvec![3]])
+            .amplitude(2.0)
+            .frequency(0.5)
+            .phase(0.3)
+            .waveform(waveform)
+    }
+
+    #[test]
+    fn builder_minimal() {
+        let prop = PeriodicSource::builder()
+            .field(F_SRC)
+            .cells(vec![smallvec![0]])
+            .build()
+            .unwrap();
+        assert_eq!(prop.name(), "PeriodicSource");
+        assert!(prop.reads().is_empty());
+        assert_eq!(prop.writes(), vec![(F_SRC, WriteMode::Incremental)]);
+    }
+
+    #[test]
+    fn builder_rejects_invalid_config() {
+        let ok = || {
+            PeriodicSource::builder()
+                .field(F_SRC)
+                .cells(vec![smallvec![0], smallvec![1]])
+        };
+        assert!(PeriodicSource::builder()
+            .cells(vec![smallvec![0]])
+            .build()
+            .unwrap_err()
+            .contains("field"));
+        assert!(PeriodicSource::builder()
+            .field(F_SRC)
+            .build()
+            .unwrap_err()
+            .contains("cells"));
+        assert!(ok()
+            .frequency(-1.0)
+            .build()
+            .unwrap_err()
+            .contains("frequency"));
+        assert!(ok()
+            .amplitude(f64::NAN)
+            .build()
+            .unwrap_err()
+            .contains("amplitude"));
+        assert!(ok()
+            .phases(vec![0.0])
+            .build()
+            .unwrap_err()
+            .contains("phases"));
+    }
+
+    #[test]
+    fn waveforms_trace_expected_values_over_ticks() {
+        for waveform in [Waveform::Sine, Waveform::Square, Waveform::Saw] {
+            let history = run(&source(waveform).build().unwrap(), 20);
+            for (k, field) in history.iter().enumerate() {
+                let t = (k + 1) as f64 * DT;
+                let theta = TAU * 0.5 * t + 0.3;
+                let expected = match waveform {
+                    Waveform::Sine => 2.0 * theta.sin(),
+                    Waveform::Square => 2.0 * theta.sin().signum(),
+                    Waveform::Saw => 2.0 * (2.0 * (theta / TAU).fract() - 1.0),
+                };
+                assert!(
+                    (scalar_to_f64(field[3]) - expected).abs() < 1e-5,
+                    "{waveform:?} tick {}: got {}, expected {expected}",
+                    k + 1,
+                    field[3]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sine_source_repeats_every_period() {
+        // frequency 0.5 at dt 0.1: one period every 20 ticks.
+        let history = run(&source(Waveform::Sine).build().unwrap(), 40);
+        for k in 0..20 {
+            assert!((history[k][3] - history[k + 20][3]).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn undriven_cells_are_untouched() {
+        let history = run(&source(Waveform::Sine).build().unwrap(), 5);
+        for field in &history {
+            for (i, &v) in field.iter().enumerate() {
+                if i != 3 {
+                    assert_eq!(v, 0.0, "cell {i} should not be driven");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn cells_follow_independent_phases() {
+        let prop = PeriodicSource::builder()
+            .field(F_SRC)
+            .cells(vec![smallvec![1], smallvec![5]])
+            .frequency(0.5)
+            .phases(vec![0.0, std::f64::consts::PI])
+            .build()
+            .unwrap();
+        let history = run(&prop, 10);
+        for (k, field) in history.iter().enumerate() {
+            let theta = TAU * 0.5 * (k + 1) as f64 * DT;
+            assert!((scalar_to_f64(field[1]) - theta.sin()).abs() < 1e-5);
+            // Half a cycle apart: always the negation of cell 1.
+            assert!((field[5] + field[1]).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn cell_outside_space_fails_step() {
+        let prop = PeriodicSource::builder()
+            .field(F_SRC)
+            .cells(vec![smallvec![99]])
+            .build()
+            .unwrap();
+        let space = Line1D::new(8, EdgeBehavior::Absorb).unwrap();
+        let reader = MockFieldReader::new();
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_SRC, space.cell_count());
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = StepContext::new(
+            &reader,
+            &reader,
+            &mut writer,
+            &mut scratch,
+            &space,
+            TickId(1),
+            DT,
+        );
+        assert!(prop.step(&mut ctx).is_err());
+    }
+}
@@ -4,13 +4,17 @@
 //! regenerates over time. Supports linear and logistic regrowth models.
 //!
 //! Common in foraging/harvesting environments: agents deplete local
-//! resources, which then regrow toward a carrying capacity.
+//! resources, which then regrow toward a carrying capacity. With a non-zero
+//! spread rate, part of each cell's regrowth seeds its neighbours, so
+//! depleted patches recolonise from their edges.
 //!
 //! Constructed via the builder pattern: [`ResourceField::builder`].
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use crate::grid_helpers::neighbour_ranks;
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
+use murk_space::Space;
 
 /// Regrowth model for [`ResourceField`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -27,16 +31,21 @@ pub enum RegrowthModel {
 /// Each tick:
 /// 1. Copies previous-tick resource values.
 /// 2. Subtracts `consumption_rate * dt` at cells where agents are present.
-/// 3. Applies regrowth (linear or logistic).
+/// 3. Applies regrowth (linear or logistic). A `spread_rate` fraction of
+///    each cell's positive regrowth is shared equally among its neighbours
+///    instead of staying in place.
 /// 4. Clamps to `[0, capacity]`.
 #[derive(Debug)]
 pub struct ResourceField {
     field: FieldId,
     presence_field: FieldId,
-    consumption_rate: f32,
-    regrowth_rate: f32,
-    capacity: f32,
+    consumption_rate: Scalar,
+    regrowth_rate: Scalar,
+    capacity: Scalar,
     regrowth_model: RegrowthModel,
+    spread_rate: Scalar,
+    /// Neighbour table for spreading, built in `init`.
+    neighbours: Option<Vec<Vec<usize>>>,
 }
 
 /// Builder for [`ResourceField`].
@@ -45,10 +54,11 @@ pub struct ResourceField {
 pub struct ResourceFieldBuilder {
     field: Option<FieldId>,
     presence_field: Option<FieldId>,
-    consumption_rate: f32,
-    regrowth_rate: f32,
-    capacity: f32,
+    consumption_rate: Scalar,
+    regrowth_rate: Scalar,
+    capacity: Scalar,
     regrowth_model: RegrowthModel,
+    spread_rate: Scalar,
 }
 
 impl ResourceField {
@@ -61,6 +71,23 @@ impl ResourceField {
             regrowth_rate: 0.1,
             capacity: 1.0,
             regrowth_model: RegrowthModel::Linear,
+            spread_rate: 0.0,
+        }
+    }
+
+    /// Regrowth increment for a cell holding `v` after consumption.
+    fn regrowth(&self, v: Scalar, dt: Scalar) -> Scalar {
+        match self.regrowth_model {
+            RegrowthModel::Linear => self.regrowth_rate * dt,
+            RegrowthModel::Logistic => {
+                // Logistic growth: rate * v * (1 - v/cap)
+                // Only applies when v > 0 (dead resources don't regrow logistically)
+                if v > 0.0 {
+                    self.regrowth_rate * v * (1.0 - v / self.capacity) * dt
+                } else {
+                    0.0
+                }
+            }
         }
     }
 }
@@ -79,19 +106,19 @@ impl ResourceFieldBuilder {
     }
 
     /// Set the consumption rate per tick per agent (default: 1.0). Must be >= 0.
-    pub fn consumption_rate(mut self, rate: f32) -> Self {
+    pub fn consumption_rate(mut self, rate: Scalar) -> Self {
         self.consumption_rate = rate;
         self
     }
 
     /// Set the regrowth rate (default: 0.1). Must be >= 0.
-    pub fn regrowth_rate(mut self, rate: f32) -> Self {
+    pub fn regrowth_rate(mut self, rate: Scalar) -> Self {
         self.regrowth_rate = rate;
         self
     }
 
     /// Set the carrying capacity (default: 1.0). Must be > 0.
-    pub fn capacity(mut self, cap: f32) -> Self {
+    pub fn capacity(mut self, cap: Scalar) -> Self {
         self.capacity = cap;
         self
     }
@@ -102,6 +129,17 @@ impl ResourceFieldBuilder {
         self
     }
 
+    /// Set the fraction of regrowth that spreads to neighbouring cells
+    /// (default: 0.0). Must be in `[0, 1]`.
+    ///
+    /// Each cell keeps `1 - spread_rate` of its positive regrowth and shares
+    /// the rest equally among its neighbours, so an isolated patch seeds
+    /// adjacent empty cells. Cells without neighbours keep all of it.
+    pub fn spread_rate(mut self, rate: Scalar) -> Self {
+        self.spread_rate = rate;
+        self
+    }
+
     /// Build the propagator, validating all configuration.
     ///
     /// # Errors
@@ -112,6 +150,7 @@ impl ResourceFieldBuilder {
     /// - `consumption_rate` is negative or NaN
     /// - `regrowth_rate` is negative or NaN
     /// - `capacity` is not > 0 or is NaN
+    /// - `spread_rate` is outside `[0, 1]` or is NaN
     pub fn build(self) -> Result<ResourceField, String> {
         let field = self.field.ok_or_else(|| "field is required".to_string())?;
         let presence_field = self
@@ -136,6 +175,12 @@ impl ResourceFieldBuilder {
                 self.capacity
             ));
         }
+        if !(0.0..=1.0).contains(&self.spread_rate) {
+            return Err(format!(
+                "spread_rate must be in [0, 1], got {}",
+                self.spread_rate
+            ));
+        }
 
         Ok(ResourceField {
             field,
@@ -144,6 +189,8 @@ impl ResourceFieldBuilder {
             regrowth_rate: self.regrowth_rate,
             capacity: self.capacity,
             regrowth_model: self.regrowth_model,
+            spread_rate: self.spread_rate,
+            neighbours: None,
         })
     }
 }
@@ -169,8 +216,27 @@ impl Propagator for ResourceField {
         None
     }
 
+    fn init(&mut self, space: &dyn Space) -> Result<(), PropagatorError> {
+        if self.spread_rate > 0.0 {
+            self.neighbours = Some(neighbour_ranks(space));
+        }
+        Ok(())
+    }
+
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        let dt = ctx.dt() as f32;
+        let dt = ctx.dt() as Scalar;
+
+        // Neighbour topology is only needed when regrowth spreads; without
+        // `init` it is built per step.
+        let built;
+        let neighbours = match (&self.neighbours, self.spread_rate > 0.0) {
+            (_, false) => None,
+            (Some(cached), true) => Some(cached),
+            (None, true) => {
+                built = neighbour_ranks(ctx.space());
+                Some(&built)
+            }
+        };
 
         let prev = ctx
             .reads_previous()
@@ -215,6 +281,47 @@ impl Propagator for ResourceField {
             });
         }
 
+        if let Some(neighbours) = neighbours {
+            if neighbours.len() != out.len() {
+                return Err(PropagatorError::ExecutionFailed {
+                    reason: format!(
+                        "space cell count ({}) != resource field length ({})",
+                        neighbours.len(),
+                        out.len()
+                    ),
+                });
+            }
+
+            // Pass 1: consumption plus the retained share of regrowth.
+            let mut shared = vec![0.0; out.len()];
+            for i in 0..out.len() {
+                let mut v = prev[i];
+                if presence[i] != 0.0 {
+                    v -= self.consumption_rate * dt;
+                }
+                let growth = self.regrowth(v, dt);
+                if growth > 0.0 && !neighbours[i].is_empty() {
+                    shared[i] = growth * self.spread_rate;
+                }
+                out[i] = v + growth - shared[i];
+            }
+
+            // Pass 2: distribute shared regrowth equally among neighbours.
+            for (share, nbs) in shared.iter().zip(neighbours) {
+                if *share > 0.0 {
+                    let per = share / nbs.len() as Scalar;
+                    for &j in nbs {
+                        out[j] += per;
+                    }
+                }
+            }
+
+            for v in out.iter_mut() {
+                *v = v.clamp(0.0, self.capacity);
+            }
+            return Ok(());
+        }
+
         for i in 0..out.len() {
             let mut v = prev[i];
 
@@ -224,18 +331,7 @@ impl Propagator for ResourceField {
             }
 
             // Regrowth
-            match self.regrowth_model {
-                RegrowthModel::Linear => {
-                    v += self.regrowth_rate * dt;
-                }
-                RegrowthModel::Logistic => {
-                    // Logistic growth: rate * v * (1 - v/cap)
-                    // Only applies when v > 0 (dead resources don't regrow logistically)
-                    if v > 0.0 {
-                        v += self.regrowth_rate * v * (1.0 - v / self.capacity) * dt;
-                    }
-                }
-            }
+            v += self.regrowth(v, dt);
 
             // Clamp to [0, capacity]
             out[i] = v.clamp(0.0, self.capacity);
@@ -322,7 +418,7 @@ mod tests {
         let result = ResourceField::builder()
             .field(F_RES)
             .presence_field(F_PRES)
-            .consumption_rate(f32::NAN)
+            .consumption_rate(Scalar::NAN)
             .build();
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("consumption_rate"));
@@ -344,12 +440,24 @@ mod tests {
         let result = ResourceField::builder()
             .field(F_RES)
             .presence_field(F_PRES)
-            .capacity(f32::NAN)
+            .capacity(Scalar::NAN)
             .build();
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("capacity"));
     }
 
+    #[test]
+    fn builder_rejects_bad_spread_rate() {
+        for rate in [-0.1, 1.5, Scalar::NAN] {
+            let result = ResourceField::builder()
+                .field(F_RES)
+                .presence_field(F_PRES)
+                .spread_rate(rate)
+                .build();
+            assert!(result.unwrap_err().contains("spread_rate"));
+        }
+    }
+
     // ---------------------------------------------------------------
     // Step logic tests
     // ---------------------------------------------------------------
@@ -370,7 +478,7 @@ mod tests {
 
         let mut reader = MockFieldReader::new();
         reader.set_field(F_RES, vec![50.0; n]);
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         presence[4] = 1.0; // agent at center
         reader.set_field(F_PRES, presence);
 
@@ -525,7 +633,7 @@ mod tests {
 
         let mut reader = MockFieldReader::new();
         reader.set_field(F_RES, vec![5.0; n]);
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         presence[0] = 1.0;
         reader.set_field(F_PRES, presence);
 
@@ -539,4 +647,172 @@ mod tests {
         let res = writer.get_field(F_RES).unwrap();
         assert!(res[0].abs() < 1e-6, "should clamp to 0, got {}", res[0]);
     }
+
+    // ---------------------------------------------------------------
+    // Spread tests
+    // ---------------------------------------------------------------
+
+    /// Run `ticks` steps of `prop` on `space`, feeding each output back in.
+    fn run_ticks(
+        prop: &ResourceField,
+        space: &dyn Space,
+        init: Vec<Scalar>,
+        presence: &[Scalar],
+        ticks: usize,
+    ) -> Vec<Scalar> {
+        let n = init.len();
+        let mut values = init;
+        for _ in 0..ticks {
+            let mut reader = MockFieldReader::new();
+            reader.set_field(F_RES, values);
+            reader.set_field(F_PRES, presence.to_vec());
+            let mut writer = MockFieldWriter::new();
+            writer.add_field(F_RES, n);
+            let mut scratch = ScratchRegion::new(0);
+            let mut ctx = StepContext::new(
+                &reader,
+                &reader,
+                &mut writer,
+                &mut scratch,
+                space,
+                TickId(1),
+                1.0,
+            );
+            prop.step(&mut ctx).unwrap();
+            values = writer.get_field(F_RES).unwrap().to_vec();
+        }
+        values
+    }
+
+    #[test]
+    fn isolated_cell_seeds_neighbours() {
+        let grid = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        let prop = ResourceField::builder()
+            .field(F_RES)
+            .presence_field(F_PRES)
+            .consumption_rate(0.0)
+            .regrowth_rate(0.5)
+            .capacity(10.0)
+            .regrowth_model(RegrowthModel::Logistic)
+            .spread_rate(0.5)
+            .build()
+            .unwrap();
+
+        let mut init = vec![0.0; n];
+        init[12] = 2.0; // centre of the 5x5 grid
+        let presence = vec![0.0; n];
+
+        let one = run_ticks(&prop, &grid, init.clone(), &presence, 1);
+        for nb in [7, 11, 13, 17] {
+            assert!(one[nb] > 0.0, "adjacent cell {nb} should be seeded");
+        }
+        assert_eq!(one[0], 0.0, "corner is two hops away after one tick");
+
+        let many = run_ticks(&prop, &grid, init, &presence, 20);
+        assert!(many[0] > 0.0, "growth should reach the corner");
+        assert!(many[7] > many[2], "growth decays with distance");
+        for &v in &many {
+            assert!((0.0..=10.0).contains(&v), "out of bounds: {v}");
+        }
+    }
+
+    #[test]
+    fn spread_stays_bounded_on_generic_space() {
+        let hex = murk_space::Hex2D::new(4, 4).unwrap();
+        let n = hex.cell_count();
+        let prop = ResourceField::builder()
+            .field(F_RES)
+            .presence_field(F_PRES)
+            .consumption_rate(3.0)
+            .regrowth_rate(5.0)
+            .capacity(4.0)
+            .regrowth_model(RegrowthModel::Linear)
+            .spread_rate(1.0)
+            .build()
+            .unwrap();
+
+        let init: Vec<Scalar> = (0..n).map(|i| (i % 5) as Scalar).collect();
+        let presence: Vec<Scalar> = (0..n).map(|i| (i % 3 == 0) as u8 as Scalar).collect();
+        let out = run_ticks(&prop, &hex, init, &presence, 10);
+        for &v in &out {
+            assert!((0.0..=4.0).contains(&v), "out of bounds: {v}");
+        }
+    }
+
+    #[test]
+    fn init_neighbour_table_matches_per_step_build() {
+        let hex = murk_space::Hex2D::new(4, 5).unwrap();
+        let n = hex.cell_count();
+        let build = || {
+            ResourceField::builder()
+                .field(F_RES)
+                .presence_field(F_PRES)
+                .regrowth_rate(0.4)
+                .capacity(6.0)
+                .spread_rate(0.5)
+                .build()
+                .unwrap()
+        };
+        let init: Vec<Scalar> = (0..n).map(|i| (i % 4) as Scalar).collect();
+        let presence = vec![0.0; n];
+        let lazy = run_ticks(&build(), &hex, init.clone(), &presence, 3);
+
+        let mut prop = build();
+        prop.init(&hex).unwrap();
+        assert!(prop.neighbours.is_some());
+        assert_eq!(run_ticks(&prop, &hex, init, &presence, 3), lazy);
+
+        // No table without spreading.
+        let mut still = ResourceField::builder()
+            .field(F_RES)
+            .presence_field(F_PRES)
+            .build()
+            .unwrap();
+        still.init(&hex).unwrap();
+        assert!(still.neighbours.is_none());
+    }
+
+    #[test]
+    fn zero_spread_matches_in_place_regrowth() {
+        let grid = Square4::new(4, 4, EdgeBehavior::Wrap).unwrap();
+        let n = grid.cell_count();
+        let init: Vec<Scalar> = (0..n).map(|i| (i * 7 % 11) as Scalar * 0.8).collect();
+        let presence: Vec<Scalar> = (0..n).map(|i| (i % 4 == 1) as u8 as Scalar).collect();
+
+        for model in [RegrowthModel::Linear, RegrowthModel::Logistic] {
+            let prop = ResourceField::builder()
+                .field(F_RES)
+                .presence_field(F_PRES)
+                .consumption_rate(0.7)
+                .regrowth_rate(0.3)
+                .capacity(8.0)
+                .regrowth_model(model)
+                .spread_rate(0.0)
+                .build()
+                .unwrap();
+
+            // Reference: the per-cell update without any spreading.
+            let mut expected = init.clone();
+            for _ in 0..5 {
+                for (v, &p) in expected.iter_mut().zip(&presence) {
+                    if p != 0.0 {
+                        *v -= 0.7;
+                    }
+                    match model {
+                        RegrowthModel::Linear => *v += 0.3,
+                        RegrowthModel::Logistic => {
+                            if *v > 0.0 {
+                                *v += 0.3 * *v * (1.0 - *v / 8.0);
+                            }
+                        }
+                    }
+                    *v = v.clamp(0.0, 8.0);
+                }
+            }
+
+            let got = run_ticks(&prop, &grid, init.clone(), &presence, 5);
+            assert_eq!(got, expected, "{model:?}");
+        }
+    }
 }
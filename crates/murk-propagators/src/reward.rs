@@ -5,7 +5,7 @@
 
 #[allow(deprecated)]
 use crate::fields::{AGENT_PRESENCE, HEAT, REWARD};
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
 
@@ -15,8 +15,8 @@ use murk_propagator::propagator::{Propagator, WriteMode};
 /// - `reward[i] = heat[i] * heat_bonus + step_cost` if agent present
 /// - `reward[i] = 0.0` otherwise
 pub struct RewardPropagator {
-    heat_bonus: f32,
-    step_cost: f32,
+    heat_bonus: Scalar,
+    step_cost: Scalar,
 }
 
 impl RewardPropagator {
@@ -24,7 +24,7 @@ impl RewardPropagator {
     ///
     /// `heat_bonus` scales the heat value at the agent's cell.
     /// `step_cost` is added per step (typically negative for a movement penalty).
-    pub fn new(heat_bonus: f32, step_cost: f32) -> Self {
+    pub fn new(heat_bonus: Scalar, step_cost: Scalar) -> Self {
         Self {
             heat_bonus,
             step_cost,
@@ -112,11 +112,11 @@ mod tests {
         let prop = RewardPropagator::new(2.0, -0.1);
 
         let mut reader = MockFieldReader::new();
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[4] = 10.0; // center has heat
         reader.set_field(HEAT, heat);
 
-        let mut presence = vec![0.0f32; n];
+        let mut presence = vec![0.0; n];
         presence[4] = 1.0; // agent at center
         reader.set_field(AGENT_PRESENCE, presence);
 
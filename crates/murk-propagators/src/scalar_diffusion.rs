@@ -2,16 +2,52 @@
 //!
 //! A generalization of [`DiffusionPropagator`](crate::DiffusionPropagator) that
 //! operates on arbitrary [`FieldId`]s instead of hardcoded constants. Supports
-//! optional exponential decay, fixed-value sources, value clamping, and
-//! central-difference gradient output.
+//! optional exponential decay, fixed-value sources, value clamping,
+//! central-difference gradient output, and multi-component fields whose
+//! channels diffuse independently.
 //!
 //! Constructed via the builder pattern: [`ScalarDiffusion::builder`].
 
-use crate::grid_helpers::{neighbours_flat, resolve_axis};
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use crate::grid_helpers::{cached_neighbours, neighbours_flat, resolve_axis, row_major_square4};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
-use murk_space::{EdgeBehavior, Square4};
+use murk_space::{EdgeBehavior, Square8};
+
+/// Neighbour weighting used by [`ScalarDiffusion`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiffusionStencil {
+    /// Every neighbour weighs the same: the update mixes towards the plain
+    /// neighbour mean.
+    #[default]
+    Uniform,
+    /// On [`Square8`], the 9-point isotropic Laplacian: orthogonal
+    /// neighbours weigh `4/6` and diagonal neighbours `1/6`, matching the
+    /// continuum Laplacian with rotational error only at fourth order.
+    /// Other spaces fall back to [`Uniform`](Self::Uniform).
+    Isotropic,
+    /// On [`Square8`], weight each neighbour by the length of the edge it
+    /// shares with the cell: orthogonal neighbours weigh `1` and diagonal
+    /// neighbours `1/√2`, so less flux crosses the corners.
+    /// Other spaces fall back to [`Uniform`](Self::Uniform).
+    EdgeLengthWeighted,
+}
+
+impl DiffusionStencil {
+    /// `(orthogonal, diagonal)` neighbour weights on `Square8`.
+    fn square8_weights(self) -> (Scalar, Scalar) {
+        match self {
+            Self::Uniform => (1.0, 1.0),
+            Self::Isotropic => (ISO_ORTHOGONAL_WEIGHT, ISO_DIAGONAL_WEIGHT),
+            Self::EdgeLengthWeighted => (1.0, std::f64::consts::FRAC_1_SQRT_2 as Scalar),
+        }
+    }
+}
+
+/// 9-point isotropic Laplacian weight of an orthogonal neighbour.
+const ISO_ORTHOGONAL_WEIGHT: Scalar = 4.0 / 6.0;
+/// 9-point isotropic Laplacian weight of a diagonal neighbour.
+const ISO_DIAGONAL_WEIGHT: Scalar = 1.0 / 6.0;
 
 /// A parameterized Jacobi-style scalar diffusion propagator.
 ///
@@ -19,9 +55,16 @@ use murk_space::{EdgeBehavior, Square4};
 /// ```text
 /// out[i] = (1 - alpha) * prev[i] + alpha * mean(prev[neighbours])
 /// ```
-/// where `alpha = coefficient * dt * num_neighbours`.
+/// where `alpha = coefficient * dt * num_neighbours`. With a non-uniform
+/// [`DiffusionStencil`] on `Square8`, the mean and neighbour count become a
+/// weighted mean and the total weight.
 ///
 /// Optionally applies exponential decay, fixed-value sources, and value clamping.
+/// With [`components`](ScalarDiffusionBuilder::components) set above 1, the
+/// field is treated as interleaved per-cell vectors and each channel is
+/// diffused independently, as if by a separate single-component instance.
+/// With [`conservative`](ScalarDiffusionBuilder::conservative) set, the
+/// equivalent flux form is used instead so the field total is preserved.
 /// If a gradient field is configured, computes central-difference gradients of
 /// the **previous-tick** (pre-diffusion) values into a 2-component vector field.
 /// This is consistent with the Jacobi stencil: all reads come from the frozen
@@ -49,10 +92,13 @@ pub struct ScalarDiffusion {
     gradient_field: Option<FieldId>,
     coefficient: f64,
     decay: f64,
-    sources: Vec<(usize, f32)>,
-    clamp_min: Option<f32>,
-    clamp_max: Option<f32>,
+    sources: Vec<(usize, Scalar)>,
+    clamp_min: Option<Scalar>,
+    clamp_max: Option<Scalar>,
     max_degree: u32,
+    conservative: bool,
+    components: usize,
+    stencil: DiffusionStencil,
 }
 
 /// Builder for [`ScalarDiffusion`].
@@ -65,10 +111,13 @@ pub struct ScalarDiffusionBuilder {
     gradient_field: Option<FieldId>,
     coefficient: f64,
     decay: f64,
-    sources: Vec<(usize, f32)>,
-    clamp_min: Option<f32>,
-    clamp_max: Option<f32>,
+    sources: Vec<(usize, Scalar)>,
+    clamp_min: Option<Scalar>,
+    clamp_max: Option<Scalar>,
     max_degree: u32,
+    conservative: bool,
+    components: usize,
+    stencil: DiffusionStencil,
 }
 
 impl ScalarDiffusion {
@@ -84,24 +133,67 @@ impl ScalarDiffusion {
             clamp_min: None,
             clamp_max: None,
             max_degree: 12,
+            conservative: false,
+            components: 1,
+            stencil: DiffusionStencil::Uniform,
+        }
+    }
+
+    /// Flux-form exchange between cell `i` and each higher-ranked neighbour,
+    /// on channel `ch` of a field with `comps` interleaved components.
+    ///
+    /// Every flux is added to one cell and subtracted from the other, so the
+    /// field total is preserved regardless of boundary treatment: a missing
+    /// (absorbed) neighbour simply contributes no flux.
+    fn exchange_flux(
+        out: &mut [Scalar],
+        prev: &[Scalar],
+        i: usize,
+        nbs: impl IntoIterator<Item = (usize, Scalar)>,
+        k: Scalar,
+        (comps, ch): (usize, usize),
+    ) {
+        let a = i * comps + ch;
+        for (j, w) in nbs {
+            if j > i {
+                let b = j * comps + ch;
+                let flux = k * w * (prev[b] - prev[a]);
+                out[a] += flux;
+                out[b] -= flux;
+            }
+        }
+    }
+
+    /// Check that a field buffer holds `cell_count * components` values.
+    fn check_len(&self, what: &str, len: usize, cell_count: usize) -> Result<(), PropagatorError> {
+        let expected = cell_count * self.components;
+        if len != expected {
+            return Err(PropagatorError::ExecutionFailed {
+                reason: format!(
+                    "{what} length {len} != cell_count {cell_count} * components {}",
+                    self.components
+                ),
+            });
         }
+        Ok(())
     }
 
     /// Apply decay, sources, and clamping to a mutable output buffer.
-    fn apply_post_processing(&self, out: &mut [f32], dt: f64) {
+    fn apply_post_processing(&self, out: &mut [Scalar], dt: f64) {
         // True exponential decay: v *= exp(-decay * dt)
         // Safe for all dt values (never goes negative or inverts sign).
         if self.decay > 0.0 {
-            let decay_factor = (-(self.decay * dt)).exp() as f32;
+            let decay_factor = (-(self.decay * dt)).exp() as Scalar;
             for v in out.iter_mut() {
                 *v *= decay_factor;
             }
         }
 
-        // Fixed sources
+        // Fixed sources (every channel of the source cell)
+        let comps = self.components;
         for &(idx, val) in &self.sources {
-            if idx < out.len() {
-                out[idx] = val;
+            if idx < out.len() / comps {
+                out[idx * comps..(idx + 1) * comps].fill(val);
             }
         }
 
@@ -150,24 +242,47 @@ impl ScalarDiffusion {
             })?
             .to_vec();
 
+        let cell_count = rows as usize * cols as usize;
+        let comps = self.components;
+        self.check_len("input field", prev.len(), cell_count)?;
+
         let out = ctx.writes().write(self.output_field).ok_or_else(|| {
             PropagatorError::ExecutionFailed {
                 reason: format!("output field {:?} not writable", self.output_field),
             }
         })?;
-
-        for r in 0..rows_i {
-            for c in 0..cols_i {
-                let i = r as usize * cols as usize + c as usize;
-                let nbs = neighbours_flat(r, c, rows_i, cols_i, edge);
-                let count = nbs.len() as u32;
-                if count > 0 {
-                    let sum: f32 = nbs.iter().map(|&ni| prev[ni]).sum();
-                    let alpha = (self.coefficient * dt * count as f64).min(1.0) as f32;
-                    let mean = sum / count as f32;
-                    out[i] = (1.0 - alpha) * prev[i] + alpha * mean;
-                } else {
-                    out[i] = prev[i];
+        self.check_len("output field", out.len(), cell_count)?;
+
+        if self.conservative {
+            out.copy_from_slice(&prev);
+            let k = (self.coefficient * dt) as Scalar;
+            for ch in 0..comps {
+                for r in 0..rows_i {
+                    for c in 0..cols_i {
+                        let i = r as usize * cols as usize + c as usize;
+                        let nbs = neighbours_flat(r, c, rows_i, cols_i, edge);
+                        let nbs = nbs.iter().map(|&j| (j, 1.0));
+                        Self::exchange_flux(out, &prev, i, nbs, k, (comps, ch));
+                    }
+                }
+            }
+        } else {
+            for ch in 0..comps {
+                for r in 0..rows_i {
+                    for c in 0..cols_i {
+                        let i = r as usize * cols as usize + c as usize;
+                        let o = i * comps + ch;
+                        let nbs = neighbours_flat(r, c, rows_i, cols_i, edge);
+                        let count = nbs.len() as u32;
+                        if count > 0 {
+                            let sum: Scalar = nbs.iter().map(|&ni| prev[ni * comps + ch]).sum();
+                            let alpha = (self.coefficient * dt * count as f64).min(1.0) as Scalar;
+                            let mean = sum / count as Scalar;
+                            out[o] = (1.0 - alpha) * prev[o] + alpha * mean;
+                        } else {
+                            out[o] = prev[o];
+                        }
+                    }
                 }
             }
         }
@@ -214,18 +329,35 @@ impl ScalarDiffusion {
     fn step_generic(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
         let dt = ctx.dt();
 
-        // Precompute spatial topology before taking any mutable borrows
-        let ordering = ctx.space().canonical_ordering();
+        // Spatial topology, shared with the rest of a propagator group
+        let topology = cached_neighbours(ctx);
+        let ordering = topology.ordering();
         let cell_count = ordering.len();
 
-        // Precompute neighbour ranks for each cell
-        let neighbour_ranks: Vec<Vec<usize>> = ordering
+        // Precompute (rank, weight) of each cell's neighbours. Diagonal
+        // neighbours differ on both axes.
+        let square8_weights = ctx
+            .space()
+            .downcast_ref::<Square8>()
+            .map(|_| self.stencil.square8_weights());
+        let neighbour_ranks: Vec<Vec<(usize, Scalar)>> = ordering
             .iter()
-            .map(|coord| {
-                let neighbours = ctx.space().neighbours(coord);
-                neighbours
+            .enumerate()
+            .map(|(i, coord)| {
+                topology
+                    .neighbours(i)
                     .iter()
-                    .filter_map(|nb| ctx.space().canonical_rank(nb))
+                    .map(|&rank| {
+                        let nb = &ordering[rank];
+                        let w = match square8_weights {
+                            None => 1.0,
+                            Some((_, diagonal)) if nb[0] != coord[0] && nb[1] != coord[1] => {
+                                diagonal
+                            }
+                            Some((orthogonal, _)) => orthogonal,
+                        };
+                        (rank, w)
+                    })
                     .collect()
             })
             .collect();
@@ -239,19 +371,35 @@ impl ScalarDiffusion {
             })?
             .to_vec();
 
+        let comps = self.components;
+        self.check_len("input field", prev.len(), cell_count)?;
+
         // Compute diffusion into local buffer
-        let mut out_buf = vec![0.0f32; cell_count];
-
-        for i in 0..cell_count {
-            let nbs = &neighbour_ranks[i];
-            let count = nbs.len() as u32;
-            if count > 0 {
-                let sum: f32 = nbs.iter().map(|&r| prev[r]).sum();
-                let alpha = (self.coefficient * dt * count as f64).min(1.0) as f32;
-                let mean = sum / count as f32;
-                out_buf[i] = (1.0 - alpha) * prev[i] + alpha * mean;
-            } else {
-                out_buf[i] = prev[i];
+        let mut out_buf = vec![0.0; cell_count * comps];
+
+        if self.conservative {
+            out_buf.copy_from_slice(&prev);
+            let k = (self.coefficient * dt) as Scalar;
+            for ch in 0..comps {
+                for (i, nbs) in neighbour_ranks.iter().enumerate() {
+                    let nbs = nbs.iter().copied();
+                    Self::exchange_flux(&mut out_buf, &prev, i, nbs, k, (comps, ch));
+                }
+            }
+        } else {
+            for ch in 0..comps {
+                for (i, nbs) in neighbour_ranks.iter().enumerate() {
+                    let o = i * comps + ch;
+                    if !nbs.is_empty() {
+                        let sum: Scalar = nbs.iter().map(|&(r, w)| w * prev[r * comps + ch]).sum();
+                        let weight: Scalar = nbs.iter().map(|&(_, w)| w).sum();
+                        let alpha = (self.coefficient * dt * weight as f64).min(1.0) as Scalar;
+                        let mean = sum / weight;
+                        out_buf[o] = (1.0 - alpha) * prev[o] + alpha * mean;
+                    } else {
+                        out_buf[o] = prev[o];
+                    }
+                }
             }
         }
 
@@ -264,6 +412,7 @@ impl ScalarDiffusion {
                 reason: format!("output field {:?} not writable", self.output_field),
             }
         })?;
+        self.check_len("output field", out.len(), cell_count)?;
         out.copy_from_slice(&out_buf);
 
         // Compute gradient if requested
@@ -271,42 +420,42 @@ impl ScalarDiffusion {
             // Precompute gradient neighbour info: (nb_rank, delta_col, delta_row)
             let grad_info: Vec<Vec<(usize, i32, i32)>> = ordering
                 .iter()
-                .map(|coord| {
-                    let neighbours = ctx.space().neighbours(coord);
-                    neighbours
+                .enumerate()
+                .map(|(i, coord)| {
+                    topology
+                        .neighbours(i)
                         .iter()
-                        .filter_map(|nb| {
-                            ctx.space().canonical_rank(nb).map(|rank| {
-                                let dc = if nb.len() >= 2 { nb[1] - coord[1] } else { 0 };
-                                let dr = nb[0] - coord[0];
-                                (rank, dc, dr)
-                            })
+                        .map(|&rank| {
+                            let nb = &ordering[rank];
+                            let dc = if nb.len() >= 2 { nb[1] - coord[1] } else { 0 };
+                            let dr = nb[0] - coord[0];
+                            (rank, dc, dr)
                         })
                         .collect()
                 })
                 .collect();
 
-            let mut grad_buf = vec![0.0f32; cell_count * 2];
+            let mut grad_buf = vec![0.0; cell_count * 2];
 
             for i in 0..cell_count {
                 let gi = &grad_info[i];
-                let mut gx = 0.0f32;
-                let mut gy = 0.0f32;
+                let mut gx = 0.0;
+                let mut gy = 0.0;
                 let mut xc = 0u32;
                 let mut yc = 0u32;
                 for &(rank, dc, dr) in gi {
                     let dh = prev[rank] - prev[i];
                     if dc != 0 {
-                        gx += dh / dc as f32;
+                        gx += dh / dc as Scalar;
                         xc += 1;
                     }
                     if dr != 0 {
-                        gy += dh / dr as f32;
+                        gy += dh / dr as Scalar;
                         yc += 1;
                     }
                 }
-                grad_buf[i * 2] = if xc > 0 { gx / xc as f32 } else { 0.0 };
-                grad_buf[i * 2 + 1] = if yc > 0 { gy / yc as f32 } else { 0.0 };
+                grad_buf[i * 2] = if xc > 0 { gx / xc as Scalar } else { 0.0 };
+                grad_buf[i * 2 + 1] = if yc > 0 { gy / yc as Scalar } else { 0.0 };
             }
 
             let grad_out =
@@ -354,25 +503,25 @@ impl ScalarDiffusionBuilder {
     }
 
     /// Add a fixed-value source cell. Each tick, `cells[idx]` is reset to `value`.
-    pub fn source(mut self, idx: usize, value: f32) -> Self {
+    pub fn source(mut self, idx: usize, value: Scalar) -> Self {
         self.sources.push((idx, value));
         self
     }
 
     /// Set all fixed-value source cells at once.
-    pub fn sources(mut self, sources: Vec<(usize, f32)>) -> Self {
+    pub fn sources(mut self, sources: Vec<(usize, Scalar)>) -> Self {
         self.sources = sources;
         self
     }
 
     /// Set the minimum clamp value.
-    pub fn clamp_min(mut self, min: f32) -> Self {
+    pub fn clamp_min(mut self, min: Scalar) -> Self {
         self.clamp_min = Some(min);
         self
     }
 
     /// Set the maximum clamp value.
-    pub fn clamp_max(mut self, max: f32) -> Self {
+    pub fn clamp_max(mut self, max: Scalar) -> Self {
         self.clamp_max = Some(max);
         self
     }
@@ -387,6 +536,40 @@ impl ScalarDiffusionBuilder {
         self
     }
 
+    /// Use the flux-form (mass-preserving) update (default `false`).
+    ///
+    /// Each neighbour pair exchanges `coefficient * dt * (prev[j] - prev[i])`,
+    /// added to one cell and subtracted from the other, so the total of the
+    /// field is preserved to within float rounding, including on `Absorb`
+    /// boundaries where fluxes across the domain edge are zero. Unlike the
+    /// default update, the per-cell mixing factor is not clamped to 1, so
+    /// `dt` must respect [`max_dt`](Propagator::max_dt). Decay, sources and
+    /// clamping are applied afterwards and do not conserve mass.
+    pub fn conservative(mut self, conservative: bool) -> Self {
+        self.conservative = conservative;
+        self
+    }
+
+    /// Set the number of interleaved components per cell (default 1).
+    ///
+    /// The input and output fields must hold `cell_count * components`
+    /// values (e.g. 2 for a `Vector { dims: 2 }` velocity field). Each
+    /// channel diffuses independently; sources set every channel of their
+    /// cell. Not supported together with a gradient field.
+    pub fn components(mut self, components: usize) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Set the neighbour weighting (default [`DiffusionStencil::Uniform`]).
+    ///
+    /// Only `Square8` distinguishes the stencils; on other spaces every
+    /// stencil behaves like `Uniform`.
+    pub fn stencil(mut self, stencil: DiffusionStencil) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
     /// Build the propagator, validating all configuration.
     ///
     /// # Errors
@@ -397,6 +580,7 @@ impl ScalarDiffusionBuilder {
     /// - `coefficient` is negative
     /// - `decay` is negative
     /// - `clamp_min > clamp_max` (when both are set)
+    /// - `components` is zero, or above 1 with a gradient field set
     pub fn build(self) -> Result<ScalarDiffusion, String> {
         let input_field = self
             .input_field
@@ -419,6 +603,15 @@ impl ScalarDiffusionBuilder {
                 return Err(format!("clamp_min ({lo}) must be <= clamp_max ({hi})"));
             }
         }
+        if self.components == 0 {
+            return Err("components must be >= 1".to_string());
+        }
+        if self.components > 1 && self.gradient_field.is_some() {
+            return Err(format!(
+                "gradient_field requires a single-component field, got components = {}",
+                self.components
+            ));
+        }
 
         Ok(ScalarDiffusion {
             input_field,
@@ -430,6 +623,9 @@ impl ScalarDiffusionBuilder {
             clamp_min: self.clamp_min,
             clamp_max: self.clamp_max,
             max_degree: self.max_degree,
+            conservative: self.conservative,
+            components: self.components,
+            stencil: self.stencil,
         })
     }
 }
@@ -455,6 +651,22 @@ impl Propagator for ScalarDiffusion {
         w
     }
 
+    fn conserved_fields(&self) -> FieldSet {
+        // Only the flux form on a field diffusing into itself, with nothing
+        // injected, removed, or clamped, preserves the total exactly.
+        let lossless = self.conservative
+            && self.input_field == self.output_field
+            && self.decay == 0.0
+            && self.sources.is_empty()
+            && self.clamp_min.is_none()
+            && self.clamp_max.is_none();
+        if lossless {
+            [self.output_field].into_iter().collect()
+        } else {
+            FieldSet::empty()
+        }
+    }
+
     fn max_dt(&self, space: &dyn murk_space::Space) -> Option<f64> {
         if self.coefficient <= 0.0 {
             return None;
@@ -469,7 +681,7 @@ impl Propagator for ScalarDiffusion {
     }
 
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        if let Some(grid) = ctx.space().downcast_ref::<Square4>() {
+        if let Some(grid) = row_major_square4(ctx.space()) {
             let rows = grid.rows();
             let cols = grid.cols();
             let edge = grid.edge_behavior();
@@ -483,9 +695,10 @@ impl Propagator for ScalarDiffusion {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use murk_core::{Coord, SpaceInstanceId, TickId};
+    use murk_core::{scalar_to_f64, Coord, SpaceInstanceId, TickId};
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, RegionPlan, RegionSpec, Space, SpaceError};
+    use murk_propagator::{validate_pipeline, PipelineError};
+    use murk_space::{EdgeBehavior, RegionPlan, RegionSpec, Space, SpaceError, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
     use smallvec::{smallvec, SmallVec};
 
@@ -666,7 +879,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[12] = 100.0; // center of 5x5
 
         let mut reader = MockFieldReader::new();
@@ -717,9 +930,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[12] = 100.0;
-        let total_before: f32 = heat.iter().sum();
+        let total_before: Scalar = heat.iter().sum();
 
         let mut reader = MockFieldReader::new();
         reader.set_field(F_HEAT, heat);
@@ -733,7 +946,7 @@ mod tests {
         prop.step(&mut ctx).unwrap();
 
         let result = writer.get_field(F_HEAT).unwrap();
-        let total_after: f32 = result.iter().sum();
+        let total_after: Scalar = result.iter().sum();
         assert!(
             (total_before - total_after).abs() < 1e-3,
             "energy not conserved: before={total_before}, after={total_after}"
@@ -804,7 +1017,7 @@ mod tests {
         let expected = 10.0_f64 * (-0.5_f64 * 0.1).exp();
         for &v in result {
             assert!(
-                (v as f64 - expected).abs() < 1e-4,
+                (scalar_to_f64(v) - expected).abs() < 1e-4,
                 "decay should reduce to ~{expected:.4}, got {v}"
             );
         }
@@ -859,7 +1072,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut data = vec![5.0f32; n];
+        let mut data = vec![5.0; n];
         data[0] = -10.0;
         data[4] = -3.0;
 
@@ -959,9 +1172,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         heat[0] = 100.0;
-        let total_before: f32 = heat.iter().sum();
+        let total_before: Scalar = heat.iter().sum();
 
         let mut reader = MockFieldReader::new();
         reader.set_field(F_HEAT, heat);
@@ -975,7 +1188,7 @@ mod tests {
         prop.step(&mut ctx).unwrap();
 
         let result = writer.get_field(F_HEAT).unwrap();
-        let total_after: f32 = result.iter().sum();
+        let total_after: Scalar = result.iter().sum();
         assert!(
             (total_before - total_after).abs() < 1e-3,
             "wrap: energy not conserved: before={total_before}, after={total_after}"
@@ -995,10 +1208,10 @@ mod tests {
             .unwrap();
 
         // Linear gradient in x: col 0=0, col 1=10, col 2=20
-        let mut heat = vec![0.0f32; n];
+        let mut heat = vec![0.0; n];
         for r in 0..3 {
             for c in 0..3 {
-                heat[r * 3 + c] = (c as f32) * 10.0;
+                heat[r * 3 + c] = (c as Scalar) * 10.0;
             }
         }
 
@@ -1041,7 +1254,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let mut data = vec![10.0f32; n];
+        let mut data = vec![10.0; n];
         data[0] = 100.0;
 
         let mut reader = MockFieldReader::new();
@@ -1098,7 +1311,7 @@ mod tests {
         let result = writer.get_field(F_HEAT).unwrap();
         for &v in result {
             assert!(
-                (v as f64 - expected).abs() < 1e-3,
+                (scalar_to_f64(v) - expected).abs() < 1e-3,
                 "combined decay+diffusion on uniform should yield ~{expected:.4}, got {v}"
             );
         }
@@ -1137,10 +1350,407 @@ mod tests {
         );
     }
 
+    // ---------------------------------------------------------------
+    // Conservative (flux-form) mode tests
+    // ---------------------------------------------------------------
+
+    /// Step `prop` `ticks` times on `space`, feeding each output back in.
+    fn run_ticks(
+        prop: &dyn Propagator,
+        space: &dyn Space,
+        init: Vec<Scalar>,
+        ticks: usize,
+    ) -> Vec<Scalar> {
+        let n = init.len();
+        let mut heat = init;
+        for t in 0..ticks {
+            let mut reader = MockFieldReader::new();
+            reader.set_field(F_HEAT, heat);
+            let mut writer = MockFieldWriter::new();
+            writer.add_field(F_HEAT, n);
+            let mut scratch = ScratchRegion::new(0);
+            let mut ctx = StepContext::new(
+                &reader,
+                &reader,
+                &mut writer,
+                &mut scratch,
+                space,
+                TickId(t as u64 + 1),
+                0.1,
+            );
+            prop.step(&mut ctx).unwrap();
+            heat = writer.get_field(F_HEAT).unwrap().to_vec();
+        }
+        heat
+    }
+
+    /// The default `ScalarDiffusion` update written as a stencil kernel.
+    struct StencilDiffusion {
+        coefficient: f64,
+    }
+
+    impl murk_propagator::StencilPropagator for StencilDiffusion {
+        fn name(&self) -> &str {
+            "stencil_diffusion"
+        }
+
+        fn input_field(&self) -> FieldId {
+            F_HEAT
+        }
+
+        fn output_field(&self) -> FieldId {
+            F_HEAT
+        }
+
+        fn kernel(&self, center: Scalar, neighbours: &[Scalar], dt: f64) -> Scalar {
+            if neighbours.is_empty() {
+                return center;
+            }
+            let n = neighbours.len();
+            let alpha = (self.coefficient * dt * n as f64).min(1.0) as Scalar;
+            let mean = neighbours.iter().sum::<Scalar>() / n as Scalar;
+            (1.0 - alpha) * center + alpha * mean
+        }
+    }
+
+    #[test]
+    fn stencil_helper_diffusion_matches_scalar_diffusion_square4() {
+        let stencil = StencilDiffusion { coefficient: 0.2 };
+        let diffusion = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(0.2)
+            .build()
+            .unwrap();
+        let init: Vec<Scalar> = (0..30).map(|i| ((i * 37) % 11) as Scalar).collect();
+        for edge in [EdgeBehavior::Absorb, EdgeBehavior::Wrap] {
+            let grid = Square4::new(5, 6, edge).unwrap();
+            let expected = run_ticks(&diffusion, &grid, init.clone(), 10);
+            let actual = run_ticks(&stencil, &grid, init.clone(), 10);
+            for (i, (a, e)) in actual.iter().zip(&expected).enumerate() {
+                assert!((a - e).abs() < 1e-4, "{edge:?} cell {i}: {a} vs {e}");
+            }
+        }
+    }
+
+    fn conservative_prop() -> ScalarDiffusion {
+        ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(0.5)
+            .conservative(true)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn conservative_preserves_mass_on_absorb_edges() {
+        let grid = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        let n = grid.cell_count();
+        // Mass concentrated on the boundary, where absorbing edges matter.
+        let mut init = vec![0.0; n];
+        init[0] = 100.0;
+        init[5] = 50.0;
+        init[33] = 25.0;
+        let total_before: f64 = init.iter().map(|&v| scalar_to_f64(v)).sum();
+
+        let out = run_ticks(&conservative_prop(), &grid, init, 500);
+        let total_after: f64 = out.iter().map(|&v| scalar_to_f64(v)).sum();
+        assert!(
+            (total_before - total_after).abs() <= total_before * 1e-5,
+            "mass not conserved: before={total_before}, after={total_after}"
+        );
+        // Sanity: the field actually spread out.
+        assert!(out.iter().all(|&v| v > 0.0));
+    }
+
+    #[test]
+    fn only_lossless_conservative_mode_declares_conserved_field() {
+        assert_eq!(
+            conservative_prop().conserved_fields(),
+            [F_HEAT].into_iter().collect()
+        );
+        let decaying = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(0.5)
+            .decay(0.1)
+            .conservative(true)
+            .build()
+            .unwrap();
+        assert!(decaying.conserved_fields().is_empty());
+        let default = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(0.5)
+            .build()
+            .unwrap();
+        assert!(default.conserved_fields().is_empty());
+    }
+
+    #[test]
+    fn conservative_preserves_mass_generic_path() {
+        let line = murk_space::Line1D::new(8, EdgeBehavior::Absorb).unwrap();
+        let mut init = vec![0.0; 8];
+        init[0] = 40.0;
+        init[7] = 10.0;
+        let total_before: f64 = init.iter().map(|&v| scalar_to_f64(v)).sum();
+
+        let out = run_ticks(&conservative_prop(), &line, init, 500);
+        let total_after: f64 = out.iter().map(|&v| scalar_to_f64(v)).sum();
+        assert!(
+            (total_before - total_after).abs() <= total_before * 1e-5,
+            "mass not conserved: before={total_before}, after={total_after}"
+        );
+    }
+
+    #[test]
+    fn conservative_matches_default_below_alpha_clamp() {
+        // With symmetric neighbour lists and alpha < 1, the flux form is
+        // algebraically identical to the default update.
+        let grid = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let init: Vec<Scalar> = (0..16).map(|i| (i * 7 % 5) as Scalar).collect();
+        let default = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(0.5)
+            .build()
+            .unwrap();
+
+        let a = run_ticks(&default, &grid, init.clone(), 1);
+        let b = run_ticks(&conservative_prop(), &grid, init, 1);
+        for (x, y) in a.iter().zip(&b) {
+            assert!((x - y).abs() < 1e-5, "default={x}, conservative={y}");
+        }
+    }
+
+    #[test]
+    fn column_major_grid_matches_row_major() {
+        // Column-major grids skip the row-major fast path; the result must
+        // be the same field, just stored transposed.
+        let row = Square4::new(4, 5, EdgeBehavior::Absorb).unwrap();
+        let col = row
+            .clone()
+            .with_memory_order(murk_space::MemoryOrder::ColumnMajor);
+        let value = |co: &Coord| ((co[0] * 3 + co[1] * 7) % 11) as Scalar;
+        let init_row: Vec<Scalar> = row.canonical_ordering().iter().map(value).collect();
+        let init_col: Vec<Scalar> = col.canonical_ordering().iter().map(value).collect();
+
+        let prop = conservative_prop();
+        let a = run_ticks(&prop, &row, init_row, 3);
+        let b = run_ticks(&prop, &col, init_col, 3);
+        for co in row.canonical_ordering() {
+            let x = a[row.canonical_rank(&co).unwrap()];
+            let y = b[col.canonical_rank(&co).unwrap()];
+            assert!((x - y).abs() < 1e-5, "{co:?}: row={x}, col={y}");
+        }
+    }
+
+    #[test]
+    fn default_clamps_mixing_factor_as_before() {
+        // coefficient * dt * 4 = 2.0 → alpha clamps to 1 in the interior,
+        // so interior cells become their neighbour mean and mass leaks.
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let prop = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(5.0)
+            .build()
+            .unwrap();
+        let mut init = vec![0.0; 9];
+        init[4] = 10.0;
+
+        let out = run_ticks(&prop, &grid, init, 1);
+        assert_eq!(out[4], 0.0, "centre takes the neighbour mean (all zero)");
+        let total: Scalar = out.iter().sum();
+        assert!((total - 10.0).abs() > 1.0, "default mode is not flux-form");
+    }
+
     // ---------------------------------------------------------------
     // Topology-aware CFL tests
     // ---------------------------------------------------------------
 
+    // ---------------------------------------------------------------
+    // Multi-component tests
+    // ---------------------------------------------------------------
+
+    fn interleave(a: &[Scalar], b: &[Scalar]) -> Vec<Scalar> {
+        a.iter().zip(b).flat_map(|(&x, &y)| [x, y]).collect()
+    }
+
+    /// Diffuse a 2-component field and the same channels as two scalar runs.
+    fn assert_channels_independent(space: &dyn Space, conservative: bool) {
+        let n = space.cell_count();
+        let u: Vec<Scalar> = (0..n).map(|i| (i * 7 % 5) as Scalar).collect();
+        let v: Vec<Scalar> = (0..n)
+            .map(|i| if i == n / 2 { 30.0 } else { 0.0 })
+            .collect();
+        let builder = || {
+            ScalarDiffusion::builder()
+                .input_field(F_HEAT)
+                .output_field(F_HEAT)
+                .coefficient(0.2)
+                .decay(0.1)
+                .source(1, 4.0)
+                .conservative(conservative)
+        };
+        let scalar = builder().build().unwrap();
+        let vector = builder().components(2).build().unwrap();
+
+        let u_out = run_ticks(&scalar, space, u.clone(), 5);
+        let v_out = run_ticks(&scalar, space, v.clone(), 5);
+        let uv_out = run_ticks(&vector, space, interleave(&u, &v), 5);
+        assert_eq!(uv_out, interleave(&u_out, &v_out));
+    }
+
+    #[test]
+    fn two_components_match_separate_scalar_runs_square4() {
+        let grid = Square4::new(5, 4, EdgeBehavior::Absorb).unwrap();
+        assert_channels_independent(&grid, false);
+        assert_channels_independent(&grid, true);
+    }
+
+    #[test]
+    fn two_components_match_separate_scalar_runs_generic() {
+        let line = murk_space::Line1D::new(9, EdgeBehavior::Wrap).unwrap();
+        assert_channels_independent(&line, false);
+        assert_channels_independent(&line, true);
+    }
+
+    fn stencil_prop(coefficient: f64, stencil: DiffusionStencil) -> ScalarDiffusion {
+        ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(coefficient)
+            .stencil(stencil)
+            .build()
+            .unwrap()
+    }
+
+    /// Relative spread between the axis cell (5, 0) and the off-axis cell
+    /// (3, 4) — both at distance 5 from a centred bump on Square8.
+    fn square8_anisotropy(prop: &ScalarDiffusion) -> Scalar {
+        let side = 31;
+        let grid = murk_space::Square8::new(side, side, EdgeBehavior::Absorb).unwrap();
+        let at = |r: usize, c: usize| r * side as usize + c;
+        let mut init = vec![0.0; grid.cell_count()];
+        init[at(15, 15)] = 1000.0;
+        let out = run_ticks(prop, &grid, init, 60);
+        let axis = out[at(20, 15)];
+        let off_axis = out[at(18, 19)];
+        assert!(axis > 0.0 && off_axis > 0.0);
+        (axis - off_axis).abs() / axis.max(off_axis)
+    }
+
+    #[test]
+    fn isotropic_stencil_reduces_square8_anisotropy() {
+        // The uniform 8-neighbour update has three times the isotropic
+        // stencil's effective diffusivity; scale it down for a fair match.
+        let uniform = square8_anisotropy(&stencil_prop(0.1, DiffusionStencil::Uniform));
+        let isotropic = square8_anisotropy(&stencil_prop(0.3, DiffusionStencil::Isotropic));
+        assert!(
+            isotropic < uniform * 0.5,
+            "isotropic anisotropy {isotropic} not clearly below uniform {uniform}"
+        );
+    }
+
+    #[test]
+    fn isotropic_stencil_is_uniform_off_square8() {
+        let init: Vec<Scalar> = (0..20).map(|i| (i * 7 % 5) as Scalar).collect();
+        let grid = Square4::new(5, 4, EdgeBehavior::Absorb).unwrap();
+        let line = murk_space::Line1D::new(20, EdgeBehavior::Wrap).unwrap();
+        let uniform = stencil_prop(0.5, DiffusionStencil::Uniform);
+        for stencil in [
+            DiffusionStencil::Isotropic,
+            DiffusionStencil::EdgeLengthWeighted,
+        ] {
+            let weighted = stencil_prop(0.5, stencil);
+            for space in [&grid as &dyn Space, &line] {
+                let a = run_ticks(&uniform, space, init.clone(), 5);
+                let b = run_ticks(&weighted, space, init.clone(), 5);
+                assert_eq!(a, b, "{stencil:?}");
+            }
+        }
+    }
+
+    /// `(orthogonal, diagonal)` values next to a single hot cell after one
+    /// tick on Square8.
+    fn square8_first_ring(prop: &ScalarDiffusion) -> (Scalar, Scalar) {
+        let grid = murk_space::Square8::new(5, 5, EdgeBehavior::Absorb).unwrap();
+        let mut init = vec![0.0; 25];
+        init[12] = 100.0;
+        let out = run_ticks(prop, &grid, init, 1);
+        (out[7], out[6])
+    }
+
+    #[test]
+    fn uniform_stencil_weighs_square8_neighbours_equally() {
+        let (orthogonal, diagonal) =
+            square8_first_ring(&stencil_prop(0.05, DiffusionStencil::Uniform));
+        assert!(orthogonal > 0.0);
+        assert_eq!(orthogonal, diagonal);
+        // Plain neighbour mean: alpha = 0.05 * 0.1 * 8, mean = 100 / 8.
+        assert!((orthogonal - 0.04 * 12.5).abs() < 1e-5, "{orthogonal}");
+    }
+
+    #[test]
+    fn edge_length_stencil_reduces_square8_diagonal_flux() {
+        for conservative in [false, true] {
+            let prop = ScalarDiffusion::builder()
+                .input_field(F_HEAT)
+                .output_field(F_HEAT)
+                .coefficient(0.05)
+                .conservative(conservative)
+                .stencil(DiffusionStencil::EdgeLengthWeighted)
+                .build()
+                .unwrap();
+            let (orthogonal, diagonal) = square8_first_ring(&prop);
+            assert!(orthogonal > 0.0);
+            let ratio = diagonal / orthogonal;
+            assert!(
+                (ratio - std::f64::consts::FRAC_1_SQRT_2 as Scalar).abs() < 1e-5,
+                "conservative={conservative}: diagonal/orthogonal = {ratio}"
+            );
+        }
+    }
+
+    #[test]
+    fn components_length_mismatch_is_error() {
+        let grid = Square4::new(3, 3, EdgeBehavior::Absorb).unwrap();
+        let prop = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_OUT)
+            .components(2)
+            .build()
+            .unwrap();
+        let mut reader = MockFieldReader::new();
+        reader.set_field(F_HEAT, vec![0.0; 9]);
+        let mut writer = MockFieldWriter::new();
+        writer.add_field(F_OUT, 18);
+        let mut scratch = ScratchRegion::new(0);
+        let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 0.1);
+        let err = prop.step(&mut ctx).unwrap_err();
+        assert!(format!("{err:?}").contains("components 2"), "{err:?}");
+    }
+
+    #[test]
+    fn builder_rejects_bad_components() {
+        let zero = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_OUT)
+            .components(0)
+            .build();
+        assert!(zero.unwrap_err().contains("components"));
+
+        let with_gradient = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_OUT)
+            .gradient_field(F_GRAD)
+            .components(2)
+            .build();
+        assert!(with_gradient.unwrap_err().contains("gradient_field"));
+    }
+
     #[test]
     fn max_dt_uses_space_degree_for_square4() {
         let prop = ScalarDiffusion::builder()
@@ -1155,6 +1765,34 @@ mod tests {
         assert_eq!(prop.max_dt(&space), Some(0.25));
     }
 
+    #[test]
+    fn dt_above_cfl_limit_is_cfl_violation() {
+        let prop = ScalarDiffusion::builder()
+            .input_field(F_HEAT)
+            .output_field(F_HEAT)
+            .coefficient(1.0)
+            .max_degree(0)
+            .build()
+            .unwrap();
+        let props: Vec<Box<dyn Propagator>> = vec![Box::new(prop)];
+        let fields = [F_HEAT].into_iter().collect();
+        let space = Square4::new(8, 8, EdgeBehavior::Wrap).unwrap();
+
+        assert!(validate_pipeline(&props, &fields, 0.25, &space).is_ok());
+        match validate_pipeline(&props, &fields, 0.5, &space) {
+            Err(PipelineError::CflViolation {
+                propagator,
+                dt,
+                max_dt,
+            }) => {
+                assert_eq!(propagator, "ScalarDiffusion");
+                assert_eq!(dt, 0.5);
+                assert_eq!(max_dt, 0.25);
+            }
+            other => panic!("expected CflViolation, got {other:?}"),
+        }
+    }
+
     #[test]
     fn max_dt_uses_space_degree_for_hex2d() {
         let prop = ScalarDiffusion::builder()
@@ -1247,10 +1885,7 @@ mod tests {
         }
 
         fn compile_region(&self, spec: &RegionSpec) -> Result<RegionPlan, SpaceError> {
-            let _ = spec;
-            Err(SpaceError::InvalidRegion {
-                reason: "SkewDegreeSpace test backend does not support compile_region".into(),
-            })
+            Err(SpaceError::UnsupportedRegion { kind: spec.kind() })
         }
 
         fn canonical_ordering(&self) -> Vec<Coord> {
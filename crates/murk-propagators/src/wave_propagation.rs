@@ -17,11 +17,11 @@
 //!
 //! Constructed via the builder pattern: [`WavePropagation::builder`].
 
-use crate::grid_helpers::neighbours_flat;
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use crate::grid_helpers::{neighbours_flat, row_major_square4};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
-use murk_space::{EdgeBehavior, Square4};
+use murk_space::EdgeBehavior;
 
 /// A second-order wave equation propagator.
 ///
@@ -72,9 +72,9 @@ impl WavePropagation {
         let rows_i = rows as i32;
         let cols_i = cols as i32;
         let dt = ctx.dt();
-        let c2 = (self.wave_speed * self.wave_speed) as f32;
-        let damp = self.damping as f32;
-        let dt_f32 = dt as f32;
+        let c2 = (self.wave_speed * self.wave_speed) as Scalar;
+        let damp = self.damping as Scalar;
+        let dt_f32 = dt as Scalar;
 
         let prev_d = ctx
             .reads_previous()
@@ -97,8 +97,8 @@ impl WavePropagation {
 
         // Compute new values into local buffers (avoids two concurrent write borrows)
         let n = (rows as usize) * (cols as usize);
-        let mut new_d = vec![0.0f32; n];
-        let mut new_v = vec![0.0f32; n];
+        let mut new_d = vec![0.0; n];
+        let mut new_v = vec![0.0; n];
 
         for r in 0..rows_i {
             for c in 0..cols_i {
@@ -106,8 +106,8 @@ impl WavePropagation {
                 let nbs = neighbours_flat(r, c, rows_i, cols_i, edge);
                 let count = nbs.len();
                 let laplacian = if count > 0 {
-                    let sum: f32 = nbs.iter().map(|&ni| prev_d[ni]).sum();
-                    sum / count as f32 - prev_d[i]
+                    let sum: Scalar = nbs.iter().map(|&ni| prev_d[ni]).sum();
+                    sum / count as Scalar - prev_d[i]
                 } else {
                     0.0
                 };
@@ -140,9 +140,9 @@ impl WavePropagation {
     /// Generic fallback.
     fn step_generic(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
         let dt = ctx.dt();
-        let c2 = (self.wave_speed * self.wave_speed) as f32;
-        let damp = self.damping as f32;
-        let dt_f32 = dt as f32;
+        let c2 = (self.wave_speed * self.wave_speed) as Scalar;
+        let damp = self.damping as Scalar;
+        let dt_f32 = dt as Scalar;
 
         let ordering = ctx.space().canonical_ordering();
         let cell_count = ordering.len();
@@ -177,15 +177,15 @@ impl WavePropagation {
             })?
             .to_vec();
 
-        let mut new_d = vec![0.0f32; cell_count];
-        let mut new_v = vec![0.0f32; cell_count];
+        let mut new_d = vec![0.0; cell_count];
+        let mut new_v = vec![0.0; cell_count];
 
         for i in 0..cell_count {
             let nbs = &neighbour_ranks[i];
             let count = nbs.len();
             let laplacian = if count > 0 {
-                let sum: f32 = nbs.iter().map(|&r| prev_d[r]).sum();
-                sum / count as f32 - prev_d[i]
+                let sum: Scalar = nbs.iter().map(|&r| prev_d[r]).sum();
+                sum / count as Scalar - prev_d[i]
             } else {
                 0.0
             };
@@ -311,7 +311,7 @@ impl Propagator for WavePropagation {
     }
 
     fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
-        if let Some(grid) = ctx.space().downcast_ref::<Square4>() {
+        if let Some(grid) = row_major_square4(ctx.space()) {
             let rows = grid.rows();
             let cols = grid.cols();
             let edge = grid.edge_behavior();
@@ -327,7 +327,7 @@ mod tests {
     use super::*;
     use murk_core::TickId;
     use murk_propagator::scratch::ScratchRegion;
-    use murk_space::{EdgeBehavior, Space};
+    use murk_space::{EdgeBehavior, Space, Square4};
     use murk_test_utils::{MockFieldReader, MockFieldWriter};
 
     const F_DISP: FieldId = FieldId(100);
@@ -486,7 +486,7 @@ mod tests {
             .unwrap();
 
         // Displacement impulse at center (cell 12)
-        let mut disp = vec![0.0f32; n];
+        let mut disp = vec![0.0; n];
         disp[12] = 10.0;
 
         let mut reader = MockFieldReader::new();
@@ -531,9 +531,9 @@ mod tests {
                 .unwrap()
         };
 
-        let disp = vec![0.0f32; n];
+        let disp = vec![0.0; n];
         // Give the system non-zero initial velocity so damping has something to act on.
-        let mut vel = vec![0.0f32; n];
+        let mut vel = vec![0.0; n];
         vel[12] = 5.0;
 
         // Run undamped
@@ -547,7 +547,7 @@ mod tests {
         let mut scratch = ScratchRegion::new(0);
         let mut ctx = make_ctx(&reader, &mut writer, &mut scratch, &grid, 0.01);
         prop_undamped.step(&mut ctx).unwrap();
-        let undamped_energy: f32 = writer.get_field(F_VEL).unwrap().iter().map(|v| v * v).sum();
+        let undamped_energy: Scalar = writer.get_field(F_VEL).unwrap().iter().map(|v| v * v).sum();
 
         // Run damped
         let prop_damped = make_prop(5.0);
@@ -560,7 +560,7 @@ mod tests {
         let mut scratch2 = ScratchRegion::new(0);
         let mut ctx2 = make_ctx(&reader2, &mut writer2, &mut scratch2, &grid, 0.01);
         prop_damped.step(&mut ctx2).unwrap();
-        let damped_energy: f32 = writer2
+        let damped_energy: Scalar = writer2
             .get_field(F_VEL)
             .unwrap()
             .iter()
@@ -0,0 +1,161 @@
+//! Integration tests for the `f64-fields` feature.
+//!
+//! Run with `cargo test -p murk-propagators --features f64-fields`. Field
+//! storage is `f64` in this build; the tests check that results agree with
+//! an `f32` computation of the same update and that long accumulations keep
+//! increments an `f32` buffer would round away.
+
+#![cfg(feature = "f64-fields")]
+
+use murk_core::{
+    BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldReader, FieldSet, FieldType,
+    PropagatorError, Scalar,
+};
+use murk_engine::{LockstepWorld, WorldConfig};
+use murk_propagator::{Propagator, StepContext, WriteMode};
+use murk_propagators::ScalarDiffusion;
+use murk_space::{EdgeBehavior, Line1D, Square4};
+
+const HEAT: FieldId = FieldId(0);
+
+fn scalar_field(name: &str) -> FieldDef {
+    FieldDef {
+        name: name.to_string(),
+        field_type: FieldType::Scalar,
+        mutability: FieldMutability::PerTick,
+        units: None,
+        bounds: None,
+        boundary_behavior: BoundaryBehavior::Clamp,
+    }
+}
+
+/// One Jacobi diffusion step on an Absorb Square4 grid, computed in `f32`
+/// exactly as `ScalarDiffusion` does for the default build.
+fn diffuse_f32(prev: &[f32], rows: usize, cols: usize, coefficient: f64, dt: f64) -> Vec<f32> {
+    let mut out = vec![0.0f32; prev.len()];
+    for r in 0..rows {
+        for c in 0..cols {
+            let mut nbs = Vec::with_capacity(4);
+            if r > 0 {
+                nbs.push((r - 1) * cols + c);
+            }
+            if r + 1 < rows {
+                nbs.push((r + 1) * cols + c);
+            }
+            if c > 0 {
+                nbs.push(r * cols + c - 1);
+            }
+            if c + 1 < cols {
+                nbs.push(r * cols + c + 1);
+            }
+            let i = r * cols + c;
+            let sum: f32 = nbs.iter().map(|&j| prev[j]).sum();
+            let alpha = (coefficient * dt * nbs.len() as f64).min(1.0) as f32;
+            let mean = sum / nbs.len() as f32;
+            out[i] = (1.0 - alpha) * prev[i] + alpha * mean;
+        }
+    }
+    out
+}
+
+#[test]
+fn diffusion_matches_f32_reference() {
+    const ROWS: usize = 8;
+    const COLS: usize = 8;
+    const TICKS: usize = 50;
+    let config = WorldConfig::builder()
+        .space(Box::new(
+            Square4::new(ROWS as u32, COLS as u32, EdgeBehavior::Absorb).unwrap(),
+        ))
+        .fields(vec![scalar_field("heat")])
+        .propagators(vec![Box::new(
+            ScalarDiffusion::builder()
+                .input_field(HEAT)
+                .output_field(HEAT)
+                .coefficient(0.2)
+                .build()
+                .unwrap(),
+        )])
+        .initializer(HEAT, |c| if c[0] == 3 && c[1] == 4 { 100.0 } else { 0.0 })
+        .dt(0.1)
+        .seed(7)
+        .build()
+        .unwrap();
+    let mut world = LockstepWorld::new(config).unwrap();
+
+    let mut reference = vec![0.0f32; ROWS * COLS];
+    reference[3 * COLS + 4] = 100.0;
+    for _ in 0..TICKS {
+        world.step_sync(vec![]).unwrap();
+        reference = diffuse_f32(&reference, ROWS, COLS, 0.2, 0.1);
+    }
+
+    let snap = world.snapshot();
+    let heat = snap.read(HEAT).unwrap();
+    let peak = reference.iter().cloned().fold(0.0f32, f32::max);
+    for (i, (&wide, &narrow)) in heat.iter().zip(&reference).enumerate() {
+        // The f32 run loses at most a few ulps of the peak per tick.
+        let tol = Scalar::from(f32::EPSILON * peak) * TICKS as Scalar;
+        assert!(
+            (wide - Scalar::from(narrow)).abs() <= tol,
+            "cell {i}: f64 {wide} vs f32 {narrow}"
+        );
+    }
+}
+
+/// Adds a fixed increment to every cell of its field each tick.
+struct Accumulate {
+    step: Scalar,
+}
+
+impl Propagator for Accumulate {
+    fn name(&self) -> &str {
+        "accumulate"
+    }
+
+    fn reads(&self) -> FieldSet {
+        FieldSet::empty()
+    }
+
+    fn reads_previous(&self) -> FieldSet {
+        [HEAT].into_iter().collect()
+    }
+
+    fn writes(&self) -> Vec<(FieldId, WriteMode)> {
+        vec![(HEAT, WriteMode::Full)]
+    }
+
+    fn step(&self, ctx: &mut StepContext<'_>) -> Result<(), PropagatorError> {
+        let prev = ctx.reads_previous().read(HEAT).unwrap().to_vec();
+        let out = ctx.writes().write(HEAT).unwrap();
+        for (o, p) in out.iter_mut().zip(prev) {
+            *o = p + self.step;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn small_increments_accumulate_on_large_values() {
+    // At 1e4 the f32 ulp is ~1e-3, so an f32 field never moves.
+    assert_eq!(1.0e4f32 + 1.0e-4, 1.0e4f32);
+
+    let config = WorldConfig::builder()
+        .space(Box::new(Line1D::new(4, EdgeBehavior::Absorb).unwrap()))
+        .fields(vec![scalar_field("heat")])
+        .propagators(vec![Box::new(Accumulate { step: 1.0e-4 })])
+        .initializer(HEAT, |_| 1.0e4)
+        .dt(0.1)
+        .seed(7)
+        .build()
+        .unwrap();
+    let mut world = LockstepWorld::new(config).unwrap();
+    for _ in 0..1000 {
+        world.step_sync(vec![]).unwrap();
+    }
+
+    let snap = world.snapshot();
+    for &v in snap.read(HEAT).unwrap() {
+        assert!((v - 1.0e4 - 0.1).abs() < 1e-9, "accumulated {v}");
+    }
+}
@@ -5,7 +5,7 @@
 
 #![allow(deprecated)] // Tests use the old hardcoded field constants intentionally.
 
-use murk_core::FieldReader;
+use murk_core::{FieldReader, Scalar};
 use murk_engine::{LockstepWorld, WorldConfig};
 use murk_propagators::agent_movement::{new_action_buffer, AgentAction, Direction};
 #[allow(deprecated)]
@@ -75,9 +75,9 @@ fn determinism_same_seed_same_output() {
 
         // Collect final state
         let snap = world.snapshot();
-        let heat: Vec<f32> = snap.read(HEAT).unwrap().to_vec();
-        let presence: Vec<f32> = snap.read(AGENT_PRESENCE).unwrap().to_vec();
-        let reward: Vec<f32> = snap.read(REWARD).unwrap().to_vec();
+        let heat: Vec<Scalar> = snap.read(HEAT).unwrap().to_vec();
+        let presence: Vec<Scalar> = snap.read(AGENT_PRESENCE).unwrap().to_vec();
+        let reward: Vec<Scalar> = snap.read(REWARD).unwrap().to_vec();
         (heat, presence, reward)
     };
 
@@ -157,7 +157,7 @@ fn diffusion_convergence() {
     assert!(
         heat.iter().all(|&v| v.abs() < 1e-6),
         "heat should remain zero: max={}",
-        heat.iter().cloned().fold(0.0f32, f32::max)
+        heat.iter().cloned().fold(0.0, Scalar::max)
     );
 }
 
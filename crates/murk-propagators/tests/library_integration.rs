@@ -5,12 +5,15 @@
 //! allocation, command ingress, propagator scheduling, overlay resolution,
 //! and snapshot publication.
 
-use murk_core::{BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldReader, FieldType};
+use murk_core::{
+    BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldReader, FieldType, Scalar,
+};
 use murk_engine::{LockstepWorld, WorldConfig};
+use murk_propagator::{Propagator, PropagatorGroup, RegionRestricted};
 use murk_propagators::{
     FlowField, GradientCompute, IdentityCopy, ScalarDiffusion, WavePropagation,
 };
-use murk_space::{EdgeBehavior, Hex2D, Ring1D, Space, Square4};
+use murk_space::{EdgeBehavior, Hex2D, RegionSpec, Ring1D, Space, Square4, Square8};
 
 // ---------- Field IDs (our own, not the deprecated constants) ----------
 
@@ -95,7 +98,7 @@ fn thousand_tick_stability_scalar_diffusion() {
         // cell and decay + absorb boundaries removing energy, the total should never
         // exceed a reasonable upper bound. A 100-cell grid with source=100 and small
         // decay: worst case every cell reaches ~100, so total < 10_000.
-        let total: f32 = heat.iter().sum();
+        let total: Scalar = heat.iter().sum();
         assert!(
             total < 10_000.0,
             "total energy diverged at tick {tick}: {total}"
@@ -137,7 +140,7 @@ fn determinism_same_seed_same_output() {
         .build()
         .unwrap();
 
-    let run = |seed: u64| -> Vec<f32> {
+    let run = |seed: u64| -> Vec<Scalar> {
         let mut world = LockstepWorld::new(make_config(seed)).unwrap();
         for _ in 0..100 {
             world.step_sync(vec![]).unwrap();
@@ -219,7 +222,7 @@ fn gradient_compute_with_diffusion() {
     // diffusion from a central source, the steepest gradient should be
     // at the front of the heat wave, not at the source itself (where
     // neighbours have similar values).
-    let mut max_grad_mag = 0.0f32;
+    let mut max_grad_mag = 0.0;
     let mut max_grad_cell = 0usize;
     for i in 0..100 {
         let gx = grad[i * 2];
@@ -280,7 +283,7 @@ fn identity_copy_preserves_values() {
     // Step tick 1.
     world.step_sync(vec![]).unwrap();
 
-    let after_tick_1: Vec<f32> = {
+    let after_tick_1: Vec<Scalar> = {
         let snap = world.snapshot();
         snap.read(FieldId(0)).unwrap().to_vec()
     };
@@ -289,7 +292,7 @@ fn identity_copy_preserves_values() {
     assert!(
         after_tick_1.iter().all(|&v| v == 0.0),
         "field should be all zeros after tick 1, max={}",
-        after_tick_1.iter().cloned().fold(0.0f32, f32::max)
+        after_tick_1.iter().cloned().fold(0.0, Scalar::max)
     );
 
     // Run 99 more ticks (total 100 ticks).
@@ -297,7 +300,7 @@ fn identity_copy_preserves_values() {
         world.step_sync(vec![]).unwrap();
     }
 
-    let after_tick_100: Vec<f32> = {
+    let after_tick_100: Vec<Scalar> = {
         let snap = world.snapshot();
         snap.read(FieldId(0)).unwrap().to_vec()
     };
@@ -312,7 +315,7 @@ fn identity_copy_preserves_values() {
     assert!(
         after_tick_100.iter().all(|&v| v == 0.0),
         "field should be all zeros after 100 ticks, max={}",
-        after_tick_100.iter().cloned().fold(0.0f32, f32::max)
+        after_tick_100.iter().cloned().fold(0.0, Scalar::max)
     );
 }
 
@@ -398,7 +401,7 @@ fn combined_pipeline_three_propagators() {
     assert!(
         marker.iter().all(|&v| v == 0.0),
         "marker field should be all zeros, max={}",
-        marker.iter().cloned().fold(0.0f32, f32::max)
+        marker.iter().cloned().fold(0.0, Scalar::max)
     );
 }
 
@@ -592,7 +595,7 @@ fn diffusion_and_gradient_generic_hex2d() {
             let gy = grad[i * 2 + 1];
             (gx * gx + gy * gy).sqrt()
         })
-        .fold(0.0f32, f32::max);
+        .fold(0.0, Scalar::max);
     assert!(
         max_grad_mag > 0.01,
         "gradient should be non-trivial, max magnitude={max_grad_mag}"
@@ -672,3 +675,120 @@ fn flow_field_generic_hex2d() {
         "should have at least one non-zero flow vector"
     );
 }
+
+// ---------- Test 10: ScalarDiffusion + GradientCompute as a PropagatorGroup ----------
+
+/// Run diffusion then gradient for 20 ticks, either as two pipeline stages
+/// or as one group sharing a neighbour cache, and return (heat, gradient).
+fn diffusion_gradient_run(space: Box<dyn Space>, grouped: bool) -> (Vec<Scalar>, Vec<Scalar>) {
+    let members: Vec<Box<dyn Propagator>> = vec![
+        Box::new(
+            ScalarDiffusion::builder()
+                .input_field(HEAT)
+                .output_field(HEAT)
+                .coefficient(0.05)
+                .sources(vec![(0, 80.0)])
+                .build()
+                .unwrap(),
+        ),
+        Box::new(
+            GradientCompute::builder()
+                .input_field(HEAT)
+                .output_field(GRADIENT)
+                .build()
+                .unwrap(),
+        ),
+    ];
+    let propagators: Vec<Box<dyn Propagator>> = if grouped {
+        vec![Box::new(
+            PropagatorGroup::new("diffuse_and_gradient", members).unwrap(),
+        )]
+    } else {
+        members
+    };
+
+    let config = WorldConfig::builder()
+        .space(space)
+        .fields(vec![scalar_field("heat"), vector2_field("gradient")])
+        .propagators(propagators)
+        .dt(0.1)
+        .seed(42)
+        .build()
+        .unwrap();
+    let mut world = LockstepWorld::new(config).unwrap();
+    for _ in 0..20 {
+        world.step_sync(vec![]).unwrap();
+    }
+    let snap = world.snapshot();
+    (
+        snap.read(HEAT).unwrap().to_vec(),
+        snap.read(GRADIENT).unwrap().to_vec(),
+    )
+}
+
+/// Grouping the propagators shares their neighbour enumeration without
+/// changing a single output bit, on both the hex and generic grid paths.
+#[test]
+fn grouped_diffusion_and_gradient_match_separate_stages() {
+    let spaces: [fn() -> Box<dyn Space>; 2] = [
+        || Box::new(Hex2D::new(6, 7).unwrap()),
+        || Box::new(Square8::new(6, 7, EdgeBehavior::Wrap).unwrap()),
+    ];
+    for space in spaces {
+        let separate = diffusion_gradient_run(space(), false);
+        let grouped = diffusion_gradient_run(space(), true);
+        assert!(separate.1.iter().any(|&g| g != 0.0));
+        assert_eq!(separate, grouped);
+    }
+}
+
+// ---------- Region-restricted diffusion ----------
+
+/// Diffusion wrapped to a central rect spreads heat only inside the rect;
+/// every outer cell keeps its initial value, including the ones bordering
+/// the hot region.
+#[test]
+fn region_restricted_diffusion_leaves_outer_cells_frozen() {
+    let rect = RegionSpec::Rect {
+        min: vec![2, 2].into(),
+        max: vec![5, 5].into(),
+    };
+    let inside = |r: i32, c: i32| (2..=5).contains(&r) && (2..=5).contains(&c);
+    let config = WorldConfig::builder()
+        .space(Box::new(Square4::new(8, 8, EdgeBehavior::Absorb).unwrap()))
+        .fields(vec![scalar_field("heat")])
+        .propagators(vec![Box::new(RegionRestricted::new(
+            ScalarDiffusion::builder()
+                .input_field(HEAT)
+                .output_field(HEAT)
+                .coefficient(0.2)
+                .build()
+                .unwrap(),
+            rect,
+        ))])
+        .initializer(HEAT, |c| if c[0] == 3 && c[1] == 3 { 100.0 } else { 1.0 })
+        .dt(0.1)
+        .seed(42)
+        .build()
+        .unwrap();
+    let mut world = LockstepWorld::new(config).unwrap();
+    for _ in 0..20 {
+        world.step_sync(vec![]).unwrap();
+    }
+
+    let snap = world.snapshot();
+    let heat = snap.read(HEAT).unwrap();
+    for r in 0..8 {
+        for c in 0..8 {
+            let v = heat[(r * 8 + c) as usize];
+            if inside(r, c) {
+                if (r, c) != (3, 3) {
+                    assert!(v > 1.0, "cell ({r},{c}) inside the rect did not heat: {v}");
+                }
+            } else {
+                assert_eq!(v, 1.0, "cell ({r},{c}) outside the rect changed");
+            }
+        }
+    }
+    assert!(heat[3 * 8 + 3] < 100.0);
+}
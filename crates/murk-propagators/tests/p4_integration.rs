@@ -4,7 +4,9 @@
 //! resource depletion/regrowth, wave stability, noise determinism,
 //! and morphological mask computation.
 
-use murk_core::{BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldReader, FieldType};
+use murk_core::{
+    BoundaryBehavior, FieldDef, FieldId, FieldMutability, FieldReader, FieldType, Scalar,
+};
 use murk_engine::{LockstepWorld, WorldConfig};
 use murk_propagators::{
     AgentEmission, EmissionMode, FlowField, IdentityCopy, MorphOp, MorphologicalOp, NoiseInjection,
@@ -208,7 +210,7 @@ fn noise_determinism() {
         .build()
         .unwrap();
 
-    let run = |seed: u64| -> Vec<f32> {
+    let run = |seed: u64| -> Vec<Scalar> {
         let mut world = LockstepWorld::new(make_config(seed)).unwrap();
         for _ in 0..100 {
             world.step_sync(vec![]).unwrap();
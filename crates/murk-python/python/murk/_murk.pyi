@@ -61,6 +61,7 @@ class RegionType:
     All: RegionType
     AgentDisk: RegionType
     AgentRect: RegionType
+    PositionChannel: RegionType
     @property
     def value(self) -> int: ...
     def __int__(self) -> int: ...
@@ -91,6 +91,8 @@ pub(crate) enum RegionType {
     AgentDisk = 5,
     /// Rectangular patch around agent center.
     AgentRect = 6,
+    /// Agent position normalized to [0, 1] per axis.
+    PositionChannel = 7,
 }
 
 #[pymethods]
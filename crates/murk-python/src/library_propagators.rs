@@ -19,7 +19,7 @@
 
 use pyo3::prelude::*;
 
-use murk_core::FieldId;
+use murk_core::{FieldId, Scalar};
 use murk_propagator::Propagator;
 
 use crate::config::Config;
@@ -108,14 +108,19 @@ impl PyScalarDiffusion {
             .output_field(FieldId(self.output_field))
             .coefficient(self.coefficient)
             .decay(self.decay)
-            .sources(self.sources.clone())
+            .sources(
+                self.sources
+                    .iter()
+                    .map(|&(cell, v)| (cell, Scalar::from(v)))
+                    .collect(),
+            )
             .max_degree(self.max_degree);
 
         if let Some(lo) = self.clamp_min {
-            builder = builder.clamp_min(lo);
+            builder = builder.clamp_min(Scalar::from(lo));
         }
         if let Some(hi) = self.clamp_max {
-            builder = builder.clamp_max(hi);
+            builder = builder.clamp_max(Scalar::from(hi));
         }
         if let Some(gf) = self.gradient_field {
             builder = builder.gradient_field(FieldId(gf));
@@ -327,7 +332,7 @@ impl PyAgentEmission {
         let prop = murk_propagators::AgentEmission::builder()
             .presence_field(FieldId(self.presence_field))
             .emission_field(FieldId(self.emission_field))
-            .intensity(self.intensity)
+            .intensity(Scalar::from(self.intensity))
             .mode(mode)
             .build()
             .map_err(|e| {
@@ -401,9 +406,9 @@ impl PyResourceField {
         let prop = murk_propagators::ResourceField::builder()
             .field(FieldId(self.field))
             .presence_field(FieldId(self.presence_field))
-            .consumption_rate(self.consumption_rate)
-            .regrowth_rate(self.regrowth_rate)
-            .capacity(self.capacity)
+            .consumption_rate(Scalar::from(self.consumption_rate))
+            .regrowth_rate(Scalar::from(self.regrowth_rate))
+            .capacity(Scalar::from(self.capacity))
             .regrowth_model(model)
             .build()
             .map_err(|e| {
@@ -469,7 +474,7 @@ impl PyMorphologicalOp {
             .output_field(FieldId(self.output_field))
             .op(op)
             .radius(self.radius)
-            .threshold(self.threshold)
+            .threshold(Scalar::from(self.threshold))
             .build()
             .map_err(|e| {
                 pyo3::exceptions::PyValueError::new_err(format!("MorphologicalOp build error: {e}"))
@@ -25,7 +25,7 @@ impl ObsEntry {
     ///
     /// Args:
     ///     field_id: Field index to observe.
-    ///     region_type: RegionType enum (All, AgentDisk, AgentRect).
+    ///     region_type: RegionType enum (All, AgentDisk, AgentRect, PositionChannel).
     ///     transform_type: TransformType enum (Identity, Normalize).
     ///     normalize_min: Lower bound for Normalize transform.
     ///     normalize_max: Upper bound for Normalize transform.
@@ -440,6 +440,19 @@ pub fn serialize_command(cmd: &Command) -> Result<SerializedCommand, ReplayError
             buf.extend_from_slice(&value.to_le_bytes());
             (PAYLOAD_SET_FIELD, buf)
         }
+        CommandPayload::SetFieldBatch { field_id, cells } => {
+            let mut buf = Vec::new();
+            buf.extend_from_slice(&field_id.0.to_le_bytes());
+            let cells_len = u32::try_from(cells.len()).map_err(|_| ReplayError::DataTooLarge {
+                detail: format!("SetFieldBatch count {} exceeds u32::MAX", cells.len()),
+            })?;
+            buf.extend_from_slice(&cells_len.to_le_bytes());
+            for (coord, value) in cells {
+                serialize_coord(&mut buf, coord)?;
+                buf.extend_from_slice(&value.to_le_bytes());
+            }
+            (PAYLOAD_SET_FIELD_BATCH, buf)
+        }
         CommandPayload::Custom { type_id, data } => {
             let mut buf = Vec::new();
             buf.extend_from_slice(&type_id.to_le_bytes());
@@ -483,6 +496,9 @@ pub fn serialize_command(cmd: &Command) -> Result<SerializedCommand, ReplayError
 }
 
 /// Deserialize a [`SerializedCommand`] back into a [`Command`].
+///
+/// Wall-clock deadlines (`Command::expires_at`) are not recorded, so the
+/// result always has `expires_at: None`.
 pub fn deserialize_command(sc: &SerializedCommand) -> Result<Command, ReplayError> {
     let data = &sc.payload;
     let payload = match sc.payload_type {
@@ -556,6 +572,29 @@ pub fn deserialize_command(sc: &SerializedCommand) -> Result<Command, ReplayErro
                 value,
             }
         }
+        PAYLOAD_SET_FIELD_BATCH => {
+            if data.len() < 8 {
+                return Err(ReplayError::MalformedFrame {
+                    detail: "truncated SetFieldBatch header".into(),
+                });
+            }
+            let field_id = FieldId(u32::from_le_bytes(data[0..4].try_into().unwrap()));
+            let count = u32::from_le_bytes(data[4..8].try_into().unwrap()) as usize;
+            let mut offset = 8;
+            let mut cells = Vec::with_capacity(count.min(data.len()));
+            for _ in 0..count {
+                let coord = deserialize_coord(data, &mut offset)?;
+                if offset + 4 > data.len() {
+                    return Err(ReplayError::MalformedFrame {
+                        detail: "truncated SetFieldBatch entry".into(),
+                    });
+                }
+                let value = f32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+                offset += 4;
+                cells.push((coord, value));
+            }
+            CommandPayload::SetFieldBatch { field_id, cells }
+        }
         PAYLOAD_CUSTOM => {
             if data.len() < 8 {
                 return Err(ReplayError::MalformedFrame {
@@ -615,6 +654,7 @@ pub fn deserialize_command(sc: &SerializedCommand) -> Result<Command, ReplayErro
     Ok(Command {
         payload,
         expires_after_tick: TickId(sc.expires_after_tick),
+        expires_at: None,
         source_id: sc.source_id,
         source_seq: sc.source_seq,
         priority_class: sc.priority_class,
@@ -656,6 +696,7 @@ mod tests {
                         target_coord: coord,
                     },
                     expires_after_tick: TickId(eat),
+                    expires_at: None,
                     source_id: sid,
                     source_seq: sseq,
                     priority_class: 1,
@@ -676,6 +717,7 @@ mod tests {
                         field_values: fvs.into_iter().map(|(f, v)| (FieldId(f), v)).collect(),
                     },
                     expires_after_tick: TickId(eat),
+                    expires_at: None,
                     source_id: sid,
                     source_seq: sseq,
                     priority_class: 0,
@@ -692,6 +734,7 @@ mod tests {
                 .prop_map(|(eid, sid, sseq, eat, aseq)| Command {
                     payload: CommandPayload::Despawn { entity_id: eid },
                     expires_after_tick: TickId(eat),
+                    expires_at: None,
                     source_id: sid,
                     source_seq: sseq,
                     priority_class: 1,
@@ -714,6 +757,28 @@ mod tests {
                         value: val,
                     },
                     expires_after_tick: TickId(eat),
+                    expires_at: None,
+                    source_id: sid,
+                    source_seq: sseq,
+                    priority_class: 1,
+                    arrival_seq: aseq,
+                }),
+            // SetFieldBatch
+            (
+                0u32..10,
+                prop::collection::vec((arb_coord(), any::<f32>()), 0..4),
+                arb_opt_u64(),
+                arb_opt_u64(),
+                any::<u64>(),
+                any::<u64>(),
+            )
+                .prop_map(|(fid, cells, sid, sseq, eat, aseq)| Command {
+                    payload: CommandPayload::SetFieldBatch {
+                        field_id: FieldId(fid),
+                        cells,
+                    },
+                    expires_after_tick: TickId(eat),
+                    expires_at: None,
                     source_id: sid,
                     source_seq: sseq,
                     priority_class: 1,
@@ -732,6 +797,7 @@ mod tests {
                     Command {
                         payload: CommandPayload::Custom { type_id: tid, data },
                         expires_after_tick: TickId(eat),
+                        expires_at: None,
                         source_id: sid,
                         source_seq: sseq,
                         priority_class: 1,
@@ -754,6 +820,7 @@ mod tests {
                             value: v,
                         },
                         expires_after_tick: TickId(eat),
+                        expires_at: None,
                         source_id: sid,
                         source_seq: sseq,
                         priority_class: 1,
@@ -776,6 +843,7 @@ mod tests {
                             .collect(),
                     },
                     expires_after_tick: TickId(eat),
+                    expires_at: None,
                     source_id: sid,
                     source_seq: sseq,
                     priority_class: 1,
@@ -942,6 +1010,7 @@ mod tests {
                         value: 2.78,
                     },
                     expires_after_tick: TickId(u64::MAX),
+                    expires_at: None,
                     source_id: Some(1),
                     source_seq: Some(1),
                     priority_class: 1,
@@ -954,6 +1023,7 @@ mod tests {
                         target_coord: Coord::from_slice(&[1, 2]),
                     },
                     expires_after_tick: TickId(500),
+                    expires_at: None,
                     source_id: Some(2),
                     source_seq: Some(3),
                     priority_class: 0,
@@ -1018,6 +1088,7 @@ mod tests {
         let cmd = Command {
             payload: CommandPayload::Despawn { entity_id: 1 },
             expires_after_tick: TickId(u64::MAX),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 1,
@@ -1037,6 +1108,7 @@ mod tests {
         let cmd = Command {
             payload: CommandPayload::Despawn { entity_id: 1 },
             expires_after_tick: TickId(u64::MAX),
+            expires_at: None,
             source_id: Some(0),
             source_seq: Some(0),
             priority_class: 1,
@@ -1282,6 +1354,7 @@ mod tests {
         let cmd = Command {
             payload: CommandPayload::Despawn { entity_id: 1 },
             expires_after_tick: TickId(500),
+            expires_at: None,
             source_id: Some(1),
             source_seq: Some(2),
             priority_class: 1,
@@ -1305,6 +1378,7 @@ mod tests {
                 value: 1.0,
             },
             expires_after_tick: TickId(999),
+            expires_at: None,
             source_id: None,
             source_seq: None,
             priority_class: 0,
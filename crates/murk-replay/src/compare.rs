@@ -6,6 +6,7 @@
 use murk_core::command::Command;
 use murk_core::id::FieldId;
 use murk_core::traits::SnapshotAccess;
+use murk_core::Scalar;
 
 use crate::codec::deserialize_command;
 use crate::error::ReplayError;
@@ -30,14 +31,14 @@ pub struct FieldDivergence {
     pub kind: DivergenceKind,
     /// Which field diverged.
     pub field_id: u32,
-    /// Byte offset within the field's f32 array where divergence starts.
+    /// Byte offset within the field's value array where divergence starts.
     pub byte_offset: usize,
-    /// Cell index (byte_offset / 4) within the field.
+    /// Cell index (`byte_offset / size_of::<Scalar>()`) within the field.
     pub cell_index: usize,
     /// The recorded value (from the original run), if available.
-    pub recorded_value: f32,
+    pub recorded_value: Scalar,
     /// The replayed value (from the current run), if available.
-    pub replayed_value: f32,
+    pub replayed_value: Scalar,
 }
 
 /// Report of all divergences found at a single tick.
@@ -86,7 +87,7 @@ pub fn compare_snapshot(
                             divergences.push(FieldDivergence {
                                 kind: DivergenceKind::ValueMismatch,
                                 field_id: field_idx,
-                                byte_offset: i * 4,
+                                byte_offset: i * std::mem::size_of::<Scalar>(),
                                 cell_index: i,
                                 recorded_value: rv,
                                 replayed_value: pv,
@@ -99,10 +100,10 @@ pub fn compare_snapshot(
                         divergences.push(FieldDivergence {
                             kind: DivergenceKind::LengthMismatch,
                             field_id: field_idx,
-                            byte_offset: rec.len().min(rep.len()) * 4,
+                            byte_offset: rec.len().min(rep.len()) * std::mem::size_of::<Scalar>(),
                             cell_index: rec.len().min(rep.len()),
-                            recorded_value: rec.len() as f32,
-                            replayed_value: rep.len() as f32,
+                            recorded_value: rec.len() as Scalar,
+                            replayed_value: rep.len() as Scalar,
                         });
                     }
                 }
@@ -112,8 +113,8 @@ pub fn compare_snapshot(
                         field_id: field_idx,
                         byte_offset: 0,
                         cell_index: 0,
-                        recorded_value: rec.len() as f32,
-                        replayed_value: f32::NAN,
+                        recorded_value: rec.len() as Scalar,
+                        replayed_value: Scalar::NAN,
                     });
                 }
                 (None, Some(rep)) => {
@@ -122,8 +123,8 @@ pub fn compare_snapshot(
                         field_id: field_idx,
                         byte_offset: 0,
                         cell_index: 0,
-                        recorded_value: f32::NAN,
-                        replayed_value: rep.len() as f32,
+                        recorded_value: Scalar::NAN,
+                        replayed_value: rep.len() as Scalar,
                     });
                 }
                 (None, None) => {}
@@ -182,7 +183,7 @@ mod tests {
     use murk_core::id::{ParameterVersion, TickId, WorldGenerationId};
     use murk_test_utils::MockSnapshot;
 
-    fn make_snapshot(field_data: Vec<(u32, Vec<f32>)>) -> MockSnapshot {
+    fn make_snapshot(field_data: Vec<(u32, Vec<Scalar>)>) -> MockSnapshot {
         let mut snap = MockSnapshot::new(TickId(1), WorldGenerationId(1), ParameterVersion(0));
         for (fid, data) in field_data {
             snap.set_field(FieldId(fid), data);
@@ -281,7 +282,7 @@ mod tests {
         {
             let mut writer = ReplayWriter::new(&mut buf, &meta, &init).unwrap();
             for tick in 1..=3u64 {
-                let snap = make_snapshot(vec![(0, vec![tick as f32; 5])]);
+                let snap = make_snapshot(vec![(0, vec![tick as Scalar; 5])]);
                 writer.write_frame(tick, &[], &snap).unwrap();
             }
         }
@@ -291,7 +292,7 @@ mod tests {
         let mut tick = 0u64;
         let result = replay_and_compare(reader, &mut |_commands| {
             tick += 1;
-            let snap = make_snapshot(vec![(0, vec![tick as f32; 5])]);
+            let snap = make_snapshot(vec![(0, vec![tick as Scalar; 5])]);
             Ok(snapshot_hash(&snap, field_count))
         })
         .unwrap();
@@ -322,7 +323,7 @@ mod tests {
         {
             let mut writer = ReplayWriter::new(&mut buf, &meta, &init).unwrap();
             for tick in 1..=3u64 {
-                let snap = make_snapshot(vec![(0, vec![tick as f32; 5])]);
+                let snap = make_snapshot(vec![(0, vec![tick as Scalar; 5])]);
                 writer.write_frame(tick, &[], &snap).unwrap();
             }
         }
@@ -333,7 +334,7 @@ mod tests {
         let result = replay_and_compare(reader, &mut |_commands| {
             tick += 1;
             // Diverge at tick 2
-            let val = if tick == 2 { 999.0 } else { tick as f32 };
+            let val = if tick == 2 { 999.0 } else { tick as Scalar };
             let snap = make_snapshot(vec![(0, vec![val; 5])]);
             Ok(snapshot_hash(&snap, field_count))
         })
@@ -37,6 +37,12 @@ pub enum ReplayError {
         /// Human-readable description of what exceeded the limit.
         detail: String,
     },
+    /// A field could not be rendered to an image (unsupported space,
+    /// missing field, or non-scalar data).
+    ImageExport {
+        /// Human-readable description of what went wrong.
+        detail: String,
+    },
     /// A snapshot hash does not match between recorded and replayed state.
     SnapshotMismatch {
         /// The tick at which the mismatch was detected.
@@ -61,6 +67,7 @@ impl fmt::Display for ReplayError {
                 write!(f, "unknown payload type tag {tag}")
             }
             Self::DataTooLarge { detail } => write!(f, "data too large: {detail}"),
+            Self::ImageExport { detail } => write!(f, "image export failed: {detail}"),
             Self::ConfigMismatch { recorded, current } => {
                 write!(
                     f,
@@ -0,0 +1,213 @@
+//! Debug image export for 2D field snapshots.
+//!
+//! Renders a scalar field over a rectangular 2D space to a binary PPM
+//! (`P6`) image. PPM needs no image dependency and opens in most viewers,
+//! which makes it handy for eyeballing a diverging replay tick.
+
+use murk_core::id::FieldId;
+use murk_core::traits::SnapshotAccess;
+use murk_core::{scalar_to_f32, Scalar};
+use murk_space::Space;
+
+use crate::error::ReplayError;
+
+/// Colour mapping applied to normalised field values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Colormap {
+    /// Black (minimum) to white (maximum).
+    Grayscale,
+    /// Approximation of matplotlib's viridis: dark purple to yellow.
+    Viridis,
+}
+
+/// Viridis control points, evenly spaced over `[0, 1]`.
+const VIRIDIS: [[f32; 3]; 5] = [
+    [68.0, 1.0, 84.0],
+    [59.0, 82.0, 139.0],
+    [33.0, 145.0, 140.0],
+    [94.0, 201.0, 98.0],
+    [253.0, 231.0, 37.0],
+];
+
+impl Colormap {
+    /// Map a normalised value in `[0, 1]` to an RGB triple.
+    fn rgb(self, t: f32) -> [u8; 3] {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Grayscale => {
+                let v = (t * 255.0).round() as u8;
+                [v, v, v]
+            }
+            Self::Viridis => {
+                let pos = t * (VIRIDIS.len() - 1) as f32;
+                let lo = (pos.floor() as usize).min(VIRIDIS.len() - 2);
+                let frac = pos - lo as f32;
+                let (a, b) = (VIRIDIS[lo], VIRIDIS[lo + 1]);
+                let mut out = [0u8; 3];
+                for (c, o) in out.iter_mut().enumerate() {
+                    *o = (a[c] + (b[c] - a[c]) * frac).round() as u8;
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Render a scalar field from `snapshot` as a binary PPM image.
+///
+/// The space must be two-dimensional with coordinates covering a full
+/// rectangle: the first coordinate axis becomes image rows and the second
+/// becomes columns. Values are normalised by the field's finite min/max
+/// before colour mapping; a constant field maps to the bottom of the
+/// colormap, and non-finite values render black.
+///
+/// # Errors
+///
+/// Returns [`ReplayError::ImageExport`] if the space is not 2D or not
+/// rectangular, the field is absent from the snapshot, or the field is
+/// not scalar (one value per cell).
+pub fn field_to_ppm(
+    snapshot: &dyn SnapshotAccess,
+    field: FieldId,
+    space: &dyn Space,
+    colormap: Colormap,
+) -> Result<Vec<u8>, ReplayError> {
+    let fail = |detail: String| ReplayError::ImageExport { detail };
+
+    if space.ndim() != 2 {
+        return Err(fail(format!(
+            "expected a 2D space, got {} dimensions",
+            space.ndim()
+        )));
+    }
+    let data = snapshot
+        .read_field(field)
+        .ok_or_else(|| fail(format!("field {} not present in snapshot", field.0)))?;
+    let cell_count = space.cell_count();
+    if data.len() != cell_count {
+        return Err(fail(format!(
+            "field {} has {} values for {cell_count} cells; only scalar fields are supported",
+            field.0,
+            data.len()
+        )));
+    }
+
+    let coords = space.canonical_ordering();
+    let (mut row_min, mut col_min) = (i32::MAX, i32::MAX);
+    let (mut row_max, mut col_max) = (i32::MIN, i32::MIN);
+    for c in &coords {
+        row_min = row_min.min(c[0]);
+        row_max = row_max.max(c[0]);
+        col_min = col_min.min(c[1]);
+        col_max = col_max.max(c[1]);
+    }
+    let height = (row_max - row_min + 1).max(0) as usize;
+    let width = (col_max - col_min + 1).max(0) as usize;
+    if width * height != cell_count {
+        return Err(fail(format!(
+            "space coordinates span {height}x{width}, which does not tile {cell_count} cells"
+        )));
+    }
+
+    let (lo, hi) = data
+        .iter()
+        .filter(|v| v.is_finite())
+        .fold((Scalar::INFINITY, Scalar::NEG_INFINITY), |(lo, hi), &v| {
+            (lo.min(v), hi.max(v))
+        });
+    let range = hi - lo;
+
+    let header = format!("P6\n{width} {height}\n255\n");
+    let mut out = Vec::with_capacity(header.len() + cell_count * 3);
+    out.extend_from_slice(header.as_bytes());
+    let pixels_start = out.len();
+    out.resize(pixels_start + cell_count * 3, 0);
+
+    for (c, &v) in coords.iter().zip(data) {
+        if !v.is_finite() {
+            continue;
+        }
+        let t = if range > 0.0 { (v - lo) / range } else { 0.0 };
+        let y = (c[0] - row_min) as usize;
+        let x = (c[1] - col_min) as usize;
+        let at = pixels_start + (y * width + x) * 3;
+        out[at..at + 3].copy_from_slice(&colormap.rgb(scalar_to_f32(t)));
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use murk_core::id::{ParameterVersion, TickId, WorldGenerationId};
+    use murk_space::{EdgeBehavior, Line1D, Square4};
+    use murk_test_utils::MockSnapshot;
+
+    fn ramp_snapshot(n: usize) -> MockSnapshot {
+        let mut snap = MockSnapshot::new(TickId(1), WorldGenerationId(1), ParameterVersion(0));
+        snap.set_field(FieldId(0), (0..n).map(|i| i as Scalar).collect());
+        snap
+    }
+
+    fn pixels(ppm: &[u8], header: &str) -> Vec<[u8; 3]> {
+        assert!(ppm.starts_with(header.as_bytes()));
+        ppm[header.len()..]
+            .chunks_exact(3)
+            .map(|p| [p[0], p[1], p[2]])
+            .collect()
+    }
+
+    #[test]
+    fn header_and_pixel_count() {
+        let space = Square4::new(4, 5, EdgeBehavior::Absorb).unwrap();
+        let snap = ramp_snapshot(20);
+        let ppm = field_to_ppm(&snap, FieldId(0), &space, Colormap::Grayscale).unwrap();
+        let header = "P6\n5 4\n255\n";
+        assert_eq!(ppm.len(), header.len() + 20 * 3);
+        assert_eq!(pixels(&ppm, header).len(), 20);
+    }
+
+    #[test]
+    fn grayscale_ramp_is_monotonic() {
+        let space = Square4::new(4, 5, EdgeBehavior::Absorb).unwrap();
+        let snap = ramp_snapshot(20);
+        let ppm = field_to_ppm(&snap, FieldId(0), &space, Colormap::Grayscale).unwrap();
+        let px = pixels(&ppm, "P6\n5 4\n255\n");
+        assert_eq!(px[0], [0, 0, 0]);
+        assert_eq!(px[19], [255, 255, 255]);
+        for w in px.windows(2) {
+            assert!(w[1][0] > w[0][0], "{:?} !> {:?}", w[1], w[0]);
+        }
+    }
+
+    #[test]
+    fn viridis_ramp_brightens() {
+        let space = Square4::new(4, 5, EdgeBehavior::Absorb).unwrap();
+        let snap = ramp_snapshot(20);
+        let ppm = field_to_ppm(&snap, FieldId(0), &space, Colormap::Viridis).unwrap();
+        let luma = |p: [u8; 3]| 299 * p[0] as u32 + 587 * p[1] as u32 + 114 * p[2] as u32;
+        let px = pixels(&ppm, "P6\n5 4\n255\n");
+        for w in px.windows(2) {
+            assert!(
+                luma(w[1]) >= luma(w[0]),
+                "{:?} darker than {:?}",
+                w[1],
+                w[0]
+            );
+        }
+        assert!(luma(px[19]) > luma(px[0]));
+    }
+
+    #[test]
+    fn non_2d_space_and_missing_field_are_rejected() {
+        let line = Line1D::new(8, EdgeBehavior::Absorb).unwrap();
+        let snap = ramp_snapshot(8);
+        let err = field_to_ppm(&snap, FieldId(0), &line, Colormap::Grayscale).unwrap_err();
+        assert!(matches!(err, ReplayError::ImageExport { .. }));
+
+        let grid = Square4::new(2, 4, EdgeBehavior::Absorb).unwrap();
+        let err = field_to_ppm(&snap, FieldId(9), &grid, Colormap::Grayscale).unwrap_err();
+        assert!(matches!(err, ReplayError::ImageExport { .. }));
+    }
+}
@@ -9,6 +9,7 @@
 //! - [`ReplayWriter`] records frames to any `Write` sink
 //! - [`ReplayReader`] plays back frames from any `Read` source
 //! - [`compare_snapshot`] and [`replay_and_compare`] verify determinism
+//! - [`field_to_ppm`] renders a 2D field to a PPM image for debugging
 //! - All I/O uses a custom binary codec (no serde dependency)
 //!
 //! # Format
@@ -29,6 +30,7 @@ pub mod codec;
 pub mod compare;
 pub mod error;
 pub mod hash;
+pub mod image;
 pub mod reader;
 pub mod types;
 pub mod writer;
@@ -38,6 +40,7 @@ pub use compare::{
 };
 pub use error::ReplayError;
 pub use hash::{config_hash, snapshot_hash};
+pub use image::{field_to_ppm, Colormap};
 pub use reader::{FrameIter, ReplayReader};
 pub use types::{BuildMetadata, Frame, InitDescriptor, SerializedCommand};
 pub use writer::ReplayWriter;
@@ -130,6 +130,7 @@ mod tests {
     use crate::writer::ReplayWriter;
     use murk_core::command::{Command, CommandPayload};
     use murk_core::id::{ParameterKey, ParameterVersion, TickId, WorldGenerationId};
+    use murk_core::Scalar;
     use murk_test_utils::MockSnapshot;
 
     fn test_metadata() -> BuildMetadata {
@@ -151,7 +152,7 @@ mod tests {
         }
     }
 
-    fn make_snapshot(tick: u64, data: Vec<f32>) -> MockSnapshot {
+    fn make_snapshot(tick: u64, data: Vec<Scalar>) -> MockSnapshot {
         let mut snap =
             MockSnapshot::new(TickId(tick), WorldGenerationId(tick), ParameterVersion(0));
         snap.set_field(murk_core::FieldId(0), data);
@@ -168,7 +169,7 @@ mod tests {
         {
             let mut writer = ReplayWriter::new(&mut buf, &meta, &init).unwrap();
             for tick in 1..=5u64 {
-                let snap = make_snapshot(tick, vec![tick as f32; 10]);
+                let snap = make_snapshot(tick, vec![tick as Scalar; 10]);
                 writer.write_frame(tick, &[], &snap).unwrap();
             }
             assert_eq!(writer.frames_written(), 5);
@@ -199,6 +200,7 @@ mod tests {
                 value: 1.5,
             },
             expires_after_tick: TickId(u64::MAX),
+            expires_at: None,
             source_id: Some(1),
             source_seq: Some(1),
             priority_class: 1,
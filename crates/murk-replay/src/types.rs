@@ -134,3 +134,5 @@ pub const PAYLOAD_CUSTOM: u8 = 4;
 pub const PAYLOAD_SET_PARAMETER: u8 = 5;
 /// Payload type tag for `CommandPayload::SetParameterBatch`.
 pub const PAYLOAD_SET_PARAMETER_BATCH: u8 = 6;
+/// Payload type tag for `CommandPayload::SetFieldBatch`.
+pub const PAYLOAD_SET_FIELD_BATCH: u8 = 7;
@@ -8,7 +8,7 @@
 
 use murk_core::command::{Command, CommandPayload};
 use murk_core::id::{Coord, FieldId, ParameterKey, TickId};
-use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType};
+use murk_core::{BoundaryBehavior, FieldDef, FieldMutability, FieldType, Scalar};
 use murk_engine::{LockstepWorld, WorldConfig};
 use murk_propagators::agent_movement::{
     new_action_buffer, ActionBuffer, AgentAction, AgentMovementPropagator, Direction,
@@ -196,6 +196,7 @@ fn determinism_multi_source_ordering() {
                         value: (tick * source + seq) as f64,
                     },
                     expires_after_tick: TickId(tick + 100),
+                    expires_at: None,
                     source_id: Some(source),
                     source_seq: Some(tick * 10 + seq),
                     priority_class: (source % 3) as u8,
@@ -385,6 +386,7 @@ fn determinism_sparse_field() {
                     value: tick as f32,
                 },
                 expires_after_tick: TickId(tick + 100),
+                expires_at: None,
                 source_id: Some(1),
                 source_seq: Some(tick),
                 priority_class: 1,
@@ -539,6 +541,7 @@ fn determinism_global_parameter() {
                     value: tick as f64 * 0.001,
                 },
                 expires_after_tick: TickId(tick + 100),
+                expires_at: None,
                 source_id: Some(1),
                 source_seq: Some(tick),
                 priority_class: 0,
@@ -555,6 +558,7 @@ fn determinism_global_parameter() {
                     ],
                 },
                 expires_after_tick: TickId(tick + 100),
+                expires_at: None,
                 source_id: Some(2),
                 source_seq: Some(tick),
                 priority_class: 0,
@@ -611,7 +615,7 @@ fn determinism_large_pipeline() {
             propagators.push(Box::new(ConstPropagator::new(
                 format!("const_{i}"),
                 FieldId(i as u32),
-                (i + 1) as f32,
+                (i + 1) as Scalar,
             )));
         }
         // Second half: IdentityPropagators copying from first half
@@ -661,3 +665,15 @@ fn determinism_large_pipeline() {
     let world_replay = LockstepWorld::new(make_config()).unwrap();
     verify_replay(&buf, world_replay, field_count);
 }
+
+/// `LockstepWorld::field_hash` is `snapshot_hash` scoped to one field, so
+/// CI can gate on a single field mid-run and compare against recordings.
+#[test]
+fn field_hash_matches_single_field_snapshot_hash() {
+    let mut world = LockstepWorld::new(reference_config(7, new_action_buffer())).unwrap();
+    for _ in 0..20 {
+        world.step_sync(vec![]).unwrap();
+    }
+    let expected = snapshot_hash(&world.snapshot(), 1);
+    assert_eq!(world.field_hash(FieldId(0)), Some(expected));
+}
@@ -0,0 +1,280 @@
+//! Memoization of compiled region plans.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use indexmap::IndexMap;
+use murk_core::SpaceInstanceId;
+
+use crate::error::SpaceError;
+use crate::region::{RegionPlan, RegionSpec};
+use crate::space::Space;
+
+/// Identity of the space a plan was compiled against.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum SpaceKey {
+    /// [`Space::topology_digest`], with the cell count as a collision guard.
+    Topology { digest: u64, cell_count: usize },
+    /// Spaces without a digest only share plans with their own clones.
+    Instance(SpaceInstanceId),
+}
+
+impl SpaceKey {
+    fn of(space: &dyn Space) -> Self {
+        match space.topology_digest() {
+            Some(digest) => SpaceKey::Topology {
+                digest,
+                cell_count: space.cell_count(),
+            },
+            None => SpaceKey::Instance(space.instance_id()),
+        }
+    }
+}
+
+type PlanMap = IndexMap<(SpaceKey, RegionSpec), Arc<RegionPlan>>;
+
+/// Cache of compiled [`RegionPlan`]s keyed by space topology and [`RegionSpec`].
+///
+/// Compiling the same region against identical spaces (for example, once
+/// per environment in a vectorized batch sharing one topology) returns a
+/// shared `Arc<RegionPlan>` instead of recompiling. Entries are keyed by
+/// [`Space::topology_digest`], so distinct spaces with equal topology share
+/// plans; spaces without a digest fall back to [`Space::instance_id`] and
+/// share only with their clones. Compile errors are not cached.
+///
+/// The cache holds at most [`capacity`](Self::capacity) plans and evicts
+/// the least recently used one when full. It is internally synchronized
+/// and can be shared across threads.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use murk_space::{EdgeBehavior, RegionPlanCache, RegionSpec, Square4};
+///
+/// let space = Square4::new(8, 8, EdgeBehavior::Absorb).unwrap();
+/// let cache = RegionPlanCache::new();
+///
+/// let a = cache.get_or_compile(&space, &RegionSpec::All).unwrap();
+/// let b = cache.get_or_compile(&space, &RegionSpec::All).unwrap();
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct RegionPlanCache {
+    plans: Mutex<PlanMap>,
+    capacity: usize,
+}
+
+impl Default for RegionPlanCache {
+    fn default() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl RegionPlanCache {
+    /// Capacity of a cache created with [`new`](Self::new).
+    pub const DEFAULT_CAPACITY: usize = 256;
+
+    /// Create an empty cache holding at most
+    /// [`DEFAULT_CAPACITY`](Self::DEFAULT_CAPACITY) plans.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty cache holding at most `capacity` plans.
+    ///
+    /// A capacity of 0 disables caching: every lookup compiles.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            plans: Mutex::new(IndexMap::new()),
+            capacity,
+        }
+    }
+
+    /// Maximum number of cached plans.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Return the cached plan for `spec` on `space`, compiling it on a miss.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any [`SpaceError`] from [`Space::compile_region`].
+    pub fn get_or_compile(
+        &self,
+        space: &dyn Space,
+        spec: &RegionSpec,
+    ) -> Result<Arc<RegionPlan>, SpaceError> {
+        let key = (SpaceKey::of(space), spec.clone());
+        {
+            let mut plans = self.lock();
+            if let Some(index) = plans.get_index_of(&key) {
+                // Most recently used entries live at the back.
+                let last = plans.len() - 1;
+                plans.move_index(index, last);
+                return Ok(Arc::clone(&plans[last]));
+            }
+        }
+        // Compile outside the lock; if another thread raced us, keep
+        // whichever plan was inserted first so callers share one Arc.
+        let plan = Arc::new(space.compile_region(spec)?);
+        if self.capacity == 0 {
+            return Ok(plan);
+        }
+        let mut plans = self.lock();
+        if let Some(existing) = plans.get(&key) {
+            return Ok(Arc::clone(existing));
+        }
+        if plans.len() >= self.capacity {
+            plans.shift_remove_index(0);
+        }
+        plans.insert(key, Arc::clone(&plan));
+        Ok(plan)
+    }
+
+    /// Number of cached plans.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether the cache holds no plans.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drop all cached plans.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, PlanMap> {
+        // A poisoned lock only means another thread panicked mid-insert;
+        // the map itself is still a valid set of compiled plans.
+        self.plans.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EdgeBehavior, Line1D, Square4};
+    use murk_core::Coord;
+
+    fn grid() -> Square4 {
+        Square4::new(10, 10, EdgeBehavior::Absorb).unwrap()
+    }
+
+    #[test]
+    fn same_spec_returns_same_plan() {
+        let space = grid();
+        let cache = RegionPlanCache::new();
+        let spec = RegionSpec::Disk {
+            center: Coord::from_slice(&[5, 5]),
+            radius: 2,
+        };
+        let a = cache.get_or_compile(&space, &spec).unwrap();
+        let b = cache.get_or_compile(&space, &spec).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn differing_spec_misses() {
+        let space = grid();
+        let cache = RegionPlanCache::new();
+        let a = cache
+            .get_or_compile(
+                &space,
+                &RegionSpec::Disk {
+                    center: Coord::from_slice(&[5, 5]),
+                    radius: 2,
+                },
+            )
+            .unwrap();
+        let b = cache
+            .get_or_compile(
+                &space,
+                &RegionSpec::Disk {
+                    center: Coord::from_slice(&[5, 5]),
+                    radius: 3,
+                },
+            )
+            .unwrap();
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert_ne!(a.cell_count(), b.cell_count());
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn distinct_spaces_with_equal_topology_share_plans() {
+        let (s1, s2) = (grid(), grid());
+        assert_ne!(s1.instance_id(), s2.instance_id());
+        let cache = RegionPlanCache::new();
+        let a = cache.get_or_compile(&s1, &RegionSpec::All).unwrap();
+        let b = cache.get_or_compile(&s2, &RegionSpec::All).unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn differing_topology_misses() {
+        let cache = RegionPlanCache::new();
+        let spec = RegionSpec::Disk {
+            center: Coord::from_slice(&[0, 0]),
+            radius: 1,
+        };
+        let absorb = cache.get_or_compile(&grid(), &spec).unwrap();
+        let wrap = Square4::new(10, 10, EdgeBehavior::Wrap).unwrap();
+        let wrapped = cache.get_or_compile(&wrap, &spec).unwrap();
+        assert!(!Arc::ptr_eq(&absorb, &wrapped));
+        assert_ne!(absorb.cell_count(), wrapped.cell_count());
+
+        let line = Line1D::new(100, EdgeBehavior::Absorb).unwrap();
+        let all = cache.get_or_compile(&grid(), &RegionSpec::All).unwrap();
+        let line_all = cache.get_or_compile(&line, &RegionSpec::All).unwrap();
+        assert!(!Arc::ptr_eq(&all, &line_all));
+        assert_eq!(cache.len(), 4);
+    }
+
+    #[test]
+    fn full_cache_evicts_least_recently_used() {
+        let space = grid();
+        let cache = RegionPlanCache::with_capacity(2);
+        let disk = |radius| RegionSpec::Disk {
+            center: Coord::from_slice(&[5, 5]),
+            radius,
+        };
+        let r1 = cache.get_or_compile(&space, &disk(1)).unwrap();
+        let r2 = cache.get_or_compile(&space, &disk(2)).unwrap();
+        // Touch radius 1 so radius 2 becomes the eviction candidate.
+        let again = cache.get_or_compile(&space, &disk(1)).unwrap();
+        assert!(Arc::ptr_eq(&r1, &again));
+        cache.get_or_compile(&space, &disk(3)).unwrap();
+        assert_eq!(cache.len(), 2);
+
+        let kept = cache.get_or_compile(&space, &disk(1)).unwrap();
+        assert!(Arc::ptr_eq(&r1, &kept));
+        let recompiled = cache.get_or_compile(&space, &disk(2)).unwrap();
+        assert!(!Arc::ptr_eq(&r2, &recompiled));
+    }
+
+    #[test]
+    fn zero_capacity_never_caches() {
+        let space = grid();
+        let cache = RegionPlanCache::with_capacity(0);
+        let a = cache.get_or_compile(&space, &RegionSpec::All).unwrap();
+        let b = cache.get_or_compile(&space, &RegionSpec::All).unwrap();
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let space = grid();
+        let cache = RegionPlanCache::new();
+        let bad = RegionSpec::Coords(vec![Coord::from_slice(&[99, 99])]);
+        assert!(cache.get_or_compile(&space, &bad).is_err());
+        assert!(cache.is_empty());
+    }
+}
@@ -255,6 +255,132 @@ pub fn assert_distance_rejects_wrong_arity(space: &dyn Space) {
     }
 }
 
+/// Assert that `neighbours_into` reproduces `neighbours` exactly, including
+/// order, when one buffer is reused across every cell.
+pub fn assert_neighbours_into_matches(space: &dyn Space) {
+    let mut buf = smallvec::SmallVec::new();
+    // Seed with stale contents to check the buffer is cleared.
+    buf.push(smallvec::smallvec![i32::MIN; space.ndim()]);
+    for coord in space.canonical_ordering() {
+        space.neighbours_into(&coord, &mut buf);
+        let expected = space.neighbours(&coord);
+        assert_eq!(
+            buf.as_slice(),
+            expected.as_slice(),
+            "neighbours_into({coord:?}) differs from neighbours()"
+        );
+    }
+}
+
+/// Assert that `canonical_rank` and `canonical_rank_slice` invert
+/// `canonical_ordering`.
+pub fn assert_canonical_rank_matches_ordering(space: &dyn Space) {
+    for (i, coord) in space.canonical_ordering().iter().enumerate() {
+        assert_eq!(
+            space.canonical_rank(coord),
+            Some(i),
+            "canonical_rank({coord:?}) != position in canonical_ordering"
+        );
+        assert_eq!(
+            space.canonical_rank_slice(coord),
+            Some(i),
+            "canonical_rank_slice({coord:?}) != position in canonical_ordering"
+        );
+    }
+}
+
+/// Assert that `compile_regions` matches per-spec `compile_region` calls
+/// and propagates errors.
+pub fn assert_compile_regions_matches_single(space: &dyn Space) {
+    let ordering = space.canonical_ordering();
+    let first = ordering[0].clone();
+    let mid = ordering[ordering.len() / 2].clone();
+    let last = ordering[ordering.len() - 1].clone();
+    let candidates = vec![
+        RegionSpec::All,
+        RegionSpec::Disk {
+            center: mid.clone(),
+            radius: 1,
+        },
+        RegionSpec::Rect {
+            min: first.clone(),
+            max: mid.clone(),
+        },
+        RegionSpec::All,
+        RegionSpec::Neighbours {
+            center: last.clone(),
+            depth: 2,
+        },
+        RegionSpec::Coords(vec![last, first.clone(), mid, first]),
+    ];
+    // Rect corners are not valid on every backend; keep specs that compile.
+    let (specs, expected): (Vec<_>, Vec<_>) = candidates
+        .into_iter()
+        .filter_map(|spec| space.compile_region(&spec).ok().map(|plan| (spec, plan)))
+        .unzip();
+    let batched = space
+        .compile_regions(&specs)
+        .expect("compile_regions should succeed when every spec compiles");
+    assert_eq!(
+        batched, expected,
+        "compile_regions differs from compile_region"
+    );
+
+    let mut bad = specs;
+    bad.push(RegionSpec::Coords(vec![
+        smallvec::smallvec![i32::MIN; space.ndim()],
+    ]));
+    assert!(
+        space.compile_regions(&bad).is_err(),
+        "compile_regions must propagate compile errors"
+    );
+}
+
+/// Assert that `region_coverage` matches `compile_region(..).valid_ratio()`,
+/// including failing exactly when compilation fails.
+pub fn assert_region_coverage_matches_plan(space: &dyn Space) {
+    let ordering = space.canonical_ordering();
+    let first = ordering[0].clone();
+    let mid = ordering[ordering.len() / 2].clone();
+    let last = ordering[ordering.len() - 1].clone();
+    let mut specs = vec![
+        RegionSpec::All,
+        RegionSpec::Rect {
+            min: first.clone(),
+            max: mid.clone(),
+        },
+        RegionSpec::Coords(vec![last.clone(), first.clone()]),
+        RegionSpec::Coords(vec![smallvec::smallvec![i32::MIN; space.ndim()]]),
+    ];
+    for radius in [0, 1, 3, 1000] {
+        for center in [&first, &mid, &last] {
+            specs.push(RegionSpec::Disk {
+                center: center.clone(),
+                radius,
+            });
+        }
+        specs.push(RegionSpec::Neighbours {
+            center: mid.clone(),
+            depth: radius,
+        });
+    }
+    for spec in &specs {
+        match (space.compile_region(spec), space.region_coverage(spec)) {
+            (Ok(plan), Ok(coverage)) => assert_eq!(
+                coverage,
+                plan.valid_ratio(),
+                "region_coverage differs from valid_ratio for {spec:?}"
+            ),
+            (Err(_), Err(_)) => {}
+            (plan, coverage) => panic!(
+                "region_coverage and compile_region disagree on {spec:?}: \
+                 compile ok = {}, coverage = {coverage:?}",
+                plan.is_ok()
+            ),
+        }
+    }
+}
+
 /// Run all compliance checks on a space.
 pub fn run_full_compliance(space: &dyn Space) {
     assert_distance_reflexive(space);
@@ -263,10 +389,14 @@ pub fn run_full_compliance(space: &dyn Space) {
     assert_neighbours_symmetric(space);
     assert_canonical_ordering_deterministic(space);
     assert_canonical_ordering_complete(space);
+    assert_canonical_rank_matches_ordering(space);
     assert_compile_region_all_valid_ratio(space);
     assert_compile_region_all_covers_all(space);
+    assert_compile_regions_matches_single(space);
+    assert_region_coverage_matches_plan(space);
     assert_ndim_consistent(space);
     assert_neighbours_returns_valid_coords(space);
+    assert_neighbours_into_matches(space);
     #[cfg(debug_assertions)]
     {
         assert_neighbours_rejects_wrong_arity(space);
@@ -13,11 +13,23 @@ pub enum SpaceError {
         /// Human-readable description of the valid range.
         bounds: String,
     },
+    /// A coordinate (or region corner) has the wrong number of dimensions.
+    DimensionMismatch {
+        /// Dimensionality of the space.
+        expected: usize,
+        /// Dimensionality of the offending coordinate.
+        got: usize,
+    },
     /// A region specification is invalid for this space.
     InvalidRegion {
         /// What went wrong.
         reason: String,
     },
+    /// The space does not support this kind of region.
+    UnsupportedRegion {
+        /// The rejected [`RegionSpec`](crate::RegionSpec) variant name.
+        kind: &'static str,
+    },
     /// Attempted to construct a space with zero cells.
     EmptySpace,
     /// A dimension exceeds the representable coordinate range.
@@ -42,9 +54,15 @@ impl fmt::Display for SpaceError {
             Self::CoordOutOfBounds { coord, bounds } => {
                 write!(f, "coordinate {coord:?} out of bounds: {bounds}")
             }
+            Self::DimensionMismatch { expected, got } => {
+                write!(f, "expected {expected}D coordinate, got {got}D")
+            }
             Self::InvalidRegion { reason } => {
                 write!(f, "invalid region: {reason}")
             }
+            Self::UnsupportedRegion { kind } => {
+                write!(f, "region kind {kind} is not supported by this space")
+            }
             Self::EmptySpace => write!(f, "space must have at least one cell"),
             Self::DimensionTooLarge { name, value, max } => {
                 write!(f, "{name} ({value}) exceeds maximum ({max})")
@@ -29,9 +29,9 @@
 use crate::edge::EdgeBehavior;
 use crate::error::SpaceError;
 use crate::region::{BoundingShape, RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use murk_core::{Coord, SpaceInstanceId};
-use smallvec::{smallvec, SmallVec};
+use smallvec::{smallvec, Array, SmallVec};
 use std::collections::VecDeque;
 
 /// All 12 FCC neighbour offsets: permutations of `(±1, ±1, 0)`.
@@ -150,9 +150,9 @@ impl Fcc12 {
     /// Check that a coordinate is in-bounds, has valid parity, and return `(x, y, z)`.
     fn check_bounds(&self, coord: &Coord) -> Result<(i32, i32, i32), SpaceError> {
         if coord.len() != 3 {
-            return Err(SpaceError::CoordOutOfBounds {
-                coord: coord.clone(),
-                bounds: format!("expected 3D coordinate, got {}D", coord.len()),
+            return Err(SpaceError::DimensionMismatch {
+                expected: 3,
+                got: coord.len(),
             });
         }
         let (x, y, z) = (coord[0], coord[1], coord[2]);
@@ -175,6 +175,24 @@ impl Fcc12 {
         Ok((x, y, z))
     }
 
+    /// Append the valid FCC neighbours of `coord` to `out`.
+    fn push_neighbours<A: Array<Item = Coord>>(&self, coord: &Coord, out: &mut SmallVec<A>) {
+        let (x, y, z) = (coord[0], coord[1], coord[2]);
+        for (dx, dy, dz) in FCC_OFFSETS {
+            let (nx, x_clamped) = resolve_axis_fcc(x + dx, self.w, self.edge);
+            let (ny, y_clamped) = resolve_axis_fcc(y + dy, self.h, self.edge);
+            let (nz, z_clamped) = resolve_axis_fcc(z + dz, self.d, self.edge);
+            // Drop the move if any axis was absorbed OR clamped.
+            // Clamping cancels one of the two ±1 changes, breaking parity.
+            match (nx, ny, nz) {
+                (Some(nx), Some(ny), Some(nz)) if !(x_clamped || y_clamped || z_clamped) => {
+                    out.push(smallvec![nx, ny, nz]);
+                }
+                _ => {}
+            }
+        }
+    }
+
     /// BFS-based disk compilation for FCC lattice.
     fn compile_fcc_disk(&self, cx: i32, cy: i32, cz: i32, radius: u32) -> RegionPlan {
         let mut visited = vec![false; self.cell_count];
@@ -234,24 +252,20 @@ impl Space for Fcc12 {
             coord.len(), self.ndim(),
             "coord arity {}, expected {}", coord.len(), self.ndim()
         );
-        let (x, y, z) = (coord[0], coord[1], coord[2]);
         let mut result = SmallVec::new();
-        for (dx, dy, dz) in FCC_OFFSETS {
-            let (nx, x_clamped) = resolve_axis_fcc(x + dx, self.w, self.edge);
-            let (ny, y_clamped) = resolve_axis_fcc(y + dy, self.h, self.edge);
-            let (nz, z_clamped) = resolve_axis_fcc(z + dz, self.d, self.edge);
-            // Drop the move if any axis was absorbed OR clamped.
-            // Clamping cancels one of the two ±1 changes, breaking parity.
-            match (nx, ny, nz) {
-                (Some(nx), Some(ny), Some(nz)) if !(x_clamped || y_clamped || z_clamped) => {
-                    result.push(smallvec![nx, ny, nz]);
-                }
-                _ => {}
-            }
-        }
+        self.push_neighbours(coord, &mut result);
         result
     }
 
+    fn neighbours_into(&self, coord: &Coord, out: &mut SmallVec<[Coord; 12]>) {
+        debug_assert_eq!(
+            coord.len(), self.ndim(),
+            "coord arity {}, expected {}", coord.len(), self.ndim()
+        );
+        out.clear();
+        self.push_neighbours(coord, out);
+    }
+
     fn max_neighbour_degree(&self) -> usize {
         match self.edge {
             EdgeBehavior::Clamp | EdgeBehavior::Wrap => 12,
@@ -361,6 +375,7 @@ impl Space for Fcc12 {
                     bounding_shape: BoundingShape::Rect(vec![cell_count]),
                 })
             }
+            RegionSpec::Product(_) => Err(SpaceError::UnsupportedRegion { kind: spec.kind() }),
         }
     }
 
@@ -452,6 +467,12 @@ impl Space for Fcc12 {
             .downcast_ref::<Self>()
             .is_some_and(|o| self.w == o.w && self.h == o.h && self.d == o.d && self.edge == o.edge)
     }
+
+    fn topology_digest(&self) -> Option<u64> {
+        Some(topology_digest_of::<Self, _>((
+            self.w, self.h, self.d, self.edge,
+        )))
+    }
 }
 
 // ── Private helpers ──────────────────────────────────────────────
@@ -696,6 +717,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn neighbours_into_interior_stays_inline() {
+        let s = Fcc12::new(6, 6, 6, EdgeBehavior::Absorb).unwrap();
+        let mut buf = SmallVec::new();
+        s.neighbours_into(&c(2, 2, 2), &mut buf);
+        assert_eq!(buf.len(), 12);
+        assert!(!buf.spilled(), "12 neighbours must fit the inline buffer");
+        assert_eq!(buf.as_slice(), s.neighbours(&c(2, 2, 2)).as_slice());
+    }
+
     #[test]
     fn neighbours_corner_origin() {
         let s = Fcc12::new(4, 4, 4, EdgeBehavior::Absorb).unwrap();
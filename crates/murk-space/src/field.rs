@@ -0,0 +1,283 @@
+//! Read helpers over field buffers laid out in a space's canonical order.
+//!
+//! These work on the plain `&[Scalar]` a snapshot hands out through
+//! `FieldReader::read`, so they apply to any snapshot type.
+
+use murk_core::{scalar_to_f64, Scalar};
+
+use crate::edge::EdgeBehavior;
+use crate::region::RegionPlan;
+use crate::space::Space;
+use crate::square4::Square4;
+use crate::square8::Square8;
+
+/// Largest position magnitude [`sample_bilinear`] accepts on either axis.
+const MAX_SAMPLE_POS: f64 = i32::MAX as f64;
+
+/// Bilinearly interpolate a scalar field at a continuous grid position.
+///
+/// `data` holds one value per cell in `space`'s canonical order. `pos` is
+/// `[row, col]` in cell units, with cell `(r, c)` centred on `[r, c]`, so
+/// integer positions return that cell's value exactly. The four
+/// surrounding cells are resolved through the grid's [`EdgeBehavior`]:
+/// `Wrap` samples the opposite side, `Clamp` repeats the edge cell, and
+/// `Absorb` drops out-of-grid cells and renormalises the remaining weights.
+///
+/// Returns `None` unless `space` is a [`Square4`] or [`Square8`] grid and
+/// `data` has one value per cell, or if `pos` is not a finite 2D position
+/// within `i32` range with at least one in-grid neighbour under `Absorb`.
+///
+/// # Examples
+///
+/// ```
+/// use murk_space::{sample_bilinear, EdgeBehavior, Square4};
+///
+/// let grid = Square4::new(1, 2, EdgeBehavior::Absorb).unwrap();
+/// assert_eq!(sample_bilinear(&[0.0, 4.0], &[0.0, 0.25], &grid), Some(1.0));
+/// ```
+pub fn sample_bilinear(data: &[Scalar], pos: &[f32], space: &dyn Space) -> Option<Scalar> {
+    let (rows, cols, edges) = if let Some(g) = space.downcast_ref::<Square4>() {
+        (g.rows(), g.cols(), g.edge_behaviors())
+    } else if let Some(g) = space.downcast_ref::<Square8>() {
+        (g.rows(), g.cols(), g.edge_behaviors())
+    } else {
+        return None;
+    };
+    if pos.len() != 2 || data.len() != space.cell_count() {
+        return None;
+    }
+    let (r, c) = (pos[0] as f64, pos[1] as f64);
+    // Rejects NaN and infinities too; keeps `floor + 1` inside `i64`.
+    if !(r.abs() <= MAX_SAMPLE_POS && c.abs() <= MAX_SAMPLE_POS) {
+        return None;
+    }
+
+    // Resolve one axis index under its edge behavior; `None` = dropped.
+    let resolve = |i: i64, n: u32, edge: EdgeBehavior| -> Option<i32> {
+        let n = n as i64;
+        match edge {
+            EdgeBehavior::Wrap => Some(i.rem_euclid(n) as i32),
+            EdgeBehavior::Clamp => Some(i.clamp(0, n - 1) as i32),
+            EdgeBehavior::Absorb => (0..n).contains(&i).then_some(i as i32),
+        }
+    };
+
+    let (r0, c0) = (r.floor(), c.floor());
+    let (tr, tc) = (r - r0, c - c0);
+    let (r0, c0) = (r0 as i64, c0 as i64);
+    let (mut sum, mut weight) = (0.0f64, 0.0f64);
+    for (dr, wr) in [(0, 1.0 - tr), (1, tr)] {
+        for (dc, wc) in [(0, 1.0 - tc), (1, tc)] {
+            let w = wr * wc;
+            if w == 0.0 {
+                continue;
+            }
+            let (Some(ri), Some(ci)) = (
+                resolve(r0 + dr, rows, edges[0]),
+                resolve(c0 + dc, cols, edges[1]),
+            ) else {
+                continue;
+            };
+            let rank = space.canonical_rank_slice(&[ri, ci])?;
+            sum += w * scalar_to_f64(data[rank]);
+            weight += w;
+        }
+    }
+    (weight > 0.0).then(|| (sum / weight) as Scalar)
+}
+
+/// A borrowed, read-only view of one field restricted to a region.
+///
+/// Nothing is copied: iteration resolves each region cell to its
+/// canonical rank and yields a slice of the underlying field buffer.
+/// Cells outside the region are never visited.
+///
+/// # Examples
+///
+/// ```
+/// use murk_core::Coord;
+/// use murk_space::{EdgeBehavior, FieldView, RegionSpec, Space, Square4};
+///
+/// let grid = Square4::new(2, 2, EdgeBehavior::Absorb).unwrap();
+/// let region = grid
+///     .compile_region(&RegionSpec::Coords(vec![Coord::from_slice(&[1, 0])]))
+///     .unwrap();
+/// let data = [0.0, 1.0, 2.0, 3.0];
+/// let view = FieldView::new(&data, &region, &grid).unwrap();
+/// let cells: Vec<_> = view.iter().collect();
+/// assert_eq!(cells, vec![(2, &data[2..3])]);
+/// ```
+pub struct FieldView<'v> {
+    data: &'v [Scalar],
+    components: usize,
+    region: &'v RegionPlan,
+    space: &'v dyn Space,
+}
+
+impl<'v> FieldView<'v> {
+    /// View `data` restricted to the cells of `region`.
+    ///
+    /// `data` holds the field's interleaved values in `space`'s canonical
+    /// order, and `region` must have been compiled against `space`. The
+    /// component count is `data.len() / space.cell_count()`; returns
+    /// `None` if that is not a positive whole number.
+    pub fn new(data: &'v [Scalar], region: &'v RegionPlan, space: &'v dyn Space) -> Option<Self> {
+        let cells = space.cell_count();
+        if cells == 0 || data.is_empty() || !data.len().is_multiple_of(cells) {
+            return None;
+        }
+        Some(Self {
+            data,
+            components: data.len() / cells,
+            region,
+            space,
+        })
+    }
+
+    /// Number of cells in the region.
+    pub fn len(&self) -> usize {
+        self.region.cell_count()
+    }
+
+    /// Whether the region contains no cells.
+    pub fn is_empty(&self) -> bool {
+        self.region.cell_count() == 0
+    }
+
+    /// Values per cell (1 for scalars).
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Iterate over the region's cells as `(rank, values)`.
+    ///
+    /// Cells follow [`RegionPlan::coords`] order, so the n-th item pairs
+    /// with the n-th entry of [`RegionPlan::tensor_indices`]. `values`
+    /// has [`components`](Self::components) elements. Coordinates the
+    /// space cannot rank are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &'v [Scalar])> + '_ {
+        let (data, components) = (self.data, self.components);
+        self.region.coords().iter().filter_map(move |coord| {
+            let rank = self.space.canonical_rank(coord)?;
+            let start = rank * components;
+            data.get(start..start + components)
+                .map(|values| (rank, values))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Line1D, RegionSpec};
+    use murk_core::Coord;
+
+    /// `rank as Scalar` over a 2x5 grid.
+    fn ramp() -> Vec<Scalar> {
+        (0..10).map(|i| i as Scalar).collect()
+    }
+
+    #[test]
+    fn sample_bilinear_centres_and_midpoints() {
+        let data = ramp();
+        let grid = Square4::new(2, 5, EdgeBehavior::Absorb).unwrap();
+
+        // Cell centres return the cell value exactly.
+        assert_eq!(sample_bilinear(&data, &[1.0, 3.0], &grid), Some(8.0));
+        assert_eq!(sample_bilinear(&data, &[0.0, 0.0], &grid), Some(0.0));
+        // Midpoints average their neighbours.
+        assert_eq!(sample_bilinear(&data, &[0.0, 2.5], &grid), Some(2.5));
+        assert_eq!(
+            sample_bilinear(&data, &[0.5, 2.5], &grid),
+            Some((2.0 + 3.0 + 7.0 + 8.0) / 4.0)
+        );
+
+        // Unsupported spaces, bad positions, and mismatched buffers.
+        let line = Line1D::new(10, EdgeBehavior::Absorb).unwrap();
+        assert_eq!(sample_bilinear(&data, &[0.0, 0.0], &line), None);
+        assert_eq!(sample_bilinear(&data, &[0.5], &grid), None);
+        assert_eq!(sample_bilinear(&data, &[f32::NAN, 0.0], &grid), None);
+        assert_eq!(sample_bilinear(&data[..9], &[0.0, 0.0], &grid), None);
+    }
+
+    #[test]
+    fn sample_bilinear_honours_edge_behavior() {
+        let data = ramp();
+        let sample = |edge, pos: &[f32]| {
+            let grid = Square4::new(2, 5, edge).unwrap();
+            sample_bilinear(&data, pos, &grid)
+        };
+
+        // Half a cell past the last column: between cells 4 and 0 on a torus.
+        assert_eq!(sample(EdgeBehavior::Wrap, &[0.0, 4.5]), Some(2.0));
+        assert_eq!(sample(EdgeBehavior::Clamp, &[0.0, 4.5]), Some(4.0));
+        assert_eq!(sample(EdgeBehavior::Absorb, &[0.0, 4.5]), Some(4.0));
+        // Entirely outside the grid.
+        assert_eq!(sample(EdgeBehavior::Absorb, &[0.0, 6.0]), None);
+        assert_eq!(sample(EdgeBehavior::Clamp, &[-3.0, 6.0]), Some(4.0));
+    }
+
+    #[test]
+    fn sample_bilinear_rejects_huge_positions() {
+        let data = ramp();
+        for edge in [
+            EdgeBehavior::Wrap,
+            EdgeBehavior::Clamp,
+            EdgeBehavior::Absorb,
+        ] {
+            let grid = Square4::new(2, 5, edge).unwrap();
+            for pos in [[1e30, 0.0], [0.0, -1e30], [f32::MAX, f32::MAX]] {
+                assert_eq!(
+                    sample_bilinear(&data, &pos, &grid),
+                    None,
+                    "{edge:?} {pos:?}"
+                );
+            }
+            assert_eq!(sample_bilinear(&data, &[f32::INFINITY, 0.0], &grid), None);
+        }
+    }
+
+    #[test]
+    fn field_view_matches_manual_gather() {
+        let data = ramp();
+        let grid = Square4::new(2, 5, EdgeBehavior::Absorb).unwrap();
+        let region = grid
+            .compile_region(&RegionSpec::Rect {
+                min: Coord::from_slice(&[0, 1]),
+                max: Coord::from_slice(&[1, 2]),
+            })
+            .unwrap();
+
+        let view = FieldView::new(&data, &region, &grid).unwrap();
+        assert_eq!(view.len(), 4);
+        assert_eq!(view.components(), 1);
+
+        let gathered: Vec<Scalar> = region
+            .coords()
+            .iter()
+            .map(|c| data[grid.canonical_rank(c).unwrap()])
+            .collect();
+        let viewed: Vec<Scalar> = view.iter().map(|(_, v)| v[0]).collect();
+        assert_eq!(viewed, gathered);
+
+        // Only the four in-region ranks are visited.
+        let ranks: Vec<usize> = view.iter().map(|(rank, _)| rank).collect();
+        assert_eq!(ranks, vec![1, 2, 6, 7]);
+
+        assert!(FieldView::new(&data[..9], &region, &grid).is_none());
+        assert!(FieldView::new(&[], &region, &grid).is_none());
+    }
+
+    #[test]
+    fn field_view_slices_vector_components() {
+        let grid = Square4::new(2, 5, EdgeBehavior::Absorb).unwrap();
+        let data: Vec<Scalar> = (0..20).map(|i| i as Scalar).collect();
+        let region = grid
+            .compile_region(&RegionSpec::Coords(vec![Coord::from_slice(&[1, 4])]))
+            .unwrap();
+        let view = FieldView::new(&data, &region, &grid).unwrap();
+        assert_eq!(view.components(), 2);
+        let cells: Vec<_> = view.iter().collect();
+        assert_eq!(cells, vec![(9, &data[18..20])]);
+    }
+}
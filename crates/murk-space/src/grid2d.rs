@@ -2,6 +2,7 @@
 
 use crate::edge::EdgeBehavior;
 use crate::error::SpaceError;
+use crate::order::MemoryOrder;
 use crate::region::{BoundingShape, RegionPlan, RegionSpec};
 use crate::space::Space;
 use murk_core::Coord;
@@ -15,9 +16,9 @@ pub(crate) fn check_2d_bounds(
     cols: u32,
 ) -> Result<(i32, i32), SpaceError> {
     if coord.len() != 2 {
-        return Err(SpaceError::CoordOutOfBounds {
-            coord: coord.clone(),
-            bounds: format!("expected 2D coordinate, got {}D", coord.len()),
+        return Err(SpaceError::DimensionMismatch {
+            expected: 2,
+            got: coord.len(),
         });
     }
     let r = coord[0];
@@ -31,31 +32,83 @@ pub(crate) fn check_2d_bounds(
     Ok((r, c))
 }
 
-/// Row-major canonical rank: `rank([r, c]) = r * cols + c`.
-pub(crate) fn canonical_rank_2d(coord: &Coord, rows: u32, cols: u32) -> Option<usize> {
+/// Flat index of in-bounds `(r, c)` in an `rows x cols` block.
+///
+/// Row-major: `r * cols + c`. Column-major: `c * rows + r`.
+pub(crate) fn rank_2d(r: i32, c: i32, rows: u32, cols: u32, order: MemoryOrder) -> usize {
+    match order {
+        MemoryOrder::RowMajor => r as usize * cols as usize + c as usize,
+        MemoryOrder::ColumnMajor => c as usize * rows as usize + r as usize,
+    }
+}
+
+/// Canonical rank of a coordinate slice, or `None` if out of bounds.
+pub(crate) fn canonical_rank_slice_2d(
+    coord: &[i32],
+    rows: u32,
+    cols: u32,
+    order: MemoryOrder,
+) -> Option<usize> {
     if coord.len() != 2 {
         return None;
     }
     let r = coord[0];
     let c = coord[1];
     if r >= 0 && r < rows as i32 && c >= 0 && c < cols as i32 {
-        Some(r as usize * cols as usize + c as usize)
+        Some(rank_2d(r, c, rows, cols, order))
     } else {
         None
     }
 }
 
-/// Row-major canonical ordering: `[0,0], [0,1], ..., [rows-1, cols-1]`.
-pub(crate) fn canonical_ordering_2d(rows: u32, cols: u32) -> Vec<Coord> {
-    let mut out = Vec::with_capacity((rows as usize) * (cols as usize));
-    for r in 0..rows as i32 {
-        for c in 0..cols as i32 {
-            out.push(smallvec![r, c]);
+/// Canonical rank: `rank([r, c])` under the given memory order.
+pub(crate) fn canonical_rank_2d(
+    coord: &Coord,
+    rows: u32,
+    cols: u32,
+    order: MemoryOrder,
+) -> Option<usize> {
+    canonical_rank_slice_2d(coord, rows, cols, order)
+}
+
+/// Canonical ordering: `[0,0], [0,1], ..., [rows-1, cols-1]` for row-major,
+/// `[0,0], [1,0], ..., [rows-1, cols-1]` for column-major.
+pub(crate) fn canonical_ordering_2d(rows: u32, cols: u32, order: MemoryOrder) -> Vec<Coord> {
+    rect_coords(0, 0, rows as i32 - 1, cols as i32 - 1, order)
+}
+
+/// Coordinates of the inclusive rectangle `[r_lo, r_hi] x [c_lo, c_hi]`
+/// in memory order.
+fn rect_coords(r_lo: i32, c_lo: i32, r_hi: i32, c_hi: i32, order: MemoryOrder) -> Vec<Coord> {
+    let n = ((r_hi - r_lo + 1) as usize) * ((c_hi - c_lo + 1) as usize);
+    let mut out = Vec::with_capacity(n);
+    match order {
+        MemoryOrder::RowMajor => {
+            for r in r_lo..=r_hi {
+                for c in c_lo..=c_hi {
+                    out.push(smallvec![r, c]);
+                }
+            }
+        }
+        MemoryOrder::ColumnMajor => {
+            for c in c_lo..=c_hi {
+                for r in r_lo..=r_hi {
+                    out.push(smallvec![r, c]);
+                }
+            }
         }
     }
     out
 }
 
+/// Sort `(row, col)` pairs into canonical order.
+fn sort_canonical(cells: &mut [(i32, i32)], order: MemoryOrder) {
+    match order {
+        MemoryOrder::RowMajor => cells.sort_unstable(),
+        MemoryOrder::ColumnMajor => cells.sort_unstable_by_key(|&(r, c)| (c, r)),
+    }
+}
+
 /// Resolve a single axis value under the given edge behavior.
 /// Returns `Some(clamped_value)` or `None` for Absorb out-of-bounds.
 pub(crate) fn resolve_axis(val: i32, len: u32, edge: EdgeBehavior) -> Option<i32> {
@@ -70,6 +123,31 @@ pub(crate) fn resolve_axis(val: i32, len: u32, edge: EdgeBehavior) -> Option<i32
     }
 }
 
+/// Resolve an offset cell `(r, c)` with each axis under its own edge
+/// behavior (`edges = [rows, cols]`). Returns `None` if either axis absorbs.
+pub(crate) fn resolve_2d(
+    r: i32,
+    c: i32,
+    rows: u32,
+    cols: u32,
+    edges: [EdgeBehavior; 2],
+) -> Option<(i32, i32)> {
+    Some((
+        resolve_axis(r, rows, edges[0])?,
+        resolve_axis(c, cols, edges[1])?,
+    ))
+}
+
+/// Maximum number of in-bounds offsets (−1 and +1) along one axis.
+pub(crate) fn axis_degree(len: u32, edge: EdgeBehavior) -> usize {
+    match (edge, len) {
+        (EdgeBehavior::Clamp | EdgeBehavior::Wrap, _) => 2,
+        (EdgeBehavior::Absorb, 0 | 1) => 0,
+        (EdgeBehavior::Absorb, 2) => 1,
+        (EdgeBehavior::Absorb, _) => 2,
+    }
+}
+
 /// 1D distance along a single axis, accounting for wrap.
 pub(crate) fn axis_distance(a: i32, b: i32, len: u32, edge: EdgeBehavior) -> f64 {
     let diff = (a - b).unsigned_abs();
@@ -92,6 +170,7 @@ pub(crate) fn compile_disk_2d(
     radius: u32,
     rows: u32,
     cols: u32,
+    order: MemoryOrder,
     get_neighbours: impl Fn(i32, i32) -> Vec<(i32, i32)>,
 ) -> RegionPlan {
     let n = (rows as usize) * (cols as usize);
@@ -119,8 +198,8 @@ pub(crate) fn compile_disk_2d(
         }
     }
 
-    // Sort in row-major canonical order.
-    result.sort();
+    // Sort in canonical order.
+    sort_canonical(&mut result, order);
     let coords: Vec<Coord> = result.iter().map(|&(r, c)| smallvec![r, c]).collect();
     let cell_count = coords.len();
     let tensor_indices: Vec<usize> = (0..cell_count).collect();
@@ -134,17 +213,54 @@ pub(crate) fn compile_disk_2d(
     }
 }
 
+/// Coverage of a region on a 2D grid space without compiling it.
+///
+/// Every 2D grid region compiles to a dense tensor, so coverage is 1.0 for
+/// any valid spec (0.0 for an empty coordinate list). Validation matches
+/// [`compile_region_2d`].
+pub(crate) fn region_coverage_2d(
+    spec: &RegionSpec,
+    rows: u32,
+    cols: u32,
+) -> Result<f64, SpaceError> {
+    match spec {
+        RegionSpec::All => Ok(1.0),
+        RegionSpec::Disk { center, .. } | RegionSpec::Neighbours { center, .. } => {
+            check_2d_bounds(center, rows, cols)?;
+            Ok(1.0)
+        }
+        RegionSpec::Rect { min, max } => {
+            let (r_lo, c_lo) = check_2d_bounds(min, rows, cols)?;
+            let (r_hi, c_hi) = check_2d_bounds(max, rows, cols)?;
+            if r_lo > r_hi || c_lo > c_hi {
+                return Err(SpaceError::InvalidRegion {
+                    reason: format!("Rect min ({r_lo},{c_lo}) > max ({r_hi},{c_hi}) on some axis"),
+                });
+            }
+            Ok(1.0)
+        }
+        RegionSpec::Coords(coords) => {
+            for coord in coords {
+                check_2d_bounds(coord, rows, cols)?;
+            }
+            Ok(if coords.is_empty() { 0.0 } else { 1.0 })
+        }
+        RegionSpec::Product(_) => Err(SpaceError::UnsupportedRegion { kind: spec.kind() }),
+    }
+}
+
 /// Compile a region for a 2D grid space.
 pub(crate) fn compile_region_2d(
     spec: &RegionSpec,
     rows: u32,
     cols: u32,
+    order: MemoryOrder,
     _space: &dyn Space,
     get_neighbours: impl Fn(i32, i32) -> Vec<(i32, i32)>,
 ) -> Result<RegionPlan, SpaceError> {
     match spec {
         RegionSpec::All => {
-            let coords = canonical_ordering_2d(rows, cols);
+            let coords = canonical_ordering_2d(rows, cols, order);
             let cell_count = coords.len();
             let tensor_indices: Vec<usize> = (0..cell_count).collect();
             let valid_mask = vec![1u8; cell_count];
@@ -164,13 +280,22 @@ pub(crate) fn compile_region_2d(
                 *radius,
                 rows,
                 cols,
+                order,
                 &get_neighbours,
             ))
         }
 
         RegionSpec::Neighbours { center, depth } => {
             let (cr, cc) = check_2d_bounds(center, rows, cols)?;
-            Ok(compile_disk_2d(cr, cc, *depth, rows, cols, &get_neighbours))
+            Ok(compile_disk_2d(
+                cr,
+                cc,
+                *depth,
+                rows,
+                cols,
+                order,
+                &get_neighbours,
+            ))
         }
 
         RegionSpec::Rect { min, max } => {
@@ -181,12 +306,7 @@ pub(crate) fn compile_region_2d(
                     reason: format!("Rect min ({r_lo},{c_lo}) > max ({r_hi},{c_hi}) on some axis"),
                 });
             }
-            let mut coords = Vec::new();
-            for r in r_lo..=r_hi {
-                for c in c_lo..=c_hi {
-                    coords.push(smallvec![r, c]);
-                }
-            }
+            let coords = rect_coords(r_lo, c_lo, r_hi, c_hi, order);
             let cell_count = coords.len();
             let tensor_indices: Vec<usize> = (0..cell_count).collect();
             let valid_mask = vec![1u8; cell_count];
@@ -204,9 +324,10 @@ pub(crate) fn compile_region_2d(
             for coord in coords {
                 check_2d_bounds(coord, rows, cols)?;
             }
-            let mut sorted: Vec<Coord> = coords.clone();
-            sorted.sort();
-            sorted.dedup();
+            let mut cells: Vec<(i32, i32)> = coords.iter().map(|c| (c[0], c[1])).collect();
+            sort_canonical(&mut cells, order);
+            cells.dedup();
+            let sorted: Vec<Coord> = cells.iter().map(|&(r, c)| smallvec![r, c]).collect();
             let cell_count = sorted.len();
             let tensor_indices: Vec<usize> = (0..cell_count).collect();
             let valid_mask = vec![1u8; cell_count];
@@ -217,5 +338,6 @@ pub(crate) fn compile_region_2d(
                 bounding_shape: BoundingShape::Rect(vec![cell_count]),
             })
         }
+        RegionSpec::Product(_) => Err(SpaceError::UnsupportedRegion { kind: spec.kind() }),
     }
 }
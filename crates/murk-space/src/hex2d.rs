@@ -2,7 +2,7 @@
 
 use crate::error::SpaceError;
 use crate::region::{BoundingShape, RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::{smallvec, SmallVec};
 
@@ -106,12 +106,32 @@ impl Hex2D {
         false
     }
 
+    /// Cartesian `(x, y)` centre of the cell at axial `[q, r]`.
+    ///
+    /// Uses pointy-top layout with unit spacing between adjacent cell
+    /// centres: `x = q + r/2`, `y = r * sqrt(3)/2`. The `y` axis points
+    /// towards increasing `r`. The coordinate need not be in bounds.
+    ///
+    /// ```
+    /// use murk_space::Hex2D;
+    ///
+    /// let hex = Hex2D::new(4, 4).unwrap();
+    /// assert_eq!(hex.to_pixel(&vec![1i32, 0].into()), (1.0, 0.0));
+    /// let (x, y) = hex.to_pixel(&vec![0i32, 1].into());
+    /// assert!((x - 0.5).abs() < 1e-12 && (y - 3f64.sqrt() / 2.0).abs() < 1e-12);
+    /// ```
+    pub fn to_pixel(&self, coord: &Coord) -> (f64, f64) {
+        let q = coord[0] as f64;
+        let r = coord[1] as f64;
+        (q + r / 2.0, r * 3f64.sqrt() / 2.0)
+    }
+
     /// Check that a coordinate is in-bounds and return `(q, r)`.
     fn check_bounds(&self, coord: &Coord) -> Result<(i32, i32), SpaceError> {
         if coord.len() != 2 {
-            return Err(SpaceError::CoordOutOfBounds {
-                coord: coord.clone(),
-                bounds: format!("expected 2D coordinate, got {}D", coord.len()),
+            return Err(SpaceError::DimensionMismatch {
+                expected: 2,
+                got: coord.len(),
             });
         }
         let q = coord[0];
@@ -146,18 +166,13 @@ impl Hex2D {
         dq.max(dr).max(ds)
     }
 
-    /// Compile a hex disk region via direct enumeration.
-    fn compile_hex_disk(
-        &self,
-        center_q: i32,
-        center_r: i32,
-        radius: u32,
-    ) -> Result<RegionPlan, SpaceError> {
+    /// Effective radius, bounding side length, and bounding area of a hex
+    /// disk of `radius`.
+    fn hex_disk_extent(&self, radius: u32) -> Result<(i32, i64, usize), SpaceError> {
         // Clamp effective radius to grid bounds to avoid overflow.
         // No cell can be further than (rows + cols) from center.
         let max_useful = (self.rows as u64 + self.cols as u64).min(i32::MAX as u64) as u32;
-        let eff_radius = radius.min(max_useful);
-        let r = eff_radius as i32;
+        let r = radius.min(max_useful) as i32;
         let side = 2i64 * r as i64 + 1;
         let bounding_size = side
             .checked_mul(side)
@@ -166,6 +181,47 @@ impl Hex2D {
                     "hex disk bounding area overflow: side={side} exceeds i64 when squared"
                 ),
             })? as usize;
+        Ok((r, side, bounding_size))
+    }
+
+    /// Fraction of a hex disk's bounding tensor covered by in-bounds cells,
+    /// counted one row at a time without enumerating cells.
+    fn hex_disk_coverage(
+        &self,
+        center_q: i32,
+        center_r: i32,
+        radius: u32,
+    ) -> Result<f64, SpaceError> {
+        let (r, _, bounding_size) = self.hex_disk_extent(radius)?;
+        let (q_max, r_max) = (self.cols as i64 - 1, self.rows as i64 - 1);
+        let (cq, cr, r) = (center_q as i64, center_r as i64, r as i64);
+        let mut valid = 0u64;
+        for dr in -r..=r {
+            if !(0..=r_max).contains(&(cr + dr)) {
+                continue;
+            }
+            // Within the disk: |dq| <= r and |dq + dr| <= r.
+            let lo = (-r).max(-r - dr).max(-cq);
+            let hi = r.min(r - dr).min(q_max - cq);
+            if hi >= lo {
+                valid += (hi - lo + 1) as u64;
+            }
+        }
+        Ok(valid as f64 / bounding_size as f64)
+    }
+
+    /// Compile a hex disk region via direct enumeration.
+    ///
+    /// The bounding tensor is the `(2r+1) x (2r+1)` axial box around the
+    /// center; only cells within cube distance `r` are marked valid, so the
+    /// two corners where `|dq + dr| > r` stay masked.
+    fn compile_hex_disk(
+        &self,
+        center_q: i32,
+        center_r: i32,
+        radius: u32,
+    ) -> Result<RegionPlan, SpaceError> {
+        let (r, side, bounding_size) = self.hex_disk_extent(radius)?;
         let mut valid_mask = vec![0u8; bounding_size];
         let mut coords = Vec::new();
         let mut tensor_indices = Vec::new();
@@ -230,6 +286,19 @@ impl Space for Hex2D {
             .collect()
     }
 
+    fn neighbours_into(&self, coord: &Coord, out: &mut SmallVec<[Coord; 12]>) {
+        debug_assert_eq!(
+            coord.len(), self.ndim(),
+            "coord arity {}, expected {}", coord.len(), self.ndim()
+        );
+        out.clear();
+        out.extend(
+            self.neighbours_qr(coord[0], coord[1])
+                .into_iter()
+                .map(|(nq, nr)| smallvec![nq, nr]),
+        );
+    }
+
     fn max_neighbour_degree(&self) -> usize {
         match (self.rows, self.cols) {
             (1, 1) => 0,
@@ -329,6 +398,26 @@ impl Space for Hex2D {
                     bounding_shape: BoundingShape::Rect(vec![cell_count]),
                 })
             }
+            RegionSpec::Product(_) => Err(SpaceError::UnsupportedRegion { kind: spec.kind() }),
+        }
+    }
+
+    fn region_coverage(&self, spec: &RegionSpec) -> Result<f64, SpaceError> {
+        match spec {
+            RegionSpec::All => Ok(1.0),
+            RegionSpec::Disk { center, radius } => {
+                let (cq, cr) = self.check_bounds(center)?;
+                self.hex_disk_coverage(cq, cr, *radius)
+            }
+            RegionSpec::Neighbours { center, depth } => {
+                let (cq, cr) = self.check_bounds(center)?;
+                self.hex_disk_coverage(cq, cr, *depth)
+            }
+            // Rect and Coords compile to dense tensors.
+            RegionSpec::Rect { .. } | RegionSpec::Coords(_) => {
+                self.compile_region(spec).map(|plan| plan.valid_ratio())
+            }
+            RegionSpec::Product(_) => Err(SpaceError::UnsupportedRegion { kind: spec.kind() }),
         }
     }
 
@@ -378,6 +467,10 @@ impl Space for Hex2D {
             .downcast_ref::<Self>()
             .is_some_and(|o| self.rows == o.rows && self.cols == o.cols)
     }
+
+    fn topology_digest(&self) -> Option<u64> {
+        Some(topology_digest_of::<Self, _>((self.rows, self.cols)))
+    }
 }
 
 #[cfg(test)]
@@ -454,6 +547,17 @@ mod tests {
         assert_eq!(s.distance(&c(2, 1), &c(3, 0)), 1.0); // NE
     }
 
+    #[test]
+    fn to_pixel_neighbours_are_unit_distance() {
+        let s = Hex2D::new(5, 5).unwrap();
+        let (x0, y0) = s.to_pixel(&c(2, 2));
+        for nb in s.neighbours(&c(2, 2)) {
+            let (x, y) = s.to_pixel(&nb);
+            let d = ((x - x0).powi(2) + (y - y0).powi(2)).sqrt();
+            assert!((d - 1.0).abs() < 1e-12, "{nb:?} at distance {d}");
+        }
+    }
+
     #[test]
     fn distance_hld_worked_example() {
         // HLD: distance((2,1), (4,0)) = 2
@@ -504,6 +608,30 @@ mod tests {
         assert_eq!(plan.cell_count(), 19);
     }
 
+    #[test]
+    fn compile_region_disk_r2_masks_box_corners() {
+        let s = Hex2D::new(10, 10).unwrap();
+        let plan = s
+            .compile_region(&RegionSpec::Disk {
+                center: c(5, 5),
+                radius: 2,
+            })
+            .unwrap();
+        assert_eq!(plan.bounding_shape, BoundingShape::Rect(vec![5, 5]));
+        assert_eq!(plan.valid_mask.iter().filter(|&&v| v == 1).count(), 19);
+        // Tensor index = (dr + 2) * 5 + (dq + 2); valid iff cube distance <= 2.
+        for dr in -2i32..=2 {
+            for dq in -2i32..=2 {
+                let idx = ((dr + 2) * 5 + dq + 2) as usize;
+                let inside = (dq + dr).abs() <= 2;
+                assert_eq!(plan.valid_mask[idx] == 1, inside, "dq={dq}, dr={dr}");
+            }
+        }
+        for coord in &plan.coords {
+            assert!(Hex2D::cube_distance(5, 5, coord[0], coord[1]) <= 2);
+        }
+    }
+
     #[test]
     fn compile_region_disk_valid_ratio_r1() {
         let s = Hex2D::new(10, 10).unwrap();
@@ -532,6 +660,50 @@ mod tests {
         assert!((ratio - 19.0 / 25.0).abs() < 0.01, "valid_ratio={ratio}");
     }
 
+    #[test]
+    fn region_coverage_matches_valid_ratio() {
+        let s = Hex2D::new(7, 9).unwrap();
+        let specs = [
+            RegionSpec::All,
+            RegionSpec::Disk {
+                center: c(4, 3),
+                radius: 2,
+            },
+            RegionSpec::Disk {
+                center: c(0, 0),
+                radius: 3,
+            },
+            RegionSpec::Disk {
+                center: c(8, 6),
+                radius: 5,
+            },
+            RegionSpec::Neighbours {
+                center: c(1, 5),
+                depth: 4,
+            },
+            RegionSpec::Rect {
+                min: c(1, 1),
+                max: c(3, 4),
+            },
+        ];
+        for spec in &specs {
+            let plan = s.compile_region(spec).unwrap();
+            assert_eq!(s.region_coverage(spec).unwrap(), plan.valid_ratio(), "{spec:?}");
+        }
+        // Corner disks lose most of their bounding box.
+        let corner = RegionSpec::Disk {
+            center: c(0, 0),
+            radius: 3,
+        };
+        assert!(s.region_coverage(&corner).unwrap() < 0.5);
+        assert!(s
+            .region_coverage(&RegionSpec::Disk {
+                center: c(9, 0),
+                radius: 1,
+            })
+            .is_err());
+    }
+
     #[test]
     fn compile_region_disk_boundary_truncation() {
         let s = Hex2D::new(5, 5).unwrap();
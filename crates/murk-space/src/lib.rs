@@ -17,18 +17,27 @@
 //! # Region Planning
 //!
 //! Spatial queries are expressed as [`RegionSpec`] values and compiled to
-//! [`RegionPlan`] for O(1) lookups during tick execution.
+//! [`RegionPlan`] for O(1) lookups during tick execution. [`RegionPlanCache`]
+//! memoizes compiled plans across repeated compiles of the same spec.
+//!
+//! # Field Data
+//!
+//! [`sample_bilinear`] and [`FieldView`] read field buffers laid out in a
+//! space's canonical order, independent of the snapshot that holds them.
 
 #![deny(missing_docs)]
 #![deny(rustdoc::broken_intra_doc_links)]
 #![forbid(unsafe_code)]
 
+pub mod cache;
 pub mod edge;
 pub mod error;
 pub mod fcc12;
+pub mod field;
 pub(crate) mod grid2d;
 pub mod hex2d;
 pub mod line1d;
+pub mod order;
 pub mod product;
 pub mod region;
 pub mod ring1d;
@@ -39,11 +48,14 @@ pub mod square8;
 #[cfg(test)]
 pub(crate) mod compliance;
 
+pub use cache::RegionPlanCache;
 pub use edge::EdgeBehavior;
 pub use error::SpaceError;
 pub use fcc12::Fcc12;
+pub use field::{sample_bilinear, FieldView};
 pub use hex2d::Hex2D;
 pub use line1d::Line1D;
+pub use order::MemoryOrder;
 pub use product::{ProductMetric, ProductSpace};
 pub use region::{BoundingShape, RegionPlan, RegionSpec};
 pub use ring1d::Ring1D;
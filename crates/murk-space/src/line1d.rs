@@ -3,10 +3,9 @@
 use crate::edge::EdgeBehavior;
 use crate::error::SpaceError;
 use crate::region::{BoundingShape, RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::{smallvec, SmallVec};
-use std::collections::VecDeque;
 
 /// A one-dimensional line lattice.
 ///
@@ -108,9 +107,9 @@ pub(crate) fn canonical_ordering_1d(len: u32) -> Vec<Coord> {
 /// Check that a 1D coordinate is in bounds.
 pub(crate) fn check_1d_bounds(coord: &Coord, len: u32) -> Result<i32, SpaceError> {
     if coord.len() != 1 {
-        return Err(SpaceError::CoordOutOfBounds {
-            coord: coord.clone(),
-            bounds: format!("expected 1D coordinate, got {}D", coord.len()),
+        return Err(SpaceError::DimensionMismatch {
+            expected: 1,
+            got: coord.len(),
         });
     }
     let i = coord[0];
@@ -191,53 +190,33 @@ pub(crate) fn compile_region_1d(
                 bounding_shape: BoundingShape::Rect(vec![cell_count]),
             })
         }
+        RegionSpec::Product(_) => Err(SpaceError::UnsupportedRegion { kind: spec.kind() }),
     }
 }
 
-/// BFS-based disk compilation for 1D. Returns cells within `radius` graph-distance
-/// of `center`, handling wrap if enabled.
+/// Disk compilation for 1D: the interval `[center - radius, center + radius]`.
+///
+/// Without wrap the interval is clipped to `[0, len)`. With wrap it is taken
+/// modulo `len`; a radius reaching at least half-way round covers every cell
+/// exactly once. Coordinates are returned in canonical order.
 fn compile_disk_1d(
     center: i32,
     radius: u32,
     len: u32,
     wrap: bool,
 ) -> Result<RegionPlan, SpaceError> {
-    let n = len as i32;
-    let mut visited = vec![false; len as usize];
-    let mut queue = VecDeque::new();
-    let mut result_indices: Vec<i32> = Vec::new();
-
-    visited[center as usize] = true;
-    queue.push_back((center, 0u32));
-    result_indices.push(center);
+    let n = len as i64;
+    let (c, r) = (center as i64, radius as i64);
+    let result_indices: Vec<i32> = if wrap && 2 * r + 1 >= n {
+        (0..len as i32).collect()
+    } else if wrap {
+        let mut idx: Vec<i32> = (c - r..=c + r).map(|i| i.rem_euclid(n) as i32).collect();
+        idx.sort_unstable();
+        idx
+    } else {
+        ((c - r).max(0)..=(c + r).min(n - 1)).map(|i| i as i32).collect()
+    };
 
-    while let Some((pos, dist)) = queue.pop_front() {
-        if dist >= radius {
-            continue;
-        }
-        let candidates = if wrap {
-            vec![((pos - 1 + n) % n), ((pos + 1) % n)]
-        } else {
-            let mut c = Vec::new();
-            if pos > 0 {
-                c.push(pos - 1);
-            }
-            if pos < n - 1 {
-                c.push(pos + 1);
-            }
-            c
-        };
-        for nb in candidates {
-            if !visited[nb as usize] {
-                visited[nb as usize] = true;
-                queue.push_back((nb, dist + 1));
-                result_indices.push(nb);
-            }
-        }
-    }
-
-    // Sort in canonical order.
-    result_indices.sort();
     let coords: Vec<Coord> = result_indices.iter().map(|&i| smallvec![i]).collect();
     let cell_count = coords.len();
     let tensor_indices: Vec<usize> = (0..cell_count).collect();
@@ -357,6 +336,10 @@ impl Space for Line1D {
             .downcast_ref::<Self>()
             .is_some_and(|o| self.len == o.len && self.edge == o.edge)
     }
+
+    fn topology_digest(&self) -> Option<u64> {
+        Some(topology_digest_of::<Self, _>((self.len, self.edge)))
+    }
 }
 
 #[cfg(test)]
@@ -520,6 +503,27 @@ mod tests {
         assert!(plan.coords.contains(&c(2)));
     }
 
+    #[test]
+    fn compile_region_disk_absorb_clips_at_edge() {
+        for edge in [EdgeBehavior::Absorb, EdgeBehavior::Clamp] {
+            let s = Line1D::new(10, edge).unwrap();
+            let plan = s
+                .compile_region(&RegionSpec::Disk {
+                    center: c(1),
+                    radius: 3,
+                })
+                .unwrap();
+            assert_eq!(plan.coords, vec![c(0), c(1), c(2), c(3), c(4)]);
+            let plan = s
+                .compile_region(&RegionSpec::Disk {
+                    center: c(9),
+                    radius: 100,
+                })
+                .unwrap();
+            assert_eq!(plan.cell_count(), 10);
+        }
+    }
+
     #[test]
     fn compile_region_rect() {
         let s = Line1D::new(10, EdgeBehavior::Absorb).unwrap();
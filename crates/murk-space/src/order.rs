@@ -0,0 +1,31 @@
+//! Memory order of canonical cell ranks for 2D grid backends.
+
+/// How a 2D grid linearizes `[row, col]` coordinates into canonical ranks.
+///
+/// The memory order determines [`Space::canonical_rank`](crate::Space::canonical_rank),
+/// [`Space::canonical_ordering`](crate::Space::canonical_ordering), and the
+/// flat layout of compiled region tensors, so field buffers and observation
+/// tensors come out directly in the chosen layout. Region bounding shapes
+/// remain `[rows, cols]` in both orders.
+///
+/// # Examples
+///
+/// ```
+/// use murk_space::{EdgeBehavior, MemoryOrder, Space, Square4};
+///
+/// let grid = Square4::new(2, 3, EdgeBehavior::Absorb)
+///     .unwrap()
+///     .with_memory_order(MemoryOrder::ColumnMajor);
+/// let coord: murk_core::Coord = vec![1i32, 2].into();
+/// // Column-major: rank = col * rows + row.
+/// assert_eq!(grid.canonical_rank(&coord), Some(5));
+/// assert_eq!(grid.canonical_rank(&vec![1i32, 0].into()), Some(1));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MemoryOrder {
+    /// `rank([r, c]) = r * cols + c` (C order). The default.
+    #[default]
+    RowMajor,
+    /// `rank([r, c]) = c * rows + r` (Fortran order).
+    ColumnMajor,
+}
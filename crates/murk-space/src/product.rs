@@ -2,13 +2,59 @@
 
 use crate::error::SpaceError;
 use crate::region::{BoundingShape, RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use indexmap::IndexSet;
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::SmallVec;
 use std::collections::VecDeque;
 use std::fmt;
 
+/// Components with more cells than this query their neighbours directly
+/// instead of through a precomputed [`NeighbourTable`].
+const NEIGHBOUR_TABLE_MAX_CELLS: usize = 1 << 16;
+
+/// Precomputed adjacency of one component space, indexed by canonical rank.
+///
+/// Stored in CSR form: the neighbours of the cell with rank `r` are
+/// `coords[targets[offsets[r]..offsets[r + 1]]]`, in the order the
+/// component's own `neighbours()` returns them.
+struct NeighbourTable {
+    coords: Vec<Coord>,
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+}
+
+impl NeighbourTable {
+    /// Tabulate `space`, or `None` if it is too large or reports a
+    /// neighbour without a canonical rank.
+    fn build(space: &dyn Space) -> Option<Self> {
+        if space.cell_count() > NEIGHBOUR_TABLE_MAX_CELLS {
+            return None;
+        }
+        let coords = space.canonical_ordering();
+        let mut offsets = Vec::with_capacity(coords.len() + 1);
+        let mut targets = Vec::with_capacity(coords.len() * space.max_neighbour_degree());
+        offsets.push(0);
+        for coord in &coords {
+            for nb in space.neighbours(coord) {
+                targets.push(space.canonical_rank(&nb)?);
+            }
+            offsets.push(targets.len());
+        }
+        Some(Self {
+            coords,
+            offsets,
+            targets,
+        })
+    }
+
+    fn neighbours_of(&self, rank: usize) -> impl Iterator<Item = &Coord> + '_ {
+        self.targets[self.offsets[rank]..self.offsets[rank + 1]]
+            .iter()
+            .map(|&t| &self.coords[t])
+    }
+}
+
 /// Distance metric for product spaces.
 #[derive(Clone, Debug)]
 pub enum ProductMetric {
@@ -40,7 +86,9 @@ pub enum ProductMetric {
 /// Each component space applies its own [`EdgeBehavior`](crate::EdgeBehavior)
 /// (Absorb/Wrap/Clamp) independently. The product space does not introduce any
 /// cross-component edge effects. For example, a `Line1D(Wrap) x Line1D(Absorb)`
-/// product wraps along the first axis and absorbs along the second.
+/// product wraps along the first axis and absorbs along the second. Likewise
+/// `Ring1D x Line1D` is a cylinder and `Ring1D x Ring1D` a torus, each with a
+/// maximum neighbour degree of 4.
 ///
 /// # Implemented operations
 ///
@@ -58,6 +106,8 @@ pub struct ProductSpace {
     dim_offsets: Vec<usize>,
     total_ndim: usize,
     total_cells: usize,
+    /// Per-component adjacency, `None` for components queried directly.
+    neighbour_tables: Vec<Option<NeighbourTable>>,
     instance_id: SpaceInstanceId,
 }
 
@@ -118,6 +168,11 @@ impl ProductSpace {
             }
         }
 
+        let neighbour_tables = components
+            .iter()
+            .map(|c| NeighbourTable::build(c.as_ref()))
+            .collect();
+
         Ok(Self {
             components,
             component_cell_counts,
@@ -125,6 +180,7 @@ impl ProductSpace {
             dim_offsets,
             total_ndim,
             total_cells,
+            neighbour_tables,
             instance_id: SpaceInstanceId::next(),
         })
     }
@@ -146,13 +202,32 @@ impl ProductSpace {
         SmallVec::from_slice(&coord[start..end])
     }
 
-    /// Join per-component coordinates into a single product coordinate.
-    fn join_coords(&self, parts: &[Coord]) -> Coord {
-        let mut out = SmallVec::with_capacity(self.total_ndim);
-        for part in parts {
-            out.extend_from_slice(part);
+    /// Call `emit` with each product-graph neighbour of `coord`, component
+    /// by component, in the order each component reports them.
+    ///
+    /// Tabulated components look neighbours up by rank; the rest (and
+    /// sub-coordinates outside the component) query the component directly.
+    fn for_each_neighbour(&self, coord: &Coord, mut emit: impl FnMut(Coord)) {
+        for (i, comp) in self.components.iter().enumerate() {
+            let (start, end) = (self.dim_offsets[i], self.dim_offsets[i + 1]);
+            let sub = &coord[start..end];
+            let table = self.neighbour_tables[i].as_ref();
+            if let Some((table, rank)) =
+                table.and_then(|t| comp.canonical_rank_slice(sub).map(|r| (t, r)))
+            {
+                for nb in table.neighbours_of(rank) {
+                    let mut out = coord.clone();
+                    out[start..end].copy_from_slice(nb);
+                    emit(out);
+                }
+            } else {
+                for nb in comp.neighbours(&SmallVec::from_slice(sub)) {
+                    let mut out = coord.clone();
+                    out[start..end].copy_from_slice(&nb);
+                    emit(out);
+                }
+            }
         }
-        out
     }
 
     /// Sort coordinates by product canonical order (leftmost component slowest).
@@ -228,7 +303,7 @@ impl ProductSpace {
     /// per-component bounding sizes.
     ///
     /// Used by [`compile_region()`](Self::compile_region) for `All`, `Rect`,
-    /// and `Coords` region specs.
+    /// and `Product` region specs.
     fn compile_cartesian_product(&self, per_comp: &[RegionPlan]) -> RegionPlan {
         // Bounding shape = concatenation of per-component bounding shapes.
         let mut bounding_dims = Vec::new();
@@ -239,6 +314,16 @@ impl ProductSpace {
         }
         let bounding_total: usize = bounding_dims.iter().product();
 
+        // An empty factor region makes the product empty.
+        if per_comp.iter().any(|p| p.coords.is_empty()) {
+            return RegionPlan {
+                coords: Vec::new(),
+                tensor_indices: Vec::new(),
+                valid_mask: vec![0u8; bounding_total],
+                bounding_shape: BoundingShape::Rect(bounding_dims),
+            };
+        }
+
         // Compute per-component bounding sizes and strides.
         let comp_bounding_sizes: Vec<usize> = per_comp
             .iter()
@@ -343,27 +428,29 @@ impl Space for ProductSpace {
     ///
     /// The neighbour count equals the sum of per-component neighbour counts
     /// (not the product), since only one component varies per edge.
+    ///
+    /// Component adjacency is precomputed at construction (for components up
+    /// to 65 536 cells), so a query costs one `canonical_rank` per component
+    /// plus a table lookup, with no intermediate per-component coordinates.
     fn neighbours(&self, coord: &Coord) -> SmallVec<[Coord; 8]> {
         debug_assert_eq!(
             coord.len(), self.ndim(),
             "coord arity {}, expected {}", coord.len(), self.ndim()
         );
-        let parts: Vec<Coord> = (0..self.components.len())
-            .map(|i| self.split_coord(coord, i))
-            .collect();
-
         let mut result = SmallVec::new();
-        for i in 0..self.components.len() {
-            let comp_neighbours = self.components[i].neighbours(&parts[i]);
-            for nb in comp_neighbours {
-                let mut new_parts = parts.clone();
-                new_parts[i] = nb;
-                result.push(self.join_coords(&new_parts));
-            }
-        }
+        self.for_each_neighbour(coord, |nb| result.push(nb));
         result
     }
 
+    fn neighbours_into(&self, coord: &Coord, out: &mut SmallVec<[Coord; 12]>) {
+        debug_assert_eq!(
+            coord.len(), self.ndim(),
+            "coord arity {}, expected {}", coord.len(), self.ndim()
+        );
+        out.clear();
+        self.for_each_neighbour(coord, |nb| out.push(nb));
+    }
+
     fn max_neighbour_degree(&self) -> usize {
         self.components
             .iter()
@@ -431,13 +518,14 @@ impl Space for ProductSpace {
             RegionSpec::Rect { min, max } => {
                 // R-SPACE-11: split min/max per-component, compile per-component Rect.
                 if min.len() != self.total_ndim || max.len() != self.total_ndim {
-                    return Err(SpaceError::InvalidRegion {
-                        reason: format!(
-                            "Rect coordinates must have {} dimensions, got {}/{}",
-                            self.total_ndim,
-                            min.len(),
-                            max.len()
-                        ),
+                    let got = if min.len() != self.total_ndim {
+                        min.len()
+                    } else {
+                        max.len()
+                    };
+                    return Err(SpaceError::DimensionMismatch {
+                        expected: self.total_ndim,
+                        got,
                     });
                 }
                 let per_comp: Vec<RegionPlan> = (0..self.components.len())
@@ -462,46 +550,67 @@ impl Space for ProductSpace {
 
             RegionSpec::Neighbours { center, depth } => self.compile_disk_bfs(center, *depth),
 
-            RegionSpec::Coords(coords) => {
-                // Validate all coords.
-                for coord in coords {
-                    if coord.len() != self.total_ndim {
-                        return Err(SpaceError::CoordOutOfBounds {
-                            coord: coord.clone(),
-                            bounds: format!("expected {}D coordinate", self.total_ndim),
-                        });
-                    }
-                    // Validate each component.
-                    for i in 0..self.components.len() {
-                        let sub = self.split_coord(coord, i);
-                        // Check bounds by trying to compute distance to self.
-                        // If the coord is invalid in any component, the canonical
-                        // ordering won't contain it.
-                        let ordering = self.components[i].canonical_ordering();
-                        if !ordering.contains(&sub) {
-                            return Err(SpaceError::CoordOutOfBounds {
-                                coord: coord.clone(),
-                                bounds: format!("component {i} coordinate out of bounds"),
-                            });
-                        }
-                    }
+            RegionSpec::Coords(coords) => self.compile_coords(coords, |i, sub| {
+                // If the coord is invalid in any component, the canonical
+                // ordering won't contain it.
+                self.components[i].canonical_ordering().contains(sub)
+            }),
+
+            RegionSpec::Product(operands) => {
+                if operands.len() != self.components.len() {
+                    return Err(SpaceError::InvalidRegion {
+                        reason: format!(
+                            "Product region has {} operands, space has {} factors",
+                            operands.len(),
+                            self.components.len()
+                        ),
+                    });
                 }
-                let mut sorted: Vec<Coord> = coords.clone();
-                self.sort_canonical(&mut sorted);
-                sorted.dedup();
-                let n = sorted.len();
-                let tensor_indices: Vec<usize> = (0..n).collect();
-                let valid_mask = vec![1u8; n];
-                Ok(RegionPlan {
-                    coords: sorted,
-                    tensor_indices,
-                    valid_mask,
-                    bounding_shape: BoundingShape::Rect(vec![n]),
-                })
+                let per_comp: Vec<RegionPlan> = self
+                    .components
+                    .iter()
+                    .zip(operands)
+                    .map(|(comp, op)| comp.compile_region(op))
+                    .collect::<Result<_, _>>()?;
+                Ok(self.compile_cartesian_product(&per_comp))
             }
         }
     }
 
+    /// Batched [`compile_region`](Space::compile_region).
+    ///
+    /// `All` is compiled once, and the per-component cell sets used to
+    /// validate `Coords` regions are built once and shared by every spec.
+    fn compile_regions(&self, specs: &[RegionSpec]) -> Result<Vec<RegionPlan>, SpaceError> {
+        let mut component_cells: Option<Vec<IndexSet<Coord>>> = None;
+        let mut all: Option<RegionPlan> = None;
+        let mut plans = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let plan = match spec {
+                RegionSpec::All => match &all {
+                    Some(plan) => plan.clone(),
+                    None => {
+                        let plan = self.compile_region(spec)?;
+                        all = Some(plan.clone());
+                        plan
+                    }
+                },
+                RegionSpec::Coords(coords) => {
+                    let cells = component_cells.get_or_insert_with(|| {
+                        self.components
+                            .iter()
+                            .map(|c| c.canonical_ordering().into_iter().collect())
+                            .collect()
+                    });
+                    self.compile_coords(coords, |i, sub| cells[i].contains(sub))?
+                }
+                _ => self.compile_region(spec)?,
+            };
+            plans.push(plan);
+        }
+        Ok(plans)
+    }
+
     fn canonical_ordering(&self) -> Vec<Coord> {
         // R-SPACE-10: lexicographic, leftmost slowest.
         // Odometer over per-component orderings.
@@ -564,9 +673,61 @@ impl Space for ProductSpace {
                 .zip(o.components.iter())
                 .all(|(a, b)| a.topology_eq(b.as_ref()))
     }
+
+    fn topology_digest(&self) -> Option<u64> {
+        let components = self
+            .components
+            .iter()
+            .map(|c| c.topology_digest())
+            .collect::<Option<Vec<u64>>>()?;
+        Some(topology_digest_of::<Self, _>(components))
+    }
 }
 
 impl ProductSpace {
+    /// Compile an explicit coordinate list, deduplicated and sorted into
+    /// canonical order as a flat (1-D bounding shape) plan.
+    ///
+    /// `in_component(i, sub)` reports whether `sub` is a valid cell of
+    /// component `i`.
+    fn compile_coords(
+        &self,
+        coords: &[Coord],
+        in_component: impl Fn(usize, &Coord) -> bool,
+    ) -> Result<RegionPlan, SpaceError> {
+        // Validate all coords.
+        for coord in coords {
+            if coord.len() != self.total_ndim {
+                return Err(SpaceError::DimensionMismatch {
+                    expected: self.total_ndim,
+                    got: coord.len(),
+                });
+            }
+            // Validate each component.
+            for i in 0..self.components.len() {
+                let sub = self.split_coord(coord, i);
+                if !in_component(i, &sub) {
+                    return Err(SpaceError::CoordOutOfBounds {
+                        coord: coord.clone(),
+                        bounds: format!("component {i} coordinate out of bounds"),
+                    });
+                }
+            }
+        }
+        let mut sorted: Vec<Coord> = coords.to_vec();
+        self.sort_canonical(&mut sorted);
+        sorted.dedup();
+        let n = sorted.len();
+        let tensor_indices: Vec<usize> = (0..n).collect();
+        let valid_mask = vec![1u8; n];
+        Ok(RegionPlan {
+            coords: sorted,
+            tensor_indices,
+            valid_mask,
+            bounding_shape: BoundingShape::Rect(vec![n]),
+        })
+    }
+
     /// BFS-based disk compilation in the product graph.
     ///
     /// Performs breadth-first search from `center`, expanding product-graph
@@ -578,9 +739,9 @@ impl ProductSpace {
     /// Returns `Err` if `center` is out of bounds in any component.
     fn compile_disk_bfs(&self, center: &Coord, radius: u32) -> Result<RegionPlan, SpaceError> {
         if center.len() != self.total_ndim {
-            return Err(SpaceError::CoordOutOfBounds {
-                coord: center.clone(),
-                bounds: format!("expected {}D coordinate", self.total_ndim),
+            return Err(SpaceError::DimensionMismatch {
+                expected: self.total_ndim,
+                got: center.len(),
             });
         }
         // Validate center is in-bounds for each component.
@@ -737,6 +898,61 @@ mod tests {
         assert_eq!(plan.cell_count(), 12);
     }
 
+    #[test]
+    fn region_product_disk_x_rect() {
+        // Square4(5x5) x Ring1D(8): disk on the grid, interval on the ring.
+        let grid = crate::Square4::new(5, 5, crate::EdgeBehavior::Absorb).unwrap();
+        let ring = Ring1D::new(8).unwrap();
+        let s = ProductSpace::new(vec![Box::new(grid), Box::new(ring)]).unwrap();
+        let disk = RegionSpec::Disk {
+            center: smallvec![2, 2],
+            radius: 1,
+        };
+        let rect = RegionSpec::Rect {
+            min: smallvec![2],
+            max: smallvec![4],
+        };
+        let plan = s
+            .compile_region(&RegionSpec::Product(vec![disk.clone(), rect.clone()]))
+            .unwrap();
+
+        let disk_plan = s.component(0).compile_region(&disk).unwrap();
+        let rect_plan = s.component(1).compile_region(&rect).unwrap();
+        let expected: Vec<Coord> = disk_plan
+            .coords()
+            .iter()
+            .flat_map(|a| {
+                rect_plan.coords().iter().map(move |b| {
+                    let mut c = a.clone();
+                    c.extend_from_slice(b);
+                    c
+                })
+            })
+            .collect();
+        assert_eq!(plan.cell_count(), 15); // 5 disk cells * 3 ring cells
+        assert_eq!(plan.coords(), &expected[..]);
+        // Bounding shape concatenates the factors': the Square4 disk is a
+        // flat 5-cell tensor, the ring interval a 3-cell one.
+        assert_eq!(disk_plan.bounding_shape(), &BoundingShape::Rect(vec![5]));
+        assert_eq!(plan.bounding_shape(), &BoundingShape::Rect(vec![5, 3]));
+        assert_eq!(plan.valid_ratio(), 1.0);
+    }
+
+    #[test]
+    fn region_product_operand_count_must_match_factors() {
+        let s = hex_line();
+        let result = s.compile_region(&RegionSpec::Product(vec![RegionSpec::All]));
+        assert!(matches!(result, Err(SpaceError::InvalidRegion { .. })));
+
+        // Non-product spaces reject Product specs outright.
+        let line = Line1D::new(5, crate::EdgeBehavior::Absorb).unwrap();
+        let result = line.compile_region(&RegionSpec::Product(vec![RegionSpec::All]));
+        assert_eq!(
+            result.unwrap_err(),
+            SpaceError::UnsupportedRegion { kind: "Product" }
+        );
+    }
+
     // ── Structural tests ────────────────────────────────────────
 
     #[test]
@@ -793,6 +1009,87 @@ mod tests {
         assert_eq!(plan.valid_ratio(), 1.0);
     }
 
+    // ── Neighbour table ─────────────────────────────────────────
+
+    /// The untabulated algorithm: split, query each component, rejoin.
+    fn reference_neighbours(s: &ProductSpace, coord: &Coord) -> Vec<Coord> {
+        let parts: Vec<Coord> = (0..s.n_components())
+            .map(|i| s.split_coord(coord, i))
+            .collect();
+        let mut result = Vec::new();
+        for i in 0..s.n_components() {
+            for nb in s.component(i).neighbours(&parts[i]) {
+                let mut joined = Coord::new();
+                for (j, part) in parts.iter().enumerate() {
+                    joined.extend_from_slice(if j == i { &nb } else { part });
+                }
+                result.push(joined);
+            }
+        }
+        result
+    }
+
+    /// Brute force: every cell differing from `coord` in exactly one
+    /// component, where that component's cells are adjacent.
+    fn brute_force_neighbours(s: &ProductSpace, coord: &Coord) -> Vec<Coord> {
+        s.canonical_ordering()
+            .into_iter()
+            .filter(|other| {
+                let differing: Vec<usize> = (0..s.n_components())
+                    .filter(|&i| s.split_coord(coord, i) != s.split_coord(other, i))
+                    .collect();
+                matches!(differing[..], [i] if s
+                    .component(i)
+                    .neighbours(&s.split_coord(coord, i))
+                    .contains(&s.split_coord(other, i)))
+            })
+            .collect()
+    }
+
+    fn assert_table_matches_reference(s: &ProductSpace) {
+        let mut buf = SmallVec::new();
+        for coord in s.canonical_ordering() {
+            let fast = s.neighbours(&coord);
+            assert_eq!(fast.to_vec(), reference_neighbours(s, &coord), "{coord:?}");
+            s.neighbours_into(&coord, &mut buf);
+            assert_eq!(buf.to_vec(), fast.to_vec());
+
+            let mut sorted = fast.to_vec();
+            sorted.sort();
+            sorted.dedup();
+            let mut brute = brute_force_neighbours(s, &coord);
+            brute.sort();
+            assert_eq!(sorted, brute, "{coord:?}");
+        }
+    }
+
+    #[test]
+    fn neighbour_table_matches_reference_square_ring() {
+        let square = crate::Square4::new(4, 3, crate::EdgeBehavior::Wrap).unwrap();
+        let ring = Ring1D::new(5).unwrap();
+        let s = ProductSpace::new(vec![Box::new(square), Box::new(ring)]).unwrap();
+        assert!(s.neighbour_tables.iter().all(Option::is_some));
+        assert_table_matches_reference(&s);
+    }
+
+    #[test]
+    fn neighbour_table_matches_reference_hex_line() {
+        assert_table_matches_reference(&hex_line());
+    }
+
+    #[test]
+    fn large_component_falls_back_to_direct_queries() {
+        let big = Line1D::new(NEIGHBOUR_TABLE_MAX_CELLS as u32 + 1, crate::EdgeBehavior::Wrap)
+            .unwrap();
+        let ring = Ring1D::new(3).unwrap();
+        let s = ProductSpace::new(vec![Box::new(big), Box::new(ring)]).unwrap();
+        assert!(s.neighbour_tables[0].is_none());
+        assert!(s.neighbour_tables[1].is_some());
+        for coord in [smallvec![0, 0], smallvec![65536, 2], smallvec![300, 1]] {
+            assert_eq!(s.neighbours(&coord).to_vec(), reference_neighbours(&s, &coord));
+        }
+    }
+
     // ── Compliance ──────────────────────────────────────────────
 
     #[test]
@@ -811,6 +1108,93 @@ mod tests {
         compliance::run_full_compliance(&s);
     }
 
+    // ── Cylinder and torus ──────────────────────────────────────
+
+    fn cylinder(edge: crate::EdgeBehavior) -> ProductSpace {
+        let ring = Ring1D::new(6).unwrap();
+        let line = Line1D::new(4, edge).unwrap();
+        ProductSpace::new(vec![Box::new(ring), Box::new(line)]).unwrap()
+    }
+
+    #[test]
+    fn compliance_cylinder_and_torus() {
+        compliance::run_full_compliance(&cylinder(crate::EdgeBehavior::Absorb));
+        compliance::run_full_compliance(&cylinder(crate::EdgeBehavior::Clamp));
+        let torus = ProductSpace::new(vec![
+            Box::new(Ring1D::new(5).unwrap()),
+            Box::new(Ring1D::new(4).unwrap()),
+        ])
+        .unwrap();
+        compliance::run_full_compliance(&torus);
+        assert_eq!(torus.max_neighbour_degree(), 4);
+    }
+
+    #[test]
+    fn cylinder_ring_seam_wraps() {
+        let s = cylinder(crate::EdgeBehavior::Absorb);
+        assert_eq!(s.max_neighbour_degree(), 4);
+
+        let n = s.neighbours(&smallvec![0, 1]);
+        assert_eq!(n.len(), 4);
+        assert!(n.contains(&smallvec![5, 1])); // across the seam
+        assert!(n.contains(&smallvec![1, 1]));
+        assert!(n.contains(&smallvec![0, 0]));
+        assert!(n.contains(&smallvec![0, 2]));
+
+        // Distance takes the short way round the ring.
+        assert_eq!(s.distance(&smallvec![0, 0], &smallvec![5, 0]), 1.0);
+        assert_eq!(s.distance(&smallvec![1, 0], &smallvec![4, 3]), 6.0);
+    }
+
+    #[test]
+    fn cylinder_line_boundary_follows_edge_behavior() {
+        // Absorb: the line end loses its outward neighbour.
+        let absorb = cylinder(crate::EdgeBehavior::Absorb);
+        let n = absorb.neighbours(&smallvec![2, 3]);
+        assert_eq!(n.len(), 3);
+        assert!(!n.iter().any(|c| c[1] == 0 || c[1] == 4));
+
+        // Clamp: the line end keeps a self-loop instead.
+        let clamp = cylinder(crate::EdgeBehavior::Clamp);
+        let n = clamp.neighbours(&smallvec![2, 3]);
+        assert_eq!(n.len(), 4);
+        assert!(n.contains(&smallvec![2, 3]));
+        assert!(!n.contains(&smallvec![2, 0]));
+
+        // The line axis never wraps, so distance is not shortened.
+        assert_eq!(clamp.distance(&smallvec![0, 0], &smallvec![0, 3]), 3.0);
+    }
+
+    #[test]
+    fn compile_regions_matches_individual() {
+        let s = hex_line();
+        let specs = vec![
+            RegionSpec::Rect {
+                min: smallvec![0, 0, 0],
+                max: smallvec![1, 2, 3],
+            },
+            RegionSpec::Coords(vec![smallvec![1, 1, 1], smallvec![0, 0, 0]]),
+            RegionSpec::All,
+            RegionSpec::Rect {
+                min: smallvec![1, 0, 2],
+                max: smallvec![2, 1, 4],
+            },
+            RegionSpec::Coords(vec![smallvec![2, 2, 4]]),
+            RegionSpec::All,
+        ];
+        let batched = s.compile_regions(&specs).unwrap();
+        for (spec, plan) in specs.iter().zip(&batched) {
+            assert_eq!(plan, &s.compile_region(spec).unwrap());
+        }
+
+        // Errors surface exactly as in the single-spec path.
+        let bad = [RegionSpec::Coords(vec![smallvec![0, 0, 99]])];
+        assert_eq!(
+            s.compile_regions(&bad).unwrap_err().to_string(),
+            s.compile_region(&bad[0]).unwrap_err().to_string()
+        );
+    }
+
     // ── Downcast test ───────────────────────────────────────────
 
     #[test]
@@ -6,7 +6,7 @@ use murk_core::Coord;
 ///
 /// Used for observation gathering, propagator spatial queries,
 /// and region-scoped operations.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum RegionSpec {
     /// Every cell in the space.
     All,
@@ -33,13 +33,36 @@ pub enum RegionSpec {
     },
     /// Explicit list of coordinates.
     Coords(Vec<Coord>),
+    /// Per-factor regions on a [`ProductSpace`](crate::ProductSpace).
+    ///
+    /// Operand `i` is compiled by factor `i` in that factor's own
+    /// coordinates, and the result is the Cartesian product of the
+    /// per-factor plans. The operand count must equal the factor count.
+    /// Other spaces reject this spec with
+    /// [`SpaceError::UnsupportedRegion`](crate::SpaceError::UnsupportedRegion).
+    Product(Vec<RegionSpec>),
+}
+
+impl RegionSpec {
+    /// Variant name of this spec (e.g. `"Disk"`), used as the `kind` of
+    /// [`SpaceError::UnsupportedRegion`](crate::SpaceError::UnsupportedRegion).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RegionSpec::All => "All",
+            RegionSpec::Disk { .. } => "Disk",
+            RegionSpec::Rect { .. } => "Rect",
+            RegionSpec::Neighbours { .. } => "Neighbours",
+            RegionSpec::Coords(_) => "Coords",
+            RegionSpec::Product(_) => "Product",
+        }
+    }
 }
 
 /// Compiled region plan — precomputed for O(1) lookups during tick execution.
 ///
 /// Created by [`Space::compile_region`](crate::Space::compile_region).
 /// Fields are `pub(crate)` — use accessor methods from outside the crate.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct RegionPlan {
     /// Precomputed coordinates in canonical iteration order.
     pub(crate) coords: Vec<Coord>,
@@ -68,6 +91,14 @@ impl RegionPlan {
         &self.tensor_indices
     }
 
+    /// Iterate `(coord, tensor_index)` pairs in canonical order.
+    ///
+    /// Pairs `coords()[i]` with `tensor_indices()[i]`, so custom gather
+    /// code can scatter each cell into the bounding tensor directly.
+    pub fn iter(&self) -> impl Iterator<Item = (&Coord, usize)> + '_ {
+        self.coords.iter().zip(self.tensor_indices.iter().copied())
+    }
+
     /// Validity mask: `1` = valid cell, `0` = padding.
     pub fn valid_mask(&self) -> &[u8] {
         &self.valid_mask
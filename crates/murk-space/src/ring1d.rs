@@ -3,7 +3,7 @@
 use crate::error::SpaceError;
 use crate::line1d;
 use crate::region::{RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::SmallVec;
 
@@ -147,6 +147,10 @@ impl Space for Ring1D {
             .downcast_ref::<Self>()
             .is_some_and(|o| self.len == o.len)
     }
+
+    fn topology_digest(&self) -> Option<u64> {
+        Some(topology_digest_of::<Self, _>(self.len))
+    }
 }
 
 #[cfg(test)]
@@ -210,6 +214,67 @@ mod tests {
         assert_eq!(plan.valid_ratio(), 1.0);
     }
 
+    #[test]
+    fn compile_region_disk_interior() {
+        let s = Ring1D::new(10).unwrap();
+        let plan = s
+            .compile_region(&RegionSpec::Disk {
+                center: c(4),
+                radius: 1,
+            })
+            .unwrap();
+        assert_eq!(plan.coords, vec![c(3), c(4), c(5)]);
+        assert_eq!(plan.tensor_indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn compile_region_disk_wraps() {
+        let s = Ring1D::new(10).unwrap();
+        let plan = s
+            .compile_region(&RegionSpec::Disk {
+                center: c(9),
+                radius: 2,
+            })
+            .unwrap();
+        assert_eq!(plan.coords, vec![c(0), c(1), c(7), c(8), c(9)]);
+        assert_eq!(plan.valid_ratio(), 1.0);
+    }
+
+    #[test]
+    fn compile_region_disk_beyond_half_ring_covers_once() {
+        let s = Ring1D::new(7).unwrap();
+        for radius in [3, 4, 7, u32::MAX] {
+            let plan = s
+                .compile_region(&RegionSpec::Disk {
+                    center: c(2),
+                    radius,
+                })
+                .unwrap();
+            assert_eq!(plan.coords, s.canonical_ordering(), "radius {radius}");
+        }
+        // Even ring, radius exactly half: both sides meet at the antipode.
+        let s = Ring1D::new(6).unwrap();
+        let plan = s
+            .compile_region(&RegionSpec::Disk {
+                center: c(0),
+                radius: 3,
+            })
+            .unwrap();
+        assert_eq!(plan.cell_count(), 6);
+    }
+
+    #[test]
+    fn compile_region_rect_is_slice() {
+        let s = Ring1D::new(10).unwrap();
+        let plan = s
+            .compile_region(&RegionSpec::Rect {
+                min: c(2),
+                max: c(5),
+            })
+            .unwrap();
+        assert_eq!(plan.coords, vec![c(2), c(3), c(4), c(5)]);
+    }
+
     // ── Constructor test ────────────────────────────────────────
 
     #[test]
@@ -4,7 +4,8 @@ use crate::error::SpaceError;
 use crate::region::{RegionPlan, RegionSpec};
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::SmallVec;
-use std::any::Any;
+use std::any::{Any, TypeId};
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 /// Central spatial abstraction for Murk simulations.
 ///
@@ -36,6 +37,20 @@ pub trait Space: Any + Send + Sync + 'static {
     /// topologies (up to 8 neighbors covers Hex2D and Square8).
     fn neighbours(&self, coord: &Coord) -> SmallVec<[Coord; 8]>;
 
+    /// Write the neighbors of a cell into a caller-provided buffer.
+    ///
+    /// Clears `out`, then fills it with the same coordinates, in the same
+    /// order, as [`neighbours`](Self::neighbours). Hot loops can reuse one
+    /// buffer across all cells; the 12-element inline capacity covers every
+    /// built-in backend (Fcc12 has 12 neighbors) without spilling.
+    ///
+    /// The default implementation copies from `neighbours()`. Backends
+    /// should override to push directly into `out`.
+    fn neighbours_into(&self, coord: &Coord, out: &mut SmallVec<[Coord; 12]>) {
+        out.clear();
+        out.extend(self.neighbours(coord));
+    }
+
     /// Maximum neighbor-list length over all cells in this space.
     ///
     /// Implementations may return a conservative upper bound, but must
@@ -60,6 +75,41 @@ pub trait Space: Any + Send + Sync + 'static {
     /// Compile a region specification into a plan for O(1) lookups.
     fn compile_region(&self, spec: &RegionSpec) -> Result<RegionPlan, SpaceError>;
 
+    /// Compile several region specifications in one pass.
+    ///
+    /// Produces exactly what calling [`compile_region`](Self::compile_region)
+    /// on each spec in order would, stopping at the first error. The default
+    /// implementation compiles `RegionSpec::All` at most once per batch;
+    /// backends with expensive per-call setup override this to share it
+    /// across all specs.
+    fn compile_regions(&self, specs: &[RegionSpec]) -> Result<Vec<RegionPlan>, SpaceError> {
+        let mut all: Option<RegionPlan> = None;
+        let mut plans = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let plan = match (spec, &all) {
+                (RegionSpec::All, Some(plan)) => plan.clone(),
+                (RegionSpec::All, None) => {
+                    let plan = self.compile_region(spec)?;
+                    all = Some(plan.clone());
+                    plan
+                }
+                _ => self.compile_region(spec)?,
+            };
+            plans.push(plan);
+        }
+        Ok(plans)
+    }
+
+    /// Fraction of the bounding tensor of `spec` that holds valid cells.
+    ///
+    /// Equals `compile_region(spec)?.valid_ratio()` and fails with the same
+    /// errors. Backends override it to count in-bounds cells without
+    /// materializing coordinates or tensor indices, so a spec can be checked
+    /// against an observation coverage threshold before it is compiled.
+    fn region_coverage(&self, spec: &RegionSpec) -> Result<f64, SpaceError> {
+        self.compile_region(spec).map(|plan| plan.valid_ratio())
+    }
+
     /// Iterate over the cells in a compiled region.
     ///
     /// Default implementation iterates over `plan.coords`. Backends may
@@ -121,6 +171,29 @@ pub trait Space: Any + Send + Sync + 'static {
     /// behavior-relevant fields (dimensions, edge behavior, etc.).
     /// Return `false` if the downcast fails (different concrete type).
     fn topology_eq(&self, other: &dyn Space) -> bool;
+
+    /// Hash of the concrete type and the parameters compared by
+    /// [`topology_eq`](Self::topology_eq).
+    ///
+    /// Spaces that are `topology_eq` must return the same digest. Used by
+    /// [`RegionPlanCache`](crate::RegionPlanCache) to share compiled plans
+    /// between distinct but identical spaces. The digest is only stable
+    /// within one process.
+    ///
+    /// Default: `None`, so plans are shared only between clones of one
+    /// instance.
+    fn topology_digest(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// [`Space::topology_digest`] of a space of type `S` with topology
+/// parameters `params`.
+pub(crate) fn topology_digest_of<S: Space, P: Hash>(params: P) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    TypeId::of::<S>().hash(&mut hasher);
+    params.hash(&mut hasher);
+    hasher.finish()
 }
 
 impl dyn Space {
@@ -3,8 +3,9 @@
 use crate::edge::EdgeBehavior;
 use crate::error::SpaceError;
 use crate::grid2d;
+use crate::order::MemoryOrder;
 use crate::region::{RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::{smallvec, SmallVec};
 
@@ -18,11 +19,15 @@ use smallvec::{smallvec, SmallVec};
 /// - **Absorb**: edge cells have fewer neighbors (corners have 2, edges have 3)
 /// - **Clamp**: edge cells self-loop on the boundary axis
 /// - **Wrap**: periodic boundary (torus topology)
+///
+/// Each axis may use its own behavior via [`new_per_axis`](Self::new_per_axis).
 #[derive(Debug, Clone)]
 pub struct Square4 {
     rows: u32,
     cols: u32,
-    edge: EdgeBehavior,
+    /// Edge behavior per axis: `[rows, cols]`.
+    edges: [EdgeBehavior; 2],
+    order: MemoryOrder,
     instance_id: SpaceInstanceId,
 }
 
@@ -49,6 +54,22 @@ impl Square4 {
     /// assert_eq!(grid.neighbours(&coord).len(), 2);
     /// ```
     pub fn new(rows: u32, cols: u32, edge: EdgeBehavior) -> Result<Self, SpaceError> {
+        Self::new_per_axis(rows, cols, [edge, edge])
+    }
+
+    /// Create a new 2D grid whose row and column axes resolve
+    /// out-of-bounds offsets independently (`edges = [rows, cols]`).
+    ///
+    /// For example `[Wrap, Absorb]` gives a cylinder: rows wrap top to
+    /// bottom while the left and right columns are hard walls.
+    /// [`new`](Self::new) is the symmetric case.
+    ///
+    /// Errors as for [`new`](Self::new).
+    pub fn new_per_axis(
+        rows: u32,
+        cols: u32,
+        edges: [EdgeBehavior; 2],
+    ) -> Result<Self, SpaceError> {
         if rows == 0 || cols == 0 {
             return Err(SpaceError::EmptySpace);
         }
@@ -69,11 +90,24 @@ impl Square4 {
         Ok(Self {
             rows,
             cols,
-            edge,
+            edges,
+            order: MemoryOrder::RowMajor,
             instance_id: SpaceInstanceId::next(),
         })
     }
 
+    /// Use `order` for canonical ranks and region tensor layout.
+    ///
+    /// The grid gets a fresh instance ID, since its rank mapping differs
+    /// from the original's.
+    pub fn with_memory_order(self, order: MemoryOrder) -> Self {
+        Self {
+            order,
+            instance_id: SpaceInstanceId::next(),
+            ..self
+        }
+    }
+
     /// Number of rows.
     pub fn rows(&self) -> u32 {
         self.rows
@@ -89,9 +123,23 @@ impl Square4 {
         false
     }
 
-    /// Edge behavior.
+    /// Edge behavior of the row axis.
+    ///
+    /// Both axes share it unless the grid was built with
+    /// [`new_per_axis`](Self::new_per_axis); see
+    /// [`edge_behaviors`](Self::edge_behaviors).
     pub fn edge_behavior(&self) -> EdgeBehavior {
-        self.edge
+        self.edges[0]
+    }
+
+    /// Edge behavior per axis: `[rows, cols]`.
+    pub fn edge_behaviors(&self) -> [EdgeBehavior; 2] {
+        self.edges
+    }
+
+    /// Memory order of canonical ranks (default: [`MemoryOrder::RowMajor`]).
+    pub fn memory_order(&self) -> MemoryOrder {
+        self.order
     }
 
     /// Compute the 4-connected neighbours of `(r, c)` as `(row, col)` pairs.
@@ -99,10 +147,8 @@ impl Square4 {
         let offsets: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
         let mut result = Vec::with_capacity(4);
         for (dr, dc) in offsets {
-            let nr = grid2d::resolve_axis(r + dr, self.rows, self.edge);
-            let nc = grid2d::resolve_axis(c + dc, self.cols, self.edge);
-            if let (Some(nr), Some(nc)) = (nr, nc) {
-                result.push((nr, nc));
+            if let Some(nb) = grid2d::resolve_2d(r + dr, c + dc, self.rows, self.cols, self.edges) {
+                result.push(nb);
             }
         }
         result
@@ -131,20 +177,22 @@ impl Space for Square4 {
             .collect()
     }
 
+    fn neighbours_into(&self, coord: &Coord, out: &mut SmallVec<[Coord; 12]>) {
+        debug_assert_eq!(
+            coord.len(), self.ndim(),
+            "coord arity {}, expected {}", coord.len(), self.ndim()
+        );
+        out.clear();
+        out.extend(
+            self.neighbours_rc(coord[0], coord[1])
+                .into_iter()
+                .map(|(nr, nc)| smallvec![nr, nc]),
+        );
+    }
+
     fn max_neighbour_degree(&self) -> usize {
-        match self.edge {
-            EdgeBehavior::Clamp | EdgeBehavior::Wrap => 4,
-            EdgeBehavior::Absorb => {
-                let axis_max = |len: u32| -> usize {
-                    match len {
-                        0 | 1 => 0,
-                        2 => 1,
-                        _ => 2,
-                    }
-                };
-                axis_max(self.rows) + axis_max(self.cols)
-            }
-        }
+        grid2d::axis_degree(self.rows, self.edges[0])
+            + grid2d::axis_degree(self.cols, self.edges[1])
     }
 
     fn distance(&self, a: &Coord, b: &Coord) -> f64 {
@@ -157,48 +205,41 @@ impl Space for Square4 {
             "coord b arity {}, expected {}", b.len(), self.ndim()
         );
         // Manhattan (L1) distance — matches graph geodesic for 4-connected.
-        let dr = grid2d::axis_distance(a[0], b[0], self.rows, self.edge);
-        let dc = grid2d::axis_distance(a[1], b[1], self.cols, self.edge);
+        let dr = grid2d::axis_distance(a[0], b[0], self.rows, self.edges[0]);
+        let dc = grid2d::axis_distance(a[1], b[1], self.cols, self.edges[1]);
         dr + dc
     }
 
     fn compile_region(&self, spec: &RegionSpec) -> Result<RegionPlan, SpaceError> {
-        let edge = self.edge;
+        let edges = self.edges;
         let rows = self.rows;
         let cols = self.cols;
-        grid2d::compile_region_2d(spec, rows, cols, self, |r, c| {
+        grid2d::compile_region_2d(spec, rows, cols, self.order, self, |r, c| {
             let offsets: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
             let mut result = Vec::with_capacity(4);
             for (dr, dc) in offsets {
-                let nr = grid2d::resolve_axis(r + dr, rows, edge);
-                let nc = grid2d::resolve_axis(c + dc, cols, edge);
-                if let (Some(nr), Some(nc)) = (nr, nc) {
-                    result.push((nr, nc));
+                if let Some(nb) = grid2d::resolve_2d(r + dr, c + dc, rows, cols, edges) {
+                    result.push(nb);
                 }
             }
             result
         })
     }
 
+    fn region_coverage(&self, spec: &RegionSpec) -> Result<f64, SpaceError> {
+        grid2d::region_coverage_2d(spec, self.rows, self.cols)
+    }
+
     fn canonical_ordering(&self) -> Vec<Coord> {
-        grid2d::canonical_ordering_2d(self.rows, self.cols)
+        grid2d::canonical_ordering_2d(self.rows, self.cols, self.order)
     }
 
     fn canonical_rank(&self, coord: &Coord) -> Option<usize> {
-        grid2d::canonical_rank_2d(coord, self.rows, self.cols)
+        grid2d::canonical_rank_2d(coord, self.rows, self.cols, self.order)
     }
 
     fn canonical_rank_slice(&self, coord: &[i32]) -> Option<usize> {
-        if coord.len() != 2 {
-            return None;
-        }
-        let r = coord[0];
-        let c = coord[1];
-        if r >= 0 && r < self.rows as i32 && c >= 0 && c < self.cols as i32 {
-            Some(r as usize * self.cols as usize + c as usize)
-        } else {
-            None
-        }
+        grid2d::canonical_rank_slice_2d(coord, self.rows, self.cols, self.order)
     }
 
     fn instance_id(&self) -> SpaceInstanceId {
@@ -208,7 +249,18 @@ impl Space for Square4 {
     fn topology_eq(&self, other: &dyn Space) -> bool {
         (other as &dyn std::any::Any)
             .downcast_ref::<Self>()
-            .is_some_and(|o| self.rows == o.rows && self.cols == o.cols && self.edge == o.edge)
+            .is_some_and(|o| {
+                self.rows == o.rows
+                    && self.cols == o.cols
+                    && self.edges == o.edges
+                    && self.order == o.order
+            })
+    }
+
+    fn topology_digest(&self) -> Option<u64> {
+        Some(topology_digest_of::<Self, _>((
+            self.rows, self.cols, self.edges, self.order,
+        )))
     }
 }
 
@@ -307,8 +359,45 @@ mod tests {
         assert_eq!(s.distance(&c(0, 0), &c(3, 4)), 7.0);
     }
 
+    #[test]
+    fn compile_region_disk_wraps_across_edges() {
+        let s = Square4::new(10, 10, EdgeBehavior::Wrap).unwrap();
+        // Opposite edges are adjacent on the torus.
+        assert_eq!(s.distance(&c(0, 0), &c(0, 9)), 1.0);
+        assert_eq!(s.distance(&c(0, 4), &c(9, 4)), 1.0);
+
+        let plan = s
+            .compile_region(&RegionSpec::Disk {
+                center: c(0, 0),
+                radius: 1,
+            })
+            .unwrap();
+        assert_eq!(plan.cell_count(), 5);
+        assert!(plan.coords().contains(&c(9, 0)));
+        assert!(plan.coords().contains(&c(0, 9)));
+        for coord in plan.coords() {
+            assert!(s.distance(&c(0, 0), coord) <= 1.0);
+        }
+    }
+
     // ── Region tests ────────────────────────────────────────────
 
+    #[test]
+    fn compile_regions_rects_match_individual() {
+        let s = Square4::new(12, 12, EdgeBehavior::Absorb).unwrap();
+        let specs: Vec<RegionSpec> = (0..6)
+            .map(|i| RegionSpec::Rect {
+                min: c(i, i / 2),
+                max: c(i + 5, 11 - i),
+            })
+            .collect();
+        let batched = s.compile_regions(&specs).unwrap();
+        assert_eq!(batched.len(), specs.len());
+        for (spec, plan) in specs.iter().zip(&batched) {
+            assert_eq!(plan, &s.compile_region(spec).unwrap());
+        }
+    }
+
     #[test]
     fn compile_region_all() {
         let s = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
@@ -331,6 +420,38 @@ mod tests {
         assert_eq!(plan.cell_count(), 13);
     }
 
+    #[test]
+    fn region_coverage_matches_valid_ratio() {
+        let s = Square4::new(6, 8, EdgeBehavior::Absorb).unwrap();
+        let specs = [
+            RegionSpec::All,
+            RegionSpec::Disk {
+                center: c(0, 0),
+                radius: 3,
+            },
+            RegionSpec::Neighbours {
+                center: c(5, 7),
+                depth: 2,
+            },
+            RegionSpec::Rect {
+                min: c(1, 2),
+                max: c(4, 6),
+            },
+            RegionSpec::Coords(vec![c(1, 1), c(0, 3)]),
+            RegionSpec::Coords(vec![]),
+        ];
+        for spec in &specs {
+            let plan = s.compile_region(spec).unwrap();
+            assert_eq!(s.region_coverage(spec).unwrap(), plan.valid_ratio(), "{spec:?}");
+        }
+        let bad = RegionSpec::Rect {
+            min: c(4, 6),
+            max: c(1, 2),
+        };
+        assert!(s.region_coverage(&bad).is_err());
+        assert!(s.region_coverage(&RegionSpec::Coords(vec![c(6, 0)])).is_err());
+    }
+
     #[test]
     fn compile_region_rect() {
         let s = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
@@ -343,6 +464,29 @@ mod tests {
         assert_eq!(plan.cell_count(), 12); // 3 rows * 4 cols
     }
 
+    #[test]
+    fn region_plan_iter_pairs_coords_with_tensor_indices() {
+        let s = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
+        let plan = s
+            .compile_region(&RegionSpec::Rect {
+                min: c(2, 3),
+                max: c(4, 6),
+            })
+            .unwrap();
+        let pairs: Vec<(&Coord, usize)> = plan.iter().collect();
+        assert_eq!(pairs.len(), plan.cell_count());
+        for (i, &(coord, idx)) in pairs.iter().enumerate() {
+            assert_eq!(coord, &plan.coords()[i]);
+            assert_eq!(idx, plan.tensor_indices()[i]);
+        }
+        // A row-major rect fills its bounding box in tensor-index order.
+        let indices: Vec<usize> = pairs.iter().map(|&(_, idx)| idx).collect();
+        assert_eq!(indices, (0..12).collect::<Vec<_>>());
+        assert_eq!(pairs[0].0, &c(2, 3));
+        assert_eq!(pairs[11].0, &c(4, 6));
+        assert!(plan.valid_mask().iter().all(|&v| v == 1));
+    }
+
     #[test]
     fn compile_region_rect_invalid() {
         let s = Square4::new(10, 10, EdgeBehavior::Absorb).unwrap();
@@ -354,6 +498,48 @@ mod tests {
             .is_err());
     }
 
+    #[test]
+    fn compile_region_errors_are_structured() {
+        let s = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
+        assert_eq!(
+            s.compile_region(&RegionSpec::Disk {
+                center: c(5, 0),
+                radius: 1,
+            }),
+            Err(SpaceError::CoordOutOfBounds {
+                coord: c(5, 0),
+                bounds: "[0, 5) x [0, 5)".into(),
+            })
+        );
+        assert_eq!(
+            s.compile_region(&RegionSpec::Coords(vec![smallvec![1, 2, 3]])),
+            Err(SpaceError::DimensionMismatch {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert!(matches!(
+            s.compile_region(&RegionSpec::Rect {
+                min: c(3, 0),
+                max: c(1, 0),
+            }),
+            Err(SpaceError::InvalidRegion { .. })
+        ));
+
+        // Square4 supports every region kind; custom backends report the
+        // ones they reject by name.
+        let spec = RegionSpec::Neighbours {
+            center: c(0, 0),
+            depth: 1,
+        };
+        assert!(s.compile_region(&spec).is_ok());
+        let err = SpaceError::UnsupportedRegion { kind: spec.kind() };
+        assert_eq!(
+            err.to_string(),
+            "region kind Neighbours is not supported by this space"
+        );
+    }
+
     #[test]
     fn compile_region_coords_valid() {
         let s = Square4::new(5, 5, EdgeBehavior::Absorb).unwrap();
@@ -440,6 +626,135 @@ mod tests {
         compliance::run_full_compliance(&s);
     }
 
+    #[test]
+    fn compliance_column_major() {
+        for edge in [
+            EdgeBehavior::Absorb,
+            EdgeBehavior::Clamp,
+            EdgeBehavior::Wrap,
+        ] {
+            let s = Square4::new(5, 7, edge)
+                .unwrap()
+                .with_memory_order(MemoryOrder::ColumnMajor);
+            compliance::run_full_compliance(&s);
+        }
+    }
+
+    #[test]
+    fn compliance_per_axis_edges() {
+        let s = Square4::new_per_axis(6, 5, [EdgeBehavior::Wrap, EdgeBehavior::Absorb]).unwrap();
+        compliance::run_full_compliance(&s);
+    }
+
+    // ── Per-axis edge tests ─────────────────────────────────────
+
+    #[test]
+    fn per_axis_wraps_rows_and_absorbs_cols() {
+        let s = Square4::new_per_axis(5, 5, [EdgeBehavior::Wrap, EdgeBehavior::Absorb]).unwrap();
+        // Top-edge cell wraps north to the bottom row.
+        let n = s.neighbours(&c(0, 2));
+        assert_eq!(n.len(), 4);
+        assert!(n.contains(&c(4, 2)));
+        // Left-edge cell loses its west neighbour.
+        let n = s.neighbours(&c(2, 0));
+        assert_eq!(n.len(), 3);
+        assert!(!n.contains(&c(2, 4)), "west must not wrap: {n:?}");
+        // Corner: wraps vertically, absorbed horizontally.
+        let n = s.neighbours(&c(0, 0));
+        assert_eq!(n.len(), 3);
+        assert!(n.contains(&c(4, 0)));
+
+        assert_eq!(s.distance(&c(0, 0), &c(4, 0)), 1.0);
+        assert_eq!(s.distance(&c(0, 0), &c(0, 4)), 4.0);
+        assert_eq!(s.max_neighbour_degree(), 4);
+        assert_eq!(
+            s.edge_behaviors(),
+            [EdgeBehavior::Wrap, EdgeBehavior::Absorb]
+        );
+    }
+
+    #[test]
+    fn per_axis_topology_differs_from_uniform() {
+        let mixed =
+            Square4::new_per_axis(4, 4, [EdgeBehavior::Wrap, EdgeBehavior::Absorb]).unwrap();
+        let uniform = Square4::new(4, 4, EdgeBehavior::Wrap).unwrap();
+        let same = Square4::new_per_axis(4, 4, [EdgeBehavior::Wrap; 2]).unwrap();
+        assert!(!mixed.topology_eq(&uniform));
+        assert!(same.topology_eq(&uniform));
+        assert_ne!(mixed.topology_digest(), uniform.topology_digest());
+        assert_eq!(same.topology_digest(), uniform.topology_digest());
+        assert!(uniform.topology_digest().is_some());
+    }
+
+    // ── Memory order tests ──────────────────────────────────────
+
+    #[test]
+    fn column_major_rank_and_ordering() {
+        let s = Square4::new(2, 3, EdgeBehavior::Absorb)
+            .unwrap()
+            .with_memory_order(MemoryOrder::ColumnMajor);
+        assert_eq!(
+            s.canonical_ordering(),
+            vec![c(0, 0), c(1, 0), c(0, 1), c(1, 1), c(0, 2), c(1, 2)]
+        );
+        assert_eq!(s.canonical_rank(&c(1, 2)), Some(5));
+        assert_eq!(s.canonical_rank_slice(&[0, 2]), Some(4));
+        assert_eq!(s.canonical_rank(&c(2, 0)), None);
+    }
+
+    #[test]
+    fn column_major_region_indices_consistent() {
+        let row = Square4::new(6, 6, EdgeBehavior::Absorb).unwrap();
+        let col = row.clone().with_memory_order(MemoryOrder::ColumnMajor);
+        let specs = [
+            RegionSpec::All,
+            RegionSpec::Rect {
+                min: c(1, 2),
+                max: c(3, 5),
+            },
+            RegionSpec::Disk {
+                center: c(2, 2),
+                radius: 2,
+            },
+            RegionSpec::Coords(vec![c(4, 0), c(0, 4), c(2, 2), c(0, 4)]),
+        ];
+        for spec in &specs {
+            let a = row.compile_region(spec).unwrap();
+            let b = col.compile_region(spec).unwrap();
+            assert_eq!(a.bounding_shape(), b.bounding_shape());
+            assert_eq!(a.cell_count(), b.cell_count());
+            // Column-major plans visit cells in ascending column-major rank.
+            let ranks: Vec<usize> = b
+                .coords()
+                .iter()
+                .map(|co| col.canonical_rank(co).unwrap())
+                .collect();
+            assert!(ranks.windows(2).all(|w| w[0] < w[1]), "{spec:?}: {ranks:?}");
+        }
+
+        // Rect tensor layout is the transpose: (r, c) lands at c_off * h + r_off.
+        let rect = col
+            .compile_region(&RegionSpec::Rect {
+                min: c(1, 2),
+                max: c(3, 5),
+            })
+            .unwrap();
+        for (co, &t) in rect.coords().iter().zip(rect.tensor_indices()) {
+            let (ro, cof) = ((co[0] - 1) as usize, (co[1] - 2) as usize);
+            assert_eq!(t, cof * 3 + ro);
+        }
+    }
+
+    #[test]
+    fn memory_order_affects_topology_and_identity() {
+        let row = Square4::new(4, 4, EdgeBehavior::Absorb).unwrap();
+        let col = row.clone().with_memory_order(MemoryOrder::ColumnMajor);
+        assert_eq!(row.memory_order(), MemoryOrder::RowMajor);
+        assert_eq!(col.memory_order(), MemoryOrder::ColumnMajor);
+        assert!(!row.topology_eq(&col));
+        assert_ne!(row.instance_id(), col.instance_id());
+    }
+
     // ── Downcast test ───────────────────────────────────────────
 
     #[test]
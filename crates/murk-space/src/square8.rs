@@ -3,8 +3,9 @@
 use crate::edge::EdgeBehavior;
 use crate::error::SpaceError;
 use crate::grid2d;
+use crate::order::MemoryOrder;
 use crate::region::{RegionPlan, RegionSpec};
-use crate::space::Space;
+use crate::space::{topology_digest_of, Space};
 use murk_core::{Coord, SpaceInstanceId};
 use smallvec::{smallvec, SmallVec};
 
@@ -30,11 +31,15 @@ const OFFSETS_8: [(i32, i32); 8] = [
 /// - **Absorb**: edge cells have fewer neighbors (corners have 3, edges have 5)
 /// - **Clamp**: edge cells self-loop on the boundary axis
 /// - **Wrap**: periodic boundary (torus topology)
+///
+/// Each axis may use its own behavior via [`new_per_axis`](Self::new_per_axis).
 #[derive(Debug, Clone)]
 pub struct Square8 {
     rows: u32,
     cols: u32,
-    edge: EdgeBehavior,
+    /// Edge behavior per axis: `[rows, cols]`.
+    edges: [EdgeBehavior; 2],
+    order: MemoryOrder,
     instance_id: SpaceInstanceId,
 }
 
@@ -47,6 +52,22 @@ impl Square8 {
     /// Returns `Err(SpaceError::EmptySpace)` if either dimension is 0, or
     /// `Err(SpaceError::DimensionTooLarge)` if either exceeds `i32::MAX`.
     pub fn new(rows: u32, cols: u32, edge: EdgeBehavior) -> Result<Self, SpaceError> {
+        Self::new_per_axis(rows, cols, [edge, edge])
+    }
+
+    /// Create a new 2D grid whose row and column axes resolve
+    /// out-of-bounds offsets independently (`edges = [rows, cols]`).
+    ///
+    /// For example `[Wrap, Absorb]` gives a cylinder: rows wrap top to
+    /// bottom while the left and right columns are hard walls.
+    /// [`new`](Self::new) is the symmetric case.
+    ///
+    /// Errors as for [`new`](Self::new).
+    pub fn new_per_axis(
+        rows: u32,
+        cols: u32,
+        edges: [EdgeBehavior; 2],
+    ) -> Result<Self, SpaceError> {
         if rows == 0 || cols == 0 {
             return Err(SpaceError::EmptySpace);
         }
@@ -67,11 +88,24 @@ impl Square8 {
         Ok(Self {
             rows,
             cols,
-            edge,
+            edges,
+            order: MemoryOrder::RowMajor,
             instance_id: SpaceInstanceId::next(),
         })
     }
 
+    /// Use `order` for canonical ranks and region tensor layout.
+    ///
+    /// The grid gets a fresh instance ID, since its rank mapping differs
+    /// from the original's.
+    pub fn with_memory_order(self, order: MemoryOrder) -> Self {
+        Self {
+            order,
+            instance_id: SpaceInstanceId::next(),
+            ..self
+        }
+    }
+
     /// Number of rows.
     pub fn rows(&self) -> u32 {
         self.rows
@@ -87,19 +121,31 @@ impl Square8 {
         false
     }
 
-    /// Edge behavior.
+    /// Edge behavior of the row axis.
+    ///
+    /// Both axes share it unless the grid was built with
+    /// [`new_per_axis`](Self::new_per_axis); see
+    /// [`edge_behaviors`](Self::edge_behaviors).
     pub fn edge_behavior(&self) -> EdgeBehavior {
-        self.edge
+        self.edges[0]
+    }
+
+    /// Edge behavior per axis: `[rows, cols]`.
+    pub fn edge_behaviors(&self) -> [EdgeBehavior; 2] {
+        self.edges
+    }
+
+    /// Memory order of canonical ranks (default: [`MemoryOrder::RowMajor`]).
+    pub fn memory_order(&self) -> MemoryOrder {
+        self.order
     }
 
     /// Compute the 8-connected neighbours of `(r, c)` as `(row, col)` pairs.
     fn neighbours_rc(&self, r: i32, c: i32) -> Vec<(i32, i32)> {
         let mut result = Vec::with_capacity(8);
         for (dr, dc) in OFFSETS_8 {
-            let nr = grid2d::resolve_axis(r + dr, self.rows, self.edge);
-            let nc = grid2d::resolve_axis(c + dc, self.cols, self.edge);
-            if let (Some(nr), Some(nc)) = (nr, nc) {
-                result.push((nr, nc));
+            if let Some(nb) = grid2d::resolve_2d(r + dr, c + dc, self.rows, self.cols, self.edges) {
+                result.push(nb);
             }
         }
         result
@@ -128,23 +174,24 @@ impl Space for Square8 {
             .collect()
     }
 
+    fn neighbours_into(&self, coord: &Coord, out: &mut SmallVec<[Coord; 12]>) {
+        debug_assert_eq!(
+            coord.len(), self.ndim(),
+            "coord arity {}, expected {}", coord.len(), self.ndim()
+        );
+        out.clear();
+        out.extend(
+            self.neighbours_rc(coord[0], coord[1])
+                .into_iter()
+                .map(|(nr, nc)| smallvec![nr, nc]),
+        );
+    }
+
     fn max_neighbour_degree(&self) -> usize {
-        match self.edge {
-            EdgeBehavior::Clamp | EdgeBehavior::Wrap => 8,
-            EdgeBehavior::Absorb => {
-                let axis_max = |len: u32| -> usize {
-                    match len {
-                        0 | 1 => 0,
-                        2 => 1,
-                        _ => 2,
-                    }
-                };
-                let v = axis_max(self.rows);
-                let h = axis_max(self.cols);
-                // Degree = cardinals + diagonals.
-                (v + h) + (v * h)
-            }
-        }
+        let v = grid2d::axis_degree(self.rows, self.edges[0]);
+        let h = grid2d::axis_degree(self.cols, self.edges[1]);
+        // Degree = cardinals + diagonals.
+        (v + h) + (v * h)
     }
 
     fn distance(&self, a: &Coord, b: &Coord) -> f64 {
@@ -157,47 +204,40 @@ impl Space for Square8 {
             "coord b arity {}, expected {}", b.len(), self.ndim()
         );
         // Chebyshev (L-inf) distance — matches graph geodesic for 8-connected.
-        let dr = grid2d::axis_distance(a[0], b[0], self.rows, self.edge);
-        let dc = grid2d::axis_distance(a[1], b[1], self.cols, self.edge);
+        let dr = grid2d::axis_distance(a[0], b[0], self.rows, self.edges[0]);
+        let dc = grid2d::axis_distance(a[1], b[1], self.cols, self.edges[1]);
         dr.max(dc)
     }
 
     fn compile_region(&self, spec: &RegionSpec) -> Result<RegionPlan, SpaceError> {
-        let edge = self.edge;
+        let edges = self.edges;
         let rows = self.rows;
         let cols = self.cols;
-        grid2d::compile_region_2d(spec, rows, cols, self, |r, c| {
+        grid2d::compile_region_2d(spec, rows, cols, self.order, self, |r, c| {
             let mut result = Vec::with_capacity(8);
             for (dr, dc) in OFFSETS_8 {
-                let nr = grid2d::resolve_axis(r + dr, rows, edge);
-                let nc = grid2d::resolve_axis(c + dc, cols, edge);
-                if let (Some(nr), Some(nc)) = (nr, nc) {
-                    result.push((nr, nc));
+                if let Some(nb) = grid2d::resolve_2d(r + dr, c + dc, rows, cols, edges) {
+                    result.push(nb);
                 }
             }
             result
         })
     }
 
+    fn region_coverage(&self, spec: &RegionSpec) -> Result<f64, SpaceError> {
+        grid2d::region_coverage_2d(spec, self.rows, self.cols)
+    }
+
     fn canonical_ordering(&self) -> Vec<Coord> {
-        grid2d::canonical_ordering_2d(self.rows, self.cols)
+        grid2d::canonical_ordering_2d(self.rows, self.cols, self.order)
     }
 
     fn canonical_rank(&self, coord: &Coord) -> Option<usize> {
-        grid2d::canonical_rank_2d(coord, self.rows, self.cols)
+        grid2d::canonical_rank_2d(coord, self.rows, self.cols, self.order)
     }
 
     fn canonical_rank_slice(&self, coord: &[i32]) -> Option<usize> {
-        if coord.len() != 2 {
-            return None;
-        }
-        let r = coord[0];
-        let c = coord[1];
-        if r >= 0 && r < self.rows as i32 && c >= 0 && c < self.cols as i32 {
-            Some(r as usize * self.cols as usize + c as usize)
-        } else {
-            None
-        }
+        grid2d::canonical_rank_slice_2d(coord, self.rows, self.cols, self.order)
     }
 
     fn instance_id(&self) -> SpaceInstanceId {
@@ -207,7 +247,18 @@ impl Space for Square8 {
     fn topology_eq(&self, other: &dyn Space) -> bool {
         (other as &dyn std::any::Any)
             .downcast_ref::<Self>()
-            .is_some_and(|o| self.rows == o.rows && self.cols == o.cols && self.edge == o.edge)
+            .is_some_and(|o| {
+                self.rows == o.rows
+                    && self.cols == o.cols
+                    && self.edges == o.edges
+                    && self.order == o.order
+            })
+    }
+
+    fn topology_digest(&self) -> Option<u64> {
+        Some(topology_digest_of::<Self, _>((
+            self.rows, self.cols, self.edges, self.order,
+        )))
     }
 }
 
@@ -278,6 +329,28 @@ mod tests {
         assert_eq!(s.distance(&c(0, 0), &c(9, 9)), 1.0);
     }
 
+    #[test]
+    fn compile_region_disk_wraps_across_edges() {
+        let s = Square8::new(10, 10, EdgeBehavior::Wrap).unwrap();
+        // Opposite edges are adjacent on the torus.
+        assert_eq!(s.distance(&c(0, 0), &c(0, 9)), 1.0);
+        assert_eq!(s.distance(&c(0, 4), &c(9, 4)), 1.0);
+
+        let plan = s
+            .compile_region(&RegionSpec::Disk {
+                center: c(0, 0),
+                radius: 1,
+            })
+            .unwrap();
+        assert_eq!(plan.cell_count(), 9);
+        assert!(plan.coords().contains(&c(9, 0)));
+        assert!(plan.coords().contains(&c(0, 9)));
+        assert!(plan.coords().contains(&c(9, 9)));
+        for coord in plan.coords() {
+            assert!(s.distance(&c(0, 0), coord) <= 1.0);
+        }
+    }
+
     // ── Region tests ────────────────────────────────────────────
 
     #[test]
@@ -374,6 +447,40 @@ mod tests {
         compliance::run_full_compliance(&s);
     }
 
+    #[test]
+    fn compliance_per_axis_edges() {
+        let s = Square8::new_per_axis(6, 5, [EdgeBehavior::Absorb, EdgeBehavior::Wrap]).unwrap();
+        compliance::run_full_compliance(&s);
+    }
+
+    #[test]
+    fn per_axis_wraps_rows_and_absorbs_cols() {
+        let s = Square8::new_per_axis(5, 5, [EdgeBehavior::Wrap, EdgeBehavior::Absorb]).unwrap();
+        // Corner wraps north (and diagonally north-east) but not west.
+        let n = s.neighbours(&c(0, 0));
+        assert_eq!(n.len(), 5);
+        assert!(n.contains(&c(4, 0)));
+        assert!(n.contains(&c(4, 1)));
+        assert!(n.iter().all(|nb| nb[1] >= 0 && nb[1] <= 1));
+        assert_eq!(s.distance(&c(0, 0), &c(4, 4)), 4.0);
+        // Cardinals (2 + 2) plus diagonals (2 * 2).
+        assert_eq!(s.max_neighbour_degree(), 8);
+    }
+
+    #[test]
+    fn compliance_column_major() {
+        for edge in [
+            EdgeBehavior::Absorb,
+            EdgeBehavior::Clamp,
+            EdgeBehavior::Wrap,
+        ] {
+            let s = Square8::new(5, 7, edge)
+                .unwrap()
+                .with_memory_order(MemoryOrder::ColumnMajor);
+            compliance::run_full_compliance(&s);
+        }
+    }
+
     // ── Downcast test ───────────────────────────────────────────
 
     #[test]
@@ -6,7 +6,7 @@
 //! - [`ConstPropagator`] — writes a constant value (Full mode, no reads).
 //! - [`FailingPropagator`] — fails deterministically after N calls.
 
-use murk_core::{FieldId, FieldSet, PropagatorError};
+use murk_core::{FieldId, FieldSet, PropagatorError, Scalar};
 use murk_propagator::context::StepContext;
 use murk_propagator::propagator::{Propagator, WriteMode};
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -51,7 +51,7 @@ impl Propagator for IdentityPropagator {
                 .ok_or_else(|| PropagatorError::ExecutionFailed {
                     reason: format!("field {:?} not readable", self.input),
                 })?;
-        let input_copy: Vec<f32> = input.to_vec();
+        let input_copy: Vec<Scalar> = input.to_vec();
         let output =
             ctx.writes()
                 .write(self.output)
@@ -82,11 +82,11 @@ impl Propagator for IdentityPropagator {
 pub struct ConstPropagator {
     pub name: String,
     pub output: FieldId,
-    pub value: f32,
+    pub value: Scalar,
 }
 
 impl ConstPropagator {
-    pub fn new(name: impl Into<String>, output: FieldId, value: f32) -> Self {
+    pub fn new(name: impl Into<String>, output: FieldId, value: Scalar) -> Self {
         Self {
             name: name.into(),
             output,
@@ -183,7 +183,7 @@ impl Propagator for FailingPropagator {
                 .ok_or_else(|| PropagatorError::ExecutionFailed {
                     reason: format!("field {:?} not writable", self.output),
                 })?;
-        output.fill(n as f32);
+        output.fill(n as Scalar);
         Ok(())
     }
 }
@@ -14,7 +14,8 @@ pub mod fixtures;
 use std::collections::HashMap;
 
 use murk_core::{
-    FieldId, FieldReader, FieldWriter, ParameterVersion, SnapshotAccess, TickId, WorldGenerationId,
+    FieldId, FieldReader, FieldWriter, ParameterKey, ParameterStore, ParameterVersion, Scalar,
+    SnapshotAccess, TickId, WorldGenerationId,
 };
 
 pub use fixtures::{ConstPropagator, FailingPropagator, IdentityPropagator};
@@ -39,11 +40,11 @@ impl Default for TestWorldBuilder {
 
 /// Mock implementation of [`FieldReader`].
 ///
-/// Backed by a `HashMap<FieldId, Vec<f32>>` for flexible test setup.
+/// Backed by a `HashMap<FieldId, Vec<Scalar>>` for flexible test setup.
 /// Pre-populate fields with [`set_field`](MockFieldReader::set_field)
 /// before passing to code under test.
 pub struct MockFieldReader {
-    fields: HashMap<FieldId, Vec<f32>>,
+    fields: HashMap<FieldId, Vec<Scalar>>,
 }
 
 impl MockFieldReader {
@@ -54,7 +55,7 @@ impl MockFieldReader {
     }
 
     /// Pre-populate a field with data for testing.
-    pub fn set_field(&mut self, field: FieldId, data: Vec<f32>) {
+    pub fn set_field(&mut self, field: FieldId, data: Vec<Scalar>) {
         self.fields.insert(field, data);
     }
 }
@@ -66,19 +67,19 @@ impl Default for MockFieldReader {
 }
 
 impl FieldReader for MockFieldReader {
-    fn read(&self, field: FieldId) -> Option<&[f32]> {
+    fn read(&self, field: FieldId) -> Option<&[Scalar]> {
         self.fields.get(&field).map(|v| v.as_slice())
     }
 }
 
 /// Mock implementation of [`FieldWriter`].
 ///
-/// Backed by a `HashMap<FieldId, Vec<f32>>` for flexible test setup.
+/// Backed by a `HashMap<FieldId, Vec<Scalar>>` for flexible test setup.
 /// Pre-allocate field buffers with [`add_field`](MockFieldWriter::add_field),
 /// then pass to code under test. Inspect results with
 /// [`get_field`](MockFieldWriter::get_field).
 pub struct MockFieldWriter {
-    fields: HashMap<FieldId, Vec<f32>>,
+    fields: HashMap<FieldId, Vec<Scalar>>,
 }
 
 impl MockFieldWriter {
@@ -94,7 +95,7 @@ impl MockFieldWriter {
     }
 
     /// Read back the current field data for test assertions.
-    pub fn get_field(&self, field: FieldId) -> Option<&[f32]> {
+    pub fn get_field(&self, field: FieldId) -> Option<&[Scalar]> {
         self.fields.get(&field).map(|v| v.as_slice())
     }
 }
@@ -106,20 +107,22 @@ impl Default for MockFieldWriter {
 }
 
 impl FieldWriter for MockFieldWriter {
-    fn write(&mut self, field: FieldId) -> Option<&mut [f32]> {
+    fn write(&mut self, field: FieldId) -> Option<&mut [Scalar]> {
         self.fields.get_mut(&field).map(|v| v.as_mut_slice())
     }
 }
 
 /// Mock snapshot implementing [`SnapshotAccess`].
 ///
-/// Backed by a `HashMap<FieldId, Vec<f32>>` with configurable
-/// tick, generation, and parameter version metadata.
+/// Backed by a `HashMap<FieldId, Vec<Scalar>>` with configurable
+/// tick, generation, and parameter version metadata, plus a
+/// [`ParameterStore`] for global parameters.
 pub struct MockSnapshot {
-    fields: HashMap<FieldId, Vec<f32>>,
+    fields: HashMap<FieldId, Vec<Scalar>>,
     tick: TickId,
     world_gen: WorldGenerationId,
     param_ver: ParameterVersion,
+    parameters: ParameterStore,
 }
 
 impl MockSnapshot {
@@ -130,14 +133,20 @@ impl MockSnapshot {
             tick,
             world_gen,
             param_ver,
+            parameters: ParameterStore::new(),
         }
     }
 
     /// Pre-populate a field with data for testing.
-    pub fn set_field(&mut self, field: FieldId, data: Vec<f32>) {
+    pub fn set_field(&mut self, field: FieldId, data: Vec<Scalar>) {
         self.fields.insert(field, data);
     }
 
+    /// Set a global parameter reported by [`SnapshotAccess::parameter`].
+    pub fn set_parameter(&mut self, key: ParameterKey, value: f64) {
+        self.parameters.set(key, value);
+    }
+
     /// Returns the number of fields in the snapshot.
     pub fn field_count(&self) -> usize {
         self.fields.len()
@@ -145,7 +154,7 @@ impl MockSnapshot {
 }
 
 impl SnapshotAccess for MockSnapshot {
-    fn read_field(&self, field: FieldId) -> Option<&[f32]> {
+    fn read_field(&self, field: FieldId) -> Option<&[Scalar]> {
         self.fields.get(&field).map(|v| v.as_slice())
     }
 
@@ -160,4 +169,26 @@ impl SnapshotAccess for MockSnapshot {
     fn parameter_version(&self) -> ParameterVersion {
         self.param_ver
     }
+
+    fn parameter(&self, key: ParameterKey) -> Option<f32> {
+        self.parameters.get(key).map(|v| v as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mock_snapshot_returns_set_parameters() {
+        let mut snap = MockSnapshot::new(TickId(3), WorldGenerationId(1), ParameterVersion(2));
+        assert_eq!(snap.parameter(ParameterKey(0)), None);
+
+        snap.set_parameter(ParameterKey(0), 2.5);
+        snap.set_parameter(ParameterKey(4), -1.0);
+        snap.set_parameter(ParameterKey(0), 0.75);
+        assert_eq!(snap.parameter(ParameterKey(0)), Some(0.75));
+        assert_eq!(snap.parameter(ParameterKey(4)), Some(-1.0));
+        assert_eq!(snap.parameter(ParameterKey(1)), None);
+    }
 }